@@ -0,0 +1,76 @@
+use super::{App, Mode};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    pub(super) async fn handle_annotation_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        match key.code {
+            KeyCode::Esc => {
+                state.annotation_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                state.save_annotation_dialog();
+            }
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                if let Some(ref mut dialog) = state.annotation_dialog {
+                    dialog.toggle_field();
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(ref mut dialog) = state.annotation_dialog {
+                    dialog.active_input().pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(ref mut dialog) = state.annotation_dialog {
+                    dialog.active_input().push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Edits the search query, selecting the first matching device as you type.
+    pub(super) async fn handle_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                let mut state = self.state.lock().await;
+                state.search_query.clear();
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let mut state = self.state.lock().await;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Backspace => {
+                {
+                    let mut state = self.state.lock().await;
+                    state.search_query.pop();
+                }
+                self.jump_to_search_match(false).await;
+            }
+            KeyCode::Char(c) => {
+                {
+                    let mut state = self.state.lock().await;
+                    state.search_query.push(c);
+                }
+                self.jump_to_search_match(false).await;
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the selection to the next device matching the search query.
+    pub(super) async fn jump_to_search_match(&mut self, skip_current: bool) {
+        let selection_changed = {
+            let mut state = self.state.lock().await;
+            let before = (state.selected_android, state.selected_ios);
+            state.select_next_search_match(skip_current)
+                && before != (state.selected_android, state.selected_ios)
+        };
+        if selection_changed {
+            self.handle_selection_changed().await;
+        }
+    }
+}
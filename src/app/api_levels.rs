@@ -1,16 +1,34 @@
 use super::{state, App, Mode, Panel};
 use crate::constants::{
     messages::{
-        errors::{CANNOT_SELECT_DURING_DOWNLOAD, CANNOT_SELECT_DURING_SYSTEM_IMAGE_OPERATION},
+        errors::{
+            CANNOT_SELECT_DURING_DOWNLOAD, CANNOT_SELECT_DURING_SYSTEM_IMAGE_OPERATION,
+            OFFLINE_INSTALL, SYSTEM_IMAGE_QUEUE_FAILED,
+        },
         notifications::{
-            INSTALL_PROGRESS_COMPLETE, SYSTEM_IMAGE_INSTALLED, SYSTEM_IMAGE_UNINSTALLED,
+            INSTALL_PROGRESS_COMPLETE, SYSTEM_IMAGES_INSTALLED, SYSTEM_IMAGE_INSTALLED,
+            SYSTEM_IMAGE_OPERATION_CANCELLED, SYSTEM_IMAGE_UNINSTALLED,
         },
     },
     performance::API_INSTALLATION_COMPLETION_DELAY,
     progress::PROGRESS_PHASE_100_PERCENT,
 };
+use crate::managers::common::DeviceManager;
+use crate::models::device_info::sort_android_devices_for_display;
+use crate::utils::{network, tasks};
 use crossterm::event::{KeyCode, KeyEvent};
 
+/// What happens to the AVDs that boot from an image being uninstalled.
+#[derive(Debug, Clone, PartialEq)]
+enum DependentAvdAction {
+    /// Leave them alone; they will fail to start
+    Keep,
+    /// Delete them before uninstalling
+    Delete,
+    /// Point them at another installed image (package ID)
+    Retarget(String),
+}
+
 impl App {
     fn update_api_level_installation_state(
         api_mgmt: &mut state::ApiLevelManagementState,
@@ -31,43 +49,82 @@ impl App {
         }
     }
 
+    /// Opens the API level dialog. Levels listed within the manager's cache
+    /// TTL are shown as final; otherwise the last known levels from the
+    /// device cache are shown right away while sdkmanager revalidates them.
     pub(super) async fn open_api_level_management(&mut self) {
-        let cached_api_levels = self.android_manager.get_cached_api_levels().await;
-        let has_warm_cache = cached_api_levels.is_some();
+        let fresh_api_levels = self.android_manager.get_cached_api_levels().await;
+        let has_warm_cache = fresh_api_levels.is_some();
 
         let should_open = {
             let mut state = self.state.lock().await;
-            if state.active_panel != Panel::Android {
-                false
+            if !state.active_capabilities().install_system_images {
+                None
             } else {
                 let mut api_state = state::ApiLevelManagementState::new();
-                if let Some(cached_api_levels) = cached_api_levels {
-                    api_state.api_levels = cached_api_levels;
+                api_state.is_offline = self.android_manager.is_offline();
+                if let Some(fresh_api_levels) = fresh_api_levels {
+                    api_state.api_levels = fresh_api_levels;
                     api_state.is_loading = false;
+                } else if let Some(ref stale_api_levels) =
+                    state.device_cache.read().await.android_system_images
+                {
+                    api_state.api_levels = stale_api_levels.clone();
                 }
+                let cancel_token = api_state.cancel_token.clone();
                 state.mode = Mode::ManageApiLevels;
                 state.api_level_management = Some(api_state);
-                true
+                Some(cancel_token)
             }
         };
 
-        if !should_open {
+        let Some(cancel_token) = should_open else {
             return;
-        }
-
-        if has_warm_cache {
-            return;
-        }
+        };
 
         let android_manager = self.android_manager.clone();
         let state_clone = self.state.clone();
-        tokio::spawn(async move {
-            let result = android_manager.list_api_levels().await;
+        let state_clone_for_progress = state_clone.clone();
+        tasks::spawn(async move {
+            // Checked first so a missing connection costs a short probe
+            // instead of sdkmanager's network timeout
+            let offline = !network::is_online().await;
+            android_manager.set_offline(offline).await;
+            if cancel_token.is_cancelled() {
+                return;
+            }
+            if let Some(ref mut api_state) = state_clone.lock().await.api_level_management {
+                api_state.is_offline = offline;
+            }
+            if has_warm_cache {
+                return;
+            }
+
+            let load = android_manager.list_api_levels_progressive(move |api_levels| {
+                let state_clone = state_clone_for_progress.clone();
+                tasks::spawn(async move {
+                    let mut state = state_clone.lock().await;
+                    if let Some(ref mut api_state) = state.api_level_management {
+                        api_state.show_partial_api_levels(api_levels);
+                    }
+                });
+            });
+            let result = cancel_token
+                .run_until_cancelled(load)
+                .await
+                .and_then(|result| result);
+            if cancel_token.is_cancelled() {
+                return;
+            }
+
             let mut state = state_clone.lock().await;
+            if let Ok(ref api_levels) = result {
+                state.device_cache.write().await.android_system_images = Some(api_levels.clone());
+            }
             if let Some(ref mut api_state) = state.api_level_management {
                 api_state.is_loading = false;
                 match result {
-                    Ok(api_levels) => api_state.api_levels = api_levels,
+                    Ok(api_levels) => api_state.set_api_levels(api_levels),
                     Err(error) => {
                         api_state.error_message =
                             Some(format!("Failed to load API levels: {error}"));
@@ -78,11 +135,37 @@ impl App {
     }
 
     pub(super) async fn handle_api_level_mode_key(&mut self, key: KeyEvent) {
+        if self.handle_uninstall_confirmation_key(key).await {
+            return;
+        }
+
+        if key.code != KeyCode::Esc {
+            let mut state = self.state.lock().await;
+            if let Some(ref mut api_mgmt) = state.api_level_management {
+                api_mgmt.confirm_cancel = false;
+            }
+        }
+
+        if self.handle_api_level_search_key(key).await {
+            return;
+        }
+
         match key.code {
             KeyCode::Esc => {
                 let mut state = self.state.lock().await;
-                if let Some(ref api_mgmt) = state.api_level_management {
-                    if !api_mgmt.is_busy() {
+                if let Some(ref mut api_mgmt) = state.api_level_management {
+                    if api_mgmt.is_busy() && !api_mgmt.confirm_cancel {
+                        // Downloads are large, so a stray Esc only asks first
+                        api_mgmt.confirm_cancel = true;
+                    } else if api_mgmt.is_busy() {
+                        api_mgmt.cancel_operation();
+                        state
+                            .add_warning_notification(SYSTEM_IMAGE_OPERATION_CANCELLED.to_string());
+                    } else if api_mgmt.is_loading && !api_mgmt.api_levels.is_empty() {
+                        // Stop revalidating and keep the cached levels usable
+                        api_mgmt.cancel_operation();
+                    } else {
+                        api_mgmt.cancel_token.cancel();
                         state.mode = Mode::Normal;
                         state.api_level_management = None;
                     }
@@ -106,6 +189,9 @@ impl App {
                     if api_mgmt.is_busy() {
                         state.add_warning_notification(CANNOT_SELECT_DURING_DOWNLOAD.to_string());
                         false
+                    } else if api_mgmt.is_offline {
+                        state.add_warning_notification(OFFLINE_INSTALL.to_string());
+                        false
                     } else {
                         true
                     }
@@ -138,124 +224,296 @@ impl App {
                     self.uninstall_selected_api_level().await;
                 }
             }
+            KeyCode::Char(' ') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut api_state) = state.api_level_management {
+                    api_state.toggle_mark_selected();
+                }
+            }
+            KeyCode::Char('y') => {
+                self.copy_install_command().await;
+            }
+            KeyCode::Char('/') | KeyCode::Char('f') | KeyCode::Char('s') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut api_state) = state.api_level_management {
+                    match key.code {
+                        KeyCode::Char('/') => api_state.start_search(),
+                        KeyCode::Char('f') => api_state.cycle_filter(),
+                        _ => api_state.toggle_sort(),
+                    }
+                }
+            }
             _ => {}
         }
     }
 
+    /// Edits the search query while it is typed. Returns false when the
+    /// search prompt is not open.
+    async fn handle_api_level_search_key(&mut self, key: KeyEvent) -> bool {
+        let mut state = self.state.lock().await;
+        let Some(api_state) = state
+            .api_level_management
+            .as_mut()
+            .filter(|api_state| api_state.is_searching)
+        else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Enter => api_state.finish_search(false),
+            KeyCode::Esc => api_state.finish_search(true),
+            KeyCode::Backspace => api_state.pop_search_char(),
+            KeyCode::Up => api_state.move_up(),
+            KeyCode::Down => api_state.move_down(),
+            KeyCode::Char(c) => api_state.push_search_char(c),
+            _ => {}
+        }
+        true
+    }
+
+    /// Installs the marked system images one after another, or the
+    /// recommended image of the selected API level when none is marked. A
+    /// failed image does not stop the ones after it.
     async fn install_selected_api_level(&mut self) {
-        let package_id = {
+        let (package_ids, cancel_token) = {
             let mut state = self.state.lock().await;
-            let Some(ref api_state) = state.api_level_management else {
-                return;
-            };
-            let Some(api_level) = api_state.get_selected_api_level() else {
+            let Some(ref mut api_mgmt) = state.api_level_management else {
                 return;
             };
-            let Some(variant) = api_level.get_recommended_variant() else {
+            let items = api_mgmt.take_install_queue_items();
+            let Some(first) = items.first() else {
                 return;
             };
-            if variant.is_installed {
-                return;
-            }
-
-            let package_id = variant.package_id.clone();
-            if let Some(ref mut api_mgmt) = state.api_level_management {
-                api_mgmt.installing_package = Some(package_id.clone());
-                api_mgmt.error_message = None;
-            }
-            package_id
+            api_mgmt.installing_package = Some(first.package_id.clone());
+            api_mgmt.error_message = None;
+            let package_ids: Vec<String> =
+                items.iter().map(|item| item.package_id.clone()).collect();
+            api_mgmt.install_queue = Some(state::InstallQueue::new(items));
+            (package_ids, api_mgmt.cancel_token.clone())
         };
 
         let android_manager = self.android_manager.clone();
         let state_clone = self.state.clone();
-        let state_clone_for_progress = state_clone.clone();
 
-        tokio::spawn(async move {
-            let result = android_manager
-                .install_system_image(&package_id, move |progress| {
+        tasks::spawn(async move {
+            let mut last_error = None;
+            for package_id in &package_ids {
+                {
+                    let mut state = state_clone.lock().await;
+                    let Some(ref mut api_mgmt) = state.api_level_management else {
+                        return;
+                    };
+                    api_mgmt.installing_package = Some(package_id.clone());
+                    api_mgmt.install_progress = None;
+                    if let Some(item) = api_mgmt
+                        .install_queue
+                        .as_mut()
+                        .and_then(|queue| queue.get_mut(package_id))
+                    {
+                        item.status = state::QueuedInstallStatus::Installing(0);
+                    }
+                }
+
+                let state_clone_for_progress = state_clone.clone();
+                let progress_package_id = package_id.clone();
+                let install = android_manager.install_system_image(package_id, move |progress| {
                     let state_clone = state_clone_for_progress.clone();
-                    tokio::spawn(async move {
+                    let package_id = progress_package_id.clone();
+                    tasks::spawn(async move {
                         let mut state = state_clone.lock().await;
-                        if let Some(ref mut api_mgmt) = state.api_level_management {
-                            let already_complete = api_mgmt
-                                .install_progress
-                                .as_ref()
-                                .map(|progress| progress.percentage >= PROGRESS_PHASE_100_PERCENT)
-                                .unwrap_or(false);
-                            if !already_complete {
-                                api_mgmt.install_progress = Some(progress);
-                            }
+                        let Some(ref mut api_mgmt) = state.api_level_management else {
+                            return;
+                        };
+                        // Updates can land after the image finished installing
+                        if api_mgmt.installing_package.as_deref() != Some(package_id.as_str()) {
+                            return;
+                        }
+                        let already_complete = api_mgmt
+                            .install_progress
+                            .as_ref()
+                            .map(|progress| progress.percentage >= PROGRESS_PHASE_100_PERCENT)
+                            .unwrap_or(false);
+                        if already_complete {
+                            return;
+                        }
+                        if let Some(item) = api_mgmt
+                            .install_queue
+                            .as_mut()
+                            .and_then(|queue| queue.get_mut(&package_id))
+                        {
+                            item.status =
+                                state::QueuedInstallStatus::Installing(progress.percentage);
                         }
+                        api_mgmt.install_progress = Some(progress);
                     });
-                })
-                .await;
+                });
+                let result = cancel_token
+                    .run_until_cancelled(install)
+                    .await
+                    .and_then(|result| result);
+                if cancel_token.is_cancelled() {
+                    return;
+                }
 
-            if result.is_ok() {
                 let mut state = state_clone.lock().await;
-                if let Some(ref mut api_mgmt) = state.api_level_management {
+                let Some(ref mut api_mgmt) = state.api_level_management else {
+                    return;
+                };
+                let status = match result {
+                    Ok(()) => {
+                        Self::update_api_level_installation_state(
+                            api_mgmt,
+                            std::slice::from_ref(package_id),
+                            true,
+                        );
+                        state::QueuedInstallStatus::Installed
+                    }
+                    Err(error) => {
+                        let status = state::QueuedInstallStatus::Failed(error.to_string());
+                        last_error = Some(error);
+                        status
+                    }
+                };
+                if let Some(item) = api_mgmt
+                    .install_queue
+                    .as_mut()
+                    .and_then(|queue| queue.get_mut(package_id))
+                {
+                    item.status = status;
+                }
+            }
+
+            let installed_count = {
+                let mut state = state_clone.lock().await;
+                let Some(ref mut api_mgmt) = state.api_level_management else {
+                    return;
+                };
+                let installed_count = api_mgmt
+                    .install_queue
+                    .as_ref()
+                    .map_or(0, |queue| queue.installed_count());
+                if installed_count > 0 {
                     api_mgmt.install_progress = Some(crate::models::InstallProgress {
                         operation: INSTALL_PROGRESS_COMPLETE.to_string(),
                         percentage: PROGRESS_PHASE_100_PERCENT,
                         eta_seconds: None,
+                        bytes_per_second: None,
                     });
                 }
-            }
+                installed_count
+            };
 
             tokio::time::sleep(API_INSTALLATION_COMPLETION_DELAY).await;
 
-            if let Err(error) = result {
-                let mut state = state_clone.lock().await;
-                if let Some(ref mut api_mgmt) = state.api_level_management {
-                    api_mgmt.installing_package = None;
-                    api_mgmt.install_progress = None;
-                    api_mgmt.error_message = Some(format!("Failed to install: {error}"));
-                }
-            } else {
-                let mut state = state_clone.lock().await;
-                if let Some(ref mut api_mgmt) = state.api_level_management {
-                    Self::update_api_level_installation_state(
-                        api_mgmt,
-                        std::slice::from_ref(&package_id),
-                        true,
-                    );
-                    api_mgmt.error_message = None;
-                    api_mgmt.is_loading = true;
+            let mut state = state_clone.lock().await;
+            let Some(ref mut api_mgmt) = state.api_level_management else {
+                return;
+            };
+            let queue = api_mgmt.install_queue.clone();
+            api_mgmt.error_message = match (&queue, last_error) {
+                (_, None) => None,
+                (Some(queue), Some(_)) if queue.items.len() > 1 => {
+                    let failed: Vec<&str> = queue
+                        .failed()
+                        .iter()
+                        .map(|item| item.display_name.as_str())
+                        .collect();
+                    Some(
+                        SYSTEM_IMAGE_QUEUE_FAILED
+                            .replacen("{}", &installed_count.to_string(), 1)
+                            .replacen("{}", &queue.items.len().to_string(), 1)
+                            .replacen("{}", &failed.join(", "), 1),
+                    )
                 }
+                (_, Some(error)) => Some(format!("Failed to install: {error}")),
+            };
 
+            if installed_count == 0 {
+                api_mgmt.installing_package = None;
+                api_mgmt.install_progress = None;
+                api_mgmt.install_queue = None;
+                return;
+            }
+            api_mgmt.is_loading = true;
+
+            if installed_count == 1 {
                 state.add_success_notification(SYSTEM_IMAGE_INSTALLED.to_string());
-                {
-                    let mut cache = state.device_cache.write().await;
-                    cache.invalidate_android_cache();
-                }
-                drop(state);
+            } else {
+                state.add_success_notification(
+                    SYSTEM_IMAGES_INSTALLED.replace("{}", &installed_count.to_string()),
+                );
+            }
+            {
+                let mut cache = state.device_cache.write().await;
+                cache.invalidate_android_cache();
+            }
+            drop(state);
 
-                let android_manager_refresh = android_manager.clone();
-                let state_refresh = state_clone.clone();
-                tokio::spawn(async move {
-                    let refresh_result = android_manager_refresh.list_api_levels_fresh().await;
-                    let mut state = state_refresh.lock().await;
-                    if let Some(ref mut api_mgmt) = state.api_level_management {
-                        api_mgmt.installing_package = None;
-                        api_mgmt.install_progress = None;
-                        api_mgmt.is_loading = false;
-                        match refresh_result {
-                            Ok(new_levels) => {
-                                api_mgmt.api_levels = new_levels;
-                                api_mgmt.error_message = None;
-                            }
-                            Err(error) => {
-                                log::warn!("Failed to refresh API levels after install: {error}");
-                            }
-                        }
+            let refresh_result = cancel_token
+                .run_until_cancelled(android_manager.list_api_levels_fresh())
+                .await
+                .and_then(|result| result);
+            if cancel_token.is_cancelled() {
+                return;
+            }
+            let mut state = state_clone.lock().await;
+            if let Ok(ref new_levels) = refresh_result {
+                state.device_cache.write().await.android_system_images = Some(new_levels.clone());
+            }
+            if let Some(ref mut api_mgmt) = state.api_level_management {
+                api_mgmt.installing_package = None;
+                api_mgmt.install_progress = None;
+                api_mgmt.install_queue = None;
+                api_mgmt.is_loading = false;
+                match refresh_result {
+                    Ok(new_levels) => api_mgmt.set_api_levels(new_levels),
+                    Err(error) => {
+                        log::warn!("Failed to refresh API levels after install: {error}");
                     }
-                });
+                }
             }
         });
     }
 
-    async fn uninstall_selected_api_level(&mut self) {
-        let installed_variants = {
+    /// Handles keys while an uninstall waits for a decision about the AVDs
+    /// using the image. Returns false when no decision is pending.
+    async fn handle_uninstall_confirmation_key(&mut self, key: KeyEvent) -> bool {
+        let confirmation = {
             let mut state = self.state.lock().await;
+            let Some(ref mut api_mgmt) = state.api_level_management else {
+                return false;
+            };
+            let Some(confirmation) = api_mgmt.uninstall_confirmation.clone() else {
+                return false;
+            };
+            if key.code == KeyCode::Esc {
+                api_mgmt.uninstall_confirmation = None;
+                return true;
+            }
+            confirmation
+        };
+
+        let action = match key.code {
+            KeyCode::Char('r') => match confirmation.retarget {
+                Some((package_id, _)) => DependentAvdAction::Retarget(package_id),
+                None => return true,
+            },
+            KeyCode::Char('x') => DependentAvdAction::Delete,
+            KeyCode::Char('u') => DependentAvdAction::Keep,
+            _ => return true,
+        };
+
+        self.start_system_image_uninstall(
+            confirmation.package_ids,
+            confirmation.dependent_avds,
+            action,
+        )
+        .await;
+        true
+    }
+
+    async fn uninstall_selected_api_level(&mut self) {
+        let (installed_variants, retarget) = {
+            let state = self.state.lock().await;
             let Some(ref api_state) = state.api_level_management else {
                 return;
             };
@@ -273,22 +531,107 @@ impl App {
                 return;
             }
 
+            let retarget = api_state.retarget_candidate(&installed_variants);
+            (installed_variants, retarget)
+        };
+
+        // Uninstalling an image in use leaves its AVDs unable to boot, so ask first
+        let dependent_avds = self
+            .android_manager
+            .avds_using_system_images(&installed_variants)
+            .await
+            .unwrap_or_else(|error| {
+                log::warn!("Failed to check which AVDs use {installed_variants:?}: {error}");
+                Vec::new()
+            });
+        if !dependent_avds.is_empty() {
+            let mut state = self.state.lock().await;
             if let Some(ref mut api_mgmt) = state.api_level_management {
-                api_mgmt.installing_package = Some(installed_variants[0].clone());
-                api_mgmt.error_message = None;
+                api_mgmt.uninstall_confirmation = Some(state::UninstallConfirmation {
+                    package_ids: installed_variants,
+                    dependent_avds,
+                    retarget,
+                });
             }
+            return;
+        }
 
-            installed_variants
+        self.start_system_image_uninstall(installed_variants, Vec::new(), DependentAvdAction::Keep)
+            .await;
+    }
+
+    async fn start_system_image_uninstall(
+        &mut self,
+        installed_variants: Vec<String>,
+        dependent_avds: Vec<String>,
+        action: DependentAvdAction,
+    ) {
+        let cancel_token = {
+            let mut state = self.state.lock().await;
+            let Some(ref mut api_mgmt) = state.api_level_management else {
+                return;
+            };
+            api_mgmt.uninstall_confirmation = None;
+            api_mgmt.installing_package = Some(installed_variants[0].clone());
+            api_mgmt.error_message = None;
+            api_mgmt.cancel_token.clone()
         };
 
         let android_manager = self.android_manager.clone();
         let state_clone = self.state.clone();
-        tokio::spawn(async move {
+        tasks::spawn(async move {
+            if action != DependentAvdAction::Keep && !dependent_avds.is_empty() {
+                let update = async {
+                    for avd_name in &dependent_avds {
+                        match &action {
+                            DependentAvdAction::Delete => {
+                                android_manager.delete_device(avd_name).await?
+                            }
+                            DependentAvdAction::Retarget(package_id) => {
+                                android_manager.retarget_avd(avd_name, package_id).await?
+                            }
+                            DependentAvdAction::Keep => {}
+                        }
+                    }
+                    anyhow::Ok(())
+                };
+                let result = cancel_token
+                    .run_until_cancelled(update)
+                    .await
+                    .and_then(|result| result);
+                if cancel_token.is_cancelled() {
+                    return;
+                }
+
+                let devices = android_manager.list_devices().await;
+                let mut state = state_clone.lock().await;
+                if let Ok(mut devices) = devices {
+                    sort_android_devices_for_display(&mut devices);
+                    state.android_devices = devices;
+                    state.device_list_updated(Panel::Android);
+                }
+                if let Err(error) = result {
+                    if let Some(ref mut api_mgmt) = state.api_level_management {
+                        api_mgmt.installing_package = None;
+                        api_mgmt.error_message =
+                            Some(format!("Failed to update devices using the image: {error}"));
+                    }
+                    return;
+                }
+            }
+
             let mut success = true;
             let mut last_error = None;
 
             for package_id in &installed_variants {
-                if let Err(error) = android_manager.uninstall_system_image(package_id).await {
+                let result = cancel_token
+                    .run_until_cancelled(android_manager.uninstall_system_image(package_id))
+                    .await
+                    .and_then(|result| result);
+                if cancel_token.is_cancelled() {
+                    return;
+                }
+                if let Err(error) = result {
                     success = false;
                     last_error = Some(error);
                 }
@@ -320,9 +663,19 @@ impl App {
 
             let android_manager_refresh = android_manager.clone();
             let state_refresh = state_clone.clone();
-            tokio::spawn(async move {
-                let refresh_result = android_manager_refresh.list_api_levels_fresh().await;
+            tasks::spawn(async move {
+                let refresh_result = cancel_token
+                    .run_until_cancelled(android_manager_refresh.list_api_levels_fresh())
+                    .await
+                    .and_then(|result| result);
+                if cancel_token.is_cancelled() {
+                    return;
+                }
                 let mut state = state_refresh.lock().await;
+                if let Ok(ref new_levels) = refresh_result {
+                    state.device_cache.write().await.android_system_images =
+                        Some(new_levels.clone());
+                }
                 if let Some(ref mut api_mgmt) = state.api_level_management {
                     api_mgmt.installing_package = None;
                     api_mgmt.install_progress = None;
@@ -0,0 +1,356 @@
+use super::{state::AppDataDialog, App, Mode, Panel};
+use crate::constants::{
+    messages::notifications::{
+        APP_COPIED, APP_COPIED_WITH_DATA, APP_COPY_FAILED, APP_COPY_NO_TARGET, APP_DATA_BACKED_UP,
+        APP_DATA_BACKUP_FAILED, APP_DATA_RESTORED, APP_DATA_RESTORE_FAILED, FOCUS_NOT_RUNNING,
+    },
+    ui_text::{
+        app_data::{BACKING_UP, COPYING, RESTORING},
+        device_activity::{BACKING_UP_APP_DATA, COPYING_APP, INSTALLING_APP, RESTORING_APP_DATA},
+    },
+};
+use crate::models::{app_data, error::format_user_error, DeviceId, DeviceOperation, Platform};
+use crate::utils::tasks;
+use anyhow::anyhow;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Opens the app data backups of the selected running device, which
+    /// also copies its apps to the other running devices of the platform.
+    /// Backups go to `[output] app_data_dir` of `.emu.toml`, or the working
+    /// directory.
+    pub(super) async fn open_app_data_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let backup_dir = state
+                .project_config
+                .as_ref()
+                .and_then(|config| config.output.app_data_dir.clone())
+                .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+            let targets = match state.active_panel {
+                Panel::Android => state
+                    .android_devices
+                    .iter()
+                    .filter(|avd| avd.is_running && avd.name != identifier)
+                    .map(|avd| (DeviceId::android(avd.name.clone()), avd.name.clone()))
+                    .collect(),
+                Panel::Ios => state
+                    .ios_devices
+                    .iter()
+                    .filter(|simulator| simulator.is_running && simulator.udid != identifier)
+                    .map(|simulator| {
+                        (
+                            DeviceId::ios(simulator.udid.clone()),
+                            simulator.name.clone(),
+                        )
+                    })
+                    .collect(),
+            };
+            let device = state.active_panel.device_id(identifier);
+            state.app_data_dialog = Some(AppDataDialog::new(device, name, targets, backup_dir));
+            state.mode = Mode::AppData;
+        }
+
+        self.load_app_data_apps();
+    }
+
+    pub(super) async fn handle_app_data_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.app_data_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        let restore = match key.code {
+            KeyCode::Esc => {
+                state.app_data_dialog = None;
+                state.mode = Mode::Normal;
+                return;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                dialog.move_app(false);
+                return;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                dialog.move_app(true);
+                return;
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                dialog.move_backup(false);
+                return;
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                dialog.move_backup(true);
+                return;
+            }
+            KeyCode::Char('t') => {
+                dialog.next_target();
+                return;
+            }
+            KeyCode::Char(c @ ('c' | 'C')) => {
+                drop(state);
+                self.copy_selected_app(c == 'C').await;
+                return;
+            }
+            KeyCode::Char('b') => false,
+            KeyCode::Char('r') => true,
+            _ => return,
+        };
+        // One backup, restore or copy at a time
+        if dialog.status.is_some() {
+            return;
+        }
+        let Some(app) = dialog.selected_app().map(str::to_string) else {
+            return;
+        };
+        let path = if restore {
+            match dialog.selected_backup() {
+                Some(path) => path.clone(),
+                None => return,
+            }
+        } else {
+            app_data::backup_path(
+                &dialog.backup_dir,
+                dialog.device.platform,
+                &app,
+                chrono::Local::now().naive_local(),
+            )
+        };
+        let status = if restore { RESTORING } else { BACKING_UP };
+        dialog.status = Some(status.replace("{}", &app));
+        let device = dialog.device.clone();
+        let device_name = dialog.device_name.clone();
+        let label = if restore {
+            RESTORING_APP_DATA
+        } else {
+            BACKING_UP_APP_DATA
+        };
+        let activity = state.device_activities.begin(device.clone(), label);
+        drop(state);
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match (device.platform, &ios_manager, restore) {
+                (Platform::Android, _, false) => {
+                    android_manager
+                        .backup_app_data(&device.identifier, &app, &path)
+                        .await
+                }
+                (Platform::Android, _, true) => {
+                    android_manager
+                        .restore_app_data(&device.identifier, &app, &path)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager), false) => {
+                    ios_manager
+                        .backup_app_data(&device.identifier, &app, &path)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager), true) => {
+                    ios_manager
+                        .restore_app_data(&device.identifier, &app, &path)
+                        .await
+                }
+                (Platform::Ios, None, _) => Err(anyhow!("iOS manager not available")),
+            };
+
+            let mut state = state.lock().await;
+            state.device_activities.end(activity);
+            if let Some(dialog) = state
+                .app_data_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device == device)
+            {
+                dialog.status = None;
+                if !restore {
+                    dialog.refresh_backups();
+                }
+            }
+            let path = path.display().to_string();
+            match (result, restore) {
+                (Ok(()), false) => state.add_success_notification(
+                    APP_DATA_BACKED_UP
+                        .replacen("{}", &app, 1)
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &path, 1),
+                ),
+                (Ok(()), true) => state.add_success_notification(
+                    APP_DATA_RESTORED
+                        .replacen("{}", &app, 1)
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &path, 1),
+                ),
+                (Err(error), restore) => {
+                    let message = if restore {
+                        APP_DATA_RESTORE_FAILED
+                    } else {
+                        APP_DATA_BACKUP_FAILED
+                    };
+                    state.add_error_notification(
+                        message
+                            .replacen("{}", &app, 1)
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    );
+                }
+            }
+        });
+    }
+
+    /// Installs the highlighted app, and its data when `with_data` is set, on
+    /// the target device in the background.
+    async fn copy_selected_app(&self, with_data: bool) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state
+            .app_data_dialog
+            .as_mut()
+            .filter(|dialog| dialog.status.is_none())
+        else {
+            return;
+        };
+        let Some(app) = dialog.selected_app().map(str::to_string) else {
+            return;
+        };
+        let Some((target, target_name)) = dialog.selected_target().cloned() else {
+            let message = APP_COPY_NO_TARGET.replace("{}", &dialog.device_name);
+            state.add_info_notification(message);
+            return;
+        };
+        dialog.status = Some(
+            COPYING
+                .replacen("{}", &app, 1)
+                .replacen("{}", &target_name, 1),
+        );
+        let source = dialog.device.clone();
+        let source_name = dialog.device_name.clone();
+        let activities = [
+            state.device_activities.begin(source.clone(), COPYING_APP),
+            state
+                .device_activities
+                .begin(target.clone(), INSTALLING_APP),
+        ];
+        drop(state);
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match (source.platform, &ios_manager) {
+                (Platform::Android, _) => {
+                    android_manager
+                        .copy_app(&source.identifier, &target.identifier, &app, with_data)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager)) => {
+                    ios_manager
+                        .copy_app(&source.identifier, &target.identifier, &app, with_data)
+                        .await
+                }
+                (Platform::Ios, None) => Err(anyhow!("iOS manager not available")),
+            };
+
+            let mut state = state.lock().await;
+            for activity in activities {
+                state.device_activities.end(activity);
+            }
+            state.record_device_operation(
+                Panel::from(target.platform),
+                &target.identifier,
+                DeviceOperation::Install,
+                result
+                    .as_ref()
+                    .map(|_| ())
+                    .map_err(|error| error.to_string()),
+            );
+            if let Some(dialog) = state
+                .app_data_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device == source)
+            {
+                dialog.status = None;
+            }
+            match result {
+                Ok(()) => {
+                    let message = if with_data {
+                        APP_COPIED_WITH_DATA
+                    } else {
+                        APP_COPIED
+                    };
+                    state.add_success_notification(
+                        message
+                            .replacen("{}", &app, 1)
+                            .replacen("{}", &source_name, 1)
+                            .replacen("{}", &target_name, 1),
+                    );
+                }
+                Err(error) => state.add_error_notification(
+                    APP_COPY_FAILED
+                        .replacen("{}", &app, 1)
+                        .replacen("{}", &target_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+    }
+
+    /// Lists the apps whose data can be backed up in the background.
+    fn load_app_data_apps(&self) {
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let device = {
+                let state = state.lock().await;
+                let Some(dialog) = &state.app_data_dialog else {
+                    return;
+                };
+                dialog.device.clone()
+            };
+            let apps = match (device.platform, &ios_manager) {
+                (Platform::Android, _) => {
+                    android_manager
+                        .list_installed_packages(&device.identifier)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager)) => {
+                    ios_manager.list_installed_apps(&device.identifier).await
+                }
+                (Platform::Ios, None) => return,
+            };
+            let apps = match apps {
+                Ok(apps) => apps,
+                Err(e) => {
+                    log::debug!("Failed to list apps of '{}': {e:#}", device.identifier);
+                    return;
+                }
+            };
+            let mut state = state.lock().await;
+            if let Some(dialog) = state
+                .app_data_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device == device)
+            {
+                dialog.set_apps(apps);
+            }
+        });
+    }
+}
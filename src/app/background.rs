@@ -1,16 +1,61 @@
-use super::{App, Panel};
-use crate::managers::common::DeviceManager;
+use super::{App, IosToolingStatus, Panel};
+use crate::constants::timeouts::TASK_SHUTDOWN_GRACE;
 use crate::managers::AndroidManager;
-use crate::models::{device_info::sort_android_devices_for_display, DeviceDetails, Platform};
+use crate::models::{
+    device_info::sort_android_devices_for_display, error::format_user_error, DeviceDetails,
+    Platform,
+};
+use crate::utils::{
+    startup_profile::{self, StartupPhase},
+    tasks, DeviceInventoryCache,
+};
 use std::sync::Arc;
 
 impl App {
+    /// Shows the device inventory saved by the previous session, if it is
+    /// still fresh, while background loading reconciles it with live data.
+    pub(super) async fn restore_device_inventory(&self) {
+        let Some(inventory) = DeviceInventoryCache::load_from_disk() else {
+            return;
+        };
+        let mut state = self.state.lock().await;
+        state.restore_device_inventory(inventory).await;
+    }
+
+    /// Persists the current device inventory for the next launch.
+    pub(super) async fn save_device_inventory(&self) {
+        let state = self.state.lock().await;
+        if state.is_loading {
+            return;
+        }
+        let mut inventory = state.device_inventory().await;
+        drop(state);
+
+        if inventory.is_empty() {
+            return;
+        }
+        if let Err(e) = inventory.save_to_disk() {
+            log::warn!("Failed to save device inventory cache: {e}");
+        }
+    }
+
+    /// Cancels the background tasks before emu quits, waiting a moment so
+    /// the processes they own, such as logcat, end with them.
+    pub(super) async fn shutdown_background_tasks(&self) {
+        let still_running = tasks::shutdown(TASK_SHUTDOWN_GRACE).await;
+        if still_running > 0 {
+            log::warn!(
+                "{still_running} background tasks were still running {TASK_SHUTDOWN_GRACE:?} after quitting"
+            );
+        }
+    }
+
     /// Start background device info cache loading
     pub(super) fn start_background_cache_loading(&mut self) {
         let state_clone = Arc::clone(&self.state);
         let android_manager = self.android_manager.clone();
 
-        tokio::spawn({
+        tasks::spawn({
             let state_clone = Arc::clone(&state_clone);
             let android_manager = android_manager.clone();
             async move {
@@ -28,7 +73,20 @@ impl App {
                     log::info!("Android device cache updated successfully");
                 }
 
-                let _ = android_manager.list_api_levels().await;
+                // Prefetch for the API level dialog so it opens without waiting
+                // for sdkmanager
+                match android_manager.list_api_levels().await {
+                    Ok(api_levels) => {
+                        let state = state_clone.lock().await;
+                        let mut cache = state.device_cache.write().await;
+                        cache.android_system_images = Some(api_levels);
+                    }
+                    Err(e) => log::debug!("Failed to prefetch API levels: {e}"),
+                }
+
+                if let Err(e) = android_manager.refresh_android_version_names().await {
+                    log::debug!("Failed to refresh Android version names: {e}");
+                }
             }
         });
 
@@ -37,7 +95,7 @@ impl App {
 
         #[cfg(target_os = "macos")]
         if let Some(ios_manager) = ios_manager {
-            tokio::spawn(async move {
+            tasks::spawn(async move {
                 let (device_types_result, runtimes_result) = tokio::join!(
                     ios_manager.list_device_types_with_names(),
                     ios_manager.list_runtimes()
@@ -59,17 +117,28 @@ impl App {
         let android_manager = self.android_manager.clone();
         let ios_manager = self.ios_manager.clone();
 
-        tokio::spawn({
+        tasks::spawn({
             let state_clone = Arc::clone(&state_clone);
             let android_manager = android_manager.clone();
             async move {
-                match android_manager.list_devices_parallel().await {
+                match android_manager.list_devices().await {
                     Ok(mut android_devices) => {
                         sort_android_devices_for_display(&mut android_devices);
+                        let adb_server_healthy = android_manager.adb_server_health().await;
                         let mut state = state_clone.lock().await;
+                        state.adb_server_healthy = adb_server_healthy;
                         state.android_devices = android_devices;
+                        state.device_list_updated(Panel::Android);
                         state.is_loading = false;
                         state.mark_refreshed();
+                        startup_profile::mark(StartupPhase::FirstDeviceList);
+
+                        tasks::spawn(Self::prefetch_device_details_internal(
+                            Arc::clone(&state_clone),
+                            android_manager.clone(),
+                            None,
+                            Panel::Android,
+                        ));
 
                         let should_update_details = state.active_panel == Panel::Android
                             && !state.android_devices.is_empty()
@@ -79,7 +148,7 @@ impl App {
                         if should_update_details {
                             let state_clone2 = Arc::clone(&state_clone);
                             let android_manager_clone = android_manager.clone();
-                            tokio::spawn(async move {
+                            tasks::spawn(async move {
                                 let state = state_clone2.lock().await;
                                 if let Some(device) =
                                     state.android_devices.get(state.selected_android)
@@ -111,7 +180,7 @@ impl App {
                         if should_start_logs {
                             let state_clone3 = Arc::clone(&state_clone);
                             let android_manager_clone2 = android_manager.clone();
-                            tokio::spawn(async move {
+                            tasks::spawn(async move {
                                 Self::update_log_stream_internal(
                                     state_clone3,
                                     android_manager_clone2,
@@ -131,15 +200,30 @@ impl App {
             }
         });
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             let Some(ios_manager) = ios_manager else {
                 return;
             };
 
+            if let Err(error) = ios_manager.probe().await {
+                state_clone.lock().await.ios_tooling =
+                    IosToolingStatus::Unavailable(format_user_error(&error));
+                return;
+            }
+            state_clone.lock().await.ios_tooling = IosToolingStatus::Ready;
+
             match ios_manager.list_devices().await {
                 Ok(ios_devices) => {
                     let mut state = state_clone.lock().await;
                     state.ios_devices = ios_devices;
+                    state.device_list_updated(Panel::Ios);
+
+                    tasks::spawn(Self::prefetch_device_details_internal(
+                        Arc::clone(&state_clone),
+                        android_manager.clone(),
+                        Some(ios_manager.clone()),
+                        Panel::Ios,
+                    ));
 
                     let should_update_details = state.active_panel == Panel::Ios
                         && !state.ios_devices.is_empty()
@@ -148,7 +232,7 @@ impl App {
 
                     if should_update_details {
                         let state_clone2 = Arc::clone(&state_clone);
-                        tokio::spawn(async move {
+                        tasks::spawn(async move {
                             let state = state_clone2.lock().await;
                             if let Some(device) = state.ios_devices.get(state.selected_ios) {
                                 let details = DeviceDetails {
@@ -188,7 +272,7 @@ impl App {
 
                     if should_start_logs {
                         let state_clone3 = Arc::clone(&state_clone);
-                        tokio::spawn(async move {
+                        tasks::spawn(async move {
                             Self::update_log_stream_internal(
                                 state_clone3,
                                 AndroidManager::new()
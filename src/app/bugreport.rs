@@ -0,0 +1,130 @@
+use super::{App, Panel};
+use crate::constants::{
+    messages::notifications::{
+        BUGREPORT_FAILED, BUGREPORT_SAVED, COLLECTING_BUGREPORT, COLLECTING_BUGREPORT_PROGRESS,
+        FOCUS_NOT_RUNNING,
+    },
+    ui_text::device_activity::COLLECTING_REPORT,
+};
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Arc;
+
+impl App {
+    /// Collects diagnostics of the selected device in the background: an
+    /// `adb bugreport` zip for a running AVD, with adb's progress in the
+    /// status line, or a `simctl diagnose` archive for a simulator. Files go
+    /// to `[output] bugreport_dir` of `.emu.toml`, or the working directory.
+    pub(super) async fn collect_selected_device_diagnostics(&mut self) {
+        let (panel, device_name, identifier, dir, scope, activity) = {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                // simctl diagnose also covers simulators that are shut down
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, true)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let output = state
+                .project_config
+                .as_ref()
+                .map(|config| config.output.clone())
+                .unwrap_or_default();
+            let dir = output
+                .bugreport_dir
+                .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+            state.set_device_operation_status(COLLECTING_BUGREPORT.replace("{}", &name));
+            let panel = state.active_panel;
+            let activity = state
+                .device_activities
+                .begin(panel.device_id(&identifier), COLLECTING_REPORT);
+            (
+                panel,
+                name,
+                identifier,
+                dir,
+                output.diagnose_scope,
+                activity,
+            )
+        };
+
+        let finished = Arc::new(AtomicBool::new(false));
+        let shown_percentage = Arc::new(AtomicU8::new(0));
+        let on_progress = {
+            let state = Arc::clone(&self.state);
+            let finished = Arc::clone(&finished);
+            let device_name = device_name.clone();
+            move |percentage: u8| {
+                // Only move forward; updates can land out of order
+                if shown_percentage.fetch_max(percentage, Ordering::SeqCst) >= percentage {
+                    return;
+                }
+                let state = Arc::clone(&state);
+                let finished = Arc::clone(&finished);
+                let status = COLLECTING_BUGREPORT_PROGRESS
+                    .replacen("{}", &device_name, 1)
+                    .replacen("{}", &percentage.to_string(), 1);
+                tasks::spawn(async move {
+                    let mut state = state.lock().await;
+                    if !finished.load(Ordering::SeqCst) {
+                        state.set_device_operation_status(status);
+                    }
+                });
+            }
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result: Result<PathBuf> = match panel {
+                Panel::Android => {
+                    android_manager
+                        .collect_bugreport(&identifier, &dir, &on_progress)
+                        .await
+                }
+                Panel::Ios => match ios_manager {
+                    Some(ios_manager) => {
+                        ios_manager
+                            .collect_diagnostics(&identifier, &dir, scope)
+                            .await
+                    }
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+            let mut state = state.lock().await;
+            finished.store(true, Ordering::SeqCst);
+            state.clear_device_operation_status();
+            state.device_activities.end(activity);
+            match result {
+                Ok(path) => state.add_success_notification(
+                    BUGREPORT_SAVED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &path.to_string_lossy(),
+                        1,
+                    ),
+                ),
+                Err(error) => state.add_error_notification(
+                    BUGREPORT_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use super::{state::DeviceClockDialog, App, Mode, Panel};
+use crate::constants::messages::notifications::{
+    CLOCK_AUTOMATIC, CLOCK_CHANGED, CLOCK_CHANGE_FAILED, FOCUS_NOT_RUNNING,
+    IOS_STATUS_BAR_TIME_CLEARED, IOS_STATUS_BAR_TIME_SET,
+};
+use crate::models::{error::format_user_error, ClockChange};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Asks for the date or offset to set the clock of the selected running
+    /// device to.
+    pub(super) async fn open_device_clock_dialog(&mut self) {
+        let mut state = self.state.lock().await;
+        let panel = state.active_panel;
+        let target = match panel {
+            Panel::Android => state
+                .selected_android_device()
+                .map(|device| (&device.name, &device.name, device.is_running)),
+            Panel::Ios => state
+                .selected_ios_device()
+                .map(|device| (&device.name, &device.udid, device.is_running)),
+        };
+        let Some((name, identifier, is_running)) = target else {
+            return;
+        };
+        let (device_name, identifier) = (name.clone(), identifier.clone());
+        if !is_running {
+            state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+            return;
+        }
+        state.device_clock_dialog = Some(DeviceClockDialog {
+            panel,
+            device_name,
+            identifier,
+            input: String::new(),
+            error: None,
+        });
+        state.mode = Mode::DeviceClock;
+    }
+
+    pub(super) async fn handle_device_clock_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.device_clock_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.device_clock_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => match ClockChange::parse(&dialog.input) {
+                Ok(change) => {
+                    let dialog = state.device_clock_dialog.take();
+                    state.mode = Mode::Normal;
+                    drop(state);
+                    if let Some(dialog) = dialog {
+                        self.change_device_clock(dialog, change);
+                    }
+                }
+                Err(error) => dialog.error = Some(format!("{error:#}")),
+            },
+            KeyCode::Backspace => {
+                dialog.input.pop();
+                dialog.error = None;
+            }
+            KeyCode::Char(c) => {
+                dialog.input.push(c);
+                dialog.error = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Changes the clock in the background. Android needs adb root and
+    /// restarts adbd for it, which takes a few seconds.
+    fn change_device_clock(&self, dialog: DeviceClockDialog, change: ClockChange) {
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let DeviceClockDialog {
+                panel,
+                device_name,
+                identifier,
+                ..
+            } = dialog;
+            let result = match (panel, ios_manager) {
+                (Panel::Android, _) => android_manager
+                    .change_clock(&identifier, &change)
+                    .await
+                    .map(|time| match change {
+                        ClockChange::Automatic => CLOCK_AUTOMATIC.replace("{}", &device_name),
+                        _ => CLOCK_CHANGED
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &time, 1),
+                    }),
+                (Panel::Ios, Some(ios_manager)) => ios_manager
+                    .override_status_bar_time(&identifier, &change)
+                    .await
+                    .map(|time| match time {
+                        Some(time) => IOS_STATUS_BAR_TIME_SET
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &time, 1),
+                        None => IOS_STATUS_BAR_TIME_CLEARED.replace("{}", &device_name),
+                    }),
+                (Panel::Ios, None) => Err(anyhow::anyhow!("iOS manager not available")),
+            };
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(message) => state.add_success_notification(message),
+                Err(error) => state.add_error_notification(
+                    CLOCK_CHANGE_FAILED
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+    }
+}
@@ -0,0 +1,44 @@
+use super::{state::CommandHistoryDialog, App, Mode};
+use crate::utils::audit;
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Opens the command history dialog with the commands executed so far.
+    pub(super) async fn open_command_history(&mut self) {
+        let dialog = CommandHistoryDialog {
+            entries: audit::recent_entries().await,
+            scroll_offset: 0,
+            log_path: audit::log_path().await,
+        };
+
+        let mut state = self.state.lock().await;
+        state.command_history_dialog = Some(dialog);
+        state.mode = Mode::CommandHistory;
+    }
+
+    pub(super) async fn handle_command_history_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('H') => {
+                let mut state = self.state.lock().await;
+                state.command_history_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut dialog) = state.command_history_dialog {
+                    dialog.scroll_up();
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut dialog) = state.command_history_dialog {
+                    dialog.scroll_down();
+                }
+            }
+            KeyCode::Char('r') => {
+                self.open_command_history().await;
+            }
+            _ => {}
+        }
+    }
+}
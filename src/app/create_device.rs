@@ -1,14 +1,23 @@
-use super::{state, App, Mode, Panel};
-use crate::constants::performance::DETAIL_UPDATE_DEBOUNCE;
-use crate::managers::common::{DeviceConfig, DeviceManager};
+use super::{state, App, AppState, Mode, Panel};
+use crate::constants::{
+    messages::notifications::{
+        DEVICE_CREATION_CANCELLED, DEVICE_CREATION_CANCELLING, DEVICE_CREATION_CLEANUP_FAILED,
+    },
+    performance::DETAIL_UPDATE_DEBOUNCE,
+};
+use crate::managers::common::DeviceConfig;
+use crate::managers::AndroidManager;
 use crate::models::device_info::sort_android_devices_for_display;
-use crate::models::error::format_user_error;
+use crate::models::{error::format_user_error, DeviceOperation, InstallProgress};
+use crate::utils::command_executor::CancellationToken;
+use crate::utils::tasks;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::sync::Arc;
+use tokio::sync::Mutex;
 
 impl App {
-    fn initialize_create_device_form(
+    pub(super) fn initialize_create_device_form(
         form: &mut state::CreateDeviceForm,
         device_types: Vec<(String, String)>,
         versions: Vec<(String, String)>,
@@ -42,10 +51,74 @@ impl App {
         form.selected_api_level_index = 0;
 
         form.generate_placeholder_name();
+        form.apply_device_profile_defaults();
+        form.apply_template();
         form.is_loading_cache = false;
     }
 
+    /// Resolves what the current Android form selection would create and stores
+    /// it on the form for the preview pane. Clears the preview when it cannot be resolved.
+    pub(super) async fn refresh_create_device_preview(
+        state: &Arc<Mutex<AppState>>,
+        android_manager: &AndroidManager,
+    ) {
+        let config = {
+            let state = state.lock().await;
+            let form = &state.create_device_form;
+            if state.active_panel != Panel::Android
+                || form.device_type_id.is_empty()
+                || form.version.is_empty()
+            {
+                None
+            } else {
+                Some(DeviceConfig::new(
+                    form.name.clone(),
+                    form.device_type_id.clone(),
+                    form.version.clone(),
+                ))
+            }
+        };
+
+        let preview = match &config {
+            Some(config) => android_manager.preview_device_creation(config).await.ok(),
+            None => None,
+        };
+
+        let mut state = state.lock().await;
+        let form = &mut state.create_device_form;
+        // A newer selection has its own refresh on the way
+        let still_selected = config.as_ref().is_none_or(|config| {
+            form.device_type_id == config.device_type && form.version == config.version
+        });
+        if still_selected {
+            form.preview = preview;
+        }
+    }
+
+    /// Refreshes the creation preview in the background, so resolving the
+    /// system image never holds up the key that changed the selection.
+    pub(super) fn spawn_create_device_preview_refresh(&self, android_manager: AndroidManager) {
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            Self::refresh_create_device_preview(&state, &android_manager).await;
+        });
+    }
+
+    /// Opens the creation form pre-filled from the selected device, so the
+    /// same hardware profile on another API level is one change away.
+    pub(super) async fn enter_duplicate_device_mode(&mut self) {
+        let template = self.state.lock().await.create_device_template();
+        if template.is_some() {
+            self.open_create_device_form(template).await;
+        }
+    }
+
     pub(super) async fn enter_create_device_mode(&mut self) {
+        self.open_create_device_form(None).await;
+    }
+
+    async fn open_create_device_form(&mut self, template: Option<state::CreateDeviceTemplate>) {
+        let android_sdks = self.android_sdk_names();
         let active_panel = {
             let mut state = self.state.lock().await;
             let active_panel = state.active_panel;
@@ -54,6 +127,16 @@ impl App {
                 Panel::Android => state::CreateDeviceForm::for_android(),
                 Panel::Ios => state::CreateDeviceForm::for_ios(),
             };
+            if active_panel == Panel::Android {
+                state.create_device_form.android_sdks = android_sdks;
+            }
+            state.create_device_form.template = template;
+            state.create_device_form.naming = state
+                .project_config
+                .as_ref()
+                .map(|config| config.naming.clone())
+                .unwrap_or_default();
+            state.create_device_form.taken_names = state.device_names(active_panel);
             state.create_device_form.is_loading_cache = true;
             active_panel
         };
@@ -79,6 +162,8 @@ impl App {
                     "No Android device definitions found. Check your Android SDK installation.",
                     "No Android targets found. Use Android Studio SDK Manager to install system images.",
                 );
+                drop(state);
+                self.spawn_create_device_preview_refresh(self.android_manager.clone());
                 return;
             }
         }
@@ -89,9 +174,12 @@ impl App {
         };
 
         if cache_available {
-            let mut state = self.state.lock().await;
-            state.populate_form_from_cache(active_panel).await;
-            state.create_device_form.is_loading_cache = false;
+            {
+                let mut state = self.state.lock().await;
+                state.populate_form_from_cache(active_panel).await;
+                state.create_device_form.is_loading_cache = false;
+            }
+            self.spawn_create_device_preview_refresh(self.android_manager.clone());
             return;
         }
 
@@ -99,7 +187,7 @@ impl App {
         let android_manager = self.android_manager.clone();
         let ios_manager = self.ios_manager.clone();
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             match active_panel {
                 Panel::Android => {
                     if let Ok((targets, devices)) = tokio::try_join!(
@@ -119,6 +207,8 @@ impl App {
                             "No Android device definitions found. Check your Android SDK installation.",
                             "No Android targets found. Use Android Studio SDK Manager to install system images.",
                         );
+                        drop(state);
+                        Self::refresh_create_device_preview(&state_clone, &android_manager).await;
                     }
                 }
                 Panel::Ios => {
@@ -208,12 +298,26 @@ impl App {
     }
 
     pub(super) async fn handle_create_mode_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        if key.code != KeyCode::Esc {
+            self.state.lock().await.create_device_form.confirm_cancel = false;
+        }
+
         match key.code {
             KeyCode::Esc => {
                 let mut state = self.state.lock().await;
-                if !state.create_device_form.is_creating {
+                let form = &mut state.create_device_form;
+                if !form.is_creating {
                     state.mode = Mode::Normal;
                     state.create_device_form.error_message = None;
+                } else if !form.confirm_cancel {
+                    form.confirm_cancel = true;
+                } else {
+                    // The creation task sees the cancellation, kills the
+                    // running command, and removes what it left behind
+                    form.confirm_cancel = false;
+                    form.cancel_token.cancel();
+                    form.creation_status = Some(DEVICE_CREATION_CANCELLING.to_string());
+                    form.creation_progress = None;
                 }
             }
             KeyCode::Tab | KeyCode::Down => {
@@ -258,76 +362,160 @@ impl App {
         Ok(())
     }
 
-    pub(super) async fn submit_create_device(&mut self) -> Result<()> {
-        let (active_panel, form_data, config) = {
-            let state = self.state.lock().await;
-            let form_data = state.create_device_form.clone();
+    /// Builds the creation config from the form, or returns `None` when the
+    /// form cannot be submitted yet (the reason is shown in the form).
+    pub(super) async fn create_device_request(&self) -> Option<(Panel, DeviceConfig)> {
+        let mut state = self.state.lock().await;
 
-            if form_data.name.trim().is_empty() {
-                drop(state);
-                let mut state = self.state.lock().await;
-                state.create_device_form.error_message =
-                    Some("Device name is required".to_string());
-                return Ok(());
-            }
+        // The name error is already shown under the field, so Enter simply does nothing.
+        if state.validate_create_device_name().is_err() {
+            return None;
+        }
 
-            if form_data.version.trim().is_empty() {
-                drop(state);
-                let mut state = self.state.lock().await;
-                state.create_device_form.error_message = Some("Version is required".to_string());
-                return Ok(());
-            }
+        let form = &state.create_device_form;
+        if form.version.trim().is_empty() {
+            state.create_device_form.error_message = Some("Version is required".to_string());
+            return None;
+        }
 
-            let device_name = form_data.name.clone();
-            let device_type = form_data.device_type_id.clone();
-            let mut config = DeviceConfig::new(device_name, device_type, form_data.version.clone());
+        let mut config = DeviceConfig::new(
+            form.name.clone(),
+            form.device_type_id.clone(),
+            form.version.clone(),
+        );
 
-            if matches!(state.active_panel, Panel::Android) {
-                if !form_data.ram_size.is_empty() {
-                    config = config.with_ram(form_data.ram_size.clone());
-                }
-                if !form_data.storage_size.is_empty() {
-                    config = config.with_storage(form_data.storage_size.clone());
-                }
+        if matches!(state.active_panel, Panel::Android) {
+            if !form.ram_size.is_empty() {
+                config = config.with_ram(form.ram_size.clone());
+            }
+            if !form.storage_size.is_empty() {
+                config = config.with_storage(form.storage_size.clone());
             }
+        }
 
-            (state.active_panel, form_data, config)
+        Some((state.active_panel, config))
+    }
+
+    pub(super) async fn submit_create_device(&mut self) -> Result<()> {
+        let Some((active_panel, config)) = self.create_device_request().await else {
+            return Ok(());
         };
 
-        {
+        let cancel_token = CancellationToken::new();
+        let (known_avd_names, known_ios_udids) = {
             let mut state = self.state.lock().await;
             state.create_device_form.is_creating = true;
             state.create_device_form.creation_status =
                 Some("Initializing device creation...".to_string());
+            state.create_device_form.creation_progress = None;
             state.create_device_form.error_message = None;
-        }
+            state.create_device_form.cancel_token = cancel_token.clone();
+            state.create_device_form.confirm_cancel = false;
+            (
+                state
+                    .android_devices
+                    .iter()
+                    .map(|device| device.name.clone())
+                    .collect::<Vec<_>>(),
+                state
+                    .ios_devices
+                    .iter()
+                    .map(|device| device.udid.clone())
+                    .collect::<Vec<_>>(),
+            )
+        };
 
         let state_clone = Arc::clone(&self.state);
-        let android_manager = self.android_manager.clone();
+        let android_manager = self.create_form_android_manager().await;
         let ios_manager = self.ios_manager.clone();
-        let device_name_for_display = form_data.name.clone();
+        let device_name_for_display = config.name.clone();
+
+        let state_for_progress = Arc::clone(&self.state);
+        let cancel_token_for_progress = cancel_token.clone();
+        let on_progress = move |progress: InstallProgress| {
+            let state = Arc::clone(&state_for_progress);
+            let cancel_token = cancel_token_for_progress.clone();
+            tasks::spawn(async move {
+                let mut state = state.lock().await;
+                let form = &mut state.create_device_form;
+                // Updates can land out of order or after the creation ended
+                if !form.is_creating
+                    || cancel_token.is_cancelled()
+                    || form.creation_progress >= Some(progress.percentage)
+                {
+                    return;
+                }
+                form.creation_status = Some(progress.operation);
+                form.creation_progress = Some(progress.percentage);
+            });
+        };
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             {
                 let mut state = state_clone.lock().await;
                 state.create_device_form.creation_status =
                     Some(format!("Creating device '{device_name_for_display}'..."));
             }
 
-            let result = match active_panel {
-                Panel::Android => {
-                    tokio::time::sleep(DETAIL_UPDATE_DEBOUNCE).await;
-                    android_manager.create_device(&config).await
-                }
-                Panel::Ios => {
-                    if let Some(ref ios_manager) = ios_manager {
+            let creation = async {
+                match active_panel {
+                    Panel::Android => {
                         tokio::time::sleep(DETAIL_UPDATE_DEBOUNCE).await;
-                        ios_manager.create_device(&config).await
-                    } else {
-                        Err(anyhow::anyhow!("iOS manager not available"))
+                        android_manager
+                            .create_device_with_progress(&config, &on_progress)
+                            .await
+                    }
+                    Panel::Ios => {
+                        if let Some(ref ios_manager) = ios_manager {
+                            tokio::time::sleep(DETAIL_UPDATE_DEBOUNCE).await;
+                            ios_manager
+                                .create_device_with_progress(&config, &on_progress)
+                                .await
+                        } else {
+                            Err(anyhow::anyhow!("iOS manager not available"))
+                        }
                     }
                 }
             };
+            let result = cancel_token
+                .run_until_cancelled(creation)
+                .await
+                .and_then(|result| result);
+
+            if cancel_token.is_cancelled() {
+                let cleanup = match active_panel {
+                    // Never touch an AVD that existed before this creation
+                    Panel::Android
+                        if known_avd_names
+                            .contains(&AndroidManager::sanitize_avd_name(&config.name)) =>
+                    {
+                        Ok(())
+                    }
+                    Panel::Android => android_manager.discard_partial_avd(&config.name).await,
+                    Panel::Ios => match ios_manager {
+                        Some(ref ios_manager) => {
+                            ios_manager
+                                .discard_cancelled_device(&config.name, &known_ios_udids)
+                                .await
+                        }
+                        None => Ok(()),
+                    },
+                };
+
+                let mut state = state_clone.lock().await;
+                state.create_device_form.is_creating = false;
+                state.create_device_form.creation_status = None;
+                state.create_device_form.confirm_cancel = false;
+                match cleanup {
+                    Ok(()) => state.add_warning_notification(DEVICE_CREATION_CANCELLED.to_string()),
+                    Err(error) => state.add_error_notification(
+                        DEVICE_CREATION_CLEANUP_FAILED
+                            .replacen("{}", &device_name_for_display, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    ),
+                }
+                return;
+            }
 
             match result {
                 Ok(()) => {
@@ -342,7 +530,19 @@ impl App {
                             if let Ok(mut devices) = android_manager.list_devices().await {
                                 sort_android_devices_for_display(&mut devices);
                                 let mut state = state_clone.lock().await;
+                                for device in &devices {
+                                    if !state.android_devices.iter().any(|d| d.name == device.name)
+                                    {
+                                        state.record_device_operation(
+                                            Panel::Android,
+                                            &device.name,
+                                            DeviceOperation::Create,
+                                            Ok(()),
+                                        );
+                                    }
+                                }
                                 state.android_devices = devices;
+                                state.device_list_updated(Panel::Android);
                                 state.mode = Mode::Normal;
                                 state.create_device_form.is_creating = false;
                                 state.create_device_form.creation_status = None;
@@ -363,7 +563,19 @@ impl App {
                             if let Some(ref ios_manager) = ios_manager {
                                 if let Ok(devices) = ios_manager.list_devices().await {
                                     let mut state = state_clone.lock().await;
+                                    for device in &devices {
+                                        if !state.ios_devices.iter().any(|d| d.udid == device.udid)
+                                        {
+                                            state.record_device_operation(
+                                                Panel::Ios,
+                                                &device.udid,
+                                                DeviceOperation::Create,
+                                                Ok(()),
+                                            );
+                                        }
+                                    }
                                     state.ios_devices = devices;
+                                    state.device_list_updated(Panel::Ios);
                                     state.mode = Mode::Normal;
                                     state.create_device_form.is_creating = false;
                                     state.create_device_form.creation_status = None;
@@ -1,8 +1,72 @@
 use super::{state, App, AppState};
+use crate::constants::defaults::DEFAULT_ANDROID_SDK_NAME;
+use crate::managers::AndroidManager;
 use crate::models::error::format_user_error;
 use anyhow::Result;
 
 impl App {
+    /// Names the create form offers for the Android SDK: the default SDK
+    /// first, then the ones from `.emu.toml`. Empty when there is no choice.
+    pub(super) fn android_sdk_names(&self) -> Vec<String> {
+        if self.android_sdks.is_empty() {
+            return Vec::new();
+        }
+        std::iter::once(DEFAULT_ANDROID_SDK_NAME.to_string())
+            .chain(self.android_sdks.iter().map(|(name, _)| name.clone()))
+            .collect()
+    }
+
+    /// The Android manager for the SDK picked in the create form.
+    pub(super) async fn create_form_android_manager(&self) -> AndroidManager {
+        let index = self
+            .state
+            .lock()
+            .await
+            .create_device_form
+            .selected_sdk_index;
+        index
+            .checked_sub(1)
+            .and_then(|index| self.android_sdks.get(index))
+            .map(|(_, manager)| manager.clone())
+            .unwrap_or_else(|| self.android_manager.clone())
+    }
+
+    /// Loads the device types and targets of the SDK picked in the create
+    /// form, since each SDK has its own system images.
+    async fn reload_create_form_for_sdk(&mut self) -> Result<()> {
+        let android_manager = self.create_form_android_manager().await;
+        {
+            let mut state = self.state.lock().await;
+            let form = &mut state.create_device_form;
+            form.is_loading_cache = true;
+            form.selected_category_index = 0;
+            form.update_selected_category();
+        }
+
+        let loaded = tokio::try_join!(
+            android_manager.list_devices_by_category(None),
+            android_manager.list_available_targets()
+        );
+
+        let mut state = self.state.lock().await;
+        match loaded {
+            Ok((devices, targets)) => {
+                Self::initialize_create_device_form(
+                    &mut state.create_device_form,
+                    devices,
+                    targets,
+                    "No Android device definitions found. Check your Android SDK installation.",
+                    "No Android targets found. Use Android Studio SDK Manager to install system images.",
+                );
+                Ok(())
+            }
+            Err(error) => {
+                state.create_device_form.is_loading_cache = false;
+                Err(error)
+            }
+        }
+    }
+
     pub(super) async fn navigate_create_form(&mut self, forward: bool) {
         let mut state = self.state.lock().await;
         if state.create_device_form.is_creating {
@@ -21,15 +85,15 @@ impl App {
         &mut self,
         move_right: bool,
     ) -> anyhow::Result<()> {
-        let should_reload = {
+        let (should_reload, should_reload_sdk, should_refresh_preview) = {
             let mut state = self.state.lock().await;
             if state.create_device_form.is_creating {
                 return Ok(());
             }
 
-            let reload_category =
-                state.create_device_form.active_field == state::CreateDeviceField::Category;
+            let active_field = state.create_device_form.active_field;
             let old_category = state.create_device_form.device_category_filter.clone();
+            let old_sdk = state.create_device_form.selected_sdk_index;
 
             if move_right {
                 self.handle_create_device_right(&mut state);
@@ -37,16 +101,37 @@ impl App {
                 self.handle_create_device_left(&mut state);
             }
 
-            reload_category && old_category != state.create_device_form.device_category_filter
+            let reload_category = active_field == state::CreateDeviceField::Category
+                && old_category != state.create_device_form.device_category_filter;
+            let reload_sdk = active_field == state::CreateDeviceField::Sdk
+                && old_sdk != state.create_device_form.selected_sdk_index;
+            let refresh_preview = matches!(
+                active_field,
+                state::CreateDeviceField::Sdk
+                    | state::CreateDeviceField::ApiLevel
+                    | state::CreateDeviceField::Category
+                    | state::CreateDeviceField::DeviceType
+            );
+            (reload_category, reload_sdk, refresh_preview)
         };
 
-        if !should_reload {
-            return Ok(());
+        if should_reload_sdk {
+            if let Err(error) = self.reload_create_form_for_sdk().await {
+                let mut state = self.state.lock().await;
+                state.create_device_form.error_message = Some(format_user_error(&error));
+            }
         }
 
-        if let Err(error) = self.reload_device_types_for_category().await {
-            let mut state = self.state.lock().await;
-            state.create_device_form.error_message = Some(format_user_error(&error));
+        if should_reload {
+            if let Err(error) = self.reload_device_types_for_category().await {
+                let mut state = self.state.lock().await;
+                state.create_device_form.error_message = Some(format_user_error(&error));
+            }
+        }
+
+        if should_refresh_preview {
+            let android_manager = self.create_form_android_manager().await;
+            self.spawn_create_device_preview_refresh(android_manager);
         }
 
         Ok(())
@@ -67,6 +152,8 @@ impl App {
             'l' => self.change_create_device_selection(true).await?,
             'j' => self.navigate_create_form(true).await,
             'k' => self.navigate_create_form(false).await,
+            'y' => self.copy_create_device_command().await,
+            'a' => self.queue_create_device().await,
             _ => {
                 let mut state = self.state.lock().await;
                 self.handle_create_device_char(&mut state, c);
@@ -83,19 +170,12 @@ impl App {
             CreateDeviceField::Name => {
                 state.create_device_form.name.push(c);
             }
+            CreateDeviceField::Sdk => {}
             CreateDeviceField::Category => {}
             CreateDeviceField::DeviceType => {}
             CreateDeviceField::ApiLevel => {}
-            CreateDeviceField::RamSize => {
-                if c.is_ascii_digit() {
-                    state.create_device_form.ram_size.push(c);
-                }
-            }
-            CreateDeviceField::StorageSize => {
-                if c.is_ascii_digit() {
-                    state.create_device_form.storage_size.push(c);
-                }
-            }
+            CreateDeviceField::RamSize => {}
+            CreateDeviceField::StorageSize => {}
         }
         state.create_device_form.error_message = None;
     }
@@ -107,15 +187,12 @@ impl App {
             CreateDeviceField::Name => {
                 state.create_device_form.name.pop();
             }
+            CreateDeviceField::Sdk => {}
             CreateDeviceField::Category => {}
             CreateDeviceField::DeviceType => {}
             CreateDeviceField::ApiLevel => {}
-            CreateDeviceField::RamSize => {
-                state.create_device_form.ram_size.pop();
-            }
-            CreateDeviceField::StorageSize => {
-                state.create_device_form.storage_size.pop();
-            }
+            CreateDeviceField::RamSize => {}
+            CreateDeviceField::StorageSize => {}
         }
         state.create_device_form.error_message = None;
     }
@@ -124,6 +201,9 @@ impl App {
         use crate::app::state::CreateDeviceField;
 
         match state.create_device_form.active_field {
+            CreateDeviceField::Sdk => {
+                state.create_device_form.cycle_sdk(false);
+            }
             CreateDeviceField::Category => {
                 if state.create_device_form.selected_category_index > 0 {
                     state.create_device_form.selected_category_index -= 1;
@@ -144,13 +224,12 @@ impl App {
                     } else {
                         current_index - 1
                     };
-                    let (id, display) = options[new_index].clone();
-                    state.create_device_form.device_type_id = id;
-                    state.create_device_form.device_type = display;
                     state.create_device_form.selected_device_type_index = new_index;
-                    state.create_device_form.generate_placeholder_name();
+                    state.create_device_form.update_selected_device_type();
                 }
             }
+            CreateDeviceField::RamSize => state.create_device_form.cycle_ram_size(false),
+            CreateDeviceField::StorageSize => state.create_device_form.cycle_storage_size(false),
             CreateDeviceField::ApiLevel => {
                 let options = &state.create_device_form.available_versions;
                 if !options.is_empty() {
@@ -180,6 +259,9 @@ impl App {
         use crate::app::state::CreateDeviceField;
 
         match state.create_device_form.active_field {
+            CreateDeviceField::Sdk => {
+                state.create_device_form.cycle_sdk(true);
+            }
             CreateDeviceField::Category => {
                 let len = state.create_device_form.available_categories.len();
                 state.create_device_form.selected_category_index =
@@ -193,13 +275,12 @@ impl App {
                     .position(|(id, _)| id == &state.create_device_form.device_type_id)
                 {
                     let new_index = (current_index + 1) % options.len();
-                    let (id, display) = options[new_index].clone();
-                    state.create_device_form.device_type_id = id;
-                    state.create_device_form.device_type = display;
                     state.create_device_form.selected_device_type_index = new_index;
-                    state.create_device_form.generate_placeholder_name();
+                    state.create_device_form.update_selected_device_type();
                 }
             }
+            CreateDeviceField::RamSize => state.create_device_form.cycle_ram_size(true),
+            CreateDeviceField::StorageSize => state.create_device_form.cycle_storage_size(true),
             CreateDeviceField::ApiLevel => {
                 let options = &state.create_device_form.available_versions;
                 if !options.is_empty() {
@@ -222,69 +303,55 @@ impl App {
     }
 
     pub(super) async fn reload_device_types_for_category(&mut self) -> Result<()> {
-        let (current_panel, category_filter, device_cache_clone) = {
+        let (current_panel, category_filter, device_cache_clone, uses_default_sdk) = {
             let state = self.state.lock().await;
             let device_cache_clone = std::sync::Arc::clone(&state.device_cache);
             (
                 state.active_panel,
                 state.create_device_form.device_category_filter.clone(),
                 device_cache_clone,
+                state.create_device_form.selected_sdk_index == 0,
             )
         };
 
         match current_panel {
             super::Panel::Android => {
-                let cache = device_cache_clone.read().await;
-                let cached_devices = cache.android_device_cache.clone();
-                drop(cache);
+                // The shared cache holds the default SDK's device types only
+                let android_manager = self.create_form_android_manager().await;
+                let cached_devices = if uses_default_sdk {
+                    device_cache_clone.read().await.android_device_cache.clone()
+                } else {
+                    None
+                };
 
-                if let Some(all_devices) = cached_devices {
-                    let filtered_devices = if category_filter == "all" {
+                let filtered_devices = if let Some(all_devices) = cached_devices {
+                    if category_filter == "all" {
                         all_devices
                     } else {
                         all_devices
                             .into_iter()
                             .filter(|(id, display)| {
                                 let device_category =
-                                    self.android_manager.get_device_category(id, display);
+                                    android_manager.get_device_category(id, display);
                                 device_category == category_filter
                             })
                             .collect()
-                    };
-
-                    let mut state = self.state.lock().await;
-                    state.create_device_form.available_device_types = filtered_devices;
-
-                    state.create_device_form.selected_device_type_index = 0;
-                    if !state.create_device_form.available_device_types.is_empty() {
-                        let (id, display) =
-                            state.create_device_form.available_device_types[0].clone();
-                        state.create_device_form.device_type_id = id;
-                        state.create_device_form.device_type = display;
-                        state.create_device_form.generate_placeholder_name();
                     }
                 } else {
-                    let filtered_devices = self
-                        .android_manager
+                    android_manager
                         .list_devices_by_category(if category_filter == "all" {
                             None
                         } else {
                             Some(&category_filter)
                         })
-                        .await?;
+                        .await?
+                };
 
-                    let mut state = self.state.lock().await;
-                    state.create_device_form.available_device_types = filtered_devices;
+                let mut state = self.state.lock().await;
+                state.create_device_form.available_device_types = filtered_devices;
 
-                    state.create_device_form.selected_device_type_index = 0;
-                    if !state.create_device_form.available_device_types.is_empty() {
-                        let (id, display) =
-                            state.create_device_form.available_device_types[0].clone();
-                        state.create_device_form.device_type_id = id;
-                        state.create_device_form.device_type = display;
-                        state.create_device_form.generate_placeholder_name();
-                    }
-                }
+                state.create_device_form.selected_device_type_index = 0;
+                state.create_device_form.update_selected_device_type();
             }
             super::Panel::Ios => {}
         }
@@ -0,0 +1,220 @@
+use super::{state::QueuedCreationStatus, App, AppState, Mode, Panel};
+use crate::constants::messages::notifications::{
+    QUEUED_CREATION_ADDED, QUEUED_CREATION_DUPLICATE, QUEUED_CREATION_FAILED,
+    QUEUED_CREATION_SUCCEEDED,
+};
+use crate::managers::{common::DeviceConfig, AndroidManager, IosManager};
+use crate::models::device_info::sort_android_devices_for_display;
+use crate::models::{error::format_user_error, DeviceOperation, InstallProgress};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::Arc;
+use tokio::sync::{Mutex, Semaphore};
+
+impl App {
+    /// Limits how many queued creations run at the same time.
+    pub async fn set_max_parallel_creates(&self, max_parallel: usize) {
+        self.state
+            .lock()
+            .await
+            .set_max_parallel_creates(max_parallel);
+    }
+
+    pub(super) async fn open_creation_queue(&mut self) {
+        let mut state = self.state.lock().await;
+        state.creation_queue.scroll_offset = 0;
+        state.mode = Mode::CreationQueue;
+    }
+
+    pub(super) async fn handle_creation_queue_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        match key.code {
+            KeyCode::Esc => state.mode = Mode::Normal,
+            KeyCode::Char('Q') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => state.creation_queue.scroll_up(),
+            KeyCode::Down | KeyCode::Char('j') => state.creation_queue.scroll_down(),
+            KeyCode::Char('c') => state.creation_queue.clear_finished(),
+            _ => {}
+        }
+    }
+
+    /// Queues the device described by the create form instead of creating it
+    /// right away, so several devices can be set up back to back.
+    pub(super) async fn queue_create_device(&mut self) {
+        let Some((panel, config)) = self.create_device_request().await else {
+            return;
+        };
+
+        let (id, slots) = {
+            let mut state = self.state.lock().await;
+            if state
+                .creation_queue
+                .contains_unfinished(panel, &config.name)
+            {
+                state.create_device_form.error_message =
+                    Some(QUEUED_CREATION_DUPLICATE.replace("{}", &config.name));
+                return;
+            }
+
+            let id = state.creation_queue.enqueue(panel, config.clone());
+            state.add_info_notification(QUEUED_CREATION_ADDED.replace("{}", &config.name));
+            (id, state.creation_queue.slots())
+        };
+
+        let android_manager = self.create_form_android_manager().await;
+        self.run_queued_creation(id, panel, config, slots, android_manager);
+    }
+
+    /// Creates the queued item `id` once one of the queue's `slots` is free.
+    pub(super) fn run_queued_creation(
+        &self,
+        id: u64,
+        panel: Panel,
+        config: DeviceConfig,
+        slots: Arc<Semaphore>,
+        android_manager: AndroidManager,
+    ) {
+        let state = Arc::clone(&self.state);
+        let ios_manager = self.ios_manager.clone();
+
+        tasks::spawn(async move {
+            // Waiting here is what keeps the item pending; the semaphore is
+            // never closed, so acquiring only fails if the queue is dropped
+            let Ok(_slot) = slots.acquire_owned().await else {
+                return;
+            };
+
+            if let Some(item) = state.lock().await.creation_queue.get_mut(id) {
+                item.status = QueuedCreationStatus::Active {
+                    stage: None,
+                    progress: None,
+                };
+            }
+
+            let state_for_progress = Arc::clone(&state);
+            let on_progress = move |progress: InstallProgress| {
+                let state = Arc::clone(&state_for_progress);
+                tasks::spawn(async move {
+                    let mut state = state.lock().await;
+                    let Some(item) = state.creation_queue.get_mut(id) else {
+                        return;
+                    };
+                    // Updates can land out of order or after the creation ended
+                    if let QueuedCreationStatus::Active {
+                        stage,
+                        progress: current,
+                    } = &mut item.status
+                    {
+                        if *current < Some(progress.percentage) {
+                            *stage = Some(progress.operation);
+                            *current = Some(progress.percentage);
+                        }
+                    }
+                });
+            };
+
+            let result = match panel {
+                Panel::Android => {
+                    android_manager
+                        .create_device_with_progress(&config, &on_progress)
+                        .await
+                }
+                Panel::Ios => match ios_manager {
+                    Some(ref ios_manager) => {
+                        ios_manager
+                            .create_device_with_progress(&config, &on_progress)
+                            .await
+                    }
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+
+            match result {
+                Ok(()) => {
+                    refresh_after_creation(&state, panel, &android_manager, ios_manager.as_ref())
+                        .await;
+                    let mut state = state.lock().await;
+                    let group = state
+                        .creation_queue
+                        .get(id)
+                        .and_then(|item| item.group.clone());
+                    if let Some(group) = group {
+                        state.add_to_matrix_group(panel, &config.name, &group);
+                    }
+                    if let Some(item) = state.creation_queue.get_mut(id) {
+                        item.status = QueuedCreationStatus::Completed;
+                    }
+                    state.add_success_notification(
+                        QUEUED_CREATION_SUCCEEDED.replace("{}", &config.name),
+                    );
+                }
+                Err(error) => {
+                    let message = format_user_error(&error);
+                    let mut state = state.lock().await;
+                    if let Some(item) = state.creation_queue.get_mut(id) {
+                        item.status = QueuedCreationStatus::Failed(message.clone());
+                    }
+                    state.add_error_notification(
+                        QUEUED_CREATION_FAILED
+                            .replacen("{}", &config.name, 1)
+                            .replacen("{}", &message, 1),
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// Reloads the device list of `panel` so the new device shows up, recording
+/// the creation in the device history like a direct creation does.
+pub(super) async fn refresh_after_creation(
+    state: &Arc<Mutex<AppState>>,
+    panel: Panel,
+    android_manager: &AndroidManager,
+    ios_manager: Option<&IosManager>,
+) {
+    match panel {
+        Panel::Android => {
+            let Ok(mut devices) = android_manager.list_devices().await else {
+                return;
+            };
+            sort_android_devices_for_display(&mut devices);
+            let mut state = state.lock().await;
+            for device in &devices {
+                if !state.android_devices.iter().any(|d| d.name == device.name) {
+                    state.record_device_operation(
+                        Panel::Android,
+                        &device.name,
+                        DeviceOperation::Create,
+                        Ok(()),
+                    );
+                }
+            }
+            state.android_devices = devices;
+            state.device_list_updated(Panel::Android);
+        }
+        Panel::Ios => {
+            let Some(ios_manager) = ios_manager else {
+                return;
+            };
+            let Ok(devices) = ios_manager.list_devices().await else {
+                return;
+            };
+            let mut state = state.lock().await;
+            for device in &devices {
+                if !state.ios_devices.iter().any(|d| d.udid == device.udid) {
+                    state.record_device_operation(
+                        Panel::Ios,
+                        &device.udid,
+                        DeviceOperation::Create,
+                        Ok(()),
+                    );
+                }
+            }
+            state.ios_devices = devices;
+            state.device_list_updated(Panel::Ios);
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use super::{App, AppState, Panel};
 use crate::constants::{
-    performance::{FAST_DETAIL_UPDATE_DEBOUNCE, FAST_LOG_UPDATE_DEBOUNCE},
+    performance::{
+        DEVICE_DETAILS_PREFETCH_CONCURRENCY, DEVICE_DETAILS_PREFETCH_DELAY,
+        FAST_DETAIL_UPDATE_DEBOUNCE, FAST_LOG_UPDATE_DEBOUNCE,
+    },
     timeouts::DEVICE_STOP_WAIT_TIME,
 };
-use crate::managers::common::DeviceManager;
 use crate::managers::{AndroidManager, IosManager};
+use crate::utils::tasks;
+use futures::stream::{self, StreamExt};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
@@ -55,6 +59,64 @@ impl App {
         }
     }
 
+    /// Reads the details of every device on `panel` shortly after its list
+    /// loads, a few devices at a time, so moving the selection shows them
+    /// without waiting for the debounced fetch.
+    pub(super) async fn prefetch_device_details_internal(
+        state: Arc<Mutex<AppState>>,
+        android_manager: AndroidManager,
+        ios_manager: Option<IosManager>,
+        panel: Panel,
+    ) {
+        tokio::time::sleep(DEVICE_DETAILS_PREFETCH_DELAY).await;
+
+        let devices = {
+            let state = state.lock().await;
+            state
+                .devices_without_details(panel)
+                .into_iter()
+                .map(|identifier| {
+                    let cached_info = match panel {
+                        Panel::Android => state.get_cached_android_device(&identifier),
+                        Panel::Ios => None,
+                    };
+                    (identifier, cached_info)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut fetches = stream::iter(devices)
+            .map(|(identifier, cached_info)| {
+                let android_manager = &android_manager;
+                let ios_manager = ios_manager.as_ref();
+                async move {
+                    let result = match (panel, ios_manager) {
+                        (Panel::Android, _) => {
+                            android_manager
+                                .get_device_details(&identifier, cached_info)
+                                .await
+                        }
+                        (Panel::Ios, Some(ios_manager)) => {
+                            ios_manager.get_device_details(&identifier).await
+                        }
+                        (Panel::Ios, None) => return None,
+                    };
+                    result
+                        .inspect_err(|e| {
+                            log::debug!("Failed to prefetch details for '{identifier}': {e}")
+                        })
+                        .ok()
+                }
+            })
+            .buffer_unordered(DEVICE_DETAILS_PREFETCH_CONCURRENCY);
+
+        while let Some(details) = fetches.next().await {
+            if let Some(details) = details {
+                state.lock().await.store_prefetched_device_details(details);
+            }
+        }
+    }
+
     /// Schedule device details update with delay to avoid performance issues
     #[allow(dead_code)]
     pub(super) async fn schedule_device_details_update(&mut self) {
@@ -67,7 +129,7 @@ impl App {
         let ios_manager = self.ios_manager.clone();
         let delay = FAST_DETAIL_UPDATE_DEBOUNCE;
 
-        let update_handle = tokio::spawn(async move {
+        let update_handle = tasks::spawn(async move {
             tokio::time::sleep(delay).await;
             Self::update_device_details_internal(state_clone, android_manager, ios_manager).await;
         });
@@ -95,13 +157,13 @@ impl App {
         let android_manager_log = android_manager.clone();
         let ios_manager_log = ios_manager.clone();
 
-        let log_handle = tokio::spawn(async move {
+        let log_handle = tasks::spawn(async move {
             tokio::time::sleep(log_delay).await;
             Self::update_log_stream_internal(state_clone_log, android_manager_log, ios_manager_log)
                 .await;
         });
 
-        let detail_handle = tokio::spawn(async move {
+        let detail_handle = tasks::spawn(async move {
             tokio::time::sleep(detail_delay).await;
             Self::update_device_details_internal(state_clone, android_manager, ios_manager).await;
         });
@@ -137,7 +199,7 @@ impl App {
         let android_manager = self.android_manager.clone();
         let ios_manager = self.ios_manager.clone();
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             tokio::time::sleep(DEVICE_STOP_WAIT_TIME).await;
 
             let (active_panel, device_identifier) = {
@@ -165,6 +227,7 @@ impl App {
                                     &identifier,
                                     device.is_running,
                                 );
+                                state.reconcile_device_states(Panel::Android);
                             }
                         }
                     }
@@ -178,6 +241,7 @@ impl App {
                                         &identifier,
                                         device.is_running,
                                     );
+                                    state.reconcile_device_states(Panel::Ios);
                                 }
                             }
                         }
@@ -247,7 +311,7 @@ impl App {
                     }
                 },
                 Panel::Ios => {
-                    if let Some(ios_manager) = ios_manager {
+                    if let Some(ios_manager) = ios_manager.as_ref() {
                         match ios_manager.get_device_details(&identifier).await {
                             Ok(details) => {
                                 let mut state_lock = state.lock().await;
@@ -263,6 +327,8 @@ impl App {
                 }
             }
         }
+
+        Self::load_details_tab_internal(state, &android_manager, ios_manager.as_ref()).await;
     }
 
     /// Schedule non-blocking updates for device details and log streams
@@ -272,7 +338,7 @@ impl App {
         let android_manager = self.android_manager.clone();
         let ios_manager = self.ios_manager.clone();
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             tokio::time::sleep(Duration::from_millis(25)).await;
 
             Self::update_device_details_internal(
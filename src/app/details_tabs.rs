@@ -0,0 +1,152 @@
+use super::{App, AppState, DetailsTab, Panel};
+use crate::app::state::DetailsTabContent;
+use crate::constants::ui_text::details_tabs::{
+    NO_APPS, NO_HISTORY, NO_LAUNCH_OUTPUT, NO_SENSORS, OUTPUT_ANDROID_ONLY, SENSORS_ANDROID_ONLY,
+};
+use crate::constants::ui_text::radios::{OFF, ON, UNKNOWN};
+use crate::managers::{AndroidManager, IosManager};
+use crate::models::{Radio, RadioStatus};
+use crate::utils::tasks;
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Switches the details panel tab and loads its content in the background.
+    pub(super) async fn switch_details_tab(&mut self, forward: bool) {
+        {
+            let mut state = self.state.lock().await;
+            state.cycle_details_tab(forward);
+        }
+
+        let state = Arc::clone(&self.state);
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        tasks::spawn(async move {
+            Self::load_details_tab_internal(state, &android_manager, ios_manager.as_ref()).await;
+        });
+    }
+
+    /// Loads the content of the current details tab for the selected device.
+    /// The Info tab is built from cached details and needs no loading.
+    pub(super) async fn load_details_tab_internal(
+        state: Arc<Mutex<AppState>>,
+        android_manager: &AndroidManager,
+        ios_manager: Option<&IosManager>,
+    ) {
+        let (tab, panel, identifier) = {
+            let state = state.lock().await;
+            match state.selected_device_identifier() {
+                Some(identifier) => (state.details_tab, state.active_panel, identifier),
+                None => return,
+            }
+        };
+        if tab == DetailsTab::Info {
+            return;
+        }
+
+        let lines = match (tab, panel) {
+            (DetailsTab::Config, Panel::Android) => {
+                format_entries(android_manager.read_device_config(&identifier).await, " = ")
+            }
+            (DetailsTab::Config, Panel::Ios) => match ios_manager {
+                Some(ios) => format_entries(ios.device_config(&identifier).await, " = "),
+                None => Ok(Vec::new()),
+            },
+            (DetailsTab::Apps, Panel::Android) => with_placeholder(
+                android_manager.list_installed_packages(&identifier).await,
+                NO_APPS,
+            ),
+            (DetailsTab::Apps, Panel::Ios) => match ios_manager {
+                Some(ios) => with_placeholder(ios.list_installed_apps(&identifier).await, NO_APPS),
+                None => Ok(vec![NO_APPS.to_string()]),
+            },
+            (DetailsTab::Sensors, Panel::Android) => {
+                // Radios come first; an image without them still shows its sensors
+                let radios = match android_manager.radio_status(&identifier).await {
+                    Ok(status) => radio_lines(&status),
+                    Err(error) => {
+                        log::debug!("Failed to read the radios of '{identifier}': {error:#}");
+                        Vec::new()
+                    }
+                };
+                format_entries(android_manager.sensor_status(&identifier).await, ": ").map(
+                    |lines| {
+                        let sensors = if lines.is_empty() {
+                            vec![NO_SENSORS.to_string()]
+                        } else {
+                            lines
+                        };
+                        if radios.is_empty() {
+                            sensors
+                        } else {
+                            radios
+                                .into_iter()
+                                .chain([String::new()])
+                                .chain(sensors)
+                                .collect()
+                        }
+                    },
+                )
+            }
+            (DetailsTab::Sensors, Panel::Ios) => Err(SENSORS_ANDROID_ONLY.to_string()),
+            (DetailsTab::Output, Panel::Android) => with_placeholder(
+                Ok(android_manager.launch_output(&identifier)),
+                NO_LAUNCH_OUTPUT,
+            ),
+            (DetailsTab::Output, Panel::Ios) => Err(OUTPUT_ANDROID_ONLY.to_string()),
+            (DetailsTab::History, _) => {
+                let lines = state.lock().await.device_history_lines(panel, &identifier);
+                Ok(if lines.is_empty() {
+                    vec![NO_HISTORY.to_string()]
+                } else {
+                    lines
+                })
+            }
+            (DetailsTab::Info, _) => return,
+        };
+
+        let mut state = state.lock().await;
+        state.set_details_tab_content(DetailsTabContent {
+            tab,
+            device_identifier: identifier,
+            lines,
+        });
+    }
+}
+
+fn format_entries(
+    entries: Result<Vec<(String, String)>>,
+    separator: &str,
+) -> Result<Vec<String>, String> {
+    entries
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|(key, value)| format!("{key}{separator}{value}"))
+                .collect()
+        })
+        .map_err(|e| e.to_string())
+}
+
+fn radio_lines(status: &RadioStatus) -> Vec<String> {
+    Radio::ALL
+        .into_iter()
+        .map(|radio| {
+            let value = match status.get(radio) {
+                Some(true) => ON,
+                Some(false) => OFF,
+                None => UNKNOWN,
+            };
+            format!("{}: {value}", radio.label())
+        })
+        .collect()
+}
+
+fn with_placeholder(items: Result<Vec<String>>, placeholder: &str) -> Result<Vec<String>, String> {
+    match items {
+        Ok(items) if items.is_empty() => Ok(vec![placeholder.to_string()]),
+        Ok(items) => Ok(items),
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,183 @@
+use super::{state::DeveloperTogglesDialog, App, AppState, Mode, Panel};
+use crate::constants::messages::notifications::{
+    DEVELOPER_TOGGLE_CHANGE_FAILED, DEVELOPER_TOGGLE_DISABLED, DEVELOPER_TOGGLE_ENABLED,
+    DEVELOPER_TOGGLE_STATUS_FAILED, FOCUS_NOT_RUNNING,
+};
+use crate::managers::{AndroidManager, IosManager};
+use crate::models::{error::format_user_error, DeveloperToggle, Platform};
+use crate::utils::tasks;
+use anyhow::anyhow;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the developer toggles of the selected running device.
+    pub(super) async fn open_developer_toggles_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let device = state.active_panel.device_id(identifier);
+            state.developer_toggles_dialog = Some(DeveloperTogglesDialog::new(device, name));
+            state.mode = Mode::DeveloperToggles;
+        }
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            load_developer_toggle_status(state, &android_manager, ios_manager.as_ref()).await;
+        });
+    }
+
+    pub(super) async fn handle_developer_toggles_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.developer_toggles_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        let toggle = match key.code {
+            KeyCode::Esc => {
+                state.developer_toggles_dialog = None;
+                state.mode = Mode::Normal;
+                return;
+            }
+            KeyCode::Char(c) => match DeveloperToggle::from_key(dialog.device.platform, c) {
+                Some(toggle) => toggle,
+                None => return,
+            },
+            _ => return,
+        };
+        // One switch at a time; the state is read again after each
+        let Some(status) = dialog
+            .status
+            .as_ref()
+            .filter(|_| dialog.switching.is_none())
+        else {
+            return;
+        };
+        let enabled = !status.is_enabled(toggle);
+        dialog.switching = Some(toggle);
+        let device = dialog.device.clone();
+        let device_name = dialog.device_name.clone();
+        drop(state);
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match (device.platform, &ios_manager) {
+                (Platform::Android, _) => {
+                    android_manager
+                        .set_developer_toggle(&device.identifier, toggle, enabled)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager)) => {
+                    ios_manager
+                        .set_developer_toggle(&device.identifier, toggle, enabled)
+                        .await
+                }
+                (Platform::Ios, None) => Err(anyhow!("iOS manager not available")),
+            };
+            {
+                let mut state = state.lock().await;
+                if let Some(dialog) = state.developer_toggles_dialog.as_mut() {
+                    dialog.switching = None;
+                }
+                match result {
+                    Ok(()) => {
+                        let message = if enabled {
+                            DEVELOPER_TOGGLE_ENABLED
+                        } else {
+                            DEVELOPER_TOGGLE_DISABLED
+                        };
+                        state.add_success_notification(
+                            message.replacen("{}", toggle.label(), 1).replacen(
+                                "{}",
+                                &device_name,
+                                1,
+                            ),
+                        );
+                    }
+                    Err(error) => state.add_error_notification(
+                        DEVELOPER_TOGGLE_CHANGE_FAILED
+                            .replacen("{}", toggle.label(), 1)
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    ),
+                }
+            }
+            load_developer_toggle_status(state, &android_manager, ios_manager.as_ref()).await;
+        });
+    }
+}
+
+/// Reads the toggles of the device the dialog shows, closing the dialog
+/// when they cannot be read.
+async fn load_developer_toggle_status(
+    state: Arc<Mutex<AppState>>,
+    android_manager: &AndroidManager,
+    ios_manager: Option<&IosManager>,
+) {
+    let Some((device, device_name)) = state
+        .lock()
+        .await
+        .developer_toggles_dialog
+        .as_ref()
+        .map(|dialog| (dialog.device.clone(), dialog.device_name.clone()))
+    else {
+        return;
+    };
+    let result = match (device.platform, ios_manager) {
+        (Platform::Android, _) => {
+            android_manager
+                .developer_toggle_status(&device.identifier)
+                .await
+        }
+        (Platform::Ios, Some(ios_manager)) => {
+            ios_manager
+                .developer_toggle_status(&device.identifier)
+                .await
+        }
+        (Platform::Ios, None) => Err(anyhow!("iOS manager not available")),
+    };
+
+    let mut state = state.lock().await;
+    let Some(dialog) = state
+        .developer_toggles_dialog
+        .as_mut()
+        .filter(|dialog| dialog.device == device)
+    else {
+        return;
+    };
+    match result {
+        Ok(status) => dialog.status = Some(status),
+        Err(error) => {
+            state.developer_toggles_dialog = None;
+            if state.mode == Mode::DeveloperToggles {
+                state.mode = Mode::Normal;
+            }
+            state.add_error_notification(
+                DEVELOPER_TOGGLE_STATUS_FAILED
+                    .replacen("{}", &device_name, 1)
+                    .replacen("{}", &format_user_error(&error), 1),
+            );
+        }
+    }
+}
@@ -1,78 +1,155 @@
 use super::{state, App, Mode, Panel};
-use crate::managers::common::DeviceManager;
-use crate::models::error::format_user_error;
+use crate::constants::messages::notifications::{
+    FOCUS_FAILED, FOCUS_NOT_RUNNING, HEADLESS_ALREADY_RUNNING,
+};
+use crate::managers::{common::DeviceManager, IosManager};
+use crate::models::{error::format_user_error, DeviceId, DeviceOperation};
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 
 impl App {
-    pub(super) async fn open_delete_confirmation(&mut self) {
+    /// Asks to delete the selected device, or deletes it right away when
+    /// the confirmation policy does not ask for it.
+    pub(super) async fn open_delete_confirmation(&mut self) -> Result<()> {
         let mut state = self.state.lock().await;
-        let dialog =
-            match state.active_panel {
-                Panel::Android => state
-                    .android_devices
-                    .get(state.selected_android)
-                    .map(|device| state::ConfirmDeleteDialog {
+        let dialog = match state.active_panel {
+            Panel::Android => state
+                .android_devices
+                .get(state.selected_android)
+                .map(|device| {
+                    let dialog = state::ConfirmDeleteDialog {
                         device_name: device.name.clone(),
                         device_identifier: device.name.clone(),
                         platform: Panel::Android,
-                    }),
-                Panel::Ios => state.ios_devices.get(state.selected_ios).map(|device| {
-                    state::ConfirmDeleteDialog {
-                        device_name: device.name.clone(),
-                        device_identifier: device.udid.clone(),
-                        platform: Panel::Ios,
-                    }
+                    };
+                    (dialog, device.is_running)
                 }),
-            };
+            Panel::Ios => state.ios_devices.get(state.selected_ios).map(|device| {
+                let dialog = state::ConfirmDeleteDialog {
+                    device_name: device.name.clone(),
+                    device_identifier: device.udid.clone(),
+                    platform: Panel::Ios,
+                };
+                (dialog, device.is_running)
+            }),
+        };
 
-        if let Some(dialog) = dialog {
+        let Some((dialog, is_running)) = dialog else {
+            return Ok(());
+        };
+        state.confirm_delete_dialog = Some(dialog);
+        if state
+            .confirmation_policy()
+            .delete
+            .requires_confirmation(is_running)
+        {
             state.mode = Mode::ConfirmDelete;
-            state.confirm_delete_dialog = Some(dialog);
+            return Ok(());
         }
+        drop(state);
+        self.delete_confirmed_device().await
     }
 
-    pub(super) async fn open_wipe_confirmation(&mut self) {
+    /// Asks to wipe the selected device, or wipes it right away when the
+    /// confirmation policy does not ask for it.
+    pub(super) async fn open_wipe_confirmation(&mut self) -> Result<()> {
         let mut state = self.state.lock().await;
-        let dialog =
-            match state.active_panel {
-                Panel::Android => state
-                    .android_devices
-                    .get(state.selected_android)
-                    .map(|device| state::ConfirmWipeDialog {
+        let dialog = match state.active_panel {
+            Panel::Android => state
+                .android_devices
+                .get(state.selected_android)
+                .map(|device| {
+                    let dialog = state::ConfirmWipeDialog {
                         device_name: device.name.clone(),
                         device_identifier: device.name.clone(),
                         platform: Panel::Android,
-                    }),
-                Panel::Ios => state.ios_devices.get(state.selected_ios).map(|device| {
-                    state::ConfirmWipeDialog {
-                        device_name: device.name.clone(),
-                        device_identifier: device.udid.clone(),
-                        platform: Panel::Ios,
-                    }
+                    };
+                    (dialog, device.is_running)
                 }),
-            };
+            Panel::Ios => state.ios_devices.get(state.selected_ios).map(|device| {
+                let dialog = state::ConfirmWipeDialog {
+                    device_name: device.name.clone(),
+                    device_identifier: device.udid.clone(),
+                    platform: Panel::Ios,
+                };
+                (dialog, device.is_running)
+            }),
+        };
 
-        if let Some(dialog) = dialog {
+        let Some((dialog, is_running)) = dialog else {
+            return Ok(());
+        };
+        state.confirm_wipe_dialog = Some(dialog);
+        if state
+            .confirmation_policy()
+            .wipe
+            .requires_confirmation(is_running)
+        {
             state.mode = Mode::ConfirmWipe;
-            state.confirm_wipe_dialog = Some(dialog);
+            return Ok(());
+        }
+        drop(state);
+        self.wipe_confirmed_device().await
+    }
+
+    /// Brings the emulator or Simulator.app window of the selected running
+    /// device to the foreground.
+    pub(super) async fn focus_selected_device_window(&mut self) {
+        let target = {
+            let state = self.state.lock().await;
+            match state.active_panel {
+                Panel::Android => state
+                    .android_devices
+                    .get(state.selected_android)
+                    .map(|device| (device.name.clone(), device.name.clone(), device.is_running)),
+                Panel::Ios => state
+                    .ios_devices
+                    .get(state.selected_ios)
+                    .map(|device| (device.name.clone(), device.udid.clone(), device.is_running)),
+            }
+            .map(|target| (state.active_panel, target))
+        };
+        let Some((panel, (name, identifier, is_running))) = target else {
+            return;
+        };
+
+        if !is_running {
+            let mut state = self.state.lock().await;
+            state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+            return;
+        }
+
+        let result = match panel {
+            Panel::Android => {
+                self.android_manager
+                    .focus_emulator_window(&identifier)
+                    .await
+            }
+            Panel::Ios => match self.ios_manager {
+                Some(ref ios_manager) => ios_manager.focus_device_window(&identifier).await,
+                None => Err(anyhow::anyhow!("iOS manager not available")),
+            },
+        };
+
+        let mut state = self.state.lock().await;
+        match result {
+            // Simulator.app now shows the device, so it no longer runs headless
+            Ok(()) if panel == Panel::Ios => state.set_ios_device_headless(&identifier, false),
+            Ok(()) => {}
+            Err(error) => {
+                state.add_error_notification(FOCUS_FAILED.replacen("{}", &name, 1).replacen(
+                    "{}",
+                    &format_user_error(&error),
+                    1,
+                ));
+            }
         }
     }
 
     pub(super) async fn handle_confirm_delete_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
         match key.code {
             KeyCode::Char('y') | KeyCode::Char('Y') => {
-                {
-                    let mut state = self.state.lock().await;
-                    state.mode = Mode::Normal;
-                    if let Some(dialog) = state.confirm_delete_dialog.clone() {
-                        state.set_device_operation_status(format!(
-                            "Deleting device '{}'...",
-                            dialog.device_name
-                        ));
-                    }
-                }
-                self.execute_delete_device().await?;
+                self.delete_confirmed_device().await?;
             }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                 let mut state = self.state.lock().await;
@@ -88,17 +165,7 @@ impl App {
     pub(super) async fn handle_confirm_wipe_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
         match key.code {
             KeyCode::Char('y') | KeyCode::Char('Y') => {
-                {
-                    let mut state = self.state.lock().await;
-                    state.mode = Mode::Normal;
-                    if let Some(dialog) = state.confirm_wipe_dialog.clone() {
-                        state.set_device_operation_status(format!(
-                            "Wiping device '{}'...",
-                            dialog.device_name
-                        ));
-                    }
-                }
-                self.execute_wipe_device().await?;
+                self.wipe_confirmed_device().await?;
             }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                 let mut state = self.state.lock().await;
@@ -111,180 +178,218 @@ impl App {
         Ok(())
     }
 
+    async fn delete_confirmed_device(&mut self) -> Result<()> {
+        {
+            let mut state = self.state.lock().await;
+            state.mode = Mode::Normal;
+            if let Some(dialog) = state.confirm_delete_dialog.clone() {
+                state.set_device_operation_status(format!(
+                    "Deleting device '{}'...",
+                    dialog.device_name
+                ));
+            }
+        }
+        self.execute_delete_device().await
+    }
+
+    async fn wipe_confirmed_device(&mut self) -> Result<()> {
+        {
+            let mut state = self.state.lock().await;
+            state.mode = Mode::Normal;
+            if let Some(dialog) = state.confirm_wipe_dialog.clone() {
+                state.set_device_operation_status(format!(
+                    "Wiping device '{}'...",
+                    dialog.device_name
+                ));
+            }
+        }
+        self.execute_wipe_device().await
+    }
+
     pub(super) async fn toggle_device(&mut self) -> Result<()> {
-        let (active_panel, selected_android, selected_ios, android_devices, ios_devices) = {
+        let selected = {
             let state = self.state.lock().await;
-            (
-                state.active_panel,
-                state.selected_android,
-                state.selected_ios,
-                state.android_devices.clone(),
-                state.ios_devices.clone(),
-            )
+            let panel = state.active_panel;
+            match panel {
+                Panel::Android => state
+                    .android_devices
+                    .get(state.selected_android)
+                    .map(|device| {
+                        (
+                            panel.device_id(&device.name),
+                            device.name.clone(),
+                            device.is_running,
+                        )
+                    }),
+                Panel::Ios => state.ios_devices.get(state.selected_ios).map(|device| {
+                    (
+                        panel.device_id(&device.udid),
+                        device.name.clone(),
+                        device.is_running,
+                    )
+                }),
+            }
+        };
+        let Some((id, name, is_running)) = selected else {
+            return Ok(());
+        };
+        let Some(manager) = self.device_manager(id.platform) else {
+            return Ok(());
         };
 
-        let result = match active_panel {
-            Panel::Android => {
-                if let Some(device) = android_devices.get(selected_android) {
-                    let name = device.name.clone();
-                    let is_running = device.is_running;
-
-                    if is_running {
-                        {
-                            let mut state = self.state.lock().await;
-                            state.set_device_operation_status(format!(
-                                "Stopping device '{name}'..."
-                            ));
-                        }
+        let (operation, verb) = if is_running {
+            (DeviceOperation::Stop, "stop")
+        } else {
+            (DeviceOperation::Boot, "start")
+        };
+        {
+            let mut state = self.state.lock().await;
+            if is_running {
+                state.set_device_operation_status(format!("Stopping device '{name}'..."));
+            } else {
+                state.set_pending_device_start(name.clone());
+                state.set_device_operation_status(format!("Starting device '{name}'..."));
+            }
+        }
 
-                        match self.android_manager.stop_device(&name).await {
-                            Ok(()) => {
-                                let mut state = self.state.lock().await;
-                                state.clear_device_operation_status();
-                                state.add_success_notification(format!("Device '{name}' stopped"));
-                                state.update_single_android_device_status(&name, false);
-
-                                if let Some(ref cached) = state.cached_device_details {
-                                    if cached.identifier == name {
-                                        state.clear_cached_device_details();
-                                    }
-                                }
-                                Ok(())
-                            }
-                            Err(error) => {
-                                let mut state = self.state.lock().await;
-                                state.clear_device_operation_status();
-                                state.add_error_notification(format!(
-                                    "Failed to stop device '{name}': {}",
-                                    format_user_error(&error)
-                                ));
-                                Err(error)
-                            }
-                        }
-                    } else {
-                        let mut state = self.state.lock().await;
-                        state.set_pending_device_start(name.clone());
-                        state.set_device_operation_status(format!("Starting device '{name}'..."));
-                        drop(state);
-
-                        match self.android_manager.start_device(&name).await {
-                            Ok(()) => {
-                                let mut state = self.state.lock().await;
-                                state.clear_device_operation_status();
-                                state.add_info_notification(format!("Starting device '{name}'..."));
-                                state.update_single_android_device_status(&name, true);
-
-                                if let Some(ref cached) = state.cached_device_details {
-                                    if cached.identifier == name {
-                                        state.clear_cached_device_details();
-                                    }
-                                }
-                                Ok(())
-                            }
-                            Err(error) => {
-                                let mut state = self.state.lock().await;
-                                state.clear_pending_device_start();
-                                state.clear_device_operation_status();
-                                state.add_error_notification(format!(
-                                    "Failed to start device '{name}': {}",
-                                    format_user_error(&error)
-                                ));
-                                Err(error)
-                            }
-                        }
-                    }
+        let result = if is_running {
+            manager.stop_device(&id.identifier).await
+        } else {
+            manager.start_device(&id.identifier).await
+        };
+
+        let panel = Panel::from(id.platform);
+        let mut state = self.state.lock().await;
+        state.clear_device_operation_status();
+        match result {
+            Ok(()) => {
+                if is_running {
+                    state.add_success_notification(format!("Device '{name}' stopped"));
                 } else {
-                    Ok(())
+                    state.add_info_notification(format!("Starting device '{name}'..."));
                 }
+                state.record_device_operation(panel, &id.identifier, operation, Ok(()));
+                state.expect_device_state(&id, &name, !is_running);
+
+                if let Some(ref cached) = state.cached_device_details {
+                    if cached.identifier == id.identifier {
+                        state.clear_cached_device_details();
+                    }
+                }
+                drop(state);
+                self.run_project_hook(operation, &id, &name).await;
+                if operation == DeviceOperation::Boot {
+                    self.apply_test_settings_on_start(&id, &name).await;
+                }
+                self.schedule_background_device_status_check().await;
             }
-            Panel::Ios => {
-                if let Some(ref ios_manager) = self.ios_manager {
-                    if let Some(device) = ios_devices.get(selected_ios) {
-                        let name = device.name.clone();
-                        let udid = device.udid.clone();
-                        let is_running = device.is_running;
-
-                        if is_running {
-                            {
-                                let mut state = self.state.lock().await;
-                                state.set_device_operation_status(format!(
-                                    "Stopping device '{name}'..."
-                                ));
-                            }
-
-                            match ios_manager.stop_device(&udid).await {
-                                Ok(()) => {
-                                    let mut state = self.state.lock().await;
-                                    state.clear_device_operation_status();
-                                    state.add_success_notification(format!(
-                                        "Device '{name}' stopped"
-                                    ));
-                                    state.update_single_ios_device_status(&udid, false);
-
-                                    if let Some(ref cached) = state.cached_device_details {
-                                        if cached.identifier == udid {
-                                            state.clear_cached_device_details();
-                                        }
-                                    }
-                                    Ok(())
-                                }
-                                Err(error) => {
-                                    let mut state = self.state.lock().await;
-                                    state.clear_device_operation_status();
-                                    state.add_error_notification(format!(
-                                        "Failed to stop device '{name}': {error}"
-                                    ));
-                                    Err(error)
-                                }
-                            }
-                        } else {
-                            let mut state = self.state.lock().await;
-                            state.set_pending_device_start(name.clone());
-                            state.set_device_operation_status(format!(
-                                "Starting device '{name}'..."
-                            ));
-                            drop(state);
+            Err(error) => {
+                state.record_device_operation(
+                    panel,
+                    &id.identifier,
+                    operation,
+                    Err(error.to_string()),
+                );
+                if !is_running {
+                    state.clear_pending_device_start();
+                }
+                let message = format_user_error(&error);
+                state
+                    .add_error_notification(format!("Failed to {verb} device '{name}': {message}"));
+                drop(state);
+                if !is_running && panel == Panel::Android {
+                    self.diagnose_launch_failure(id.identifier, Some(message));
+                }
+            }
+        }
+        Ok(())
+    }
 
-                            match ios_manager.start_device(&udid).await {
-                                Ok(()) => {
-                                    let mut state = self.state.lock().await;
-                                    state.clear_device_operation_status();
-                                    state.add_info_notification(format!(
-                                        "Starting device '{name}'..."
-                                    ));
-                                    state.update_single_ios_device_status(&udid, true);
-
-                                    if let Some(ref cached) = state.cached_device_details {
-                                        if cached.identifier == udid {
-                                            state.clear_cached_device_details();
-                                        }
-                                    }
-                                    Ok(())
-                                }
-                                Err(error) => {
-                                    let mut state = self.state.lock().await;
-                                    state.clear_pending_device_start();
-                                    state.clear_device_operation_status();
-                                    state.add_error_notification(format!(
-                                        "Failed to start device '{name}': {error}"
-                                    ));
-                                    Err(error)
-                                }
-                            }
-                        }
-                    } else {
-                        Ok(())
+    /// Boots an iOS simulator, opening Simulator.app unless `headless` is set.
+    async fn start_ios_device(
+        &self,
+        ios_manager: &IosManager,
+        name: String,
+        udid: String,
+        headless: bool,
+    ) -> Result<()> {
+        let mut state = self.state.lock().await;
+        state.set_pending_device_start(name.clone());
+        state.set_device_operation_status(format!("Starting device '{name}'..."));
+        drop(state);
+
+        let result = if headless {
+            ios_manager.start_device_headless(&udid).await
+        } else {
+            ios_manager.start_device(&udid).await
+        };
+
+        match result {
+            Ok(()) => {
+                let mut state = self.state.lock().await;
+                state.clear_device_operation_status();
+                state.add_info_notification(format!("Starting device '{name}'..."));
+                state.record_device_operation(Panel::Ios, &udid, DeviceOperation::Boot, Ok(()));
+                state.expect_device_state(&DeviceId::ios(&udid), &name, true);
+                state.set_ios_device_headless(&udid, headless);
+
+                if let Some(ref cached) = state.cached_device_details {
+                    if cached.identifier == udid {
+                        state.clear_cached_device_details();
                     }
-                } else {
-                    Ok(())
                 }
+                drop(state);
+                let id = DeviceId::ios(&udid);
+                self.run_project_hook(DeviceOperation::Boot, &id, &name)
+                    .await;
+                self.apply_test_settings_on_start(&id, &name).await;
+                Ok(())
+            }
+            Err(error) => {
+                let mut state = self.state.lock().await;
+                state.record_device_operation(
+                    Panel::Ios,
+                    &udid,
+                    DeviceOperation::Boot,
+                    Err(error.to_string()),
+                );
+                state.clear_pending_device_start();
+                state.clear_device_operation_status();
+                state.add_error_notification(format!("Failed to start device '{name}': {error}"));
+                Err(error)
+            }
+        }
+    }
+
+    /// Boots the selected iOS simulator without opening Simulator.app.
+    pub(super) async fn start_selected_ios_device_headless(&mut self) {
+        let device = {
+            let state = self.state.lock().await;
+            if !state.active_capabilities().headless_boot {
+                return;
             }
+            state.ios_devices.get(state.selected_ios).cloned()
+        };
+        let Some(device) = device else {
+            return;
         };
 
-        if result.is_ok() {
+        if device.is_running {
+            let mut state = self.state.lock().await;
+            state.add_info_notification(HEADLESS_ALREADY_RUNNING.replace("{}", &device.name));
+            return;
+        }
+
+        let Some(ios_manager) = self.ios_manager.clone() else {
+            return;
+        };
+        if self
+            .start_ios_device(&ios_manager, device.name, device.udid, true)
+            .await
+            .is_ok()
+        {
             self.schedule_background_device_status_check().await;
         }
-        Ok(())
     }
 
     pub(super) async fn execute_delete_device(&mut self) -> Result<()> {
@@ -312,27 +417,7 @@ impl App {
             match result {
                 Ok(()) => {
                     let mut state = self.state.lock().await;
-
-                    match dialog.platform {
-                        Panel::Android => {
-                            state
-                                .android_devices
-                                .retain(|device| device.name != dialog.device_identifier);
-                            if state.selected_android >= state.android_devices.len() {
-                                state.selected_android =
-                                    state.android_devices.len().saturating_sub(1);
-                            }
-                        }
-                        Panel::Ios => {
-                            state
-                                .ios_devices
-                                .retain(|device| device.udid != dialog.device_identifier);
-                            if state.selected_ios >= state.ios_devices.len() {
-                                state.selected_ios = state.ios_devices.len().saturating_sub(1);
-                            }
-                        }
-                    }
-
+                    state.forget_deleted_device(dialog.platform, &dialog.device_identifier);
                     state.clear_device_operation_status();
                     state.add_success_notification(format!(
                         "Device '{}' deleted successfully",
@@ -381,6 +466,12 @@ impl App {
                 Ok(()) => {
                     let mut state = self.state.lock().await;
                     state.clear_device_operation_status();
+                    state.record_device_operation(
+                        dialog.platform,
+                        &dialog.device_identifier,
+                        DeviceOperation::Wipe,
+                        Ok(()),
+                    );
                     state.add_success_notification(format!(
                         "Device '{}' wiped successfully",
                         dialog.device_name
@@ -403,6 +494,12 @@ impl App {
                 Err(error) => {
                     let mut state = self.state.lock().await;
                     state.clear_device_operation_status();
+                    state.record_device_operation(
+                        dialog.platform,
+                        &dialog.device_identifier,
+                        DeviceOperation::Wipe,
+                        Err(error.to_string()),
+                    );
                     state.add_error_notification(format!(
                         "Failed to wipe device '{}': {}",
                         dialog.device_name,
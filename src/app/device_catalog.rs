@@ -0,0 +1,193 @@
+use super::{creation_queue::refresh_after_creation, state::DeviceCatalogDialog, App, Mode, Panel};
+use crate::constants::{
+    messages::notifications::{
+        CATALOG_ANDROID_ONLY, CATALOG_DEVICE_CREATED, CATALOG_DEVICE_FAILED, CATALOG_REFRESHED,
+    },
+    ui_text::device_catalog::{CREATING, INSTALLING_IMAGE, REFRESHING},
+};
+use crate::managers::AndroidManager;
+use crate::models::{error::format_user_error, CatalogEntry, DeviceCatalog, InstallProgress};
+use crate::utils::{network, tasks};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Opens the recommended device configurations.
+    pub(super) async fn open_device_catalog(&mut self) {
+        let mut state = self.state.lock().await;
+        if !state.active_capabilities().create {
+            return;
+        }
+        if state.active_panel != Panel::Android {
+            state.add_info_notification(CATALOG_ANDROID_ONLY.to_string());
+            return;
+        }
+        state.device_catalog_dialog = Some(DeviceCatalogDialog::new(DeviceCatalog::load()));
+        state.mode = Mode::DeviceCatalog;
+    }
+
+    pub(super) async fn handle_device_catalog_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.device_catalog_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            // A running download or creation finishes in the background
+            KeyCode::Esc => {
+                state.device_catalog_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            _ if dialog.is_busy() => {}
+            KeyCode::Up | KeyCode::Char('k') => dialog.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.move_down(),
+            KeyCode::Char('r') => {
+                dialog.status = Some(REFRESHING.to_string());
+                dialog.error = None;
+                drop(state);
+                self.refresh_device_catalog();
+            }
+            KeyCode::Enter => {
+                let Some(entry) = dialog.selected_entry().cloned() else {
+                    return;
+                };
+                // Numbered the way a second creation of the entry would be
+                let name = entry.unused_name(|name| {
+                    let avd_name = AndroidManager::sanitize_avd_name(name);
+                    state
+                        .android_devices
+                        .iter()
+                        .any(|device| device.name == avd_name)
+                });
+                if let Some(dialog) = state.device_catalog_dialog.as_mut() {
+                    dialog.status = Some(CREATING.replace("{}", &name));
+                    dialog.error = None;
+                }
+                drop(state);
+                self.create_from_catalog(entry, name);
+            }
+            _ => {}
+        }
+    }
+
+    /// Downloads the latest catalog and keeps it for the next sessions.
+    fn refresh_device_catalog(&self) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager.fetch_device_catalog().await;
+            let mut state = state.lock().await;
+            match result {
+                Ok(catalog) => {
+                    if let Err(error) = catalog.save_to_disk() {
+                        log::warn!("Failed to save the device catalog: {error}");
+                    }
+                    state.add_success_notification(
+                        CATALOG_REFRESHED.replace("{}", &catalog.devices.len().to_string()),
+                    );
+                    if let Some(dialog) = state.device_catalog_dialog.as_mut() {
+                        dialog.replace_catalog(catalog);
+                        dialog.status = None;
+                    }
+                }
+                Err(error) => {
+                    if let Some(dialog) = state.device_catalog_dialog.as_mut() {
+                        dialog.status = None;
+                        dialog.error = Some(format_user_error(&error));
+                    }
+                }
+            }
+        });
+    }
+
+    /// Installs the system image of `entry` when it is missing, then
+    /// creates the device as `name`.
+    fn create_from_catalog(&self, entry: CatalogEntry, name: String) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let set_status = |status: String, progress: Option<u8>| {
+                let state = Arc::clone(&state);
+                async move {
+                    if let Some(dialog) = state.lock().await.device_catalog_dialog.as_mut() {
+                        dialog.status = Some(status);
+                        dialog.progress = progress;
+                    }
+                }
+            };
+            let on_progress = {
+                let state = Arc::clone(&state);
+                move |progress: InstallProgress| {
+                    let state = Arc::clone(&state);
+                    tasks::spawn(async move {
+                        if let Some(dialog) = state.lock().await.device_catalog_dialog.as_mut() {
+                            // Updates can land out of order
+                            if dialog.progress < Some(progress.percentage) {
+                                dialog.progress = Some(progress.percentage);
+                            }
+                        }
+                    });
+                }
+            };
+
+            let result = async {
+                let installed = android_manager
+                    .check_system_image_available(
+                        &entry.api_level.to_string(),
+                        &entry.tag,
+                        entry.abi(),
+                    )
+                    .await
+                    .unwrap_or(false);
+                if !installed {
+                    set_status(INSTALLING_IMAGE.replace("{}", &name), Some(0)).await;
+                    // Checked first so a missing connection fails right away
+                    // instead of after sdkmanager's network timeout
+                    let offline = !network::is_online().await;
+                    android_manager.set_offline(offline).await;
+                    // sdkmanager's own message says more than the generic
+                    // one for a missing image
+                    android_manager
+                        .install_system_image(&entry.package_id(), on_progress.clone())
+                        .await
+                        .map_err(|error| error.to_string())?;
+                }
+                set_status(CREATING.replace("{}", &name), Some(0)).await;
+                android_manager
+                    .create_device_with_progress(&entry.device_config(&name), &on_progress)
+                    .await
+                    .map_err(|error| format_user_error(&error))
+            }
+            .await;
+
+            if result.is_ok() {
+                refresh_after_creation(&state, Panel::Android, &android_manager, None).await;
+            }
+            let mut state = state.lock().await;
+            if let Some(dialog) = state.device_catalog_dialog.as_mut() {
+                dialog.status = None;
+                dialog.progress = None;
+            }
+            match result {
+                Ok(()) => {
+                    state.add_success_notification(CATALOG_DEVICE_CREATED.replace("{}", &name));
+                    if state.device_catalog_dialog.is_some() {
+                        state.device_catalog_dialog = None;
+                        state.mode = Mode::Normal;
+                    }
+                }
+                Err(message) => {
+                    if let Some(dialog) = state.device_catalog_dialog.as_mut() {
+                        dialog.error = Some(message.clone());
+                    }
+                    state.add_error_notification(
+                        CATALOG_DEVICE_FAILED
+                            .replacen("{}", &name, 1)
+                            .replacen("{}", &message, 1),
+                    );
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use super::{state::DiagnosticsDialog, App, Mode};
+use crate::constants::messages::notifications::{
+    REPORT_FAILED, REPORT_SAVED, REPORT_SAVING, XCODE_DEFAULT_RESTORED, XCODE_SWITCHED,
+};
+use crate::utils::{diagnostics, report, tasks, xcode};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Opens the diagnostics viewer with emu's log records captured so far
+    /// and, on macOS, the Xcode simctl commands run against.
+    pub(super) async fn open_diagnostics(&mut self) {
+        let (developer_dir, installed_xcodes) = if cfg!(target_os = "macos") {
+            (
+                xcode::active_developer_dir().await,
+                xcode::installed_developer_dirs(),
+            )
+        } else {
+            (None, Vec::new())
+        };
+        let dialog = DiagnosticsDialog {
+            entries: diagnostics::recent_entries(),
+            scroll_offset: 0,
+            developer_dir,
+            installed_xcodes,
+        };
+
+        let mut state = self.state.lock().await;
+        state.diagnostics_dialog = Some(dialog);
+        state.mode = Mode::Diagnostics;
+    }
+
+    pub(super) async fn handle_diagnostics_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::F(12) => {
+                let mut state = self.state.lock().await;
+                state.diagnostics_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut dialog) = state.diagnostics_dialog {
+                    dialog.scroll_up();
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let mut state = self.state.lock().await;
+                if let Some(ref mut dialog) = state.diagnostics_dialog {
+                    dialog.scroll_down();
+                }
+            }
+            KeyCode::Char('r') => {
+                self.open_diagnostics().await;
+            }
+            KeyCode::Char('s') => {
+                self.save_bug_report().await;
+            }
+            KeyCode::Char('x') => {
+                self.switch_xcode().await;
+            }
+            _ => {}
+        }
+    }
+
+    /// Points simctl commands at the next installed Xcode, going back to the
+    /// default one after the last. Runtimes and device types differ per Xcode,
+    /// so the iOS caches are dropped and the device lists reloaded.
+    async fn switch_xcode(&mut self) {
+        let installed = match self.state.lock().await.diagnostics_dialog.as_ref() {
+            Some(dialog) if dialog.installed_xcodes.len() > 1 => dialog.installed_xcodes.clone(),
+            _ => return,
+        };
+
+        let next =
+            xcode::next_developer_dir(&installed, xcode::developer_dir_override().as_deref());
+        xcode::set_developer_dir_override(next.clone());
+        let developer_dir = xcode::active_developer_dir().await;
+
+        let mut state = self.state.lock().await;
+        if let Some(dialog) = state.diagnostics_dialog.as_mut() {
+            dialog.developer_dir = developer_dir;
+        }
+        state.device_cache.write().await.invalidate_ios_cache();
+        state.request_manual_refresh();
+        let message = match next {
+            Some(dir) => XCODE_SWITCHED.replace("{}", &dir.display().to_string()),
+            None => XCODE_DEFAULT_RESTORED.to_string(),
+        };
+        state.add_info_notification(message);
+    }
+
+    /// Writes a bug report bundle to the working directory in the background,
+    /// since querying tool versions runs external commands.
+    async fn save_bug_report(&self) {
+        self.state
+            .lock()
+            .await
+            .add_info_notification(REPORT_SAVING.to_string());
+
+        let state = self.state.clone();
+        tasks::spawn(async move {
+            let path = report::default_report_path();
+            let result = report::write_report(&path).await;
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => state.add_success_notification(
+                    REPORT_SAVED.replace("{}", &path.display().to_string()),
+                ),
+                Err(e) => state.add_error_notification(REPORT_FAILED.replace("{}", &e.to_string())),
+            }
+        });
+    }
+}
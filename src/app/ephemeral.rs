@@ -0,0 +1,233 @@
+//! Throwaway devices for experiments that should not clutter the inventory.
+//!
+//! An iOS simulator is cloned and the clone booted headless, then deleted
+//! once it stops or emu quits. An AVD boots with `-read-only`, which leaves
+//! its files untouched, so there is nothing to delete afterwards.
+
+use super::{creation_queue::refresh_after_creation, App, Panel};
+use crate::constants::{
+    defaults::EPHEMERAL_CLONE_NAME,
+    messages::notifications::{
+        EPHEMERAL_ALREADY_RUNNING, EPHEMERAL_CLONE_DELETED, EPHEMERAL_CLONE_DELETE_FAILED,
+        EPHEMERAL_CLONE_FAILED, EPHEMERAL_CLONE_STARTED, EPHEMERAL_CLONING,
+        EPHEMERAL_READ_ONLY_FAILED, EPHEMERAL_READ_ONLY_STARTED, EPHEMERAL_SOURCE_RUNNING,
+    },
+};
+use crate::managers::common::DeviceManager;
+use crate::models::{error::format_user_error, DeviceId, DeviceOperation, Platform};
+use crate::utils::tasks;
+use std::sync::Arc;
+
+impl App {
+    /// Starts a throwaway copy of the selected device: a clone on iOS, the
+    /// AVD itself in read-only mode on Android.
+    pub(super) async fn start_selected_device_ephemeral(&mut self) {
+        let panel = {
+            let state = self.state.lock().await;
+            if !state.active_capabilities().create {
+                return;
+            }
+            state.active_panel
+        };
+        match panel {
+            Panel::Android => self.start_selected_avd_read_only().await,
+            Panel::Ios => self.clone_selected_ios_device().await,
+        }
+    }
+
+    async fn start_selected_avd_read_only(&mut self) {
+        let Some(device) = self.state.lock().await.selected_android_device().cloned() else {
+            return;
+        };
+        let id = DeviceId::android(&device.name);
+        {
+            let mut state = self.state.lock().await;
+            if device.is_running {
+                state.add_info_notification(EPHEMERAL_ALREADY_RUNNING.replace("{}", &device.name));
+                return;
+            }
+            state.set_pending_device_start(device.name.clone());
+        }
+
+        let result = self
+            .android_manager
+            .start_device_read_only(&device.name)
+            .await;
+        let mut state = self.state.lock().await;
+        match result {
+            Ok(()) => {
+                // Not marked running yet: the AVD only counts as started, and
+                // later as stopped, once the device list shows it
+                state.track_ephemeral_device(id.clone());
+                state.record_device_operation(
+                    Panel::Android,
+                    &device.name,
+                    DeviceOperation::Boot,
+                    Ok(()),
+                );
+                state
+                    .add_info_notification(EPHEMERAL_READ_ONLY_STARTED.replace("{}", &device.name));
+                drop(state);
+                self.run_project_hook(DeviceOperation::Boot, &id, &device.name)
+                    .await;
+                self.apply_test_settings_on_start(&id, &device.name).await;
+                self.schedule_background_device_status_check().await;
+            }
+            Err(error) => {
+                state.record_device_operation(
+                    Panel::Android,
+                    &device.name,
+                    DeviceOperation::Boot,
+                    Err(error.to_string()),
+                );
+                state.clear_pending_device_start();
+                state.add_error_notification(
+                    EPHEMERAL_READ_ONLY_FAILED
+                        .replacen("{}", &device.name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                );
+            }
+        }
+    }
+
+    /// Clones the selected simulator and boots the clone headless.
+    async fn clone_selected_ios_device(&mut self) {
+        let (source, name) = {
+            let mut state = self.state.lock().await;
+            let Some(source) = state.ios_devices.get(state.selected_ios).cloned() else {
+                return;
+            };
+            // simctl clones only shut down simulators
+            if source.is_running {
+                state.add_error_notification(EPHEMERAL_SOURCE_RUNNING.replace("{}", &source.name));
+                return;
+            }
+
+            let base = EPHEMERAL_CLONE_NAME.replace("{}", &source.name);
+            let name = (1..)
+                .map(|number| match number {
+                    1 => base.clone(),
+                    number => format!("{base} {number}"),
+                })
+                .find(|candidate| !state.ios_devices.iter().any(|d| &d.name == candidate))
+                .unwrap_or(base);
+            state.set_device_operation_status(EPHEMERAL_CLONING.replace("{}", &source.name));
+            (source, name)
+        };
+
+        let Some(ios_manager) = self.ios_manager.clone() else {
+            return;
+        };
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = async {
+                let udid = ios_manager.clone_device(&source.udid, &name).await?;
+                // Track the clone before booting it, so a failed boot still
+                // leaves nothing behind when emu quits
+                state
+                    .lock()
+                    .await
+                    .track_ephemeral_device(DeviceId::ios(&udid));
+                ios_manager.start_device_headless(&udid).await?;
+                anyhow::Ok(udid)
+            }
+            .await;
+
+            match result {
+                Ok(udid) => {
+                    refresh_after_creation(
+                        &state,
+                        Panel::Ios,
+                        &android_manager,
+                        Some(&ios_manager),
+                    )
+                    .await;
+                    let mut state = state.lock().await;
+                    state.clear_device_operation_status();
+                    state.set_ios_device_headless(&udid, true);
+                    state.add_success_notification(EPHEMERAL_CLONE_STARTED.replace("{}", &name));
+                }
+                Err(error) => {
+                    let mut state = state.lock().await;
+                    state.clear_device_operation_status();
+                    state.add_error_notification(
+                        EPHEMERAL_CLONE_FAILED
+                            .replacen("{}", &source.name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    );
+                }
+            }
+        });
+    }
+
+    /// Deletes the ephemeral clones that stopped since the last check. A
+    /// read-only AVD that stopped just stops being ephemeral.
+    pub(super) async fn delete_stopped_ephemeral_devices(&self) {
+        let stopped = {
+            let mut state = self.state.lock().await;
+            let stopped = state.take_stopped_ephemeral_devices();
+            // A clone that left the list was deleted outside emu already
+            stopped
+                .into_iter()
+                .filter(|id| id.platform == Platform::Ios)
+                .filter_map(|id| {
+                    state
+                        .ios_devices
+                        .iter()
+                        .find(|device| device.udid == id.identifier)
+                        .map(|device| (id.identifier, device.name.clone()))
+                })
+                .collect::<Vec<_>>()
+        };
+        if stopped.is_empty() {
+            return;
+        }
+        let Some(ios_manager) = self.ios_manager.clone() else {
+            return;
+        };
+
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            for (udid, name) in stopped {
+                let result = ios_manager.delete_device(&udid).await;
+                let mut state = state.lock().await;
+                match result {
+                    Ok(()) => {
+                        state.forget_deleted_device(Panel::Ios, &udid);
+                        state.add_info_notification(EPHEMERAL_CLONE_DELETED.replace("{}", &name));
+                    }
+                    Err(error) => state.add_error_notification(
+                        EPHEMERAL_CLONE_DELETE_FAILED
+                            .replacen("{}", &name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    ),
+                }
+            }
+        });
+    }
+
+    /// Shuts down and deletes every ephemeral clone before emu exits.
+    /// Read-only AVDs keep running and discard their changes on their own.
+    pub(super) async fn delete_all_ephemeral_devices(&self) {
+        let udids: Vec<String> = {
+            let mut state = self.state.lock().await;
+            state
+                .ephemeral_devices
+                .drain()
+                .filter(|(id, _)| id.platform == Platform::Ios)
+                .map(|(id, _)| id.identifier)
+                .collect()
+        };
+        let Some(ios_manager) = self.ios_manager.as_ref() else {
+            return;
+        };
+        for udid in udids {
+            // Deleting a booted simulator fails, so stop it first
+            let _ = ios_manager.stop_device(&udid).await;
+            if let Err(error) = ios_manager.delete_device(&udid).await {
+                log::warn!("Failed to delete ephemeral clone {udid}: {error}");
+            }
+        }
+    }
+}
@@ -3,8 +3,12 @@
 //! This module defines the event system for the application, handling keyboard inputs,
 //! navigation, and device management actions. Events are processed through a centralized
 //! event handler that translates raw keyboard input into structured application actions.
+//! A [`KeySequencer`] in front of it recognizes two-key chords such as `g g` and tells
+//! keys the terminal reports as repeats apart from separate presses.
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::constants::performance::KEY_CHORD_TIMEOUT;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::time::Instant;
 
 /// Represents all possible events that can occur in the application.
 ///
@@ -21,6 +25,8 @@ pub enum AppEvent {
     Quit,
     /// Refresh device lists and status
     Refresh,
+    /// Pause or resume background auto-refresh
+    ToggleAutoRefresh,
     /// Switch to the next panel (Android -> iOS)
     NextPanel,
     /// Switch to the previous panel (iOS -> Android)
@@ -100,6 +106,7 @@ impl AppEvent {
     /// * `↑/↓` - Navigate items in list
     /// * `Enter`, `Space` - Toggle device state
     /// * `r` - Refresh device lists
+    /// * `p` - Pause/resume auto-refresh
     /// * `c` - Create new device
     /// * `d` - Delete device
     /// * `w` - Wipe device
@@ -127,6 +134,7 @@ impl AppEvent {
 
             // Device operations
             (KeyCode::Char('r'), _) => Some(Self::Refresh),
+            (KeyCode::Char('p'), _) => Some(Self::ToggleAutoRefresh),
             (KeyCode::Enter, _) => Some(Self::ToggleDevice),
             (KeyCode::Char(' '), _) => Some(Self::ToggleDevice),
             (KeyCode::Char('s'), _) => Some(Self::StartDevice),
@@ -188,6 +196,7 @@ impl AppEvent {
                 | Self::DeleteDevice
                 | Self::WipeDevice
                 | Self::Refresh
+                | Self::ToggleAutoRefresh
         )
     }
 
@@ -205,6 +214,167 @@ impl AppEvent {
             Self::EnterCreateMode | Self::EnterDeleteMode | Self::EnterHelpMode | Self::ExitMode
         )
     }
+
+    /// Converts a completed chord into an application event.
+    ///
+    /// # Chord Mappings
+    /// * `g g` - Move to first item in list
+    /// * `d d` - Delete the selected device
+    pub fn from_chord(chord: Chord) -> Option<Self> {
+        match (chord.first, chord.second) {
+            ('g', 'g') => Some(Self::Home),
+            ('d', 'd') => Some(Self::DeleteDevice),
+            _ => None,
+        }
+    }
+}
+
+/// Two keys pressed one after the other, such as `g g`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// Key that starts the chord
+    pub first: char,
+    /// Key that completes the chord
+    pub second: char,
+}
+
+impl Chord {
+    /// Creates a chord of two character keys.
+    pub const fn new(first: char, second: char) -> Self {
+        Self { first, second }
+    }
+}
+
+/// Chords that [`AppEvent::from_chord`] maps to events.
+pub const DEFAULT_CHORDS: [Chord; 2] = [Chord::new('g', 'g'), Chord::new('d', 'd')];
+
+/// How a key press came about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPress {
+    /// The key was pressed on its own
+    Discrete,
+    /// The key is held down and the terminal repeats it
+    Repeat,
+}
+
+impl KeyPress {
+    /// How the terminal reported `key`.
+    pub fn of(key: &KeyEvent) -> Self {
+        if key.kind == KeyEventKind::Repeat {
+            Self::Repeat
+        } else {
+            Self::Discrete
+        }
+    }
+}
+
+/// Keyboard input after chords and repeats have been recognized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyInput {
+    /// A single key
+    Key(KeyEvent, KeyPress),
+    /// Both keys of a chord, pressed separately and in time
+    Chord(Chord),
+}
+
+/// Recognizes chords and key repeats in a stream of key events.
+///
+/// The first key of a known chord is held back until the next key shows
+/// whether the chord is completed. A different key, a repeat, or
+/// [`KEY_CHORD_TIMEOUT`] passing without a second key releases it as a
+/// key of its own.
+///
+/// Only keys the terminal reports as [`KeyEventKind::Repeat`] count as
+/// repeats. Timing presses instead would take a quickly typed `d d` for a
+/// held key. Key releases produce no input.
+#[derive(Debug, Clone, Default)]
+pub struct KeySequencer {
+    /// Chords to recognize
+    chords: Vec<Chord>,
+    /// First key of a chord waiting for its second key
+    pending: Option<(KeyEvent, Instant)>,
+}
+
+impl KeySequencer {
+    /// Creates a sequencer recognizing `chords`. Without chords every key
+    /// is passed on right away.
+    pub fn new(chords: &[Chord]) -> Self {
+        Self {
+            chords: chords.to_vec(),
+            ..Self::default()
+        }
+    }
+
+    /// Feeds a key event received now. See [`KeySequencer::feed_at`].
+    pub fn feed(&mut self, key: KeyEvent) -> Vec<KeyInput> {
+        self.feed_at(key, Instant::now())
+    }
+
+    /// Feeds a key event received at `now` and returns the input it
+    /// completes: nothing while a chord is pending, a chord, or one or two
+    /// single keys when a pending chord is given up.
+    pub fn feed_at(&mut self, key: KeyEvent, now: Instant) -> Vec<KeyInput> {
+        if key.kind == KeyEventKind::Release {
+            return Vec::new();
+        }
+
+        let press = KeyPress::of(&key);
+        let mut inputs: Vec<KeyInput> = self.expire(now).into_iter().collect();
+
+        if let Some((first, _)) = self.pending.take() {
+            let chord = match (first.code, key.code) {
+                (KeyCode::Char(first), KeyCode::Char(second)) if press == KeyPress::Discrete => {
+                    Some(Chord::new(first, second)).filter(|chord| self.chords.contains(chord))
+                }
+                _ => None,
+            };
+            match chord {
+                Some(chord) if is_plain(&key) => {
+                    inputs.push(KeyInput::Chord(chord));
+                    return inputs;
+                }
+                _ => inputs.push(KeyInput::Key(first, KeyPress::Discrete)),
+            }
+        }
+
+        if press == KeyPress::Discrete && self.starts_chord(&key) {
+            self.pending = Some((key, now));
+        } else {
+            inputs.push(KeyInput::Key(key, press));
+        }
+        inputs
+    }
+
+    /// Gives up on a pending chord once [`KEY_CHORD_TIMEOUT`] has passed,
+    /// returning its first key as a key of its own. Called on every tick so
+    /// that a lone `g` still takes effect.
+    pub fn expire(&mut self, now: Instant) -> Option<KeyInput> {
+        let (_, started) = self.pending?;
+        if now.saturating_duration_since(started) < KEY_CHORD_TIMEOUT {
+            return None;
+        }
+        self.pending
+            .take()
+            .map(|(key, _)| KeyInput::Key(key, KeyPress::Discrete))
+    }
+
+    /// Whether the first key of a chord is waiting for its second key.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    fn starts_chord(&self, key: &KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) if is_plain(key) => self.chords.iter().any(|chord| chord.first == c),
+            _ => false,
+        }
+    }
+}
+
+/// Chords are typed without Ctrl or Alt.
+fn is_plain(key: &KeyEvent) -> bool {
+    !key.modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
 }
 
 /// Central event handler for processing application events.
@@ -274,6 +444,7 @@ impl Default for EventHandler {
 mod tests {
     use super::*;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::time::Duration;
 
     #[test]
     fn test_app_event_from_key_quit() {
@@ -459,6 +630,104 @@ mod tests {
         assert!(!handler.should_quit());
     }
 
+    fn press(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_key_sequencer_chords() {
+        let mut sequencer = KeySequencer::new(&DEFAULT_CHORDS);
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        assert!(sequencer.feed_at(press('g'), at(0)).is_empty());
+        assert!(sequencer.is_pending());
+        let chord = Chord::new('g', 'g');
+        assert_eq!(
+            sequencer.feed_at(press('g'), at(200)),
+            vec![KeyInput::Chord(chord)]
+        );
+        assert_eq!(AppEvent::from_chord(chord), Some(AppEvent::Home));
+        assert!(!sequencer.is_pending());
+
+        // A different key releases the pending one before itself
+        assert!(sequencer.feed_at(press('d'), at(1000)).is_empty());
+        assert_eq!(
+            sequencer.feed_at(press('j'), at(1100)),
+            vec![
+                KeyInput::Key(press('d'), KeyPress::Discrete),
+                KeyInput::Key(press('j'), KeyPress::Discrete),
+            ]
+        );
+
+        // A lone first key takes effect once the chord times out
+        assert!(sequencer.feed_at(press('d'), at(2000)).is_empty());
+        assert_eq!(sequencer.expire(at(2100)), None);
+        assert_eq!(
+            sequencer.expire(at(2000) + KEY_CHORD_TIMEOUT),
+            Some(KeyInput::Key(press('d'), KeyPress::Discrete))
+        );
+
+        // Keys that start no chord pass straight through
+        assert_eq!(
+            sequencer.feed_at(press('r'), at(3000)),
+            vec![KeyInput::Key(press('r'), KeyPress::Discrete)]
+        );
+    }
+
+    #[test]
+    fn test_key_sequencer_repeats() {
+        let mut sequencer = KeySequencer::new(&DEFAULT_CHORDS);
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        // Holding a chord key does not complete the chord
+        let mut repeat = press('d');
+        repeat.kind = KeyEventKind::Repeat;
+        assert!(sequencer.feed_at(press('d'), at(0)).is_empty());
+        assert_eq!(
+            sequencer.feed_at(repeat, at(30)),
+            vec![
+                KeyInput::Key(press('d'), KeyPress::Discrete),
+                KeyInput::Key(repeat, KeyPress::Repeat),
+            ]
+        );
+        assert_eq!(
+            sequencer.feed_at(repeat, at(60)),
+            vec![KeyInput::Key(repeat, KeyPress::Repeat)]
+        );
+
+        // Quick presses are separate presses, however close together
+        assert_eq!(
+            sequencer.feed_at(press('j'), at(100)),
+            vec![KeyInput::Key(press('j'), KeyPress::Discrete)]
+        );
+        assert_eq!(
+            sequencer.feed_at(press('j'), at(110)),
+            vec![KeyInput::Key(press('j'), KeyPress::Discrete)]
+        );
+
+        // Reported repeats and releases, as with the kitty protocol
+        let mut sequencer = KeySequencer::new(&[]);
+        let mut held = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        assert_eq!(
+            sequencer.feed_at(held, at(0)),
+            vec![KeyInput::Key(held, KeyPress::Discrete)]
+        );
+        held.kind = KeyEventKind::Repeat;
+        assert_eq!(
+            sequencer.feed_at(held, at(500)),
+            vec![KeyInput::Key(held, KeyPress::Repeat)]
+        );
+        held.kind = KeyEventKind::Release;
+        assert!(sequencer.feed_at(held, at(520)).is_empty());
+        held.kind = KeyEventKind::Press;
+        assert_eq!(
+            sequencer.feed_at(held, at(540)),
+            vec![KeyInput::Key(held, KeyPress::Discrete)]
+        );
+    }
+
     #[test]
     fn test_app_event_equality() {
         assert_eq!(AppEvent::Quit, AppEvent::Quit);
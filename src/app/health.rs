@@ -0,0 +1,129 @@
+use super::{App, Panel};
+use crate::constants::messages::notifications::{
+    DEVICE_WEDGED, FOCUS_NOT_RUNNING, FORCE_RESTARTING, FORCE_RESTART_FAILED,
+};
+use crate::models::{error::format_user_error, DeviceId};
+use crate::utils::tasks;
+use futures::future::join_all;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+impl App {
+    /// Checks in the background that the devices shown as running still
+    /// respond, and flags the ones that do not as wedged.
+    pub(super) async fn check_device_health(&self) {
+        let (avd_names, udids) = {
+            let state = self.state.lock().await;
+            if state.auto_refresh_paused {
+                return;
+            }
+            let avd_names: Vec<String> = state
+                .android_devices
+                .iter()
+                .filter(|device| device.is_running)
+                .map(|device| device.name.clone())
+                .collect();
+            let udids: Vec<String> = state
+                .ios_devices
+                .iter()
+                .filter(|device| device.is_running)
+                .map(|device| device.udid.clone())
+                .collect();
+            (avd_names, udids)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let android_checks = avd_names.into_iter().map(|avd_name| {
+                let android_manager = &android_manager;
+                async move {
+                    let responsive = android_manager.is_responsive(&avd_name).await;
+                    (DeviceId::android(avd_name), responsive)
+                }
+            });
+            let ios_checks = udids.into_iter().filter_map(|udid| {
+                let ios_manager = ios_manager.as_ref()?;
+                Some(async move {
+                    let responsive = ios_manager.is_responsive(&udid).await;
+                    (DeviceId::ios(udid), responsive)
+                })
+            });
+            let (android_results, ios_results) =
+                tokio::join!(join_all(android_checks), join_all(ios_checks));
+
+            // A device that stopped since the list was read is not wedged
+            let wedged: HashSet<DeviceId> = android_results
+                .into_iter()
+                .chain(ios_results)
+                .filter_map(|(id, responsive)| matches!(responsive, Ok(false)).then_some(id))
+                .collect();
+
+            let mut state = state.lock().await;
+            for id in state.set_wedged_devices(wedged) {
+                let name = state
+                    .ios_devices
+                    .iter()
+                    .find(|device| Panel::Ios.device_id(&device.udid) == id)
+                    .map_or_else(|| id.identifier.clone(), |device| device.name.clone());
+                log::warn!("'{name}' did not answer the health check");
+                state.add_error_notification(DEVICE_WEDGED.replace("{}", &name));
+            }
+        });
+    }
+
+    /// Kills the selected running device and boots it again, for devices
+    /// that stopped responding to a normal stop.
+    pub(super) async fn force_restart_selected_device(&mut self) {
+        let (panel, identifier, name) = {
+            let mut state = self.state.lock().await;
+            let selected = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (device.name.clone(), device.name.clone(), device.is_running)),
+                Panel::Ios => state
+                    .ios_devices
+                    .get(state.selected_ios)
+                    .map(|device| (device.udid.clone(), device.name.clone(), device.is_running)),
+            };
+            let Some((identifier, name, is_running)) = selected else {
+                return;
+            };
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            state.add_info_notification(FORCE_RESTARTING.replace("{}", &name));
+            (state.active_panel, identifier, name)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match (panel, ios_manager) {
+                (Panel::Android, _) => android_manager.force_restart_device(&identifier).await,
+                (Panel::Ios, Some(ios_manager)) => {
+                    ios_manager.force_restart_device(&identifier).await
+                }
+                (Panel::Ios, None) => Err(anyhow::anyhow!("iOS manager not available")),
+            };
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => {
+                    state.wedged_devices.remove(&panel.device_id(identifier));
+                    // Reports the device as started once it shows up again
+                    state.set_pending_device_start(name);
+                }
+                Err(error) => state.add_error_notification(
+                    FORCE_RESTART_FAILED.replacen("{}", &name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
@@ -1,32 +1,105 @@
+use super::events::{AppEvent, Chord, KeyInput, KeyPress};
 use super::{App, Mode, Panel};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::constants::messages::{
+    notifications::{AUTO_REFRESH_PAUSED, AUTO_REFRESH_RESUMED},
+    read_only,
+};
+use crate::models::DeviceError;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::time::Instant;
 
 impl App {
     pub(super) async fn process_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
-        if self.handle_quit_key(key).await {
-            return Ok(true);
-        }
-
         let mode = {
-            let state = self.state.lock().await;
+            let mut state = self.state.lock().await;
+            state.mark_input();
             state.mode
         };
 
+        // Chords only exist in normal mode; dialogs and text fields take
+        // every key as it comes
+        let inputs = if mode == Mode::Normal {
+            self.key_sequencer.feed(key)
+        } else if key.kind == KeyEventKind::Release {
+            Vec::new()
+        } else {
+            vec![KeyInput::Key(key, KeyPress::of(&key))]
+        };
+        for input in inputs {
+            if self.process_key_input(input).await? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Lets the first key of a chord that was not completed by `now` act on
+    /// its own. Called on every loop iteration.
+    pub(super) async fn release_expired_key_chord(&mut self, now: Instant) -> anyhow::Result<bool> {
+        match self.key_sequencer.expire(now) {
+            Some(input) => self.process_key_input(input).await,
+            None => Ok(false),
+        }
+    }
+
+    async fn process_key_input(&mut self, input: KeyInput) -> anyhow::Result<bool> {
+        let (key, press) = match input {
+            KeyInput::Key(key, press) => (key, press),
+            KeyInput::Chord(chord) => {
+                self.handle_chord(chord).await?;
+                return Ok(false);
+            }
+        };
+        // An earlier key of the same batch may have opened a dialog
+        let mode = self.state.lock().await.mode;
+        if press == KeyPress::Repeat && mode == Mode::Normal && !repeats_in_normal_mode(&key) {
+            return Ok(false);
+        }
+
+        if self.handle_quit_key(key, mode).await {
+            return Ok(true);
+        }
+
         match mode {
             Mode::Normal => self.handle_normal_mode_key(key).await?,
             Mode::CreateDevice => self.handle_create_mode_key(key).await?,
             Mode::ConfirmDelete => self.handle_confirm_delete_key(key).await?,
             Mode::ConfirmWipe => self.handle_confirm_wipe_key(key).await?,
+            Mode::RepairDevice => self.handle_repair_device_key(key).await,
+            Mode::PruneUnavailable => self.handle_prune_unavailable_key(key).await,
             Mode::ManageApiLevels => self.handle_api_level_mode_key(key).await,
             Mode::Help => self.handle_help_mode_key(key).await,
+            Mode::CommandHistory => self.handle_command_history_key(key).await,
+            Mode::Diagnostics => self.handle_diagnostics_key(key).await,
+            Mode::EditAnnotation => self.handle_annotation_key(key).await,
+            Mode::Search => self.handle_search_key(key).await,
+            Mode::MoveDeviceData => self.handle_move_device_data_key(key).await,
+            Mode::SendIntent => self.handle_intent_key(key).await,
+            Mode::ProcessInspector => self.handle_process_inspector_key(key).await,
+            Mode::SystemProperties => self.handle_system_properties_key(key).await,
+            Mode::RunMonkey => self.handle_monkey_key(key).await,
+            Mode::LaunchDiagnosis => self.handle_launch_diagnosis_key(key).await,
+            Mode::QuickBoot => self.handle_quick_boot_key(key).await,
+            Mode::DeviceClock => self.handle_device_clock_key(key).await,
+            Mode::TypeIntoDevice => self.handle_type_into_device_key(key).await,
+            Mode::Radios => self.handle_radios_key(key).await,
+            Mode::CreationQueue => self.handle_creation_queue_key(key).await,
+            Mode::DeviceCatalog => self.handle_device_catalog_key(key).await,
+            Mode::TestMatrix => self.handle_test_matrix_key(key).await,
+            Mode::Snippets => self.handle_snippets_key(key).await,
+            Mode::LaunchApp => self.handle_launch_app_key(key).await,
+            Mode::CaptureTrace => self.handle_trace_key(key).await,
+            Mode::DeveloperToggles => self.handle_developer_toggles_key(key).await,
+            Mode::AppData => self.handle_app_data_key(key).await,
         }
 
         Ok(false)
     }
 
-    async fn handle_quit_key(&mut self, key: KeyEvent) -> bool {
+    async fn handle_quit_key(&mut self, key: KeyEvent, mode: Mode) -> bool {
         let should_quit = matches!(key.code, KeyCode::Char('q'))
-            && (key.modifiers.contains(KeyModifiers::CONTROL) || key.modifiers.is_empty())
+            && (key.modifiers.contains(KeyModifiers::CONTROL)
+                || key.modifiers.is_empty() && !mode.is_text_entry())
             || matches!(key.code, KeyCode::Char('c'))
                 && key.modifiers.contains(KeyModifiers::CONTROL);
 
@@ -41,14 +114,60 @@ impl App {
         true
     }
 
+    async fn handle_chord(&mut self, chord: Chord) -> anyhow::Result<()> {
+        match AppEvent::from_chord(chord) {
+            Some(AppEvent::Home) => {
+                self.state.lock().await.select_first();
+                self.handle_selection_changed().await;
+            }
+            Some(AppEvent::DeleteDevice) => {
+                let mut state = self.state.lock().await;
+                if state.read_only {
+                    state.add_info_notification(
+                        DeviceError::read_only(read_only::DELETE_DEVICE).to_string(),
+                    );
+                    return Ok(());
+                }
+                drop(state);
+                self.open_delete_confirmation().await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     async fn handle_normal_mode_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        if let Some(operation) = read_only_operation(&key) {
+            let mut state = self.state.lock().await;
+            if state.read_only {
+                state.add_info_notification(DeviceError::read_only(operation).to_string());
+                return Ok(());
+            }
+        }
+
         match key.code {
             KeyCode::Esc => {
                 let mut state = self.state.lock().await;
                 state.dismiss_all_notifications();
             }
             KeyCode::Char('r') => {
-                self.refresh_devices_incremental().await?;
+                let mut state = self.state.lock().await;
+                state.request_manual_refresh();
+            }
+            KeyCode::Char('[') => {
+                self.switch_details_tab(false).await;
+            }
+            KeyCode::Char(']') => {
+                self.switch_details_tab(true).await;
+            }
+            KeyCode::Char('p') => {
+                let mut state = self.state.lock().await;
+                let message = if state.toggle_auto_refresh_paused() {
+                    AUTO_REFRESH_PAUSED
+                } else {
+                    AUTO_REFRESH_RESUMED
+                };
+                state.add_info_notification(message.to_string());
             }
             KeyCode::Tab
             | KeyCode::BackTab
@@ -82,6 +201,18 @@ impl App {
                 let mut state = self.state.lock().await;
                 state.toggle_fullscreen_logs();
             }
+            KeyCode::Char('D') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                let mut state = self.state.lock().await;
+                state.toggle_fullscreen_devices();
+            }
+            KeyCode::Char('Z') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                let mut state = self.state.lock().await;
+                state.toggle_zen_logs();
+            }
+            KeyCode::Char('P') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                let mut state = self.state.lock().await;
+                state.show_perf_overlay = !state.show_perf_overlay;
+            }
             KeyCode::Char('L') if key.modifiers.contains(KeyModifiers::SHIFT) => {
                 let mut state = self.state.lock().await;
                 state.clear_logs();
@@ -90,15 +221,160 @@ impl App {
             KeyCode::Char('c') => {
                 self.enter_create_device_mode().await;
             }
+            KeyCode::Char('C') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.enter_duplicate_device_mode().await;
+            }
+            KeyCode::Char('e') => {
+                self.open_device_catalog().await;
+            }
+            KeyCode::Char('x') => {
+                self.open_test_matrix().await;
+            }
+            KeyCode::Char('E') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.start_selected_matrix_group().await;
+            }
+            KeyCode::Char('z') => {
+                self.open_snippets().await;
+            }
+            KeyCode::Char('u') => {
+                self.start_selected_device_ephemeral().await;
+            }
             KeyCode::Char('d') => {
-                self.open_delete_confirmation().await;
+                self.open_delete_confirmation().await?;
             }
             KeyCode::Char('w') => {
-                self.open_wipe_confirmation().await;
+                self.open_wipe_confirmation().await?;
+            }
+            KeyCode::Char('M') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_move_device_data_dialog().await;
+            }
+            KeyCode::Char('B') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.collect_selected_device_diagnostics().await;
+            }
+            KeyCode::Char('K') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_process_inspector().await;
+            }
+            KeyCode::Char('T') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.toggle_selected_device_test_settings().await;
+            }
+            KeyCode::Char('J') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_trace_dialog().await;
+            }
+            KeyCode::Char('U') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_developer_toggles_dialog().await;
+            }
+            KeyCode::Char('G') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_app_data_dialog().await;
+            }
+            KeyCode::Char('A') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_launch_app_dialog().await;
+            }
+            KeyCode::Char('a') => {
+                self.open_intent_dialog().await;
+            }
+            KeyCode::Char('g') => {
+                self.open_system_properties().await;
+            }
+            KeyCode::Char('S') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_monkey_dialog().await;
+            }
+            KeyCode::Char('s') => {
+                self.open_quick_boot_options().await;
+            }
+            KeyCode::Char('N') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_device_clock_dialog().await;
+            }
+            KeyCode::Char('v') => {
+                self.toggle_mirroring(false).await;
+            }
+            KeyCode::Char('V') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.toggle_mirroring(true).await;
+            }
+            KeyCode::Char('O') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_radios_dialog().await;
+            }
+            KeyCode::Char('Y') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.toggle_network_capture().await;
+            }
+            KeyCode::Char('I') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_type_into_device().await;
+            }
+            KeyCode::Char('W') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.force_restart_selected_device().await;
+            }
+            KeyCode::Char('m') => {
+                self.write_selected_device_log_marker().await;
+            }
+            KeyCode::Char('o') => {
+                self.focus_selected_device_window().await;
+            }
+            KeyCode::Char('b') => {
+                self.start_selected_ios_device_headless().await;
+            }
+            KeyCode::Char('R') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_repair_dialog().await;
+            }
+            KeyCode::Char('X') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_prune_unavailable().await;
             }
             KeyCode::Char('i') => {
                 self.open_api_level_management().await;
             }
+            KeyCode::Char('y') => {
+                self.toggle_device_command().await;
+            }
+            KeyCode::Char('H') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_command_history().await;
+            }
+            KeyCode::Char('Q') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.open_creation_queue().await;
+            }
+            KeyCode::F(12) => {
+                self.open_diagnostics().await;
+            }
+            KeyCode::Char('t') => {
+                let mut state = self.state.lock().await;
+                state.open_annotation_dialog();
+            }
+            KeyCode::Char('/') => {
+                let mut state = self.state.lock().await;
+                state.search_query.clear();
+                state.mode = Mode::Search;
+            }
+            KeyCode::Char('n') => {
+                self.jump_to_search_match(true).await;
+            }
+            KeyCode::Char('+') => {
+                self.state
+                    .lock()
+                    .await
+                    .panel_layout
+                    .resize_device_panels(true);
+            }
+            KeyCode::Char('-') => {
+                self.state
+                    .lock()
+                    .await
+                    .panel_layout
+                    .resize_device_panels(false);
+            }
+            KeyCode::Char('>') => {
+                self.state
+                    .lock()
+                    .await
+                    .panel_layout
+                    .resize_details_panel(true);
+            }
+            KeyCode::Char('<') => {
+                self.state
+                    .lock()
+                    .await
+                    .panel_layout
+                    .resize_details_panel(false);
+            }
+            KeyCode::Char('0') => {
+                self.state.lock().await.panel_layout.reset();
+            }
             _ => {}
         }
 
@@ -110,6 +386,7 @@ impl App {
             let mut state = self.state.lock().await;
             let new_panel = state.active_panel.toggle();
             state.smart_clear_cached_device_details(new_panel);
+            state.shown_command = None;
             state.active_panel = new_panel;
         }
 
@@ -117,14 +394,23 @@ impl App {
     }
 
     async fn move_selection_and_schedule_updates(&mut self, move_up: bool) {
-        let should_update = {
+        {
             let mut state = self.state.lock().await;
             if move_up {
                 state.move_up();
             } else {
                 state.move_down();
             }
+        }
+        self.handle_selection_changed().await;
+    }
+
+    /// Resets logs and details for the newly selected device.
+    pub(super) async fn handle_selection_changed(&mut self) {
+        let should_update = {
+            let mut state = self.state.lock().await;
             state.clear_logs();
+            state.shown_command = None;
 
             if let Some(handle) = state.log_task_handle.take() {
                 handle.abort();
@@ -170,3 +456,33 @@ impl App {
         }
     }
 }
+
+/// Normal mode keys that keep acting while held down: moving the selection,
+/// switching details tabs, resizing panels and stepping through search
+/// matches. Everything else, such as opening dialogs, needs a new press.
+fn repeats_in_normal_mode(key: &KeyEvent) -> bool {
+    matches!(
+        key.code,
+        KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::Char('j' | 'k' | '[' | ']' | '+' | '-' | '<' | '>' | 'n')
+    )
+}
+
+/// The operation a normal mode key starts that `--read-only` turns off.
+fn read_only_operation(key: &KeyEvent) -> Option<&'static str> {
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+    match key.code {
+        KeyCode::Char('c') => Some(read_only::CREATE_DEVICE),
+        KeyCode::Char('C') if shift => Some(read_only::CREATE_DEVICE),
+        KeyCode::Char('e') => Some(read_only::CREATE_DEVICE),
+        KeyCode::Char('x') => Some(read_only::CREATE_DEVICE),
+        KeyCode::Char('u') => Some(read_only::CREATE_DEVICE),
+        KeyCode::Char('d') => Some(read_only::DELETE_DEVICE),
+        KeyCode::Char('w') => Some(read_only::WIPE_DEVICE),
+        KeyCode::Char('i') => Some(read_only::INSTALL_SYSTEM_IMAGE),
+        KeyCode::Char('M') if shift => Some(read_only::MOVE_DEVICE_DATA),
+        KeyCode::Char('X') if shift => Some(read_only::PRUNE_UNAVAILABLE),
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+use super::{state, App, IntentField, Mode};
+use crate::constants::messages::notifications::{
+    FOCUS_NOT_RUNNING, INTENT_FAILED, INTENT_SENT, SENDING_INTENT,
+};
+use crate::models::{error::format_user_error, AndroidIntent, DeviceId};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Opens the intent launcher for the selected running AVD.
+    pub(super) async fn open_intent_dialog(&mut self) {
+        let mut state = self.state.lock().await;
+        if !state.active_capabilities().intents {
+            return;
+        }
+        let Some(device) = state.selected_android_device() else {
+            return;
+        };
+        let device_name = device.name.clone();
+        if !device.is_running {
+            state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+            return;
+        }
+
+        let history = state
+            .intent_history
+            .entries_for(&DeviceId::android(&device_name))
+            .to_vec();
+        state.intent_dialog = Some(state::IntentDialog {
+            device_name,
+            intent: AndroidIntent::default(),
+            active_field: IntentField::Action,
+            history,
+            history_index: None,
+        });
+        state.mode = Mode::SendIntent;
+    }
+
+    pub(super) async fn handle_intent_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.intent_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.intent_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                // Catch incomplete intents while the fields are still open
+                if let Err(error) = dialog.intent.am_args() {
+                    state.add_error_notification(format_user_error(&error));
+                    return;
+                }
+                let device_name = dialog.device_name.clone();
+                let intent = dialog.intent.clone();
+                state.intent_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.send_intent(device_name, intent);
+            }
+            KeyCode::Tab => dialog.active_field = dialog.active_field.next(),
+            KeyCode::BackTab => dialog.active_field = dialog.active_field.previous(),
+            KeyCode::Up => dialog.history_older(),
+            KeyCode::Down => dialog.history_newer(),
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                if dialog.active_field == IntentField::Kind =>
+            {
+                dialog.toggle_kind();
+            }
+            KeyCode::Backspace => {
+                if let Some(input) = dialog.active_input() {
+                    input.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(input) = dialog.active_input() {
+                    input.push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Sends the intent in the background and remembers it in the device's
+    /// intent history once the device accepted it.
+    fn send_intent(&self, device_name: String, intent: AndroidIntent) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            state
+                .lock()
+                .await
+                .set_device_operation_status(SENDING_INTENT.replace("{}", &device_name));
+            let result = android_manager.send_intent(&device_name, &intent).await;
+
+            let mut state = state.lock().await;
+            state.clear_device_operation_status();
+            match result {
+                Ok(()) => {
+                    state.add_success_notification(
+                        INTENT_SENT.replacen("{}", &device_name, 1).replacen(
+                            "{}",
+                            &intent.summary(),
+                            1,
+                        ),
+                    );
+                    state
+                        .intent_history
+                        .record(&DeviceId::android(&device_name), intent);
+                }
+                Err(error) => state.add_error_notification(
+                    INTENT_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
@@ -0,0 +1,258 @@
+use super::{
+    state::{AppConsole, LaunchAppDialog},
+    App, LaunchAppField, Mode, Panel,
+};
+use crate::constants::messages::{
+    formats::APP_LOG_LINE,
+    notifications::{
+        APP_CONSOLE_STARTED, APP_CONSOLE_STOPPED, APP_EXITED, APP_LAUNCHED, APP_LAUNCH_FAILED,
+        FOCUS_NOT_RUNNING,
+    },
+};
+use crate::models::{error::format_user_error, AppLaunch, Platform};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+impl App {
+    /// Opens the app launcher for the selected running device, or stops the
+    /// iOS app whose console output is being shown.
+    pub(super) async fn open_launch_app_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            if let Some(console) = state.app_console.take() {
+                if !console.handle.is_finished() {
+                    drop(state);
+                    self.stop_app_console(console).await;
+                    return;
+                }
+            }
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let device = state.active_panel.device_id(identifier);
+            state.launch_app_dialog = Some(LaunchAppDialog::new(device, name));
+            state.mode = Mode::LaunchApp;
+        }
+
+        self.load_launchable_apps();
+    }
+
+    pub(super) async fn handle_launch_app_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.launch_app_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.launch_app_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let launch = match AppLaunch::parse(
+                    dialog.device.platform,
+                    &dialog.app,
+                    &dialog.arguments,
+                    &dialog.environment,
+                ) {
+                    Ok(launch) => launch,
+                    Err(error) => {
+                        state.add_error_notification(format_user_error(&error));
+                        return;
+                    }
+                };
+                let device = dialog.device.clone();
+                let device_name = dialog.device_name.clone();
+                state.launch_app_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                match device.platform {
+                    Platform::Android => self.launch_android_app(device_name, launch),
+                    Platform::Ios => {
+                        self.launch_ios_app_with_console(device.identifier, device_name, launch)
+                            .await
+                    }
+                }
+            }
+            KeyCode::Tab => dialog.next_field(),
+            KeyCode::BackTab => dialog.previous_field(),
+            KeyCode::Up if dialog.active_field == LaunchAppField::App => dialog.cycle_app(false),
+            KeyCode::Down if dialog.active_field == LaunchAppField::App => dialog.cycle_app(true),
+            KeyCode::Backspace => {
+                dialog.active_input().pop();
+            }
+            KeyCode::Char(c) => dialog.active_input().push(c),
+            _ => {}
+        }
+    }
+
+    /// Lists the apps the launcher offers in the background.
+    fn load_launchable_apps(&self) {
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let device = {
+                let state = state.lock().await;
+                let Some(dialog) = &state.launch_app_dialog else {
+                    return;
+                };
+                dialog.device.clone()
+            };
+            let apps = match (device.platform, &ios_manager) {
+                (Platform::Android, _) => {
+                    android_manager
+                        .list_installed_packages(&device.identifier)
+                        .await
+                }
+                (Platform::Ios, Some(ios_manager)) => {
+                    ios_manager.list_installed_apps(&device.identifier).await
+                }
+                (Platform::Ios, None) => return,
+            };
+            let apps = match apps {
+                Ok(apps) => apps,
+                Err(e) => {
+                    log::debug!("Failed to list apps of '{}': {e:#}", device.identifier);
+                    return;
+                }
+            };
+            let mut state = state.lock().await;
+            if let Some(dialog) = state
+                .launch_app_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device == device)
+            {
+                dialog.set_apps(apps);
+            }
+        });
+    }
+
+    /// Starts the app's launcher activity with its extras in the background.
+    fn launch_android_app(&self, device_name: String, launch: AppLaunch) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager.launch_app(&device_name, &launch).await;
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(component) => state.add_success_notification(
+                    APP_LAUNCHED
+                        .replacen("{}", &component, 1)
+                        .replacen("{}", &device_name, 1),
+                ),
+                Err(error) => state.add_error_notification(
+                    APP_LAUNCH_FAILED
+                        .replacen("{}", &launch.app, 1)
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+    }
+
+    /// Launches an iOS app in the background, streaming its console output
+    /// into the log panel until it exits or is stopped.
+    async fn launch_ios_app_with_console(
+        &self,
+        udid: String,
+        device_name: String,
+        launch: AppLaunch,
+    ) {
+        let Some(ios_manager) = self.ios_manager.clone() else {
+            return;
+        };
+        let state = Arc::clone(&self.state);
+        let task_udid = udid.clone();
+        let task_device_name = device_name.clone();
+        let task_launch = launch.clone();
+        let handle = tasks::spawn(async move {
+            let (udid, device_name, launch) = (task_udid, task_device_name, task_launch);
+            let (line_sender, mut line_receiver) = mpsc::unbounded_channel::<String>();
+            let run_app = async {
+                // Dropping the sender with the closure ends the output loop
+                let on_line = move |line: &str| {
+                    let _ = line_sender.send(line.to_string());
+                };
+                ios_manager
+                    .launch_app_with_console(&udid, &launch, &on_line)
+                    .await
+            };
+            let show_output = async {
+                while let Some(line) = line_receiver.recv().await {
+                    state
+                        .lock()
+                        .await
+                        .add_log("INFO".to_string(), APP_LOG_LINE.replace("{}", &line));
+                }
+            };
+            let (result, ()) = tokio::join!(run_app, show_output);
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => state.add_info_notification(
+                    APP_EXITED
+                        .replacen("{}", &launch.app, 1)
+                        .replacen("{}", &device_name, 1),
+                ),
+                Err(error) => state.add_error_notification(
+                    APP_LAUNCH_FAILED
+                        .replacen("{}", &launch.app, 1)
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+
+        let mut state = self.state.lock().await;
+        state.add_info_notification(APP_CONSOLE_STARTED.replacen("{}", &launch.app, 1).replacen(
+            "{}",
+            &device_name,
+            1,
+        ));
+        state.app_console = Some(AppConsole {
+            udid,
+            device_name,
+            bundle_id: launch.app,
+            handle,
+        });
+    }
+
+    /// Stops streaming an app's console output and quits the app, which
+    /// may outlive simctl.
+    async fn stop_app_console(&self, console: AppConsole) {
+        console.handle.abort();
+        self.state.lock().await.add_info_notification(
+            APP_CONSOLE_STOPPED
+                .replacen("{}", &console.bundle_id, 1)
+                .replacen("{}", &console.device_name, 1),
+        );
+        if let Some(ios_manager) = self.ios_manager.clone() {
+            tasks::spawn(async move {
+                if let Err(e) = ios_manager
+                    .terminate_app(&console.udid, &console.bundle_id)
+                    .await
+                {
+                    log::debug!("{e:#}");
+                }
+            });
+        }
+    }
+}
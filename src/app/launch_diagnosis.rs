@@ -0,0 +1,127 @@
+use super::{state::LaunchDiagnosisDialog, App, Mode};
+use crate::constants::messages::notifications::{
+    LAUNCH_DIAGNOSIS_CLEAN, LAUNCH_DIAGNOSIS_FAILED, LAUNCH_ISSUES_FOUND, LAUNCH_ISSUE_FIXED,
+    LAUNCH_ISSUE_FIXING, LAUNCH_ISSUE_FIX_FAILED,
+};
+use crate::models::{error::format_user_error, LaunchIssue};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Checks in the background why an AVD failed to start and shows the
+    /// problems found with their fixes. `reason` is the emulator's last
+    /// error, if it exited with one.
+    pub(super) fn diagnose_launch_failure(&self, avd_name: String, reason: Option<String>) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager.diagnose_launch(&avd_name).await;
+            let mut state = state.lock().await;
+            match result {
+                Ok(issues) if issues.is_empty() => {
+                    state.add_info_notification(LAUNCH_DIAGNOSIS_CLEAN.replace("{}", &avd_name));
+                }
+                // Don't pull the user out of another dialog
+                Ok(issues) if state.mode != Mode::Normal => {
+                    let summaries: Vec<String> = issues.iter().map(LaunchIssue::summary).collect();
+                    state.add_error_notification(
+                        LAUNCH_ISSUES_FOUND.replacen("{}", &avd_name, 1).replacen(
+                            "{}",
+                            &summaries.join("; "),
+                            1,
+                        ),
+                    );
+                }
+                Ok(issues) => {
+                    state.launch_diagnosis_dialog =
+                        Some(LaunchDiagnosisDialog::new(avd_name, reason, issues));
+                    state.mode = Mode::LaunchDiagnosis;
+                }
+                Err(error) => state.add_error_notification(
+                    LAUNCH_DIAGNOSIS_FAILED
+                        .replacen("{}", &avd_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+    }
+
+    pub(super) async fn handle_launch_diagnosis_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.launch_diagnosis_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.launch_diagnosis_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => dialog.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.select_next(),
+            KeyCode::Enter if dialog.fixing.is_none() => {
+                let Some(issue) = dialog.selected_issue().filter(|issue| issue.can_fix()) else {
+                    return;
+                };
+                let issue = issue.clone();
+                let avd_name = dialog.device_name.clone();
+                dialog.fixing = Some(issue.clone());
+                state.add_info_notification(
+                    LAUNCH_ISSUE_FIXING
+                        .replacen("{}", issue.fix_hint(), 1)
+                        .replacen("{}", &avd_name, 1),
+                );
+                drop(state);
+                self.fix_launch_issue(avd_name, issue);
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies the fix of `issue` in the background and drops it from the
+    /// diagnosis once it succeeded.
+    fn fix_launch_issue(&self, avd_name: String, issue: LaunchIssue) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager.fix_launch_issue(&issue).await;
+            let mut state = state.lock().await;
+            let dialog = state
+                .launch_diagnosis_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device_name == avd_name);
+            match result {
+                Ok(()) => {
+                    let all_fixed = dialog.is_some_and(|dialog| {
+                        dialog.fixing = None;
+                        dialog.issue_fixed(&issue)
+                    });
+                    if all_fixed && state.mode == Mode::LaunchDiagnosis {
+                        state.launch_diagnosis_dialog = None;
+                        state.mode = Mode::Normal;
+                    }
+                    if matches!(issue, LaunchIssue::MissingSystemImage { .. }) {
+                        state.device_cache.write().await.invalidate_android_cache();
+                    }
+                    state.add_success_notification(
+                        LAUNCH_ISSUE_FIXED
+                            .replacen("{}", issue.fix_hint(), 1)
+                            .replacen("{}", &avd_name, 1),
+                    );
+                }
+                Err(error) => {
+                    if let Some(dialog) = dialog {
+                        dialog.fixing = None;
+                    }
+                    state.add_error_notification(
+                        LAUNCH_ISSUE_FIX_FAILED
+                            .replacen("{}", &avd_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    );
+                }
+            }
+        });
+    }
+}
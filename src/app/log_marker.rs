@@ -0,0 +1,83 @@
+use super::{App, Panel};
+use crate::constants::{
+    defaults::LOG_MARKER_TAG,
+    messages::{
+        formats::LOG_MARKER,
+        notifications::{FOCUS_NOT_RUNNING, LOG_MARKER_FAILED, LOG_MARKER_WRITTEN},
+    },
+};
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use anyhow::Result;
+use std::sync::Arc;
+
+impl App {
+    /// Writes a numbered marker line into the log of the selected running
+    /// device, so the log around a manual test step is easy to find later.
+    pub(super) async fn write_selected_device_log_marker(&mut self) {
+        let (panel, device_name, identifier, marker) = {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            state.log_marker_count += 1;
+            let marker = LOG_MARKER
+                .replacen("{}", &state.log_marker_count.to_string(), 1)
+                .replacen(
+                    "{}",
+                    &chrono::Local::now().format("%H:%M:%S").to_string(),
+                    1,
+                );
+            (state.active_panel, name, identifier, marker)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result: Result<()> = match panel {
+                Panel::Android => {
+                    android_manager
+                        .write_log_marker(&identifier, LOG_MARKER_TAG, &marker)
+                        .await
+                }
+                Panel::Ios => match ios_manager {
+                    Some(ios_manager) => {
+                        ios_manager
+                            .write_log_marker(&identifier, LOG_MARKER_TAG, &marker)
+                            .await
+                    }
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => state.add_success_notification(
+                    LOG_MARKER_WRITTEN
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &marker, 1),
+                ),
+                Err(error) => state.add_error_notification(
+                    LOG_MARKER_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
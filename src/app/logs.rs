@@ -1,12 +1,19 @@
 use super::{App, AppState, Panel};
 use crate::constants::{
+    commands,
     keywords::{LOG_LEVEL_ERROR, LOG_LEVEL_WARNING},
+    messages::zen_logs,
     performance::DETAIL_UPDATE_DEBOUNCE,
 };
 use crate::managers::{AndroidManager, IosManager};
-use anyhow::Result;
+use crate::models::Platform;
+use crate::service::DeviceService;
+use crate::utils::{
+    boot_wait, command::format_command_line, processes, tasks, tool_paths, LossyLines,
+};
+use anyhow::{bail, Context, Result};
+use std::io::Write;
 use std::sync::Arc;
-use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 use tokio::sync::Mutex;
 
@@ -21,6 +28,61 @@ impl App {
         Ok(())
     }
 
+    /// Streams the logs of a running device to stdout as plain lines, the
+    /// zen log mode for pipes (`emu --zen-logs Pixel_7 | grep MyTag`).
+    /// Returns when the log stream ends or stdout is closed.
+    pub async fn print_device_logs(device: &str) -> Result<()> {
+        let service = DeviceService::new().await?;
+        let id = boot_wait::resolve_device(&service, device).await?;
+        let found = service.find(&id).await?;
+        if !found.is_running() {
+            bail!(zen_logs::NOT_RUNNING.replace("{}", device));
+        }
+        let device_name = found.name().to_string();
+
+        let state = Arc::new(Mutex::new(AppState::new()));
+        let handle = match id.platform {
+            Platform::Android => {
+                let running_avds = AndroidManager::new()?.get_running_avd_names().await?;
+                let serial = running_avds
+                    .get(&device_name)
+                    .or_else(|| running_avds.get(&device_name.replace(' ', "_")))
+                    .cloned()
+                    .with_context(|| zen_logs::NO_EMULATOR_SERIAL.replace("{}", device))?;
+                state.lock().await.current_log_device = Some((Panel::Android, device_name.clone()));
+                tasks::spawn(Self::stream_android_logs(
+                    Arc::clone(&state),
+                    device_name,
+                    serial,
+                ))
+            }
+            Platform::Ios => tasks::spawn(Self::stream_ios_logs(
+                Arc::clone(&state),
+                id.identifier,
+                device_name,
+            )),
+        };
+
+        let mut stdout = std::io::stdout();
+        loop {
+            let finished = handle.is_finished();
+            let lines = state.lock().await.take_plain_log_lines();
+            for line in lines {
+                if let Err(e) = writeln!(stdout, "{line}") {
+                    handle.abort();
+                    if e.kind() == std::io::ErrorKind::BrokenPipe {
+                        return Ok(());
+                    }
+                    return Err(e.into());
+                }
+            }
+            if finished {
+                return Ok(());
+            }
+            tokio::time::sleep(DETAIL_UPDATE_DEBOUNCE).await;
+        }
+    }
+
     pub(super) async fn update_log_stream_internal(
         state: Arc<Mutex<AppState>>,
         android_manager: AndroidManager,
@@ -93,7 +155,7 @@ impl App {
                         if let Ok(running_avds) = android_manager.get_running_avd_names().await {
                             if let Some(emulator_serial) = running_avds.get(&device_name) {
                                 let serial = emulator_serial.clone();
-                                let handle = tokio::spawn(async move {
+                                let handle = tasks::spawn(async move {
                                     Self::stream_android_logs(state_clone, device_name, serial)
                                         .await;
                                 });
@@ -103,7 +165,7 @@ impl App {
                                 let normalized_name = device_name.replace(' ', "_");
                                 if let Some(emulator_serial) = running_avds.get(&normalized_name) {
                                     let serial = emulator_serial.clone();
-                                    let handle = tokio::spawn(async move {
+                                    let handle = tasks::spawn(async move {
                                         Self::stream_android_logs(state_clone, device_name, serial)
                                             .await;
                                     });
@@ -112,7 +174,7 @@ impl App {
                                 } else if device.is_running && !running_avds.is_empty() {
                                     if let Some((_, serial)) = running_avds.iter().next() {
                                         let serial = serial.clone();
-                                        let handle = tokio::spawn(async move {
+                                        let handle = tasks::spawn(async move {
                                             Self::stream_android_logs(
                                                 state_clone,
                                                 device_name,
@@ -141,7 +203,7 @@ impl App {
                         let device_udid = device.udid.clone();
                         let device_name = device.name.clone();
                         let state_clone = Arc::clone(&state);
-                        let handle = tokio::spawn(async move {
+                        let handle = tasks::spawn(async move {
                             Self::stream_ios_logs(state_clone, device_udid, device_name).await;
                         });
                         let mut state_lock = state.lock().await;
@@ -157,17 +219,19 @@ impl App {
         device_name: String,
         emulator_serial: String,
     ) {
-        let result = Command::new("adb")
-            .args(["-s", &emulator_serial, "logcat", "-v", "time"])
+        let args = ["-s", &emulator_serial, "logcat", "-v", "time"];
+        let mut command = Command::new(tool_paths::resolve_program(commands::ADB.as_ref()));
+        command
+            .args(args)
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::null())
-            .stdin(std::process::Stdio::null())
-            .spawn();
+            .stdin(std::process::Stdio::null());
+        // logcat must not outlive the stream, even when its task is cancelled
+        let result = processes::spawn(&mut command, &format_command_line(commands::ADB, args));
 
         if let Ok(mut child) = result {
             if let Some(stdout) = child.stdout.take() {
-                let reader = BufReader::new(stdout);
-                let mut lines = reader.lines();
+                let mut lines = LossyLines::new(stdout);
 
                 loop {
                     tokio::select! {
@@ -233,17 +297,19 @@ impl App {
         ];
 
         for (command, args) in log_commands.iter() {
-            let result = tokio::process::Command::new(command)
+            let mut process =
+                tokio::process::Command::new(tool_paths::resolve_program(command.as_ref()));
+            crate::utils::xcode::apply_developer_dir(&mut process, command.as_ref());
+            process
                 .args(args)
                 .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .spawn();
+                .stderr(std::process::Stdio::piped());
+            let result = processes::spawn(&mut process, &format_command_line(command, args));
 
             match result {
                 Ok(mut child) => {
                     if let Some(stdout) = child.stdout.take() {
-                        let reader = BufReader::new(stdout);
-                        let mut lines = reader.lines();
+                        let mut lines = LossyLines::new(stdout);
 
                         while let Ok(Some(line_content)) = lines.next_line().await {
                             if line_content.trim().is_empty() {
@@ -0,0 +1,83 @@
+use super::App;
+use crate::constants::{
+    files::{MIRROR_RECORDING_EXTENSION, MIRROR_RECORDING_PREFIX},
+    messages::notifications::{
+        FOCUS_NOT_RUNNING, MIRRORING_AND_RECORDING, MIRRORING_FAILED, MIRRORING_IOS_UNSUPPORTED,
+        MIRRORING_STARTED, MIRRORING_STOPPED,
+    },
+};
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use std::sync::Arc;
+
+impl App {
+    /// Opens a scrcpy window for the selected running AVD, or closes the
+    /// one emu opened. With `record`, the screen is also recorded to
+    /// `[output] recording_dir` of `.emu.toml`, or the working directory.
+    pub(super) async fn toggle_mirroring(&mut self, record: bool) {
+        let (device_name, recording) = {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().mirroring {
+                if let Some(device) = state.selected_ios_device() {
+                    let message = MIRRORING_IOS_UNSUPPORTED.replace("{}", &device.name);
+                    state.add_info_notification(message);
+                }
+                return;
+            }
+            let Some(device) = state.selected_android_device() else {
+                return;
+            };
+            let (device_name, is_running) = (device.name.clone(), device.is_running);
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+                return;
+            }
+            let recording = record.then(|| {
+                let dir = state
+                    .project_config
+                    .as_ref()
+                    .and_then(|config| config.output.recording_dir.clone())
+                    .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+                dir.join(format!(
+                    "{MIRROR_RECORDING_PREFIX}{device_name}-{}{MIRROR_RECORDING_EXTENSION}",
+                    chrono::Local::now().format("%Y%m%d-%H%M%S")
+                ))
+            });
+            (device_name, recording)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = if android_manager.is_mirroring(&device_name) {
+                android_manager
+                    .stop_mirroring(&device_name)
+                    .await
+                    .map(|_| MIRRORING_STOPPED.replace("{}", &device_name))
+            } else {
+                android_manager
+                    .start_mirroring(&device_name, recording.as_deref())
+                    .await
+                    .map(|()| match &recording {
+                        Some(path) => MIRRORING_AND_RECORDING
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &path.display().to_string(), 1),
+                        None => MIRRORING_STARTED.replace("{}", &device_name),
+                    })
+            };
+
+            let mut state = state.lock().await;
+            state.active_recording = android_manager.recording_mirror();
+            match result {
+                Ok(message) => state.add_success_notification(message),
+                Err(error) => state.add_error_notification(
+                    MIRRORING_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
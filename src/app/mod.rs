@@ -15,23 +15,64 @@ pub mod state;
 /// Event processing optimizations for improved key input handling.
 pub mod event_processing;
 
+mod annotations;
 mod api_levels;
+mod app_data;
 mod background;
+mod bugreport;
+mod clock;
+mod command_history;
 mod create_device;
 mod create_device_form;
+mod creation_queue;
 mod details;
+mod details_tabs;
+mod developer_toggles;
 mod device_actions;
+mod device_catalog;
+mod diagnostics;
+mod ephemeral;
+mod health;
 mod input;
+mod intents;
+mod launch_app;
+mod launch_diagnosis;
+mod log_marker;
 mod logs;
+mod mirror;
+mod monkey;
+mod move_device_data;
+mod network_capture;
+mod processes;
+mod project;
+mod properties;
+mod prune_unavailable;
+mod quick_boot;
+mod radios;
 mod refresh;
+mod repair_device;
+mod show_command;
+mod snippets;
+mod test_matrix;
+mod test_settings;
+mod trace;
+mod type_into_device;
 
 use crate::{
     constants::{
         performance::{FULL_DEVICE_REFRESH_INTERVAL, INPUT_BATCH_DELAY, MAX_CONTINUOUS_EVENTS},
-        timeouts::{AUTO_REFRESH_CHECK_INTERVAL, EVENT_POLL_TIMEOUT, NOTIFICATION_CHECK_INTERVAL},
+        timeouts::{
+            AUTO_REFRESH_CHECK_INTERVAL, EVENT_POLL_TIMEOUT, HEALTH_CHECK_INTERVAL,
+            IDLE_EVENT_POLL_TIMEOUT, NOTIFICATION_CHECK_INTERVAL,
+        },
     },
-    managers::{AndroidManager, IosManager},
+    managers::{common::DeviceManager, AndroidManager, IosManager},
+    models::{DeviceAnnotations, DeviceHistory, IntentHistory, PanelLayout},
     ui,
+    utils::{
+        metrics::{self, Metric},
+        startup_profile::{self, StartupPhase},
+    },
 };
 use anyhow::Result;
 use crossterm::event::{self, Event as CrosstermEvent};
@@ -45,7 +86,10 @@ use crate::models::AndroidDevice;
 // Removed EventBatcher import for more responsive input handling
 
 // Re-export commonly used types from the state module
-pub use self::state::{ApiLevelManagementState, AppState, FocusedPanel, Mode, Panel};
+pub use self::state::{
+    AnnotationField, ApiLevelManagementState, AppState, DetailsTab, FocusedPanel, IntentField,
+    IosToolingStatus, LaunchAppField, Mode, MonkeyField, Panel, TraceField,
+};
 
 /// Main application controller that coordinates all components.
 ///
@@ -73,6 +117,14 @@ pub struct App {
     /// Only present on macOS where Xcode tools are available.
     ios_manager: Option<IosManager>,
 
+    /// The same managers behind the platform-neutral trait, Android first.
+    /// Operations that work alike on every platform go through these.
+    device_managers: Vec<Arc<dyn DeviceManager>>,
+
+    /// Further Android SDKs from `.emu.toml` by name. The create form can
+    /// pick one of them instead of `android_manager`.
+    android_sdks: Vec<(String, AndroidManager)>,
+
     /// Join handle for background log streaming task.
     /// Cancelled and recreated when switching devices or panels.
     log_update_handle: Option<tokio::task::JoinHandle<()>>,
@@ -84,6 +136,11 @@ pub struct App {
     /// Timestamp of the last full device metadata refresh.
     /// Auto-refresh can use lighter status-only checks between these refreshes.
     last_full_device_refresh: std::time::Instant,
+
+    /// Recognizes the `g g` and `d d` chords and tells keys held down apart
+    /// from separate presses, so that actions such as opening the delete
+    /// dialog do not auto-repeat.
+    key_sequencer: events::KeySequencer,
 }
 
 impl App {
@@ -99,6 +156,8 @@ impl App {
     ///
     /// The function prioritizes fast startup by deferring expensive operations:
     /// - Device discovery runs in the background
+    /// - The Xcode tooling is probed in the background; the iOS panel shows
+    ///   "detecting..." until it is ready
     /// - Cache loading is non-blocking
     /// - UI renders immediately with loading indicators
     ///
@@ -106,31 +165,83 @@ impl App {
     ///
     /// Returns an error if:
     /// - Android SDK is not properly configured
-    /// - iOS tools are unavailable on macOS
     /// - Initial manager creation fails
     pub async fn new() -> Result<Self> {
-        let state = Arc::new(Mutex::new(AppState::new()));
+        let sdk_discovery_started = std::time::Instant::now();
         let android_manager = AndroidManager::new()?;
+        startup_profile::record(StartupPhase::SdkDiscovery, sdk_discovery_started.elapsed());
+
+        let construction_started = std::time::Instant::now();
+        let state = Arc::new(Mutex::new(AppState::new()));
         let ios_manager = if cfg!(target_os = "macos") {
             Some(IosManager::new()?)
         } else {
             None
         };
 
-        let mut app = Self {
+        let mut app = Self::from_managers(state, android_manager, ios_manager);
+        app.last_full_device_refresh = std::time::Instant::now() - FULL_DEVICE_REFRESH_INTERVAL;
+
+        // Start background operations for optimal startup performance
+        app.start_background_cache_loading();
+        app.start_background_device_loading();
+        startup_profile::record(
+            StartupPhase::ManagerConstruction,
+            construction_started.elapsed(),
+        );
+
+        Ok(app)
+    }
+
+    /// Assembles an app around existing managers without starting any
+    /// background work.
+    fn from_managers(
+        state: Arc<Mutex<AppState>>,
+        android_manager: AndroidManager,
+        ios_manager: Option<IosManager>,
+    ) -> Self {
+        let mut device_managers: Vec<Arc<dyn DeviceManager>> =
+            vec![Arc::new(android_manager.clone())];
+        if let Some(ios_manager) = &ios_manager {
+            device_managers.push(Arc::new(ios_manager.clone()));
+        }
+
+        Self {
             state,
             android_manager,
             ios_manager,
+            device_managers,
+            android_sdks: Vec::new(),
             log_update_handle: None,
             detail_update_handle: None,
-            last_full_device_refresh: std::time::Instant::now() - FULL_DEVICE_REFRESH_INTERVAL,
-        };
+            last_full_device_refresh: std::time::Instant::now(),
+            key_sequencer: events::KeySequencer::new(&events::DEFAULT_CHORDS),
+        }
+    }
 
-        // Start background operations for optimal startup performance
-        app.start_background_cache_loading();
-        app.start_background_device_loading();
+    /// Sets how often device lists are polled in the background.
+    /// A zero interval starts with auto-refresh paused.
+    pub async fn set_auto_refresh_interval(&self, interval: std::time::Duration) {
+        let mut state = self.state.lock().await;
+        state.set_auto_refresh_interval(interval);
+    }
 
-        Ok(app)
+    /// Sets the colors the UI is drawn with.
+    pub async fn set_theme(&self, theme: ui::Theme) {
+        self.state.lock().await.theme = theme;
+    }
+
+    /// Turns off creating, deleting, wiping and installing (`--read-only`).
+    /// The managers refuse these operations, and the UI stops offering them.
+    pub async fn set_read_only(&self, read_only: bool) {
+        self.android_manager.set_read_only(read_only);
+        for (_, manager) in &self.android_sdks {
+            manager.set_read_only(read_only);
+        }
+        if let Some(ios_manager) = &self.ios_manager {
+            ios_manager.set_read_only(read_only);
+        }
+        self.state.lock().await.read_only = read_only;
     }
 
     /// Runs the ultra-responsive main application event loop.
@@ -138,9 +249,12 @@ impl App {
     /// This function implements the core application loop optimized for 120fps input responsiveness:
     /// 1. Renders the UI with immediate state updates
     /// 2. Processes user input events directly without batching or debouncing
-    /// 3. Manages background refresh cycles
+    /// 3. Manages background refresh cycles (configurable and pausable)
     /// 4. Handles notification timeouts
     ///
+    /// The device inventory saved by the previous session is shown before the
+    /// first frame and saved again when the user quits.
+    ///
     /// # Event Processing
     ///
     /// The loop uses direct event processing with 8ms polling for maximum responsiveness:
@@ -171,10 +285,40 @@ impl App {
     pub async fn run(
         mut self,
         mut terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
+    ) -> Result<()> {
+        self.restore_device_inventory().await;
+        self.android_manager.watch_device_connections();
+        {
+            let mut state = self.state.lock().await;
+            state.device_history = DeviceHistory::load_from_disk();
+            state.device_annotations = DeviceAnnotations::load_from_disk();
+            state.intent_history = IntentHistory::load_from_disk();
+            state.panel_layout = PanelLayout::load_from_disk();
+        }
+
+        // Quitting and errors both leave through here, so nothing emu
+        // started outlives it
+        let result = self.event_loop(&mut terminal).await;
+        self.save_device_inventory().await;
+        self.android_manager.stop_all_mirroring().await;
+        self.stop_all_network_captures().await;
+        self.delete_all_ephemeral_devices().await;
+        self.state.lock().await.device_history.flush();
+        self.shutdown_background_tasks().await;
+        result
+    }
+
+    /// Runs until the user quits or an error ends the session.
+    async fn event_loop(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     ) -> Result<()> {
         let mut last_auto_refresh_check = std::time::Instant::now();
         // Use constants from performance module instead of hardcoding
         let mut last_notification_check = std::time::Instant::now();
+        let mut last_health_check = std::time::Instant::now();
+        // Updated every frame; an idle session polls less often to save CPU
+        let mut idle = false;
 
         loop {
             // Priority 1: Process multiple events in batch for ultra-responsive handling
@@ -183,10 +327,8 @@ impl App {
                 if let Ok(event) = event::read() {
                     events_processed += 1;
                     match event {
-                        CrosstermEvent::Key(key) => {
-                            if self.process_key_event(key).await? {
-                                return Ok(());
-                            }
+                        CrosstermEvent::Key(key) if self.process_key_event(key).await? => {
+                            return Ok(());
                         }
                         CrosstermEvent::Resize(_, _) => {
                             // Handle resize if needed
@@ -198,20 +340,53 @@ impl App {
                 }
             }
 
+            // A lone `g` or `d` acts once its chord can no longer complete
+            if self
+                .release_expired_key_chord(std::time::Instant::now())
+                .await?
+            {
+                return Ok(());
+            }
+
             // If no events available, poll with longer timeout for efficiency
-            if events_processed == 0 && event::poll(EVENT_POLL_TIMEOUT)? {
+            let poll_timeout = if idle {
+                IDLE_EVENT_POLL_TIMEOUT
+            } else {
+                EVENT_POLL_TIMEOUT
+            };
+            if events_processed == 0 && event::poll(poll_timeout)? {
                 // Process single event with longer timeout
                 continue;
             }
 
             // Priority 2: Render UI after processing input for immediate visual feedback
             {
+                let lock_started = std::time::Instant::now();
                 let mut state = self.state.lock().await;
-                terminal.draw(|f| ui::render::draw_app(f, &mut state, &ui::Theme::dark()))?;
+                metrics::record(Metric::LockWait, lock_started.elapsed());
+
+                idle = state.is_idle();
+                let theme = state.theme.clone();
+                let frame_started = std::time::Instant::now();
+                terminal.draw(|f| ui::render::draw_app(f, &mut state, &theme))?;
+                metrics::record(Metric::Frame, frame_started.elapsed());
+                startup_profile::mark(StartupPhase::FirstRender);
+            }
+
+            // A manual refresh runs after the frame above has shown its spinner
+            let manual_refresh =
+                std::mem::take(&mut self.state.lock().await.manual_refresh_pending);
+            if manual_refresh {
+                let refresh_started = std::time::Instant::now();
+                self.refresh_devices_incremental().await?;
+                metrics::record(Metric::Refresh, refresh_started.elapsed());
+                last_auto_refresh_check = std::time::Instant::now();
             }
 
             // Priority 3: Handle background tasks (less frequently to avoid blocking input)
             if last_auto_refresh_check.elapsed() >= AUTO_REFRESH_CHECK_INTERVAL {
+                self.report_emulator_launch_failures().await;
+
                 let state = self.state.lock().await;
                 let should_refresh = state.should_auto_refresh();
                 let has_devices =
@@ -220,11 +395,19 @@ impl App {
 
                 // Only refresh if we have devices loaded (not during initial loading)
                 if should_refresh && has_devices {
+                    let refresh_started = std::time::Instant::now();
                     self.refresh_devices_smart().await?;
+                    metrics::record(Metric::Refresh, refresh_started.elapsed());
                 }
+                self.delete_stopped_ephemeral_devices().await;
                 last_auto_refresh_check = std::time::Instant::now();
             }
 
+            if last_health_check.elapsed() >= HEALTH_CHECK_INTERVAL {
+                self.check_device_health().await;
+                last_health_check = std::time::Instant::now();
+            }
+
             // Handle notification cleanup
             if last_notification_check.elapsed() >= NOTIFICATION_CHECK_INTERVAL {
                 let mut state = self.state.lock().await;
@@ -0,0 +1,207 @@
+use super::{state::MonkeyDialog, App, Mode, MonkeyField};
+use crate::constants::{
+    defaults::{MONKEY_EVENT_COUNT, MONKEY_THROTTLE_MS},
+    messages::{
+        formats::MONKEY_LOG_LINE,
+        notifications::{
+            FOCUS_NOT_RUNNING, MONKEY_FAILED, MONKEY_FOUND_FAILURES, MONKEY_PASSED, MONKEY_STARTED,
+            MONKEY_STOPPED,
+        },
+    },
+};
+use crate::models::{error::format_user_error, MonkeyReport, MonkeyRun};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+impl App {
+    /// Opens the monkey launcher for the selected running AVD, or stops the
+    /// monkey run in progress.
+    pub(super) async fn open_monkey_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            if let Some((device_name, handle)) = state.monkey_task.take() {
+                if !handle.is_finished() {
+                    handle.abort();
+                    state.add_info_notification(MONKEY_STOPPED.replace("{}", &device_name));
+                    return;
+                }
+            }
+            if !state.active_capabilities().stress_test {
+                return;
+            }
+            let Some(device) = state.selected_android_device() else {
+                return;
+            };
+            let device_name = device.name.clone();
+            if !device.is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+                return;
+            }
+            state.monkey_dialog = Some(MonkeyDialog::new(
+                device_name,
+                MONKEY_EVENT_COUNT,
+                MONKEY_THROTTLE_MS,
+            ));
+            state.mode = Mode::RunMonkey;
+        }
+
+        self.load_monkey_packages();
+    }
+
+    pub(super) async fn handle_monkey_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.monkey_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.monkey_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let run = match MonkeyRun::parse(
+                    &dialog.package,
+                    &dialog.event_count,
+                    &dialog.throttle_ms,
+                ) {
+                    Ok(run) => run,
+                    Err(error) => {
+                        state.add_error_notification(format_user_error(&error));
+                        return;
+                    }
+                };
+                let device_name = dialog.device_name.clone();
+                state.monkey_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.start_monkey_run(device_name, run).await;
+            }
+            KeyCode::Tab => dialog.active_field = dialog.active_field.next(),
+            KeyCode::BackTab => dialog.active_field = dialog.active_field.previous(),
+            KeyCode::Up if dialog.active_field == MonkeyField::Package => {
+                dialog.cycle_package(false)
+            }
+            KeyCode::Down if dialog.active_field == MonkeyField::Package => {
+                dialog.cycle_package(true)
+            }
+            KeyCode::Backspace => {
+                dialog.active_input().pop();
+            }
+            KeyCode::Char(c) => dialog.active_input().push(c),
+            _ => {}
+        }
+    }
+
+    /// Lists the packages the launcher offers in the background.
+    fn load_monkey_packages(&self) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let device_name = {
+                let state = state.lock().await;
+                let Some(dialog) = &state.monkey_dialog else {
+                    return;
+                };
+                dialog.device_name.clone()
+            };
+            let packages = match android_manager.list_installed_packages(&device_name).await {
+                Ok(packages) => packages,
+                Err(e) => {
+                    log::debug!("Failed to list packages of '{device_name}': {e:#}");
+                    return;
+                }
+            };
+            let mut state = state.lock().await;
+            if let Some(dialog) = state
+                .monkey_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device_name == device_name)
+            {
+                dialog.set_packages(packages);
+            }
+        });
+    }
+
+    /// Runs monkey in the background, streaming its output into the log
+    /// panel and reporting the crashes and ANRs it found when it ends.
+    async fn start_monkey_run(&self, device_name: String, run: MonkeyRun) {
+        let started =
+            MONKEY_STARTED
+                .replacen("{}", &run.package, 1)
+                .replacen("{}", &device_name, 1);
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        let task_device_name = device_name.clone();
+        let handle = tasks::spawn(async move {
+            let device_name = task_device_name;
+            let (line_sender, mut line_receiver) = mpsc::unbounded_channel::<String>();
+            let run_monkey = async {
+                // Dropping the sender with the closure ends the output loop
+                let on_line = move |line: &str| {
+                    let _ = line_sender.send(line.to_string());
+                };
+                android_manager
+                    .run_monkey(&device_name, &run, &on_line)
+                    .await
+            };
+            let show_output = async {
+                while let Some(line) = line_receiver.recv().await {
+                    let level = if MonkeyReport::is_failure_line(&line) {
+                        "ERROR"
+                    } else {
+                        "INFO"
+                    };
+                    state
+                        .lock()
+                        .await
+                        .add_log(level.to_string(), MONKEY_LOG_LINE.replace("{}", &line));
+                }
+            };
+            let (result, ()) = tokio::join!(run_monkey, show_output);
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(report) if report.has_failures() => {
+                    let failures: Vec<&str> = report
+                        .crashes
+                        .iter()
+                        .chain(&report.anrs)
+                        .map(String::as_str)
+                        .collect();
+                    state.add_error_notification(
+                        MONKEY_FOUND_FAILURES
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &report.crashes.len().to_string(), 1)
+                            .replacen("{}", &report.anrs.len().to_string(), 1)
+                            .replacen("{}", &failures.join("; "), 1),
+                    );
+                }
+                Ok(report) => {
+                    let events = report
+                        .events_injected
+                        .map_or_else(|| "?".to_string(), |count| count.to_string());
+                    state.add_success_notification(
+                        MONKEY_PASSED
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &events, 1),
+                    );
+                }
+                Err(error) => state.add_error_notification(
+                    MONKEY_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+
+        let mut state = self.state.lock().await;
+        state.add_info_notification(started);
+        state.monkey_task = Some((device_name, handle));
+    }
+}
@@ -0,0 +1,122 @@
+use super::{state, App, Mode};
+use crate::constants::{
+    messages::{
+        errors::DEVICE_DATA_MOVE_WHILE_RUNNING,
+        notifications::{DEVICE_DATA_MOVED, DEVICE_DATA_MOVE_FAILED, MOVING_DEVICE_DATA},
+    },
+    ui_text::device_activity::MOVING_DATA,
+};
+use crate::managers::AndroidManager;
+use crate::models::{error::format_user_error, DeviceId};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl App {
+    /// Asks where to move the data directory of the selected stopped AVD.
+    pub(super) async fn open_move_device_data_dialog(&mut self) {
+        let device_name = {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().move_data {
+                return;
+            }
+            let Some(device) = state.android_devices.get(state.selected_android) else {
+                return;
+            };
+            if device.is_running {
+                let message = DEVICE_DATA_MOVE_WHILE_RUNNING.replace("{}", &device.name);
+                state.add_info_notification(message);
+                return;
+            }
+            device.name.clone()
+        };
+
+        let Some(current_dir) = AndroidManager::avd_data_dir(&device_name).await else {
+            return;
+        };
+        let mut state = self.state.lock().await;
+        state.move_device_data_dialog = Some(state::MoveDeviceDataDialog {
+            device_name,
+            current_dir,
+            target_input: String::new(),
+        });
+        state.mode = Mode::MoveDeviceData;
+    }
+
+    pub(super) async fn handle_move_device_data_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.move_device_data_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.move_device_data_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let Some(target_dir) = dialog.target_dir() else {
+                    return;
+                };
+                let device_name = dialog.device_name.clone();
+                state.move_device_data_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.move_device_data(device_name, target_dir).await;
+            }
+            KeyCode::Backspace => {
+                dialog.target_input.pop();
+            }
+            KeyCode::Char(c) => {
+                dialog.target_input.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the data in the background, since copying to another disk can
+    /// take minutes, then refreshes the device so its details show the new
+    /// location.
+    async fn move_device_data(&mut self, device_name: String, target_dir: PathBuf) {
+        let activity = {
+            let mut state = self.state.lock().await;
+            state.set_device_operation_status(MOVING_DEVICE_DATA.replace("{}", &device_name));
+            state
+                .device_activities
+                .begin(DeviceId::android(&device_name), MOVING_DATA)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager
+                .move_device_data(&device_name, &target_dir)
+                .await;
+            let mut state = state.lock().await;
+            state.clear_device_operation_status();
+            state.device_activities.end(activity);
+            match result {
+                Ok(new_dir) => {
+                    state.add_success_notification(
+                        DEVICE_DATA_MOVED.replacen("{}", &device_name, 1).replacen(
+                            "{}",
+                            &new_dir.to_string_lossy(),
+                            1,
+                        ),
+                    );
+                    state.clear_cached_device_details();
+                    state.request_manual_refresh();
+                }
+                Err(error) => {
+                    state.add_error_notification(
+                        DEVICE_DATA_MOVE_FAILED
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    );
+                }
+            }
+        });
+    }
+}
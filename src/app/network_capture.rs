@@ -0,0 +1,254 @@
+use super::{state::NetworkCapture, App, AppState, Panel};
+use crate::constants::{
+    files::{NETWORK_CAPTURE_PREFIX, PCAP_EXTENSION},
+    messages::notifications::{
+        FOCUS_NOT_RUNNING, NETWORK_CAPTURE_FAILED, NETWORK_CAPTURE_IOS_STARTED,
+        NETWORK_CAPTURE_SAVED, NETWORK_CAPTURE_STARTED,
+    },
+    performance::NETWORK_CAPTURE_SIZE_INTERVAL,
+};
+use crate::models::{error::format_user_error, DeviceId, Platform};
+use crate::utils::tasks;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Starts writing the network traffic of the selected running device to
+    /// a pcap, or stops the capture running for it. Files go to
+    /// `[output] trace_dir` of `.emu.toml`, or the working directory.
+    pub(super) async fn toggle_network_capture(&mut self) {
+        let mut state = self.state.lock().await;
+        let target = match state.active_panel {
+            Panel::Android => state
+                .selected_android_device()
+                .map(|device| (&device.name, &device.name, device.is_running)),
+            Panel::Ios => state
+                .selected_ios_device()
+                .map(|device| (&device.name, &device.udid, device.is_running)),
+        };
+        let Some((name, identifier, is_running)) = target else {
+            return;
+        };
+        let (name, identifier) = (name.clone(), identifier.clone());
+        let device = state.active_panel.device_id(identifier.clone());
+        if let Some(index) = state
+            .network_captures
+            .iter()
+            .position(|capture| capture.device == device)
+        {
+            let capture = state.network_captures.remove(index);
+            drop(state);
+            self.stop_network_capture(capture);
+            return;
+        }
+        if !is_running {
+            state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+            return;
+        }
+
+        let dir = state
+            .project_config
+            .as_ref()
+            .and_then(|config| config.output.trace_dir.clone())
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+        let path = dir.join(format!(
+            "{NETWORK_CAPTURE_PREFIX}{identifier}-{}{PCAP_EXTENSION}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        // The task waits for the lock, so the capture is listed before it
+        // reads the size
+        let handle = match device.platform {
+            Platform::Android => self.start_android_network_capture(&device, &name, &path),
+            Platform::Ios => match self.start_ios_network_capture(&device, &name, &path) {
+                Some(handle) => handle,
+                None => return,
+            },
+        };
+        state.network_captures.push(NetworkCapture {
+            device,
+            device_name: name,
+            path,
+            bytes: 0,
+            handle,
+        });
+    }
+
+    /// Starts the emulator console capture, then follows the pcap's size.
+    fn start_android_network_capture(
+        &self,
+        device: &DeviceId,
+        device_name: &str,
+        path: &Path,
+    ) -> tokio::task::JoinHandle<()> {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        let (device, device_name, path) =
+            (device.clone(), device_name.to_string(), path.to_owned());
+        tasks::spawn(async move {
+            let started = android_manager
+                .start_network_capture(&device.identifier, &path)
+                .await;
+            {
+                let mut state = state.lock().await;
+                match started {
+                    Ok(()) => state.add_success_notification(
+                        NETWORK_CAPTURE_STARTED
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &path.display().to_string(), 1),
+                    ),
+                    Err(error) => {
+                        state
+                            .network_captures
+                            .retain(|capture| capture.path != path);
+                        state.add_error_notification(
+                            NETWORK_CAPTURE_FAILED
+                                .replacen("{}", &device_name, 1)
+                                .replacen("{}", &format_user_error(&error), 1),
+                        );
+                        return;
+                    }
+                }
+            }
+            follow_capture_size(state, &device, &path).await;
+        })
+    }
+
+    /// Runs tcpdump on the Mac until the capture is stopped, following the
+    /// pcap's size meanwhile. `None` without an iOS manager.
+    fn start_ios_network_capture(
+        &self,
+        device: &DeviceId,
+        device_name: &str,
+        path: &Path,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let ios_manager = self.ios_manager.clone()?;
+        let state = Arc::clone(&self.state);
+        let (device, device_name, path) =
+            (device.clone(), device_name.to_string(), path.to_owned());
+        Some(tasks::spawn(async move {
+            state.lock().await.add_success_notification(
+                NETWORK_CAPTURE_IOS_STARTED
+                    .replacen("{}", &device_name, 1)
+                    .replacen("{}", &path.display().to_string(), 1),
+            );
+            // Following the size ends when the device stops, which drops
+            // and so stops tcpdump
+            let result = tokio::select! {
+                result = ios_manager.capture_network_traffic(&path) => result,
+                () = follow_capture_size(Arc::clone(&state), &device, &path) => return,
+            };
+
+            let mut state = state.lock().await;
+            state
+                .network_captures
+                .retain(|capture| capture.path != path);
+            match result {
+                Ok(()) => state.add_info_notification(
+                    NETWORK_CAPTURE_SAVED
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &path.display().to_string(), 1),
+                ),
+                Err(error) => state.add_error_notification(
+                    NETWORK_CAPTURE_FAILED
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        }))
+    }
+
+    /// Ends a capture that is no longer listed and reports where it was
+    /// saved.
+    fn stop_network_capture(&self, capture: NetworkCapture) {
+        // Stops tcpdump on iOS along with the task
+        capture.handle.abort();
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match capture.device.platform {
+                Platform::Android => {
+                    android_manager
+                        .stop_network_capture(&capture.device.identifier)
+                        .await
+                }
+                Platform::Ios => Ok(()),
+            };
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => state.add_success_notification(
+                    NETWORK_CAPTURE_SAVED
+                        .replacen("{}", &capture.device_name, 1)
+                        .replacen("{}", &capture.path.display().to_string(), 1),
+                ),
+                Err(error) => state.add_error_notification(
+                    NETWORK_CAPTURE_FAILED
+                        .replacen("{}", &capture.device_name, 1)
+                        .replacen("{}", &format_user_error(&error), 1),
+                ),
+            }
+        });
+    }
+
+    /// Stops every network capture, e.g. when emu exits, so the emulator
+    /// completes its pcaps.
+    pub(super) async fn stop_all_network_captures(&self) {
+        let captures = std::mem::take(&mut self.state.lock().await.network_captures);
+        for capture in captures {
+            capture.handle.abort();
+            if capture.device.platform == Platform::Android {
+                if let Err(error) = self
+                    .android_manager
+                    .stop_network_capture(&capture.device.identifier)
+                    .await
+                {
+                    log::warn!(
+                        "Failed to stop the network capture of '{}': {error:#}",
+                        capture.device_name
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Reads the size of the capture at `path` until it is stopped, or until
+/// the device stops, when the capture is reported as saved.
+async fn follow_capture_size(state: Arc<Mutex<AppState>>, device: &DeviceId, path: &Path) {
+    loop {
+        tokio::time::sleep(NETWORK_CAPTURE_SIZE_INTERVAL).await;
+        let bytes = tokio::fs::metadata(path)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+
+        let mut state = state.lock().await;
+        let is_running = match device.platform {
+            Platform::Android => state
+                .android_devices
+                .iter()
+                .any(|avd| avd.name == device.identifier && avd.is_running),
+            Platform::Ios => state
+                .ios_devices
+                .iter()
+                .any(|simulator| simulator.udid == device.identifier && simulator.is_running),
+        };
+        let Some(index) = state
+            .network_captures
+            .iter()
+            .position(|capture| capture.path == path)
+        else {
+            return;
+        };
+        if !is_running {
+            let capture = state.network_captures.remove(index);
+            state.add_info_notification(
+                NETWORK_CAPTURE_SAVED
+                    .replacen("{}", &capture.device_name, 1)
+                    .replacen("{}", &path.display().to_string(), 1),
+            );
+            return;
+        }
+        state.network_captures[index].bytes = bytes;
+    }
+}
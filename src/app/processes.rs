@@ -0,0 +1,179 @@
+use super::{state::ProcessInspectorDialog, App, AppState, Mode, Panel};
+use crate::constants::messages::notifications::{
+    FOCUS_NOT_RUNNING, PROCESS_KILLED, PROCESS_KILL_FAILED,
+};
+use crate::managers::{AndroidManager, IosManager};
+use crate::models::{error::format_user_error, DeviceProcess};
+use crate::utils::tasks;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the process inspector for the selected running device.
+    pub(super) async fn open_process_inspector(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let panel = state.active_panel;
+            state.process_inspector_dialog =
+                Some(ProcessInspectorDialog::new(name, identifier, panel));
+            state.mode = Mode::ProcessInspector;
+        }
+
+        self.reload_process_inspector();
+    }
+
+    pub(super) async fn handle_process_inspector_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.process_inspector_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('K') => {
+                state.process_inspector_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Tab | KeyCode::BackTab => dialog.switch_tab(),
+            KeyCode::Up | KeyCode::Char('k') => dialog.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.select_next(),
+            KeyCode::Char('s') => dialog.toggle_sort(),
+            KeyCode::Char('r') => {
+                drop(state);
+                self.reload_process_inspector();
+            }
+            KeyCode::Char('x') => {
+                let Some(process) = dialog.selected_process().cloned() else {
+                    return;
+                };
+                drop(state);
+                self.kill_process(process);
+            }
+            _ => {}
+        }
+    }
+
+    /// Lists processes and services of the inspected device in the background.
+    fn reload_process_inspector(&self) {
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            load_process_lists(state, &android_manager, ios_manager.as_ref()).await;
+        });
+    }
+
+    /// Stops a process in the background, then lists the processes again so
+    /// the inspector shows whether it came back.
+    fn kill_process(&self, process: DeviceProcess) {
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let target = {
+                let state = state.lock().await;
+                state.process_inspector_dialog.as_ref().map(|dialog| {
+                    (
+                        dialog.platform,
+                        dialog.device_name.clone(),
+                        dialog.device_identifier.clone(),
+                    )
+                })
+            };
+            let Some((panel, device_name, identifier)) = target else {
+                return;
+            };
+
+            let result: Result<()> = match panel {
+                Panel::Android => android_manager.kill_process(&identifier, &process).await,
+                Panel::Ios => match &ios_manager {
+                    Some(ios_manager) => ios_manager.kill_process(&identifier, &process).await,
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+
+            {
+                let mut state = state.lock().await;
+                match result {
+                    Ok(()) => state.add_success_notification(
+                        PROCESS_KILLED.replacen("{}", &process.name, 1).replacen(
+                            "{}",
+                            &device_name,
+                            1,
+                        ),
+                    ),
+                    Err(error) => state.add_error_notification(
+                        PROCESS_KILL_FAILED
+                            .replacen("{}", &process.name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    ),
+                }
+            }
+            load_process_lists(state, &android_manager, ios_manager.as_ref()).await;
+        });
+    }
+}
+
+/// Loads both lists of the open process inspector and stores them unless
+/// the inspector was closed or opened for another device meanwhile.
+async fn load_process_lists(
+    state: Arc<Mutex<AppState>>,
+    android_manager: &AndroidManager,
+    ios_manager: Option<&IosManager>,
+) {
+    let target = {
+        let state = state.lock().await;
+        state
+            .process_inspector_dialog
+            .as_ref()
+            .map(|dialog| (dialog.platform, dialog.device_identifier.clone()))
+    };
+    let Some((panel, identifier)) = target else {
+        return;
+    };
+
+    let (processes, services) = match (panel, ios_manager) {
+        (Panel::Android, _) => (
+            android_manager.list_processes(&identifier).await,
+            android_manager.list_services(&identifier).await,
+        ),
+        (Panel::Ios, Some(ios_manager)) => (
+            ios_manager.list_processes(&identifier).await,
+            ios_manager.list_services(&identifier).await,
+        ),
+        (Panel::Ios, None) => (
+            Err(anyhow::anyhow!("iOS manager not available")),
+            Err(anyhow::anyhow!("iOS manager not available")),
+        ),
+    };
+
+    let mut state = state.lock().await;
+    if let Some(dialog) = state
+        .process_inspector_dialog
+        .as_mut()
+        .filter(|dialog| dialog.device_identifier == identifier)
+    {
+        dialog.set_lists(
+            processes.map_err(|e| format_user_error(&e)),
+            services.map_err(|e| format_user_error(&e)),
+        );
+    }
+}
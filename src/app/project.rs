@@ -0,0 +1,106 @@
+use super::{App, AppState};
+use crate::constants::{
+    env_vars::{EMU_DEVICE_ID, EMU_DEVICE_NAME, EMU_PLATFORM},
+    messages::notifications::{PROJECT_HOOK_FAILED, PROJECT_HOOK_FINISHED},
+};
+use crate::managers::AndroidManager;
+use crate::models::{DeviceId, DeviceOperation, ProjectConfig};
+use crate::utils::{tasks, CommandRunner};
+use std::process::Stdio;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Applies the `.emu.toml` of the directory emu was started in.
+    /// SDKs listed under `[tools.android_sdks]` that cannot be used are
+    /// logged and left out.
+    pub async fn set_project_config(&mut self, config: ProjectConfig) {
+        self.android_sdks = config
+            .tools
+            .android_sdks
+            .iter()
+            .filter_map(|(name, root)| {
+                AndroidManager::with_sdk_root(Arc::new(CommandRunner::new()), root.clone())
+                    .inspect_err(|e| log::warn!("Android SDK '{name}' unavailable: {e}"))
+                    .ok()
+                    .map(|manager| (name.clone(), manager))
+            })
+            .collect();
+        for (_, manager) in &self.android_sdks {
+            manager.set_read_only(self.android_manager.is_read_only());
+        }
+
+        let mut state = self.state.lock().await;
+        state.set_project_config(config);
+    }
+
+    /// Runs the project hook for a successful operation in the background.
+    /// The device is described to the hook through `EMU_*` variables.
+    pub(super) async fn run_project_hook(
+        &self,
+        operation: DeviceOperation,
+        device: &DeviceId,
+        device_name: &str,
+    ) {
+        Self::run_project_hook_with(&self.state, operation, device, device_name).await;
+    }
+
+    /// [`App::run_project_hook`] for background tasks, which only hold the
+    /// shared state.
+    pub(super) async fn run_project_hook_with(
+        state: &Arc<Mutex<AppState>>,
+        operation: DeviceOperation,
+        device: &DeviceId,
+        device_name: &str,
+    ) {
+        let (hook, command, root) = {
+            let state = state.lock().await;
+            let Some(config) = &state.project_config else {
+                return;
+            };
+            let Some((hook, command)) = config.hooks.for_operation(operation) else {
+                return;
+            };
+            (hook, command.to_string(), config.root.clone())
+        };
+        let mut process = tokio::process::Command::new("sh");
+        process
+            .arg("-c")
+            .arg(&command)
+            .current_dir(root)
+            .env(EMU_PLATFORM, device.platform.short_name())
+            .env(EMU_DEVICE_NAME, device_name)
+            .env(EMU_DEVICE_ID, &device.identifier)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+
+        let state = Arc::clone(state);
+        tasks::spawn(async move {
+            let result = match process.output().await {
+                Ok(output) if output.status.success() => Ok(()),
+                Ok(output) => {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    Err(stderr
+                        .lines()
+                        .rev()
+                        .find(|line| !line.trim().is_empty())
+                        .map(str::to_string)
+                        .unwrap_or_else(|| output.status.to_string()))
+                }
+                Err(e) => Err(e.to_string()),
+            };
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => {
+                    state.add_info_notification(PROJECT_HOOK_FINISHED.replace("{hook}", hook))
+                }
+                Err(error) => state.add_error_notification(
+                    PROJECT_HOOK_FAILED
+                        .replace("{hook}", hook)
+                        .replace("{error}", &error),
+                ),
+            }
+        });
+    }
+}
@@ -0,0 +1,152 @@
+use super::{state::SystemPropertiesDialog, App, AppState, Mode};
+use crate::constants::messages::notifications::{
+    FOCUS_NOT_RUNNING, PROPERTY_READ_ONLY, PROPERTY_SET, PROPERTY_SET_FAILED,
+};
+use crate::managers::AndroidManager;
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the system property browser for the selected running AVD.
+    pub(super) async fn open_system_properties(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().system_properties {
+                return;
+            }
+            let Some(device) = state.selected_android_device() else {
+                return;
+            };
+            let device_name = device.name.clone();
+            if !device.is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+                return;
+            }
+            state.system_properties_dialog = Some(SystemPropertiesDialog::new(device_name));
+            state.mode = Mode::SystemProperties;
+        }
+
+        self.reload_system_properties();
+    }
+
+    pub(super) async fn handle_system_properties_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.system_properties_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc if dialog.edit_value.is_some() => dialog.edit_value = None,
+            KeyCode::Esc => {
+                state.system_properties_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                drop(state);
+                self.reload_system_properties();
+            }
+            KeyCode::Up => dialog.select_previous(),
+            KeyCode::Down => dialog.select_next(),
+            KeyCode::Enter => match dialog.edit_value.take() {
+                Some(value) => {
+                    let Some(name) = dialog.selected_property().map(|p| p.name.clone()) else {
+                        return;
+                    };
+                    let device_name = dialog.device_name.clone();
+                    drop(state);
+                    self.set_system_property(device_name, name, value);
+                }
+                None => {
+                    if !dialog.start_edit() {
+                        if let Some(name) = dialog.selected_property().map(|p| p.name.clone()) {
+                            state.add_info_notification(PROPERTY_READ_ONLY.replace("{}", &name));
+                        }
+                    }
+                }
+            },
+            KeyCode::Backspace => {
+                dialog.active_input().pop();
+                if dialog.edit_value.is_none() {
+                    dialog.filter_changed();
+                }
+            }
+            KeyCode::Char(c) => {
+                dialog.active_input().push(c);
+                if dialog.edit_value.is_none() {
+                    dialog.filter_changed();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Lists the properties of the browsed AVD in the background.
+    fn reload_system_properties(&self) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            load_system_properties(state, &android_manager).await;
+        });
+    }
+
+    /// Sets a property in the background, then lists the properties again
+    /// so the browser shows the value the device actually kept.
+    fn set_system_property(&self, device_name: String, name: String, value: String) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager
+                .set_property(&device_name, &name, &value)
+                .await;
+            {
+                let mut state = state.lock().await;
+                match result {
+                    Ok(()) => state.add_success_notification(
+                        PROPERTY_SET
+                            .replacen("{}", &name, 1)
+                            .replacen("{}", &value, 1)
+                            .replacen("{}", &device_name, 1),
+                    ),
+                    Err(error) => state.add_error_notification(
+                        PROPERTY_SET_FAILED.replacen("{}", &name, 1).replacen(
+                            "{}",
+                            &format_user_error(&error),
+                            1,
+                        ),
+                    ),
+                }
+            }
+            load_system_properties(state, &android_manager).await;
+        });
+    }
+}
+
+/// Loads the properties of the open browser and stores them unless the
+/// browser was closed or opened for another AVD meanwhile.
+async fn load_system_properties(state: Arc<Mutex<AppState>>, android_manager: &AndroidManager) {
+    let device_name = {
+        let state = state.lock().await;
+        state
+            .system_properties_dialog
+            .as_ref()
+            .map(|dialog| dialog.device_name.clone())
+    };
+    let Some(device_name) = device_name else {
+        return;
+    };
+
+    let properties = android_manager.list_properties(&device_name).await;
+
+    let mut state = state.lock().await;
+    if let Some(dialog) = state
+        .system_properties_dialog
+        .as_mut()
+        .filter(|dialog| dialog.device_name == device_name)
+    {
+        dialog.set_properties(properties.map_err(|e| format_user_error(&e)));
+    }
+}
@@ -0,0 +1,176 @@
+use super::{state, App, Mode, Panel};
+use crate::constants::messages::notifications::{
+    PRUNE_FAILED, PRUNE_IN_PROGRESS, PRUNE_NONE_CONFIRMED, PRUNE_NOTHING_UNAVAILABLE,
+    PRUNE_PARTIAL, PRUNE_SUCCEEDED,
+};
+use crate::managers::common::DeviceManager;
+use crate::models::{error::format_user_error, BatchConfirmation};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Shows which simulators `simctl delete unavailable` would remove, or
+    /// removes them right away when the confirmation policy does not ask.
+    pub(super) async fn open_prune_unavailable(&mut self) {
+        let mut state = self.state.lock().await;
+        let devices: Vec<_> = state
+            .ios_devices
+            .iter()
+            .filter(|device| !device.is_available)
+            .cloned()
+            .collect();
+
+        if devices.is_empty() {
+            state.add_info_notification(PRUNE_NOTHING_UNAVAILABLE.to_string());
+            return;
+        }
+
+        let policy = state.confirmation_policy();
+        let any_running = devices.iter().any(|device| device.is_running);
+        let one_at_a_time = policy.batch == BatchConfirmation::Each;
+        state.prune_unavailable_dialog =
+            Some(state::PruneUnavailableDialog::new(devices, one_at_a_time));
+        if policy.prune_unavailable.requires_confirmation(any_running) {
+            state.mode = Mode::PruneUnavailable;
+            return;
+        }
+        if let Some(dialog) = state.prune_unavailable_dialog.as_mut() {
+            dialog.one_at_a_time = false;
+        }
+        state.set_device_operation_status(PRUNE_IN_PROGRESS.to_string());
+        drop(state);
+        self.execute_prune_unavailable().await;
+    }
+
+    pub(super) async fn handle_prune_unavailable_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let one_at_a_time = state
+            .prune_unavailable_dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.one_at_a_time);
+        if one_at_a_time && key.code != KeyCode::Esc {
+            let delete = match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => true,
+                KeyCode::Char('n') | KeyCode::Char('N') => false,
+                _ => return,
+            };
+            let Some(dialog) = state.prune_unavailable_dialog.as_mut() else {
+                return;
+            };
+            if !dialog.answer(delete) {
+                return;
+            }
+            let none_accepted = dialog.accepted.is_empty();
+            state.mode = Mode::Normal;
+            if none_accepted {
+                state.prune_unavailable_dialog = None;
+                state.add_info_notification(PRUNE_NONE_CONFIRMED.to_string());
+                return;
+            }
+            state.set_device_operation_status(PRUNE_IN_PROGRESS.to_string());
+            drop(state);
+            self.execute_prune_unavailable().await;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                state.mode = Mode::Normal;
+                state.set_device_operation_status(PRUNE_IN_PROGRESS.to_string());
+                drop(state);
+                self.execute_prune_unavailable().await;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                state.mode = Mode::Normal;
+                state.prune_unavailable_dialog = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                if let Some(dialog) = state.prune_unavailable_dialog.as_mut() {
+                    dialog.scroll_up();
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if let Some(dialog) = state.prune_unavailable_dialog.as_mut() {
+                    dialog.scroll_down();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn execute_prune_unavailable(&mut self) {
+        let Some(dialog) = self.state.lock().await.prune_unavailable_dialog.take() else {
+            return;
+        };
+
+        let result = match self.ios_manager {
+            Some(ref ios_manager) if dialog.one_at_a_time => {
+                delete_each(ios_manager, &dialog.accepted).await
+            }
+            Some(ref ios_manager) => ios_manager.prune_unavailable_devices().await,
+            None => Err(anyhow::anyhow!("iOS manager not available")),
+        };
+
+        let mut state = self.state.lock().await;
+        state.clear_device_operation_status();
+        match result {
+            Ok(removed) => {
+                state
+                    .ios_devices
+                    .retain(|device| !removed.contains(&device.udid));
+                if state.selected_ios >= state.ios_devices.len() {
+                    state.selected_ios = state.ios_devices.len().saturating_sub(1);
+                }
+                for udid in &removed {
+                    state
+                        .device_history
+                        .remove_device(&Panel::Ios.device_id(udid));
+                    state
+                        .device_annotations
+                        .remove_device(&Panel::Ios.device_id(udid));
+                }
+                state.device_list_updated(Panel::Ios);
+
+                let expected = if dialog.one_at_a_time {
+                    dialog.accepted.len()
+                } else {
+                    dialog.devices.len()
+                };
+                let message = if removed.len() >= expected {
+                    PRUNE_SUCCEEDED.replace("{}", &removed.len().to_string())
+                } else {
+                    PRUNE_PARTIAL
+                        .replacen("{}", &removed.len().to_string(), 1)
+                        .replacen("{}", &expected.to_string(), 1)
+                };
+                state.add_success_notification(message);
+            }
+            Err(error) => {
+                state
+                    .add_error_notification(PRUNE_FAILED.replace("{}", &format_user_error(&error)));
+            }
+        }
+    }
+}
+
+/// Deletes the simulators confirmed one at a time, returning the UDIDs
+/// that were removed. Fails only when none of them could be.
+async fn delete_each(
+    ios_manager: &crate::managers::IosManager,
+    udids: &[String],
+) -> anyhow::Result<Vec<String>> {
+    let mut removed = Vec::new();
+    let mut last_error = None;
+    for udid in udids {
+        match ios_manager.delete_device(udid).await {
+            Ok(()) => removed.push(udid.clone()),
+            Err(error) => {
+                log::warn!("Failed to delete unavailable simulator {udid}: {error}");
+                last_error = Some(error);
+            }
+        }
+    }
+    match last_error {
+        Some(error) if removed.is_empty() => Err(error),
+        _ => Ok(removed),
+    }
+}
@@ -0,0 +1,172 @@
+use super::{state::QuickBootDialog, App, AppState, Mode};
+use crate::constants::messages::notifications::{
+    ALWAYS_COLD_BOOT_OFF, ALWAYS_COLD_BOOT_ON, COLD_BOOT_ONCE_OFF, COLD_BOOT_ONCE_ON,
+    QUICK_BOOT_FAILED, QUICK_BOOT_SNAPSHOT_DELETED,
+};
+use crate::managers::AndroidManager;
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the Quick Boot options of the selected AVD.
+    pub(super) async fn open_quick_boot_options(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().snapshots {
+                return;
+            }
+            let Some(device) = state.selected_android_device() else {
+                return;
+            };
+            state.quick_boot_dialog = Some(QuickBootDialog::new(device.name.clone()));
+            state.mode = Mode::QuickBoot;
+        }
+
+        self.reload_quick_boot_status();
+    }
+
+    pub(super) async fn handle_quick_boot_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.quick_boot_dialog.as_ref() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+        let device_name = dialog.device_name.clone();
+        let Some(status) = dialog.status.clone() else {
+            if key.code == KeyCode::Esc {
+                state.quick_boot_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.quick_boot_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Char('o') => {
+                let enabled = !status.cold_boot_once;
+                self.android_manager
+                    .set_cold_boot_once(&device_name, enabled);
+                let message = if enabled {
+                    COLD_BOOT_ONCE_ON
+                } else {
+                    COLD_BOOT_ONCE_OFF
+                };
+                state.add_info_notification(message.replace("{}", &device_name));
+                drop(state);
+                self.reload_quick_boot_status();
+            }
+            KeyCode::Char('a') => {
+                drop(state);
+                self.change_quick_boot(
+                    device_name,
+                    QuickBootChange::AlwaysColdBoot(!status.always_cold_boot),
+                );
+            }
+            KeyCode::Char('d') if status.snapshot_size.is_some() => {
+                drop(state);
+                self.change_quick_boot(device_name, QuickBootChange::DeleteSnapshot);
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies a change to the settings on disk in the background, then
+    /// reads them again.
+    fn change_quick_boot(&self, device_name: String, change: QuickBootChange) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let (result, message) = match change {
+                QuickBootChange::AlwaysColdBoot(enabled) => (
+                    android_manager
+                        .set_always_cold_boot(&device_name, enabled)
+                        .await,
+                    if enabled {
+                        ALWAYS_COLD_BOOT_ON
+                    } else {
+                        ALWAYS_COLD_BOOT_OFF
+                    },
+                ),
+                QuickBootChange::DeleteSnapshot => (
+                    android_manager
+                        .delete_quick_boot_snapshot(&device_name)
+                        .await,
+                    QUICK_BOOT_SNAPSHOT_DELETED,
+                ),
+            };
+            {
+                let mut state = state.lock().await;
+                match result {
+                    Ok(()) => state.add_success_notification(message.replace("{}", &device_name)),
+                    Err(error) => state.add_error_notification(
+                        QUICK_BOOT_FAILED.replacen("{}", &device_name, 1).replacen(
+                            "{}",
+                            &format_user_error(&error),
+                            1,
+                        ),
+                    ),
+                }
+            }
+            load_quick_boot_status(state, &android_manager).await;
+        });
+    }
+
+    fn reload_quick_boot_status(&self) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            load_quick_boot_status(state, &android_manager).await;
+        });
+    }
+}
+
+enum QuickBootChange {
+    AlwaysColdBoot(bool),
+    DeleteSnapshot,
+}
+
+/// Reads the Quick Boot settings of the AVD the dialog shows, closing the
+/// dialog when they cannot be read.
+async fn load_quick_boot_status(state: Arc<Mutex<AppState>>, android_manager: &AndroidManager) {
+    let Some(device_name) = state
+        .lock()
+        .await
+        .quick_boot_dialog
+        .as_ref()
+        .map(|dialog| dialog.device_name.clone())
+    else {
+        return;
+    };
+    let result = android_manager.quick_boot_status(&device_name).await;
+
+    let mut state = state.lock().await;
+    let Some(dialog) = state
+        .quick_boot_dialog
+        .as_mut()
+        .filter(|dialog| dialog.device_name == device_name)
+    else {
+        return;
+    };
+    match result {
+        Ok(status) => dialog.status = Some(status),
+        Err(error) => {
+            state.quick_boot_dialog = None;
+            if state.mode == Mode::QuickBoot {
+                state.mode = Mode::Normal;
+            }
+            state.add_error_notification(
+                QUICK_BOOT_FAILED.replacen("{}", &device_name, 1).replacen(
+                    "{}",
+                    &format_user_error(&error),
+                    1,
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use super::{state::RadiosDialog, App, AppState, DetailsTab, Mode};
+use crate::constants::messages::notifications::{
+    FOCUS_NOT_RUNNING, RADIOS_IOS_UNSUPPORTED, RADIO_CHANGE_FAILED, RADIO_DISABLED, RADIO_ENABLED,
+    RADIO_STATUS_FAILED,
+};
+use crate::managers::AndroidManager;
+use crate::models::{error::format_user_error, Radio};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the Wi-Fi, Bluetooth and airplane mode toggles of the selected
+    /// running AVD.
+    pub(super) async fn open_radios_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().radios {
+                if let Some(device) = state.selected_ios_device() {
+                    let message = RADIOS_IOS_UNSUPPORTED.replace("{}", &device.name);
+                    state.add_info_notification(message);
+                }
+                return;
+            }
+            let Some(device) = state.selected_android_device() else {
+                return;
+            };
+            let (device_name, is_running) = (device.name.clone(), device.is_running);
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+                return;
+            }
+            state.radios_dialog = Some(RadiosDialog::new(device_name));
+            state.mode = Mode::Radios;
+        }
+
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            load_radio_status(state, &android_manager).await;
+        });
+    }
+
+    pub(super) async fn handle_radios_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.radios_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        let radio = match key.code {
+            KeyCode::Esc => {
+                state.radios_dialog = None;
+                state.mode = Mode::Normal;
+                return;
+            }
+            KeyCode::Char('w') => Radio::Wifi,
+            KeyCode::Char('b') => Radio::Bluetooth,
+            KeyCode::Char('a') => Radio::AirplaneMode,
+            _ => return,
+        };
+        // One switch at a time; the state is read again after each
+        let Some(status) = dialog.status.filter(|_| dialog.switching.is_none()) else {
+            return;
+        };
+        dialog.switching = Some(radio);
+        let enabled = !status.get(radio).unwrap_or(false);
+        let device_name = dialog.device_name.clone();
+        drop(state);
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = android_manager
+                .set_radio(&device_name, radio, enabled)
+                .await;
+            {
+                let mut state = state.lock().await;
+                if let Some(dialog) = state.radios_dialog.as_mut() {
+                    dialog.switching = None;
+                }
+                match result {
+                    Ok(()) => {
+                        let message = if enabled {
+                            RADIO_ENABLED
+                        } else {
+                            RADIO_DISABLED
+                        };
+                        state.add_success_notification(
+                            message.replacen("{}", radio.label(), 1).replacen(
+                                "{}",
+                                &device_name,
+                                1,
+                            ),
+                        );
+                    }
+                    Err(error) => state.add_error_notification(
+                        RADIO_CHANGE_FAILED
+                            .replacen("{}", radio.label(), 1)
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    ),
+                }
+            }
+            load_radio_status(Arc::clone(&state), &android_manager).await;
+            let shows_sensors = state.lock().await.details_tab == DetailsTab::Sensors;
+            if shows_sensors {
+                Self::load_details_tab_internal(state, &android_manager, ios_manager.as_ref())
+                    .await;
+            }
+        });
+    }
+}
+
+/// Reads the radios of the AVD the dialog shows, closing the dialog when
+/// they cannot be read.
+async fn load_radio_status(state: Arc<Mutex<AppState>>, android_manager: &AndroidManager) {
+    let Some(device_name) = state
+        .lock()
+        .await
+        .radios_dialog
+        .as_ref()
+        .map(|dialog| dialog.device_name.clone())
+    else {
+        return;
+    };
+    let result = android_manager.radio_status(&device_name).await;
+
+    let mut state = state.lock().await;
+    let Some(dialog) = state
+        .radios_dialog
+        .as_mut()
+        .filter(|dialog| dialog.device_name == device_name)
+    else {
+        return;
+    };
+    match result {
+        Ok(status) => dialog.status = Some(status),
+        Err(error) => {
+            state.radios_dialog = None;
+            if state.mode == Mode::Radios {
+                state.mode = Mode::Normal;
+            }
+            state.add_error_notification(
+                RADIO_STATUS_FAILED
+                    .replacen("{}", &device_name, 1)
+                    .replacen("{}", &format_user_error(&error), 1),
+            );
+        }
+    }
+}
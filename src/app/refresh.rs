@@ -1,10 +1,108 @@
 use super::{App, Panel};
-use crate::managers::common::DeviceManager;
-use crate::models::{device_info::sort_android_devices_for_display, AndroidDevice, IosDevice};
+use crate::constants::{
+    messages::notifications::{DEVICE_START_STALLED, EMULATOR_LAUNCH_FAILED, MIRRORING_FAILED},
+    timeouts::DEVICE_START_STALL_TIMEOUT,
+};
+use crate::managers::{common::DeviceManager, IosManager};
+use crate::models::{
+    device_info::sort_android_devices_for_display, AndroidDevice, DeviceId, IosDevice,
+    ManagedDevice, Platform,
+};
 use anyhow::Result;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 impl App {
+    /// The iOS manager, once the background probe found working Xcode
+    /// tooling. Refreshes leave the iOS list alone until then.
+    pub(super) async fn ready_ios_manager(&self) -> Option<IosManager> {
+        let ios_manager = self.ios_manager.clone()?;
+        self.state
+            .lock()
+            .await
+            .ios_tooling
+            .is_ready()
+            .then_some(ios_manager)
+    }
+
+    /// The manager for `platform`, if this host supports it.
+    pub(super) fn device_manager(&self, platform: Platform) -> Option<Arc<dyn DeviceManager>> {
+        self.device_managers
+            .iter()
+            .find(|manager| manager.platform() == platform)
+            .cloned()
+    }
+
+    /// The managers whose devices can be listed right now; iOS joins once
+    /// its tooling probe succeeded.
+    pub(super) async fn ready_device_managers(&self) -> Vec<Arc<dyn DeviceManager>> {
+        let ios_ready = self.state.lock().await.ios_tooling.is_ready();
+        self.device_managers
+            .iter()
+            .filter(|manager| manager.platform() != Platform::Ios || ios_ready)
+            .cloned()
+            .collect()
+    }
+
+    /// Reports emulators that exited with an error since the last check,
+    /// which otherwise just never show up as running, and AVDs that did not
+    /// come up in time. Both get a launch diagnosis. Also drops the recording
+    /// indicator once the recording scrcpy window is closed.
+    pub(super) async fn report_emulator_launch_failures(&self) {
+        let failures = self.android_manager.take_launch_failures();
+        let mut state = self.state.lock().await;
+        let pending_is_android = state
+            .get_pending_device_start()
+            .is_some_and(|name| state.android_devices.iter().any(|d| &d.name == name));
+        let stalled = if pending_is_android {
+            state.take_stalled_device_start(DEVICE_START_STALL_TIMEOUT)
+        } else {
+            None
+        };
+        if let Some(avd_name) = &stalled {
+            state
+                .reconciliation_journal
+                .forget(&DeviceId::android(avd_name));
+            state.add_error_notification(
+                DEVICE_START_STALLED.replacen("{}", avd_name, 1).replacen(
+                    "{}",
+                    &DEVICE_START_STALL_TIMEOUT.as_secs().to_string(),
+                    1,
+                ),
+            );
+        }
+        for failure in &failures {
+            // Reported here, with the reason
+            state
+                .reconciliation_journal
+                .forget(&DeviceId::android(&failure.avd_name));
+            if state.get_pending_device_start() == Some(&failure.avd_name) {
+                state.clear_pending_device_start();
+            }
+            state.add_error_notification(
+                EMULATOR_LAUNCH_FAILED
+                    .replacen("{}", &failure.avd_name, 1)
+                    .replacen("{}", &failure.reason, 1),
+            );
+        }
+        for failure in self.android_manager.take_mirror_failures() {
+            state.add_error_notification(
+                MIRRORING_FAILED
+                    .replacen("{}", &failure.avd_name, 1)
+                    .replacen("{}", &failure.reason, 1),
+            );
+        }
+        state.active_recording = self.android_manager.recording_mirror();
+        drop(state);
+
+        if let Some(avd_name) = stalled {
+            self.diagnose_launch_failure(avd_name, None);
+        }
+        for failure in failures {
+            self.diagnose_launch_failure(failure.avd_name, Some(failure.reason));
+        }
+    }
+
     /// Refresh devices using incremental update for optimal performance
     pub(super) async fn refresh_devices_smart(&mut self) -> Result<()> {
         let (has_android_devices, has_ios_devices, pending_device) = {
@@ -19,7 +117,7 @@ impl App {
         let should_full_refresh = Self::should_use_full_device_refresh(
             has_android_devices,
             has_ios_devices,
-            self.ios_manager.is_some(),
+            self.ready_ios_manager().await.is_some(),
             pending_device.is_some(),
             self.last_full_device_refresh.elapsed(),
         );
@@ -50,27 +148,33 @@ impl App {
             (existing_android, existing_ios, pending_device)
         };
 
-        let new_android_devices;
-        let new_ios_devices;
-        if let Some(ios_manager) = self.ios_manager.clone() {
-            let (android_devices, ios_devices) = tokio::try_join!(
-                self.android_manager.list_devices(),
-                ios_manager.list_devices()
-            )?;
-            new_android_devices = android_devices;
-            new_ios_devices = ios_devices;
-        } else {
-            new_android_devices = self.android_manager.list_devices().await?;
-            new_ios_devices = Vec::new();
+        let managers = self.ready_device_managers().await;
+        let ios_detecting = self.ios_manager.is_some()
+            && !managers
+                .iter()
+                .any(|manager| manager.platform() == Platform::Ios);
+        let device_lists =
+            futures::future::try_join_all(managers.iter().map(|manager| manager.list_devices()))
+                .await?;
+
+        let mut new_android_devices = Vec::new();
+        let mut new_ios_devices = Vec::new();
+        for device in device_lists.into_iter().flatten() {
+            match device {
+                ManagedDevice::Android(device) => new_android_devices.push(device),
+                ManagedDevice::Ios(device) => new_ios_devices.push(device),
+            }
         }
 
         let mut updated_android =
             self.process_android_updates(existing_android, new_android_devices);
         sort_android_devices_for_display(&mut updated_android);
         let updated_ios = self.process_ios_updates(existing_ios, new_ios_devices);
+        let adb_server_healthy = self.android_manager.adb_server_health().await;
 
         {
             let mut state = self.state.lock().await;
+            state.adb_server_healthy = adb_server_healthy;
             let mut device_started = None;
             if let Some(ref pending_name) = pending_device {
                 let device_running = updated_android
@@ -91,7 +195,11 @@ impl App {
             }
 
             state.android_devices = updated_android;
-            state.ios_devices = updated_ios;
+            state.device_list_updated(Panel::Android);
+            if !ios_detecting {
+                state.ios_devices = updated_ios;
+                state.device_list_updated(Panel::Ios);
+            }
 
             if state.selected_android >= state.android_devices.len() {
                 state.selected_android = state.android_devices.len().saturating_sub(1);
@@ -145,13 +253,14 @@ impl App {
             )
         };
 
+        let ios_manager = self.ready_ios_manager().await;
         let should_refresh_android = !existing_android.is_empty();
-        let should_refresh_ios = !existing_ios.is_empty();
+        let should_refresh_ios = !existing_ios.is_empty() && ios_manager.is_some();
 
         let running_avds;
         let new_ios_devices;
         if should_refresh_android && should_refresh_ios {
-            if let Some(ios_manager) = self.ios_manager.clone() {
+            if let Some(ios_manager) = ios_manager {
                 let (android_running_avds, ios_devices) = tokio::try_join!(
                     self.android_manager.get_running_avd_names(),
                     ios_manager.list_devices()
@@ -167,7 +276,7 @@ impl App {
             new_ios_devices = Vec::new();
         } else if should_refresh_ios {
             running_avds = HashMap::new();
-            new_ios_devices = if let Some(ref ios_manager) = self.ios_manager {
+            new_ios_devices = if let Some(ref ios_manager) = ios_manager {
                 ios_manager.list_devices().await?
             } else {
                 Vec::new()
@@ -181,10 +290,16 @@ impl App {
             self.process_android_status_updates(existing_android, &running_avds);
         sort_android_devices_for_display(&mut updated_android);
         let updated_ios = self.process_ios_updates(existing_ios, new_ios_devices);
+        let adb_server_healthy = self.android_manager.adb_server_health().await;
 
         let mut state = self.state.lock().await;
+        state.adb_server_healthy = adb_server_healthy;
         state.android_devices = updated_android;
-        state.ios_devices = updated_ios;
+        state.device_list_updated(Panel::Android);
+        if should_refresh_ios {
+            state.ios_devices = updated_ios;
+            state.device_list_updated(Panel::Ios);
+        }
 
         if state.selected_android >= state.android_devices.len() {
             state.selected_android = state.android_devices.len().saturating_sub(1);
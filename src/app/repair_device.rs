@@ -0,0 +1,107 @@
+use super::{state, App, Mode, Panel};
+use crate::constants::messages::notifications::{
+    REPAIR_NOT_NEEDED, RUNTIME_INSTALL_FAILED, RUNTIME_INSTALL_STARTED, RUNTIME_INSTALL_SUCCEEDED,
+};
+use crate::models::error::format_user_error;
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Opens the repair options for the selected simulator when simctl
+    /// reports it as unavailable.
+    pub(super) async fn open_repair_dialog(&mut self) {
+        let mut state = self.state.lock().await;
+        if !state.active_capabilities().repair {
+            return;
+        }
+        let Some(device) = state.ios_devices.get(state.selected_ios) else {
+            return;
+        };
+
+        match device.unavailable_reason() {
+            Some(reason) => {
+                let dialog = state::RepairDeviceDialog {
+                    device_name: device.name.clone(),
+                    udid: device.udid.clone(),
+                    ios_version: device.ios_version.clone(),
+                    reason,
+                };
+                state.repair_device_dialog = Some(dialog);
+                state.mode = Mode::RepairDevice;
+            }
+            None => {
+                let message = REPAIR_NOT_NEEDED.replace("{}", &device.name);
+                state.add_info_notification(message);
+            }
+        }
+    }
+
+    pub(super) async fn handle_repair_device_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.repair_device_dialog.clone() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char('i') if dialog.can_install_runtime() => {
+                state.repair_device_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.install_missing_runtime(dialog.ios_version).await;
+            }
+            KeyCode::Char('d') => {
+                // Deleting goes through the usual confirmation
+                state.repair_device_dialog = None;
+                state.confirm_delete_dialog = Some(state::ConfirmDeleteDialog {
+                    device_name: dialog.device_name,
+                    device_identifier: dialog.udid,
+                    platform: Panel::Ios,
+                });
+                state.mode = Mode::ConfirmDelete;
+            }
+            KeyCode::Esc => {
+                state.repair_device_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Installs the runtime an unavailable simulator was created with in the
+    /// background, then refreshes the device lists so it shows as available.
+    async fn install_missing_runtime(&mut self, version: String) {
+        let Some(ios_manager) = self.ios_manager.clone() else {
+            return;
+        };
+
+        self.state
+            .lock()
+            .await
+            .add_info_notification(RUNTIME_INSTALL_STARTED.replace("{}", &version));
+
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = ios_manager.install_runtime(&version).await;
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => {
+                    state.add_success_notification(
+                        RUNTIME_INSTALL_SUCCEEDED.replace("{}", &version),
+                    );
+                    state.request_manual_refresh();
+                }
+                Err(error) => {
+                    state.add_error_notification(
+                        RUNTIME_INSTALL_FAILED.replacen("{}", &version, 1).replacen(
+                            "{}",
+                            &format_user_error(&error),
+                            1,
+                        ),
+                    );
+                }
+            }
+        });
+    }
+}
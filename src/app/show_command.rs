@@ -0,0 +1,112 @@
+use super::{App, Panel};
+use crate::constants::messages::notifications::{
+    COMMAND_COPIED, COMMAND_COPY_FAILED, COMMAND_NOT_READY,
+};
+use crate::managers::common::DeviceConfig;
+use crate::managers::{AndroidManager, IosManager};
+use crate::utils::command::copy_to_clipboard;
+
+impl App {
+    /// Toggles the start command of the selected device in the details panel,
+    /// copying it to the clipboard when it is shown.
+    pub(super) async fn toggle_device_command(&mut self) {
+        let command = {
+            let mut state = self.state.lock().await;
+            if state.shown_command.take().is_some() {
+                return;
+            }
+
+            let command = match state.active_panel {
+                Panel::Android => {
+                    let name = state
+                        .android_devices
+                        .get(state.selected_android)
+                        .map(|device| device.name.clone());
+                    match name {
+                        // Only reads config.ini, never waits for avdmanager
+                        Some(name) => Some(self.android_manager.start_command(&name).await),
+                        None => None,
+                    }
+                }
+                Panel::Ios => state
+                    .ios_devices
+                    .get(state.selected_ios)
+                    .map(|device| IosManager::boot_command(&device.udid)),
+            };
+            let Some(command) = command else {
+                return;
+            };
+
+            let command = command.to_string();
+            state.shown_command = Some(command.clone());
+            command
+        };
+
+        self.copy_command(command).await;
+    }
+
+    /// Copies the command that the create dialog would run.
+    pub(super) async fn copy_create_device_command(&mut self) {
+        let command = {
+            let state = self.state.lock().await;
+            let form = &state.create_device_form;
+            match state.active_panel {
+                Panel::Android => form.preview.as_ref().map(|preview| {
+                    preview
+                        .command(&AndroidManager::sanitize_avd_name(&form.name))
+                        .to_string()
+                }),
+                Panel::Ios => Some(
+                    IosManager::create_command(&DeviceConfig::new(
+                        form.name.clone(),
+                        form.device_type_id.clone(),
+                        form.version.clone(),
+                    ))
+                    .to_string(),
+                ),
+            }
+        };
+
+        match command {
+            Some(command) => self.copy_command(command).await,
+            None => {
+                let mut state = self.state.lock().await;
+                state.add_warning_notification(COMMAND_NOT_READY.to_string());
+            }
+        }
+    }
+
+    /// Copies the sdkmanager command for the API level selected in the dialog.
+    pub(super) async fn copy_install_command(&mut self) {
+        let package_id = {
+            let state = self.state.lock().await;
+            state
+                .api_level_management
+                .as_ref()
+                .and_then(|api_state| api_state.get_selected_api_level())
+                .and_then(|api_level| api_level.get_recommended_variant())
+                .map(|variant| variant.package_id.clone())
+        };
+        let Some(package_id) = package_id else {
+            return;
+        };
+
+        match self.android_manager.install_command(&package_id) {
+            Ok(command) => self.copy_command(command.to_string()).await,
+            Err(e) => {
+                let mut state = self.state.lock().await;
+                state.add_error_notification(e.to_string());
+            }
+        }
+    }
+
+    async fn copy_command(&self, command: String) {
+        let result = copy_to_clipboard(&command).await;
+
+        let mut state = self.state.lock().await;
+        match result {
+            Ok(()) => state.add_info_notification(COMMAND_COPIED.replace("{}", &command)),
+            Err(_) => state.add_warning_notification(COMMAND_COPY_FAILED.replace("{}", &command)),
+        }
+    }
+}
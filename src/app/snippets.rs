@@ -0,0 +1,209 @@
+use super::{state::SnippetsDialog, App, AppState, Mode, Panel};
+use crate::constants::{
+    env_vars::{EMU_DEVICE_ID, EMU_DEVICE_NAME, EMU_PLATFORM},
+    messages::{
+        errors::SNIPPET_TIMED_OUT,
+        formats::{SNIPPET_LOG_COMMAND, SNIPPET_LOG_LINE},
+        notifications::{SNIPPET_FAILED, SNIPPET_FINISHED, SNIPPET_NONE},
+    },
+    timeouts::SNIPPET_TIMEOUT,
+};
+use crate::models::{error::format_user_error, DeviceId, Platform, Snippet, SnippetTarget};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::Mutex;
+
+impl App {
+    /// Opens the shell snippets of the selected device's platform.
+    pub(super) async fn open_snippets(&mut self) {
+        let mut state = self.state.lock().await;
+        let panel = state.active_panel;
+        let selected = match panel {
+            Panel::Android => state
+                .selected_android_device()
+                .map(|device| (device.name.clone(), device.name.clone())),
+            Panel::Ios => state
+                .ios_devices
+                .get(state.selected_ios)
+                .map(|device| (device.udid.clone(), device.name.clone())),
+        };
+        let Some((identifier, name)) = selected else {
+            return;
+        };
+
+        let snippets = state
+            .project_config
+            .as_ref()
+            .map(|config| config.snippets.clone())
+            .unwrap_or_default()
+            .for_platform(panel.into());
+        if snippets.is_empty() {
+            state.add_info_notification(SNIPPET_NONE.to_string());
+            return;
+        }
+        state.snippets_dialog = Some(SnippetsDialog::new(
+            panel.device_id(&identifier),
+            name,
+            snippets,
+        ));
+        state.mode = Mode::Snippets;
+    }
+
+    pub(super) async fn handle_snippets_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.snippets_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.snippets_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Up | KeyCode::Char('k') => dialog.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.move_down(),
+            KeyCode::Enter => {
+                let Some(snippet) = dialog.selected_snippet().cloned() else {
+                    return;
+                };
+                let device = dialog.device.clone();
+                let device_name = dialog.device_name.clone();
+                state.snippets_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.run_snippet(snippet, device, device_name);
+            }
+            _ => {}
+        }
+    }
+
+    /// Runs a snippet in the background from the project directory, with
+    /// its output going to the log panel and the device described through
+    /// the same `EMU_*` variables as the project hooks.
+    fn run_snippet(&self, snippet: Snippet, device: DeviceId, device_name: String) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let serial = match device.platform {
+                Platform::Android => android_manager
+                    .get_running_avd_names()
+                    .await
+                    .ok()
+                    .and_then(|running| running.get(&device.identifier).cloned()),
+                Platform::Ios => None,
+            };
+            let target = SnippetTarget {
+                platform: device.platform,
+                identifier: device.identifier.clone(),
+                name: device_name.clone(),
+                serial,
+            };
+
+            let result = match snippet.render(&target) {
+                Ok(command) => {
+                    let dir = {
+                        let mut state = state.lock().await;
+                        state.add_log(
+                            "INFO".to_string(),
+                            SNIPPET_LOG_COMMAND.replace("{}", &command),
+                        );
+                        state
+                            .project_config
+                            .as_ref()
+                            .map(|config| config.root.clone())
+                            .filter(|root| !root.as_os_str().is_empty())
+                    };
+                    let mut process = tokio::process::Command::new("sh");
+                    process
+                        .arg("-c")
+                        .arg(&command)
+                        .env(EMU_PLATFORM, device.platform.short_name())
+                        .env(EMU_DEVICE_NAME, &device_name)
+                        .env(EMU_DEVICE_ID, &device.identifier);
+                    run_with_output(process, dir, &state).await
+                }
+                Err(error) => Err(format_user_error(&error)),
+            };
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(()) => state.add_success_notification(
+                    SNIPPET_FINISHED.replacen("{}", &snippet.name, 1).replacen(
+                        "{}",
+                        &device_name,
+                        1,
+                    ),
+                ),
+                Err(error) => state.add_error_notification(
+                    SNIPPET_FAILED
+                        .replacen("{}", &snippet.name, 1)
+                        .replacen("{}", &device_name, 1)
+                        .replacen("{}", &error, 1),
+                ),
+            }
+        });
+    }
+}
+
+/// Runs `process`, adding its stdout and stderr lines to the log panel as
+/// they come. Fails with the last stderr line when it exits unsuccessfully,
+/// and kills it after `SNIPPET_TIMEOUT`.
+async fn run_with_output(
+    mut process: tokio::process::Command,
+    dir: Option<PathBuf>,
+    state: &Arc<Mutex<AppState>>,
+) -> Result<(), String> {
+    if let Some(dir) = dir {
+        process.current_dir(dir);
+    }
+    process
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let run = async {
+        let mut child = process.spawn().map_err(|e| e.to_string())?;
+        let (_, last_error, status) = tokio::join!(
+            show_lines(child.stdout.take(), "INFO", state),
+            show_lines(child.stderr.take(), "WARN", state),
+            child.wait()
+        );
+        match status {
+            Ok(status) if status.success() => Ok(()),
+            Ok(status) => Err(last_error.unwrap_or_else(|| status.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    };
+    tokio::time::timeout(SNIPPET_TIMEOUT, run)
+        .await
+        .unwrap_or_else(|_| {
+            Err(SNIPPET_TIMED_OUT.replace("{}", &SNIPPET_TIMEOUT.as_secs().to_string()))
+        })
+}
+
+/// Adds each line of `reader` to the log panel and returns the last
+/// non-empty one.
+async fn show_lines(
+    reader: Option<impl AsyncRead + Unpin>,
+    level: &str,
+    state: &Arc<Mutex<AppState>>,
+) -> Option<String> {
+    let mut lines = BufReader::new(reader?).lines();
+    let mut last = None;
+    while let Ok(Some(line)) = lines.next_line().await {
+        if !line.trim().is_empty() {
+            last = Some(line.clone());
+        }
+        state
+            .lock()
+            .await
+            .add_log(level.to_string(), SNIPPET_LOG_LINE.replace("{}", &line));
+    }
+    last
+}
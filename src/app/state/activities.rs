@@ -0,0 +1,70 @@
+use super::{AppState, Panel};
+use crate::models::DeviceId;
+
+/// An operation running against a device in the background.
+#[derive(Debug, Clone)]
+pub struct DeviceActivity {
+    /// Identifier the operation ends the activity with
+    pub id: u64,
+    pub device: DeviceId,
+    /// What runs, shown on the device's row
+    pub label: &'static str,
+}
+
+/// Operations running against devices, in the order they began, so a row
+/// shows why the device is busy.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceActivities {
+    pub items: Vec<DeviceActivity>,
+    next_id: u64,
+}
+
+impl DeviceActivities {
+    /// Records an operation that began on `device` and returns the
+    /// identifier that ends it.
+    pub fn begin(&mut self, device: DeviceId, label: &'static str) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push(DeviceActivity { id, device, label });
+        id
+    }
+
+    pub fn end(&mut self, id: u64) {
+        self.items.retain(|activity| activity.id != id);
+    }
+
+    /// The operation that began last on `device`, if any still runs.
+    pub fn latest(&self, device: &DeviceId) -> Option<&'static str> {
+        self.items
+            .iter()
+            .rev()
+            .find(|activity| activity.device == *device)
+            .map(|activity| activity.label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl AppState {
+    /// The operation that began last on a device, if any still runs.
+    pub fn device_activity(&self, panel: Panel, identifier: &str) -> Option<&'static str> {
+        self.device_activities.latest(&panel.device_id(identifier))
+    }
+
+    /// Whether the device is the one whose start is pending. The pending
+    /// start is kept by display name.
+    pub fn is_device_booting(&self, panel: Panel, identifier: &str) -> bool {
+        let Some(pending) = &self.pending_device_start else {
+            return false;
+        };
+        match panel {
+            Panel::Android => pending == identifier,
+            Panel::Ios => self
+                .ios_devices
+                .iter()
+                .any(|device| device.udid == identifier && device.name == *pending),
+        }
+    }
+}
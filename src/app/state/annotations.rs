@@ -0,0 +1,136 @@
+use super::{AnnotationDialog, AnnotationField, AppState, Mode, Panel};
+use crate::managers::AndroidManager;
+use crate::models::{matrix, DeviceAnnotation};
+
+impl AppState {
+    /// Tags and note attached to a device, if any.
+    pub fn device_annotation(&self, panel: Panel, identifier: &str) -> Option<&DeviceAnnotation> {
+        self.device_annotations.get(&panel.device_id(identifier))
+    }
+
+    /// Opens the tags and note editor for the selected device.
+    pub fn open_annotation_dialog(&mut self) {
+        let selected = match self.active_panel {
+            Panel::Android => self
+                .android_devices
+                .get(self.selected_android)
+                .map(|device| (device.name.clone(), device.name.clone())),
+            Panel::Ios => self
+                .ios_devices
+                .get(self.selected_ios)
+                .map(|device| (device.name.clone(), device.udid.clone())),
+        };
+        let Some((device_name, device_identifier)) = selected else {
+            return;
+        };
+
+        let existing = self
+            .device_annotation(self.active_panel, &device_identifier)
+            .cloned()
+            .unwrap_or_default();
+        self.annotation_dialog = Some(AnnotationDialog {
+            device_name,
+            device_identifier,
+            platform: self.active_panel,
+            tags_input: existing.tags.join(", "),
+            note_input: existing.note,
+            active_field: AnnotationField::Tags,
+        });
+        self.mode = Mode::EditAnnotation;
+    }
+
+    /// Saves the editor contents and closes it.
+    pub fn save_annotation_dialog(&mut self) {
+        if let Some(dialog) = self.annotation_dialog.take() {
+            self.device_annotations.set(
+                &dialog.platform.device_id(&dialog.device_identifier),
+                DeviceAnnotation::from_input(&dialog.tags_input, &dialog.note_input),
+            );
+        }
+        self.mode = Mode::Normal;
+    }
+
+    /// Puts the device named `name` into a test matrix group. Android names
+    /// are matched the way avdmanager stores them.
+    pub fn add_to_matrix_group(&mut self, panel: Panel, name: &str, group: &str) {
+        let identifier = match panel {
+            Panel::Android => {
+                let avd_name = AndroidManager::sanitize_avd_name(name);
+                self.android_devices
+                    .iter()
+                    .find(|device| device.name == avd_name)
+                    .map(|device| device.name.clone())
+            }
+            Panel::Ios => self
+                .ios_devices
+                .iter()
+                .find(|device| device.name == name)
+                .map(|device| device.udid.clone()),
+        };
+        let Some(identifier) = identifier else {
+            return;
+        };
+        let mut annotation = self
+            .device_annotation(panel, &identifier)
+            .cloned()
+            .unwrap_or_default();
+        annotation.add_tag(&matrix::group_tag(group));
+        self.device_annotations
+            .set(&panel.device_id(identifier), annotation);
+    }
+
+    /// Finds the next device in the active panel whose name, tags, or note
+    /// contain the search query. The search wraps around and starts at the
+    /// current device unless `skip_current` is set.
+    pub fn find_search_match(&self, skip_current: bool) -> Option<usize> {
+        let query = self.search_query.trim().to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+
+        let panel = self.active_panel;
+        let candidates: Vec<(String, &str)> = match panel {
+            Panel::Android => self
+                .android_devices
+                .iter()
+                .map(|device| (device.name.replace('_', " "), device.name.as_str()))
+                .collect(),
+            Panel::Ios => self
+                .ios_devices
+                .iter()
+                .map(|device| (device.name.clone(), device.udid.as_str()))
+                .collect(),
+        };
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let current = match panel {
+            Panel::Android => self.selected_android,
+            Panel::Ios => self.selected_ios,
+        };
+        let start = usize::from(skip_current);
+        (start..start + candidates.len())
+            .map(|offset| (current + offset) % candidates.len())
+            .find(|&index| {
+                let (name, identifier) = &candidates[index];
+                name.to_lowercase().contains(&query)
+                    || identifier.to_lowercase().contains(&query)
+                    || self
+                        .device_annotation(panel, identifier)
+                        .is_some_and(|annotation| annotation.matches(&query))
+            })
+    }
+
+    /// Selects the next search match, returning whether a device matched.
+    pub fn select_next_search_match(&mut self, skip_current: bool) -> bool {
+        let Some(index) = self.find_search_match(skip_current) else {
+            return false;
+        };
+        match self.active_panel {
+            Panel::Android => self.selected_android = index,
+            Panel::Ios => self.selected_ios = index,
+        }
+        true
+    }
+}
@@ -1,4 +1,9 @@
-use crate::models::{ApiLevel, InstallProgress};
+use crate::constants::ui_text::api_management::{
+    FILTER_ALL, FILTER_AVAILABLE, FILTER_INSTALLED, SORT_API_LEVEL, SORT_SIZE,
+};
+use crate::models::{ApiLevel, InstallProgress, SystemImageVariant};
+use crate::utils::command_executor::CancellationToken;
+use std::time::{Duration, Instant};
 
 /// State for API level management dialog.
 #[derive(Debug, Clone)]
@@ -17,6 +22,227 @@ pub struct ApiLevelManagementState {
     pub error_message: Option<String>,
     /// Scroll offset for the API level list
     pub scroll_offset: usize,
+    /// Cancels the loading, install, or uninstall currently running for this dialog
+    pub cancel_token: CancellationToken,
+    /// Esc was pressed once during an install or uninstall; a second Esc cancels it
+    pub confirm_cancel: bool,
+    /// Uninstall held back because existing AVDs boot from the image
+    pub uninstall_confirmation: Option<UninstallConfirmation>,
+    /// Which API levels the list shows
+    pub filter: ApiLevelFilter,
+    /// Order of the list
+    pub sort: ApiLevelSort,
+    /// Text the API levels are searched for
+    pub search_query: String,
+    /// Whether typed characters go to the search query
+    pub is_searching: bool,
+    /// Packages marked with Space for the next install, in marking order
+    pub marked_packages: Vec<String>,
+    /// System images being installed one after another
+    pub install_queue: Option<InstallQueue>,
+    /// The SDK repository cannot be reached, so nothing can be downloaded
+    pub is_offline: bool,
+}
+
+/// Which API levels the list shows, cycled with `f`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApiLevelFilter {
+    #[default]
+    All,
+    Installed,
+    Available,
+}
+
+impl ApiLevelFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Installed,
+            Self::Installed => Self::Available,
+            Self::Available => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => FILTER_ALL,
+            Self::Installed => FILTER_INSTALLED,
+            Self::Available => FILTER_AVAILABLE,
+        }
+    }
+
+    fn matches(self, api_level: &ApiLevel) -> bool {
+        match self {
+            Self::All => true,
+            Self::Installed => api_level.is_installed,
+            Self::Available => !api_level.is_installed,
+        }
+    }
+}
+
+/// Order of the API level list, toggled with `s`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApiLevelSort {
+    /// Newest API level first
+    #[default]
+    ApiLevel,
+    /// Largest first, by disk usage of installed levels and download size
+    /// of the others
+    Size,
+}
+
+impl ApiLevelSort {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::ApiLevel => Self::Size,
+            Self::Size => Self::ApiLevel,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ApiLevel => SORT_API_LEVEL,
+            Self::Size => SORT_SIZE,
+        }
+    }
+}
+
+/// An uninstall waiting for the user to decide what happens to the AVDs that
+/// use the image.
+#[derive(Debug, Clone)]
+pub struct UninstallConfirmation {
+    /// Installed packages of the selected API level
+    pub package_ids: Vec<String>,
+    /// AVDs that boot from one of those packages
+    pub dependent_avds: Vec<String>,
+    /// Another installed image the AVDs can move to: (package ID, display name)
+    pub retarget: Option<(String, String)>,
+}
+
+/// System images installed one after another, started with Enter after
+/// marking them.
+#[derive(Debug, Clone)]
+pub struct InstallQueue {
+    /// Images in install order
+    pub items: Vec<QueuedInstall>,
+    /// When the first install started
+    pub started_at: Instant,
+}
+
+/// A system image in the install queue.
+#[derive(Debug, Clone)]
+pub struct QueuedInstall {
+    /// Full package ID
+    pub package_id: String,
+    /// API level and variant, e.g. "API 34 (Android 14) - Google Play"
+    pub display_name: String,
+    /// Download size sdkmanager reports, in bytes
+    pub download_size: Option<u64>,
+    pub status: QueuedInstallStatus,
+}
+
+/// Where a queued install stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueuedInstallStatus {
+    /// Waiting for the images before it
+    Pending,
+    /// Being installed, with its progress in percent
+    Installing(u8),
+    Installed,
+    /// Failed with the given error; the queue moves on to the next image
+    Failed(String),
+}
+
+impl QueuedInstallStatus {
+    fn is_finished(&self) -> bool {
+        matches!(self, Self::Installed | Self::Failed(_))
+    }
+}
+
+impl InstallQueue {
+    pub fn new(items: Vec<QueuedInstall>) -> Self {
+        Self {
+            items,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub fn get(&self, package_id: &str) -> Option<&QueuedInstall> {
+        self.items.iter().find(|item| item.package_id == package_id)
+    }
+
+    pub fn get_mut(&mut self, package_id: &str) -> Option<&mut QueuedInstall> {
+        self.items
+            .iter_mut()
+            .find(|item| item.package_id == package_id)
+    }
+
+    /// The image being installed and its position, counting from 1.
+    pub fn current(&self) -> Option<(usize, &QueuedInstall)> {
+        self.items
+            .iter()
+            .enumerate()
+            .find(|(_, item)| matches!(item.status, QueuedInstallStatus::Installing(_)))
+            .map(|(index, item)| (index + 1, item))
+    }
+
+    /// How many images were installed.
+    pub fn installed_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == QueuedInstallStatus::Installed)
+            .count()
+    }
+
+    /// The images that failed to install.
+    pub fn failed(&self) -> Vec<&QueuedInstall> {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, QueuedInstallStatus::Failed(_)))
+            .collect()
+    }
+
+    /// Share of the queue done, between 0 and 1. Images are weighted by
+    /// their download size when every size is known, and count the same
+    /// otherwise.
+    fn fraction_done(&self) -> f64 {
+        let by_size = self
+            .items
+            .iter()
+            .all(|item| item.download_size.is_some_and(|size| size > 0));
+        let weight = |item: &QueuedInstall| {
+            if by_size {
+                item.download_size.unwrap_or_default() as f64
+            } else {
+                1.0
+            }
+        };
+        let total: f64 = self.items.iter().map(weight).sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        let done: f64 = self
+            .items
+            .iter()
+            .map(|item| match item.status {
+                QueuedInstallStatus::Installing(percentage) => {
+                    weight(item) * f64::from(percentage.min(100)) / 100.0
+                }
+                ref status if status.is_finished() => weight(item),
+                _ => 0.0,
+            })
+            .sum();
+        done / total
+    }
+
+    /// Time left for the whole queue at the pace so far, `elapsed` after it
+    /// started. `None` until something has progressed.
+    pub fn remaining_time(&self, elapsed: Duration) -> Option<Duration> {
+        let done = self.fraction_done();
+        if done <= 0.0 || done >= 1.0 {
+            return None;
+        }
+        Some(elapsed.mul_f64((1.0 - done) / done))
+    }
 }
 
 impl Default for ApiLevelManagementState {
@@ -29,6 +255,16 @@ impl Default for ApiLevelManagementState {
             installing_package: None,
             error_message: None,
             scroll_offset: 0,
+            cancel_token: CancellationToken::new(),
+            confirm_cancel: false,
+            uninstall_confirmation: None,
+            filter: ApiLevelFilter::default(),
+            sort: ApiLevelSort::default(),
+            search_query: String::new(),
+            is_searching: false,
+            marked_packages: Vec::new(),
+            install_queue: None,
+            is_offline: false,
         }
     }
 }
@@ -39,11 +275,28 @@ impl ApiLevelManagementState {
         Self::default()
     }
 
+    /// The API levels the list shows: those passing the filter and the
+    /// search, in the chosen order.
+    pub fn visible_api_levels(&self) -> Vec<&ApiLevel> {
+        let query = self.search_query.trim().to_lowercase();
+        let mut api_levels: Vec<&ApiLevel> = self
+            .api_levels
+            .iter()
+            .filter(|api_level| self.filter.matches(api_level))
+            .filter(|api_level| query.is_empty() || api_level.matches_search(&query))
+            .collect();
+        if self.sort == ApiLevelSort::Size {
+            api_levels.sort_by_key(|api_level| std::cmp::Reverse(api_level.listed_size()));
+        }
+        api_levels
+    }
+
     /// Moves selection up.
     pub fn move_up(&mut self) {
-        if !self.api_levels.is_empty() {
+        let count = self.visible_api_levels().len();
+        if count > 0 {
             if self.selected_index == 0 {
-                self.selected_index = self.api_levels.len() - 1;
+                self.selected_index = count - 1;
             } else {
                 self.selected_index -= 1;
             }
@@ -52,8 +305,136 @@ impl ApiLevelManagementState {
 
     /// Moves selection down.
     pub fn move_down(&mut self) {
-        if !self.api_levels.is_empty() {
-            self.selected_index = (self.selected_index + 1) % self.api_levels.len();
+        let count = self.visible_api_levels().len();
+        if count > 0 {
+            self.selected_index = (self.selected_index + 1) % count;
+        }
+    }
+
+    /// Replaces the list, keeping the selected API level selected.
+    pub fn set_api_levels(&mut self, api_levels: Vec<ApiLevel>) {
+        self.update_view(|api_state| api_state.api_levels = api_levels);
+    }
+
+    /// Cycles the filter between all, installed and available API levels.
+    pub fn cycle_filter(&mut self) {
+        self.update_view(|api_state| api_state.filter = api_state.filter.next());
+    }
+
+    /// Switches between sorting by API level and by size.
+    pub fn toggle_sort(&mut self) {
+        self.update_view(|api_state| api_state.sort = api_state.sort.toggle());
+    }
+
+    /// Starts typing a search query.
+    pub fn start_search(&mut self) {
+        self.is_searching = true;
+    }
+
+    /// Adds a typed character to the search query.
+    pub fn push_search_char(&mut self, c: char) {
+        self.update_view(|api_state| api_state.search_query.push(c));
+    }
+
+    /// Removes the last character of the search query.
+    pub fn pop_search_char(&mut self) {
+        self.update_view(|api_state| {
+            api_state.search_query.pop();
+        });
+    }
+
+    /// Stops typing, keeping the query, or dropping it when `clear` is set.
+    pub fn finish_search(&mut self, clear: bool) {
+        self.is_searching = false;
+        if clear {
+            self.update_view(|api_state| api_state.search_query.clear());
+        }
+    }
+
+    /// Applies a change to the list or how it is shown, keeping the
+    /// selected API level selected while it is still shown.
+    fn update_view(&mut self, change: impl FnOnce(&mut Self)) {
+        let selected_api = self.get_selected_api_level().map(|level| level.api);
+        change(self);
+        self.selected_index = selected_api
+            .and_then(|api| {
+                self.visible_api_levels()
+                    .iter()
+                    .position(|level| level.api == api)
+            })
+            .unwrap_or(0);
+    }
+
+    /// Marks the recommended image of the selected API level for the next
+    /// install, or unmarks it. Installed images cannot be marked.
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(variant) = self
+            .get_selected_api_level()
+            .and_then(|api_level| api_level.get_recommended_variant())
+            .filter(|variant| !variant.is_installed)
+        else {
+            return;
+        };
+        let package_id = variant.package_id.clone();
+        if let Some(position) = self.marked_packages.iter().position(|p| *p == package_id) {
+            self.marked_packages.remove(position);
+        } else {
+            self.marked_packages.push(package_id);
+        }
+    }
+
+    /// Whether the recommended image of `api_level` is marked.
+    pub fn is_marked(&self, api_level: &ApiLevel) -> bool {
+        api_level
+            .get_recommended_variant()
+            .is_some_and(|variant| self.marked_packages.contains(&variant.package_id))
+    }
+
+    /// The images Enter installs: the marked ones that are still not
+    /// installed, or the recommended image of the selected API level when
+    /// nothing is marked. Clears the marks.
+    pub fn take_install_queue_items(&mut self) -> Vec<QueuedInstall> {
+        let package_ids = if self.marked_packages.is_empty() {
+            self.get_selected_api_level()
+                .and_then(|api_level| api_level.get_recommended_variant())
+                .map(|variant| vec![variant.package_id.clone()])
+                .unwrap_or_default()
+        } else {
+            std::mem::take(&mut self.marked_packages)
+        };
+        package_ids
+            .iter()
+            .filter_map(|package_id| self.queued_install(package_id))
+            .collect()
+    }
+
+    fn queued_install(&self, package_id: &str) -> Option<QueuedInstall> {
+        self.api_levels.iter().find_map(|api_level| {
+            let variant = api_level
+                .variants
+                .iter()
+                .find(|variant| variant.package_id == package_id && !variant.is_installed)?;
+            Some(QueuedInstall {
+                package_id: variant.package_id.clone(),
+                display_name: format!("{} - {}", api_level.display_name, variant.display_name),
+                download_size: variant.download_size,
+                status: QueuedInstallStatus::Pending,
+            })
+        })
+    }
+
+    /// Shows the API levels parsed so far while sdkmanager is still listing.
+    /// Ignored once loading finished, and for updates that arrive out of
+    /// order with fewer images than already shown.
+    pub fn show_partial_api_levels(&mut self, api_levels: Vec<ApiLevel>) {
+        let variant_count = |levels: &[ApiLevel]| {
+            levels
+                .iter()
+                .map(|level| level.variants.len())
+                .sum::<usize>()
+        };
+        if self.is_loading && variant_count(&api_levels) >= variant_count(&self.api_levels) {
+            self.set_api_levels(api_levels);
         }
     }
 
@@ -62,18 +443,64 @@ impl ApiLevelManagementState {
         self.install_progress.is_some() || self.installing_package.is_some()
     }
 
+    /// Cancels the running operation and resets the busy state so the dialog
+    /// can be used again.
+    pub fn cancel_operation(&mut self) {
+        self.cancel_token.cancel();
+        self.cancel_token = CancellationToken::new();
+        self.is_loading = false;
+        self.install_progress = None;
+        self.installing_package = None;
+        self.install_queue = None;
+        self.confirm_cancel = false;
+    }
+
+    /// Picks the installed image AVDs move to when `excluded` is uninstalled:
+    /// the recommended variant of the newest other installed API level.
+    pub fn retarget_candidate(&self, excluded: &[String]) -> Option<(String, String)> {
+        self.api_levels
+            .iter()
+            .filter_map(|api_level| {
+                let usable = |variant: &&SystemImageVariant| {
+                    variant.is_installed && !excluded.contains(&variant.package_id)
+                };
+                let variant = api_level
+                    .get_recommended_variant()
+                    .filter(usable)
+                    .or_else(|| api_level.variants.iter().find(usable))?;
+                Some((api_level, variant))
+            })
+            .max_by_key(|(api_level, _)| api_level.api)
+            .map(|(api_level, variant)| {
+                (
+                    variant.package_id.clone(),
+                    format!("{} - {}", api_level.display_name, variant.display_name),
+                )
+            })
+    }
+
+    /// Disk space taken by all installed system images, in bytes.
+    pub fn installed_disk_usage(&self) -> u64 {
+        self.api_levels
+            .iter()
+            .flat_map(|api_level| &api_level.variants)
+            .filter(|variant| variant.is_installed)
+            .filter_map(|variant| variant.installed_size)
+            .sum()
+    }
+
     /// Gets the currently selected API level.
     pub fn get_selected_api_level(&self) -> Option<&ApiLevel> {
-        self.api_levels.get(self.selected_index)
+        self.visible_api_levels().get(self.selected_index).copied()
     }
 
     /// Calculates scroll offset to keep selected item visible.
     pub fn get_scroll_offset(&self, available_height: usize) -> usize {
-        if self.api_levels.is_empty() || available_height == 0 {
+        let total_items = self.visible_api_levels().len();
+        if total_items == 0 || available_height == 0 {
             return 0;
         }
 
-        let total_items = self.api_levels.len();
         let selected = self.selected_index;
         let preferred_offset = selected.saturating_sub(available_height / 2);
         let max_offset = total_items.saturating_sub(available_height);
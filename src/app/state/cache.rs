@@ -1,4 +1,7 @@
+use super::{AppState, Panel};
 use crate::constants::timeouts::{CACHE_EXPIRATION_TIME, CACHE_INVALIDATION_OFFSET_SECS};
+use crate::models::{ApiLevel, DeviceStatus};
+use crate::utils::DeviceInventoryCache;
 
 /// Cache for device creation options to avoid repeated API calls.
 /// This cache stores available device types, API levels, and runtimes.
@@ -11,6 +14,9 @@ pub struct DeviceCache {
     pub android_api_levels: Vec<(String, String)>,
     /// Complete list of Android devices for category filtering
     pub android_device_cache: Option<Vec<(String, String)>>,
+    /// Last known system images per API level, shown by the API level dialog
+    /// while it revalidates them
+    pub android_system_images: Option<Vec<ApiLevel>>,
     /// Available iOS device types as (identifier, display_name) tuples
     pub ios_device_types: Vec<(String, String)>,
     /// Available iOS runtime versions as (identifier, display_name) tuples
@@ -27,6 +33,7 @@ impl Default for DeviceCache {
             android_device_types: Vec::new(),
             android_api_levels: Vec::new(),
             android_device_cache: None,
+            android_system_images: None,
             ios_device_types: Vec::new(),
             ios_runtimes: Vec::new(),
             last_updated: std::time::Instant::now(),
@@ -68,6 +75,22 @@ impl DeviceCache {
         self.is_loading = false;
     }
 
+    /// Fills empty creation options from a persisted inventory.
+    /// Options that were already loaded this session are kept.
+    pub fn restore_from_inventory(&mut self, inventory: &DeviceInventoryCache) {
+        if self.android_device_types.is_empty() && self.android_api_levels.is_empty() {
+            self.android_device_types = inventory.android_device_types.clone();
+            self.android_api_levels = inventory.android_api_levels.clone();
+            if !self.android_device_types.is_empty() {
+                self.android_device_cache = Some(self.android_device_types.clone());
+            }
+        }
+        if self.ios_device_types.is_empty() && self.ios_runtimes.is_empty() {
+            self.ios_device_types = inventory.ios_device_types.clone();
+            self.ios_runtimes = inventory.ios_runtimes.clone();
+        }
+    }
+
     /// Invalidates the Android cache by clearing API levels and marking as stale.
     /// This forces a cache refresh on the next device creation.
     pub fn invalidate_android_cache(&mut self) {
@@ -84,3 +107,63 @@ impl DeviceCache {
             - std::time::Duration::from_secs(CACHE_INVALIDATION_OFFSET_SECS);
     }
 }
+
+impl AppState {
+    /// Shows a persisted device inventory until the first live refresh lands.
+    /// Lists that background loading has already filled are left untouched.
+    /// Restored devices are shown with an unknown status, and the refresh age
+    /// counts from when the inventory was saved.
+    pub async fn restore_device_inventory(&mut self, inventory: DeviceInventoryCache) {
+        self.device_cache
+            .write()
+            .await
+            .restore_from_inventory(&inventory);
+
+        if !self.is_loading {
+            return;
+        }
+        let mut restored = false;
+        if self.android_devices.is_empty() && !inventory.android_devices.is_empty() {
+            self.android_devices = inventory.android_devices;
+            for device in &mut self.android_devices {
+                device.status = DeviceStatus::Unknown;
+                device.is_running = false;
+            }
+            self.sort_pinned_devices_first(Panel::Android);
+            restored = true;
+        }
+        if self.ios_devices.is_empty() && !inventory.ios_devices.is_empty() {
+            self.ios_devices = inventory.ios_devices;
+            for device in &mut self.ios_devices {
+                device.status = DeviceStatus::Unknown;
+                device.is_running = false;
+            }
+            self.sort_pinned_devices_first(Panel::Ios);
+            restored = true;
+        }
+        if restored {
+            self.is_loading = false;
+            self.showing_cached_inventory = true;
+            let age = inventory
+                .timestamp
+                .and_then(|timestamp| timestamp.elapsed().ok())
+                .unwrap_or_default();
+            let now = std::time::Instant::now();
+            self.last_refresh = now.checked_sub(age).unwrap_or(now);
+        }
+    }
+
+    /// Captures the current device lists and creation options for persisting.
+    pub async fn device_inventory(&self) -> DeviceInventoryCache {
+        let cache = self.device_cache.read().await;
+        DeviceInventoryCache {
+            android_devices: self.android_devices.clone(),
+            ios_devices: self.ios_devices.clone(),
+            android_device_types: cache.android_device_types.clone(),
+            android_api_levels: cache.android_api_levels.clone(),
+            ios_device_types: cache.ios_device_types.clone(),
+            ios_runtimes: cache.ios_runtimes.clone(),
+            timestamp: None,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use super::Panel;
+use crate::constants::defaults::DEFAULT_MAX_PARALLEL_CREATES;
+use crate::managers::common::DeviceConfig;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Where a queued device creation is in its lifecycle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueuedCreationStatus {
+    /// Waiting for a free worker slot
+    Pending,
+    /// Being created; the stage and percentage come from the platform tool
+    Active {
+        stage: Option<String>,
+        progress: Option<u8>,
+    },
+    /// Created successfully
+    Completed,
+    /// Creation failed with the given message
+    Failed(String),
+}
+
+impl QueuedCreationStatus {
+    /// Returns true once the creation has completed or failed.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed(_))
+    }
+}
+
+/// One device creation request in the queue.
+#[derive(Debug, Clone)]
+pub struct QueuedCreation {
+    /// Identifier used by the worker to report back
+    pub id: u64,
+    /// Platform the device is created on
+    pub panel: Panel,
+    /// What to create
+    pub config: DeviceConfig,
+    pub status: QueuedCreationStatus,
+    /// Test matrix group the device joins once created
+    pub group: Option<String>,
+}
+
+/// Device creations queued from the creation form, run by a worker pool
+/// that creates at most `max_parallel` devices at the same time.
+#[derive(Debug, Clone)]
+pub struct CreationQueue {
+    /// Queued creations in the order they were added
+    pub items: Vec<QueuedCreation>,
+    /// Index of the first item shown in the queue view
+    pub scroll_offset: usize,
+    max_parallel: usize,
+    slots: Arc<Semaphore>,
+    next_id: u64,
+}
+
+impl Default for CreationQueue {
+    fn default() -> Self {
+        Self::with_max_parallel(DEFAULT_MAX_PARALLEL_CREATES)
+    }
+}
+
+impl CreationQueue {
+    /// Creates an empty queue running up to `max_parallel` creations at once
+    /// (at least one).
+    pub fn with_max_parallel(max_parallel: usize) -> Self {
+        let max_parallel = max_parallel.max(1);
+        Self {
+            items: Vec::new(),
+            scroll_offset: 0,
+            max_parallel,
+            slots: Arc::new(Semaphore::new(max_parallel)),
+            next_id: 0,
+        }
+    }
+
+    pub fn max_parallel(&self) -> usize {
+        self.max_parallel
+    }
+
+    /// Worker slots shared by the creation tasks of this queue.
+    pub fn slots(&self) -> Arc<Semaphore> {
+        Arc::clone(&self.slots)
+    }
+
+    /// Adds a pending creation and returns its identifier.
+    pub fn enqueue(&mut self, panel: Panel, config: DeviceConfig) -> u64 {
+        self.enqueue_in_group(panel, config, None)
+    }
+
+    /// Adds a pending creation whose device joins the test matrix `group`.
+    pub fn enqueue_in_group(
+        &mut self,
+        panel: Panel,
+        config: DeviceConfig,
+        group: Option<String>,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push(QueuedCreation {
+            id,
+            panel,
+            config,
+            status: QueuedCreationStatus::Pending,
+            group,
+        });
+        id
+    }
+
+    pub fn get(&self, id: u64) -> Option<&QueuedCreation> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: u64) -> Option<&mut QueuedCreation> {
+        self.items.iter_mut().find(|item| item.id == id)
+    }
+
+    /// Returns true if an unfinished creation on `panel` uses `name`.
+    pub fn contains_unfinished(&self, panel: Panel, name: &str) -> bool {
+        self.items.iter().any(|item| {
+            item.panel == panel && item.config.name == name && !item.status.is_finished()
+        })
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.count(|status| matches!(status, QueuedCreationStatus::Pending))
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.count(|status| matches!(status, QueuedCreationStatus::Active { .. }))
+    }
+
+    pub fn finished_count(&self) -> usize {
+        self.count(QueuedCreationStatus::is_finished)
+    }
+
+    /// Removes completed and failed items from the view.
+    pub fn clear_finished(&mut self) {
+        self.items.retain(|item| !item.status.is_finished());
+        self.scroll_offset = self.scroll_offset.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Scrolls the list up by one item.
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the list down by one item, stopping at the last item.
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset + 1 < self.items.len() {
+            self.scroll_offset += 1;
+        }
+    }
+
+    fn count(&self, predicate: impl Fn(&QueuedCreationStatus) -> bool) -> usize {
+        self.items
+            .iter()
+            .filter(|item| predicate(&item.status))
+            .count()
+    }
+}
@@ -1,9 +1,11 @@
-use super::{AppState, Panel};
-use crate::models::{DeviceDetails, Platform};
+use super::{AppState, DetailsTab, DetailsTabContent, NetworkCapture, Panel};
+use crate::models::{device::Device, DeviceDetails, DeviceId, DeviceOperation, Platform};
+use std::collections::HashSet;
 
 impl AppState {
     /// Gets details for the currently selected device.
-    /// Returns cached details if available and matching current selection.
+    /// Returns cached details if available and matching current selection,
+    /// then prefetched details with the status of the current device list.
     /// Falls back to generating basic details from device data if cache miss.
     pub fn get_selected_device_details(&self) -> Option<DeviceDetails> {
         if let Some(ref cached) = self.cached_device_details {
@@ -23,7 +25,7 @@ impl AppState {
             }
         }
 
-        match self.active_panel {
+        let basic = match self.active_panel {
             Panel::Android => self
                 .android_devices
                 .get(self.selected_android)
@@ -75,6 +77,18 @@ impl AppState {
                     system_image: None,
                     identifier: device.udid.clone(),
                 }),
+        }?;
+
+        // Whether a device runs changes far more often than its configuration
+        match self
+            .prefetched_device_details
+            .get(&self.active_panel.device_id(basic.identifier.as_str()))
+        {
+            Some(prefetched) => Some(DeviceDetails {
+                status: basic.status,
+                ..prefetched.clone()
+            }),
+            None => Some(basic),
         }
     }
 
@@ -87,12 +101,268 @@ impl AppState {
             details.platform,
             details.device_path.is_some()
         );
+        self.store_prefetched_device_details(details.clone());
         self.cached_device_details = Some(details);
     }
 
+    /// Keeps the details of a device so selecting it shows them at once.
+    pub fn store_prefetched_device_details(&mut self, details: DeviceDetails) {
+        self.prefetched_device_details.insert(
+            DeviceId::new(details.platform, details.identifier.as_str()),
+            details,
+        );
+    }
+
+    /// Drops the details of devices that left `panel`'s list.
+    pub(super) fn prune_prefetched_device_details(&mut self, panel: Panel) {
+        let platform = Platform::from(panel);
+        let listed: HashSet<&str> = match panel {
+            Panel::Android => self
+                .android_devices
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect(),
+            Panel::Ios => self.ios_devices.iter().map(|d| d.udid.as_str()).collect(),
+        };
+        self.prefetched_device_details
+            .retain(|id, _| id.platform != platform || listed.contains(id.identifier.as_str()));
+    }
+
+    /// Devices of `panel` whose details have not been fetched yet.
+    pub fn devices_without_details(&self, panel: Panel) -> Vec<String> {
+        let identifiers: Vec<String> = match panel {
+            Panel::Android => self
+                .android_devices
+                .iter()
+                .map(|d| d.name.clone())
+                .collect(),
+            Panel::Ios => self.ios_devices.iter().map(|d| d.udid.clone()).collect(),
+        };
+        identifiers
+            .into_iter()
+            .filter(|identifier| {
+                !self
+                    .prefetched_device_details
+                    .contains_key(&panel.device_id(identifier.as_str()))
+            })
+            .collect()
+    }
+
+    /// Identifier of the selected device (AVD name or UDID).
+    pub fn selected_device_identifier(&self) -> Option<String> {
+        match self.active_panel {
+            Panel::Android => self
+                .android_devices
+                .get(self.selected_android)
+                .map(|d| d.name.clone()),
+            Panel::Ios => self
+                .ios_devices
+                .get(self.selected_ios)
+                .map(|d| d.udid.clone()),
+        }
+    }
+
+    /// Switches the details panel to the next or previous tab.
+    pub fn cycle_details_tab(&mut self, forward: bool) {
+        self.details_tab = if forward {
+            self.details_tab.next()
+        } else {
+            self.details_tab.previous()
+        };
+    }
+
+    /// Content of the current details tab, if it was loaded for the
+    /// selected device. Returns `None` while it is still loading.
+    pub fn current_details_tab_content(&self) -> Option<&DetailsTabContent> {
+        let identifier = self.selected_device_identifier()?;
+        self.details_tab_content.as_ref().filter(|content| {
+            content.tab == self.details_tab && content.device_identifier == identifier
+        })
+    }
+
+    /// Stores loaded tab content if the user has not moved on meanwhile.
+    pub fn set_details_tab_content(&mut self, content: DetailsTabContent) {
+        if content.tab == self.details_tab
+            && self.selected_device_identifier().as_deref() == Some(&content.device_identifier)
+        {
+            self.details_tab_content = Some(content);
+        }
+    }
+
+    /// Records a lifecycle operation in the device's history.
+    pub fn record_device_operation(
+        &mut self,
+        panel: Panel,
+        identifier: &str,
+        operation: DeviceOperation,
+        result: Result<(), String>,
+    ) {
+        self.device_history
+            .record(&panel.device_id(identifier), operation, result);
+
+        // Keep an open History tab current without another load
+        if self.details_tab == DetailsTab::History
+            && self.active_panel == panel
+            && self.selected_device_identifier().as_deref() == Some(identifier)
+        {
+            self.details_tab_content = Some(DetailsTabContent {
+                tab: DetailsTab::History,
+                device_identifier: identifier.to_string(),
+                lines: Ok(self.device_history_lines(panel, identifier)),
+            });
+        }
+    }
+
+    /// Feeds the observed running state of a panel's devices into the
+    /// usage markers behind uptime and "last used".
+    pub(super) fn track_device_usage(&mut self, panel: Panel) {
+        match panel {
+            Panel::Android => {
+                for device in &self.android_devices {
+                    self.device_history
+                        .observe_device(&device.device_id(), device.is_running);
+                }
+            }
+            Panel::Ios => {
+                for device in &self.ios_devices {
+                    self.device_history
+                        .observe_device(&device.device_id(), device.is_running);
+                }
+                // A simulator that shut down boots with a window next time
+                let running: Vec<&str> = self
+                    .ios_devices
+                    .iter()
+                    .filter(|device| device.is_running)
+                    .map(|device| device.udid.as_str())
+                    .collect();
+                self.headless_ios_devices
+                    .retain(|udid| running.contains(&udid.as_str()));
+            }
+        }
+    }
+
+    /// Records whether an iOS simulator was booted without Simulator.app.
+    pub fn set_ios_device_headless(&mut self, udid: &str, headless: bool) {
+        if headless {
+            self.headless_ios_devices.insert(udid.to_string());
+        } else {
+            self.headless_ios_devices.remove(udid);
+        }
+    }
+
+    pub fn is_ios_device_headless(&self, udid: &str) -> bool {
+        self.headless_ios_devices.contains(udid)
+    }
+
+    /// The running network capture of a device, if any.
+    pub fn network_capture(&self, device: &DeviceId) -> Option<&NetworkCapture> {
+        self.network_captures
+            .iter()
+            .find(|capture| &capture.device == device)
+    }
+
+    /// Drops a deleted device from the list and forgets its history,
+    /// annotations and intents.
+    pub fn forget_deleted_device(&mut self, panel: Panel, identifier: &str) {
+        match panel {
+            Panel::Android => {
+                self.android_devices
+                    .retain(|device| device.name != identifier);
+                if self.selected_android >= self.android_devices.len() {
+                    self.selected_android = self.android_devices.len().saturating_sub(1);
+                }
+            }
+            Panel::Ios => {
+                self.ios_devices.retain(|device| device.udid != identifier);
+                if self.selected_ios >= self.ios_devices.len() {
+                    self.selected_ios = self.ios_devices.len().saturating_sub(1);
+                }
+            }
+        }
+        let id = panel.device_id(identifier);
+        self.device_history.remove_device(&id);
+        self.device_annotations.remove_device(&id);
+        self.intent_history.remove_device(&id);
+    }
+
+    /// Marks a simulator clone for deletion once it stops, or an AVD as
+    /// running read-only.
+    pub fn track_ephemeral_device(&mut self, id: DeviceId) {
+        self.ephemeral_devices.insert(id, false);
+    }
+
+    pub fn is_ephemeral_device(&self, panel: Panel, identifier: &str) -> bool {
+        self.ephemeral_devices
+            .contains_key(&panel.device_id(identifier))
+    }
+
+    /// The ephemeral devices that stopped since they were last seen running,
+    /// which are no longer tracked afterwards. A device counts as stopped
+    /// only after the device list showed it running, so a list fetched
+    /// before its boot finished does not end it.
+    pub fn take_stopped_ephemeral_devices(&mut self) -> Vec<DeviceId> {
+        let mut stopped = Vec::new();
+        for (id, seen_running) in &mut self.ephemeral_devices {
+            let is_running = match id.platform {
+                Platform::Android => self
+                    .android_devices
+                    .iter()
+                    .any(|device| device.name == id.identifier && device.is_running),
+                Platform::Ios => self
+                    .ios_devices
+                    .iter()
+                    .any(|device| device.udid == id.identifier && device.is_running),
+            };
+            if is_running {
+                *seen_running = true;
+            } else if *seen_running {
+                // Stopped, or deleted outside emu
+                stopped.push(id.clone());
+            }
+        }
+        for id in &stopped {
+            self.ephemeral_devices.remove(id);
+        }
+        stopped
+    }
+
+    /// How long a running device has been up, if its boot was observed.
+    pub fn device_uptime(&self, panel: Panel, identifier: &str) -> Option<std::time::Duration> {
+        let booted_at = self
+            .device_history
+            .usage_for(&panel.device_id(identifier))?
+            .booted_at?;
+        (chrono::Local::now() - booted_at).to_std().ok()
+    }
+
+    /// How long ago a stopped device was last seen running.
+    pub fn device_idle_time(&self, panel: Panel, identifier: &str) -> Option<std::time::Duration> {
+        let usage = self
+            .device_history
+            .usage_for(&panel.device_id(identifier))?;
+        if usage.booted_at.is_some() {
+            return None;
+        }
+        (chrono::Local::now() - usage.last_seen_running?)
+            .to_std()
+            .ok()
+    }
+
+    /// History tab lines for a device, newest first.
+    pub fn device_history_lines(&self, panel: Panel, identifier: &str) -> Vec<String> {
+        self.device_history
+            .entries_for(&panel.device_id(identifier))
+            .into_iter()
+            .map(|record| record.display_line())
+            .collect()
+    }
+
     /// Clears all cached device details.
     pub fn clear_cached_device_details(&mut self) {
-        self.cached_device_details = None;
+        if let Some(cached) = self.cached_device_details.take() {
+            self.prefetched_device_details
+                .remove(&DeviceId::new(cached.platform, cached.identifier));
+        }
     }
 
     /// Intelligently clears cached device details only when switching platforms.
@@ -1,28 +1,60 @@
 use super::{AppState, Panel};
 use crate::constants::{
-    defaults::{DEFAULT_RAM_MB, DEFAULT_STORAGE_MB},
-    limits::{MAX_WORDS_IN_API_DISPLAY, MAX_WORDS_IN_DEVICE_NAME},
+    defaults::{
+        DEFAULT_RAM_MB, DEFAULT_STORAGE_MB, LARGE_DEVICE_DEFAULT_RAM_MB,
+        LARGE_DEVICE_DEFAULT_STORAGE_MB, WEAR_DEFAULT_RAM_MB, WEAR_DEFAULT_STORAGE_MB,
+    },
+    ios::IOS_RUNTIME_PREFIX,
+    limits::{
+        MAX_WORDS_IN_API_DISPLAY, MAX_WORDS_IN_DEVICE_NAME, RAM_SIZE_OPTIONS_MB,
+        STORAGE_MB_TO_GB_DIVISOR, STORAGE_SIZE_OPTIONS_MB,
+    },
+    messages::validation::{DEVICE_NAME_DUPLICATE_ERROR, RAM_EXCEEDS_HOST_MEMORY_WARNING},
+};
+use crate::models::{
+    device_info::DynamicDeviceConfig, DeviceCreationPreview, NameParts, NamingConfig,
+};
+use crate::utils::command_executor::CancellationToken;
+use crate::utils::validation::{
+    DeviceNameValidator, DevicePlatform, FieldValidator, NumericRangeValidator,
 };
-use crate::models::device_info::DynamicDeviceConfig;
 
 /// Fields in the device creation form.
 /// The order represents the navigation flow in the form.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CreateDeviceField {
+    /// Android SDK selection - only when `.emu.toml` lists several SDKs
+    Sdk,
     /// API Level selection (Android) or iOS version selection
     ApiLevel,
     /// Device category selection (phone/tablet/tv/wear/etc) - Android only
     Category,
     /// Specific device type selection
     DeviceType,
-    /// RAM size in MB - Android only
+    /// RAM size selection in MB - Android only
     RamSize,
-    /// Storage size in MB - Android only
+    /// Storage size selection in MB - Android only
     StorageSize,
     /// Custom device name (final field)
     Name,
 }
 
+/// Configuration of an existing device used to pre-fill the creation form,
+/// so a variant of it (e.g. the same hardware on another API level) is quick to make.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateDeviceTemplate {
+    /// Name of the device being duplicated
+    pub source_name: String,
+    /// Device type ID or display name to preselect
+    pub device_type: String,
+    /// API level or runtime identifier to preselect
+    pub version: String,
+    /// RAM size in MB (Android only)
+    pub ram_size: Option<String>,
+    /// Storage size in MB (Android only)
+    pub storage_size: Option<String>,
+}
+
 /// Form state for creating new devices.
 /// Manages all fields, selections, and validation for device creation.
 #[derive(Debug, Clone)]
@@ -59,12 +91,30 @@ pub struct CreateDeviceForm {
     pub is_creating: bool,
     /// Status message during device creation
     pub creation_status: Option<String>,
+    /// Percentage of the creation phase reported by the platform tool
+    pub creation_progress: Option<u8>,
+    /// Cancels the device creation currently running
+    pub cancel_token: CancellationToken,
+    /// Esc was pressed once during creation; a second Esc cancels it
+    pub confirm_cancel: bool,
     /// Current device category filter (all/phone/tablet/tv/wear/etc)
     pub device_category_filter: String,
     /// List of available device categories
     pub available_categories: Vec<String>,
     /// Currently selected category index
     pub selected_category_index: usize,
+    /// Resolved image, profile, and command for the current selection (Android only)
+    pub preview: Option<DeviceCreationPreview>,
+    /// Device whose configuration pre-fills the form when duplicating
+    pub template: Option<CreateDeviceTemplate>,
+    /// Names of the Android SDKs a device can be created with, default first
+    pub android_sdks: Vec<String>,
+    /// Currently selected index in the SDK list
+    pub selected_sdk_index: usize,
+    /// Naming template from `.emu.toml`, used for the placeholder name
+    pub naming: NamingConfig,
+    /// Names of the existing devices, which the placeholder name avoids
+    pub taken_names: Vec<String>,
 }
 
 impl Default for CreateDeviceForm {
@@ -86,6 +136,9 @@ impl Default for CreateDeviceForm {
             is_loading_cache: false,
             is_creating: false,
             creation_status: None,
+            creation_progress: None,
+            cancel_token: CancellationToken::new(),
+            confirm_cancel: false,
             device_category_filter: "all".to_string(),
             available_categories: vec![
                 "all".to_string(),
@@ -97,6 +150,12 @@ impl Default for CreateDeviceForm {
                 "desktop".to_string(),
             ],
             selected_category_index: 0,
+            preview: None,
+            template: None,
+            android_sdks: Vec::new(),
+            selected_sdk_index: 0,
+            naming: NamingConfig::default(),
+            taken_names: Vec::new(),
         }
     }
 }
@@ -131,14 +190,17 @@ impl CreateDeviceForm {
     }
 
     /// Moves focus to the next field in the form (Android version).
-    /// Cycles through all fields in order: ApiLevel -> Category -> DeviceType -> RamSize -> StorageSize -> Name.
+    /// Cycles through all fields in order: Sdk -> ApiLevel -> Category -> DeviceType -> RamSize -> StorageSize -> Name.
+    /// Sdk is skipped unless there is more than one SDK to choose from.
     pub fn next_field(&mut self) {
         self.active_field = match self.active_field {
+            CreateDeviceField::Sdk => CreateDeviceField::ApiLevel,
             CreateDeviceField::ApiLevel => CreateDeviceField::Category,
             CreateDeviceField::Category => CreateDeviceField::DeviceType,
             CreateDeviceField::DeviceType => CreateDeviceField::RamSize,
             CreateDeviceField::RamSize => CreateDeviceField::StorageSize,
             CreateDeviceField::StorageSize => CreateDeviceField::Name,
+            CreateDeviceField::Name if self.has_sdk_choice() => CreateDeviceField::Sdk,
             CreateDeviceField::Name => CreateDeviceField::ApiLevel,
         };
     }
@@ -147,6 +209,8 @@ impl CreateDeviceForm {
     /// Cycles through fields in reverse order.
     pub fn prev_field(&mut self) {
         self.active_field = match self.active_field {
+            CreateDeviceField::Sdk => CreateDeviceField::Name,
+            CreateDeviceField::ApiLevel if self.has_sdk_choice() => CreateDeviceField::Sdk,
             CreateDeviceField::ApiLevel => CreateDeviceField::Name,
             CreateDeviceField::Category => CreateDeviceField::ApiLevel,
             CreateDeviceField::DeviceType => CreateDeviceField::Category,
@@ -178,6 +242,34 @@ impl CreateDeviceForm {
         };
     }
 
+    /// Whether the form offers a choice between several Android SDKs.
+    pub fn has_sdk_choice(&self) -> bool {
+        self.android_sdks.len() > 1
+    }
+
+    /// Name of the selected Android SDK, if there is a choice.
+    pub fn selected_sdk(&self) -> Option<&str> {
+        self.has_sdk_choice()
+            .then(|| self.android_sdks.get(self.selected_sdk_index))
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Steps the selected Android SDK forward or back, wrapping around.
+    /// Returns true when the selection changed.
+    pub fn cycle_sdk(&mut self, forward: bool) -> bool {
+        let count = self.android_sdks.len();
+        if count < 2 {
+            return false;
+        }
+        self.selected_sdk_index = if forward {
+            (self.selected_sdk_index + 1) % count
+        } else {
+            (self.selected_sdk_index + count - 1) % count
+        };
+        true
+    }
+
     /// Placeholder method that always returns false.
     /// Selection is handled through field navigation instead.
     pub fn move_selection_up(&mut self) -> bool {
@@ -210,7 +302,8 @@ impl CreateDeviceForm {
     }
 
     /// Updates the selected device type based on the current index.
-    /// Also regenerates the placeholder device name.
+    /// Also regenerates the placeholder device name and resets RAM/storage
+    /// to the defaults for the device's category.
     pub fn update_selected_device_type(&mut self) {
         if let Some((id, display)) = self
             .available_device_types
@@ -219,13 +312,173 @@ impl CreateDeviceForm {
             self.device_type_id = id.clone();
             self.device_type = display.clone();
             self.generate_placeholder_name();
+            self.apply_device_profile_defaults();
+        }
+    }
+
+    /// Sets RAM and storage to sensible defaults for the selected device type.
+    /// Wear devices get smaller values, while tablets, desktops, and automotive
+    /// devices get larger ones.
+    pub fn apply_device_profile_defaults(&mut self) {
+        let category = DynamicDeviceConfig::detect_android_device_category(
+            &self.device_type_id,
+            &self.device_type,
+        );
+        let (ram_mb, storage_mb) = match category.as_str() {
+            "wear" => (WEAR_DEFAULT_RAM_MB, WEAR_DEFAULT_STORAGE_MB),
+            "tablet" | "desktop" => (LARGE_DEVICE_DEFAULT_RAM_MB, LARGE_DEVICE_DEFAULT_STORAGE_MB),
+            "automotive" => (LARGE_DEVICE_DEFAULT_RAM_MB, DEFAULT_STORAGE_MB),
+            _ => (DEFAULT_RAM_MB, DEFAULT_STORAGE_MB),
+        };
+        self.ram_size = ram_mb.to_string();
+        self.storage_size = storage_mb.to_string();
+    }
+
+    /// RAM sizes selectable in the form, limited to the validator's range.
+    pub fn ram_size_options() -> Vec<u32> {
+        let validator = NumericRangeValidator::ram_size();
+        RAM_SIZE_OPTIONS_MB
+            .iter()
+            .copied()
+            .filter(|size| validator.validate(&size.to_string()).is_ok())
+            .collect()
+    }
+
+    /// Storage sizes selectable in the form, limited to the validator's range.
+    pub fn storage_size_options() -> Vec<u32> {
+        let validator = NumericRangeValidator::storage_size();
+        STORAGE_SIZE_OPTIONS_MB
+            .iter()
+            .copied()
+            .filter(|size| validator.validate(&size.to_string()).is_ok())
+            .collect()
+    }
+
+    /// Steps the RAM size to the next or previous option, wrapping around.
+    pub fn cycle_ram_size(&mut self, forward: bool) {
+        self.ram_size = Self::cycle_size(
+            &self.ram_size,
+            &Self::ram_size_options(),
+            DEFAULT_RAM_MB,
+            forward,
+        );
+    }
+
+    /// Steps the storage size to the next or previous option, wrapping around.
+    pub fn cycle_storage_size(&mut self, forward: bool) {
+        self.storage_size = Self::cycle_size(
+            &self.storage_size,
+            &Self::storage_size_options(),
+            DEFAULT_STORAGE_MB,
+            forward,
+        );
+    }
+
+    /// Picks the neighbouring option of `current`. A value that is not one of
+    /// the options snaps to the nearest option in the chosen direction, and
+    /// one that is not a number counts as `default`.
+    fn cycle_size(current: &str, options: &[u32], default: u32, forward: bool) -> String {
+        let Some((&first, &last)) = options.first().zip(options.last()) else {
+            return current.to_string();
+        };
+        let current = current.trim().parse::<u32>().unwrap_or(default);
+
+        let next = if forward {
+            options
+                .iter()
+                .copied()
+                .find(|&size| size > current)
+                .unwrap_or(first)
+        } else {
+            options
+                .iter()
+                .rev()
+                .copied()
+                .find(|&size| size < current)
+                .unwrap_or(last)
+        };
+        next.to_string()
+    }
+
+    /// Formats a size in MB for display, using GB for whole gigabytes.
+    pub fn format_size_mb(size: &str) -> String {
+        match size.trim().parse::<u32>() {
+            Ok(mb) if mb >= STORAGE_MB_TO_GB_DIVISOR && mb % STORAGE_MB_TO_GB_DIVISOR == 0 => {
+                format!("{} GB", mb / STORAGE_MB_TO_GB_DIVISOR)
+            }
+            Ok(mb) => format!("{mb} MB"),
+            Err(_) => size.to_string(),
         }
     }
 
+    /// Returns a warning when the selected RAM is more than the host has.
+    pub fn ram_warning(&self, host_memory_mb: Option<u32>) -> Option<String> {
+        let host_memory_mb = host_memory_mb?;
+        let ram_mb = self.ram_size.trim().parse::<u32>().ok()?;
+        (ram_mb > host_memory_mb).then(|| {
+            RAM_EXCEEDS_HOST_MEMORY_WARNING
+                .replace("{}", &Self::format_size_mb(&host_memory_mb.to_string()))
+        })
+    }
+
+    /// Preselects the template's device type, version, RAM, and storage once the
+    /// available options are loaded. Values that are no longer available keep
+    /// the form's defaults. Focus stays on the version field so only that needs
+    /// changing before submitting.
+    pub fn apply_template(&mut self) {
+        let Some(template) = self.template.clone() else {
+            return;
+        };
+
+        if let Some(index) = self
+            .available_device_types
+            .iter()
+            .position(|(id, display)| {
+                id.eq_ignore_ascii_case(&template.device_type)
+                    || display.eq_ignore_ascii_case(&template.device_type)
+            })
+        {
+            self.selected_device_type_index = index;
+            self.update_selected_device_type();
+        }
+        if let Some(index) = self
+            .available_versions
+            .iter()
+            .position(|(value, _)| *value == template.version)
+        {
+            self.selected_api_level_index = index;
+            self.update_selected_api_level();
+        }
+        if let Some(ram_size) = template.ram_size {
+            self.ram_size = ram_size;
+        }
+        if let Some(storage_size) = template.storage_size {
+            self.storage_size = storage_size;
+        }
+        self.active_field = CreateDeviceField::ApiLevel;
+    }
+
     /// Generates a placeholder name based on selected device type and API level.
-    /// Uses DynamicDeviceConfig for intelligent parsing of device names.
-    /// Falls back to simple concatenation if parsing fails.
+    /// A naming template from `.emu.toml` wins, suffixed when the name is taken.
+    /// Otherwise uses DynamicDeviceConfig for intelligent parsing of device names,
+    /// falling back to simple concatenation if parsing fails.
     pub fn generate_placeholder_name(&mut self) {
+        let parts = NameParts {
+            device_type: &self.device_type_id,
+            device_display: &self.device_type,
+            version: &self.version,
+            version_display: &self.version_display,
+        };
+        if let Some(name) = self.naming.render(parts) {
+            self.name = self.naming.unused_name(&name, |candidate| {
+                let candidate = candidate.replace(' ', "_");
+                self.taken_names
+                    .iter()
+                    .any(|taken| taken.replace(' ', "_") == candidate)
+            });
+            return;
+        }
+
         let device_config = DynamicDeviceConfig::new();
         let device_part = if !self.device_type.is_empty() {
             let parsed_parts = device_config.parse_device_name(&self.device_type);
@@ -284,7 +537,118 @@ impl CreateDeviceForm {
     }
 }
 
+/// Converts sizes such as "2048", "2048 MB", "8192M", or "8G" to a plain MB value.
+fn size_in_mb(size: &str) -> Option<String> {
+    let size = size
+        .trim()
+        .trim_end_matches('B')
+        .trim_end_matches('M')
+        .trim();
+    let mb = match size.strip_suffix('G') {
+        Some(gb) => gb.trim().parse::<u32>().ok()? * STORAGE_MB_TO_GB_DIVISOR,
+        None => size.parse::<u32>().ok()?,
+    };
+    Some(mb.to_string())
+}
+
 impl AppState {
+    /// Names of the devices on `panel`, iOS ones without the runtime suffix.
+    pub fn device_names(&self, panel: Panel) -> Vec<String> {
+        match panel {
+            Panel::Android => self
+                .android_devices
+                .iter()
+                .map(|device| device.name.clone())
+                .collect(),
+            Panel::Ios => self
+                .ios_devices
+                .iter()
+                .map(|device| {
+                    device
+                        .name
+                        .rsplit_once(" (")
+                        .map_or(device.name.as_str(), |(base, _)| base)
+                        .to_string()
+                })
+                .collect(),
+        }
+    }
+
+    /// Validates the name entered in the device creation form.
+    /// Spaces are checked as underscores since that is how they end up in the AVD name.
+    /// Returns an error message when the name has invalid characters or is already taken.
+    pub fn validate_create_device_name(&self) -> Result<(), String> {
+        let name = self.create_device_form.name.trim();
+        let normalized = name.replace(' ', "_");
+
+        let platform = match self.active_panel {
+            Panel::Android => DevicePlatform::Android,
+            Panel::Ios => DevicePlatform::Ios,
+        };
+        DeviceNameValidator::new(platform).validate(&normalized)?;
+
+        let is_duplicate = match self.active_panel {
+            Panel::Android => self
+                .android_devices
+                .iter()
+                .any(|device| device.name.replace(' ', "_") == normalized),
+            Panel::Ios => self.ios_devices.iter().any(|device| {
+                let base_name = device
+                    .name
+                    .rsplit_once(" (")
+                    .map_or(device.name.as_str(), |(base, _)| base);
+                base_name == name
+            }),
+        };
+
+        if is_duplicate {
+            return Err(DEVICE_NAME_DUPLICATE_ERROR.replace("{}", name));
+        }
+
+        Ok(())
+    }
+
+    /// Captures the selected device's configuration for the "duplicate with
+    /// changes" flow. Android RAM and storage come from the loaded details when
+    /// they belong to the selected device, since the device list only holds defaults.
+    pub fn create_device_template(&self) -> Option<CreateDeviceTemplate> {
+        match self.active_panel {
+            Panel::Android => {
+                let device = self.android_devices.get(self.selected_android)?;
+                let details = self
+                    .cached_device_details
+                    .as_ref()
+                    .filter(|details| details.identifier == device.name);
+                let ram_size = details
+                    .and_then(|details| details.ram_size.as_deref())
+                    .unwrap_or(&device.ram_size);
+                let storage_size = details
+                    .and_then(|details| details.storage_size.as_deref())
+                    .unwrap_or(&device.storage_size);
+                Some(CreateDeviceTemplate {
+                    source_name: device.name.clone(),
+                    device_type: device.device_type.clone(),
+                    version: device.api_level.to_string(),
+                    ram_size: size_in_mb(ram_size),
+                    storage_size: size_in_mb(storage_size),
+                })
+            }
+            Panel::Ios => {
+                let device = self.ios_devices.get(self.selected_ios)?;
+                Some(CreateDeviceTemplate {
+                    source_name: device.name.clone(),
+                    device_type: device.device_type.clone(),
+                    version: format!(
+                        "{IOS_RUNTIME_PREFIX}{}",
+                        device.ios_version.replace('.', "-")
+                    ),
+                    ram_size: None,
+                    storage_size: None,
+                })
+            }
+        }
+    }
+
     /// Populates the device creation form from cached data.
     /// Updates available device types and versions based on platform.
     /// Also handles initial selection and category filtering setup.
@@ -337,6 +701,7 @@ impl AppState {
             }
         }
 
+        self.create_device_form.apply_template();
         self.create_device_form.is_loading_cache = false;
     }
 
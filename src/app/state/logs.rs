@@ -1,4 +1,5 @@
 use super::AppState;
+use crate::models::{DeviceId, Platform};
 
 /// Represents a single log entry from device output.
 /// Used for displaying device logs in the UI.
@@ -12,6 +13,13 @@ pub struct LogEntry {
     pub message: String,
 }
 
+impl LogEntry {
+    /// Uncolored single-line form, e.g. `12:00:01 [INFO] message`.
+    pub fn plain_line(&self) -> String {
+        format!("{} [{}] {}", self.timestamp, self.level, self.message)
+    }
+}
+
 impl AppState {
     /// Adds a new log entry to the device log queue.
     /// Automatically manages log rotation when max_log_entries is exceeded.
@@ -36,6 +44,15 @@ impl AppState {
         }
     }
 
+    /// Removes the collected log entries and returns them as plain lines,
+    /// oldest first.
+    pub fn take_plain_log_lines(&mut self) -> Vec<String> {
+        self.device_logs
+            .drain(..)
+            .map(|entry| entry.plain_line())
+            .collect()
+    }
+
     /// Clears all device logs from memory.
     pub fn clear_logs(&mut self) {
         self.device_logs.clear();
@@ -86,8 +103,29 @@ impl AppState {
     }
 
     /// Toggles fullscreen log display mode.
+    /// The display modes are exclusive, so this leaves the other ones.
     pub fn toggle_fullscreen_logs(&mut self) {
-        self.fullscreen_logs = !self.fullscreen_logs;
+        let enabled = !self.fullscreen_logs;
+        self.set_display_mode(enabled, false, false);
+    }
+
+    /// Toggles the mode that shows only the device lists.
+    pub fn toggle_fullscreen_devices(&mut self) {
+        let enabled = !self.fullscreen_devices;
+        self.set_display_mode(false, enabled, false);
+    }
+
+    /// Toggles zen log mode, which shows plain log lines with no borders,
+    /// header, or shortcuts so they can be selected and copied cleanly.
+    pub fn toggle_zen_logs(&mut self) {
+        let enabled = !self.zen_logs;
+        self.set_display_mode(false, false, enabled);
+    }
+
+    fn set_display_mode(&mut self, fullscreen_logs: bool, fullscreen_devices: bool, zen: bool) {
+        self.fullscreen_logs = fullscreen_logs;
+        self.fullscreen_devices = fullscreen_devices;
+        self.zen_logs = zen;
     }
 
     /// Toggles automatic log scrolling.
@@ -179,4 +217,19 @@ impl AppState {
             }
         }
     }
+
+    /// Updates the status of any device after a start or stop.
+    /// A simulator toggled this way runs with its window, so it is no
+    /// longer marked headless.
+    pub fn update_single_device_status(&mut self, id: &DeviceId, is_running: bool) {
+        match id.platform {
+            Platform::Android => {
+                self.update_single_android_device_status(&id.identifier, is_running)
+            }
+            Platform::Ios => {
+                self.update_single_ios_device_status(&id.identifier, is_running);
+                self.set_ios_device_headless(&id.identifier, false);
+            }
+        }
+    }
 }
@@ -11,32 +11,56 @@
 //! State updates are performed through methods that ensure consistency and thread safety.
 //! Background operations use async tasks with proper synchronization through RwLock.
 
+mod activities;
+mod annotations;
 mod api_levels;
 mod cache;
+mod creation_queue;
 mod details;
 mod forms;
 mod logs;
 mod navigation;
 mod notifications;
+mod project;
+mod reconciliation;
 #[cfg(test)]
 mod tests;
 mod ui;
 
 use crate::constants::{
-    timeouts::{DEFAULT_AUTO_REFRESH_INTERVAL, FAST_REFRESH_INTERVAL_SECS},
+    timeouts::{DEFAULT_AUTO_REFRESH_INTERVAL, FAST_REFRESH_INTERVAL_SECS, IDLE_INPUT_THRESHOLD},
     MAX_LOG_ENTRIES, MAX_NOTIFICATIONS,
 };
-use crate::models::{AndroidDevice, IosDevice};
-use std::collections::VecDeque;
+use crate::models::{
+    AndroidDevice, DeviceAnnotations, DeviceCapabilities, DeviceHistory, DeviceId, DeviceStatus,
+    IntentHistory, IosDevice, PanelLayout, ProjectConfig, TestSettingsBackup,
+};
+use crate::ui::Theme;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-pub use self::api_levels::ApiLevelManagementState;
+pub use self::activities::{DeviceActivities, DeviceActivity};
+pub use self::api_levels::{
+    ApiLevelFilter, ApiLevelManagementState, ApiLevelSort, InstallQueue, QueuedInstall,
+    QueuedInstallStatus, UninstallConfirmation,
+};
 pub use self::cache::DeviceCache;
-pub use self::forms::{CreateDeviceField, CreateDeviceForm};
+pub use self::creation_queue::{CreationQueue, QueuedCreation, QueuedCreationStatus};
+pub use self::forms::{CreateDeviceField, CreateDeviceForm, CreateDeviceTemplate};
 pub use self::logs::LogEntry;
 pub use self::notifications::{Notification, NotificationType};
-pub use self::ui::{ConfirmDeleteDialog, ConfirmWipeDialog, FocusedPanel, Mode, Panel};
+pub use self::reconciliation::{ExpectedTransition, ReconciliationJournal};
+pub use self::ui::{
+    AnnotationDialog, AnnotationField, AppConsole, AppDataDialog, CommandHistoryDialog,
+    ConfirmDeleteDialog, ConfirmWipeDialog, DetailsTab, DetailsTabContent, DeveloperTogglesDialog,
+    DeviceCatalogDialog, DeviceClockDialog, DiagnosticsDialog, FocusedPanel, IntentDialog,
+    IntentField, IosToolingStatus, LaunchAppDialog, LaunchAppField, LaunchDiagnosisDialog, Mode,
+    MonkeyDialog, MonkeyField, MoveDeviceDataDialog, NetworkCapture, Panel, ProcessInspectorDialog,
+    ProcessInspectorTab, PruneUnavailableDialog, QuickBootDialog, RadiosDialog, RepairDeviceDialog,
+    SnippetsDialog, SystemPropertiesDialog, TestMatrixDialog, TestMatrixField, TraceDialog,
+    TraceField, TypeIntoDeviceDialog,
+};
 pub use crate::models::DeviceDetails;
 
 /// Main application state containing all UI and data state.
@@ -66,6 +90,10 @@ pub struct AppState {
     pub confirm_delete_dialog: Option<ConfirmDeleteDialog>,
     /// Active wipe confirmation dialog data
     pub confirm_wipe_dialog: Option<ConfirmWipeDialog>,
+    /// Active repair dialog data for an unavailable simulator
+    pub repair_device_dialog: Option<RepairDeviceDialog>,
+    /// Active preview of unavailable simulators to prune
+    pub prune_unavailable_dialog: Option<PruneUnavailableDialog>,
     /// Queue of user notifications
     pub notifications: VecDeque<Notification>,
     /// Maximum number of notifications to display
@@ -76,34 +104,146 @@ pub struct AppState {
     pub log_filter_level: Option<String>,
     /// Timestamp of last device list refresh
     pub last_refresh: std::time::Instant,
+    /// True while the device lists come from the persisted inventory and no
+    /// live refresh has confirmed them yet
+    pub showing_cached_inventory: bool,
+    /// Timestamp of the last key press, used to detect idle sessions
+    pub last_input: std::time::Instant,
     /// Interval for automatic device list refresh
     pub auto_refresh_interval: std::time::Duration,
+    /// User-configured refresh interval, restored after a fast refresh ends
+    pub configured_refresh_interval: std::time::Duration,
+    /// Background polling is paused (toggled with `p`)
+    pub auto_refresh_paused: bool,
+    /// Operations that change devices or the SDK are turned off (`--read-only`)
+    pub read_only: bool,
+    /// A manual refresh was requested and runs after the next frame is drawn
+    pub manual_refresh_pending: bool,
+    /// Whether the adb server answered the last device query (`None` until asked)
+    pub adb_server_healthy: Option<bool>,
+    /// Device whose screen is currently being recorded, if any
+    pub active_recording: Option<String>,
     /// Name of device that was just started (triggers faster refresh)
     pub pending_device_start: Option<String>,
+    /// When the pending device start began
+    pub pending_device_start_at: Option<std::time::Instant>,
+    /// Running devices that did not answer the last health check
+    pub wedged_devices: HashSet<DeviceId>,
     /// Shared cache for device creation options
     pub device_cache: Arc<RwLock<DeviceCache>>,
     /// Current device operation status message
     pub device_operation_status: Option<String>,
+    /// Operations running against each device, shown on its row
+    pub device_activities: DeviceActivities,
+    /// Starts and stops shown before a refresh confirmed them
+    pub reconciliation_journal: ReconciliationJournal,
     /// Which panel currently has keyboard focus
     pub focused_panel: FocusedPanel,
     /// Flag for fullscreen log display mode
     pub fullscreen_logs: bool,
+    /// Flag for the device-lists-only display mode
+    pub fullscreen_devices: bool,
+    /// Flag for the zen log mode: plain log lines without any chrome
+    pub zen_logs: bool,
+    /// Whether the frame, lock, refresh, and command timings are shown
+    pub show_perf_overlay: bool,
     /// Flag for automatic log scrolling
     pub auto_scroll_logs: bool,
     /// Flag indicating user has manually scrolled logs
     pub manually_scrolled: bool,
+    /// Log markers written this session, numbering the next one
+    pub log_marker_count: usize,
     /// Device whose logs are currently being streamed
     pub current_log_device: Option<(Panel, String)>,
     /// Handle to the background log streaming task
     pub log_task_handle: Option<tokio::task::JoinHandle<()>>,
     /// Cached device details for the details panel
     pub cached_device_details: Option<DeviceDetails>,
+    /// Details fetched per device, so selection changes show them at once
+    pub prefetched_device_details: HashMap<DeviceId, DeviceDetails>,
+    /// Sub-view shown in the details panel
+    pub details_tab: DetailsTab,
+    /// Content of the current details tab, once loaded
+    pub details_tab_content: Option<DetailsTabContent>,
+    /// Lifecycle operations recorded per device
+    pub device_history: DeviceHistory,
+    /// User-defined tags and notes per device
+    pub device_annotations: DeviceAnnotations,
+    /// Annotation editor data (when the editor is open)
+    pub annotation_dialog: Option<AnnotationDialog>,
+    /// Target directory prompt for moving an AVD's data (when open)
+    pub move_device_data_dialog: Option<MoveDeviceDataDialog>,
+    /// Intents recently sent to each AVD
+    pub intent_history: IntentHistory,
+    /// Intent launcher data (when the launcher is open)
+    pub intent_dialog: Option<IntentDialog>,
+    /// Process inspector data (when the inspector is open)
+    pub process_inspector_dialog: Option<ProcessInspectorDialog>,
+    /// System property browser data (when the browser is open)
+    pub system_properties_dialog: Option<SystemPropertiesDialog>,
+    /// Monkey launcher data (when the launcher is open)
+    pub monkey_dialog: Option<MonkeyDialog>,
+    /// AVD name and task of the monkey run in progress
+    pub monkey_task: Option<(String, tokio::task::JoinHandle<()>)>,
+    /// Launch diagnosis data (when the diagnosis is shown)
+    pub launch_diagnosis_dialog: Option<LaunchDiagnosisDialog>,
+    /// Quick Boot options data (when the options are open)
+    pub quick_boot_dialog: Option<QuickBootDialog>,
+    /// Device clock prompt data (when the prompt is open)
+    pub device_clock_dialog: Option<DeviceClockDialog>,
+    /// Keyboard forwarding data (while typing into a device)
+    pub type_into_device_dialog: Option<TypeIntoDeviceDialog>,
+    /// Radio toggles data (when the dialog is open)
+    pub radios_dialog: Option<RadiosDialog>,
+    /// Recommended device catalog data (when the catalog is open)
+    pub device_catalog_dialog: Option<DeviceCatalogDialog>,
+    /// Test matrix wizard data (when the wizard is open)
+    pub test_matrix_dialog: Option<TestMatrixDialog>,
+    /// Shell snippet picker data (when the picker is open)
+    pub snippets_dialog: Option<SnippetsDialog>,
+    /// App launcher data (when the launcher is open)
+    pub launch_app_dialog: Option<LaunchAppDialog>,
+    /// iOS app whose console output is being shown
+    pub app_console: Option<AppConsole>,
+    /// Network captures that are running
+    pub network_captures: Vec<NetworkCapture>,
+    /// Trace capture dialog data (when the dialog is open)
+    pub trace_dialog: Option<TraceDialog>,
+    /// Developer toggles data (when the dialog is open)
+    pub developer_toggles_dialog: Option<DeveloperTogglesDialog>,
+    /// App data backups (when the dialog is open)
+    pub app_data_dialog: Option<AppDataDialog>,
+    /// Device search query, kept after the prompt closes for `n`
+    pub search_query: String,
+    /// Project `.emu.toml` found in the working directory, if any
+    pub project_config: Option<ProjectConfig>,
+    /// Split ratios of the device, details, and log panels
+    pub panel_layout: PanelLayout,
+    /// Colors and styles the UI is drawn with
+    pub theme: Theme,
     /// Scroll offset for Android device list
     pub android_scroll_offset: usize,
     /// Scroll offset for iOS device list
     pub ios_scroll_offset: usize,
     /// API level management dialog state (when dialog is open)
     pub api_level_management: Option<ApiLevelManagementState>,
+    /// Command line shown in the details panel (toggled with `y`)
+    pub shown_command: Option<String>,
+    /// Command history dialog data (when dialog is open)
+    pub command_history_dialog: Option<CommandHistoryDialog>,
+    /// Diagnostics viewer data (when the viewer is open)
+    pub diagnostics_dialog: Option<DiagnosticsDialog>,
+    /// Device creations queued from the creation form
+    pub creation_queue: CreationQueue,
+    /// Result of the background check of the Xcode tooling
+    pub ios_tooling: IosToolingStatus,
+    /// UDIDs of iOS simulators booted without opening Simulator.app
+    pub headless_ios_devices: HashSet<String>,
+    /// Ephemeral simulator clones, deleted once they stop, and AVDs running
+    /// read-only, with whether a device list has shown them running yet
+    pub ephemeral_devices: HashMap<DeviceId, bool>,
+    /// Settings devices had before the UI test settings were applied
+    pub test_settings_backups: HashMap<DeviceId, TestSettingsBackup>,
 }
 
 impl Default for AppState {
@@ -121,25 +261,82 @@ impl Default for AppState {
             create_device_form: CreateDeviceForm::default(),
             confirm_delete_dialog: None,
             confirm_wipe_dialog: None,
+            repair_device_dialog: None,
+            prune_unavailable_dialog: None,
             notifications: VecDeque::new(),
             max_notifications: MAX_NOTIFICATIONS,
             log_scroll_offset: 0,
             log_filter_level: None,
             last_refresh: std::time::Instant::now(),
+            showing_cached_inventory: false,
+            last_input: std::time::Instant::now(),
             auto_refresh_interval: DEFAULT_AUTO_REFRESH_INTERVAL, // 3-second refresh
+            configured_refresh_interval: DEFAULT_AUTO_REFRESH_INTERVAL,
+            auto_refresh_paused: false,
+            read_only: false,
+            manual_refresh_pending: false,
+            adb_server_healthy: None,
+            active_recording: None,
             pending_device_start: None,
+            pending_device_start_at: None,
+            wedged_devices: HashSet::new(),
             device_cache: Arc::new(RwLock::new(DeviceCache::default())),
             device_operation_status: None,
+            device_activities: DeviceActivities::default(),
+            reconciliation_journal: ReconciliationJournal::default(),
             focused_panel: FocusedPanel::DeviceList,
             fullscreen_logs: false,
+            fullscreen_devices: false,
+            zen_logs: false,
+            show_perf_overlay: false,
             auto_scroll_logs: true,
             manually_scrolled: false,
+            log_marker_count: 0,
             current_log_device: None,
             log_task_handle: None,
             cached_device_details: None,
+            prefetched_device_details: HashMap::new(),
+            details_tab: DetailsTab::default(),
+            details_tab_content: None,
+            device_history: DeviceHistory::default(),
+            device_annotations: DeviceAnnotations::default(),
+            annotation_dialog: None,
+            move_device_data_dialog: None,
+            intent_history: IntentHistory::default(),
+            intent_dialog: None,
+            process_inspector_dialog: None,
+            system_properties_dialog: None,
+            monkey_dialog: None,
+            monkey_task: None,
+            launch_diagnosis_dialog: None,
+            quick_boot_dialog: None,
+            device_clock_dialog: None,
+            type_into_device_dialog: None,
+            radios_dialog: None,
+            device_catalog_dialog: None,
+            test_matrix_dialog: None,
+            snippets_dialog: None,
+            launch_app_dialog: None,
+            app_console: None,
+            network_captures: Vec::new(),
+            trace_dialog: None,
+            developer_toggles_dialog: None,
+            app_data_dialog: None,
+            search_query: String::new(),
+            project_config: None,
+            panel_layout: PanelLayout::default(),
+            theme: Theme::dark(),
             android_scroll_offset: 0,
             ios_scroll_offset: 0,
             api_level_management: None,
+            shown_command: None,
+            command_history_dialog: None,
+            diagnostics_dialog: None,
+            creation_queue: CreationQueue::default(),
+            ios_tooling: IosToolingStatus::default(),
+            headless_ios_devices: HashSet::new(),
+            ephemeral_devices: HashMap::new(),
+            test_settings_backups: HashMap::new(),
         }
     }
 }
@@ -182,6 +379,126 @@ impl AppState {
         self.mode == Mode::ManageApiLevels
     }
 
+    /// Returns true if the command history dialog is open.
+    pub fn is_command_history_mode(&self) -> bool {
+        self.mode == Mode::CommandHistory
+    }
+
+    /// Returns true if the repair dialog of an unavailable simulator is open.
+    pub fn is_repair_device_mode(&self) -> bool {
+        self.mode == Mode::RepairDevice
+    }
+
+    /// Returns true if the unavailable simulator prune preview is open.
+    pub fn is_prune_unavailable_mode(&self) -> bool {
+        self.mode == Mode::PruneUnavailable
+    }
+
+    /// Returns true if the creation queue view is open.
+    pub fn is_creation_queue_mode(&self) -> bool {
+        self.mode == Mode::CreationQueue
+    }
+
+    /// Returns true if the diagnostics viewer is open.
+    pub fn is_diagnostics_mode(&self) -> bool {
+        self.mode == Mode::Diagnostics
+    }
+
+    /// Returns true if the device tags and note editor is open.
+    pub fn is_edit_annotation_mode(&self) -> bool {
+        self.mode == Mode::EditAnnotation
+    }
+
+    /// Returns true if the device search prompt is open.
+    pub fn is_search_mode(&self) -> bool {
+        self.mode == Mode::Search
+    }
+
+    /// Returns true if the move device data prompt is open.
+    pub fn is_move_device_data_mode(&self) -> bool {
+        self.mode == Mode::MoveDeviceData
+    }
+
+    /// Returns true if the intent launcher is open.
+    pub fn is_send_intent_mode(&self) -> bool {
+        self.mode == Mode::SendIntent
+    }
+
+    /// Returns true if the process inspector is open.
+    pub fn is_process_inspector_mode(&self) -> bool {
+        self.mode == Mode::ProcessInspector
+    }
+
+    /// Returns true if the system property browser is open.
+    pub fn is_system_properties_mode(&self) -> bool {
+        self.mode == Mode::SystemProperties
+    }
+
+    /// Returns true if the monkey launcher is open.
+    pub fn is_run_monkey_mode(&self) -> bool {
+        self.mode == Mode::RunMonkey
+    }
+
+    /// Returns true if the launch diagnosis is shown.
+    pub fn is_launch_diagnosis_mode(&self) -> bool {
+        self.mode == Mode::LaunchDiagnosis
+    }
+
+    /// Returns true if the Quick Boot options are open.
+    pub fn is_quick_boot_mode(&self) -> bool {
+        self.mode == Mode::QuickBoot
+    }
+
+    /// Returns true if the device clock prompt is open.
+    pub fn is_device_clock_mode(&self) -> bool {
+        self.mode == Mode::DeviceClock
+    }
+
+    /// Returns true while keystrokes are forwarded to a device.
+    pub fn is_type_into_device_mode(&self) -> bool {
+        self.mode == Mode::TypeIntoDevice
+    }
+
+    /// Returns true if the radio toggles are open.
+    pub fn is_radios_mode(&self) -> bool {
+        self.mode == Mode::Radios
+    }
+
+    /// Returns true if the recommended device catalog is open.
+    pub fn is_device_catalog_mode(&self) -> bool {
+        self.mode == Mode::DeviceCatalog
+    }
+
+    /// Returns true if the test matrix wizard is open.
+    pub fn is_test_matrix_mode(&self) -> bool {
+        self.mode == Mode::TestMatrix
+    }
+
+    /// Returns true if the shell snippet picker is open.
+    pub fn is_snippets_mode(&self) -> bool {
+        self.mode == Mode::Snippets
+    }
+
+    /// Returns true if the app launcher is open.
+    pub fn is_launch_app_mode(&self) -> bool {
+        self.mode == Mode::LaunchApp
+    }
+
+    /// Returns true if the trace capture dialog is open.
+    pub fn is_capture_trace_mode(&self) -> bool {
+        self.mode == Mode::CaptureTrace
+    }
+
+    /// Returns true if the developer toggles are open.
+    pub fn is_developer_toggles_mode(&self) -> bool {
+        self.mode == Mode::DeveloperToggles
+    }
+
+    /// Returns true if the app data backups are open.
+    pub fn is_app_data_mode(&self) -> bool {
+        self.mode == Mode::AppData
+    }
+
     // --- Panel predicates ---
 
     /// Returns true if the Android panel is active.
@@ -194,6 +511,12 @@ impl AppState {
         self.active_panel == Panel::Ios
     }
 
+    /// What the devices of the active panel support, with the operations
+    /// `--read-only` turns off removed.
+    pub fn active_capabilities(&self) -> DeviceCapabilities {
+        DeviceCapabilities::for_platform(self.active_panel.into()).with_read_only(self.read_only)
+    }
+
     // --- Device accessors ---
 
     /// Returns the number of Android devices.
@@ -265,20 +588,94 @@ impl AppState {
 
     /// Checks if device list should be automatically refreshed.
     /// Returns true if refresh interval elapsed or device start is pending.
+    /// While paused, only a pending device start keeps polling.
     pub fn should_auto_refresh(&self) -> bool {
-        self.last_refresh.elapsed() >= self.auto_refresh_interval
-            || self.pending_device_start.is_some()
+        if self.pending_device_start.is_some() {
+            return true;
+        }
+        !self.auto_refresh_paused
+            && !self.is_idle()
+            && self.last_refresh.elapsed() >= self.auto_refresh_interval
+    }
+
+    /// Records a key press, which ends an idle period.
+    pub fn mark_input(&mut self) {
+        self.last_input = std::time::Instant::now();
+    }
+
+    /// Returns true when there has been no input for a while and no device is
+    /// changing state, so polling can slow down and refresh can pause.
+    pub fn is_idle(&self) -> bool {
+        self.last_input.elapsed() >= IDLE_INPUT_THRESHOLD && !self.has_transitioning_devices()
+    }
+
+    fn has_transitioning_devices(&self) -> bool {
+        let transitioning = |status: &DeviceStatus| {
+            matches!(
+                status,
+                DeviceStatus::Starting | DeviceStatus::Stopping | DeviceStatus::Creating
+            )
+        };
+        self.pending_device_start.is_some()
+            || self.device_operation_status.is_some()
+            || !self.device_activities.is_empty()
+            || self.creation_queue.active_count() > 0
+            || self
+                .android_devices
+                .iter()
+                .any(|device| transitioning(&device.status))
+            || self
+                .ios_devices
+                .iter()
+                .any(|device| transitioning(&device.status))
     }
 
     /// Updates the last refresh timestamp to current time.
     pub fn mark_refreshed(&mut self) {
         self.last_refresh = std::time::Instant::now();
+        self.showing_cached_inventory = false;
+    }
+
+    /// Returns how long ago the device lists were last refreshed.
+    pub fn refresh_age(&self) -> std::time::Duration {
+        self.last_refresh.elapsed()
+    }
+
+    /// Sets how many queued device creations run at the same time. Only
+    /// affects creations queued afterwards.
+    pub fn set_max_parallel_creates(&mut self, max_parallel: usize) {
+        self.creation_queue = CreationQueue::with_max_parallel(max_parallel);
+    }
+
+    /// Sets the normal auto-refresh interval. A zero interval pauses auto-refresh.
+    /// A fast refresh for a pending device start keeps running until it ends.
+    pub fn set_auto_refresh_interval(&mut self, interval: std::time::Duration) {
+        if interval.is_zero() {
+            self.auto_refresh_paused = true;
+            return;
+        }
+        self.configured_refresh_interval = interval;
+        if self.pending_device_start.is_none() {
+            self.auto_refresh_interval = interval;
+        }
+    }
+
+    /// Pauses or resumes background polling. Returns true if now paused.
+    pub fn toggle_auto_refresh_paused(&mut self) -> bool {
+        self.auto_refresh_paused = !self.auto_refresh_paused;
+        self.auto_refresh_paused
+    }
+
+    /// Requests a manual refresh, shown as a spinner until it completes.
+    pub fn request_manual_refresh(&mut self) {
+        self.manual_refresh_pending = true;
     }
 
     /// Sets a device as pending start, triggering faster refresh.
     /// Reduces refresh interval to 1 second for quicker status updates.
     pub fn set_pending_device_start(&mut self, device_name: String) {
         self.pending_device_start = Some(device_name);
+        self.pending_device_start_at = Some(std::time::Instant::now());
         // Refresh more frequently when device is starting
         self.auto_refresh_interval = std::time::Duration::from_secs(FAST_REFRESH_INTERVAL_SECS);
     }
@@ -286,8 +683,28 @@ impl AppState {
     /// Clears pending device start and returns to normal refresh interval.
     pub fn clear_pending_device_start(&mut self) {
         self.pending_device_start = None;
+        self.pending_device_start_at = None;
         // Return to normal refresh interval
-        self.auto_refresh_interval = DEFAULT_AUTO_REFRESH_INTERVAL;
+        self.auto_refresh_interval = self.configured_refresh_interval;
+    }
+
+    /// Counts operations currently running in the background: device
+    /// operations, a pending start, device creation (from the form or the
+    /// queue), system image work, and a manual refresh.
+    pub fn active_operation_count(&self) -> usize {
+        [
+            self.device_operation_status.is_some(),
+            self.pending_device_start.is_some(),
+            self.create_device_form.is_creating,
+            self.creation_queue.active_count() > 0,
+            self.api_level_management
+                .as_ref()
+                .is_some_and(|dialog| dialog.is_busy()),
+            self.manual_refresh_pending,
+        ]
+        .into_iter()
+        .filter(|active| *active)
+        .count()
     }
 
     /// Gets the name of device pending start, if any.
@@ -295,6 +712,31 @@ impl AppState {
         self.pending_device_start.as_ref()
     }
 
+    /// Returns true if the device did not answer the last health check.
+    pub fn is_device_wedged(&self, panel: Panel, identifier: &str) -> bool {
+        self.wedged_devices.contains(&panel.device_id(identifier))
+    }
+
+    /// Replaces the wedged devices with the result of a health check and
+    /// returns the ones that stopped responding since the last check.
+    pub fn set_wedged_devices(&mut self, wedged: HashSet<DeviceId>) -> Vec<DeviceId> {
+        let newly_wedged = wedged.difference(&self.wedged_devices).cloned().collect();
+        self.wedged_devices = wedged;
+        newly_wedged
+    }
+
+    /// Ends a pending device start that has not completed within `timeout`
+    /// and returns the name of the device.
+    pub fn take_stalled_device_start(&mut self, timeout: std::time::Duration) -> Option<String> {
+        let started_at = self.pending_device_start_at?;
+        if started_at.elapsed() < timeout {
+            return None;
+        }
+        let device_name = self.pending_device_start.clone();
+        self.clear_pending_device_start();
+        device_name
+    }
+
     /// Sets the current device operation status message.
     /// Used to display progress for long-running operations.
     pub fn set_device_operation_status(&mut self, status: String) {
@@ -36,6 +36,24 @@ impl AppState {
         }
     }
 
+    /// Selects the first device of the current list.
+    pub fn select_first(&mut self) {
+        match self.active_panel {
+            Panel::Android => {
+                if !self.android_devices.is_empty() {
+                    self.selected_android = 0;
+                    self.update_android_scroll_offset();
+                }
+            }
+            Panel::Ios => {
+                if !self.ios_devices.is_empty() {
+                    self.selected_ios = 0;
+                    self.update_ios_scroll_offset();
+                }
+            }
+        }
+    }
+
     /// Moves selection down in the current device list.
     /// Wraps around from bottom to top when reaching the last item.
     pub fn move_down(&mut self) {
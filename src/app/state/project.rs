@@ -0,0 +1,59 @@
+use super::{AppState, Panel};
+use crate::models::{
+    AndroidDevice, ConfirmationPolicy, DeviceListColumns, IosDevice, ProjectConfig,
+};
+
+impl AppState {
+    /// Applies a project `.emu.toml`, moving its pinned devices to the top.
+    pub fn set_project_config(&mut self, config: ProjectConfig) {
+        self.project_config = Some(config);
+        self.sort_pinned_devices_first(Panel::Android);
+        self.sort_pinned_devices_first(Panel::Ios);
+    }
+
+    /// Post-processing after a panel's device list was replaced with fresh data.
+    pub fn device_list_updated(&mut self, panel: Panel) {
+        self.sort_pinned_devices_first(panel);
+        self.track_device_usage(panel);
+        self.prune_prefetched_device_details(panel);
+        self.reconcile_device_states(panel);
+    }
+
+    pub(super) fn sort_pinned_devices_first(&mut self, panel: Panel) {
+        let Some(config) = &self.project_config else {
+            return;
+        };
+        match panel {
+            Panel::Android => config.sort_android_pinned_first(&mut self.android_devices),
+            Panel::Ios => config.sort_ios_pinned_first(&mut self.ios_devices),
+        }
+    }
+
+    pub fn is_android_device_pinned(&self, device: &AndroidDevice) -> bool {
+        self.project_config
+            .as_ref()
+            .is_some_and(|config| config.android_pin_index(device).is_some())
+    }
+
+    pub fn is_ios_device_pinned(&self, device: &IosDevice) -> bool {
+        self.project_config
+            .as_ref()
+            .is_some_and(|config| config.ios_pin_index(device).is_some())
+    }
+
+    /// Which operations ask for confirmation, from `.emu.toml`.
+    pub fn confirmation_policy(&self) -> ConfirmationPolicy {
+        self.project_config
+            .as_ref()
+            .map(|config| config.confirm)
+            .unwrap_or_default()
+    }
+
+    /// Columns of the device lists, from `.emu.toml`.
+    pub fn device_list_columns(&self) -> DeviceListColumns {
+        self.project_config
+            .as_ref()
+            .map(|config| config.columns.clone())
+            .unwrap_or_default()
+    }
+}
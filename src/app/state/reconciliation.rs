@@ -0,0 +1,138 @@
+use super::{AppState, Panel};
+use crate::constants::{
+    messages::notifications::{DEVICE_START_NOT_CONFIRMED, DEVICE_STOP_NOT_CONFIRMED},
+    timeouts::{DEVICE_START_STALL_TIMEOUT, DEVICE_STOP_CONFIRM_TIMEOUT},
+};
+use crate::models::DeviceId;
+use std::time::{Duration, Instant};
+
+/// A start or stop the device list shows before a refresh reported it.
+#[derive(Debug, Clone)]
+pub struct ExpectedTransition {
+    pub device: DeviceId,
+    /// Display name, for the notification when the device disagrees
+    pub name: String,
+    /// Whether the device should end up running
+    pub running: bool,
+    pub recorded_at: Instant,
+    /// How long the device may take to report `running`
+    pub grace: Duration,
+}
+
+impl ExpectedTransition {
+    fn is_overdue(&self) -> bool {
+        self.recorded_at.elapsed() >= self.grace
+    }
+}
+
+/// Transitions shown optimistically, each checked against the refreshes
+/// that follow until the device reports it or the grace period ends.
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationJournal {
+    pub entries: Vec<ExpectedTransition>,
+}
+
+impl ReconciliationJournal {
+    /// Records that `device` should end up `running` within `grace`,
+    /// replacing a transition recorded for it before.
+    pub fn record(&mut self, device: DeviceId, name: String, running: bool, grace: Duration) {
+        self.forget(&device);
+        self.entries.push(ExpectedTransition {
+            device,
+            name,
+            running,
+            recorded_at: Instant::now(),
+            grace,
+        });
+    }
+
+    /// Stops checking `device`, e.g. once its failure was reported otherwise.
+    pub fn forget(&mut self, device: &DeviceId) {
+        self.entries.retain(|entry| entry.device != *device);
+    }
+
+    pub fn expected(&self, device: &DeviceId) -> Option<bool> {
+        self.entries
+            .iter()
+            .find(|entry| entry.device == *device)
+            .map(|entry| entry.running)
+    }
+}
+
+impl AppState {
+    /// Shows a device as running or stopped right after a start or stop
+    /// succeeded, and records the transition for the next refreshes to
+    /// confirm or revert.
+    pub fn expect_device_state(&mut self, device: &DeviceId, name: &str, running: bool) {
+        let grace = if running {
+            DEVICE_START_STALL_TIMEOUT
+        } else {
+            DEVICE_STOP_CONFIRM_TIMEOUT
+        };
+        self.reconciliation_journal
+            .record(device.clone(), name.to_string(), running, grace);
+        self.update_single_device_status(device, running);
+    }
+
+    /// Checks the recorded transitions of `panel` against its refreshed
+    /// device list. A transition the device reports is dropped; one it does
+    /// not report yet stays shown until its grace period ends, when the
+    /// real state is shown instead and the mismatch notified.
+    pub fn reconcile_device_states(&mut self, panel: Panel) {
+        let entries: Vec<ExpectedTransition> = self
+            .reconciliation_journal
+            .entries
+            .iter()
+            .filter(|entry| Panel::from(entry.device.platform) == panel)
+            .cloned()
+            .collect();
+        for entry in entries {
+            let identifier = &entry.device.identifier;
+            let observed = match panel {
+                Panel::Android => self
+                    .android_devices
+                    .iter()
+                    .find(|device| device.name == *identifier)
+                    .map(|device| device.is_running),
+                Panel::Ios => self
+                    .ios_devices
+                    .iter()
+                    .find(|device| device.udid == *identifier)
+                    .map(|device| device.is_running),
+            };
+            let Some(observed) = observed else {
+                // Deleted meanwhile
+                self.reconciliation_journal.forget(&entry.device);
+                continue;
+            };
+            if observed == entry.running {
+                self.reconciliation_journal.forget(&entry.device);
+            } else if !entry.is_overdue() {
+                self.show_device_running(panel, identifier, entry.running);
+            } else {
+                self.reconciliation_journal.forget(&entry.device);
+                self.show_device_running(panel, identifier, observed);
+                let message = if entry.running {
+                    if self.pending_device_start.as_ref() == Some(&entry.name) {
+                        self.clear_pending_device_start();
+                    }
+                    DEVICE_START_NOT_CONFIRMED
+                } else {
+                    DEVICE_STOP_NOT_CONFIRMED
+                };
+                self.add_error_notification(message.replacen("{}", &entry.name, 1).replacen(
+                    "{}",
+                    &entry.grace.as_secs().to_string(),
+                    1,
+                ));
+            }
+        }
+    }
+
+    fn show_device_running(&mut self, panel: Panel, identifier: &str, running: bool) {
+        match panel {
+            Panel::Android => self.update_single_android_device_status(identifier, running),
+            Panel::Ios => self.update_single_ios_device_status(identifier, running),
+        }
+    }
+}
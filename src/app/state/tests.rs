@@ -18,6 +18,160 @@ fn test_api_level_management_state_new() {
     assert_eq!(state.scroll_offset, 0);
 }
 
+#[test]
+fn test_partial_api_levels_keep_selection_until_loaded() {
+    let level = |api: u32| crate::models::ApiLevel::new(api, format!("API {api}"), String::new());
+    let mut state = ApiLevelManagementState::new();
+
+    state.show_partial_api_levels(vec![level(34), level(33)]);
+    state.selected_index = 1;
+    state.show_partial_api_levels(vec![level(35), level(34), level(33)]);
+    assert_eq!(state.get_selected_api_level().unwrap().api, 33);
+
+    state.is_loading = false;
+    state.show_partial_api_levels(vec![level(36)]);
+    assert_eq!(state.api_levels.len(), 3);
+}
+
+#[test]
+fn test_api_levels_filter_search_and_sort() {
+    let level = |api: u32, installed: bool, size: u64| {
+        let mut level =
+            crate::models::ApiLevel::new(api, format!("Android {}", api - 20), String::new());
+        level.is_installed = installed;
+        level.variants.push(crate::models::SystemImageVariant {
+            variant: "google_apis".to_string(),
+            architecture: "x86_64".to_string(),
+            package_id: format!("system-images;android-{api};google_apis;x86_64"),
+            is_installed: installed,
+            display_name: "Google APIs".to_string(),
+            installed_size: installed.then_some(size),
+            download_size: Some(size),
+        });
+        level
+    };
+    let mut state = ApiLevelManagementState::new();
+    state.set_api_levels(vec![
+        level(35, false, 2_000),
+        level(34, true, 1_000),
+        level(33, false, 3_000),
+    ]);
+    state.selected_index = 1;
+
+    state.toggle_sort();
+    let apis = |state: &ApiLevelManagementState| -> Vec<u32> {
+        state
+            .visible_api_levels()
+            .iter()
+            .map(|level| level.api)
+            .collect()
+    };
+    assert_eq!(apis(&state), [33, 35, 34]);
+    assert_eq!(state.get_selected_api_level().unwrap().api, 34);
+
+    state.cycle_filter();
+    assert_eq!(state.filter, ApiLevelFilter::Installed);
+    assert_eq!(apis(&state), [34]);
+    state.cycle_filter();
+    assert_eq!(apis(&state), [33, 35]);
+    assert_eq!(state.selected_index, 0);
+
+    state.cycle_filter();
+    state.toggle_sort();
+    state.start_search();
+    for c in "android 13".chars() {
+        state.push_search_char(c);
+    }
+    assert_eq!(apis(&state), [33]);
+    state.pop_search_char();
+    state.push_search_char('5');
+    assert_eq!(apis(&state), [35]);
+    state.finish_search(true);
+    assert!(!state.is_searching);
+    assert_eq!(apis(&state), [35, 34, 33]);
+}
+
+#[test]
+fn test_marked_api_levels_form_the_install_queue() {
+    let level = |api: u32, installed: bool, size: u64| {
+        let mut level =
+            crate::models::ApiLevel::new(api, format!("Android {}", api - 20), String::new());
+        level.is_installed = installed;
+        level.variants.push(crate::models::SystemImageVariant {
+            variant: "google_apis".to_string(),
+            architecture: "x86_64".to_string(),
+            package_id: format!("system-images;android-{api};google_apis;x86_64"),
+            is_installed: installed,
+            display_name: "Google APIs".to_string(),
+            installed_size: None,
+            download_size: Some(size),
+        });
+        level
+    };
+    let mut state = ApiLevelManagementState::new();
+    state.set_api_levels(vec![
+        level(35, false, 3_000),
+        level(34, true, 1_000),
+        level(33, false, 1_000),
+    ]);
+
+    // Installed images cannot be marked; marking twice unmarks
+    state.selected_index = 2;
+    state.toggle_mark_selected();
+    state.selected_index = 1;
+    state.toggle_mark_selected();
+    state.selected_index = 0;
+    state.toggle_mark_selected();
+    state.toggle_mark_selected();
+    state.toggle_mark_selected();
+    assert!(state.is_marked(&state.api_levels[0]));
+    assert!(!state.is_marked(&state.api_levels[1]));
+
+    let items = state.take_install_queue_items();
+    assert!(state.marked_packages.is_empty());
+    let apis: Vec<&str> = items
+        .iter()
+        .map(|item| item.display_name.as_str())
+        .collect();
+    assert_eq!(
+        apis,
+        [
+            "API 33 (Android 13) - Google APIs",
+            "API 35 (Android 15) - Google APIs"
+        ]
+    );
+
+    // Weighted by download size: 1 000 of 4 000 bytes done after a minute
+    let mut queue = InstallQueue::new(items);
+    assert_eq!(
+        queue.remaining_time(std::time::Duration::from_secs(60)),
+        None
+    );
+    queue.items[0].status = QueuedInstallStatus::Installed;
+    queue.items[1].status = QueuedInstallStatus::Installing(0);
+    assert_eq!(queue.current().map(|(position, _)| position), Some(2));
+    assert_eq!(
+        queue.remaining_time(std::time::Duration::from_secs(60)),
+        Some(std::time::Duration::from_secs(180))
+    );
+    queue.items[1].status = QueuedInstallStatus::Failed("no space".to_string());
+    assert_eq!(queue.installed_count(), 1);
+    assert_eq!(queue.failed().len(), 1);
+    assert_eq!(
+        queue.remaining_time(std::time::Duration::from_secs(60)),
+        None
+    );
+
+    // Without marks, Enter installs the selected image
+    state.selected_index = 0;
+    let items = state.take_install_queue_items();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0].package_id,
+        "system-images;android-35;google_apis;x86_64"
+    );
+}
+
 #[test]
 fn test_notification_creation() {
     let notification = Notification {
@@ -78,3 +232,886 @@ fn test_device_cache_ios_data() {
     assert_eq!(cache.ios_device_types.len(), 1);
     assert_eq!(cache.ios_runtimes.len(), 1);
 }
+
+#[test]
+fn test_validate_create_device_name_accepts_placeholder_with_spaces() {
+    let mut state = AppState::new();
+    state.create_device_form.name = "Pixel 7 API 34".to_string();
+    assert!(state.validate_create_device_name().is_ok());
+}
+
+#[test]
+fn test_validate_create_device_name_rejects_invalid_characters() {
+    let mut state = AppState::new();
+    state.create_device_form.name = "Pixel@7".to_string();
+    assert_eq!(
+        state.validate_create_device_name().unwrap_err(),
+        crate::constants::messages::validation::DEVICE_NAME_INVALID_CHARS_ERROR
+    );
+
+    state.create_device_form.name = "   ".to_string();
+    assert!(state.validate_create_device_name().is_err());
+}
+
+#[test]
+fn test_validate_create_device_name_rejects_duplicates() {
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7_API_34".to_string(),
+        ..Default::default()
+    });
+    state.create_device_form.name = "Pixel 7 API 34".to_string();
+    let error = state.validate_create_device_name().unwrap_err();
+    assert!(error.contains("already exists"));
+
+    state.active_panel = Panel::Ios;
+    state.ios_devices.push(crate::models::IosDevice {
+        name: "iPhone 15 (iOS 17.0)".to_string(),
+        udid: "UDID-1".to_string(),
+        device_type: "iPhone 15".to_string(),
+        ios_version: "17.0".to_string(),
+        runtime_version: "17.0".to_string(),
+        status: crate::models::DeviceStatus::Stopped,
+        is_running: false,
+        is_available: true,
+        availability_error: None,
+    });
+    state.create_device_form.name = "iPhone 15".to_string();
+    assert!(state.validate_create_device_name().is_err());
+
+    state.create_device_form.name = "iPhone 15 Pro".to_string();
+    assert!(state.validate_create_device_name().is_ok());
+}
+
+#[test]
+fn test_device_profile_defaults_follow_category() {
+    let mut form = CreateDeviceForm::for_android();
+    form.available_device_types = vec![
+        (
+            "wearos_large_round".to_string(),
+            "Wear OS Large Round".to_string(),
+        ),
+        ("pixel_tablet".to_string(), "Pixel Tablet".to_string()),
+        ("pixel_7".to_string(), "Pixel 7".to_string()),
+    ];
+
+    form.update_selected_device_type();
+    assert_eq!(form.ram_size, "1024");
+    assert_eq!(form.storage_size, "2048");
+
+    form.selected_device_type_index = 1;
+    form.update_selected_device_type();
+    assert_eq!(form.ram_size, "4096");
+    assert_eq!(form.storage_size, "16384");
+
+    form.selected_device_type_index = 2;
+    form.update_selected_device_type();
+    assert_eq!(form.ram_size, "2048");
+    assert_eq!(form.storage_size, "8192");
+}
+
+#[test]
+fn test_cycle_ram_and_storage_sizes_stay_in_range() {
+    let mut form = CreateDeviceForm::for_android();
+
+    form.ram_size = "8192".to_string();
+    form.cycle_ram_size(true);
+    assert_eq!(form.ram_size, "512");
+    form.cycle_ram_size(false);
+    assert_eq!(form.ram_size, "8192");
+
+    form.ram_size = "2500".to_string();
+    form.cycle_ram_size(true);
+    assert_eq!(form.ram_size, "3072");
+
+    form.storage_size = "8192".to_string();
+    form.cycle_storage_size(false);
+    assert_eq!(form.storage_size, "4096");
+
+    // An unreadable size steps from the storage default, not the RAM one
+    form.storage_size = "abc".to_string();
+    form.cycle_storage_size(true);
+    assert!(
+        form.storage_size.parse::<u32>().unwrap() > crate::constants::defaults::DEFAULT_STORAGE_MB
+    );
+}
+
+#[test]
+fn test_format_size_mb_and_ram_warning() {
+    assert_eq!(CreateDeviceForm::format_size_mb("512"), "512 MB");
+    assert_eq!(CreateDeviceForm::format_size_mb("1536"), "1536 MB");
+    assert_eq!(CreateDeviceForm::format_size_mb("4096"), "4 GB");
+
+    let mut form = CreateDeviceForm::for_android();
+    form.ram_size = "8192".to_string();
+    assert!(form.ram_warning(Some(4096)).unwrap().contains("4 GB"));
+    assert!(form.ram_warning(Some(16384)).is_none());
+    assert!(form.ram_warning(None).is_none());
+}
+
+#[test]
+fn test_command_history_dialog_scroll_is_bounded() {
+    let entry = crate::utils::audit::CommandAuditEntry {
+        started_at: chrono::Local::now(),
+        command: "adb devices".to_string(),
+        duration: std::time::Duration::from_millis(5),
+        exit_code: Some(0),
+        success: true,
+    };
+    let mut dialog = CommandHistoryDialog {
+        entries: vec![entry.clone(), entry],
+        ..Default::default()
+    };
+
+    dialog.scroll_up();
+    assert_eq!(dialog.scroll_offset, 0);
+    dialog.scroll_down();
+    dialog.scroll_down();
+    assert_eq!(dialog.scroll_offset, 1);
+}
+
+#[tokio::test]
+async fn test_restore_device_inventory_only_fills_empty_state() {
+    let inventory = crate::utils::DeviceInventoryCache {
+        android_devices: vec![crate::models::AndroidDevice {
+            name: "Cached_AVD".to_string(),
+            status: crate::models::DeviceStatus::Running,
+            is_running: true,
+            ..Default::default()
+        }],
+        android_device_types: vec![("pixel_7".to_string(), "Pixel 7".to_string())],
+        android_api_levels: vec![("34".to_string(), "API 34".to_string())],
+        timestamp: Some(std::time::SystemTime::now() - std::time::Duration::from_secs(120)),
+        ..Default::default()
+    };
+
+    let mut state = AppState::new();
+    state.restore_device_inventory(inventory.clone()).await;
+    assert!(!state.is_loading);
+    assert_eq!(state.android_devices[0].name, "Cached_AVD");
+    // A cached running flag is not trusted until a live refresh confirms it
+    assert_eq!(
+        state.android_devices[0].status,
+        crate::models::DeviceStatus::Unknown
+    );
+    assert!(!state.android_devices[0].is_running);
+    assert!(state.showing_cached_inventory);
+    assert!(state.refresh_age() >= std::time::Duration::from_secs(120));
+    state.mark_refreshed();
+    assert!(!state.showing_cached_inventory);
+    assert!(state.is_cache_available(Panel::Android).await);
+    assert!(!state.is_cache_available(Panel::Ios).await);
+
+    let mut loaded = AppState::new();
+    loaded.android_devices.push(crate::models::AndroidDevice {
+        name: "Live_AVD".to_string(),
+        ..Default::default()
+    });
+    loaded.is_loading = false;
+    loaded.restore_device_inventory(inventory).await;
+    assert_eq!(loaded.android_devices.len(), 1);
+    assert_eq!(loaded.android_devices[0].name, "Live_AVD");
+}
+
+#[test]
+fn test_idle_session_pauses_auto_refresh_until_input() {
+    let mut state = AppState::new();
+    state.last_refresh = std::time::Instant::now() - std::time::Duration::from_secs(60);
+    state.last_input = std::time::Instant::now() - std::time::Duration::from_secs(60);
+
+    assert!(state.is_idle());
+    assert!(!state.should_auto_refresh());
+
+    // A booting device keeps the session active
+    state.android_devices.push(crate::models::AndroidDevice {
+        status: crate::models::DeviceStatus::Starting,
+        ..Default::default()
+    });
+    assert!(!state.is_idle());
+    assert!(state.should_auto_refresh());
+    state.android_devices.clear();
+
+    state.mark_input();
+    assert!(!state.is_idle());
+    assert!(state.should_auto_refresh());
+}
+
+#[test]
+fn test_auto_refresh_interval_and_pause() {
+    let mut state = AppState::new();
+    state.last_refresh = std::time::Instant::now() - std::time::Duration::from_secs(10);
+
+    state.set_auto_refresh_interval(std::time::Duration::from_secs(30));
+    assert!(!state.should_auto_refresh());
+
+    state.set_pending_device_start("Pixel_7".to_string());
+    state.clear_pending_device_start();
+    assert_eq!(state.auto_refresh_interval.as_secs(), 30);
+
+    state.set_auto_refresh_interval(std::time::Duration::from_secs(5));
+    assert!(state.should_auto_refresh());
+    assert!(state.toggle_auto_refresh_paused());
+    assert!(!state.should_auto_refresh());
+
+    // A device that is starting keeps polling even while paused
+    state.set_pending_device_start("Pixel_7".to_string());
+    assert!(state.should_auto_refresh());
+    state.clear_pending_device_start();
+
+    assert!(!state.toggle_auto_refresh_paused());
+    state.set_auto_refresh_interval(std::time::Duration::ZERO);
+    assert!(state.auto_refresh_paused);
+    assert_eq!(state.auto_refresh_interval.as_secs(), 5);
+}
+
+#[test]
+fn test_details_tabs_cycle_and_ignore_stale_content() {
+    assert_eq!(DetailsTab::Info.previous(), DetailsTab::History);
+    assert_eq!(DetailsTab::History.next(), DetailsTab::Info);
+    assert_eq!(DetailsTab::Sensors.next(), DetailsTab::Output);
+
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7".to_string(),
+        ..Default::default()
+    });
+    state.cycle_details_tab(true);
+    assert_eq!(state.details_tab, DetailsTab::Config);
+    assert!(state.current_details_tab_content().is_none());
+
+    // Content for a device or tab the user has moved away from is dropped
+    state.set_details_tab_content(DetailsTabContent {
+        tab: DetailsTab::Apps,
+        device_identifier: "Pixel_7".to_string(),
+        lines: Ok(vec![]),
+    });
+    assert!(state.details_tab_content.is_none());
+
+    state.set_details_tab_content(DetailsTabContent {
+        tab: DetailsTab::Config,
+        device_identifier: "Pixel_7".to_string(),
+        lines: Ok(vec!["hw.ramSize = 2048".to_string()]),
+    });
+    assert!(state.current_details_tab_content().is_some());
+
+    state.cycle_details_tab(true);
+    assert!(state.current_details_tab_content().is_none());
+}
+
+#[test]
+fn test_record_device_operation_refreshes_open_history_tab() {
+    use crate::models::DeviceOperation;
+
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7".to_string(),
+        ..Default::default()
+    });
+    state.details_tab = DetailsTab::History;
+
+    state.record_device_operation(Panel::Android, "Pixel_7", DeviceOperation::Wipe, Ok(()));
+    state.record_device_operation(Panel::Ios, "Pixel_7", DeviceOperation::Boot, Ok(()));
+
+    let lines = state.device_history_lines(Panel::Android, "Pixel_7");
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].contains("wipe"));
+    let content = state.current_details_tab_content().unwrap();
+    assert_eq!(content.lines.as_ref().unwrap(), &lines);
+}
+
+#[test]
+fn test_device_usage_tracks_uptime_and_idle_time() {
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7".to_string(),
+        is_running: true,
+        ..Default::default()
+    });
+    assert!(state.device_uptime(Panel::Android, "Pixel_7").is_none());
+
+    state.track_device_usage(Panel::Android);
+    assert!(state.device_uptime(Panel::Android, "Pixel_7").is_some());
+    assert!(state.device_idle_time(Panel::Android, "Pixel_7").is_none());
+
+    state.android_devices[0].is_running = false;
+    state.track_device_usage(Panel::Android);
+    assert!(state.device_uptime(Panel::Android, "Pixel_7").is_none());
+    assert!(state.device_idle_time(Panel::Android, "Pixel_7").is_some());
+}
+
+#[test]
+fn test_headless_ios_device_cleared_once_shut_down() {
+    let mut state = AppState::new();
+    state.ios_devices.push(crate::models::IosDevice {
+        name: "iPhone 15".to_string(),
+        udid: "UDID-1".to_string(),
+        device_type: "iPhone 15".to_string(),
+        ios_version: "17.0".to_string(),
+        runtime_version: "17.0".to_string(),
+        status: crate::models::DeviceStatus::Running,
+        is_running: true,
+        is_available: true,
+        availability_error: None,
+    });
+    state.set_ios_device_headless("UDID-1", true);
+
+    state.device_list_updated(Panel::Ios);
+    assert!(state.is_ios_device_headless("UDID-1"));
+
+    state.ios_devices[0].is_running = false;
+    state.device_list_updated(Panel::Ios);
+    assert!(!state.is_ios_device_headless("UDID-1"));
+}
+
+#[test]
+fn test_ephemeral_devices_reported_once_they_stop_after_running() {
+    let mut state = AppState::new();
+    state.ios_devices.push(crate::models::IosDevice {
+        name: "iPhone 15 Clone".to_string(),
+        udid: "UDID-CLONE".to_string(),
+        device_type: "iPhone 15".to_string(),
+        ios_version: "17.0".to_string(),
+        runtime_version: "17.0".to_string(),
+        status: crate::models::DeviceStatus::Stopped,
+        is_running: false,
+        is_available: true,
+        availability_error: None,
+    });
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7".to_string(),
+        ..Default::default()
+    });
+    state.track_ephemeral_device(crate::models::DeviceId::ios("UDID-CLONE"));
+    state.track_ephemeral_device(crate::models::DeviceId::android("Pixel_7"));
+
+    // Still booting
+    assert!(state.take_stopped_ephemeral_devices().is_empty());
+    state.ios_devices[0].is_running = true;
+    state.android_devices[0].is_running = true;
+    assert!(state.take_stopped_ephemeral_devices().is_empty());
+    assert!(state.is_ephemeral_device(Panel::Ios, "UDID-CLONE"));
+    assert!(state.is_ephemeral_device(Panel::Android, "Pixel_7"));
+
+    state.ios_devices[0].is_running = false;
+    assert_eq!(
+        state.take_stopped_ephemeral_devices(),
+        [crate::models::DeviceId::ios("UDID-CLONE")]
+    );
+    assert!(!state.is_ephemeral_device(Panel::Ios, "UDID-CLONE"));
+    assert!(state.is_ephemeral_device(Panel::Android, "Pixel_7"));
+
+    state.forget_deleted_device(Panel::Ios, "UDID-CLONE");
+    assert!(state.ios_devices.is_empty());
+}
+
+#[test]
+fn test_search_matches_names_and_annotations_with_wraparound() {
+    let mut state = AppState::new();
+    for name in ["Pixel_7", "Pixel_Tablet", "Wear_OS"] {
+        state.android_devices.push(crate::models::AndroidDevice {
+            name: name.to_string(),
+            ..Default::default()
+        });
+    }
+    state.device_annotations.set(
+        &crate::models::DeviceId::android("Pixel_7"),
+        crate::models::DeviceAnnotation::from_input("checkout", "customer demo"),
+    );
+
+    state.selected_android = 1;
+    state.search_query = "pixel 7".to_string();
+    assert!(state.select_next_search_match(false));
+    assert_eq!(state.selected_android, 0);
+
+    state.search_query = "DEMO".to_string();
+    assert_eq!(state.find_search_match(true), Some(0));
+
+    state.search_query = "pixel".to_string();
+    assert!(state.select_next_search_match(true));
+    assert_eq!(state.selected_android, 1);
+    assert!(state.select_next_search_match(true));
+    assert_eq!(state.selected_android, 0);
+
+    state.search_query = "nothing".to_string();
+    assert!(!state.select_next_search_match(false));
+    assert_eq!(state.selected_android, 0);
+}
+
+#[test]
+fn test_project_config_pins_devices_first() {
+    let mut state = AppState::new();
+    for name in ["Pixel_Tablet", "Pixel_7"] {
+        state.android_devices.push(crate::models::AndroidDevice {
+            name: name.to_string(),
+            ..Default::default()
+        });
+    }
+
+    let config = crate::models::ProjectConfig::parse("[devices]\nandroid = [\"Pixel 7\"]").unwrap();
+    state.set_project_config(config);
+    assert_eq!(state.android_devices[0].name, "Pixel_7");
+    assert!(state.is_android_device_pinned(&state.android_devices[0]));
+    assert!(!state.is_android_device_pinned(&state.android_devices[1]));
+
+    // Fresh lists from a refresh are re-sorted
+    state.android_devices.reverse();
+    state.device_list_updated(Panel::Android);
+    assert_eq!(state.android_devices[0].name, "Pixel_7");
+}
+
+#[test]
+fn test_prefetched_details_follow_selection_and_device_list() {
+    let mut state = AppState::new();
+    for name in ["Pixel_7", "Pixel_Tablet"] {
+        state.android_devices.push(crate::models::AndroidDevice {
+            name: name.to_string(),
+            ..Default::default()
+        });
+    }
+    assert_eq!(
+        state.devices_without_details(Panel::Android),
+        vec!["Pixel_7".to_string(), "Pixel_Tablet".to_string()]
+    );
+
+    state.store_prefetched_device_details(DeviceDetails {
+        name: "Pixel_Tablet".to_string(),
+        status: "Stopped".to_string(),
+        platform: crate::models::Platform::Android,
+        device_type: "pixel_tablet".to_string(),
+        api_level_or_version: "API 34 (Android 14)".to_string(),
+        ram_size: Some("4096 MB".to_string()),
+        storage_size: None,
+        resolution: Some("2560x1600".to_string()),
+        dpi: None,
+        device_path: None,
+        system_image: None,
+        identifier: "Pixel_Tablet".to_string(),
+    });
+    assert_eq!(
+        state.devices_without_details(Panel::Android),
+        vec!["Pixel_7".to_string()]
+    );
+
+    // Selecting the tablet shows its details, with the list's running state
+    state.selected_android = 1;
+    state.android_devices[1].is_running = true;
+    let details = state.get_selected_device_details().unwrap();
+    assert_eq!(details.ram_size.as_deref(), Some("4096 MB"));
+    assert_eq!(details.resolution.as_deref(), Some("2560x1600"));
+    assert_eq!(details.status, "Running");
+
+    state.selected_android = 0;
+    assert_eq!(state.get_selected_device_details().unwrap().ram_size, None);
+
+    // Deleted devices drop their details
+    state.android_devices.truncate(1);
+    state.device_list_updated(Panel::Android);
+    assert!(state.prefetched_device_details.is_empty());
+}
+
+#[test]
+fn test_duplicate_template_prefills_create_form() {
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7_API_34".to_string(),
+        device_type: "pixel_7".to_string(),
+        api_level: 34,
+        ram_size: "2048".to_string(),
+        storage_size: "8192M".to_string(),
+        ..Default::default()
+    });
+    state.cached_device_details = Some(DeviceDetails {
+        name: "Pixel_7_API_34".to_string(),
+        status: "Stopped".to_string(),
+        platform: crate::models::Platform::Android,
+        device_type: "pixel_7".to_string(),
+        api_level_or_version: "34".to_string(),
+        ram_size: Some("4096 MB".to_string()),
+        storage_size: None,
+        resolution: None,
+        dpi: None,
+        device_path: None,
+        system_image: None,
+        identifier: "Pixel_7_API_34".to_string(),
+    });
+
+    let template = state.create_device_template().unwrap();
+    assert_eq!(template.version, "34");
+    assert_eq!(template.ram_size.as_deref(), Some("4096"));
+    assert_eq!(template.storage_size.as_deref(), Some("8192"));
+
+    let mut form = CreateDeviceForm::for_android();
+    form.available_device_types = vec![
+        ("pixel_6".to_string(), "Pixel 6".to_string()),
+        ("pixel_7".to_string(), "Pixel 7".to_string()),
+    ];
+    form.available_versions = vec![
+        ("35".to_string(), "API 35 - Android 15".to_string()),
+        ("34".to_string(), "API 34 - Android 14".to_string()),
+    ];
+    form.template = Some(template);
+    form.apply_template();
+
+    assert_eq!(form.device_type_id, "pixel_7");
+    assert_eq!(form.version, "34");
+    assert_eq!(form.ram_size, "4096");
+    assert_eq!(form.storage_size, "8192");
+    assert_eq!(form.active_field, CreateDeviceField::ApiLevel);
+
+    // Switching to the other API level leaves the hardware profile alone
+    form.selected_api_level_index = 0;
+    form.update_selected_api_level();
+    assert_eq!(form.version, "35");
+    assert_eq!(form.device_type_id, "pixel_7");
+    assert_eq!(form.ram_size, "4096");
+}
+
+#[test]
+fn test_display_modes_are_exclusive() {
+    let mut state = AppState::new();
+    state.toggle_fullscreen_logs();
+    state.toggle_zen_logs();
+    assert!(state.zen_logs);
+    assert!(!state.fullscreen_logs);
+
+    state.toggle_fullscreen_devices();
+    assert!(state.fullscreen_devices);
+    assert!(!state.zen_logs);
+
+    state.toggle_fullscreen_devices();
+    assert!(!state.fullscreen_devices && !state.fullscreen_logs && !state.zen_logs);
+}
+
+#[test]
+fn test_creation_queue_tracks_item_status() {
+    use crate::managers::common::DeviceConfig;
+
+    let mut queue = CreationQueue::with_max_parallel(0);
+    assert_eq!(queue.max_parallel(), 1);
+
+    let first = queue.enqueue(
+        Panel::Android,
+        DeviceConfig::new(
+            "Pixel_A".to_string(),
+            "pixel_7".to_string(),
+            "34".to_string(),
+        ),
+    );
+    let second = queue.enqueue(
+        Panel::Ios,
+        DeviceConfig::new(
+            "iPhone A".to_string(),
+            "iphone-15".to_string(),
+            "17.0".to_string(),
+        ),
+    );
+    assert_ne!(first, second);
+    assert_eq!(queue.pending_count(), 2);
+    assert!(queue.contains_unfinished(Panel::Android, "Pixel_A"));
+    assert!(!queue.contains_unfinished(Panel::Ios, "Pixel_A"));
+
+    queue.get_mut(first).unwrap().status = QueuedCreationStatus::Completed;
+    queue.get_mut(second).unwrap().status = QueuedCreationStatus::Active {
+        stage: None,
+        progress: Some(40),
+    };
+    assert_eq!(queue.active_count(), 1);
+    assert_eq!(queue.finished_count(), 1);
+    assert!(!queue.contains_unfinished(Panel::Android, "Pixel_A"));
+
+    queue.scroll_down();
+    queue.clear_finished();
+    assert_eq!(queue.items.len(), 1);
+    assert_eq!(queue.items[0].id, second);
+    assert_eq!(queue.scroll_offset, 0);
+}
+
+#[test]
+fn test_process_inspector_sorts_services_by_host_memory_and_targets_host() {
+    use crate::models::{DeviceProcess, DeviceService};
+
+    let process = |pid: u32, name: &str, memory_kb: u64| DeviceProcess {
+        pid,
+        name: name.to_string(),
+        user: None,
+        memory_kb: Some(memory_kb),
+    };
+    let service = |name: &str, pid: Option<u32>| DeviceService {
+        name: name.to_string(),
+        pid,
+    };
+    let mut dialog =
+        ProcessInspectorDialog::new("Pixel".to_string(), "Pixel".to_string(), Panel::Android);
+    assert!(dialog.selected_process().is_none());
+
+    dialog.set_lists(
+        Ok(vec![
+            process(10, "com.example.small", 20_000),
+            process(20, "com.example.large", 300_000),
+        ]),
+        Ok(vec![
+            service("com.example.idle/.Idle", None),
+            service("com.example.small/.Sync", Some(10)),
+            service("com.example.large/.Player", Some(20)),
+        ]),
+    );
+    assert_eq!(dialog.selected_process().unwrap().pid, 20);
+
+    dialog.switch_tab();
+    let services: Vec<_> = dialog
+        .loaded_services()
+        .iter()
+        .map(|service| service.name.as_str())
+        .collect();
+    assert_eq!(
+        services,
+        [
+            "com.example.large/.Player",
+            "com.example.small/.Sync",
+            "com.example.idle/.Idle"
+        ]
+    );
+    dialog.select_next();
+    assert_eq!(dialog.selected_process().unwrap().pid, 10);
+    dialog.select_next();
+    dialog.select_next();
+    assert_eq!(dialog.selected, 2);
+    assert!(dialog.selected_process().is_none());
+
+    dialog.toggle_sort();
+    assert_eq!(dialog.selected, 0);
+    assert_eq!(dialog.loaded_services()[0].name, "com.example.idle/.Idle");
+    assert_eq!(dialog.loaded_processes()[0].name, "com.example.large");
+}
+
+#[test]
+fn test_system_properties_dialog_filters_and_edits_writable_properties() {
+    use crate::models::SystemProperty;
+
+    let property = |name: &str, value: &str| SystemProperty {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+    let mut dialog = SystemPropertiesDialog::new("Pixel".to_string());
+    assert!(!dialog.start_edit());
+
+    dialog.set_properties(Ok(vec![
+        property("debug.hwui.profile", "false"),
+        property("debug.layout", "false"),
+        property("ro.build.version.sdk", "34"),
+    ]));
+    dialog.active_input().push_str("LAYOUT");
+    dialog.filter_changed();
+    assert_eq!(dialog.filtered().len(), 1);
+    assert!(dialog.start_edit());
+    assert_eq!(dialog.edit_value.as_deref(), Some("false"));
+
+    // Typing goes to the new value while editing, not to the search
+    dialog.active_input().clear();
+    dialog.active_input().push_str("true");
+    assert_eq!(dialog.filter, "LAYOUT");
+    assert_eq!(dialog.selected_property().unwrap().name, "debug.layout");
+
+    dialog.edit_value = None;
+    dialog.filter = "ro.".to_string();
+    dialog.filter_changed();
+    assert!(!dialog.start_edit());
+    assert!(dialog.edit_value.is_none());
+}
+
+#[test]
+fn test_monkey_dialog_preselects_and_cycles_installed_packages() {
+    let mut dialog = MonkeyDialog::new("Pixel".to_string(), 500, 100);
+    dialog.cycle_package(true);
+    assert!(dialog.package.is_empty());
+
+    dialog.set_packages(vec![
+        "com.example.a".to_string(),
+        "com.example.b".to_string(),
+    ]);
+    assert_eq!(dialog.package, "com.example.a");
+    dialog.cycle_package(false);
+    assert_eq!(dialog.package, "com.example.b");
+    dialog.cycle_package(true);
+    assert_eq!(dialog.package, "com.example.a");
+
+    // A typed package is kept when the list arrives late
+    let mut dialog = MonkeyDialog::new("Pixel".to_string(), 500, 100);
+    dialog.active_input().push_str("com.typed");
+    dialog.set_packages(vec!["com.example.a".to_string()]);
+    assert_eq!(dialog.package, "com.typed");
+    dialog.active_field = dialog.active_field.next();
+    dialog.active_input().push('0');
+    assert_eq!(dialog.event_count, "5000");
+}
+
+#[test]
+fn test_launch_app_dialog_offers_environment_only_on_ios() {
+    let mut android = LaunchAppDialog::new(DeviceId::android("Pixel"), "Pixel".to_string());
+    android.set_apps(vec!["com.example".to_string()]);
+    assert_eq!(android.app, "com.example");
+    android.next_field();
+    assert_eq!(android.active_field, LaunchAppField::Arguments);
+    android.next_field();
+    assert_eq!(android.active_field, LaunchAppField::App);
+    android.previous_field();
+    assert_eq!(android.active_field, LaunchAppField::Arguments);
+
+    let mut ios = LaunchAppDialog::new(DeviceId::ios("UDID"), "iPhone 15".to_string());
+    ios.previous_field();
+    assert_eq!(ios.active_field, LaunchAppField::Environment);
+    ios.active_input().push_str("DEBUG=1");
+    assert_eq!(ios.environment, "DEBUG=1");
+    ios.next_field();
+    assert_eq!(ios.active_field, LaunchAppField::App);
+}
+
+#[test]
+fn test_stalled_device_start_is_taken_once_after_timeout() {
+    let mut state = AppState::new();
+    assert_eq!(
+        state.take_stalled_device_start(std::time::Duration::ZERO),
+        None
+    );
+
+    state.set_pending_device_start("Pixel_7".to_string());
+    assert_eq!(
+        state.take_stalled_device_start(std::time::Duration::from_secs(60)),
+        None
+    );
+    assert!(state.get_pending_device_start().is_some());
+
+    assert_eq!(
+        state.take_stalled_device_start(std::time::Duration::ZERO),
+        Some("Pixel_7".to_string())
+    );
+    assert!(state.get_pending_device_start().is_none());
+    assert_eq!(
+        state.auto_refresh_interval,
+        state.configured_refresh_interval
+    );
+}
+
+#[test]
+fn test_launch_diagnosis_dialog_drops_fixed_issues() {
+    let stale_locks = crate::models::LaunchIssue::StaleLocks {
+        lock_files: vec![std::path::PathBuf::from(
+            "/avd/Pixel.avd/multiinstance.lock",
+        )],
+    };
+    let mut dialog = LaunchDiagnosisDialog::new(
+        "Pixel".to_string(),
+        None,
+        vec![crate::models::LaunchIssue::PortsInUse, stale_locks.clone()],
+    );
+    dialog.select_next();
+    dialog.select_next();
+    assert_eq!(dialog.selected_issue(), Some(&stale_locks));
+
+    assert!(!dialog.issue_fixed(&stale_locks));
+    assert_eq!(dialog.selected, 0);
+    assert_eq!(
+        dialog.selected_issue(),
+        Some(&crate::models::LaunchIssue::PortsInUse)
+    );
+    assert!(dialog.issue_fixed(&crate::models::LaunchIssue::PortsInUse));
+}
+
+#[test]
+fn test_wedged_devices_are_reported_once() {
+    let mut state = AppState::new();
+    let pixel = Panel::Android.device_id("Pixel_7");
+    let newly_wedged = state.set_wedged_devices([pixel.clone()].into_iter().collect());
+    assert_eq!(newly_wedged, vec![pixel.clone()]);
+    assert!(state.is_device_wedged(Panel::Android, "Pixel_7"));
+    assert!(!state.is_device_wedged(Panel::Ios, "Pixel_7"));
+
+    assert!(state
+        .set_wedged_devices([pixel].into_iter().collect())
+        .is_empty());
+    assert!(state.set_wedged_devices(Default::default()).is_empty());
+    assert!(!state.is_device_wedged(Panel::Android, "Pixel_7"));
+}
+
+#[test]
+fn test_device_row_shows_latest_running_activity() {
+    use crate::constants::ui_text::device_activity::{COPYING_APP, RECORDING_TRACE};
+
+    let mut state = AppState::new();
+    let pixel = Panel::Android.device_id("Pixel_7");
+    let trace = state
+        .device_activities
+        .begin(pixel.clone(), RECORDING_TRACE);
+    let copy = state.device_activities.begin(pixel, COPYING_APP);
+    assert_eq!(
+        state.device_activity(Panel::Android, "Pixel_7"),
+        Some(COPYING_APP)
+    );
+    assert_eq!(state.device_activity(Panel::Ios, "Pixel_7"), None);
+    assert!(state.has_transitioning_devices());
+
+    state.device_activities.end(copy);
+    assert_eq!(
+        state.device_activity(Panel::Android, "Pixel_7"),
+        Some(RECORDING_TRACE)
+    );
+    state.device_activities.end(trace);
+    assert!(state.device_activity(Panel::Android, "Pixel_7").is_none());
+
+    state.pending_device_start = Some("Pixel_7".to_string());
+    assert!(state.is_device_booting(Panel::Android, "Pixel_7"));
+    assert!(!state.is_device_booting(Panel::Android, "Pixel_8"));
+}
+
+#[test]
+fn test_optimistic_start_is_kept_until_confirmed_or_overdue() {
+    let mut state = AppState::new();
+    state.android_devices.push(crate::models::AndroidDevice {
+        name: "Pixel_7".to_string(),
+        ..Default::default()
+    });
+    let pixel = DeviceId::android("Pixel_7");
+
+    state.expect_device_state(&pixel, "Pixel_7", true);
+    assert!(state.android_devices[0].is_running);
+    // A refresh before the emulator attached keeps it shown as running
+    state.android_devices[0].is_running = false;
+    state.device_list_updated(Panel::Android);
+    assert!(state.android_devices[0].is_running);
+    assert_eq!(state.reconciliation_journal.expected(&pixel), Some(true));
+
+    state.android_devices[0].is_running = true;
+    state.device_list_updated(Panel::Android);
+    assert!(state.reconciliation_journal.entries.is_empty());
+
+    // An emulator that never came up is shown stopped again
+    state.set_pending_device_start("Pixel_7".to_string());
+    state.reconciliation_journal.record(
+        pixel.clone(),
+        "Pixel_7".to_string(),
+        true,
+        std::time::Duration::ZERO,
+    );
+    state.android_devices[0].is_running = false;
+    state.device_list_updated(Panel::Android);
+    assert!(!state.android_devices[0].is_running);
+    assert!(state.reconciliation_journal.expected(&pixel).is_none());
+    assert!(state.get_pending_device_start().is_none());
+    assert!(state
+        .notifications
+        .back()
+        .is_some_and(|notification| notification.message.contains("still not running")));
+}
+
+#[test]
+fn test_take_plain_log_lines_drains_oldest_first() {
+    let mut state = AppState::new();
+    state.add_log("INFO".to_string(), "first".to_string());
+    state.add_log("ERROR".to_string(), "second".to_string());
+
+    let lines = state.take_plain_log_lines();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with(" [INFO] first"));
+    assert!(lines[1].ends_with(" [ERROR] second"));
+    assert!(state.device_logs.is_empty());
+}
@@ -1,3 +1,17 @@
+use crate::constants::{defaults::DEFAULT_MATRIX_GROUP, limits::MAX_TYPED_ECHO_CHARS};
+use crate::models::{
+    app_data, AndroidIntent, CatalogEntry, DeveloperToggle, DeveloperToggleStatus, DeviceCatalog,
+    DeviceId, DeviceInput, DeviceProcess, DeviceService, IntentKind, IosDevice, LaunchIssue,
+    Platform, ProcessSort, QuickBootStatus, Radio, RadioStatus, SimulatorUnavailableReason,
+    Snippet, SystemProperty, TestMatrix,
+};
+use crate::utils::audit::CommandAuditEntry;
+use crate::utils::diagnostics::DiagnosticEntry;
+use crate::utils::xcode::DeveloperDir;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use tokio::sync::mpsc::UnboundedSender;
+
 /// Represents the two main device panels in the UI.
 /// The application displays Android and iOS devices in separate panels.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -9,6 +23,11 @@ pub enum Panel {
 }
 
 impl Panel {
+    /// Identifies a device of this panel: an AVD name or an iOS UDID.
+    pub fn device_id(self, identifier: impl Into<String>) -> DeviceId {
+        DeviceId::new(self.into(), identifier)
+    }
+
     /// Toggles between Android and iOS panels.
     /// Returns the opposite panel from the current one.
     pub fn toggle(self) -> Self {
@@ -19,6 +38,24 @@ impl Panel {
     }
 }
 
+impl From<Panel> for Platform {
+    fn from(panel: Panel) -> Self {
+        match panel {
+            Panel::Android => Platform::Android,
+            Panel::Ios => Platform::Ios,
+        }
+    }
+}
+
+impl From<Platform> for Panel {
+    fn from(platform: Platform) -> Self {
+        match platform {
+            Platform::Android => Panel::Android,
+            Platform::Ios => Panel::Ios,
+        }
+    }
+}
+
 /// Represents which UI panel currently has focus.
 /// Used for keyboard navigation between device list and log area.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -29,6 +66,88 @@ pub enum FocusedPanel {
     LogArea,
 }
 
+/// Whether the Xcode tooling behind the iOS panel has been checked yet.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum IosToolingStatus {
+    /// `xcrun` is still being resolved in the background
+    #[default]
+    Detecting,
+    /// simctl commands can run
+    Ready,
+    /// The tooling is missing or broken, with the reason
+    Unavailable(String),
+}
+
+impl IosToolingStatus {
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready)
+    }
+}
+
+/// Sub-views of the device details panel, cycled with `[` and `]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetailsTab {
+    /// Summary of the device (status, display, memory, version)
+    #[default]
+    Info,
+    /// Raw configuration (`config.ini` for Android, simctl fields for iOS)
+    Config,
+    /// Installed third-party apps (running devices only)
+    Apps,
+    /// Emulated sensor state (Android only)
+    Sensors,
+    /// Console output of the last emulator launch (Android only)
+    Output,
+    /// Commands previously run against the device
+    History,
+}
+
+impl DetailsTab {
+    pub const ALL: [Self; 6] = [
+        Self::Info,
+        Self::Config,
+        Self::Apps,
+        Self::Sensors,
+        Self::Output,
+        Self::History,
+    ];
+
+    /// Tab label shown in the details panel.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Info => "Info",
+            Self::Config => "Config",
+            Self::Apps => "Apps",
+            Self::Sensors => "Sensors",
+            Self::Output => "Output",
+            Self::History => "History",
+        }
+    }
+
+    /// Returns the next tab, wrapping around after the last one.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous tab, wrapping around before the first one.
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Loaded content of a non-Info details tab for one device.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailsTabContent {
+    /// Tab the content belongs to
+    pub tab: DetailsTab,
+    /// Device the content was loaded for (AVD name or UDID)
+    pub device_identifier: String,
+    /// Lines to display, or the error that prevented loading them
+    pub lines: Result<Vec<String>, String>,
+}
+
 /// Application modes representing different UI states.
 /// Each mode corresponds to a different screen or modal dialog.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -41,10 +160,79 @@ pub enum Mode {
     ConfirmDelete,
     /// Wipe data confirmation dialog is active
     ConfirmWipe,
+    /// Repair options for an unavailable simulator are displayed
+    RepairDevice,
+    /// Preview of the unavailable simulators about to be deleted
+    PruneUnavailable,
     /// API level management dialog is active
     ManageApiLevels,
     /// Help screen is displayed
     Help,
+    /// Executed command history is displayed
+    CommandHistory,
+    /// Queued device creations are displayed
+    CreationQueue,
+    /// emu's own diagnostic log records are displayed
+    Diagnostics,
+    /// Device tags and note editor is active
+    EditAnnotation,
+    /// Device search prompt is active
+    Search,
+    /// Target directory prompt for moving an AVD's data is active
+    MoveDeviceData,
+    /// Intent launcher for a running AVD is active
+    SendIntent,
+    /// Processes and services of a running device are displayed
+    ProcessInspector,
+    /// System properties of a running AVD are browsed and edited
+    SystemProperties,
+    /// Monkey stress test launcher for a running AVD is active
+    RunMonkey,
+    /// Problems found after an AVD failed to start, with their fixes
+    LaunchDiagnosis,
+    /// Quick Boot snapshot and cold boot options of an AVD
+    QuickBoot,
+    /// Date or offset prompt for the clock of a running device
+    DeviceClock,
+    /// Keystrokes are forwarded to a running AVD
+    TypeIntoDevice,
+    /// Wi-Fi, Bluetooth and airplane mode toggles of a running AVD
+    Radios,
+    /// Recommended Android device configurations to create
+    DeviceCatalog,
+    /// Device types and versions to cross into a test matrix
+    TestMatrix,
+    /// Shell snippets to run against the selected device
+    Snippets,
+    /// App launcher with arguments and environment for a running device
+    LaunchApp,
+    /// Perfetto or Instruments trace capture for a running device
+    CaptureTrace,
+    /// Rendering and debugging aids of a running device
+    DeveloperToggles,
+    /// Backups and copies of the apps of a running device
+    AppData,
+}
+
+impl Mode {
+    /// Modes that take free text, where a plain `q` is typed rather than quitting.
+    pub fn is_text_entry(self) -> bool {
+        matches!(
+            self,
+            Mode::CreateDevice
+                | Mode::EditAnnotation
+                | Mode::Search
+                | Mode::MoveDeviceData
+                | Mode::SendIntent
+                | Mode::SystemProperties
+                | Mode::RunMonkey
+                | Mode::DeviceClock
+                | Mode::TestMatrix
+                | Mode::TypeIntoDevice
+                | Mode::LaunchApp
+                | Mode::CaptureTrace
+        )
+    }
 }
 
 /// Data for the delete confirmation dialog.
@@ -70,3 +258,1305 @@ pub struct ConfirmWipeDialog {
     /// Platform of the device being wiped
     pub platform: Panel,
 }
+
+/// Data for the repair dialog of an unavailable iOS simulator.
+#[derive(Debug, Clone)]
+pub struct RepairDeviceDialog {
+    /// Display name of the device
+    pub device_name: String,
+    /// UDID of the simulator
+    pub udid: String,
+    /// iOS version the simulator was created with (e.g., "17.0")
+    pub ios_version: String,
+    /// Why simctl reports the simulator as unavailable
+    pub reason: SimulatorUnavailableReason,
+}
+
+impl RepairDeviceDialog {
+    /// Installing the runtime only helps when the runtime is what is missing.
+    pub fn can_install_runtime(&self) -> bool {
+        self.reason == SimulatorUnavailableReason::RuntimeMissing
+    }
+}
+
+/// Data for the prune dialog, listing the simulators that
+/// `simctl delete unavailable` will remove.
+#[derive(Debug, Clone)]
+pub struct PruneUnavailableDialog {
+    /// Unavailable simulators at the time the dialog opened
+    pub devices: Vec<IosDevice>,
+    /// Index of the first device shown
+    pub scroll_offset: usize,
+    /// Ask about each simulator in turn instead of all of them at once
+    pub one_at_a_time: bool,
+    /// Index of the simulator being asked about when `one_at_a_time`
+    pub current: usize,
+    /// UDIDs of the simulators confirmed for deletion when `one_at_a_time`
+    pub accepted: Vec<String>,
+}
+
+impl PruneUnavailableDialog {
+    pub fn new(devices: Vec<IosDevice>, one_at_a_time: bool) -> Self {
+        Self {
+            devices,
+            scroll_offset: 0,
+            one_at_a_time,
+            current: 0,
+            accepted: Vec::new(),
+        }
+    }
+
+    /// Records whether the simulator being asked about gets deleted and
+    /// moves on to the next one. Returns `true` once every simulator has
+    /// been answered.
+    pub fn answer(&mut self, delete: bool) -> bool {
+        if let Some(device) = self.devices.get(self.current) {
+            if delete {
+                self.accepted.push(device.udid.clone());
+            }
+            self.current += 1;
+            self.scroll_offset = self.current.min(self.devices.len().saturating_sub(1));
+        }
+        self.current >= self.devices.len()
+    }
+
+    /// Scrolls the list up by one device.
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the list down by one device, stopping at the last one.
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset + 1 < self.devices.len() {
+            self.scroll_offset += 1;
+        }
+    }
+}
+
+/// Field focused in the annotation editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnnotationField {
+    #[default]
+    Tags,
+    Note,
+}
+
+/// Data for the device tags and note editor.
+#[derive(Debug, Clone)]
+pub struct AnnotationDialog {
+    /// Display name of the device
+    pub device_name: String,
+    /// Unique identifier (AVD name for Android, UDID for iOS)
+    pub device_identifier: String,
+    /// Platform of the annotated device
+    pub platform: Panel,
+    /// Comma-separated tags being edited
+    pub tags_input: String,
+    /// Note being edited
+    pub note_input: String,
+    /// Field receiving typed characters
+    pub active_field: AnnotationField,
+}
+
+impl AnnotationDialog {
+    /// Input buffer of the focused field.
+    pub fn active_input(&mut self) -> &mut String {
+        match self.active_field {
+            AnnotationField::Tags => &mut self.tags_input,
+            AnnotationField::Note => &mut self.note_input,
+        }
+    }
+
+    pub fn toggle_field(&mut self) {
+        self.active_field = match self.active_field {
+            AnnotationField::Tags => AnnotationField::Note,
+            AnnotationField::Note => AnnotationField::Tags,
+        };
+    }
+}
+
+/// Data for the prompt that moves an AVD's data directory elsewhere.
+#[derive(Debug, Clone)]
+pub struct MoveDeviceDataDialog {
+    /// AVD name
+    pub device_name: String,
+    /// Where the data directory is now
+    pub current_dir: PathBuf,
+    /// Directory the data directory is moved into, as typed
+    pub target_input: String,
+}
+
+impl MoveDeviceDataDialog {
+    /// The typed target directory, with a leading `~` expanded to the home
+    /// directory. `None` while nothing has been typed.
+    pub fn target_dir(&self) -> Option<PathBuf> {
+        let input = self.target_input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        let home_relative = input
+            .strip_prefix('~')
+            .filter(|rest| rest.is_empty() || rest.starts_with(std::path::is_separator))
+            .and_then(|rest| {
+                let home = dirs::home_dir()?;
+                Some(home.join(rest.trim_start_matches(std::path::is_separator)))
+            });
+        Some(home_relative.unwrap_or_else(|| PathBuf::from(input)))
+    }
+}
+
+/// Field focused in the intent launcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntentField {
+    #[default]
+    Kind,
+    Action,
+    DataUri,
+    Component,
+    Extras,
+}
+
+impl IntentField {
+    /// Every field, in the order Tab visits them.
+    pub const ALL: [IntentField; 5] = [
+        IntentField::Kind,
+        IntentField::Action,
+        IntentField::DataUri,
+        IntentField::Component,
+        IntentField::Extras,
+    ];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|field| *field == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|field| *field == self)
+            .unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Data for the intent launcher of a running AVD.
+#[derive(Debug, Clone)]
+pub struct IntentDialog {
+    /// AVD name
+    pub device_name: String,
+    /// Intent being edited
+    pub intent: AndroidIntent,
+    /// Field receiving typed characters
+    pub active_field: IntentField,
+    /// Intents recently sent to the device, most recent first
+    pub history: Vec<AndroidIntent>,
+    /// History entry loaded into the fields, if any
+    pub history_index: Option<usize>,
+}
+
+impl IntentDialog {
+    /// Input buffer of the focused field; `None` on the kind selector.
+    pub fn active_input(&mut self) -> Option<&mut String> {
+        match self.active_field {
+            IntentField::Kind => None,
+            IntentField::Action => Some(&mut self.intent.action),
+            IntentField::DataUri => Some(&mut self.intent.data_uri),
+            IntentField::Component => Some(&mut self.intent.component),
+            IntentField::Extras => Some(&mut self.intent.extras),
+        }
+    }
+
+    pub fn toggle_kind(&mut self) {
+        self.intent.kind = self.intent.kind.toggle();
+    }
+
+    /// Loads the next older history entry into the fields.
+    pub fn history_older(&mut self) {
+        let next = self.history_index.map_or(0, |index| index + 1);
+        if next < self.history.len() {
+            self.load_history_entry(next);
+        }
+    }
+
+    /// Loads the next newer history entry into the fields, or clears them
+    /// after the most recent one.
+    pub fn history_newer(&mut self) {
+        match self.history_index {
+            Some(0) => {
+                self.history_index = None;
+                self.intent = AndroidIntent {
+                    kind: self.intent.kind,
+                    ..Default::default()
+                };
+            }
+            Some(index) => self.load_history_entry(index - 1),
+            None => {}
+        }
+    }
+
+    fn load_history_entry(&mut self, index: usize) {
+        self.intent = self.history[index].clone();
+        self.history_index = Some(index);
+    }
+
+    /// Whether the kind selector shows a broadcast.
+    pub fn is_broadcast(&self) -> bool {
+        self.intent.kind == IntentKind::Broadcast
+    }
+}
+
+/// Field focused in the monkey launcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonkeyField {
+    #[default]
+    Package,
+    EventCount,
+    Throttle,
+}
+
+impl MonkeyField {
+    pub fn next(self) -> Self {
+        match self {
+            MonkeyField::Package => MonkeyField::EventCount,
+            MonkeyField::EventCount => MonkeyField::Throttle,
+            MonkeyField::Throttle => MonkeyField::Package,
+        }
+    }
+
+    pub fn previous(self) -> Self {
+        match self {
+            MonkeyField::Package => MonkeyField::Throttle,
+            MonkeyField::EventCount => MonkeyField::Package,
+            MonkeyField::Throttle => MonkeyField::EventCount,
+        }
+    }
+}
+
+/// Data for the monkey launcher of a running AVD.
+#[derive(Debug, Clone)]
+pub struct MonkeyDialog {
+    /// AVD name
+    pub device_name: String,
+    /// Third-party packages installed on the AVD, empty until loaded
+    pub packages: Vec<String>,
+    /// Package to exercise
+    pub package: String,
+    /// Number of events, as typed
+    pub event_count: String,
+    /// Pause between events in milliseconds, as typed
+    pub throttle_ms: String,
+    /// Field receiving typed characters
+    pub active_field: MonkeyField,
+}
+
+impl MonkeyDialog {
+    pub fn new(device_name: String, event_count: u32, throttle_ms: u32) -> Self {
+        Self {
+            device_name,
+            packages: Vec::new(),
+            package: String::new(),
+            event_count: event_count.to_string(),
+            throttle_ms: throttle_ms.to_string(),
+            active_field: MonkeyField::default(),
+        }
+    }
+
+    /// Stores the installed packages and preselects the first one unless a
+    /// package was typed meanwhile.
+    pub fn set_packages(&mut self, packages: Vec<String>) {
+        if self.package.is_empty() {
+            if let Some(first) = packages.first() {
+                self.package = first.clone();
+            }
+        }
+        self.packages = packages;
+    }
+
+    /// Replaces the package with the installed package before or after it.
+    pub fn cycle_package(&mut self, forward: bool) {
+        if self.packages.is_empty() {
+            return;
+        }
+        let len = self.packages.len();
+        let next = match self.packages.iter().position(|p| *p == self.package) {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None => 0,
+        };
+        self.package = self.packages[next].clone();
+    }
+
+    pub fn active_input(&mut self) -> &mut String {
+        match self.active_field {
+            MonkeyField::Package => &mut self.package,
+            MonkeyField::EventCount => &mut self.event_count,
+            MonkeyField::Throttle => &mut self.throttle_ms,
+        }
+    }
+}
+
+/// List shown by the process inspector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessInspectorTab {
+    #[default]
+    Processes,
+    Services,
+}
+
+/// Data for the process inspector of a running device.
+#[derive(Debug, Clone)]
+pub struct ProcessInspectorDialog {
+    /// Display name of the device
+    pub device_name: String,
+    /// Unique identifier (AVD name for Android, UDID for iOS)
+    pub device_identifier: String,
+    /// Platform of the inspected device
+    pub platform: Panel,
+    /// List being shown
+    pub tab: ProcessInspectorTab,
+    /// Order of both lists
+    pub sort: ProcessSort,
+    /// Processes, or why they could not be listed; `None` while loading
+    pub processes: Option<Result<Vec<DeviceProcess>, String>>,
+    /// Services, or why they could not be listed; `None` while loading
+    pub services: Option<Result<Vec<DeviceService>, String>>,
+    /// Index of the selected row of the current list
+    pub selected: usize,
+}
+
+impl ProcessInspectorDialog {
+    pub fn new(device_name: String, device_identifier: String, platform: Panel) -> Self {
+        Self {
+            device_name,
+            device_identifier,
+            platform,
+            tab: ProcessInspectorTab::default(),
+            sort: ProcessSort::default(),
+            processes: None,
+            services: None,
+            selected: 0,
+        }
+    }
+
+    /// Stores freshly loaded lists in the current sort order.
+    pub fn set_lists(
+        &mut self,
+        processes: Result<Vec<DeviceProcess>, String>,
+        services: Result<Vec<DeviceService>, String>,
+    ) {
+        self.processes = Some(processes);
+        self.services = Some(services);
+        self.sort_lists();
+        self.selected = self.selected.min(self.row_count().saturating_sub(1));
+    }
+
+    pub fn toggle_sort(&mut self) {
+        self.sort = self.sort.toggle();
+        self.sort_lists();
+        self.selected = 0;
+    }
+
+    pub fn switch_tab(&mut self) {
+        self.tab = match self.tab {
+            ProcessInspectorTab::Processes => ProcessInspectorTab::Services,
+            ProcessInspectorTab::Services => ProcessInspectorTab::Processes,
+        };
+        self.selected = 0;
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.row_count() {
+            self.selected += 1;
+        }
+    }
+
+    /// Loaded processes, empty while loading or after an error.
+    pub fn loaded_processes(&self) -> &[DeviceProcess] {
+        match &self.processes {
+            Some(Ok(processes)) => processes,
+            _ => &[],
+        }
+    }
+
+    /// Loaded services, empty while loading or after an error.
+    pub fn loaded_services(&self) -> &[DeviceService] {
+        match &self.services {
+            Some(Ok(services)) => services,
+            _ => &[],
+        }
+    }
+
+    /// Resident memory of the process with `pid`, if known.
+    pub fn memory_of(&self, pid: u32) -> Option<u64> {
+        self.loaded_processes()
+            .iter()
+            .find(|process| process.pid == pid)
+            .and_then(|process| process.memory_kb)
+    }
+
+    /// The process the kill action targets: the selected process, or the
+    /// process hosting the selected service.
+    pub fn selected_process(&self) -> Option<&DeviceProcess> {
+        match self.tab {
+            ProcessInspectorTab::Processes => self.loaded_processes().get(self.selected),
+            ProcessInspectorTab::Services => {
+                let pid = self.loaded_services().get(self.selected)?.pid?;
+                self.loaded_processes()
+                    .iter()
+                    .find(|process| process.pid == pid)
+            }
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        match self.tab {
+            ProcessInspectorTab::Processes => self.loaded_processes().len(),
+            ProcessInspectorTab::Services => self.loaded_services().len(),
+        }
+    }
+
+    /// Services follow the memory of their hosting process, so a service
+    /// keeping a large process alive shows up at the top.
+    fn sort_lists(&mut self) {
+        if let Some(Ok(processes)) = &mut self.processes {
+            self.sort.sort(processes);
+        }
+        let memory: Vec<(u32, Option<u64>)> = self
+            .loaded_processes()
+            .iter()
+            .map(|process| (process.pid, process.memory_kb))
+            .collect();
+        let memory_of = |service: &DeviceService| {
+            service.pid.and_then(|pid| {
+                memory
+                    .iter()
+                    .find(|(process_pid, _)| *process_pid == pid)
+                    .and_then(|(_, memory_kb)| *memory_kb)
+            })
+        };
+        let sort = self.sort;
+        if let Some(Ok(services)) = &mut self.services {
+            match sort {
+                ProcessSort::Memory => services.sort_by(|a, b| {
+                    memory_of(b)
+                        .cmp(&memory_of(a))
+                        .then_with(|| a.name.cmp(&b.name))
+                }),
+                ProcessSort::Name => services.sort_by(|a, b| a.name.cmp(&b.name)),
+            }
+        }
+    }
+}
+
+/// Data for the system property browser of a running AVD.
+#[derive(Debug, Clone)]
+pub struct SystemPropertiesDialog {
+    /// AVD name
+    pub device_name: String,
+    /// Properties, or why they could not be listed; `None` while loading
+    pub properties: Option<Result<Vec<SystemProperty>, String>>,
+    /// Search text narrowing the list by name or value
+    pub filter: String,
+    /// Index of the selected row of the filtered list
+    pub selected: usize,
+    /// New value being typed for the selected property, if editing
+    pub edit_value: Option<String>,
+}
+
+impl SystemPropertiesDialog {
+    pub fn new(device_name: String) -> Self {
+        Self {
+            device_name,
+            properties: None,
+            filter: String::new(),
+            selected: 0,
+            edit_value: None,
+        }
+    }
+
+    /// Stores a freshly loaded list, keeping the selection in range.
+    pub fn set_properties(&mut self, properties: Result<Vec<SystemProperty>, String>) {
+        self.properties = Some(properties);
+        self.selected = self.selected.min(self.filtered().len().saturating_sub(1));
+    }
+
+    /// Properties matching the search text.
+    pub fn filtered(&self) -> Vec<&SystemProperty> {
+        match &self.properties {
+            Some(Ok(properties)) => properties
+                .iter()
+                .filter(|property| property.matches(&self.filter))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn selected_property(&self) -> Option<&SystemProperty> {
+        self.filtered().get(self.selected).copied()
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.filtered().len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Input buffer receiving typed characters: the new value while
+    /// editing, the search text otherwise.
+    pub fn active_input(&mut self) -> &mut String {
+        match &mut self.edit_value {
+            Some(value) => value,
+            None => &mut self.filter,
+        }
+    }
+
+    /// Called after the search text changed.
+    pub fn filter_changed(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Starts editing the selected property with its current value.
+    /// Returns false when nothing is selected or the property is read-only.
+    pub fn start_edit(&mut self) -> bool {
+        match self.selected_property() {
+            Some(property) if property.is_writable() => {
+                self.edit_value = Some(property.value.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Data for the launch diagnosis of an AVD that failed to start.
+#[derive(Debug, Clone)]
+pub struct LaunchDiagnosisDialog {
+    /// Name of the AVD
+    pub device_name: String,
+    /// The emulator's last error, when it exited with one
+    pub reason: Option<String>,
+    /// Problems found, each with its fix
+    pub issues: Vec<LaunchIssue>,
+    /// Index of the selected issue
+    pub selected: usize,
+    /// Issue whose fix is being applied
+    pub fixing: Option<LaunchIssue>,
+}
+
+impl LaunchDiagnosisDialog {
+    pub fn new(device_name: String, reason: Option<String>, issues: Vec<LaunchIssue>) -> Self {
+        Self {
+            device_name,
+            reason,
+            issues,
+            selected: 0,
+            fixing: None,
+        }
+    }
+
+    pub fn selected_issue(&self) -> Option<&LaunchIssue> {
+        self.issues.get(self.selected)
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.issues.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Drops an issue once its fix was applied. Returns true when no issue
+    /// is left.
+    pub fn issue_fixed(&mut self, issue: &LaunchIssue) -> bool {
+        self.issues.retain(|remaining| remaining != issue);
+        self.selected = self.selected.min(self.issues.len().saturating_sub(1));
+        self.issues.is_empty()
+    }
+}
+
+/// Data for the Quick Boot options of an AVD.
+#[derive(Debug, Clone)]
+pub struct QuickBootDialog {
+    /// Name of the AVD
+    pub device_name: String,
+    /// Snapshot and cold boot settings, `None` until they are read
+    pub status: Option<QuickBootStatus>,
+}
+
+impl QuickBootDialog {
+    pub fn new(device_name: String) -> Self {
+        Self {
+            device_name,
+            status: None,
+        }
+    }
+}
+
+/// Data for the radio toggles of a running AVD.
+#[derive(Debug, Clone)]
+pub struct RadiosDialog {
+    /// Name of the AVD
+    pub device_name: String,
+    /// State of each radio, `None` until it is read
+    pub status: Option<RadioStatus>,
+    /// Radio being switched, whose state is shown as pending
+    pub switching: Option<Radio>,
+}
+
+impl RadiosDialog {
+    pub fn new(device_name: String) -> Self {
+        Self {
+            device_name,
+            status: None,
+            switching: None,
+        }
+    }
+}
+
+/// Data for the developer toggles of a running device.
+#[derive(Debug, Clone)]
+pub struct DeveloperTogglesDialog {
+    pub device: DeviceId,
+    pub device_name: String,
+    /// Which toggles are on, `None` until they are read
+    pub status: Option<DeveloperToggleStatus>,
+    /// Toggle being switched, whose state is shown as pending
+    pub switching: Option<DeveloperToggle>,
+}
+
+impl DeveloperTogglesDialog {
+    pub fn new(device: DeviceId, device_name: String) -> Self {
+        Self {
+            device,
+            device_name,
+            status: None,
+            switching: None,
+        }
+    }
+}
+
+/// Data for the app data backups of a running device, and for copying its
+/// apps to other running devices.
+#[derive(Debug, Clone)]
+pub struct AppDataDialog {
+    pub device: DeviceId,
+    pub device_name: String,
+    /// Other running devices of the platform, with their names
+    pub targets: Vec<(DeviceId, String)>,
+    /// Index of the device that `c` copies the app to
+    pub selected_target: usize,
+    /// Where backups are written and looked up
+    pub backup_dir: PathBuf,
+    /// Installed packages or bundle identifiers, empty until loaded
+    pub apps: Vec<String>,
+    /// Index of the highlighted app
+    pub selected_app: usize,
+    /// Backups of the highlighted app, newest first
+    pub backups: Vec<PathBuf>,
+    /// Index of the backup that `r` restores
+    pub selected_backup: usize,
+    /// Backup, restore or copy running in the background, if any
+    pub status: Option<String>,
+}
+
+impl AppDataDialog {
+    pub fn new(
+        device: DeviceId,
+        device_name: String,
+        targets: Vec<(DeviceId, String)>,
+        backup_dir: PathBuf,
+    ) -> Self {
+        Self {
+            device,
+            device_name,
+            targets,
+            selected_target: 0,
+            backup_dir,
+            apps: Vec::new(),
+            selected_app: 0,
+            backups: Vec::new(),
+            selected_backup: 0,
+            status: None,
+        }
+    }
+
+    pub fn selected_app(&self) -> Option<&str> {
+        self.apps.get(self.selected_app).map(String::as_str)
+    }
+
+    pub fn selected_backup(&self) -> Option<&PathBuf> {
+        self.backups.get(self.selected_backup)
+    }
+
+    pub fn selected_target(&self) -> Option<&(DeviceId, String)> {
+        self.targets.get(self.selected_target)
+    }
+
+    pub fn next_target(&mut self) {
+        if !self.targets.is_empty() {
+            self.selected_target = (self.selected_target + 1) % self.targets.len();
+        }
+    }
+
+    pub fn set_apps(&mut self, apps: Vec<String>) {
+        self.apps = apps;
+        self.selected_app = 0;
+        self.refresh_backups();
+    }
+
+    /// Highlights the app before or after the current one and lists its
+    /// backups.
+    pub fn move_app(&mut self, forward: bool) {
+        if self.apps.is_empty() {
+            return;
+        }
+        let len = self.apps.len();
+        self.selected_app = if forward {
+            (self.selected_app + 1) % len
+        } else {
+            (self.selected_app + len - 1) % len
+        };
+        self.refresh_backups();
+    }
+
+    /// Picks an older (`forward`) or newer backup of the highlighted app.
+    pub fn move_backup(&mut self, forward: bool) {
+        self.selected_backup = if forward {
+            (self.selected_backup + 1).min(self.backups.len().saturating_sub(1))
+        } else {
+            self.selected_backup.saturating_sub(1)
+        };
+    }
+
+    /// Lists the backups of the highlighted app again, picking the newest.
+    pub fn refresh_backups(&mut self) {
+        self.backups = match self.selected_app() {
+            Some(app) => app_data::list_backups(&self.backup_dir, self.device.platform, app),
+            None => Vec::new(),
+        };
+        self.selected_backup = 0;
+    }
+}
+
+/// Data for the recommended device catalog.
+#[derive(Debug, Clone)]
+pub struct DeviceCatalogDialog {
+    pub catalog: DeviceCatalog,
+    /// Index of the highlighted entry
+    pub selected: usize,
+    /// What runs in the background, e.g. the image download, if anything
+    pub status: Option<String>,
+    /// Percentage of the running step, when known
+    pub progress: Option<u8>,
+    /// Why the last refresh or creation failed
+    pub error: Option<String>,
+}
+
+impl DeviceCatalogDialog {
+    pub fn new(catalog: DeviceCatalog) -> Self {
+        Self {
+            catalog,
+            selected: 0,
+            status: None,
+            progress: None,
+            error: None,
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&CatalogEntry> {
+        self.catalog.devices.get(self.selected)
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.status.is_some()
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.catalog.devices.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Shows a newly downloaded catalog, keeping the highlighted entry when
+    /// it is still listed.
+    pub fn replace_catalog(&mut self, catalog: DeviceCatalog) {
+        let selected_name = self.selected_entry().map(|entry| entry.name.clone());
+        self.selected = selected_name
+            .and_then(|name| catalog.devices.iter().position(|entry| entry.name == name))
+            .unwrap_or(0);
+        self.catalog = catalog;
+    }
+}
+
+/// Data for the shell snippet picker.
+#[derive(Debug, Clone)]
+pub struct SnippetsDialog {
+    /// Device the snippets run against
+    pub device: DeviceId,
+    pub device_name: String,
+    pub snippets: Vec<Snippet>,
+    /// Index of the highlighted snippet
+    pub selected: usize,
+}
+
+impl SnippetsDialog {
+    pub fn new(device: DeviceId, device_name: String, snippets: Vec<Snippet>) -> Self {
+        Self {
+            device,
+            device_name,
+            snippets,
+            selected: 0,
+        }
+    }
+
+    pub fn selected_snippet(&self) -> Option<&Snippet> {
+        self.snippets.get(self.selected)
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.snippets.len() {
+            self.selected += 1;
+        }
+    }
+}
+
+/// Field focused in the app launcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LaunchAppField {
+    #[default]
+    App,
+    Arguments,
+    /// iOS only
+    Environment,
+}
+
+/// Data for the app launcher of a running device.
+#[derive(Debug, Clone)]
+pub struct LaunchAppDialog {
+    /// Device the app runs on
+    pub device: DeviceId,
+    pub device_name: String,
+    /// Installed packages or bundle identifiers, empty until loaded
+    pub apps: Vec<String>,
+    /// Package name or bundle identifier to launch
+    pub app: String,
+    /// Launch arguments on iOS, intent extras on Android
+    pub arguments: String,
+    /// Space-separated `KEY=VALUE` variables
+    pub environment: String,
+    /// Field receiving typed characters
+    pub active_field: LaunchAppField,
+}
+
+impl LaunchAppDialog {
+    pub fn new(device: DeviceId, device_name: String) -> Self {
+        Self {
+            device,
+            device_name,
+            apps: Vec::new(),
+            app: String::new(),
+            arguments: String::new(),
+            environment: String::new(),
+            active_field: LaunchAppField::default(),
+        }
+    }
+
+    /// Whether the launched app takes environment variables, which only
+    /// simctl passes on.
+    pub fn takes_environment(&self) -> bool {
+        self.device.platform == Platform::Ios
+    }
+
+    /// Stores the installed apps and preselects the first one unless an app
+    /// was typed meanwhile.
+    pub fn set_apps(&mut self, apps: Vec<String>) {
+        if self.app.is_empty() {
+            if let Some(first) = apps.first() {
+                self.app = first.clone();
+            }
+        }
+        self.apps = apps;
+    }
+
+    /// Replaces the app with the installed app before or after it.
+    pub fn cycle_app(&mut self, forward: bool) {
+        if self.apps.is_empty() {
+            return;
+        }
+        let len = self.apps.len();
+        let next = match self.apps.iter().position(|app| *app == self.app) {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None => 0,
+        };
+        self.app = self.apps[next].clone();
+    }
+
+    /// Moves to the next field, skipping the environment on Android.
+    pub fn next_field(&mut self) {
+        self.active_field = match self.active_field {
+            LaunchAppField::App => LaunchAppField::Arguments,
+            LaunchAppField::Arguments if self.takes_environment() => LaunchAppField::Environment,
+            LaunchAppField::Arguments | LaunchAppField::Environment => LaunchAppField::App,
+        };
+    }
+
+    pub fn previous_field(&mut self) {
+        self.active_field = match self.active_field {
+            LaunchAppField::App if self.takes_environment() => LaunchAppField::Environment,
+            LaunchAppField::App | LaunchAppField::Environment => LaunchAppField::Arguments,
+            LaunchAppField::Arguments => LaunchAppField::App,
+        };
+    }
+
+    pub fn active_input(&mut self) -> &mut String {
+        match self.active_field {
+            LaunchAppField::App => &mut self.app,
+            LaunchAppField::Arguments => &mut self.arguments,
+            LaunchAppField::Environment => &mut self.environment,
+        }
+    }
+}
+
+/// An iOS app launched with its console output going to the log panel.
+#[derive(Debug)]
+pub struct AppConsole {
+    pub udid: String,
+    pub device_name: String,
+    pub bundle_id: String,
+    /// Task streaming the output until the app exits
+    pub handle: tokio::task::JoinHandle<()>,
+}
+
+/// A network capture of a running device, written to a pcap on the host.
+#[derive(Debug)]
+pub struct NetworkCapture {
+    pub device: DeviceId,
+    pub device_name: String,
+    pub path: PathBuf,
+    /// Size of the pcap so far
+    pub bytes: u64,
+    /// Task reading the size, and running tcpdump on iOS
+    pub handle: tokio::task::JoinHandle<()>,
+}
+
+/// Field focused in the trace capture dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TraceField {
+    #[default]
+    Target,
+    Duration,
+}
+
+impl TraceField {
+    pub fn toggle(self) -> Self {
+        match self {
+            TraceField::Target => TraceField::Duration,
+            TraceField::Duration => TraceField::Target,
+        }
+    }
+}
+
+/// Data for the trace capture dialog of a running device.
+#[derive(Debug, Clone)]
+pub struct TraceDialog {
+    /// Device to trace
+    pub device: DeviceId,
+    pub device_name: String,
+    /// Installed packages offered as the target, empty until loaded (Android only)
+    pub packages: Vec<String>,
+    /// App package (Android) or process (iOS) to trace, empty for the whole system
+    pub target: String,
+    /// Seconds to record, as typed
+    pub duration_secs: String,
+    /// Field receiving typed characters
+    pub active_field: TraceField,
+}
+
+impl TraceDialog {
+    pub fn new(device: DeviceId, device_name: String, duration_secs: u32) -> Self {
+        Self {
+            device,
+            device_name,
+            packages: Vec::new(),
+            target: String::new(),
+            duration_secs: duration_secs.to_string(),
+            active_field: TraceField::default(),
+        }
+    }
+
+    /// Steps through the installed packages, with the whole system before
+    /// the first one.
+    pub fn cycle_target(&mut self, forward: bool) {
+        let options: Vec<&str> = std::iter::once("")
+            .chain(self.packages.iter().map(String::as_str))
+            .collect();
+        let len = options.len();
+        let next = match options.iter().position(|option| *option == self.target) {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None => 0,
+        };
+        self.target = options[next].to_string();
+    }
+
+    pub fn active_input(&mut self) -> &mut String {
+        match self.active_field {
+            TraceField::Target => &mut self.target,
+            TraceField::Duration => &mut self.duration_secs,
+        }
+    }
+}
+
+/// Part of the test matrix wizard that takes keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestMatrixField {
+    DeviceTypes,
+    Versions,
+    Group,
+}
+
+impl TestMatrixField {
+    pub fn next(self) -> Self {
+        match self {
+            Self::DeviceTypes => Self::Versions,
+            Self::Versions => Self::Group,
+            Self::Group => Self::DeviceTypes,
+        }
+    }
+}
+
+/// Data for the test matrix wizard.
+#[derive(Debug, Clone)]
+pub struct TestMatrixDialog {
+    /// Panel the devices are created on
+    pub panel: Panel,
+    /// Device types to pick from, as `(identifier, display name)`
+    pub device_types: Vec<(String, String)>,
+    /// API levels or iOS runtimes to pick from, as `(identifier, display name)`
+    pub versions: Vec<(String, String)>,
+    pub marked_device_types: BTreeSet<usize>,
+    pub marked_versions: BTreeSet<usize>,
+    pub selected_device_type: usize,
+    pub selected_version: usize,
+    pub field: TestMatrixField,
+    /// Group the created devices join, for booting them together
+    pub group: String,
+    /// Device types and versions are still being listed
+    pub is_loading: bool,
+    pub error: Option<String>,
+}
+
+impl TestMatrixDialog {
+    pub fn new(panel: Panel) -> Self {
+        Self {
+            panel,
+            device_types: Vec::new(),
+            versions: Vec::new(),
+            marked_device_types: BTreeSet::new(),
+            marked_versions: BTreeSet::new(),
+            selected_device_type: 0,
+            selected_version: 0,
+            field: TestMatrixField::DeviceTypes,
+            group: DEFAULT_MATRIX_GROUP.to_string(),
+            is_loading: true,
+            error: None,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        match self.field {
+            TestMatrixField::DeviceTypes => {
+                self.selected_device_type = self.selected_device_type.saturating_sub(1);
+            }
+            TestMatrixField::Versions => {
+                self.selected_version = self.selected_version.saturating_sub(1);
+            }
+            TestMatrixField::Group => {}
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        match self.field {
+            TestMatrixField::DeviceTypes => {
+                if self.selected_device_type + 1 < self.device_types.len() {
+                    self.selected_device_type += 1;
+                }
+            }
+            TestMatrixField::Versions => {
+                if self.selected_version + 1 < self.versions.len() {
+                    self.selected_version += 1;
+                }
+            }
+            TestMatrixField::Group => {}
+        }
+    }
+
+    /// Marks or unmarks the highlighted device type or version.
+    pub fn toggle_selected(&mut self) {
+        let (marked, selected, len) = match self.field {
+            TestMatrixField::DeviceTypes => (
+                &mut self.marked_device_types,
+                self.selected_device_type,
+                self.device_types.len(),
+            ),
+            TestMatrixField::Versions => (
+                &mut self.marked_versions,
+                self.selected_version,
+                self.versions.len(),
+            ),
+            TestMatrixField::Group => return,
+        };
+        if selected < len && !marked.remove(&selected) {
+            marked.insert(selected);
+        }
+    }
+
+    /// The marked device types crossed with the marked versions.
+    pub fn matrix(&self) -> TestMatrix {
+        let pick = |items: &[(String, String)], marked: &BTreeSet<usize>| {
+            marked
+                .iter()
+                .filter_map(|&index| items.get(index).cloned())
+                .collect()
+        };
+        TestMatrix {
+            device_types: pick(&self.device_types, &self.marked_device_types),
+            versions: pick(&self.versions, &self.marked_versions),
+        }
+    }
+}
+
+/// Data for the prompt that sets or shifts a device clock.
+#[derive(Debug, Clone)]
+pub struct DeviceClockDialog {
+    /// Panel of the device, which decides how the clock is changed
+    pub panel: Panel,
+    /// Display name of the device
+    pub device_name: String,
+    /// AVD name for Android, UDID for iOS
+    pub identifier: String,
+    /// Date, offset, or `auto`, as typed
+    pub input: String,
+    /// Why the last input was rejected
+    pub error: Option<String>,
+}
+
+/// Data for forwarding keystrokes to a running AVD.
+#[derive(Debug, Clone)]
+pub struct TypeIntoDeviceDialog {
+    /// Name of the AVD
+    pub device_name: String,
+    /// Queue of the task that sends the keystrokes
+    pub sender: UnboundedSender<DeviceInput>,
+    /// Tail of what was typed, with symbols for keys such as Enter
+    pub echo: String,
+    /// Esc was pressed once; a second one stops typing
+    pub escape_armed: bool,
+}
+
+impl TypeIntoDeviceDialog {
+    pub fn new(device_name: String, sender: UnboundedSender<DeviceInput>) -> Self {
+        Self {
+            device_name,
+            sender,
+            echo: String::new(),
+            escape_armed: false,
+        }
+    }
+
+    /// Queues a keystroke for the device and adds it to the echo.
+    pub fn send(&mut self, input: DeviceInput) {
+        match &input {
+            DeviceInput::Text(text) => self.echo.push_str(text),
+            DeviceInput::Key(key) => self.echo.push_str(key.symbol()),
+        }
+        let overflow = self
+            .echo
+            .chars()
+            .count()
+            .saturating_sub(MAX_TYPED_ECHO_CHARS);
+        if overflow > 0 {
+            self.echo = self.echo.chars().skip(overflow).collect();
+        }
+        // The task is gone once sending failed, which it reports itself
+        let _ = self.sender.send(input);
+    }
+}
+
+/// Data for the command history dialog.
+/// Holds a snapshot of the executed commands taken when the dialog was opened.
+#[derive(Debug, Clone, Default)]
+pub struct CommandHistoryDialog {
+    /// Executed commands, newest first
+    pub entries: Vec<CommandAuditEntry>,
+    /// Index of the first visible entry
+    pub scroll_offset: usize,
+    /// Audit log file the entries are also written to, if enabled
+    pub log_path: Option<PathBuf>,
+}
+
+/// Data for the diagnostics viewer.
+/// Holds a snapshot of emu's log records taken when the viewer was opened.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsDialog {
+    /// Captured log records, newest first
+    pub entries: Vec<DiagnosticEntry>,
+    /// Index of the first visible entry
+    pub scroll_offset: usize,
+    /// Xcode simctl commands run against (macOS only)
+    pub developer_dir: Option<DeveloperDir>,
+    /// Developer directories of the installed Xcode versions
+    pub installed_xcodes: Vec<PathBuf>,
+}
+
+impl DiagnosticsDialog {
+    /// Scrolls the list up by one entry.
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the list down by one entry, stopping at the last entry.
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset + 1 < self.entries.len() {
+            self.scroll_offset += 1;
+        }
+    }
+}
+
+impl CommandHistoryDialog {
+    /// Scrolls the list up by one entry.
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the list down by one entry, stopping at the last entry.
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset + 1 < self.entries.len() {
+            self.scroll_offset += 1;
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use super::{state::TestMatrixDialog, state::TestMatrixField, App, Mode, Panel};
+use crate::constants::{
+    messages::notifications::{
+        MATRIX_GROUP_RUNNING, MATRIX_GROUP_STARTED, MATRIX_GROUP_START_FAILED, MATRIX_NO_GROUP,
+        MATRIX_QUEUED,
+    },
+    ui_text::test_matrix::{GROUP_REQUIRED, NOTHING_MARKED},
+};
+use crate::managers::AndroidManager;
+use crate::models::{error::format_user_error, matrix, DeviceId, DeviceOperation};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::sync::Arc;
+
+impl App {
+    /// Opens the test matrix wizard for the active panel.
+    pub(super) async fn open_test_matrix(&mut self) {
+        let panel = {
+            let mut state = self.state.lock().await;
+            if !state.active_capabilities().create {
+                return;
+            }
+            state.test_matrix_dialog = Some(TestMatrixDialog::new(state.active_panel));
+            state.mode = Mode::TestMatrix;
+            state.active_panel
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result = match panel {
+                Panel::Android => tokio::try_join!(
+                    android_manager.list_devices_by_category(Some("all")),
+                    android_manager.list_available_targets()
+                ),
+                Panel::Ios => match ios_manager {
+                    Some(ref ios_manager) => tokio::try_join!(
+                        ios_manager.list_device_types_with_names(),
+                        ios_manager.list_runtimes()
+                    ),
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+
+            let mut state = state.lock().await;
+            let Some(dialog) = state.test_matrix_dialog.as_mut() else {
+                return;
+            };
+            dialog.is_loading = false;
+            match result {
+                Ok((device_types, versions)) => {
+                    dialog.device_types = device_types;
+                    dialog.versions = versions;
+                }
+                Err(error) => dialog.error = Some(format_user_error(&error)),
+            }
+        });
+    }
+
+    pub(super) async fn handle_test_matrix_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.test_matrix_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.test_matrix_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            _ if dialog.is_loading => {}
+            KeyCode::Tab => dialog.field = dialog.field.next(),
+            KeyCode::Enter => {
+                drop(state);
+                self.create_test_matrix().await;
+            }
+            _ if dialog.field == TestMatrixField::Group => match key.code {
+                KeyCode::Backspace => {
+                    dialog.group.pop();
+                }
+                // Commas separate tags
+                KeyCode::Char(c) if c != ',' => dialog.group.push(c),
+                _ => {}
+            },
+            KeyCode::Up | KeyCode::Char('k') => dialog.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.move_down(),
+            KeyCode::Char(' ') => dialog.toggle_selected(),
+            _ => {}
+        }
+    }
+
+    /// Queues every device of the marked matrix that does not exist yet and
+    /// puts the existing ones into the group right away.
+    async fn create_test_matrix(&mut self) {
+        let (panel, configs, group, slots) = {
+            let mut state = self.state.lock().await;
+            let Some(dialog) = state.test_matrix_dialog.as_mut() else {
+                return;
+            };
+            let matrix = dialog.matrix();
+            let group = dialog.group.trim().to_string();
+            if matrix.is_empty() {
+                dialog.error = Some(NOTHING_MARKED.to_string());
+                return;
+            }
+            if group.is_empty() {
+                dialog.error = Some(GROUP_REQUIRED.to_string());
+                return;
+            }
+            let panel = dialog.panel;
+            let naming = state
+                .project_config
+                .as_ref()
+                .map(|config| config.naming.clone())
+                .unwrap_or_default();
+            let configs = matrix.configs(panel.into(), &naming);
+            state.test_matrix_dialog = None;
+            (panel, configs, group, state.creation_queue.slots())
+        };
+
+        let mut queued = Vec::new();
+        let mut existing = 0;
+        {
+            let mut state = self.state.lock().await;
+            for config in configs {
+                let exists = match panel {
+                    Panel::Android => {
+                        let avd_name = AndroidManager::sanitize_avd_name(&config.name);
+                        state
+                            .android_devices
+                            .iter()
+                            .any(|device| device.name == avd_name)
+                    }
+                    Panel::Ios => state
+                        .ios_devices
+                        .iter()
+                        .any(|device| device.name == config.name),
+                };
+                if exists {
+                    state.add_to_matrix_group(panel, &config.name, &group);
+                    existing += 1;
+                } else if !state
+                    .creation_queue
+                    .contains_unfinished(panel, &config.name)
+                {
+                    let id = state.creation_queue.enqueue_in_group(
+                        panel,
+                        config.clone(),
+                        Some(group.clone()),
+                    );
+                    queued.push((id, config));
+                }
+            }
+            state.add_info_notification(
+                MATRIX_QUEUED
+                    .replace("{group}", &group)
+                    .replace("{queued}", &queued.len().to_string())
+                    .replace("{existing}", &existing.to_string()),
+            );
+            state.creation_queue.scroll_offset = 0;
+            state.mode = Mode::CreationQueue;
+        }
+
+        for (id, config) in queued {
+            self.run_queued_creation(
+                id,
+                panel,
+                config,
+                Arc::clone(&slots),
+                self.android_manager.clone(),
+            );
+        }
+    }
+
+    /// Starts every stopped device, on both panels, that shares a test
+    /// matrix group with the selected device.
+    pub(super) async fn start_selected_matrix_group(&mut self) {
+        let (group, targets) = {
+            let mut state = self.state.lock().await;
+            let panel = state.active_panel;
+            let selected = match panel {
+                Panel::Android => state
+                    .android_devices
+                    .get(state.selected_android)
+                    .map(|device| (device.name.clone(), device.name.clone())),
+                Panel::Ios => state
+                    .ios_devices
+                    .get(state.selected_ios)
+                    .map(|device| (device.name.clone(), device.udid.clone())),
+            };
+            let Some((name, identifier)) = selected else {
+                return;
+            };
+            let Some(group) = state
+                .device_annotation(panel, &identifier)
+                .and_then(matrix::group_of)
+                .map(str::to_string)
+            else {
+                state.add_info_notification(MATRIX_NO_GROUP.replace("{}", &name));
+                return;
+            };
+
+            let tag = matrix::group_tag(&group);
+            let in_group = |id: &DeviceId| {
+                state.device_annotations.get(id).is_some_and(|annotation| {
+                    annotation
+                        .tags
+                        .iter()
+                        .any(|existing| existing.eq_ignore_ascii_case(&tag))
+                })
+            };
+            let android = state
+                .android_devices
+                .iter()
+                .filter(|device| !device.is_running)
+                .map(|device| (DeviceId::android(&device.name), device.name.clone()));
+            let ios = state
+                .ios_devices
+                .iter()
+                .filter(|device| !device.is_running)
+                .map(|device| (DeviceId::ios(&device.udid), device.name.clone()));
+            let targets: Vec<(DeviceId, String)> =
+                android.chain(ios).filter(|(id, _)| in_group(id)).collect();
+
+            if targets.is_empty() {
+                state.add_info_notification(MATRIX_GROUP_RUNNING.replace("{}", &group));
+                return;
+            }
+            (group, targets)
+        };
+
+        // Each AVD takes a while to launch, so the group boots in the
+        // background while keys keep working
+        let count = targets.len();
+        let targets: Vec<_> = targets
+            .into_iter()
+            .filter_map(|(id, name)| {
+                self.device_manager(id.platform)
+                    .map(|manager| (id, name, manager))
+            })
+            .collect();
+        let state = Arc::clone(&self.state);
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        tasks::spawn(async move {
+            let mut started = 0;
+            let mut last_error = None;
+            for (id, name, manager) in targets {
+                state
+                    .lock()
+                    .await
+                    .set_device_operation_status(format!("Starting device '{name}'..."));
+                let result = manager.start_device(&id.identifier).await;
+
+                let panel = Panel::from(id.platform);
+                let mut locked = state.lock().await;
+                match result {
+                    Ok(()) => {
+                        locked.record_device_operation(
+                            panel,
+                            &id.identifier,
+                            DeviceOperation::Boot,
+                            Ok(()),
+                        );
+                        locked.expect_device_state(&id, &name, true);
+                        drop(locked);
+                        started += 1;
+                        Self::run_project_hook_with(&state, DeviceOperation::Boot, &id, &name)
+                            .await;
+                        Self::apply_test_settings_on_start_with(
+                            &state,
+                            &android_manager,
+                            ios_manager.as_ref(),
+                            &id,
+                            &name,
+                        )
+                        .await;
+                    }
+                    Err(error) => {
+                        locked.record_device_operation(
+                            panel,
+                            &id.identifier,
+                            DeviceOperation::Boot,
+                            Err(error.to_string()),
+                        );
+                        last_error = Some(format!("'{name}': {}", format_user_error(&error)));
+                    }
+                }
+            }
+
+            let mut state = state.lock().await;
+            state.clear_device_operation_status();
+            match last_error {
+                None => state.add_success_notification(
+                    MATRIX_GROUP_STARTED
+                        .replace("{count}", &started.to_string())
+                        .replace("{group}", &group),
+                ),
+                Some(error) => state.add_error_notification(
+                    MATRIX_GROUP_START_FAILED
+                        .replace("{started}", &started.to_string())
+                        .replace("{count}", &count.to_string())
+                        .replace("{group}", &group)
+                        .replace("{error}", &error),
+                ),
+            }
+        });
+        self.schedule_background_device_status_check().await;
+    }
+}
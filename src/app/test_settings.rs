@@ -0,0 +1,200 @@
+use super::{App, AppState, Panel};
+use crate::constants::{
+    messages::notifications::{
+        FOCUS_NOT_RUNNING, TEST_SETTINGS_APPLIED_ANDROID, TEST_SETTINGS_APPLIED_IOS,
+        TEST_SETTINGS_FAILED, TEST_SETTINGS_RESTORED,
+    },
+    timeouts::{DEFAULT_WAIT_FOR_BOOT_TIMEOUT, WAIT_FOR_BOOT_POLL_INTERVAL},
+};
+use crate::managers::{AndroidManager, IosManager};
+use crate::models::{error::format_user_error, DeviceId, Platform, TestSettingsBackup};
+use crate::utils::boot_wait::poll_until;
+use crate::utils::tasks;
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+impl App {
+    /// Applies the UI test settings to the selected device, or restores what
+    /// it had before when they are applied already.
+    pub(super) async fn toggle_selected_device_test_settings(&mut self) {
+        let (id, name, backup) = {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state.selected_android_device().map(|device| {
+                    (
+                        DeviceId::android(&device.name),
+                        &device.name,
+                        device.is_running,
+                    )
+                }),
+                // Simulator.app reads the keyboard preference, so a
+                // simulator does not have to be booted
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (DeviceId::ios(&device.udid), &device.name, true)),
+            };
+            let Some((id, name, is_running)) = target else {
+                return;
+            };
+            let name = name.clone();
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let backup = state.test_settings_backups.get(&id).cloned();
+            (id, name, backup)
+        };
+
+        Self::spawn_test_settings_change(
+            &self.state,
+            &self.android_manager,
+            self.ios_manager.as_ref(),
+            id,
+            name,
+            backup,
+        );
+    }
+
+    /// Applies the UI test settings to a device emu just started, when
+    /// `.emu.toml` asks for it. An AVD only accepts settings once it has
+    /// booted, so this waits in the background.
+    pub(super) async fn apply_test_settings_on_start(&self, id: &DeviceId, name: &str) {
+        Self::apply_test_settings_on_start_with(
+            &self.state,
+            &self.android_manager,
+            self.ios_manager.as_ref(),
+            id,
+            name,
+        )
+        .await;
+    }
+
+    /// [`App::apply_test_settings_on_start`] for background tasks, which
+    /// only hold the shared state and the managers.
+    pub(super) async fn apply_test_settings_on_start_with(
+        state: &Arc<Mutex<AppState>>,
+        android_manager: &AndroidManager,
+        ios_manager: Option<&IosManager>,
+        id: &DeviceId,
+        name: &str,
+    ) {
+        {
+            let state = state.lock().await;
+            let apply_on_start = state
+                .project_config
+                .as_ref()
+                .is_some_and(|config| config.test_settings.apply_on_start);
+            // Android keeps the settings across reboots, so a backup from
+            // before the restart still holds the original values
+            if !apply_on_start || state.test_settings_backups.contains_key(id) {
+                return;
+            }
+        }
+
+        Self::spawn_test_settings_change(
+            state,
+            android_manager,
+            ios_manager,
+            id.clone(),
+            name.to_string(),
+            None,
+        );
+    }
+
+    fn spawn_test_settings_change(
+        state: &Arc<Mutex<AppState>>,
+        android_manager: &AndroidManager,
+        ios_manager: Option<&IosManager>,
+        id: DeviceId,
+        name: String,
+        backup: Option<TestSettingsBackup>,
+    ) {
+        let android_manager = android_manager.clone();
+        let ios_manager = ios_manager.cloned();
+        let state = Arc::clone(state);
+        tasks::spawn(async move {
+            let result = match &backup {
+                Some(backup) => {
+                    restore_test_settings(&android_manager, ios_manager.as_ref(), &id, backup)
+                        .await
+                        .map(|()| None)
+                }
+                None => apply_test_settings(&android_manager, ios_manager.as_ref(), &id)
+                    .await
+                    .map(Some),
+            };
+
+            let mut state = state.lock().await;
+            match result {
+                Ok(Some(backup)) => {
+                    let message = match id.platform {
+                        Platform::Android => TEST_SETTINGS_APPLIED_ANDROID.replace("{}", &name),
+                        Platform::Ios => TEST_SETTINGS_APPLIED_IOS.to_string(),
+                    };
+                    state.test_settings_backups.insert(id, backup);
+                    state.add_success_notification(message);
+                }
+                Ok(None) => {
+                    state.test_settings_backups.remove(&id);
+                    state.add_success_notification(TEST_SETTINGS_RESTORED.replace("{}", &name));
+                }
+                Err(error) => {
+                    state.add_error_notification(
+                        TEST_SETTINGS_FAILED.replacen("{}", &name, 1).replacen(
+                            "{}",
+                            &format_user_error(&error),
+                            1,
+                        ),
+                    );
+                }
+            }
+        });
+    }
+}
+
+async fn apply_test_settings(
+    android_manager: &AndroidManager,
+    ios_manager: Option<&IosManager>,
+    id: &DeviceId,
+) -> Result<TestSettingsBackup> {
+    match id.platform {
+        Platform::Android => {
+            poll_until(
+                WAIT_FOR_BOOT_POLL_INTERVAL,
+                DEFAULT_WAIT_FOR_BOOT_TIMEOUT,
+                || async {
+                    android_manager
+                        .is_boot_completed(&id.identifier)
+                        .await
+                        .unwrap_or(false)
+                },
+            )
+            .await;
+            android_manager.apply_test_settings(&id.identifier).await
+        }
+        Platform::Ios => match ios_manager {
+            Some(ios_manager) => ios_manager.apply_test_settings().await,
+            None => Err(anyhow::anyhow!("iOS manager not available")),
+        },
+    }
+}
+
+async fn restore_test_settings(
+    android_manager: &AndroidManager,
+    ios_manager: Option<&IosManager>,
+    id: &DeviceId,
+    backup: &TestSettingsBackup,
+) -> Result<()> {
+    match id.platform {
+        Platform::Android => {
+            android_manager
+                .restore_test_settings(&id.identifier, backup)
+                .await
+        }
+        Platform::Ios => match ios_manager {
+            Some(ios_manager) => ios_manager.restore_test_settings(backup).await,
+            None => Err(anyhow::anyhow!("iOS manager not available")),
+        },
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::models::DeviceStatus;
-use crate::models::{ApiLevel, SystemImageVariant};
+use crate::models::{ApiLevel, DeviceId, IntentKind, SystemImageVariant};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::ffi::OsString;
 use std::sync::OnceLock;
@@ -44,6 +44,7 @@ struct StartupTestEnv {
     _home: EnvVarGuard,
     _xdg_config_home: EnvVarGuard,
     _path: EnvVarGuard,
+    _offline: EnvVarGuard,
 }
 
 impl StartupTestEnv {
@@ -211,6 +212,8 @@ hw.device.name=pixel_7
             _home: EnvVarGuard::set("HOME", home_dir),
             _xdg_config_home: EnvVarGuard::set("XDG_CONFIG_HOME", xdg_config_home),
             _path: EnvVarGuard::set("PATH", combined_path),
+            // Tests never wait on the network check
+            _offline: EnvVarGuard::set("EMU_OFFLINE", "0"),
         }
     }
 }
@@ -301,6 +304,7 @@ async fn test_update_log_stream_internal_clears_log_target_for_stopped_android_d
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }];
         state_lock.current_log_device = Some((Panel::Android, "OldDevice".to_string()));
     }
@@ -332,6 +336,7 @@ async fn test_update_log_stream_internal_sets_log_target_for_running_android_dev
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }];
     }
 
@@ -369,6 +374,7 @@ async fn test_update_device_details_internal_populates_selected_android_details(
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }];
     }
 
@@ -395,14 +401,11 @@ async fn test_execute_delete_device_removes_android_device_and_adjusts_selection
     let _env_lock = acquire_test_env_lock().await;
     let _env = StartupTestEnv::new();
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::new().expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
 
     {
         let mut state = app.state.lock().await;
@@ -416,6 +419,7 @@ async fn test_execute_delete_device_removes_android_device_and_adjusts_selection
                 is_running: false,
                 ram_size: "4096".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             },
             AndroidDevice {
                 name: "Tablet_API_33".to_string(),
@@ -426,6 +430,7 @@ async fn test_execute_delete_device_removes_android_device_and_adjusts_selection
                 is_running: false,
                 ram_size: "4096".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             },
         ];
         state.selected_android = 1;
@@ -459,14 +464,11 @@ async fn test_execute_wipe_device_removes_android_user_data_and_notifies() {
     let _env_lock = acquire_test_env_lock().await;
     let _env = StartupTestEnv::new();
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::new().expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
 
     let home_dir = std::env::var("HOME").expect("HOME should be set by StartupTestEnv");
     let avd_dir = std::path::PathBuf::from(home_dir).join(".android/avd/Pixel_7_API_34.avd");
@@ -488,6 +490,7 @@ async fn test_execute_wipe_device_removes_android_user_data_and_notifies() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }];
         state.device_operation_status = Some("Wiping device...".to_string());
         state.confirm_wipe_dialog = Some(state::ConfirmWipeDialog {
@@ -514,19 +517,313 @@ async fn test_execute_wipe_device_removes_android_user_data_and_notifies() {
 }
 
 #[test]
-async fn test_reload_device_types_for_category_uses_cached_android_devices() {
+async fn test_confirmation_policy_skips_wipe_of_stopped_devices() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+
+    let home_dir = std::env::var("HOME").expect("HOME should be set by StartupTestEnv");
+    let userdata_path =
+        std::path::PathBuf::from(home_dir).join(".android/avd/Pixel_7_API_34.avd/userdata.img");
+    std::fs::write(&userdata_path, "userdata").unwrap();
+
+    {
+        let mut state = app.state.lock().await;
+        state.set_project_config(
+            crate::models::ProjectConfig::parse("[confirm]\nwipe = \"running\"").unwrap(),
+        );
+        state.active_panel = Panel::Android;
+        state.android_devices = vec![AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            status: DeviceStatus::Running,
+            is_running: true,
+            ..Default::default()
+        }];
+    }
+
+    // A running device still asks first
+    app.open_wipe_confirmation().await.unwrap();
+    {
+        let mut state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::ConfirmWipe);
+        assert!(userdata_path.exists());
+        state.mode = Mode::Normal;
+        state.confirm_wipe_dialog = None;
+        state.android_devices[0].status = DeviceStatus::Stopped;
+        state.android_devices[0].is_running = false;
+    }
+
+    app.open_wipe_confirmation().await.unwrap();
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::Normal);
+    assert!(!userdata_path.exists());
+    assert_eq!(
+        state
+            .notifications
+            .back()
+            .map(|notification| notification.message.as_str()),
+        Some("Device 'Pixel_7_API_34' wiped successfully")
+    );
+}
+
+#[test]
+async fn test_read_only_mode_turns_off_mutating_operations() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+    app.set_read_only(true).await;
+
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Android;
+        state.android_devices = vec![AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            status: DeviceStatus::Stopped,
+            ..Default::default()
+        }];
+    }
+
+    // A lone `d` acts once the `d d` chord can no longer complete
+    app.process_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE))
+        .await
+        .unwrap();
+    assert!(app.state.lock().await.notifications.is_empty());
+    app.release_expired_key_chord(
+        std::time::Instant::now() + crate::constants::performance::KEY_CHORD_TIMEOUT,
+    )
+    .await
+    .unwrap();
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::Normal);
+        assert!(state.confirm_delete_dialog.is_none());
+        assert_eq!(
+            state
+                .notifications
+                .back()
+                .map(|notification| notification.message.as_str()),
+            Some("Read-only mode: deleting devices is turned off")
+        );
+    }
+
+    // The managers refuse as well, whichever way the operation is reached
+    let capabilities = crate::managers::common::DeviceManager::capabilities(&app.android_manager);
+    assert!(!capabilities.delete && capabilities.snapshots);
+    let error = app
+        .android_manager
+        .delete_device("Pixel_7_API_34")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<crate::models::DeviceError>(),
+        Some(crate::models::DeviceError::ReadOnly { .. })
+    ));
+}
+
+#[test]
+async fn test_chords_jump_to_the_first_device_and_open_delete() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Android;
+        state.android_devices = ["Pixel_7_API_34", "Pixel_8_API_35"]
+            .into_iter()
+            .map(|name| AndroidDevice {
+                name: name.to_string(),
+                status: DeviceStatus::Stopped,
+                ..Default::default()
+            })
+            .collect();
+        state.selected_android = 1;
+    }
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    app.process_key_event(press('g')).await.unwrap();
+    app.process_key_event(press('g')).await.unwrap();
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.selected_android, 0);
+        assert_eq!(state.mode, Mode::Normal);
+    }
+
+    app.process_key_event(press('d')).await.unwrap();
+    app.process_key_event(press('d')).await.unwrap();
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::ConfirmDelete);
+    assert_eq!(
+        state
+            .confirm_delete_dialog
+            .as_ref()
+            .map(|dialog| dialog.device_name.as_str()),
+        Some("Pixel_7_API_34")
+    );
+}
+
+#[test]
+async fn test_move_device_data_dialog_moves_the_avd() {
     let _env_lock = acquire_test_env_lock().await;
     let _env = StartupTestEnv::new();
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::new().expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+
+    let home_dir = std::path::PathBuf::from(std::env::var("HOME").unwrap());
+    let avd_root = home_dir.join(".android/avd");
+    std::fs::write(
+        avd_root.join("Pixel_7_API_34.ini"),
+        format!("path={}\n", avd_root.join("Pixel_7_API_34.avd").display()),
+    )
+    .unwrap();
+    let target_dir = home_dir.join("quick-disk");
+
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Android;
+        state.android_devices = vec![AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            status: DeviceStatus::Stopped,
+            ..Default::default()
+        }];
+    }
+
+    app.process_key_event(KeyEvent::new(KeyCode::Char('M'), KeyModifiers::SHIFT))
+        .await
+        .unwrap();
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::MoveDeviceData);
+        let dialog = state.move_device_data_dialog.as_ref().unwrap();
+        assert_eq!(dialog.current_dir, avd_root.join("Pixel_7_API_34.avd"));
+    }
+
+    // `q` is part of the path rather than quitting
+    for c in "~/quick-disk".chars() {
+        let quit = app
+            .process_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert!(!quit);
+    }
+    app.process_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await
+        .unwrap();
+
+    wait_for_app_state(&app, |state| {
+        state
+            .notifications
+            .back()
+            .is_some_and(|notification| notification.message.starts_with("Moved data"))
+    })
+    .await;
+    assert!(target_dir.join("Pixel_7_API_34.avd").is_dir());
+    assert!(!avd_root.join("Pixel_7_API_34.avd").exists());
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::Normal);
+    assert!(state.move_device_data_dialog.is_none());
+}
+
+#[test]
+async fn test_intent_dialog_sends_intent_and_recalls_it_from_history() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::with_running_android(true);
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Android;
+        state.android_devices = vec![AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            status: DeviceStatus::Running,
+            is_running: true,
+            ..Default::default()
+        }];
+    }
+
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    app.process_key_event(press(KeyCode::Char('a')))
+        .await
+        .unwrap();
+    assert_eq!(app.state.lock().await.mode, Mode::SendIntent);
+
+    // Without an action or component the dialog stays open
+    app.process_key_event(press(KeyCode::Enter)).await.unwrap();
+    assert_eq!(app.state.lock().await.mode, Mode::SendIntent);
+
+    for c in "com.example.SYNC".chars() {
+        app.process_key_event(press(KeyCode::Char(c)))
+            .await
+            .unwrap();
+    }
+    app.process_key_event(press(KeyCode::BackTab))
+        .await
+        .unwrap();
+    app.process_key_event(press(KeyCode::Char(' ')))
+        .await
+        .unwrap();
+    app.process_key_event(press(KeyCode::Enter)).await.unwrap();
+
+    wait_for_app_state(&app, |state| {
+        state
+            .notifications
+            .back()
+            .is_some_and(|notification| notification.message.starts_with("Sent to"))
+    })
+    .await;
+    let sent = {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::Normal);
+        let history = state
+            .intent_history
+            .entries_for(&DeviceId::android("Pixel_7_API_34"));
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, IntentKind::Broadcast);
+        assert_eq!(history[0].action, "com.example.SYNC");
+        history[0].clone()
     };
 
+    app.process_key_event(press(KeyCode::Char('a')))
+        .await
+        .unwrap();
+    app.process_key_event(press(KeyCode::Up)).await.unwrap();
+    let state = app.state.lock().await;
+    assert_eq!(state.intent_dialog.as_ref().unwrap().intent, sent);
+}
+
+#[test]
+async fn test_reload_device_types_for_category_uses_cached_android_devices() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+
     {
         let mut state = app.state.lock().await;
         state.active_panel = Panel::Android;
@@ -598,16 +895,14 @@ async fn test_app_new() {
 
     #[cfg(target_os = "macos")]
     {
-        if result.is_ok() {
-            let app = result.unwrap();
+        if let Ok(app) = result {
             assert!(app.ios_manager.is_some());
         }
     }
 
     #[cfg(not(target_os = "macos"))]
     {
-        if result.is_ok() {
-            let app = result.unwrap();
+        if let Ok(app) = result {
             assert!(app.ios_manager.is_none());
         }
     }
@@ -625,7 +920,9 @@ async fn test_start_background_cache_loading() {
             let has_android_cache = {
                 let state = app.state.lock().await;
                 let cache = state.device_cache.read().await;
-                !cache.android_device_types.is_empty() && !cache.android_api_levels.is_empty()
+                !cache.android_device_types.is_empty()
+                    && !cache.android_api_levels.is_empty()
+                    && cache.android_system_images.is_some()
             };
             let has_api_level_cache = app.android_manager.get_cached_api_levels().await.is_some();
 
@@ -724,6 +1021,7 @@ async fn test_app_with_mock_devices() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             };
 
             state.android_devices.push(mock_android_device);
@@ -803,15 +1101,12 @@ async fn test_refresh_devices_smart_uses_status_only_path_between_full_refreshes
             "Pixel_7_API_34\n",
         );
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::with_executor(Arc::new(mock_executor))
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(mock_executor))
             .expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+        None,
+    );
 
     {
         let mut state = app.state.lock().await;
@@ -824,6 +1119,7 @@ async fn test_refresh_devices_smart_uses_status_only_path_between_full_refreshes
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }];
     }
 
@@ -925,6 +1221,7 @@ async fn test_refresh_device_statuses_only_resorts_unsorted_android_devices() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             },
             AndroidDevice {
                 name: "Pixel_9_API_36".to_string(),
@@ -935,6 +1232,7 @@ async fn test_refresh_device_statuses_only_resorts_unsorted_android_devices() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             },
             AndroidDevice {
                 name: "Pixel_7a_API_34".to_string(),
@@ -945,6 +1243,7 @@ async fn test_refresh_device_statuses_only_resorts_unsorted_android_devices() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             },
         ];
     }
@@ -965,6 +1264,49 @@ async fn test_refresh_device_statuses_only_resorts_unsorted_android_devices() {
     );
 }
 
+#[test]
+async fn test_refresh_keeps_ios_devices_while_tooling_is_detected() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let mut app = App::new().await.expect("App should initialize");
+    let app_state = app.state.clone();
+    let android_manager = app.android_manager.clone();
+    app = App::from_managers(
+        app_state,
+        android_manager,
+        Some(IosManager::new().expect("iOS manager should initialize")),
+    );
+
+    {
+        let mut state = app.state.lock().await;
+        state.ios_tooling = IosToolingStatus::Detecting;
+        state.android_devices = vec![AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            ..Default::default()
+        }];
+        state.ios_devices = vec![crate::models::IosDevice {
+            name: "iPhone 15".to_string(),
+            udid: "UDID-1".to_string(),
+            device_type: "iPhone 15".to_string(),
+            ios_version: "17.0".to_string(),
+            runtime_version: "17.0".to_string(),
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            is_available: true,
+            availability_error: None,
+        }];
+    }
+    assert!(app.ready_ios_manager().await.is_none());
+
+    app.refresh_device_statuses_only()
+        .await
+        .expect("status-only refresh should skip iOS while detecting");
+
+    let state = app.state.lock().await;
+    assert_eq!(state.ios_devices.len(), 1);
+    assert_eq!(state.ios_devices[0].udid, "UDID-1");
+}
+
 #[test]
 async fn test_open_api_level_management_uses_cached_levels_immediately() {
     let _env_lock = acquire_test_env_lock().await;
@@ -1007,14 +1349,7 @@ EOF
     let cached_levels = android_manager.list_api_levels().await.unwrap();
     assert!(!cached_levels.is_empty());
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager,
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+    let mut app = App::from_managers(Arc::new(Mutex::new(AppState::new())), android_manager, None);
 
     let start = std::time::Instant::now();
     app.open_api_level_management().await;
@@ -1033,6 +1368,57 @@ EOF
     );
 }
 
+#[test]
+async fn test_open_api_level_management_revalidates_prefetched_levels() {
+    use crate::utils::fault_injection::{Fault, FaultyCommandExecutor};
+
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    // sdkmanager never answers, so the revalidation stays in flight
+    let executor = FaultyCommandExecutor::new(
+        crate::utils::command_executor::mock::MockCommandExecutor::new(),
+    )
+    .with_fault("sdkmanager", &[], Fault::Hang(Duration::from_secs(60)));
+    let android_manager = AndroidManager::with_executor(Arc::new(executor))
+        .expect("Android manager should initialize");
+    let mut app = App::from_managers(Arc::new(Mutex::new(AppState::new())), android_manager, None);
+    {
+        let state = app.state.lock().await;
+        state.device_cache.write().await.android_system_images = Some(vec![ApiLevel::new(
+            34,
+            "API 34".to_string(),
+            "platforms;android-34".to_string(),
+        )]);
+    }
+
+    app.open_api_level_management().await;
+    let cancel_token = {
+        let state = app.state.lock().await;
+        let api_state = state.api_level_management.as_ref().unwrap();
+        assert_eq!(api_state.api_levels.len(), 1);
+        assert!(api_state.is_loading);
+        api_state.cancel_token.clone()
+    };
+
+    // The first Esc only stops the listing and keeps the cached levels
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+    app.handle_api_level_mode_key(esc).await;
+    {
+        let state = app.state.lock().await;
+        let api_state = state.api_level_management.as_ref().unwrap();
+        assert!(cancel_token.is_cancelled());
+        assert!(!api_state.is_loading);
+        assert_eq!(api_state.api_levels.len(), 1);
+        assert_eq!(state.mode, Mode::ManageApiLevels);
+    }
+
+    app.handle_api_level_mode_key(esc).await;
+    let state = app.state.lock().await;
+    assert!(state.api_level_management.is_none());
+    assert_eq!(state.mode, Mode::Normal);
+}
+
 #[test]
 async fn test_handle_api_level_mode_key_ignores_install_while_busy() {
     let _env_lock = acquire_test_env_lock().await;
@@ -1057,15 +1443,12 @@ async fn test_handle_api_level_mode_key_ignores_install_while_busy() {
         "system-images;android-34;google_apis_playstore;arm64-v8a".to_string(),
     ));
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::with_executor(Arc::new(mock_executor))
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(mock_executor))
             .expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+        None,
+    );
 
     {
         let mut state = app.state.lock().await;
@@ -1132,15 +1515,12 @@ async fn test_handle_api_level_mode_key_ignores_uninstall_while_busy() {
     api_level.is_installed = true;
     api_level.variants.push(installed_variant);
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::with_executor(Arc::new(mock_executor))
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(mock_executor))
             .expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+        None,
+    );
 
     {
         let mut state = app.state.lock().await;
@@ -1185,32 +1565,506 @@ async fn test_handle_api_level_mode_key_ignores_uninstall_while_busy() {
 }
 
 #[test]
-async fn test_install_selected_api_level_marks_installed_when_refresh_fails() {
+async fn test_api_level_search_takes_typed_keys_until_done() {
     let _env_lock = acquire_test_env_lock().await;
     let _env = StartupTestEnv::new();
-    let android_home =
-        std::env::var("ANDROID_HOME").expect("ANDROID_HOME should be set by StartupTestEnv");
-    let sdkmanager_path =
-        std::path::PathBuf::from(&android_home).join("cmdline-tools/latest/bin/sdkmanager");
-    let refresh_fail_marker = std::path::PathBuf::from(&android_home).join("refresh-fail");
-
-    std::fs::write(
-        &sdkmanager_path,
-        format!(
-            r#"#!/bin/sh
-MARKER="{marker}"
-if [ "$1" = "--list" ]; then
-    if [ -f "$MARKER" ]; then
-        echo "simulated refresh failure" >&2
-        exit 1
-    fi
-
-    cat <<'EOF'
-Installed packages:
-  Path | Version | Description | Location
 
-Available Packages:
-  system-images;android-34;google_apis_playstore;arm64-v8a | 1 | Android SDK Platform 34 | system-images/android-34/google_apis_playstore/arm64-v8a
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.mode = Mode::ManageApiLevels;
+        state.api_level_management = Some(state::ApiLevelManagementState {
+            api_levels: [35, 34, 33]
+                .into_iter()
+                .map(|api| ApiLevel::new(api, format!("Android {}", api - 20), String::new()))
+                .collect(),
+            is_loading: false,
+            ..Default::default()
+        });
+    }
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    // Typed characters go to the query instead of the dialog's shortcuts
+    for c in ['/', 'a', 'p', 'i', ' ', '3', '4'] {
+        app.handle_api_level_mode_key(press(c)).await;
+    }
+    app.handle_api_level_mode_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await;
+    app.handle_api_level_mode_key(press('f')).await;
+
+    let state = app.state.lock().await;
+    let api_state = state.api_level_management.as_ref().unwrap();
+    assert_eq!(api_state.search_query, "api 34");
+    assert!(!api_state.is_searching);
+    assert_eq!(api_state.filter, state::ApiLevelFilter::Installed);
+    assert_eq!(state.mode, Mode::ManageApiLevels);
+}
+
+#[test]
+async fn test_handle_api_level_mode_key_confirms_before_cancelling() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    let cancel_token = {
+        let mut state = app.state.lock().await;
+        state.mode = Mode::ManageApiLevels;
+        let api_state = state::ApiLevelManagementState {
+            installing_package: Some("system-images;android-34;google_apis;arm64-v8a".to_string()),
+            ..Default::default()
+        };
+        let cancel_token = api_state.cancel_token.clone();
+        state.api_level_management = Some(api_state);
+        cancel_token
+    };
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+
+    // Any other key after the first Esc keeps the install running
+    app.handle_api_level_mode_key(esc).await;
+    app.handle_api_level_mode_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+        .await;
+    app.handle_api_level_mode_key(esc).await;
+    {
+        let state = app.state.lock().await;
+        let api_state = state.api_level_management.as_ref().unwrap();
+        assert!(api_state.confirm_cancel);
+        assert!(api_state.is_busy());
+        assert!(!cancel_token.is_cancelled());
+    }
+
+    app.handle_api_level_mode_key(esc).await;
+
+    let state = app.state.lock().await;
+    let api_state = state.api_level_management.as_ref().unwrap();
+    assert!(cancel_token.is_cancelled());
+    assert!(!api_state.is_busy());
+    assert!(!api_state.confirm_cancel);
+    assert_eq!(state.mode, Mode::ManageApiLevels);
+}
+
+#[test]
+async fn test_handle_create_mode_key_confirms_before_cancelling_creation() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    let cancel_token = {
+        let mut state = app.state.lock().await;
+        state.mode = Mode::CreateDevice;
+        state.create_device_form.is_creating = true;
+        state.create_device_form.cancel_token.clone()
+    };
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+
+    app.handle_create_mode_key(esc).await.unwrap();
+    {
+        let state = app.state.lock().await;
+        assert!(state.create_device_form.confirm_cancel);
+        assert!(!cancel_token.is_cancelled());
+        assert_eq!(state.mode, Mode::CreateDevice);
+    }
+
+    app.handle_create_mode_key(esc).await.unwrap();
+
+    let state = app.state.lock().await;
+    assert!(cancel_token.is_cancelled());
+    assert!(!state.create_device_form.confirm_cancel);
+    assert_eq!(
+        state.create_device_form.creation_status.as_deref(),
+        Some(crate::constants::messages::notifications::DEVICE_CREATION_CANCELLING)
+    );
+    // The form stays open until the creation task has cleaned up
+    assert_eq!(state.mode, Mode::CreateDevice);
+}
+
+#[test]
+async fn test_uninstall_asks_before_breaking_dependent_avds() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let home_dir = std::env::var("HOME").expect("HOME should be set by StartupTestEnv");
+    let avd_dir = std::path::PathBuf::from(home_dir).join(".android/avd/Pixel_7_API_34.avd");
+    std::fs::create_dir_all(&avd_dir).unwrap();
+    std::fs::write(
+        avd_dir.join("config.ini"),
+        "image.sysdir.1=system-images/android-34/google_apis/x86_64/\n",
+    )
+    .unwrap();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.mode = Mode::ManageApiLevels;
+        let api_levels = [34, 35]
+            .into_iter()
+            .map(|api| {
+                let package_id = format!("system-images;android-{api};google_apis;x86_64");
+                let mut api_level =
+                    crate::models::ApiLevel::new(api, format!("{api}"), package_id.clone());
+                let mut variant = crate::models::SystemImageVariant::new(
+                    "google_apis".to_string(),
+                    "x86_64".to_string(),
+                    package_id,
+                );
+                variant.is_installed = true;
+                api_level.variants.push(variant);
+                api_level.is_installed = true;
+                api_level
+            })
+            .collect();
+        state.api_level_management = Some(state::ApiLevelManagementState {
+            api_levels,
+            is_loading: false,
+            ..Default::default()
+        });
+    }
+
+    app.handle_api_level_mode_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE))
+        .await;
+    {
+        let state = app.state.lock().await;
+        let api_mgmt = state.api_level_management.as_ref().unwrap();
+        let confirmation = api_mgmt.uninstall_confirmation.as_ref().unwrap();
+        assert_eq!(
+            confirmation.dependent_avds,
+            vec!["Pixel_7_API_34".to_string()]
+        );
+        assert_eq!(
+            confirmation
+                .retarget
+                .as_ref()
+                .map(|(package_id, _)| package_id.as_str()),
+            Some("system-images;android-35;google_apis;x86_64")
+        );
+        // Nothing is uninstalled until the user decides
+        assert!(!api_mgmt.is_busy());
+    }
+
+    app.handle_api_level_mode_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .await;
+
+    let state = app.state.lock().await;
+    let api_mgmt = state.api_level_management.as_ref().unwrap();
+    assert!(api_mgmt.uninstall_confirmation.is_none());
+    assert!(!api_mgmt.is_busy());
+    assert_eq!(state.mode, Mode::ManageApiLevels);
+}
+
+#[test]
+async fn test_ctrl_a_queues_creation_and_rejects_duplicates() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    // Occupy the only worker slot so the queued creation stays pending
+    let _busy_slot = {
+        let mut state = app.state.lock().await;
+        state.set_max_parallel_creates(1);
+        state.mode = Mode::CreateDevice;
+        state.create_device_form.name = "Queued_Pixel".to_string();
+        state.create_device_form.device_type_id = "pixel_7".to_string();
+        state.create_device_form.version = "34".to_string();
+        state.creation_queue.slots().try_acquire_owned().unwrap()
+    };
+    let ctrl_a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+
+    app.handle_create_mode_key(ctrl_a).await.unwrap();
+    app.handle_create_mode_key(ctrl_a).await.unwrap();
+
+    let state = app.state.lock().await;
+    assert_eq!(state.creation_queue.items.len(), 1);
+    assert_eq!(state.creation_queue.pending_count(), 1);
+    assert_eq!(state.creation_queue.items[0].config.name, "Queued_Pixel");
+    assert!(state
+        .create_device_form
+        .error_message
+        .as_deref()
+        .is_some_and(|message| message.contains("already in the creation queue")));
+    // Queuing leaves the form open for the next device
+    assert_eq!(state.mode, Mode::CreateDevice);
+    assert!(!state.create_device_form.is_creating);
+}
+
+#[test]
+async fn test_repair_dialog_offers_delete_for_unavailable_simulator() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Ios;
+        state.ios_devices = ["AAAA", "BBBB"]
+            .into_iter()
+            .map(|udid| crate::models::IosDevice {
+                name: format!("iPhone {udid}"),
+                udid: udid.to_string(),
+                device_type: "iPhone".to_string(),
+                ios_version: "15.0".to_string(),
+                runtime_version: "15.0".to_string(),
+                status: DeviceStatus::Stopped,
+                is_running: false,
+                is_available: udid == "AAAA",
+                availability_error: (udid == "BBBB")
+                    .then(|| "device type profile not found".to_string()),
+            })
+            .collect();
+    }
+
+    // Available simulators have nothing to repair
+    app.open_repair_dialog().await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::Normal);
+        assert!(state
+            .notifications
+            .iter()
+            .any(|notification| notification.message.contains("needs no repair")));
+    }
+
+    app.state.lock().await.selected_ios = 1;
+    app.open_repair_dialog().await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::RepairDevice);
+        let dialog = state.repair_device_dialog.as_ref().unwrap();
+        assert_eq!(
+            dialog.reason,
+            crate::models::SimulatorUnavailableReason::Unsupported
+        );
+        assert!(!dialog.can_install_runtime());
+    }
+
+    // Installing a runtime does not help an unsupported device type
+    app.handle_repair_device_key(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE))
+        .await;
+    assert_eq!(app.state.lock().await.mode, Mode::RepairDevice);
+
+    app.handle_repair_device_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE))
+        .await;
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::ConfirmDelete);
+    assert!(state.repair_device_dialog.is_none());
+    let confirm = state.confirm_delete_dialog.as_ref().unwrap();
+    assert_eq!(confirm.device_identifier, "BBBB");
+    assert_eq!(confirm.platform, Panel::Ios);
+}
+
+#[test]
+async fn test_prune_unavailable_previews_only_unavailable_simulators() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+
+    // Nothing to prune while every simulator is available
+    app.open_prune_unavailable().await;
+    assert_eq!(app.state.lock().await.mode, Mode::Normal);
+
+    app.state.lock().await.ios_devices = ["AAAA", "BBBB", "CCCC"]
+        .into_iter()
+        .map(|udid| crate::models::IosDevice {
+            name: format!("iPhone {udid}"),
+            udid: udid.to_string(),
+            device_type: "iPhone".to_string(),
+            ios_version: "15.0".to_string(),
+            runtime_version: "15.0".to_string(),
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            is_available: udid == "BBBB",
+            availability_error: None,
+        })
+        .collect();
+
+    app.open_prune_unavailable().await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::PruneUnavailable);
+        let udids: Vec<&str> = state
+            .prune_unavailable_dialog
+            .as_ref()
+            .unwrap()
+            .devices
+            .iter()
+            .map(|device| device.udid.as_str())
+            .collect();
+        assert_eq!(udids, vec!["AAAA", "CCCC"]);
+    }
+
+    app.handle_prune_unavailable_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::Normal);
+        assert!(state.prune_unavailable_dialog.is_none());
+        assert_eq!(state.ios_devices.len(), 3);
+    }
+
+    // With `batch = "each"` every simulator is asked about on its own
+    app.state.lock().await.set_project_config(
+        crate::models::ProjectConfig::parse("[confirm]\nbatch = \"each\"").unwrap(),
+    );
+    app.open_prune_unavailable().await;
+    for _ in 0..2 {
+        app.handle_prune_unavailable_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE))
+            .await;
+    }
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::Normal);
+    assert!(state.prune_unavailable_dialog.is_none());
+    assert_eq!(state.ios_devices.len(), 3);
+    assert_eq!(
+        state
+            .notifications
+            .back()
+            .map(|notification| notification.message.as_str()),
+        Some("Kept all unavailable iOS simulators")
+    );
+}
+
+#[test]
+async fn test_focus_window_requires_running_device() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    app.state.lock().await.android_devices = vec![crate::models::AndroidDevice {
+        name: "Pixel_7_API_34".to_string(),
+        ..Default::default()
+    }];
+
+    app.focus_selected_device_window().await;
+
+    let state = app.state.lock().await;
+    assert!(state
+        .notifications
+        .iter()
+        .any(|notification| notification.message
+            == "'Pixel_7_API_34' is not running, start it first"));
+}
+
+#[test]
+async fn test_headless_boot_skips_running_device() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.active_panel = Panel::Ios;
+        state.ios_devices = vec![crate::models::IosDevice {
+            name: "iPhone 15".to_string(),
+            udid: "UDID-1".to_string(),
+            device_type: "iPhone 15".to_string(),
+            ios_version: "17.0".to_string(),
+            runtime_version: "17.0".to_string(),
+            status: crate::models::DeviceStatus::Running,
+            is_running: true,
+            is_available: true,
+            availability_error: None,
+        }];
+    }
+
+    app.start_selected_ios_device_headless().await;
+
+    let state = app.state.lock().await;
+    assert!(!state.is_ios_device_headless("UDID-1"));
+    assert!(state
+        .notifications
+        .iter()
+        .any(|notification| notification.message
+            == "'iPhone 15' is already running, [o] opens its window"));
+}
+
+#[test]
+async fn test_install_selected_api_level_marks_installed_when_refresh_fails() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let android_home =
+        std::env::var("ANDROID_HOME").expect("ANDROID_HOME should be set by StartupTestEnv");
+    let sdkmanager_path =
+        std::path::PathBuf::from(&android_home).join("cmdline-tools/latest/bin/sdkmanager");
+    let refresh_fail_marker = std::path::PathBuf::from(&android_home).join("refresh-fail");
+
+    std::fs::write(
+        &sdkmanager_path,
+        format!(
+            r#"#!/bin/sh
+MARKER="{marker}"
+if [ "$1" = "--list" ]; then
+    if [ -f "$MARKER" ]; then
+        echo "simulated refresh failure" >&2
+        exit 1
+    fi
+
+    cat <<'EOF'
+Installed packages:
+  Path | Version | Description | Location
+
+Available Packages:
+  system-images;android-34;google_apis_playstore;arm64-v8a | 1 | Android SDK Platform 34 | system-images/android-34/google_apis_playstore/arm64-v8a
 EOF
     exit 0
 fi
@@ -1231,14 +2085,11 @@ exit 0
         std::fs::set_permissions(&sdkmanager_path, perms).unwrap();
     }
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::new().expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
 
     app.open_api_level_management().await;
 
@@ -1300,29 +2151,258 @@ exit 0
                 .unwrap_or(false)
         };
 
-        if is_installed {
-            break;
-        }
+        if is_installed {
+            break;
+        }
+
+        sleep(Duration::from_millis(25)).await;
+    }
+
+    let state = app.state.lock().await;
+    let api_state = state
+        .api_level_management
+        .as_ref()
+        .expect("API level management should stay open");
+    let selected_api = api_state
+        .get_selected_api_level()
+        .expect("selected API level should exist");
+    let selected_variant = selected_api
+        .get_recommended_variant()
+        .expect("recommended variant should exist");
+
+    assert!(selected_api.is_installed);
+    assert!(selected_variant.is_installed);
+    assert!(!api_state.is_loading);
+    assert!(api_state.error_message.is_none());
+}
+
+#[test]
+async fn test_marked_system_images_install_one_after_another() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let android_home =
+        std::env::var("ANDROID_HOME").expect("ANDROID_HOME should be set by StartupTestEnv");
+    let sdkmanager_path =
+        std::path::PathBuf::from(&android_home).join("cmdline-tools/latest/bin/sdkmanager");
+    let install_log = std::path::PathBuf::from(&android_home).join("installs.log");
+
+    std::fs::write(
+        &sdkmanager_path,
+        format!(
+            r#"#!/bin/sh
+if [ "$1" = "--list" ]; then
+    cat <<'EOF'
+Installed packages:
+  Path | Version | Description | Location
+
+Available Packages:
+  system-images;android-34;google_apis_playstore;arm64-v8a | 1 | Android SDK Platform 34 | system-images/android-34/google_apis_playstore/arm64-v8a
+  system-images;android-33;google_apis_playstore;arm64-v8a | 1 | Android SDK Platform 33 | system-images/android-33/google_apis_playstore/arm64-v8a
+EOF
+    exit 0
+fi
+
+echo "$1" >> "{log}"
+exit 0
+"#,
+            log = install_log.display()
+        ),
+    )
+    .unwrap();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&sdkmanager_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&sdkmanager_path, perms).unwrap();
+    }
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+
+    app.open_api_level_management().await;
+    for _ in 0..40 {
+        let is_ready = {
+            let state = app.state.lock().await;
+            state
+                .api_level_management
+                .as_ref()
+                .is_some_and(|api_mgmt| !api_mgmt.is_loading && api_mgmt.api_levels.len() == 2)
+        };
+        if is_ready {
+            break;
+        }
+        sleep(Duration::from_millis(25)).await;
+    }
+
+    // Marks API 33, then API 34
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    app.handle_api_level_mode_key(press(KeyCode::Down)).await;
+    app.handle_api_level_mode_key(press(KeyCode::Char(' ')))
+        .await;
+    app.handle_api_level_mode_key(press(KeyCode::Up)).await;
+    app.handle_api_level_mode_key(press(KeyCode::Char(' ')))
+        .await;
+    app.handle_api_level_mode_key(press(KeyCode::Enter)).await;
+
+    {
+        let state = app.state.lock().await;
+        let api_state = state.api_level_management.as_ref().unwrap();
+        assert!(api_state.marked_packages.is_empty());
+        assert_eq!(api_state.install_queue.as_ref().unwrap().items.len(), 2);
+    }
+
+    for _ in 0..160 {
+        let is_complete = {
+            let state = app.state.lock().await;
+            state
+                .api_level_management
+                .as_ref()
+                .is_some_and(|api_mgmt| !api_mgmt.is_busy() && api_mgmt.install_queue.is_none())
+        };
+        if is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(25)).await;
+    }
+
+    let installs = std::fs::read_to_string(&install_log).unwrap();
+    assert_eq!(
+        installs.lines().collect::<Vec<_>>(),
+        [
+            "system-images;android-33;google_apis_playstore;arm64-v8a",
+            "system-images;android-34;google_apis_playstore;arm64-v8a"
+        ]
+    );
+    let state = app.state.lock().await;
+    assert!(state
+        .api_level_management
+        .as_ref()
+        .unwrap()
+        .error_message
+        .is_none());
+    assert!(state
+        .notifications
+        .iter()
+        .any(|notification| notification.message == "2 system images installed successfully"));
+}
+
+#[test]
+async fn test_offline_api_dialog_lists_local_images_and_refuses_downloads() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let _offline = EnvVarGuard::set("EMU_OFFLINE", "1");
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+    app.open_api_level_management().await;
+    wait_for_app_state(&app, |state| {
+        state
+            .api_level_management
+            .as_ref()
+            .is_some_and(|api_state| api_state.is_offline && !api_state.is_loading)
+    })
+    .await;
+    assert!(app.android_manager.is_offline());
+
+    // API 35 is only available from the repository
+    app.handle_api_level_mode_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await;
 
-        sleep(Duration::from_millis(25)).await;
+    let state = app.state.lock().await;
+    let api_state = state.api_level_management.as_ref().unwrap();
+    assert_eq!(
+        api_state.get_selected_api_level().map(|level| level.api),
+        Some(35)
+    );
+    assert!(api_state.install_queue.is_none());
+    assert!(!api_state.is_busy());
+    assert_eq!(
+        state
+            .notifications
+            .back()
+            .map(|notification| notification.message.as_str()),
+        Some(crate::constants::messages::errors::OFFLINE_INSTALL)
+    );
+}
+
+#[test]
+async fn test_device_catalog_refreshes_and_creates_recommended_configs() {
+    use crate::models::DeviceCatalog;
+
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+    let _offline = EnvVarGuard::set("EMU_OFFLINE", "1");
+    let android_home =
+        std::env::var("ANDROID_HOME").expect("ANDROID_HOME should be set by StartupTestEnv");
+    let curl_path = std::path::PathBuf::from(&android_home).join("platform-tools/curl");
+    std::fs::write(
+        &curl_path,
+        r#"#!/bin/sh
+cat <<'EOF'
+{"devices": [{"name": "Pixel 7 API 35", "device_type": "pixel_7", "api_level": 35,
+  "tag": "google_apis", "abi": "arm64-v8a", "description": "Newest phone"}]}
+EOF
+"#,
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&curl_path, std::fs::Permissions::from_mode(0o755)).unwrap();
     }
 
-    let state = app.state.lock().await;
-    let api_state = state
-        .api_level_management
-        .as_ref()
-        .expect("API level management should stay open");
-    let selected_api = api_state
-        .get_selected_api_level()
-        .expect("selected API level should exist");
-    let selected_variant = selected_api
-        .get_recommended_variant()
-        .expect("recommended variant should exist");
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
+    app.open_device_catalog().await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::DeviceCatalog);
+        let dialog = state.device_catalog_dialog.as_ref().unwrap();
+        assert_eq!(dialog.catalog, DeviceCatalog::bundled());
+    }
 
-    assert!(selected_api.is_installed);
-    assert!(selected_variant.is_installed);
-    assert!(!api_state.is_loading);
-    assert!(api_state.error_message.is_none());
+    app.handle_device_catalog_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE))
+        .await;
+    wait_for_app_state(&app, |state| {
+        state
+            .device_catalog_dialog
+            .as_ref()
+            .is_some_and(|dialog| !dialog.is_busy() && dialog.catalog.devices.len() == 1)
+    })
+    .await;
+    // The next session starts from the downloaded catalog
+    assert_eq!(DeviceCatalog::load().devices[0].name, "Pixel 7 API 35");
+
+    // API 35 is not installed, and offline it cannot be downloaded
+    app.handle_device_catalog_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await;
+    wait_for_app_state(&app, |state| {
+        state
+            .device_catalog_dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.error.is_some())
+    })
+    .await;
+    let state = app.state.lock().await;
+    let dialog = state.device_catalog_dialog.as_ref().unwrap();
+    assert!(!dialog.is_busy());
+    assert!(dialog
+        .error
+        .as_deref()
+        .unwrap()
+        .contains(crate::constants::messages::errors::OFFLINE_INSTALL));
+    assert!(app.android_manager.is_offline());
 }
 
 #[test]
@@ -1377,14 +2457,11 @@ exit 0
         std::fs::set_permissions(&sdkmanager_path, perms).unwrap();
     }
 
-    let mut app = App {
-        state: Arc::new(Mutex::new(AppState::new())),
-        android_manager: AndroidManager::new().expect("Android manager should initialize"),
-        ios_manager: None,
-        log_update_handle: None,
-        detail_update_handle: None,
-        last_full_device_refresh: std::time::Instant::now(),
-    };
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::new().expect("Android manager should initialize"),
+        None,
+    );
 
     app.open_api_level_management().await;
 
@@ -1564,6 +2641,75 @@ async fn test_enter_create_device_mode_prefers_manager_cache_over_stale_state_ca
     assert_eq!(state.create_device_form.available_versions[0].0, "34");
 }
 
+#[test]
+async fn test_create_form_switches_android_sdk() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::new()
+        .await
+        .expect("App should initialize with startup test environment");
+    let canary_executor = crate::utils::command_executor::mock::MockCommandExecutor::new()
+        .with_success(
+            "sdkmanager",
+            &["--list", "--verbose", "--include_obsolete"],
+            "Installed packages:\n  system-images;android-36;google_apis;arm64-v8a | 1 | Google APIs ARM 64 v8a System Image\n",
+        )
+        .with_success(
+            "avdmanager",
+            &["list", "device"],
+            "id: 0 or \"pixel_9\"\n    Name: Pixel 9\n    OEM : Google\n---------\n",
+        );
+    let canary = AndroidManager::with_sdk_root(
+        Arc::new(canary_executor),
+        app.android_manager.android_home().to_path_buf(),
+    )
+    .expect("canary SDK should initialize");
+    app.android_sdks = vec![("canary".to_string(), canary)];
+
+    app.enter_create_device_mode().await;
+    for _ in 0..120 {
+        if !app.state.lock().await.create_device_form.is_loading_cache {
+            break;
+        }
+        sleep(Duration::from_millis(25)).await;
+    }
+    {
+        let state = app.state.lock().await;
+        let form = &state.create_device_form;
+        assert_eq!(form.android_sdks, vec!["default", "canary"]);
+        assert_eq!(form.selected_sdk(), Some("default"));
+        assert_eq!(form.version, "34");
+    }
+
+    // The SDK field sits above the API level
+    app.navigate_create_form(false).await;
+    assert_eq!(
+        app.state.lock().await.create_device_form.active_field,
+        state::CreateDeviceField::Sdk
+    );
+    app.change_create_device_selection(true).await.unwrap();
+
+    let state = app.state.lock().await;
+    let form = &state.create_device_form;
+    assert_eq!(form.selected_sdk(), Some("canary"));
+    assert!(!form.is_loading_cache);
+    assert_eq!(form.version, "36");
+    assert_eq!(form.device_type_id, "pixel_9");
+    drop(state);
+
+    // Changing the category keeps listing the picked SDK's device types
+    app.reload_device_types_for_category().await.unwrap();
+    let state = app.state.lock().await;
+    let ids: Vec<&str> = state
+        .create_device_form
+        .available_device_types
+        .iter()
+        .map(|(id, _)| id.as_str())
+        .collect();
+    assert_eq!(ids, ["pixel_9"]);
+}
+
 #[test]
 async fn test_enter_create_device_mode_uses_manager_cache_empty_state_messages() {
     let _env_lock = acquire_test_env_lock().await;
@@ -1925,3 +3071,341 @@ async fn test_mode_transitions() {
 
     std::env::remove_var("ANDROID_HOME");
 }
+
+#[test]
+async fn test_device_inventory_round_trips_through_disk() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let app = App::new()
+        .await
+        .expect("app should initialize with test SDK");
+    wait_for_app_state(&app, |state| {
+        !state.is_loading && state.android_devices.len() == 1
+    })
+    .await;
+    app.save_device_inventory().await;
+
+    let inventory =
+        crate::utils::DeviceInventoryCache::load_from_disk().expect("inventory should be saved");
+    assert!(inventory.is_fresh());
+    assert_eq!(inventory.android_devices[0].name, "Pixel_7_API_34");
+
+    let mut state = AppState::new();
+    state.restore_device_inventory(inventory).await;
+    assert!(!state.is_loading);
+    assert_eq!(state.android_devices[0].name, "Pixel_7_API_34");
+
+    crate::utils::DeviceInventoryCache::clear_from_disk().unwrap();
+    assert!(crate::utils::DeviceInventoryCache::load_from_disk().is_none());
+}
+
+#[test]
+async fn test_search_and_annotation_keys() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+    {
+        let mut state = app.state.lock().await;
+        state.android_devices = vec![
+            AndroidDevice {
+                name: "Pixel_7".to_string(),
+                ..Default::default()
+            },
+            AndroidDevice {
+                name: "Pixel_Tablet".to_string(),
+                ..Default::default()
+            },
+        ];
+        state.selected_android = 1;
+    }
+
+    // Tag the tablet through the editor
+    app.process_key_event(key(KeyCode::Char('t')))
+        .await
+        .unwrap();
+    for c in "acme".chars() {
+        app.process_key_event(key(KeyCode::Char(c))).await.unwrap();
+    }
+    app.process_key_event(key(KeyCode::Enter)).await.unwrap();
+    {
+        let mut state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::Normal);
+        assert_eq!(
+            state
+                .device_annotation(Panel::Android, "Pixel_Tablet")
+                .map(|annotation| annotation.tags.clone()),
+            Some(vec!["acme".to_string()])
+        );
+        state.selected_android = 0;
+    }
+
+    // A plain `q` is typed into the query instead of quitting
+    app.process_key_event(key(KeyCode::Char('/')))
+        .await
+        .unwrap();
+    assert!(!app
+        .process_key_event(key(KeyCode::Char('q')))
+        .await
+        .unwrap());
+    app.process_key_event(key(KeyCode::Backspace))
+        .await
+        .unwrap();
+    for c in "ACME".chars() {
+        app.process_key_event(key(KeyCode::Char(c))).await.unwrap();
+    }
+    app.process_key_event(key(KeyCode::Enter)).await.unwrap();
+
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::Normal);
+    assert_eq!(state.search_query, "ACME");
+    assert_eq!(state.selected_android, 1);
+}
+
+#[test]
+async fn test_project_hook_failure_is_reported() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    let mut config = crate::models::ProjectConfig::parse(
+        "[hooks]\nafter_start = \"test \\\"$EMU_DEVICE_NAME\\\" = Pixel_7 && echo broken >&2 && exit 3\"",
+    )
+    .unwrap();
+    config.root = std::env::temp_dir();
+    app.set_project_config(config).await;
+
+    app.run_project_hook(
+        crate::models::DeviceOperation::Boot,
+        &crate::models::DeviceId::android("Pixel_7"),
+        "Pixel_7",
+    )
+    .await;
+    // Stop has no hook configured, so nothing runs
+    app.run_project_hook(
+        crate::models::DeviceOperation::Stop,
+        &crate::models::DeviceId::android("Pixel_7"),
+        "Pixel_7",
+    )
+    .await;
+
+    wait_for_app_state(&app, |state| !state.notifications.is_empty()).await;
+    let state = app.state.lock().await;
+    assert_eq!(state.notifications.len(), 1);
+    assert_eq!(
+        state.notifications[0].message,
+        "Project hook after_start failed: broken"
+    );
+}
+
+#[test]
+async fn test_test_matrix_groups_devices_for_batch_boot() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let android_manager = AndroidManager::new().expect("Android manager should initialize");
+    let mut state = AppState::new();
+    state.android_devices = android_manager.list_devices().await.unwrap();
+    let mut app = App::from_managers(Arc::new(Mutex::new(state)), android_manager, None);
+
+    // The only device has no group yet
+    app.process_key_event(KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT))
+        .await
+        .unwrap();
+    assert!(app
+        .state
+        .lock()
+        .await
+        .notifications
+        .iter()
+        .any(|notification| notification.message.contains("no test matrix group")));
+
+    app.open_test_matrix().await;
+    wait_for_app_state(&app, |state| {
+        state
+            .test_matrix_dialog
+            .as_ref()
+            .is_some_and(|dialog| !dialog.is_loading)
+    })
+    .await;
+    for code in [
+        KeyCode::Enter,
+        KeyCode::Char(' '),
+        KeyCode::Tab,
+        KeyCode::Char(' '),
+        KeyCode::Tab,
+        KeyCode::Backspace,
+        KeyCode::Backspace,
+        KeyCode::Char('r'),
+        KeyCode::Char('c'),
+    ] {
+        app.handle_test_matrix_key(KeyEvent::new(code, KeyModifiers::NONE))
+            .await;
+        let state = app.state.lock().await;
+        if let Some(dialog) = state.test_matrix_dialog.as_ref() {
+            // Enter before marking anything creates nothing
+            assert_eq!(
+                dialog.error.as_deref(),
+                Some(crate::constants::ui_text::test_matrix::NOTHING_MARKED)
+            );
+        }
+    }
+    {
+        let state = app.state.lock().await;
+        let dialog = state.test_matrix_dialog.as_ref().unwrap();
+        assert_eq!(dialog.matrix().len(), 1);
+        assert_eq!(dialog.group, "rc");
+    }
+
+    app.handle_test_matrix_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await;
+    {
+        let state = app.state.lock().await;
+        assert_eq!(state.mode, Mode::CreationQueue);
+        // "Pixel 7 API 34" already exists, so it only joins the group
+        assert!(state.creation_queue.items.is_empty());
+        let annotation = state
+            .device_annotation(Panel::Android, "Pixel_7_API_34")
+            .unwrap();
+        assert_eq!(crate::models::matrix::group_of(annotation), Some("rc"));
+    }
+
+    app.state.lock().await.mode = Mode::Normal;
+    // The group boots in the background
+    app.start_selected_matrix_group().await;
+    wait_for_app_state(&app, |state| {
+        state
+            .notifications
+            .iter()
+            .any(|notification| notification.message == "Started 1 devices of group 'rc'")
+    })
+    .await;
+    let state = app.state.lock().await;
+    assert!(state.android_devices[0].is_running);
+}
+
+#[test]
+async fn test_snippet_runs_against_selected_device_into_log() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    let mut app = App::from_managers(
+        Arc::new(Mutex::new(AppState::new())),
+        AndroidManager::with_executor(Arc::new(
+            crate::utils::command_executor::mock::MockCommandExecutor::new(),
+        ))
+        .expect("Android manager should initialize"),
+        None,
+    );
+    {
+        let mut state = app.state.lock().await;
+        state.android_devices = vec![crate::models::AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            ..Default::default()
+        }];
+        state.set_project_config(
+            crate::models::ProjectConfig::parse(
+                "[snippets.android]\n\"Greet\" = \"echo hi {avd}; echo oops >&2\"",
+            )
+            .unwrap(),
+        );
+    }
+
+    app.open_snippets().await;
+    {
+        let state = app.state.lock().await;
+        let dialog = state.snippets_dialog.as_ref().unwrap();
+        assert_eq!(state.mode, Mode::Snippets);
+        assert_eq!(dialog.selected_snippet().unwrap().name, "Greet");
+        assert!(dialog.snippets.len() > 1);
+    }
+    app.handle_snippets_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .await;
+    wait_for_app_state(&app, |state| {
+        state.notifications.iter().any(|notification| {
+            notification.message == "Snippet 'Greet' finished on 'Pixel_7_API_34'"
+        })
+    })
+    .await;
+
+    let state = app.state.lock().await;
+    assert_eq!(state.mode, Mode::Normal);
+    let logs: Vec<(&str, &str)> = state
+        .device_logs
+        .iter()
+        .map(|entry| (entry.level.as_str(), entry.message.as_str()))
+        .collect();
+    assert!(logs.contains(&("INFO", "[snippet] hi Pixel_7_API_34")));
+    assert!(logs.contains(&("WARN", "[snippet] oops")));
+}
+
+#[test]
+async fn test_copy_app_records_install_on_target_device() {
+    let _env_lock = acquire_test_env_lock().await;
+    let _env = StartupTestEnv::new();
+
+    // No adb answers are mocked, so the copy fails
+    let android_manager = AndroidManager::with_executor(Arc::new(
+        crate::utils::command_executor::mock::MockCommandExecutor::new(),
+    ))
+    .expect("Android manager should initialize");
+    let mut app = App::from_managers(Arc::new(Mutex::new(AppState::new())), android_manager, None);
+    let source = DeviceId::android("Source_AVD".to_string());
+    let target = DeviceId::android("Target_AVD".to_string());
+    {
+        let mut state = app.state.lock().await;
+        let mut dialog = crate::app::state::AppDataDialog::new(
+            source.clone(),
+            "Source_AVD".to_string(),
+            vec![(target.clone(), "Target_AVD".to_string())],
+            std::env::temp_dir(),
+        );
+        dialog.apps = vec!["com.example.app".to_string()];
+        state.app_data_dialog = Some(dialog);
+        state.mode = Mode::AppData;
+    }
+
+    app.handle_app_data_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE))
+        .await;
+
+    for _ in 0..100 {
+        if !app
+            .state
+            .lock()
+            .await
+            .device_history
+            .entries_for(&target)
+            .is_empty()
+        {
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    let state = app.state.lock().await;
+    let entries = state.device_history.entries_for(&target);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0].operation,
+        crate::models::DeviceOperation::Install
+    );
+    assert!(!entries[0].success);
+    assert!(entries[0].error.is_some());
+    assert!(state.device_history.entries_for(&source).is_empty());
+}
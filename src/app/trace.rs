@@ -0,0 +1,179 @@
+use super::{state::TraceDialog, App, Mode, Panel, TraceField};
+use crate::constants::{
+    defaults::TRACE_DURATION_SECS,
+    messages::notifications::{CAPTURING_TRACE, FOCUS_NOT_RUNNING, TRACE_FAILED, TRACE_SAVED},
+    ui_text::device_activity::RECORDING_TRACE,
+};
+use crate::models::{error::format_user_error, DeviceId, Platform, TraceCapture};
+use crate::utils::tasks;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl App {
+    /// Opens the trace capture dialog for the selected running device.
+    pub(super) async fn open_trace_dialog(&mut self) {
+        {
+            let mut state = self.state.lock().await;
+            let target = match state.active_panel {
+                Panel::Android => state
+                    .selected_android_device()
+                    .map(|device| (&device.name, &device.name, device.is_running)),
+                Panel::Ios => state
+                    .selected_ios_device()
+                    .map(|device| (&device.name, &device.udid, device.is_running)),
+            };
+            let Some((name, identifier, is_running)) = target else {
+                return;
+            };
+            let (name, identifier) = (name.clone(), identifier.clone());
+            if !is_running {
+                state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &name));
+                return;
+            }
+            let device = state.active_panel.device_id(identifier);
+            state.trace_dialog = Some(TraceDialog::new(device, name, TRACE_DURATION_SECS));
+            state.mode = Mode::CaptureTrace;
+        }
+
+        self.load_trace_packages();
+    }
+
+    pub(super) async fn handle_trace_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.trace_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                state.trace_dialog = None;
+                state.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let capture = match TraceCapture::parse(&dialog.target, &dialog.duration_secs) {
+                    Ok(capture) => capture,
+                    Err(error) => {
+                        state.add_error_notification(format_user_error(&error));
+                        return;
+                    }
+                };
+                let device = dialog.device.clone();
+                let device_name = dialog.device_name.clone();
+                state.trace_dialog = None;
+                state.mode = Mode::Normal;
+                drop(state);
+                self.capture_trace(device, device_name, capture).await;
+            }
+            KeyCode::Tab | KeyCode::BackTab => dialog.active_field = dialog.active_field.toggle(),
+            KeyCode::Up if dialog.active_field == TraceField::Target => dialog.cycle_target(false),
+            KeyCode::Down if dialog.active_field == TraceField::Target => dialog.cycle_target(true),
+            KeyCode::Backspace => {
+                dialog.active_input().pop();
+            }
+            KeyCode::Char(c) => dialog.active_input().push(c),
+            _ => {}
+        }
+    }
+
+    /// Lists the packages the dialog offers as targets in the background.
+    /// Simulator processes are typed, since xctrace attaches by process name.
+    fn load_trace_packages(&self) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let device = {
+                let state = state.lock().await;
+                match &state.trace_dialog {
+                    Some(dialog) if dialog.device.platform == Platform::Android => {
+                        dialog.device.clone()
+                    }
+                    _ => return,
+                }
+            };
+            let packages = match android_manager
+                .list_installed_packages(&device.identifier)
+                .await
+            {
+                Ok(packages) => packages,
+                Err(e) => {
+                    log::debug!("Failed to list packages of '{}': {e:#}", device.identifier);
+                    return;
+                }
+            };
+            let mut state = state.lock().await;
+            if let Some(dialog) = state
+                .trace_dialog
+                .as_mut()
+                .filter(|dialog| dialog.device == device)
+            {
+                dialog.packages = packages;
+            }
+        });
+    }
+
+    /// Records the trace in the background and reports where it was saved.
+    /// Files go to `[output] trace_dir` of `.emu.toml`, or the working
+    /// directory.
+    async fn capture_trace(&self, device: DeviceId, device_name: String, capture: TraceCapture) {
+        let (dir, activity) = {
+            let mut state = self.state.lock().await;
+            let activity = state
+                .device_activities
+                .begin(device.clone(), RECORDING_TRACE);
+            state.set_device_operation_status(
+                CAPTURING_TRACE
+                    .replacen("{}", &capture.duration_secs.to_string(), 1)
+                    .replacen("{}", &device_name, 1),
+            );
+            let dir = state
+                .project_config
+                .as_ref()
+                .and_then(|config| config.output.trace_dir.clone())
+                .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+            (dir, activity)
+        };
+
+        let android_manager = self.android_manager.clone();
+        let ios_manager = self.ios_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let result: Result<PathBuf> = match device.platform {
+                Platform::Android => {
+                    android_manager
+                        .capture_perfetto_trace(&device.identifier, &capture, &dir)
+                        .await
+                }
+                Platform::Ios => match ios_manager {
+                    Some(ios_manager) => {
+                        ios_manager
+                            .record_time_profile(&device.identifier, &capture, &dir)
+                            .await
+                    }
+                    None => Err(anyhow::anyhow!("iOS manager not available")),
+                },
+            };
+            let mut state = state.lock().await;
+            state.clear_device_operation_status();
+            state.device_activities.end(activity);
+            match result {
+                Ok(path) => state.add_success_notification(
+                    TRACE_SAVED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &path.to_string_lossy(),
+                        1,
+                    ),
+                ),
+                Err(error) => state.add_error_notification(
+                    TRACE_FAILED.replacen("{}", &device_name, 1).replacen(
+                        "{}",
+                        &format_user_error(&error),
+                        1,
+                    ),
+                ),
+            }
+        });
+    }
+}
@@ -0,0 +1,131 @@
+use super::{state::TypeIntoDeviceDialog, App, Mode};
+use crate::constants::messages::notifications::{
+    FOCUS_NOT_RUNNING, TYPE_INTO_DEVICE_FAILED, TYPE_INTO_IOS_UNSUPPORTED,
+};
+use crate::models::{error::format_user_error, DeviceInput, DeviceKey};
+use crate::utils::tasks;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+impl App {
+    /// Starts forwarding keystrokes to the selected running AVD.
+    pub(super) async fn open_type_into_device(&mut self) {
+        let mut state = self.state.lock().await;
+        if !state.active_capabilities().type_into_device {
+            if let Some(device) = state.selected_ios_device() {
+                let message = TYPE_INTO_IOS_UNSUPPORTED.replace("{}", &device.name);
+                state.add_info_notification(message);
+            }
+            return;
+        }
+        let Some(device) = state.selected_android_device() else {
+            return;
+        };
+        let (device_name, is_running) = (device.name.clone(), device.is_running);
+        if !is_running {
+            state.add_info_notification(FOCUS_NOT_RUNNING.replace("{}", &device_name));
+            return;
+        }
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.forward_device_input(device_name.clone(), receiver);
+        state.type_into_device_dialog = Some(TypeIntoDeviceDialog::new(device_name, sender));
+        state.mode = Mode::TypeIntoDevice;
+    }
+
+    pub(super) async fn handle_type_into_device_key(&mut self, key: KeyEvent) {
+        let mut state = self.state.lock().await;
+        let Some(dialog) = state.type_into_device_dialog.as_mut() else {
+            state.mode = Mode::Normal;
+            return;
+        };
+
+        if key.code == KeyCode::Esc {
+            if dialog.escape_armed {
+                // Dropping the dialog closes the queue, which ends the task
+                state.type_into_device_dialog = None;
+                state.mode = Mode::Normal;
+            } else {
+                dialog.escape_armed = true;
+            }
+            return;
+        }
+
+        let input = match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                DeviceInput::Text(c.to_string())
+            }
+            KeyCode::Enter => DeviceInput::Key(DeviceKey::Enter),
+            KeyCode::Backspace => DeviceInput::Key(DeviceKey::Backspace),
+            KeyCode::Delete => DeviceInput::Key(DeviceKey::Delete),
+            KeyCode::Tab => DeviceInput::Key(DeviceKey::Tab),
+            KeyCode::Up => DeviceInput::Key(DeviceKey::Up),
+            KeyCode::Down => DeviceInput::Key(DeviceKey::Down),
+            KeyCode::Left => DeviceInput::Key(DeviceKey::Left),
+            KeyCode::Right => DeviceInput::Key(DeviceKey::Right),
+            KeyCode::Home => DeviceInput::Key(DeviceKey::Home),
+            KeyCode::End => DeviceInput::Key(DeviceKey::End),
+            _ => return,
+        };
+        if dialog.escape_armed {
+            dialog.escape_armed = false;
+            dialog.send(DeviceInput::Key(DeviceKey::Escape));
+        }
+        dialog.send(input);
+    }
+
+    /// Sends queued keystrokes one command at a time, joining text typed
+    /// while the previous command ran. Stops when the dialog closes or a
+    /// command fails.
+    fn forward_device_input(
+        &self,
+        device_name: String,
+        mut receiver: mpsc::UnboundedReceiver<DeviceInput>,
+    ) {
+        let android_manager = self.android_manager.clone();
+        let state = Arc::clone(&self.state);
+        tasks::spawn(async move {
+            let mut pending = None;
+            loop {
+                let mut input = match pending.take() {
+                    Some(input) => input,
+                    None => match receiver.recv().await {
+                        Some(input) => input,
+                        None => break,
+                    },
+                };
+                while let Ok(next) = receiver.try_recv() {
+                    if let Some(next) = input.merge(next) {
+                        pending = Some(next);
+                        break;
+                    }
+                }
+
+                let result = match &input {
+                    DeviceInput::Text(text) => android_manager.send_text(&device_name, text).await,
+                    DeviceInput::Key(key) => android_manager.send_key(&device_name, *key).await,
+                };
+                if let Err(error) = result {
+                    let mut state = state.lock().await;
+                    let same_device = state
+                        .type_into_device_dialog
+                        .as_ref()
+                        .is_some_and(|dialog| dialog.device_name == device_name);
+                    if same_device {
+                        state.type_into_device_dialog = None;
+                        if state.mode == Mode::TypeIntoDevice {
+                            state.mode = Mode::Normal;
+                        }
+                    }
+                    state.add_error_notification(
+                        TYPE_INTO_DEVICE_FAILED
+                            .replacen("{}", &device_name, 1)
+                            .replacen("{}", &format_user_error(&error), 1),
+                    );
+                    break;
+                }
+            }
+        });
+    }
+}
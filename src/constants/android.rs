@@ -3,6 +3,9 @@
 /// Android emulator serial number prefix
 pub const EMULATOR_SERIAL_PREFIX: &str = "emulator-";
 
+/// Host and port of Google's SDK repository, probed to tell whether emu is online
+pub const SDK_REPOSITORY_ADDRESS: &str = "dl.google.com:443";
+
 /// ADB device state strings
 pub const ADB_DEVICE_STATE: &str = "device";
 pub const ADB_OFFLINE_STATE: &str = "offline";
@@ -45,3 +48,5 @@ pub const DEFAULT_MIN_API_LEVEL: u32 = 21; // Android 5.0
 /// Android emulator port configuration
 pub const EMULATOR_PORT_BASE: u16 = 5554;
 pub const EMULATOR_PORT_INCREMENT: u16 = 2;
+/// Last console port the emulator picks on its own
+pub const EMULATOR_PORT_MAX: u16 = 5584;
@@ -34,3 +34,22 @@ pub const LOG_COLOR_INFO: Color = Color::Blue;
 pub const LOG_COLOR_DEBUG: Color = Color::Cyan;
 pub const LOG_COLOR_VERBOSE: Color = Color::Magenta;
 pub const LOG_COLOR_DEFAULT: Color = Color::Gray;
+
+// Device tag badge colors, picked per tag so a tag keeps its color
+pub const TAG_BADGE_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::LightRed,
+];
+
+// Okabe-Ito colors used by the color-blind-friendly themes
+pub const CB_ORANGE: Color = Color::Rgb(230, 159, 0);
+pub const CB_SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+pub const CB_BLUISH_GREEN: Color = Color::Rgb(0, 158, 115);
+pub const CB_YELLOW: Color = Color::Rgb(240, 228, 66);
+pub const CB_BLUE: Color = Color::Rgb(0, 114, 178);
+pub const CB_VERMILLION: Color = Color::Rgb(213, 94, 0);
+pub const CB_REDDISH_PURPLE: Color = Color::Rgb(204, 121, 167);
@@ -11,21 +11,257 @@ pub const XCRUN: &str = "xcrun";
 pub const SIMCTL: &str = "simctl";
 pub const OSASCRIPT: &str = "osascript";
 pub const KILLALL: &str = "killall";
+pub const XCODEBUILD: &str = "xcodebuild";
+pub const XCODE_SELECT: &str = "xcode-select";
+pub const OPEN: &str = "open";
+pub const DEFAULTS: &str = "defaults";
+/// Copies directory trees with their metadata
+pub const DITTO: &str = "ditto";
+
+/// Screen mirroring of Android devices, an optional tool
+pub const SCRCPY: &str = "scrcpy";
+
+/// Packet capture on the Mac, whose network simulators share
+pub const TCPDUMP: &str = "tcpdump";
+
+/// X11 window manager control, used to focus emulator windows on Linux
+pub const WMCTRL: &str = "wmctrl";
+pub const WMCTRL_ACTIVATE: &str = "-a";
+
+/// macOS host information
+pub const SYSCTL: &str = "sysctl";
+
+/// Host process control, the last resort for an emulator that hangs
+pub const KILL_PROCESS: &str = "kill";
+pub const KILL_SIGNAL: &str = "-9";
+
+/// Free disk space in POSIX format with 1K blocks
+pub const DF: &str = "df";
+pub const DF_POSIX_KB: &str = "-Pk";
+
+/// Downloads of the device catalog
+pub const CURL: &str = "curl";
+/// Fails on HTTP errors, prints only errors and follows redirects
+pub const CURL_QUIET: &str = "-fsSL";
+pub const CURL_MAX_TIME: &str = "--max-time";
 
 /// ADB subcommands and arguments
 pub mod adb {
     pub const DEVICES: &str = "devices";
+    /// Prints the device list again whenever a device connects, disconnects,
+    /// or changes state, each update prefixed with its length in 4 hex digits
+    pub const TRACK_DEVICES: &str = "track-devices";
     pub const SHELL: &str = "shell";
     pub const GETPROP: &str = "getprop";
+    pub const SETPROP: &str = "setprop";
     pub const EMU: &str = "emu";
     pub const AVD: &str = "avd";
     pub const NAME: &str = "name";
     pub const KILL: &str = "kill";
     pub const LOGCAT: &str = "logcat";
+    /// `log -t <tag> <message>` writes a line to logcat
+    pub const LOG: &str = "log";
+    pub const LOG_TAG: &str = "-t";
+    pub const PM: &str = "pm";
+    pub const SENSOR: &str = "sensor";
+    pub const INPUT: &str = "input";
+    pub const TEXT: &str = "text";
+    pub const KEYEVENT: &str = "keyevent";
+    pub const SCREENRECORD: &str = "screenrecord";
+    /// Emulator console `network capture start <file>|stop`, which writes
+    /// the virtual network's traffic to a pcap on the host
+    pub const NETWORK: &str = "network";
+    pub const CAPTURE: &str = "capture";
+    pub const CAPTURE_START: &str = "start";
+    pub const CAPTURE_STOP: &str = "stop";
+    pub const SCREENSHOT: &str = "screenshot";
+    pub const DUMPSYS: &str = "dumpsys";
+    pub const WINDOW: &str = "window";
+    pub const SETTINGS: &str = "settings";
+    pub const GLOBAL: &str = "global";
+    pub const GET: &str = "get";
+    pub const PUT: &str = "put";
+    pub const DELETE: &str = "delete";
+    pub const BUGREPORT: &str = "bugreport";
+    /// `pull <device path> <local path>` copies a file off the device
+    pub const PULL: &str = "pull";
+    /// `push <local path> <device path>` copies a file onto the device
+    pub const PUSH: &str = "push";
+    pub const RM_FORCE: [&str; 2] = ["rm", "-f"];
+    /// `run-as <package> tar cf - . > <file>` archives the data directory
+    /// of a debuggable app; the shell, not the app, writes the archive
+    pub const RUN_AS: &str = "run-as";
+    pub const TAR: &str = "tar";
+    pub const TAR_CREATE: &str = "cf";
+    pub const TAR_EXTRACT: &str = "xf";
+    pub const TAR_STDIO: &str = "-";
+    pub const TAR_CURRENT_DIR: &str = ".";
+    pub const REDIRECT_OUTPUT: &str = ">";
+    pub const REDIRECT_INPUT: &str = "<";
+    /// Where app data archives are staged on the way to and from the host
+    pub const APP_DATA_STAGING_DIR: &str = "/data/local/tmp/";
+    /// `pm clear <package>` stops an app and deletes its data
+    pub const PM_CLEAR: &str = "clear";
+    /// Prefix of each APK `pm path <package>` prints for an app
+    pub const PM_PATH_PREFIX: &str = "package:";
+    /// `install-multiple -r -t <apks>` installs an app split into several
+    /// APKs, replacing an installed one and allowing test-only builds
+    pub const INSTALL: &str = "install";
+    pub const INSTALL_MULTIPLE: &str = "install-multiple";
+    pub const INSTALL_REPLACE: &str = "-r";
+    pub const INSTALL_TEST_ONLY: &str = "-t";
+    /// Start of the line adb install reports a rejected APK with
+    pub const INSTALL_FAILURE: &str = "Failure";
+    /// `perfetto -o <file> -t <duration> [--app <package>] <categories>`
+    /// records a system trace; the file must be under `PERFETTO_TRACE_DIR`
+    pub const PERFETTO: &str = "perfetto";
+    pub const PERFETTO_OUTPUT: &str = "-o";
+    pub const PERFETTO_DURATION: &str = "-t";
+    pub const PERFETTO_BUFFER: &str = "-b";
+    pub const PERFETTO_BUFFER_SIZE: &str = "64mb";
+    pub const PERFETTO_APP: &str = "--app";
+    pub const PERFETTO_TRACE_DIR: &str = "/data/misc/perfetto-traces/";
+    /// atrace categories a trace records: scheduling, frames, binder and
+    /// the framework's own sections
+    pub const PERFETTO_CATEGORIES: &[&str] = &[
+        "sched",
+        "freq",
+        "idle",
+        "am",
+        "wm",
+        "gfx",
+        "view",
+        "binder_driver",
+        "hal",
+        "dalvik",
+        "input",
+        "res",
+        "memory",
+    ];
+    /// `echo ok` answers as long as adbd and the shell respond
+    pub const ECHO: &str = "echo";
+    pub const HEALTH_CHECK_REPLY: &str = "ok";
+
+    /// `adb root` restarts adbd as root, which setting the clock needs;
+    /// images with Google Play refuse with `ROOT_REFUSED`
+    pub const ROOT: &str = "root";
+    pub const ROOT_REFUSED: &str = "cannot run as root";
+    pub const WAIT_FOR_DEVICE: &str = "wait-for-device";
+    /// `date +<DATE_NOW_FORMAT>` prints the local device time,
+    /// `date MMDDhhmmYYYY.ss` sets it
+    pub const DATE: &str = "date";
+    pub const DATE_NOW_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+    pub const DATE_SET_FORMAT: &str = "%m%d%H%M%Y.%S";
+    /// Global setting that syncs the clock with the network; 0 turns it off
+    pub const AUTO_TIME_SETTING: &str = "auto_time";
+
+    /// `ps -A -o PID,USER,RSS,NAME` lists every process with its memory
+    pub const PS: &str = "ps";
+    pub const PS_ALL: &str = "-A";
+    pub const PS_FORMAT: &str = "-o";
+    pub const PS_COLUMNS: &str = "PID,USER,RSS,NAME";
+    /// `dumpsys activity services` lists the running services
+    pub const ACTIVITY: &str = "activity";
+    pub const SERVICES: &str = "services";
+
+    /// `monkey -p <package> --throttle <ms> -v <count>` injects random events
+    pub const MONKEY: &str = "monkey";
+    pub const MONKEY_PACKAGE: &str = "-p";
+    pub const MONKEY_THROTTLE: &str = "--throttle";
+    pub const MONKEY_VERBOSE: &str = "-v";
+
+    /// Activity manager commands that send an intent
+    pub const AM: &str = "am";
+    pub const AM_FORCE_STOP: &str = "force-stop";
+    pub const AM_START: &str = "start";
+    pub const AM_BROADCAST: &str = "broadcast";
+    pub const INTENT_ACTION: &str = "-a";
+    pub const INTENT_DATA: &str = "-d";
+    pub const INTENT_COMPONENT: &str = "-n";
+    pub const INTENT_EXTRA_STRING: &str = "--es";
+    pub const INTENT_EXTRA_INT: &str = "--ei";
+    pub const INTENT_EXTRA_LONG: &str = "--el";
+    pub const INTENT_EXTRA_FLOAT: &str = "--ef";
+    pub const INTENT_EXTRA_BOOL: &str = "--ez";
+
+    /// `pm path android` answers once the package manager is up
+    pub const PM_PATH: &str = "path";
+    pub const ANDROID_PACKAGE: &str = "android";
+
+    /// Global settings that say whether a radio is on
+    pub const WIFI_ON_SETTING: &str = "wifi_on";
+    pub const BLUETOOTH_ON_SETTING: &str = "bluetooth_on";
+    pub const AIRPLANE_MODE_ON_SETTING: &str = "airplane_mode_on";
+    /// `svc wifi enable|disable` and `cmd bluetooth_manager enable|disable`
+    pub const SVC: &str = "svc";
+    pub const WIFI: &str = "wifi";
+    pub const CMD: &str = "cmd";
+    pub const BLUETOOTH_MANAGER: &str = "bluetooth_manager";
+    pub const ENABLE: &str = "enable";
+    pub const DISABLE: &str = "disable";
+    /// Tells the system that the airplane mode setting changed. Newer
+    /// images refuse the broadcast from the shell, where
+    /// `cmd connectivity airplane-mode enable|disable` does both steps
+    pub const AIRPLANE_MODE_ACTION: &str = "android.intent.action.AIRPLANE_MODE";
+    pub const AIRPLANE_MODE_STATE_EXTRA: &str = "state";
+    pub const BROADCAST_REFUSED: [&str; 2] = ["Security exception", "SecurityException"];
+    pub const CONNECTIVITY: &str = "connectivity";
+    pub const AIRPLANE_MODE: &str = "airplane-mode";
+
+    /// Properties behind the developer options toggles, with their on and
+    /// off values
+    pub const OVERDRAW_PROPERTY: &str = "debug.hwui.overdraw";
+    pub const OVERDRAW_ON: &str = "show";
+    pub const LAYOUT_BOUNDS_PROPERTY: &str = "debug.layout";
+    pub const STRICT_MODE_PROPERTY: &str = "persist.sys.strictmode.visual";
+    pub const PROFILE_BARS_PROPERTY: &str = "debug.hwui.profile";
+    pub const PROFILE_BARS_ON: &str = "visual_bars";
+    pub const PROPERTY_TRUE: &str = "true";
+    pub const PROPERTY_FALSE: &str = "false";
+    pub const PROPERTY_ONE: &str = "1";
+    pub const PROPERTY_ZERO: &str = "0";
+    /// `service call activity 1599295570` (SYSPROPS_TRANSACTION) makes
+    /// running apps read the `debug.*` properties again, as the developer
+    /// options do
+    pub const SERVICE: &str = "service";
+    pub const SERVICE_CALL: &str = "call";
+    pub const SYSPROPS_TRANSACTION: &str = "1599295570";
+
+    /// `cmd package resolve-activity` arguments that print the home activity
+    pub const RESOLVE_HOME_ACTIVITY: &[&str] = &[
+        "cmd",
+        "package",
+        "resolve-activity",
+        "--brief",
+        "-a",
+        "android.intent.action.MAIN",
+        "-c",
+        "android.intent.category.HOME",
+    ];
+    /// `cmd package resolve-activity` arguments that, followed by a package,
+    /// print its launcher activity
+    pub const RESOLVE_LAUNCHER_ACTIVITY: &[&str] = &[
+        "cmd",
+        "package",
+        "resolve-activity",
+        "--brief",
+        "-a",
+        "android.intent.action.MAIN",
+        "-c",
+        "android.intent.category.LAUNCHER",
+    ];
+
+    /// Global settings that scale UI animations; 0 turns them off
+    pub const ANIMATION_SCALE_SETTINGS: [&str; 3] = [
+        "window_animation_scale",
+        "transition_animation_scale",
+        "animator_duration_scale",
+    ];
 
     // System properties
     pub const PROP_AVD_NAME: &str = "ro.boot.qemu.avd_name";
     pub const PROP_KERNEL_AVD_NAME: &str = "ro.kernel.qemu.avd_name";
+    pub const PROP_BOOT_COMPLETED: &str = "sys.boot_completed";
 }
 
 /// iOS Simulator subcommands
@@ -38,7 +274,168 @@ pub mod ios {
     pub const ERASE: &str = "erase";
     pub const DEVTYPES: &str = "devicetypes";
     pub const CREATE: &str = "create";
+    /// `clone <udid> <name>` copies a shut down simulator with its data
+    pub const CLONE: &str = "clone";
+    pub const DELETE: &str = "delete";
+    /// Target of `simctl delete` that removes every unavailable simulator
+    pub const UNAVAILABLE: &str = "unavailable";
+    /// Runs a command inside a booted simulator
+    pub const SPAWN: &str = "spawn";
+    /// `launch --console-pty --terminate-running-process <udid> <bundle> <args>`
+    /// restarts an app with its output on simctl's stdout until it exits
+    pub const LAUNCH: &str = "launch";
+    pub const LAUNCH_CONSOLE_PTY: &str = "--console-pty";
+    pub const LAUNCH_TERMINATE_RUNNING: &str = "--terminate-running-process";
+    /// Prefix of the simctl variables passed on to the launched app
+    pub const LAUNCH_ENV_PREFIX: &str = "SIMCTL_CHILD_";
+    /// `terminate <udid> <bundle>` quits an app
+    pub const TERMINATE: &str = "terminate";
+    /// `get_app_container <udid> <bundle> app|data` prints the host path of
+    /// an app's bundle or data container
+    pub const GET_APP_CONTAINER: &str = "get_app_container";
+    pub const APP_CONTAINER: &str = "app";
+    pub const DATA_CONTAINER: &str = "data";
+    /// `install <udid> <path to .app>`
+    pub const INSTALL: &str = "install";
+    /// `getenv <udid> HOME` answers as long as the simulator's launchd responds
+    pub const GETENV: &str = "getenv";
+    pub const HOME_ENV: &str = "HOME";
+    /// `status_bar <udid> override --time <time>` fakes the status bar clock,
+    /// `status_bar <udid> clear` removes the override
+    pub const STATUS_BAR: &str = "status_bar";
+    pub const STATUS_BAR_OVERRIDE: &str = "override";
+    pub const STATUS_BAR_TIME: &str = "--time";
+    pub const STATUS_BAR_CLEAR: &str = "clear";
+    /// ISO date the status bar override also shows as the date
+    pub const STATUS_BAR_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+    /// `logger -t <tag> <message>` writes a line to the unified log
+    pub const LOGGER: &str = "logger";
+    pub const LOGGER_TAG: &str = "-t";
+    pub const DIAGNOSE: &str = "diagnose";
+    /// `simctl diagnose`: do not open a Finder window when done
+    pub const DIAGNOSE_NO_FINDER: &str = "-b";
+    /// `simctl diagnose`: collect all system logs rather than recent ones
+    pub const DIAGNOSE_ALL_LOGS: &str = "--all-logs";
+    pub const DIAGNOSE_UDID_PREFIX: &str = "--udid=";
+    pub const DIAGNOSE_OUTPUT_PREFIX: &str = "--output=";
+}
+
+/// `launchctl` run inside a simulator with `simctl spawn`
+pub mod launchctl {
+    pub const LAUNCHCTL: &str = "launchctl";
+    pub const LIST: &str = "list";
+    pub const STOP: &str = "stop";
+    /// Variables of the simulator's launchd, which apps launched later inherit
+    pub const GETENV: &str = "getenv";
+    pub const SETENV: &str = "setenv";
+    pub const UNSETENV: &str = "unsetenv";
+}
+
+/// macOS `defaults` subcommands and arguments
+pub mod defaults {
+    pub const READ: &str = "read";
+    pub const WRITE: &str = "write";
     pub const DELETE: &str = "delete";
+    pub const BOOL: &str = "-bool";
+    pub const FLOAT: &str = "-float";
+    pub const GLOBAL_DOMAIN: &str = "-g";
+}
+
+/// xcrun arguments
+pub mod xcrun {
+    /// Prints the path a developer tool resolves to
+    pub const FIND: &str = "--find";
+}
+
+/// `xcrun xctrace record` arguments for an Instruments time profile
+pub mod xctrace {
+    pub const XCTRACE: &str = "xctrace";
+    pub const RECORD: &str = "record";
+    pub const DEVICE: &str = "--device";
+    pub const TEMPLATE: &str = "--template";
+    pub const TIME_PROFILER: &str = "Time Profiler";
+    /// e.g. `10s`
+    pub const TIME_LIMIT: &str = "--time-limit";
+    pub const OUTPUT: &str = "--output";
+    /// `--attach <process name or pid>`, or `--all-processes`
+    pub const ATTACH: &str = "--attach";
+    pub const ALL_PROCESSES: &str = "--all-processes";
+    /// Skips the privacy prompt, which would wait for the TUI's keyboard
+    pub const NO_PROMPT: &str = "--no-prompt";
+}
+
+/// xcode-select arguments
+pub mod xcode_select {
+    /// Prints the active developer directory
+    pub const PRINT_PATH: &str = "-p";
+}
+
+/// xcodebuild arguments for downloading simulator runtimes
+pub mod xcodebuild {
+    pub const DOWNLOAD_PLATFORM: &str = "-downloadPlatform";
+    pub const PLATFORM_IOS: &str = "iOS";
+    pub const BUILD_VERSION: &str = "-buildVersion";
+}
+
+/// Clipboard tools tried in order, with the arguments that make them read stdin
+pub mod clipboard {
+    #[cfg(target_os = "macos")]
+    pub const TOOLS: &[(&str, &[&str])] = &[("pbcopy", &[])];
+
+    #[cfg(not(target_os = "macos"))]
+    pub const TOOLS: &[(&str, &[&str])] = &[
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+}
+
+/// Built-in shell snippets as (name, command), see `models::snippet`
+pub mod snippets {
+    pub const ANDROID: &[(&str, &str)] = &[
+        ("Battery status", "{adb} -s {serial} shell dumpsys battery"),
+        (
+            "Current activity",
+            "{adb} -s {serial} shell dumpsys activity activities | grep -E 'mResumedActivity|topResumedActivity'",
+        ),
+        ("Dark mode on", "{adb} -s {serial} shell cmd uimode night yes"),
+        ("Dark mode off", "{adb} -s {serial} shell cmd uimode night no"),
+        ("Screen size and density", "{adb} -s {serial} shell 'wm size; wm density'"),
+        ("Third-party packages", "{adb} -s {serial} shell pm list packages -3"),
+        ("Clear logcat", "{adb} -s {serial} logcat -c"),
+    ];
+
+    pub const IOS: &[(&str, &str)] = &[
+        (
+            "Dark appearance",
+            "{xcrun} simctl ui {udid} appearance dark",
+        ),
+        (
+            "Light appearance",
+            "{xcrun} simctl ui {udid} appearance light",
+        ),
+        ("Installed apps", "{xcrun} simctl listapps {udid}"),
+        ("Data directory", "{xcrun} simctl getenv {udid} HOME"),
+        ("Privacy reset", "{xcrun} simctl privacy {udid} reset all"),
+    ];
+}
+
+/// Version queries recorded in bug report bundles
+pub mod versions {
+    pub const ADB_VERSION: &str = "version";
+    pub const SIMCTL_VERSION: &str = "--version";
+    pub const XCODEBUILD: &str = "xcodebuild";
+    pub const XCODEBUILD_VERSION: &str = "-version";
+
+    /// Shown when a tool could not be queried
+    pub const UNAVAILABLE_PREFIX: &str = "unavailable: ";
+    pub const UNKNOWN: &str = "unknown";
+}
+
+/// sysctl arguments
+pub mod sysctl {
+    pub const VALUE_ONLY: &str = "-n";
+    pub const MEMSIZE: &str = "hw.memsize";
 }
 
 /// AVD Manager subcommands
@@ -66,6 +463,7 @@ pub mod sdkmanager {
     pub const VERBOSE: &str = "--verbose";
     pub const UNINSTALL: &str = "--uninstall";
     pub const INCLUDE_OBSOLETE: &str = "--include_obsolete";
+    pub const OFFLINE: &str = "--offline";
 }
 
 /// Emulator arguments
@@ -73,9 +471,38 @@ pub mod emulator {
     pub const AVD_ARG: &str = "-avd";
     pub const WIPE_DATA: &str = "-wipe-data";
     pub const NO_SNAPSHOT_LOAD: &str = "-no-snapshot-load";
+    /// Keeps the AVD's files untouched and discards what the guest writes
+    pub const READ_ONLY: &str = "-read-only";
     pub const NO_AUDIO: &str = "-no-audio";
     pub const NO_WINDOW: &str = "-no-window";
     pub const GPU_ARG: &str = "-gpu";
     pub const MEMORY_ARG: &str = "-memory";
     pub const PARTITION_SIZE_ARG: &str = "-partition-size";
+    /// Reports whether hardware acceleration is usable
+    pub const ACCEL_CHECK: &str = "-accel-check";
+
+    /// Window title prefix, followed by `<avd name>:<console port>`
+    pub const WINDOW_TITLE_PREFIX: &str = "Android Emulator - ";
+    /// AppleScript raising the process with the given PID ({} is replaced)
+    pub const FOCUS_PROCESS_SCRIPT: &str =
+        "tell application \"System Events\" to set frontmost of (first process whose unix id is {}) to true";
+}
+
+/// scrcpy flags
+pub mod scrcpy {
+    pub const SERIAL: &str = "--serial";
+    pub const WINDOW_TITLE: &str = "--window-title";
+    pub const STAY_AWAKE: &str = "--stay-awake";
+    /// Followed by `=<file>`; the container format comes from the extension
+    pub const RECORD: &str = "--record";
+}
+
+/// tcpdump flags
+pub mod tcpdump {
+    pub const INTERFACE: &str = "-i";
+    /// Every interface of the Mac, through Apple's pktap
+    pub const ALL_INTERFACES: &str = "pktap,all";
+    /// Writes each packet as it arrives, so a stopped capture is complete
+    pub const PACKET_BUFFERED: &str = "-U";
+    pub const WRITE: &str = "-w";
 }
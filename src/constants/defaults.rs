@@ -8,6 +8,18 @@ pub const DEFAULT_RAM_MB: u32 = 2048;
 /// Default storage size in MB for new Android devices
 pub const DEFAULT_STORAGE_MB: u32 = 8192;
 
+/// Default RAM size in MB for new Wear OS devices
+pub const WEAR_DEFAULT_RAM_MB: u32 = 1024;
+
+/// Default storage size in MB for new Wear OS devices
+pub const WEAR_DEFAULT_STORAGE_MB: u32 = 2048;
+
+/// Default RAM size in MB for tablets, desktops, and automotive devices
+pub const LARGE_DEVICE_DEFAULT_RAM_MB: u32 = 4096;
+
+/// Default storage size in MB for tablets and desktops
+pub const LARGE_DEVICE_DEFAULT_STORAGE_MB: u32 = 16384;
+
 /// Default ABI for the current architecture
 pub fn default_abi() -> &'static str {
     #[cfg(target_arch = "x86_64")]
@@ -24,6 +36,10 @@ pub fn default_abi() -> &'static str {
     }
 }
 
+/// Name of the Android SDK found through `ANDROID_HOME` or `[tools] android_sdk`
+/// when `.emu.toml` lists more SDKs
+pub const DEFAULT_ANDROID_SDK_NAME: &str = "default";
+
 /// Default GPU mode for emulators
 pub const DEFAULT_GPU_MODE: &str = "auto";
 
@@ -48,6 +64,16 @@ pub const DEFAULT_LOG_LEVEL: &str = "info";
 /// Environment variable value to disable Android logging
 pub const ANDROID_LOGGING_DISABLED_VALUE: &str = "0";
 
+/// Tag of the marker lines emu writes into a device log
+pub const LOG_MARKER_TAG: &str = "EMU";
+
+/// Event count and throttle (ms) prefilled in the monkey launcher
+pub const MONKEY_EVENT_COUNT: u32 = 500;
+pub const MONKEY_THROTTLE_MS: u32 = 100;
+
+/// Seconds prefilled in the trace capture dialog
+pub const TRACE_DURATION_SECS: u32 = 10;
+
 /// Test device constants for debug builds
 pub const TEST_DEVICE_NAME_BASE: &str = "test_debug_device";
 pub const TEST_DEVICE_NAME_33: &str = "test_debug_device_33";
@@ -80,6 +106,22 @@ pub const UNKNOWN_VALUE: &str = "Unknown";
 pub const NO_DEVICE_SELECTED: &str = "No device selected";
 pub const DEFAULT_DEVICE_CATEGORY: &str = "all";
 
+/// Queued device creations run at the same time
+pub const DEFAULT_MAX_PARALLEL_CREATES: usize = 2;
+
+/// Name of an ephemeral simulator clone, {} is the source simulator
+pub const EPHEMERAL_CLONE_NAME: &str = "{} Clone";
+
+/// Tags of the devices a test matrix creates start with this, followed by
+/// the group name
+pub const MATRIX_GROUP_TAG_PREFIX: &str = "matrix:";
+
+/// Group name prefilled in the test matrix wizard
+pub const DEFAULT_MATRIX_GROUP: &str = "qa";
+
+/// Exit code of `emu wait-for-boot` when the device did not boot in time
+pub const WAIT_FOR_BOOT_TIMEOUT_EXIT_CODE: i32 = 2;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +137,12 @@ mod tests {
         assert!((MIN_RAM_MB..=MAX_RAM_MB).contains(&DEFAULT_RAM_MB));
         assert!((MIN_STORAGE_MB..=MAX_STORAGE_MB).contains(&DEFAULT_STORAGE_MB));
         assert!(DEFAULT_STORAGE_MB >= DEFAULT_RAM_MB);
+        assert!(RAM_SIZE_OPTIONS_MB.contains(&DEFAULT_RAM_MB));
+        assert!(RAM_SIZE_OPTIONS_MB.contains(&WEAR_DEFAULT_RAM_MB));
+        assert!(RAM_SIZE_OPTIONS_MB.contains(&LARGE_DEVICE_DEFAULT_RAM_MB));
+        assert!(STORAGE_SIZE_OPTIONS_MB.contains(&DEFAULT_STORAGE_MB));
+        assert!(STORAGE_SIZE_OPTIONS_MB.contains(&WEAR_DEFAULT_STORAGE_MB));
+        assert!(STORAGE_SIZE_OPTIONS_MB.contains(&LARGE_DEVICE_DEFAULT_STORAGE_MB));
     }
 
     #[test]
@@ -138,6 +186,14 @@ mod tests {
         assert!(EXTRACT_PHASE_START_PERCENTAGE < INSTALL_PHASE_START_PERCENTAGE);
         assert!(INSTALL_PHASE_START_PERCENTAGE < COMPLETION_THRESHOLD_PERCENTAGE);
         assert!(COMPLETION_THRESHOLD_PERCENTAGE <= PROGRESS_PHASE_100_PERCENT);
+
+        assert!(CREATE_RESOLVE_PERCENTAGE < CREATE_LOADING_START_PERCENTAGE);
+        assert!(CREATE_LOADING_START_PERCENTAGE < CREATE_FETCH_START_PERCENTAGE);
+        assert!(CREATE_FETCH_START_PERCENTAGE < CREATE_COMPUTE_START_PERCENTAGE);
+        assert!(CREATE_COMPUTE_START_PERCENTAGE < CREATE_COMPUTE_END_PERCENTAGE);
+        assert!(CREATE_COMPUTE_END_PERCENTAGE < CREATE_PROFILE_PERCENTAGE);
+        assert!(CREATE_PROFILE_PERCENTAGE < CREATE_WRITE_PERCENTAGE);
+        assert!(CREATE_WRITE_PERCENTAGE < CREATE_CONFIGURE_PERCENTAGE);
     }
 
     #[test]
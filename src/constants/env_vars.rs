@@ -4,12 +4,62 @@
 pub const ANDROID_HOME: &str = "ANDROID_HOME";
 pub const ANDROID_SDK_ROOT: &str = "ANDROID_SDK_ROOT";
 
+/// Android emulator data locations, in the order the emulator consults them
+/// for the AVD directory
+pub const ANDROID_AVD_HOME: &str = "ANDROID_AVD_HOME";
+pub const ANDROID_EMULATOR_HOME: &str = "ANDROID_EMULATOR_HOME";
+pub const ANDROID_USER_HOME: &str = "ANDROID_USER_HOME";
+
 /// System environment variables
 pub const HOME: &str = "HOME";
 pub const PATH: &str = "PATH";
+pub const USER: &str = "USER";
+pub const USERNAME: &str = "USERNAME";
+pub const XDG_RUNTIME_DIR: &str = "XDG_RUNTIME_DIR";
+
+/// Xcode used by `xcrun` and `xcodebuild` instead of the `xcode-select` choice
+pub const DEVELOPER_DIR: &str = "DEVELOPER_DIR";
 
 /// Logging and debugging variables
 pub const RUST_LOG: &str = "RUST_LOG";
 pub const ANDROID_EMULATOR_LOG_ENABLE: &str = "ANDROID_EMULATOR_LOG_ENABLE";
 pub const ANDROID_AVD_VERBOSE: &str = "ANDROID_AVD_VERBOSE";
 pub const ANDROID_VERBOSE: &str = "ANDROID_VERBOSE";
+
+/// Terminal color conventions and theme selection
+pub const NO_COLOR: &str = "NO_COLOR";
+pub const CLICOLOR: &str = "CLICOLOR";
+pub const CLICOLOR_FORCE: &str = "CLICOLOR_FORCE";
+pub const EMU_THEME: &str = "EMU_THEME";
+
+/// Variables recorded in bug report bundles
+pub const REPORTED_ENV_VARS: &[&str] = &[
+    ANDROID_HOME,
+    ANDROID_SDK_ROOT,
+    ANDROID_AVD_HOME,
+    ANDROID_EMULATOR_HOME,
+    ANDROID_USER_HOME,
+    "JAVA_HOME",
+    "TERM",
+    "COLORTERM",
+    NO_COLOR,
+    CLICOLOR,
+    EMU_THEME,
+    RUST_LOG,
+    DEVELOPER_DIR,
+];
+
+/// Variables passed to project hooks from `.emu.toml`
+pub const EMU_PLATFORM: &str = "EMU_PLATFORM";
+pub const EMU_DEVICE_NAME: &str = "EMU_DEVICE_NAME";
+pub const EMU_DEVICE_ID: &str = "EMU_DEVICE_ID";
+
+/// Skips the network check: "1" always works offline, "0" always online
+pub const EMU_OFFLINE: &str = "EMU_OFFLINE";
+
+/// Proxies the network check goes through instead of connecting directly,
+/// in the order they are consulted
+pub const PROXY_VARS: &[&str] = &["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"];
+
+/// Rewrites golden files in UI snapshot tests instead of comparing against them
+pub const EMU_UPDATE_SNAPSHOTS: &str = "EMU_UPDATE_SNAPSHOTS";
@@ -8,16 +8,112 @@ pub mod android {
     pub const AVD_DIR: &str = ".android";
     pub const AVD_SUBDIR: &str = "avd";
     pub const CONFIG_INI: &str = "config.ini";
+    /// Key of the data directory in the `<name>.ini` pointer of an AVD
+    pub const AVD_INI_PATH_KEY: &str = "path";
+    /// `config.ini` key that makes every launch of an AVD skip Quick Boot
+    pub const FORCE_COLD_BOOT_KEY: &str = "fastboot.forceColdBoot";
+    /// `config.ini` key of the RAM size in MB
+    pub const RAM_SIZE_KEY: &str = "hw.ramSize";
+    /// Boolean values in `config.ini`
+    pub const CONFIG_YES: &str = "yes";
+    pub const CONFIG_NO: &str = "no";
+    /// Snapshots of an AVD, under its data directory
+    pub const SNAPSHOTS_DIR: &str = "snapshots";
+    /// The snapshot Quick Boot saves on exit and resumes from
+    pub const QUICK_BOOT_SNAPSHOT: &str = "default_boot";
     pub const SKINS_DIR: &str = "skins";
     pub const PLATFORMS_DIR: &str = "platforms";
     pub const SYSTEM_IMAGES_DIR: &str = "system-images";
+    /// Package metadata next to a tool's `bin` directory
+    pub const SOURCE_PROPERTIES: &str = "source.properties";
+    pub const PKG_REVISION_PREFIX: &str = "Pkg.Revision=";
+    /// Where running emulators announce their ports, under the runtime directory
+    pub const EMULATOR_DISCOVERY_DIR: &str = "avd/running";
+    /// Runtime directory of the emulator on macOS, under the home directory
+    pub const MACOS_CACHES_DIR: &str = "Library/Caches/TemporaryItems";
+}
+
+/// Xcode installation paths
+pub mod xcode {
+    /// Where Xcode versions are installed
+    pub const APPLICATIONS_DIR: &str = "/Applications";
+    /// Prefix of Xcode bundle names, e.g. `Xcode.app` or `Xcode-15.4.app`
+    pub const APP_PREFIX: &str = "Xcode";
+    pub const APP_EXTENSION: &str = "app";
+    /// Developer directory inside an Xcode bundle
+    pub const DEVELOPER_SUBDIR: &str = "Contents/Developer";
 }
 
 /// File extensions
 pub const AVD_EXTENSION: &str = ".avd";
 pub const INI_EXTENSION: &str = ".ini";
 pub const LOG_EXTENSION: &str = ".log";
+/// Lock files the emulator leaves in an AVD directory while it runs
+pub const LOCK_EXTENSION: &str = ".lock";
 
 /// Configuration file names
 pub const CONFIG_FILE: &str = "config.ini";
 pub const HARDWARE_FILE: &str = "hardware-qemu.ini";
+
+/// Emulator skin definition files
+pub const SKIN_HARDWARE_FILE: &str = "hardware.ini";
+pub const SKIN_LAYOUT_FILE: &str = "layout";
+
+/// Linux memory statistics file used to detect host RAM
+pub const PROC_MEMINFO: &str = "/proc/meminfo";
+
+/// Audit log of executed commands, stored under the user's local data directory
+pub const APP_DATA_DIR: &str = "emu";
+pub const COMMAND_AUDIT_LOG_FILE: &str = "commands.log";
+pub const ROTATED_LOG_SUFFIX: &str = ".1";
+
+/// Persistent caches, stored under the user's config directory
+pub const API_LEVEL_CACHE_FILE: &str = "api_level_cache.json";
+pub const DEVICE_INVENTORY_CACHE_FILE: &str = "device_inventory_cache.json";
+pub const ANDROID_VERSION_NAME_CACHE_FILE: &str = "android_version_names.json";
+pub const DEVICE_HISTORY_FILE: &str = "device_history.json";
+pub const DEVICE_ANNOTATIONS_FILE: &str = "device_annotations.json";
+pub const INTENT_HISTORY_FILE: &str = "intent_history.json";
+pub const PANEL_LAYOUT_FILE: &str = "panel_layout.json";
+pub const DEVICE_CATALOG_FILE: &str = "device_catalog.json";
+
+/// Latest recommended device configurations, downloaded when the catalog is refreshed
+pub const DEVICE_CATALOG_URL: &str =
+    "https://raw.githubusercontent.com/wasabeef/emu/main/catalog/devices.json";
+
+/// Bug report bundles written by `emu report` or the diagnostics viewer
+pub const REPORT_FILE_PREFIX: &str = "emu-report-";
+pub const REPORT_EXTENSION: &str = ".zip";
+pub const REPORT_ENVIRONMENT_FILE: &str = "environment.txt";
+pub const REPORT_TOOL_VERSIONS_FILE: &str = "tool-versions.txt";
+pub const REPORT_DIAGNOSTICS_FILE: &str = "diagnostics.log";
+pub const REPORT_COMMANDS_FILE: &str = "commands.log";
+
+/// Android bug reports collected with `adb bugreport`
+pub const BUGREPORT_FILE_PREFIX: &str = "bugreport-";
+/// iOS diagnostics collected with `simctl diagnose`
+pub const DIAGNOSE_FILE_PREFIX: &str = "simctl_diagnose-";
+pub const DIAGNOSE_ARCHIVE_EXTENSION: &str = ".tar.gz";
+
+/// Perfetto traces of an AVD and Instruments time profiles of a simulator
+pub const TRACE_FILE_PREFIX: &str = "trace-";
+pub const PERFETTO_TRACE_EXTENSION: &str = ".perfetto-trace";
+pub const XCTRACE_EXTENSION: &str = ".trace";
+
+/// Backups of an app's data: a tar archive of an Android app's data
+/// directory, or a copy of an iOS app's data container
+pub const APP_DATA_BACKUP_PREFIX: &str = "app-data-";
+pub const APP_DATA_ARCHIVE_EXTENSION: &str = ".tar";
+/// Temporary directory holding an app on its way to another device
+pub const APP_COPY_DIR_PREFIX: &str = "emu-app-copy-";
+
+/// Packet captures of a device's network traffic
+pub const NETWORK_CAPTURE_PREFIX: &str = "capture-";
+pub const PCAP_EXTENSION: &str = ".pcap";
+
+/// Screen recordings made while mirroring with scrcpy
+pub const MIRROR_RECORDING_PREFIX: &str = "mirror-";
+pub const MIRROR_RECORDING_EXTENSION: &str = ".mp4";
+
+/// Project-local configuration read from the directory emu is started in
+pub const PROJECT_CONFIG_FILE: &str = ".emu.toml";
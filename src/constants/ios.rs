@@ -19,6 +19,23 @@ pub const IOS_INCH_PATTERN: &str = " inch";
 pub const SIMULATOR_QUIT_COMMAND: &str = "tell application \"Simulator\" to quit";
 pub const SIMULATOR_APP_NAME: &str = "Simulator";
 pub const SIMULATOR_OPEN_FLAG: &str = "-a";
+/// Passes the rest of the arguments to Simulator.app
+pub const SIMULATOR_ARGS_FLAG: &str = "--args";
+/// Makes Simulator.app show the window of the given UDID
+pub const SIMULATOR_CURRENT_DEVICE_FLAG: &str = "-CurrentDeviceUDID";
+
+/// Preferences domain of Simulator.app
+pub const SIMULATOR_DEFAULTS_DOMAIN: &str = "com.apple.iphonesimulator";
+/// Simulator.app preference for I/O > Keyboard > Connect Hardware Keyboard
+pub const CONNECT_HARDWARE_KEYBOARD_KEY: &str = "ConnectHardwareKeyboard";
+
+/// launchd variable that draws the Metal performance HUD over apps
+pub const METAL_HUD_VARIABLE: &str = "MTL_HUD_ENABLED";
+pub const METAL_HUD_ON: &str = "1";
+/// Global default that slows UIKit animations down, like Debug > Slow
+/// Animations of Simulator.app
+pub const ANIMATION_DRAG_COEFFICIENT_KEY: &str = "UIAnimationDragCoefficient";
+pub const SLOW_ANIMATION_COEFFICIENT: &str = "10";
 
 /// iOS error messages for graceful handling
 pub const IOS_ALREADY_BOOTED_ERROR: &str = "Unable to boot device in current state: Booted";
@@ -70,3 +70,6 @@ pub const DEVICE_KEYWORD_SONY: &str = "sony";
 // Device type detection keywords - Screen sizes for iOS
 pub const DEVICE_KEYWORD_12_9: &str = "12.9";
 pub const DEVICE_KEYWORD_11: &str = "11";
+
+// Clock dialog input that hands the clock back to network time
+pub const CLOCK_AUTOMATIC_INPUT: &str = "auto";
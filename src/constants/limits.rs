@@ -37,6 +37,12 @@ pub const MIN_STORAGE_MB: u32 = 1024;
 /// Maximum storage size in MB for Android devices
 pub const MAX_STORAGE_MB: u32 = 65536;
 
+/// RAM sizes in MB offered by the device creation form
+pub const RAM_SIZE_OPTIONS_MB: &[u32] = &[512, 1024, 1536, 2048, 3072, 4096, 6144, 8192];
+
+/// Storage sizes in MB offered by the device creation form
+pub const STORAGE_SIZE_OPTIONS_MB: &[u32] = &[1024, 2048, 4096, 8192, 16384, 32768, 65536];
+
 /// Upper limit for storage validation testing
 pub const STORAGE_UPPER_LIMIT_TEST: u32 = 16384;
 
@@ -55,6 +61,37 @@ pub const MAX_LOG_ENTRIES: usize = 1000;
 /// Maximum notification queue size
 pub const MAX_NOTIFICATIONS: usize = 10;
 
+/// Maximum executed commands kept for the command history view
+pub const MAX_COMMAND_HISTORY_ENTRIES: usize = 200;
+
+/// Maximum emu log records kept for the in-app diagnostics viewer
+pub const MAX_DIAGNOSTIC_ENTRIES: usize = 500;
+
+/// Maximum operations kept in each device's persisted history
+pub const MAX_DEVICE_HISTORY_ENTRIES: usize = 100;
+
+/// Maximum intents kept in each device's persisted intent history
+pub const MAX_INTENT_HISTORY_ENTRIES: usize = 20;
+
+/// Lines of emulator console output kept for each launched AVD
+pub const MAX_LAUNCH_OUTPUT_LINES: usize = 500;
+
+/// Bytes of stdout or stderr kept from one command; the rest is dropped
+pub const MAX_COMMAND_OUTPUT_BYTES: usize = 32 * 1024 * 1024;
+
+/// Bytes kept of a single line streamed from a command; the rest of the line
+/// is dropped
+pub const MAX_OUTPUT_LINE_BYTES: usize = 64 * 1024;
+
+/// Characters of forwarded keystrokes echoed while typing into a device
+pub const MAX_TYPED_ECHO_CHARS: usize = 60;
+
+/// Free disk space in MB below which an AVD is unlikely to boot
+pub const MIN_LAUNCH_FREE_DISK_MB: u64 = 2048;
+
+/// Size in bytes at which the command audit log is rotated
+pub const COMMAND_AUDIT_LOG_MAX_BYTES: u64 = 1024 * 1024;
+
 /// Percentage calculation multiplier
 pub const PERCENTAGE_MULTIPLIER: f64 = 100.0;
 
@@ -64,6 +101,9 @@ pub const MAX_DEVICE_NAME_CREATE_LENGTH: usize = 20;
 /// Maximum error message display length
 pub const MAX_ERROR_MESSAGE_LENGTH: usize = 60;
 
+/// Longest trace the trace capture dialog records, in seconds
+pub const MAX_TRACE_DURATION_SECS: u32 = 600;
+
 /// Maximum version number value
 pub const MAX_VERSION_NUMBER: u32 = 50;
 
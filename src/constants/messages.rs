@@ -11,6 +11,8 @@ pub mod errors {
     pub const ANDROID_SDK_NOT_FOUND: &str =
         "Android SDK not found. Please set ANDROID_HOME or ANDROID_SDK_ROOT";
     pub const TOOL_NOT_FOUND: &str = "Tool '{}' not found in Android SDK";
+    pub const AVD_HOME_NOT_FOUND: &str =
+        "AVD directory not found. Please set ANDROID_AVD_HOME or HOME";
     pub const DEVICE_NOT_FOUND: &str = "Device '{}' not found";
     pub const NO_DEVICE_DEFINITIONS: &str =
         "No Android device definitions found. Please check your Android SDK installation.";
@@ -23,9 +25,27 @@ pub mod errors {
     pub const DEVICE_CREATE_FAILED: &str = "Failed to create device '{}': {}";
     pub const DEVICE_DELETE_FAILED: &str = "Failed to delete device '{}': {}";
     pub const DEVICE_WIPE_FAILED: &str = "Failed to wipe device '{}': {}";
+    pub const DEVICE_DATA_MOVE_WHILE_RUNNING: &str = "Stop '{}' before moving its data";
+    pub const DEVICE_DATA_TARGET_NOT_ABSOLUTE: &str = "'{}' is not an absolute path";
+    pub const DEVICE_DATA_ALREADY_THERE: &str = "The data of '{}' is already in {}";
+    pub const DEVICE_DATA_TARGET_EXISTS: &str = "{} already exists";
+    pub const QUICK_BOOT_SNAPSHOT_IN_USE: &str =
+        "Stop '{}' before deleting its Quick Boot snapshot";
+    pub const SNIPPET_NEEDS_RUNNING_DEVICE: &str =
+        "Start '{}' first, the snippet needs its adb serial";
+    pub const SNIPPET_TIMED_OUT: &str = "timed out after {}s";
+    pub const CLOCK_NEEDS_ROOT: &str =
+        "Changing the clock needs adb root, which Google Play images do not allow";
+    pub const SCRCPY_NOT_FOUND: &str =
+        "scrcpy not found, install it (brew install scrcpy, apt install scrcpy) or set `scrcpy` under [tools] in .emu.toml";
+    pub const ALREADY_MIRRORING: &str = "'{}' is already mirrored";
+    pub const NETWORK_CAPTURE_PATH_HAS_SPACES: &str =
+        "The emulator console cannot write to {} as the path has spaces, set [output] trace_dir in .emu.toml to a directory without them";
 
     // Generic errors
     pub const COMMAND_EXECUTION_FAILED: &str = "Command execution failed";
+    pub const COMMAND_TIMED_OUT: &str = "Command timed out after {}s";
+    pub const COMMAND_CANCELLED: &str = "Command cancelled";
     pub const CONFIGURATION_ERROR: &str = "Configuration error: {}";
     pub const FILE_ACCESS_ERROR: &str = "File access error occurred";
     pub const DATA_PARSING_FAILED: &str = "Data parsing failed";
@@ -33,6 +53,9 @@ pub mod errors {
     pub const CANNOT_SELECT_DURING_DOWNLOAD: &str = "Cannot select items during download";
     pub const CANNOT_SELECT_DURING_SYSTEM_IMAGE_OPERATION: &str =
         "Cannot select items while a system image operation is in progress";
+    pub const OFFLINE_INSTALL: &str =
+        "Offline: system images can be downloaded once the connection is back";
+    pub const SYSTEM_IMAGE_QUEUE_FAILED: &str = "Installed {} of {} system images. Failed: {}";
 
     // Platform-specific
     pub const IOS_NOT_AVAILABLE: &str = "iOS manager not available (only available on macOS)";
@@ -52,6 +75,7 @@ pub mod notifications {
     // System operations
     pub const LOGS_CLEARED: &str = "Logs cleared";
     pub const SYSTEM_IMAGE_INSTALLED: &str = "System image installed successfully";
+    pub const SYSTEM_IMAGES_INSTALLED: &str = "{} system images installed successfully";
     pub const SYSTEM_IMAGE_UNINSTALLED: &str = "System image uninstalled successfully";
     pub const INSTALL_PROGRESS_COMPLETE: &str = "✅ Installation completed successfully!";
 
@@ -60,6 +84,177 @@ pub mod notifications {
     pub const WIPING_DEVICE: &str = "Wiping device '{}'...";
     pub const CREATING_DEVICE: &str = "Creating device '{}'...";
     pub const DELETING_DEVICE: &str = "Deleting device '{}'...";
+    pub const DEVICE_CREATION_CANCELLING: &str = "Cancelling device creation...";
+    pub const DEVICE_CREATION_CANCELLED: &str = "Device creation cancelled";
+    pub const DEVICE_CREATION_CLEANUP_FAILED: &str =
+        "Device creation cancelled, but cleaning up '{}' failed: {}";
+    pub const SYSTEM_IMAGE_OPERATION_CANCELLED: &str = "System image operation cancelled";
+    pub const QUEUED_CREATION_ADDED: &str = "Queued creation of '{}' (Shift+Q shows the queue)";
+    pub const QUEUED_CREATION_DUPLICATE: &str = "'{}' is already in the creation queue";
+    pub const QUEUED_CREATION_SUCCEEDED: &str = "Queued device '{}' created successfully";
+    pub const QUEUED_CREATION_FAILED: &str = "Queued creation of '{}' failed: {}";
+    pub const CATALOG_DEVICE_CREATED: &str = "Device '{}' created from the catalog";
+    pub const CATALOG_DEVICE_FAILED: &str = "Failed to create '{}' from the catalog: {}";
+    pub const CATALOG_REFRESHED: &str = "Device catalog updated, {} configurations";
+    pub const CATALOG_ANDROID_ONLY: &str =
+        "The device catalog lists Android configurations, [c] creates a simulator";
+    pub const EPHEMERAL_ALREADY_RUNNING: &str = "Stop '{}' before starting it read-only";
+    pub const EPHEMERAL_READ_ONLY_STARTED: &str =
+        "Starting '{}' read-only, its changes are discarded when it stops";
+    pub const EPHEMERAL_READ_ONLY_FAILED: &str = "Failed to start '{}' read-only: {}";
+    pub const EPHEMERAL_SOURCE_RUNNING: &str = "Stop '{}' before cloning it";
+    pub const EPHEMERAL_CLONING: &str = "Cloning '{}'...";
+    pub const EPHEMERAL_CLONE_STARTED: &str =
+        "Ephemeral clone '{}' booted, it is deleted when it stops or emu quits";
+    pub const EPHEMERAL_CLONE_FAILED: &str = "Failed to clone '{}': {}";
+    pub const EPHEMERAL_CLONE_DELETED: &str = "Deleted ephemeral clone '{}'";
+    pub const EPHEMERAL_CLONE_DELETE_FAILED: &str = "Failed to delete ephemeral clone '{}': {}";
+    pub const MATRIX_QUEUED: &str =
+        "Test matrix '{group}': {queued} devices queued, {existing} already existed";
+    pub const MATRIX_NO_GROUP: &str = "'{}' is in no test matrix group, [x] creates one";
+    pub const MATRIX_GROUP_RUNNING: &str = "Every device of group '{}' is already running";
+    pub const MATRIX_GROUP_STARTED: &str = "Started {count} devices of group '{group}'";
+    pub const MATRIX_GROUP_START_FAILED: &str =
+        "Started {started} of {count} devices of group '{group}': {error}";
+    pub const REPAIR_NOT_NEEDED: &str = "'{}' is available and needs no repair";
+    pub const RUNTIME_INSTALL_STARTED: &str =
+        "Installing the iOS {} runtime, this can take several minutes...";
+    pub const RUNTIME_INSTALL_SUCCEEDED: &str = "iOS {} runtime installed";
+    pub const RUNTIME_INSTALL_FAILED: &str = "Failed to install the iOS {} runtime: {}";
+    pub const FOCUS_NOT_RUNNING: &str = "'{}' is not running, start it first";
+    pub const MOVING_DEVICE_DATA: &str = "Moving data of '{}'...";
+    pub const DEVICE_DATA_MOVED: &str = "Moved data of '{}' to {}";
+    pub const DEVICE_DATA_MOVE_FAILED: &str = "Failed to move data of '{}': {}";
+    pub const FOCUS_FAILED: &str = "Failed to focus the window of '{}': {}";
+    pub const COLLECTING_BUGREPORT: &str = "Collecting bug report of '{}'...";
+    pub const COLLECTING_BUGREPORT_PROGRESS: &str = "Collecting bug report of '{}'... {}%";
+    pub const BUGREPORT_SAVED: &str = "Bug report of '{}' saved to {}";
+    pub const BUGREPORT_FAILED: &str = "Failed to collect bug report of '{}': {}";
+    pub const CAPTURING_TRACE: &str = "Recording a {}s trace of '{}'...";
+    pub const TRACE_SAVED: &str = "Trace of '{}' saved to {}";
+    pub const TRACE_FAILED: &str = "Failed to record a trace of '{}': {}";
+    pub const TEST_SETTINGS_APPLIED_ANDROID: &str =
+        "Animations turned off on '{}', [Shift+T] restores them";
+    pub const TEST_SETTINGS_APPLIED_IOS: &str =
+        "Hardware keyboard disconnected in Simulator.app, [Shift+T] restores it";
+    pub const TEST_SETTINGS_RESTORED: &str = "Restored the settings of '{}'";
+    pub const TEST_SETTINGS_FAILED: &str = "Failed to change the settings of '{}': {}";
+    pub const SENDING_INTENT: &str = "Sending intent to '{}'...";
+    pub const INTENT_SENT: &str = "Sent to '{}': {}";
+    pub const INTENT_FAILED: &str = "Failed to send intent to '{}': {}";
+    pub const LOG_MARKER_WRITTEN: &str = "Marked the log of '{}': {}";
+    pub const LOG_MARKER_FAILED: &str = "Failed to mark the log of '{}': {}";
+    pub const PROCESS_KILLED: &str = "Stopped {} on '{}'";
+    pub const PROCESS_KILL_FAILED: &str = "Failed to stop {}: {}";
+    pub const PROPERTY_SET: &str = "Set {} to '{}' on '{}'";
+    pub const PROPERTY_SET_FAILED: &str = "Failed to set {}: {}";
+    pub const PROPERTY_READ_ONLY: &str = "{} is read-only";
+    pub const MONKEY_STARTED: &str = "Running monkey against {} on '{}', output goes to the log";
+    pub const MONKEY_STOPPED: &str = "Stopped the monkey run on '{}'";
+    pub const MONKEY_PASSED: &str = "Monkey finished on '{}': {} events, no crashes";
+    pub const MONKEY_FOUND_FAILURES: &str = "Monkey on '{}' found {} crash(es) and {} ANR(s): {}";
+    pub const EMULATOR_LAUNCH_FAILED: &str =
+        "Emulator for '{}' exited: {} (details tab Output has its full output)";
+    pub const MONKEY_FAILED: &str = "Monkey run on '{}' failed: {}";
+    pub const APP_LAUNCHED: &str = "Launched {} on '{}'";
+    pub const APP_CONSOLE_STARTED: &str =
+        "Launched {} on '{}', its output goes to the log, [Shift+A] stops it";
+    pub const APP_CONSOLE_STOPPED: &str = "Stopped {} on '{}'";
+    pub const APP_EXITED: &str = "{} exited on '{}'";
+    pub const APP_LAUNCH_FAILED: &str = "Failed to launch {} on '{}': {}";
+    pub const DEVICE_START_STALLED: &str = "'{}' has not come up after {}s, checking why";
+    pub const DEVICE_START_NOT_CONFIRMED: &str =
+        "'{}' is still not running {}s after it was started, showing it as stopped";
+    pub const DEVICE_STOP_NOT_CONFIRMED: &str =
+        "'{}' is still running {}s after it was stopped, showing it as running";
+    pub const LAUNCH_DIAGNOSIS_CLEAN: &str =
+        "No known launch problem found for '{}', the details tab Output has the emulator output";
+    pub const LAUNCH_DIAGNOSIS_FAILED: &str = "Failed to diagnose the launch of '{}': {}";
+    pub const LAUNCH_ISSUES_FOUND: &str = "'{}' cannot start: {}";
+    pub const LAUNCH_ISSUE_FIXING: &str = "{} for '{}'...";
+    pub const LAUNCH_ISSUE_FIXED: &str = "Done: {} for '{}', start it again";
+    pub const LAUNCH_ISSUE_FIX_FAILED: &str = "Failed to fix '{}': {}";
+    pub const DEVICE_WEDGED: &str = "'{}' stopped responding, [Shift+W] force-restarts it";
+    pub const FORCE_RESTARTING: &str = "Force-restarting '{}'...";
+    pub const FORCE_RESTART_FAILED: &str = "Failed to force-restart '{}': {}";
+    pub const QUICK_BOOT_SNAPSHOT_DELETED: &str = "Deleted the Quick Boot snapshot of '{}'";
+    pub const COLD_BOOT_ONCE_ON: &str = "'{}' boots cold on its next start";
+    pub const COLD_BOOT_ONCE_OFF: &str = "'{}' resumes from Quick Boot on its next start";
+    pub const ALWAYS_COLD_BOOT_ON: &str = "'{}' now always boots cold";
+    pub const ALWAYS_COLD_BOOT_OFF: &str = "'{}' uses Quick Boot again";
+    pub const QUICK_BOOT_FAILED: &str = "Quick Boot change for '{}' failed: {}";
+    pub const CLOCK_CHANGED: &str = "Clock of '{}' is now {}";
+    pub const CLOCK_AUTOMATIC: &str = "'{}' follows the network time again";
+    pub const IOS_STATUS_BAR_TIME_SET: &str =
+        "Status bar of '{}' shows {}, apps still run on the Mac's clock";
+    pub const IOS_STATUS_BAR_TIME_CLEARED: &str = "Status bar of '{}' shows the real time again";
+    pub const CLOCK_CHANGE_FAILED: &str = "Failed to change the clock of '{}': {}";
+    pub const TYPE_INTO_IOS_UNSUPPORTED: &str =
+        "simctl cannot send keystrokes, [o] opens the window of '{}' to type there";
+    pub const TYPE_INTO_DEVICE_FAILED: &str = "Stopped typing into '{}': {}";
+    pub const RADIO_ENABLED: &str = "{} of '{}' is on";
+    pub const RADIO_DISABLED: &str = "{} of '{}' is off";
+    pub const RADIO_CHANGE_FAILED: &str = "Failed to switch {} of '{}': {}";
+    pub const RADIO_STATUS_FAILED: &str = "Failed to read the radios of '{}': {}";
+    pub const RADIOS_IOS_UNSUPPORTED: &str =
+        "simctl cannot switch radios, '{}' uses the network of the Mac";
+    pub const DEVELOPER_TOGGLE_ENABLED: &str = "{} of '{}' is on";
+    pub const DEVELOPER_TOGGLE_DISABLED: &str = "{} of '{}' is off";
+    pub const DEVELOPER_TOGGLE_CHANGE_FAILED: &str = "Failed to switch {} of '{}': {}";
+    pub const DEVELOPER_TOGGLE_STATUS_FAILED: &str =
+        "Failed to read the developer toggles of '{}': {}";
+    pub const MIRRORING_STARTED: &str = "Mirroring '{}' with scrcpy, [v] again closes it";
+    pub const MIRRORING_AND_RECORDING: &str = "Mirroring '{}' with scrcpy and recording to {}";
+    pub const MIRRORING_STOPPED: &str = "Stopped mirroring '{}'";
+    pub const NETWORK_CAPTURE_STARTED: &str =
+        "Capturing the traffic of '{}' to {}, [Shift+Y] again stops";
+    pub const NETWORK_CAPTURE_IOS_STARTED: &str =
+        "Capturing all network traffic of this Mac, not only that of '{}', to {}, [Shift+Y] again stops";
+    pub const NETWORK_CAPTURE_SAVED: &str = "Saved the traffic of '{}' to {}";
+    pub const NETWORK_CAPTURE_FAILED: &str = "Network capture of '{}' failed: {}";
+    pub const APP_DATA_BACKED_UP: &str = "Backed up the data of {} on '{}' to {}";
+    pub const APP_DATA_RESTORED: &str = "Restored the data of {} on '{}' from {}";
+    pub const APP_DATA_BACKUP_FAILED: &str = "Failed to back up the data of {} on '{}': {}";
+    pub const APP_DATA_RESTORE_FAILED: &str = "Failed to restore the data of {} on '{}': {}";
+    pub const APP_COPIED: &str = "Installed {} of '{}' on '{}'";
+    pub const APP_COPIED_WITH_DATA: &str = "Installed {} of '{}' on '{}' with its data";
+    pub const APP_COPY_FAILED: &str = "Failed to copy {} to '{}': {}";
+    pub const APP_COPY_NO_TARGET: &str = "Start another device to copy the apps of '{}' to";
+    pub const MIRRORING_FAILED: &str = "Failed to mirror '{}': {}";
+    pub const MIRRORING_IOS_UNSUPPORTED: &str =
+        "scrcpy mirrors Android devices only, [o] opens the window of '{}'";
+    pub const HEADLESS_ALREADY_RUNNING: &str = "'{}' is already running, [o] opens its window";
+    pub const XCODE_SWITCHED: &str = "Using {} for simulator commands";
+    pub const XCODE_DEFAULT_RESTORED: &str = "Using the default Xcode for simulator commands";
+    pub const PRUNE_NOTHING_UNAVAILABLE: &str = "No unavailable iOS simulators to prune";
+    pub const PRUNE_IN_PROGRESS: &str = "Deleting unavailable iOS simulators...";
+    pub const PRUNE_SUCCEEDED: &str = "Removed {} unavailable iOS simulator(s)";
+    pub const PRUNE_PARTIAL: &str = "Removed {} of {} unavailable iOS simulator(s)";
+    pub const PRUNE_FAILED: &str = "Failed to prune unavailable iOS simulators: {}";
+    pub const PRUNE_NONE_CONFIRMED: &str = "Kept all unavailable iOS simulators";
+
+    // Auto-refresh
+    pub const AUTO_REFRESH_PAUSED: &str = "Auto-refresh paused, press [p] to resume";
+    pub const AUTO_REFRESH_RESUMED: &str = "Auto-refresh resumed";
+
+    // Project hooks ({hook} is the hook name)
+    pub const SNIPPET_NONE: &str =
+        "No snippets for this platform, add some under [snippets] in .emu.toml";
+    pub const SNIPPET_FINISHED: &str = "Snippet '{}' finished on '{}'";
+    pub const SNIPPET_FAILED: &str = "Snippet '{}' failed on '{}': {}";
+    pub const PROJECT_HOOK_FINISHED: &str = "Project hook {hook} finished";
+    pub const PROJECT_HOOK_FAILED: &str = "Project hook {hook} failed: {error}";
+
+    // Command display
+    pub const COMMAND_COPIED: &str = "Copied to clipboard: {}";
+    pub const COMMAND_COPY_FAILED: &str = "Clipboard unavailable, run it yourself: {}";
+
+    // Bug report bundles
+    pub const REPORT_SAVING: &str = "Collecting bug report...";
+    pub const REPORT_SAVED: &str = "Bug report saved to {}";
+    pub const REPORT_FAILED: &str = "Failed to save bug report: {}";
+    pub const COMMAND_NOT_READY: &str =
+        "Command not available yet, still resolving the system image";
 }
 
 /// Local environment check messages
@@ -69,6 +264,8 @@ pub mod checks {
         "Android manager ready: {device_count} device(s) discovered";
     pub const IOS_MANAGER_READY: &str = "iOS manager ready: {device_count} device(s) discovered";
     pub const IOS_MANAGER_SKIPPED: &str = "iOS manager check skipped: only available on macOS";
+    pub const ANDROID_SDK_READY: &str = "Android SDK '{name}' ready: {path}";
+    pub const ANDROID_SDK_UNAVAILABLE: &str = "Android SDK '{name}' unavailable: {error}";
     pub const APP_INITIALIZATION_READY: &str = "App initialization succeeded";
     pub const READY_TO_LAUNCH_TUI: &str = "Local check passed. Launch the TUI with: cargo run";
     pub const ANDROID_MANAGER_CONTEXT: &str = "Android manager initialization failed";
@@ -78,6 +275,54 @@ pub mod checks {
     pub const APP_INITIALIZATION_CONTEXT: &str = "Application initialization failed";
 }
 
+/// Operations `--read-only` turns off, as named in the error it reports
+pub mod read_only {
+    pub const CREATE_DEVICE: &str = "creating devices";
+    pub const DELETE_DEVICE: &str = "deleting devices";
+    pub const WIPE_DEVICE: &str = "wiping devices";
+    pub const INSTALL_SYSTEM_IMAGE: &str = "installing system images";
+    pub const UNINSTALL_SYSTEM_IMAGE: &str = "uninstalling system images";
+    pub const MOVE_DEVICE_DATA: &str = "moving device data";
+    pub const PRUNE_UNAVAILABLE: &str = "deleting unavailable simulators";
+    pub const INSTALL_RUNTIME: &str = "installing iOS runtimes";
+    pub const RESTORE_APP_DATA: &str = "restoring app data";
+    pub const INSTALL_APP: &str = "installing apps";
+}
+
+/// Operations that hold a device, as named when another one is refused
+pub mod device_busy {
+    pub const STARTING: &str = "starting";
+    pub const BOOTING: &str = "booting";
+    pub const STOPPING: &str = "stopping";
+    pub const RESTARTING: &str = "restarting";
+    pub const WIPING: &str = "wiping";
+    pub const DELETING: &str = "deleting";
+    pub const MOVING_DATA: &str = "moving its data";
+    pub const BACKING_UP_APP_DATA: &str = "backing up app data";
+    pub const RESTORING_APP_DATA: &str = "restoring app data";
+    pub const COPYING_APP: &str = "copying an app";
+    pub const INSTALLING_APP: &str = "installing an app";
+}
+
+/// `emu wait-for-boot` messages
+pub mod wait_for_boot {
+    pub const BOOTING: &str = "Booting {}...";
+    pub const BOOTED: &str = "{} finished booting";
+    pub const WAITING_FOR: &str = "Waiting for {check} on {device}...";
+    pub const READY: &str = "{} is ready";
+    pub const TIMED_OUT: &str = "Timed out after {seconds}s waiting for {device} to boot";
+    pub const TIMED_OUT_ON_CHECK: &str =
+        "Timed out after {seconds}s waiting for {check} on {device}";
+    pub const DEVICE_NOT_FOUND: &str = "No Android or iOS device named '{}'";
+    pub const AMBIGUOUS_DEVICE: &str = "Several iOS simulators are named '{}', pass a UDID instead";
+}
+
+/// `emu --zen-logs` messages
+pub mod zen_logs {
+    pub const NOT_RUNNING: &str = "{} is not running, start it to stream its logs";
+    pub const NO_EMULATOR_SERIAL: &str = "No running emulator found for {}";
+}
+
 /// UI labels and static text
 pub mod ui {
     // Window titles (Note: These are now dynamically generated in render.rs with version)
@@ -185,6 +430,32 @@ pub mod formats {
     pub const API_VERSION: &str = "API {} ({})";
     pub const DEVICE_WITH_OEM: &str = "{} ({})";
     pub const DPI_FORMAT: &str = "{} DPI";
+    /// Marker line written into a device log, numbered and timestamped
+    pub const LOG_MARKER: &str = "=== emu marker {} at {} ===";
+    /// Monkey output line shown in the log panel
+    pub const MONKEY_LOG_LINE: &str = "[monkey] {}";
+    /// Shell snippet command and output lines shown in the log panel
+    pub const SNIPPET_LOG_COMMAND: &str = "[snippet] $ {}";
+    pub const SNIPPET_LOG_LINE: &str = "[snippet] {}";
+    /// Console output line of a launched iOS app shown in the log panel
+    pub const APP_LOG_LINE: &str = "[app] {}";
+}
+
+/// Problems found by the launch diagnosis of an AVD
+pub mod launch_issues {
+    pub const MISSING_SYSTEM_IMAGE: &str = "System image {} is not installed";
+    pub const MISSING_SYSTEM_IMAGE_FIX: &str = "Install the image with sdkmanager";
+    pub const STALE_LOCKS: &str =
+        "{} lock file(s) left behind by an emulator that did not shut down cleanly";
+    pub const STALE_LOCKS_FIX: &str = "Remove the lock files";
+    pub const PORTS_IN_USE: &str = "Every emulator console port from {} to {} is in use";
+    pub const PORTS_IN_USE_FIX: &str =
+        "Stop another emulator or the process listening on these ports";
+    pub const LOW_DISK: &str = "Only {} MB free where the AVD is stored, {} MB recommended";
+    pub const LOW_DISK_FIX: &str = "Free up disk space, or move the AVD data with [Shift+M]";
+    pub const NO_ACCELERATION: &str = "Hardware acceleration is not usable: {}";
+    pub const NO_ACCELERATION_FIX: &str =
+        "Enable virtualization in the BIOS/UEFI and install KVM (Linux) or the Android Emulator hypervisor driver (Windows)";
 }
 
 /// Platform names
@@ -197,9 +468,6 @@ pub mod platforms {
 pub mod error_formatting {
     /// Maximum length for error messages before truncation
     pub const MAX_ERROR_MESSAGE_LENGTH: usize = 150;
-
-    /// Length at which to truncate error messages (allows for "..." suffix)
-    pub const ERROR_MESSAGE_TRUNCATED_LENGTH: usize = 147;
 }
 
 /// Validation error messages and hints
@@ -210,6 +478,7 @@ pub mod validation {
     pub const DEVICE_NAME_INVALID_CHARS_ERROR: &str =
         "Device name can only contain letters, numbers, dots, dashes, and underscores";
     pub const DEVICE_NAME_INVALID_START_ERROR: &str = "Device name cannot start with '.' or '-'";
+    pub const DEVICE_NAME_DUPLICATE_ERROR: &str = "A device named '{}' already exists";
     pub const DEVICE_NAME_HINT: &str = "Letters, numbers, dots, dashes, and underscores only";
 
     // Numeric validation messages
@@ -217,6 +486,8 @@ pub mod validation {
     pub const NUMERIC_VALUE_TOO_HIGH_ERROR: &str = "Value must be at most {} {}";
     pub const NUMERIC_VALUE_INVALID_ERROR: &str = "Please enter a valid number";
     pub const NUMERIC_VALUE_HINT: &str = "Enter a number or leave empty for default";
+    pub const RAM_EXCEEDS_HOST_MEMORY_WARNING: &str =
+        "RAM exceeds this machine's memory ({} total)";
 
     // Selection validation messages
     pub const REQUIRED_SELECTION_ERROR: &str = "Please select a {}";
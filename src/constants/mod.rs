@@ -39,6 +39,7 @@
 //! ```
 
 pub mod android;
+#[cfg(feature = "tui")]
 pub mod colors;
 pub mod commands;
 pub mod defaults;
@@ -61,6 +62,7 @@ pub mod ui_layout;
 pub mod ui_text;
 
 // Re-export commonly used constants for convenience
+#[cfg(feature = "tui")]
 pub use colors::*;
 pub use commands::*;
 pub use defaults::*;
@@ -40,9 +40,17 @@ lazy_static! {
     pub static ref EMULATOR_SERIAL: Regex =
         Regex::new(r"emulator-\d+").unwrap();
 
+    /// Pattern to extract the port from an "Address already in use" bind failure
+    pub static ref PORT_IN_USE: Regex =
+        Regex::new(r"(?:port\s+|:)(\d{2,5})\b.*[Aa]ddress already in use").unwrap();
+
     /// Pattern to parse system image package format
     pub static ref SYSTEM_IMAGE_PACKAGE: Regex =
         Regex::new(r"system-images;android-(\d+);([^;]+);([^;]+)").unwrap();
+
+    /// Pattern for validating Android package names, e.g. `com.example.app`
+    pub static ref JAVA_PACKAGE: Regex =
+        Regex::new(r"^[A-Za-z]\w*(\.[A-Za-z]\w*)+$").unwrap();
 }
 
 /// Pattern for validating device names
@@ -8,6 +8,10 @@ pub const MAX_EVENTS_PER_FRAME: usize = 50;
 /// UI update interval (16.67ms for ~60 FPS)
 pub const UI_UPDATE_INTERVAL: Duration = Duration::from_millis(17);
 
+/// System images parsed from streaming sdkmanager output between updates
+/// of the API level dialog
+pub const API_LEVEL_STREAM_BATCH: usize = 10;
+
 /// Device detail update debounce (100ms)
 pub const DETAIL_UPDATE_DEBOUNCE: Duration = Duration::from_millis(100);
 
@@ -26,6 +30,9 @@ pub const KEY_REPEAT_DELAY: Duration = Duration::from_millis(200);
 /// Keyboard navigation repeat rate (50ms)
 pub const KEY_REPEAT_RATE: Duration = Duration::from_millis(50);
 
+/// Time allowed between the two keys of a chord such as `g g` (500ms)
+pub const KEY_CHORD_TIMEOUT: Duration = Duration::from_millis(500);
+
 /// Target frame rate for rendering (125 FPS)
 pub const TARGET_FPS: u32 = 125;
 
@@ -35,6 +42,10 @@ pub const FRAME_TIME_MS: u64 = 1000 / TARGET_FPS as u64;
 /// Frame duration as Duration
 pub const FRAME_DURATION: Duration = Duration::from_millis(FRAME_TIME_MS);
 
+/// Upper bounds in milliseconds of the timing histogram buckets shown in the
+/// perf overlay; slower timings fall into one final bucket
+pub const METRIC_BUCKET_BOUNDS_MS: [u64; 12] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 4096];
+
 /// Fast panel switching optimization delays
 /// Device detail update debounce for fast panel switching (25ms)
 pub const FAST_DETAIL_UPDATE_DEBOUNCE: Duration = Duration::from_millis(25);
@@ -42,6 +53,13 @@ pub const FAST_DETAIL_UPDATE_DEBOUNCE: Duration = Duration::from_millis(25);
 /// Log update debounce for fast panel switching (50ms)
 pub const FAST_LOG_UPDATE_DEBOUNCE: Duration = Duration::from_millis(50);
 
+/// Wait after a device list first loads before the details of every device
+/// are read in the background
+pub const DEVICE_DETAILS_PREFETCH_DELAY: Duration = Duration::from_millis(300);
+
+/// Devices whose details are read at the same time while prefetching
+pub const DEVICE_DETAILS_PREFETCH_CONCURRENCY: usize = 4;
+
 /// Additional performance delays
 /// API installation completion delay
 pub const API_INSTALLATION_COMPLETION_DELAY: Duration = Duration::from_millis(500);
@@ -81,6 +99,10 @@ pub const INPUT_BATCH_DELAY: Duration = Duration::from_millis(1);
 /// Session cache TTL for Android SDK-backed list data.
 pub const ANDROID_SDK_LIST_CACHE_TTL: Duration = Duration::from_secs(5);
 
+/// How long a parsed `simctl list devices` snapshot is reused by actions
+/// (start, details) before they list devices again.
+pub const IOS_DEVICE_LIST_SNAPSHOT_TTL: Duration = Duration::from_secs(2);
+
 /// Minimum interval between full device metadata refreshes during auto-refresh.
 pub const FULL_DEVICE_REFRESH_INTERVAL: Duration = Duration::from_secs(15);
 
@@ -92,3 +114,6 @@ pub const API_LEVEL_DIALOG_OPEN_TARGET: Duration = Duration::from_millis(20);
 
 /// Target duration for opening the create-device dialog when caches are warm.
 pub const CREATE_DEVICE_DIALOG_OPEN_TARGET: Duration = Duration::from_millis(20);
+
+/// How often the size of a running network capture is read.
+pub const NETWORK_CAPTURE_SIZE_INTERVAL: Duration = Duration::from_secs(1);
@@ -12,6 +12,10 @@
 //! 4. Install (90-95%) - Installing to SDK location
 //! 5. Cleanup (95-100%) - Finalizing installation
 //!
+//! The percentage sdkmanager prints on its progress bar is placed within the
+//! phase its line names. The increments below only simulate progress until
+//! sdkmanager prints its first progress line.
+//!
 //! # Increment Values
 //!
 //! Each phase uses different increment values to ensure smooth progress:
@@ -20,19 +24,21 @@
 //! - Extract: 4% increments
 //! - Install: 5% increments
 //! - Cleanup: 3% increments
+//!
+//! # Device Creation Phases
+//!
+//! Device creation reports stages parsed from `avdmanager create avd` output:
+//! resolving the system image (5%), loading the local repository (10-30%),
+//! fetching the remote repository (30-50%), computing updates (50-60%),
+//! writing the hardware profile (70%) and the AVD files (80%), then applying
+//! emu's settings (90%).
 // API installation progress phase thresholds
 pub const DOWNLOAD_PHASE_START_PERCENTAGE: u8 = 20;
 pub const EXTRACT_PHASE_START_PERCENTAGE: u8 = 70;
 pub const INSTALL_PHASE_START_PERCENTAGE: u8 = 90;
 pub const COMPLETION_THRESHOLD_PERCENTAGE: u8 = 95;
 
-// Progress calculation constants
-pub const DOWNLOAD_PROGRESS_MULTIPLIER: u8 = 50;
-pub const DOWNLOAD_PROGRESS_DIVISOR: u8 = 100;
-
 // Additional progress phase values
-pub const PROGRESS_PHASE_75_PERCENT: u8 = 75;
-pub const PROGRESS_PHASE_85_PERCENT: u8 = 85;
 pub const PROGRESS_PHASE_100_PERCENT: u8 = 100;
 
 // Progress increment values for each phase
@@ -41,3 +47,13 @@ pub const DOWNLOAD_PHASE_INCREMENT: u8 = 3;
 pub const EXTRACT_PHASE_INCREMENT: u8 = 4;
 pub const INSTALL_PHASE_INCREMENT: u8 = 5;
 pub const CLEANUP_PHASE_INCREMENT: u8 = 3;
+
+// Device creation progress phase thresholds
+pub const CREATE_RESOLVE_PERCENTAGE: u8 = 5;
+pub const CREATE_LOADING_START_PERCENTAGE: u8 = 10;
+pub const CREATE_FETCH_START_PERCENTAGE: u8 = 30;
+pub const CREATE_COMPUTE_START_PERCENTAGE: u8 = 50;
+pub const CREATE_COMPUTE_END_PERCENTAGE: u8 = 60;
+pub const CREATE_PROFILE_PERCENTAGE: u8 = 70;
+pub const CREATE_WRITE_PERCENTAGE: u8 = 80;
+pub const CREATE_CONFIGURE_PERCENTAGE: u8 = 90;
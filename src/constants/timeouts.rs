@@ -8,6 +8,36 @@ pub const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(100);
 /// Maximum retry delay for command execution
 pub const MAX_RETRY_DELAY: Duration = Duration::from_secs(2);
 
+/// Per-attempt timeout for adb commands against an emulator that may still be booting
+pub const ADB_COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time a running device has to answer the health check before it counts as wedged
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Interval between health checks of the running devices
+pub const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Retries for adb commands that time out or fail while an emulator boots
+pub const ADB_COMMAND_MAX_RETRIES: u32 = 2;
+
+/// Time to wait for an emulator's gRPC endpoint before falling back to adb
+pub const EMULATOR_GRPC_CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Per-attempt timeout for sdkmanager commands that fetch repository data
+pub const SDKMANAGER_NETWORK_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Time the SDK repository has to accept a connection before emu works offline
+pub const NETWORK_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Time a download of the device catalog may take
+pub const DEVICE_CATALOG_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Time a shell snippet may run before it is killed
+pub const SNIPPET_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Retries for sdkmanager repository fetches
+pub const SDKMANAGER_NETWORK_MAX_RETRIES: u32 = 1;
+
 /// Thread sleep duration for event processing
 pub const EVENT_PROCESSING_SLEEP: Duration = Duration::from_millis(15);
 
@@ -41,8 +71,11 @@ pub const AVD_CREATION_WAIT_TIMEOUT: Duration = Duration::from_secs(2);
 /// Device status check delay (used in Android manager)
 pub const DEVICE_STATUS_CHECK_DELAY: Duration = Duration::from_millis(500);
 
-/// Device start wait time
-pub const DEVICE_START_WAIT_TIME: Duration = Duration::from_secs(2);
+/// How long deleting or wiping a running AVD waits for it to shut down
+pub const DEVICE_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Interval between simulated progress steps while sdkmanager installs
+pub const INSTALL_PROGRESS_TICK: Duration = Duration::from_secs(2);
 
 /// Log stream startup delay
 pub const LOG_STREAM_STARTUP_DELAY: Duration = Duration::from_millis(500);
@@ -56,6 +89,13 @@ pub const DEVICE_STOP_WAIT_TIME: Duration = Duration::from_millis(2000);
 /// Cache expiration time (5 minutes)
 pub const CACHE_EXPIRATION_TIME: Duration = Duration::from_secs(300);
 
+/// How long the on-disk device inventory is trusted at startup (24 hours)
+pub const DEVICE_INVENTORY_CACHE_TTL: Duration = Duration::from_secs(86400);
+
+/// How long an Android version name read from sdkmanager is trusted before it
+/// is looked up again (1 week)
+pub const ANDROID_VERSION_NAME_CACHE_TTL: Duration = Duration::from_secs(7 * 86400);
+
 /// Notification auto-dismiss time
 pub const NOTIFICATION_AUTO_DISMISS_TIME: Duration = Duration::from_secs(5);
 
@@ -71,6 +111,13 @@ pub const NOTIFICATION_CHECK_INTERVAL: Duration = Duration::from_millis(500);
 /// Event poll timeout (reduced for ultra-responsive input)
 pub const EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(8);
 
+/// Time without input after which emu goes idle: polling slows down and
+/// background refresh pauses until the next key press
+pub const IDLE_INPUT_THRESHOLD: Duration = Duration::from_secs(30);
+
+/// Event poll timeout while idle
+pub const IDLE_EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(250);
+
 /// Log task sleep duration
 pub const LOG_TASK_SLEEP_DURATION: Duration = Duration::from_millis(100);
 
@@ -79,3 +126,28 @@ pub const DEVICE_OPERATION_WAIT_TIME: Duration = Duration::from_millis(100);
 
 /// Panel switch delay
 pub const PANEL_SWITCH_DELAY: Duration = Duration::from_millis(50);
+
+/// Time a started AVD may take to show up as running before its launch is diagnosed
+pub const DEVICE_START_STALL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Time a stopped device may keep running before the stop is reported as failed
+pub const DEVICE_STOP_CONFIRM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest a started AVD refuses other operations while it has not finished booting
+pub const DEVICE_BOOT_LOCK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default time `emu wait-for-boot` waits for a device to finish booting
+pub const DEFAULT_WAIT_FOR_BOOT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Interval between boot status checks in `emu wait-for-boot`
+pub const WAIT_FOR_BOOT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Wait before `adb track-devices` is started again after it exited,
+/// e.g. because the adb server was restarted
+pub const DEVICE_WATCH_RESTART_DELAY: Duration = Duration::from_secs(5);
+
+/// How long quitting waits for cancelled background tasks to end
+pub const TASK_SHUTDOWN_GRACE: Duration = Duration::from_secs(1);
+
+/// How often quitting checks whether cancelled background tasks have ended
+pub const TASK_SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(10);
@@ -36,6 +36,13 @@ pub const ANDROID_PANEL_PERCENTAGE: u16 = 30;
 pub const IOS_PANEL_PERCENTAGE: u16 = 30;
 pub const DEVICE_DETAILS_PANEL_PERCENTAGE: u16 = 40;
 
+// Panel resizing limits; the percentages above are the defaults
+pub const PANEL_RESIZE_STEP: u16 = 5;
+pub const MIN_DEVICE_PANELS_PERCENTAGE: u16 = 20;
+pub const MAX_DEVICE_PANELS_PERCENTAGE: u16 = 80;
+pub const MIN_DETAILS_PANEL_PERCENTAGE: u16 = 20;
+pub const MAX_DETAILS_PANEL_PERCENTAGE: u16 = 60;
+
 // Panel switch delay
 pub const PANEL_SWITCH_DELAY_MS: u64 = 50;
 
@@ -46,6 +53,7 @@ pub const DIALOG_WIDTH_MEDIUM: u16 = 80;
 pub const DIALOG_HEIGHT_MEDIUM: u16 = 16;
 pub const DIALOG_WIDTH_LARGE: u16 = 90;
 pub const DIALOG_HEIGHT_LARGE: u16 = 26;
+pub const CREATE_DIALOG_PREVIEW_WIDTH: u16 = 44;
 
 // Minimum terminal dimensions
 pub const MIN_TERMINAL_WIDTH: u16 = 40;
@@ -58,8 +66,17 @@ pub const SEPARATOR_LENGTH: u16 = 30;
 
 // Character display limits
 pub const DEVICE_TYPE_DISPLAY_MAX_LENGTH: usize = 25;
-pub const DEVICE_TYPE_TRUNCATED_LENGTH: usize = 22;
-pub const ERROR_MESSAGE_TRUNCATED_LENGTH: usize = 147;
+/// Device names are never cut below this width to make room for badges
+pub const MIN_DEVICE_NAME_DISPLAY_WIDTH: usize = 10;
+
+/// Optional device list columns are dropped before names get narrower
+pub const DEVICE_COLUMN_NAME_WIDTH: usize = 24;
+// Default maximum widths of the device list columns, in terminal cells
+pub const DEVICE_COLUMN_STATUS_WIDTH: usize = 5;
+pub const DEVICE_COLUMN_VERSION_WIDTH: usize = 10;
+pub const DEVICE_COLUMN_ABI_WIDTH: usize = 11;
+pub const DEVICE_COLUMN_RAM_WIDTH: usize = 8;
+pub const DEVICE_COLUMN_UPTIME_WIDTH: usize = 14;
 
 // Dialog and UI element margins
 pub const DIALOG_MARGIN: u16 = 4;
@@ -68,7 +85,6 @@ pub const LOADING_INDICATOR_MARGIN: u16 = 3;
 // Log display dimensions
 pub const LOG_TIMESTAMP_WIDTH: usize = 9;
 pub const LOG_LEVEL_WIDTH: usize = 9;
-pub const MESSAGE_TRUNCATE_SUFFIX_LENGTH: usize = 3;
 
 // Notification dimensions
 pub const NOTIFICATION_HEIGHT: u16 = 4;
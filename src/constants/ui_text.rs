@@ -10,6 +10,9 @@ pub mod status_indicators {
 
     /// Inactive/stopped device indicator
     pub const INACTIVE_INDICATOR: &str = "○";
+
+    /// Marker for devices pinned by the project's `.emu.toml`
+    pub const PINNED_INDICATOR: &str = "📌 ";
 }
 
 /// Navigation arrows and scroll indicators
@@ -47,6 +50,9 @@ pub mod shortcuts {
     /// Create device shortcut
     pub const CREATE: &str = "➕ [c]reate";
 
+    /// Duplicate selected device into the create form shortcut
+    pub const DUPLICATE: &str = "👯 [Shift+C]duplicate";
+
     /// Delete device shortcut
     pub const DELETE: &str = "❌ [d]elete";
 
@@ -56,11 +62,254 @@ pub mod shortcuts {
     /// Install packages shortcut
     pub const INSTALL: &str = "📦 [i]nstall";
 
+    /// Shown instead of the create, delete, wipe and install shortcuts
+    /// with `--read-only`
+    pub const READ_ONLY: &str = "🔒 read-only";
+
+    /// Show and copy the device's start command shortcut
+    pub const COPY_COMMAND: &str = "📋 [y]ank command";
+
+    /// Command history shortcut
+    pub const COMMAND_HISTORY: &str = "📜 [Shift+H]istory";
+
+    /// Details panel tab switching shortcut
+    pub const DETAILS_TABS: &str = "🗂 [[/]]detail tabs";
+
+    /// Device-lists-only display shortcut
+    pub const FULLSCREEN_DEVICES: &str = "🔲 [Shift+D]evices only";
+
+    /// Panel resizing shortcut
+    pub const RESIZE_PANELS: &str = "📐 [+/-/</>]resize [0]reset";
+
+    /// Move Android device data shortcut
+    pub const MOVE_DATA: &str = "🚚 [Shift+M]ove data";
+
+    /// Android bug report shortcut
+    pub const BUGREPORT: &str = "🐞 [Shift+B]ugreport";
+
+    /// iOS `simctl diagnose` shortcut
+    pub const DIAGNOSE: &str = "🐞 [Shift+B] diagnose";
+
+    /// Perfetto trace or Instruments time profile shortcut
+    pub const TRACE: &str = "⏱ [Shift+J] trace";
+
+    /// Apply/restore UI test settings shortcut
+    pub const TEST_SETTINGS: &str = "🧪 [Shift+T]est settings";
+
+    /// Process inspector shortcut
+    pub const PROCESSES: &str = "🩺 [Shift+K] processes";
+
+    /// System property browser shortcut (Android only)
+    pub const PROPERTIES: &str = "⚙ [g]etprop/setprop";
+
+    /// Monkey stress test shortcut (Android only)
+    pub const MONKEY: &str = "🐒 [Shift+S]tress test";
+
+    /// Quick Boot snapshot and cold boot options (Android only)
+    pub const QUICK_BOOT: &str = "⚡ [s]napshot/cold boot";
+
+    /// Set or shift the device clock
+    pub const CLOCK: &str = "🕒 [Shift+N]ow/clock";
+
+    /// Forward keystrokes to the device (Android only)
+    pub const TYPE_INTO_DEVICE: &str = "⌨ [Shift+I]nput typing";
+
+    /// Open or close a scrcpy window for the device (Android only)
+    pub const MIRROR: &str = "📺 [v] mirror [Shift+V] + record";
+
+    /// Switch Wi-Fi, Bluetooth and airplane mode (Android only)
+    pub const RADIOS: &str = "📶 [Shift+O] radios";
+
+    /// Start or stop writing the device's network traffic to a pcap
+    pub const NETWORK_CAPTURE: &str = "📡 [Shift+Y] network capture";
+
+    /// Switch overdraw, layout bounds, Metal HUD and similar debugging aids
+    pub const DEVELOPER_TOGGLES: &str = "🎛 [Shift+U] developer toggles";
+
+    /// Back up an app's data, restore a backup of it, or copy the app to
+    /// another device
+    pub const APP_DATA: &str = "💾 [Shift+G] app backup/copy";
+
+    /// Create a recommended configuration (Android only)
+    pub const DEVICE_CATALOG: &str = "📚 [e] device catalog";
+
+    /// Create every combination of some device types and versions
+    pub const TEST_MATRIX: &str = "🧮 [x] test matrix";
+
+    /// Run a shell one-liner against the selected device
+    pub const SNIPPETS: &str = "🧾 [z] snippets";
+
+    /// Launch an app with arguments and environment variables
+    pub const LAUNCH_APP: &str = "▶ [Shift+A]pp launch";
+
+    /// Boot a throwaway copy of the selected simulator (iOS only)
+    pub const EPHEMERAL_CLONE: &str = "🧬 [u] ephemeral clone";
+
+    /// Boot the selected AVD without keeping its changes (Android only)
+    pub const READ_ONLY_BOOT: &str = "🧬 [u] read-only boot";
+
+    /// Start the devices of the selected device's test matrix group
+    pub const BOOT_GROUP: &str = "🚦 [Shift+E] boot group";
+
+    /// Kill and reboot a running device, e.g. a wedged one
+    pub const FORCE_RESTART: &str = "🔨 [Shift+W] force restart";
+
+    /// Intent launcher shortcut (Android only)
+    pub const SEND_INTENT: &str = "📨 [a]ctivity/intent";
+
+    /// Pause/resume auto-refresh shortcut
+    pub const PAUSE_REFRESH: &str = "⏸ [p]ause refresh";
+
+    /// Device tags and note editor shortcut
+    pub const ANNOTATE: &str = "🏷 [t]ags/note";
+
+    /// Device search shortcut
+    pub const SEARCH: &str = "🔍 [/]search [n]ext";
+
     /// Complete shortcut text for Android normal mode
-    pub const ANDROID_NORMAL_MODE_SHORTCUTS: &str = "🔄 [r]efresh  🔀 [Tab]switch panels  🔁 [h/l/←/→]switch  🚀 [Enter]start/stop  🔃 [k/j/↑/↓]move  ➕ [c]reate  ❌ [d]elete  🧹 [w]ipe  📦 [i]nstall";
+    pub const ANDROID_NORMAL_MODE_SHORTCUTS: &str = "🔄 [r]efresh  🔀 [Tab]switch panels  🔁 [h/l/←/→]switch  🚀 [Enter]start/stop  🪟 [o]pen window  🧬 [u] read-only boot  🧾 [z] snippets  ▶ [Shift+A]pp launch  🔃 [k/j/↑/↓]move  🔍 [/]search [n]ext  ➕ [c]reate  👯 [Shift+C]duplicate  📚 [e] device catalog  📥 [Shift+Q]ueue  🧮 [x] test matrix  🚦 [Shift+E] boot group  ❌ [d]elete  🧹 [w]ipe  🚚 [Shift+M]ove data  🧪 [Shift+T]est settings  🐞 [Shift+B]ugreport  ⏱ [Shift+J] trace  🎛 [Shift+U] developer toggles  📡 [Shift+Y] network capture  💾 [Shift+G] app backup/copy  🩺 [Shift+K] processes  ⚙ [g]etprop/setprop  🐒 [Shift+S]tress test  ⚡ [s]napshot/cold boot  🕒 [Shift+N]ow/clock  ⌨ [Shift+I]nput typing  📺 [v] mirror [Shift+V] + record  📶 [Shift+O] radios  🔨 [Shift+W] force restart  📨 [a]ctivity/intent  📦 [i]nstall  📋 [y]ank command  📜 [Shift+H]istory  🗂 [[/]]detail tabs  🏷 [t]ags/note  🔲 [Shift+D]evices only  📐 [+/-/</>]resize [0]reset  ⏸ [p]ause refresh";
 
     /// Complete shortcut text for iOS normal mode
-    pub const IOS_NORMAL_MODE_SHORTCUTS: &str = "🔄 [r]efresh  🔀 [Tab]switch panels  🔁 [h/l/←/→]switch  🚀 [Enter]start/stop  🔃 [k/j/↑/↓]move  ➕ [c]reate  ❌ [d]elete  🧹 [w]ipe";
+    pub const IOS_NORMAL_MODE_SHORTCUTS: &str = "🔄 [r]efresh  🔀 [Tab]switch panels  🔁 [h/l/←/→]switch  🚀 [Enter]start/stop  🪟 [o]pen window  👻 [b] headless boot  🧬 [u] ephemeral clone  🧾 [z] snippets  ▶ [Shift+A]pp launch  🔃 [k/j/↑/↓]move  🔍 [/]search [n]ext  ➕ [c]reate  👯 [Shift+C]duplicate  📥 [Shift+Q]ueue  🧮 [x] test matrix  🚦 [Shift+E] boot group  ❌ [d]elete  🧹 [w]ipe  🩹 [Shift+R]epair  🧽 [Shift+X] prune unavailable  🧪 [Shift+T]est settings  🐞 [Shift+B] diagnose  ⏱ [Shift+J] trace  🎛 [Shift+U] developer toggles  📡 [Shift+Y] network capture  💾 [Shift+G] app backup/copy  🩺 [Shift+K] processes  🕒 [Shift+N]ow/clock  🔨 [Shift+W] force restart  📋 [y]ank command  📜 [Shift+H]istory  🗂 [[/]]detail tabs  🏷 [t]ags/note  🔲 [Shift+D]evices only  📐 [+/-/</>]resize [0]reset  ⏸ [p]ause refresh";
+}
+
+/// Device tags and notes editor, search prompt, and details lines
+pub mod annotations {
+    /// Editor dialog title ({} is replaced with the device name)
+    pub const DIALOG_TITLE: &str = "🏷 Tags & note: {}";
+
+    /// Label of the tags field
+    pub const TAGS_FIELD: &str = "Tags (comma-separated)";
+
+    /// Label of the note field
+    pub const NOTE_FIELD: &str = "Note";
+
+    /// Editor key hints
+    pub const DIALOG_SHORTCUTS: &str = "[Tab]switch field  [Enter]save  [Esc]cancel";
+
+    /// Search prompt title
+    pub const SEARCH_TITLE: &str = "🔍 Search devices by name, tag, or note";
+
+    /// Search prompt key hints
+    pub const SEARCH_SHORTCUTS: &str = "[Enter]done  [Esc]cancel  [n]next match afterwards";
+
+    /// Shown when no device in the active panel matches the query
+    pub const SEARCH_NO_MATCH: &str = "no match";
+
+    /// Details label for the device tags
+    pub const TAGS_LABEL: &str = "🏷  Tags: ";
+
+    /// Details label for the device note
+    pub const NOTE_LABEL: &str = "📝 Note: ";
+}
+
+/// Uptime and last-used hints for device rows and details
+pub mod device_usage {
+    /// Uptime column of a running device ({} is replaced with the uptime)
+    pub const ROW_UPTIME: &str = "up {}";
+
+    /// Uptime column of a stopped device ({} is replaced with the idle time)
+    pub const ROW_LAST_USED: &str = "used {} ago";
+
+    /// Details label for the uptime of a running device
+    pub const UPTIME_LABEL: &str = "⏱  Uptime: ";
+
+    /// Details label for the last use of a stopped device
+    pub const LAST_USED_LABEL: &str = "🕒 Last used: ";
+
+    /// Details value for the last use ({} is replaced with the idle time)
+    pub const LAST_USED_AGO: &str = "{} ago";
+}
+
+/// Window state of running iOS simulators in the details panel
+pub mod simulator_window {
+    /// Details label for the window state
+    pub const WINDOW_LABEL: &str = "🪟 Window: ";
+
+    /// Booted without Simulator.app
+    pub const HEADLESS: &str = "Headless ([o] opens Simulator.app)";
+
+    /// Shown in Simulator.app
+    pub const WINDOWED: &str = "Simulator.app";
+}
+
+/// Running network capture in the device details
+pub mod network_capture {
+    /// Details label, followed by the pcap's size and path
+    pub const CAPTURE_LABEL: &str = "📡 Capturing: ";
+}
+
+/// Refresh status shown in the header
+pub mod refresh_status {
+    /// Shown while a manual refresh is running (prefixed with the spinner)
+    pub const REFRESHING: &str = "refreshing...";
+
+    /// Shown while background polling is paused
+    pub const PAUSED: &str = "⏸ auto-refresh paused";
+
+    /// Time since the last refresh ({} is replaced with the age)
+    pub const REFRESHED_AGO: &str = "refreshed {} ago";
+
+    /// Shown while the device lists come from the saved inventory
+    pub const CACHED: &str = "cached";
+}
+
+/// Device details panel tabs
+pub mod details_tabs {
+    /// Shown while tab content is being fetched
+    pub const LOADING: &str = "Loading...";
+
+    /// The device has no third-party apps installed
+    pub const NO_APPS: &str = "No third-party apps installed";
+
+    /// No commands have been run against the device this session
+    pub const NO_HISTORY: &str = "No operations recorded for this device";
+
+    /// The emulator reported no sensors
+    pub const NO_SENSORS: &str = "No sensors reported";
+
+    /// Sensor state has no simctl equivalent
+    pub const SENSORS_ANDROID_ONLY: &str = "Sensor state is only available for Android emulators";
+
+    /// No emulator output was captured for the device
+    pub const NO_LAUNCH_OUTPUT: &str =
+        "No emulator output captured, it is kept for devices started from emu";
+
+    /// Simulators are not launched through a console process
+    pub const OUTPUT_ANDROID_ONLY: &str = "Launch output is only captured for Android emulators";
+
+    /// Tab switching hint shown under the tab bar
+    pub const TAB_HINT: &str = "[ / ] switch tabs";
+}
+
+/// Global status bar segments
+pub mod status_bar {
+    /// Running Android devices ({} is replaced with the count)
+    pub const ANDROID_RUNNING: &str = "🤖 {} running";
+
+    /// Running iOS simulators ({} is replaced with the count)
+    pub const IOS_RUNNING: &str = "🍎 {} running";
+
+    /// Background operations in flight ({} is replaced with the count)
+    pub const ACTIVE_OPERATIONS: &str = "⚙ {} active";
+
+    /// Current log filter ({} is replaced with the level)
+    pub const LOG_FILTER: &str = "filter: {}";
+
+    /// Shown while a screen recording is running
+    pub const RECORDING: &str = "⏺ REC";
+
+    /// adb server answered the last device query
+    pub const ADB_HEALTHY: &str = "adb ✓";
+
+    /// adb server did not answer the last device query
+    pub const ADB_UNHEALTHY: &str = "adb ✗";
+
+    /// adb has not been queried yet
+    pub const ADB_UNKNOWN: &str = "adb …";
+
+    /// Separator between segments
+    pub const SEPARATOR: &str = "  │  ";
 }
 
 /// Architecture identifiers
@@ -118,6 +367,39 @@ pub mod device_states {
 
     /// iOS unavailable suffix
     pub const IOS_UNAVAILABLE: &str = " (unavailable)";
+
+    /// iOS unavailable suffix with the reason reported by simctl
+    pub const IOS_UNAVAILABLE_REASON: &str = " (unavailable: {})";
+
+    /// Badge of a running device that did not answer the health check
+    pub const WEDGED_BADGE: &str = " wedged ";
+
+    /// Badge of a simulator clone that is deleted once it stops
+    pub const EPHEMERAL_BADGE: &str = " ephemeral ";
+}
+
+/// What runs against a device, shown on its row after the spinner
+pub mod device_activity {
+    pub const BOOTING: &str = "booting";
+    pub const COLLECTING_REPORT: &str = "collecting report";
+    pub const RECORDING_TRACE: &str = "recording trace";
+    pub const MOVING_DATA: &str = "moving data";
+    pub const BACKING_UP_APP_DATA: &str = "backing up app data";
+    pub const RESTORING_APP_DATA: &str = "restoring app data";
+    pub const COPYING_APP: &str = "copying app";
+    pub const INSTALLING_APP: &str = "installing app";
+
+    /// Shown without a spinner for as long as a network capture runs
+    pub const CAPTURING: &str = "📡 capturing";
+}
+
+/// iOS panel states before the Xcode tooling is ready
+pub mod ios_tooling {
+    /// Panel title while `xcrun` is being resolved
+    pub const DETECTING_TITLE: &str = "🍎 iOS (detecting…)";
+
+    /// Panel title when the Xcode tooling is missing or broken
+    pub const UNAVAILABLE_TITLE: &str = "🍎 iOS (unavailable)";
 }
 
 /// Progress and loading text
@@ -141,7 +423,10 @@ pub mod progress {
     pub const PROCESSING: &str = "Processing... Please wait...";
 
     /// Processing with waiting indicator
-    pub const PROCESSING_WAIT: &str = "⏳ Processing... Please wait...";
+    pub const PROCESSING_WAIT: &str = "⏳ Processing... Please wait...  [Esc] Cancel";
+
+    /// Shown after Esc during a creation or install, before it is cancelled
+    pub const CONFIRM_CANCEL: &str = "Press Esc again to cancel, any other key to keep going";
 }
 
 /// Notification icons
@@ -175,14 +460,537 @@ pub mod api_management {
         "✅ Green = Installed  📦 Gray = Available  Select and press Enter/d";
 
     /// API management navigation (installed packages)
-    pub const NAV_UNINSTALL: &str = "[↑/↓/j/k] Navigate  [d] Uninstall Selected  [Esc] Cancel";
+    pub const NAV_UNINSTALL: &str =
+        "[↑/↓/j/k] Navigate  [d] Uninstall Selected  [y] Copy Command  [Esc] Cancel";
 
     /// API management navigation (available packages)
-    pub const NAV_INSTALL: &str = "[↑/↓/j/k] Navigate  [Enter] Install Selected  [Esc] Cancel";
+    pub const NAV_INSTALL: &str =
+        "[↑/↓/j/k] Navigate  [Space] Mark  [Enter] Install Selected  [y] Copy Command  [Esc] Cancel";
+
+    /// API management navigation (available packages, while offline)
+    pub const NAV_INSTALL_OFFLINE: &str =
+        "[↑/↓/j/k] Navigate  [y] Copy Command  [Esc] Cancel  (offline: downloads unavailable)";
+
+    /// Appended to the dialog title while the SDK repository cannot be reached
+    pub const OFFLINE_BADGE: &str = " · offline";
+
+    /// API management navigation (images marked for install)
+    pub const NAV_INSTALL_MARKED: &str =
+        "[↑/↓/j/k] Navigate  [Space] Mark/Unmark  [Enter] Install {} Marked  [Esc] Cancel";
+
+    /// Row markers for images marked for install or in the install queue
+    pub const ROW_MARKED: &str = "  [marked]";
+    pub const ROW_QUEUED: &str = "  [queued]";
+    pub const ROW_INSTALLING: &str = "  [installing {}%]";
+    pub const ROW_INSTALL_FAILED: &str = "  [failed]";
+
+    /// Position in the install queue, before the progress of the current image
+    pub const QUEUE_POSITION: &str = "[{}/{}] ";
+
+    /// Download speed of the image being installed, after the progress
+    pub const TRANSFER_SPEED: &str = " · {}/s";
+
+    /// Time left for the image, or for the whole install queue, after the
+    /// progress
+    pub const INSTALL_TIME_LEFT: &str = " · about {} left";
 
     /// API management navigation (general)
     pub const NAV_GENERAL: &str =
         "[↑/↓/j/k] Navigate  [Enter] Install  [d] Uninstall  [Esc] Cancel";
+
+    /// Size of an available image's download
+    pub const DOWNLOAD_SIZE: &str = "~{} download";
+
+    /// Filter, sort and search line above the list ({} are replaced with
+    /// the filter, the sort order and the search query)
+    pub const VIEW_OPTIONS: &str = "[f] Show: {}  [s] Sort: {}  [/] Search: {}";
+
+    /// Cursor after the search query while it is typed
+    pub const SEARCH_CURSOR: &str = "_";
+
+    /// Shown when no API level passes the filter and the search
+    pub const NO_MATCHING_API_LEVELS: &str = "No system images match the filter or search.";
+
+    /// Search prompt navigation
+    pub const NAV_SEARCH: &str = "Type to search  [Enter] Done  [Esc] Clear";
+
+    /// Filter labels
+    pub const FILTER_ALL: &str = "all";
+    pub const FILTER_INSTALLED: &str = "installed";
+    pub const FILTER_AVAILABLE: &str = "available";
+
+    /// Sort order labels
+    pub const SORT_API_LEVEL: &str = "API level";
+    pub const SORT_SIZE: &str = "size";
+
+    /// Total disk space of installed images, shown under the list
+    pub const DISK_USED: &str = "Disk used: {}";
+
+    /// Warning shown before uninstalling an image that AVDs still use
+    pub const UNINSTALL_DEPENDENTS_WARNING: &str =
+        "⚠ Used by {}. They will not start once this image is uninstalled.";
+
+    /// Names the image [r] moves the AVDs to
+    pub const UNINSTALL_RETARGET_HINT: &str = "[r] moves them to {}.";
+
+    /// Choices for an image that AVDs still use
+    pub const NAV_UNINSTALL_DEPENDENTS: &str =
+        "[r] Retarget Devices  [x] Delete Devices  [u] Uninstall Anyway  [Esc] Keep Image";
+
+    /// Choices for an image that AVDs still use, with no other image installed
+    pub const NAV_UNINSTALL_DEPENDENTS_NO_RETARGET: &str =
+        "[x] Delete Devices  [u] Uninstall Anyway  [Esc] Keep Image";
+}
+
+/// Command history dialog text
+pub mod command_history {
+    /// Shown when no command has been executed yet
+    pub const EMPTY_HISTORY: &str = "No commands executed yet";
+
+    /// Prefix for the audit log file location
+    pub const LOG_FILE_PREFIX: &str = "Log file: ";
+
+    /// Dialog navigation
+    pub const HISTORY_SHORTCUTS: &str = "[↑/↓/j/k] Scroll  [r] Reload  [Esc] Close";
+}
+
+/// Diagnostics viewer text
+pub mod diagnostics {
+    /// Shown when emu has not logged anything yet
+    pub const EMPTY_DIAGNOSTICS: &str = "No diagnostics recorded yet";
+
+    /// Dialog navigation
+    pub const DIAGNOSTICS_SHORTCUTS: &str =
+        "[↑/↓/j/k] Scroll  [r] Reload  [s] Save bug report  [Esc/F12] Close";
+
+    /// Dialog navigation when more than one Xcode is installed
+    pub const DIAGNOSTICS_SHORTCUTS_WITH_XCODE: &str =
+        "[↑/↓/j/k] Scroll  [r] Reload  [s] Save bug report  [x] Switch Xcode  [Esc/F12] Close";
+
+    /// Active Xcode line, {} is replaced with the developer directory
+    pub const XCODE_LABEL: &str = "Xcode: {}";
+
+    /// Where the active Xcode was chosen
+    pub const XCODE_SOURCE_EMU: &str = " (selected in emu)";
+    pub const XCODE_SOURCE_ENV: &str = " (DEVELOPER_DIR)";
+    pub const XCODE_SOURCE_SELECT: &str = " (xcode-select)";
+
+    /// Shown when the developer directory could not be determined
+    pub const XCODE_UNKNOWN: &str = "unknown";
+}
+
+/// Recommended device catalog
+pub mod device_catalog {
+    pub const TITLE: &str = "📚 Device Catalog";
+
+    /// Image line of an entry: API level, tag and ABI
+    pub const IMAGE: &str = "API {} · {} · {}";
+
+    /// Progress lines, {} is replaced with the entry name
+    pub const REFRESHING: &str = "Downloading the latest catalog...";
+    pub const INSTALLING_IMAGE: &str = "Installing the system image for '{}'...";
+    pub const CREATING: &str = "Creating '{}'...";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[↑/↓/j/k] Select  [Enter] Create  [r] Refresh  [Esc] Close";
+    pub const SHORTCUTS_BUSY: &str = "[Esc] Close, it keeps running in the background";
+}
+
+/// Shell snippet picker
+pub mod snippets {
+    /// Picker title ({} is replaced with the device name)
+    pub const TITLE: &str = "🧾 Snippets: {}";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str =
+        "[↑/↓/j/k] Select  [Enter] Run, output goes to the log  [Esc] Close";
+}
+
+/// App launcher with arguments and environment
+pub mod launch_app {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "▶ Launch app: {}";
+
+    /// Field titles
+    pub const APP_FIELD: &str = "App (↑/↓ installed apps)";
+    pub const ANDROID_ARGUMENTS_FIELD: &str =
+        "Extras (key=value, comma-separated; i:/l:/f:/b: for numbers and booleans)";
+    pub const IOS_ARGUMENTS_FIELD: &str = "Arguments (e.g. -UITests YES)";
+    pub const ENVIRONMENT_FIELD: &str = "Environment (KEY=VALUE, space-separated)";
+
+    /// Explains where the app's output goes
+    pub const ANDROID_HINT: &str =
+        "The app is force-stopped and its launcher activity started with the extras.";
+    pub const IOS_HINT: &str =
+        "The app is restarted and its console output streams into the log panel, [Shift+A] stops it.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[Tab] Next field  [Enter] Launch  [Esc] Cancel";
+}
+
+/// Perfetto trace and Instruments time profile capture
+pub mod trace {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "⏱ Trace: {}";
+
+    /// Field titles
+    pub const ANDROID_TARGET_FIELD: &str =
+        "App (↑/↓ installed packages, empty for the whole system)";
+    pub const IOS_TARGET_FIELD: &str = "Process (name or PID, empty for all processes)";
+    pub const DURATION_FIELD: &str = "Duration (seconds)";
+
+    /// Explains what gets recorded
+    pub const ANDROID_HINT: &str =
+        "Records a Perfetto system trace with adb shell perfetto, to open in ui.perfetto.dev.";
+    pub const IOS_HINT: &str =
+        "Records an Instruments Time Profiler trace with xcrun xctrace record, to open in Instruments.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[Tab] Next field  [Enter] Record  [Esc] Cancel";
+}
+
+/// Test matrix wizard
+pub mod test_matrix {
+    pub const TITLE: &str = "🧮 Test Matrix";
+    pub const DEVICE_TYPES: &str = "Device types";
+    pub const API_LEVELS: &str = "API levels";
+    pub const RUNTIMES: &str = "Runtimes";
+    pub const GROUP: &str = "Group";
+    pub const LOADING: &str = "Loading device types and versions...";
+
+    /// Size of the marked matrix
+    pub const SUMMARY: &str = "{devices} devices: {types} device types × {versions} versions";
+
+    /// Why Enter did nothing
+    pub const NOTHING_MARKED: &str = "Mark at least one device type and one version";
+    pub const GROUP_REQUIRED: &str = "Name the group the devices join";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str =
+        "[Tab] Next list  [Space] Mark  [↑/↓/j/k] Select  [Enter] Create all  [Esc] Cancel";
+}
+
+/// Device creation queue view text
+pub mod creation_queue {
+    /// Shown when nothing has been queued
+    pub const EMPTY_QUEUE: &str =
+        "No queued creations. Press Ctrl+a in the create dialog to queue one";
+
+    /// Status labels
+    pub const STATUS_PENDING: &str = "Pending";
+    pub const STATUS_CREATING: &str = "Creating";
+    pub const STATUS_COMPLETED: &str = "Created";
+    pub const STATUS_FAILED: &str = "Failed";
+
+    /// Dialog navigation
+    pub const QUEUE_SHORTCUTS: &str = "[↑/↓/j/k] Scroll  [c] Clear finished  [Esc] Close";
+}
+
+/// Repair dialog for unavailable simulators
+pub mod repair_device {
+    /// Dialog title
+    pub const TITLE: &str = "🩹 Repair Simulator";
+
+    /// First line, {} is replaced with the device name
+    pub const UNAVAILABLE: &str = "{} is unavailable";
+
+    /// Reason line, {} is replaced with simctl's explanation
+    pub const REASON: &str = "Reason: {}";
+
+    /// Shown when simctl gave no explanation
+    pub const UNKNOWN_REASON: &str = "not reported by simctl";
+
+    /// Hint for a simulator whose runtime is not installed, {} is the iOS version
+    pub const RUNTIME_MISSING_HINT: &str =
+        "The iOS {} runtime is not installed. Install it through Xcode or delete the simulator.";
+
+    /// Hint for a simulator the installed Xcode cannot run
+    pub const UNSUPPORTED_HINT: &str =
+        "The installed Xcode cannot run this simulator. Delete it and create a new one.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS_WITH_INSTALL: &str =
+        "[i] Install runtime  [d] Delete simulator  [Esc] Cancel";
+    pub const SHORTCUTS: &str = "[d] Delete simulator  [Esc] Cancel";
+}
+
+/// Prompt for moving an AVD's data directory to another disk
+pub mod move_device_data {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🚚 Move data: {}";
+
+    /// Current location line, {} is replaced with the data directory
+    pub const CURRENT_LOCATION: &str = "Now in: {}";
+
+    /// Label of the target directory field
+    pub const TARGET_FIELD: &str = "Move into directory (absolute or ~/...)";
+
+    /// Where the data ends up, {} is replaced with the new data directory
+    pub const RESULT_LOCATION: &str = "New location: {}";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[Enter] Move  [Esc] Cancel";
+}
+
+/// Launcher that sends an intent to a running AVD
+pub mod intent {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "📨 Send intent: {}";
+
+    /// Field labels
+    pub const KIND_FIELD: &str = "Send as";
+    pub const ACTION_FIELD: &str = "Action (-a)";
+    pub const DATA_URI_FIELD: &str = "Data URI (-d)";
+    pub const COMPONENT_FIELD: &str = "Component (-n), package/.Activity";
+    pub const EXTRAS_FIELD: &str = "Extras: key=value, key=i:1, key=b:true";
+
+    /// Kind selector values
+    pub const KIND_ACTIVITY: &str = "am start (activity)";
+    pub const KIND_BROADCAST: &str = "am broadcast";
+
+    /// Position in the history, {} is replaced with "<index>/<count>"
+    pub const HISTORY_POSITION: &str = "History {}";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str =
+        "[Tab]field  [Space/←/→]kind  [↑/↓]history  [Enter]send  [Esc]cancel";
+}
+
+/// Process inspector of a running device
+pub mod processes {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🩺 Processes: {}";
+
+    /// Tab labels
+    pub const PROCESSES_TAB: &str = "Processes";
+    pub const SERVICES_TAB: &str = "Services";
+
+    /// Column headers
+    pub const PROCESS_HEADER: &str = "    PID  MEMORY  USER          NAME";
+    pub const SERVICE_HEADER: &str = "    PID  MEMORY  SERVICE";
+
+    /// Shown while the lists are being fetched
+    pub const LOADING: &str = "Loading...";
+
+    /// Shown when a list is empty
+    pub const EMPTY: &str = "Nothing running";
+
+    /// Current order, {} is replaced with "memory" or "name"
+    pub const SORTED_BY: &str = "Sorted by {}";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str =
+        "[Tab] Processes/Services  [↑/↓/j/k] Select  [s] Sort  [x] Kill  [r] Reload  [Esc] Close";
+}
+
+/// System property browser
+pub mod properties {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "⚙ System properties: {}";
+
+    /// Search input title
+    pub const SEARCH_FIELD: &str = "Search";
+
+    /// Edit input title, {} is replaced with the property name
+    pub const EDIT_FIELD: &str = "New value of {}";
+
+    /// Marker of properties that cannot be set
+    pub const READ_ONLY: &str = "🔒";
+
+    /// Shown while the properties are being fetched
+    pub const LOADING: &str = "Loading...";
+
+    /// Shown when no property matches the search
+    pub const EMPTY: &str = "No matching properties";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str =
+        "[type] Search  [↑/↓] Select  [Enter] Edit/Set  [Ctrl+r] Reload  [Esc] Cancel/Close";
+}
+
+/// Monkey stress test launcher
+pub mod monkey {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🐒 Monkey: {}";
+
+    /// Field titles
+    pub const PACKAGE_FIELD: &str = "Package (↑/↓ installed packages)";
+    pub const EVENT_COUNT_FIELD: &str = "Events";
+    pub const THROTTLE_FIELD: &str = "Throttle (ms between events)";
+
+    /// Explains where the run's output goes
+    pub const HINT: &str =
+        "Output streams into the log panel. Crashes and ANRs are reported when the run ends, [Shift+S] stops it.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[Tab] Next field  [Enter] Run  [Esc] Cancel";
+}
+
+/// Launch diagnosis of an AVD that failed to start
+pub mod launch_diagnosis {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🩺 Why '{}' did not start";
+
+    /// Line with the emulator's last error, {} is replaced with it
+    pub const REASON: &str = "Emulator said: {}";
+
+    /// Prefix of the fix of an issue [Enter] can apply, and of one it cannot
+    pub const AUTO_FIX: &str = "  ↳ [Enter] ";
+    pub const MANUAL_FIX: &str = "  ↳ ";
+
+    /// Shown on the selected issue while its fix runs
+    pub const FIXING: &str = "  ↳ fixing...";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[↑/↓] Select  [Enter] Fix  [Esc] Close";
+}
+
+/// Quick Boot options of an AVD
+pub mod quick_boot {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "⚡ Quick Boot · {}";
+
+    /// Snapshot line label, followed by its size or `NO_SNAPSHOT`
+    pub const SNAPSHOT: &str = "Quick Boot snapshot: ";
+    pub const NO_SNAPSHOT: &str = "none, the next start boots cold";
+    pub const LOADING: &str = "Checking the snapshot...";
+
+    /// Toggle labels, followed by `ON` or `OFF`
+    pub const COLD_BOOT_ONCE: &str = "[o] Cold boot next start: ";
+    pub const ALWAYS_COLD_BOOT: &str = "[a] Always cold boot: ";
+    pub const ON: &str = "on";
+    pub const OFF: &str = "off";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[o] Once  [a] Always  [d] Delete snapshot  [Esc] Close";
+}
+
+/// Wi-Fi, Bluetooth and airplane mode toggles
+pub mod radios {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "📶 Radios · {}";
+
+    pub const LOADING: &str = "Reading the radio state...";
+
+    /// Toggle labels, followed by `ON`, `OFF`, `UNKNOWN` or `SWITCHING`
+    pub const WIFI: &str = "[w] Wi-Fi: ";
+    pub const BLUETOOTH: &str = "[b] Bluetooth: ";
+    pub const AIRPLANE_MODE: &str = "[a] Airplane mode: ";
+    pub const ON: &str = "on";
+    pub const OFF: &str = "off";
+    pub const UNKNOWN: &str = "unknown";
+    pub const SWITCHING: &str = "switching...";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[w] Wi-Fi  [b] Bluetooth  [a] Airplane mode  [Esc] Close";
+}
+
+/// Developer toggles dialog
+pub mod developer_toggles {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🎛 Developer toggles · {}";
+
+    pub const LOADING: &str = "Reading the developer toggles...";
+
+    /// Toggle values, after `[key] label: `
+    pub const ON: &str = "on";
+    pub const OFF: &str = "off";
+    pub const SWITCHING: &str = "switching...";
+
+    /// What changes take effect on
+    pub const ANDROID_HINT: &str = "Running apps redraw with the change.";
+    pub const IOS_HINT: &str = "Apps launched from now on pick the change up.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[key] Switch  [Esc] Close";
+}
+
+/// App data backups dialog
+pub mod app_data {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "💾 Apps · {}";
+
+    pub const APPS_TITLE: &str = " Apps ";
+    pub const BACKUPS_TITLE: &str = " Backups ";
+    pub const LOADING_APPS: &str = "Listing installed apps...";
+    pub const NO_BACKUPS: &str = "No backups yet, [b] takes one";
+
+    /// Status while the background work runs, {} is replaced with the app
+    pub const BACKING_UP: &str = "Backing up {}...";
+    pub const RESTORING: &str = "Restoring {}...";
+    /// {} are replaced with the app and the target device
+    pub const COPYING: &str = "Copying {} to '{}'...";
+
+    /// Device the app is copied to, followed by its name and `TARGET_KEYS`
+    pub const TARGET_LABEL: &str = "Copy to: ";
+    pub const TARGET_KEYS: &str = "  [t] next  [c] app  [Shift+C] app and data";
+    pub const NO_TARGET: &str = "no other running device";
+
+    /// What can be backed up
+    pub const ANDROID_HINT: &str =
+        "Debuggable apps only (run-as); restoring clears the app's data first.";
+    pub const IOS_HINT: &str = "Copies the data container; restoring quits the app first.";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[↑/↓] App  [←/→] Backup  [b] Back up  [r] Restore  [Esc] Close";
+}
+
+/// Device clock dialog
+pub mod clock {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "🕒 Clock of '{}'";
+
+    pub const INPUT_FIELD: &str = " Date, offset, or auto ";
+
+    /// Input examples
+    pub const ANDROID_HINT: &str =
+        "2025-12-31 23:59, 2025-12-31, +7d, -3h, +90m, or auto for network time. Needs adb root.";
+    pub const IOS_HINT: &str =
+        "Simulators run on the Mac's clock, so only the status bar changes. auto clears it.";
+
+    /// How to shift the clock an iOS app sees, shown below `IOS_HINT`
+    pub const IOS_APP_TIME_HINT: &str = "To shift app time, launch with libfaketime: SIMCTL_CHILD_DYLD_INSERT_LIBRARIES=<libfaketime.dylib> SIMCTL_CHILD_FAKETIME=+7d xcrun simctl launch <udid> <bundle id>";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[Enter] Apply  [Esc] Cancel";
+}
+
+/// Keyboard forwarding to a running AVD
+pub mod type_into_device {
+    /// Dialog title, {} is replaced with the device name
+    pub const TITLE: &str = "⌨ Typing into '{}'";
+
+    /// Shown before anything was typed
+    pub const PLACEHOLDER: &str = "Keys go to the focused field of the device";
+
+    /// Dialog navigation, and the hint after a single Esc
+    pub const SHORTCUTS: &str = "[Esc Esc] Stop typing";
+    pub const ESCAPE_ARMED: &str = "Esc again stops typing, any other key sends Esc to the device";
+}
+
+/// Preview dialog for pruning unavailable simulators
+pub mod prune_unavailable {
+    /// Dialog title, {} is replaced with the number of simulators
+    pub const TITLE: &str = "🧽 Delete {} unavailable simulator(s)?";
+
+    /// Dialog navigation
+    pub const SHORTCUTS: &str = "[y] Delete all  [↑/↓/j/k] Scroll  [n/Esc] Cancel";
+
+    /// Title when asking about one simulator at a time, {} are replaced
+    /// with its position and the number of simulators
+    pub const TITLE_EACH: &str = "🧽 Delete unavailable simulator {} of {}?";
+
+    /// Dialog navigation when asking about one simulator at a time
+    pub const SHORTCUTS_EACH: &str = "[y] Delete  [n] Keep  [Esc] Cancel";
+}
+
+/// Perf overlay text
+pub mod perf {
+    /// Overlay title
+    pub const TITLE: &str = "⏱ Perf";
+
+    /// Shown for a metric with no timings yet
+    pub const NO_DATA: &str = "-";
 }
 
 /// Log management shortcuts
@@ -196,9 +1004,15 @@ pub mod log_shortcuts {
     /// Fullscreen logs shortcut
     pub const FULLSCREEN_LOGS: &str = "🖥️ [Shift+F]ullscreen";
 
+    /// Zen log mode shortcut
+    pub const ZEN_LOGS: &str = "🧘 [Shift+Z]en";
+
+    /// Log marker shortcut
+    pub const MARK_LOG: &str = "🔖 [m]ark log";
+
     /// Complete log shortcuts text
     pub const LOG_MODE_SHORTCUTS: &str =
-        "🗑️ [Shift+L]clear logs  🔍 [f]filter  🖥️ [Shift+F]ullscreen";
+        "🗑️ [Shift+L]clear logs  🔍 [f]filter  🔖 [m]ark log  🖥️ [Shift+F]ullscreen  🧘 [Shift+Z]en";
 }
 
 /// Mode indicators for status text
@@ -213,7 +1027,8 @@ pub mod mode_indicators {
 /// Form field navigation
 pub mod form_navigation {
     /// Field navigation instructions
-    pub const FIELD_NAV: &str = "[Tab]next field [Shift+Tab]prev field [Enter]submit [Esc]cancel";
+    pub const FIELD_NAV: &str =
+        "[Tab]next field [Shift+Tab]prev field [Enter]submit [Ctrl+y]copy command [Esc]cancel";
 }
 
 /// Quit instructions for different modes
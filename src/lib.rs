@@ -11,6 +11,7 @@
 //! - [`app`] - Main application logic, state management, and event handling
 //! - [`managers`] - Platform-specific device management implementations
 //! - [`models`] - Core data structures and domain models
+//! - [`service`] - UI-free device management for embedding in other programs
 //! - [`ui`] - Terminal UI rendering and widget components
 //! - [`utils`] - Shared utilities for command execution and logging
 //! - [`constants`] - Application-wide constants and configuration values
@@ -25,25 +26,32 @@
 //!
 //! # Usage
 //!
-//! The primary entry point is the [`App`] struct which coordinates all functionality:
+//! The TUI entry point is the `App` struct, available with the default `tui`
+//! feature:
 //!
 //! ```no_run
+//! # #[cfg(feature = "tui")]
+//! # async fn example() -> anyhow::Result<()> {
 //! use emu::App;
 //! use ratatui::Terminal;
 //! use ratatui::backend::CrosstermBackend;
 //!
-//! # async fn example() -> anyhow::Result<()> {
 //! let app = App::new().await?;
 //! let terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
 //! app.run(terminal).await?;
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Programs that only manage devices can depend on emu with
+//! `default-features = false` and use [`service::DeviceService`], which
+//! pulls in neither ratatui nor the app loop.
 
 /// Application core functionality including state management and event handling.
 ///
 /// This module contains the main application controller, state management,
 /// and coordination between different components.
+#[cfg(feature = "tui")]
 pub mod app;
 
 /// Application-wide constants and configuration values.
@@ -64,10 +72,17 @@ pub mod managers;
 /// device representations, error types, and configuration structures.
 pub mod models;
 
+/// UI-free device management facade.
+///
+/// Wraps both platform managers behind one API keyed by device ID, with
+/// errors reported as `DeviceError`.
+pub mod service;
+
 /// Terminal user interface components.
 ///
 /// Provides the rendering logic, themes, and custom widgets for the
 /// three-panel terminal interface.
+#[cfg(feature = "tui")]
 pub mod ui;
 
 /// Shared utility functions and helpers.
@@ -77,4 +92,5 @@ pub mod ui;
 pub mod utils;
 
 // Re-export the main application entry point for convenience
+#[cfg(feature = "tui")]
 pub use app::App;
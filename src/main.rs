@@ -18,17 +18,42 @@
 //! emu --debug           # Enable debug logging to console
 //! emu --check           # Run a non-interactive local environment check
 //! emu --log-level trace # Set custom log level (debug mode only)
+//! emu --refresh-interval 0 # Start with background auto-refresh paused
+//! emu --format json | jq    # Print a device summary when stdout is not a terminal
+//! emu --theme deuteranopia  # Use a color-blind-friendly theme
+//! emu --profile-startup 2> startup.json # Print startup timings as JSON on exit
+//! emu report                # Write a sanitized bug report zip for GitHub issues
+//! emu wait-for-boot Pixel_7  # Boot a device and block until it has booted
+//! emu --zen-logs Pixel_7 | grep MyTag # Stream a device's logs as plain lines
 //! ```
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use emu::app::App;
 use emu::constants::{
-    defaults::{ANDROID_LOGGING_DISABLED_VALUE, DEFAULT_LOG_LEVEL},
-    env_vars::{ANDROID_AVD_VERBOSE, ANDROID_EMULATOR_LOG_ENABLE, ANDROID_VERBOSE},
-    messages::checks,
+    defaults::{
+        ANDROID_LOGGING_DISABLED_VALUE, DEFAULT_ANDROID_SDK_NAME, DEFAULT_LOG_LEVEL,
+        DEFAULT_MAX_PARALLEL_CREATES, WAIT_FOR_BOOT_TIMEOUT_EXIT_CODE,
+    },
+    env_vars::{
+        self, ANDROID_AVD_VERBOSE, ANDROID_EMULATOR_LOG_ENABLE, ANDROID_VERBOSE, EMU_THEME,
+    },
+    messages::{checks, notifications},
+    timeouts::{DEFAULT_AUTO_REFRESH_INTERVAL, DEFAULT_WAIT_FOR_BOOT_TIMEOUT},
 };
-use emu::managers::{common::DeviceManager, AndroidManager, IosManager};
+use emu::managers::{AndroidManager, IosManager};
+use emu::models::{AndroidDevice, IosDevice, ProjectConfig, ReadinessCheck, ReadinessConfig};
+use emu::ui::{Theme, ThemeName};
+use emu::utils::{
+    audit,
+    boot_wait::{self, BootWaitOutcome},
+    diagnostics, host, processes, report, startup_profile, tool_paths, CommandRunner,
+    DeviceSummary, SummaryFormat,
+};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Command line arguments for the Emu application.
 ///
@@ -55,7 +80,8 @@ struct Cli {
     /// - Terminal UI may be affected by log output
     ///
     /// When disabled (default):
-    /// - No console logging to preserve TUI display
+    /// - No console logging to preserve TUI display; emu's own records
+    ///   can still be viewed inside the TUI with F12
     /// - Android emulator output is suppressed
     #[arg(long)]
     debug: bool,
@@ -70,6 +96,93 @@ struct Cli {
     /// Use this before launching the TUI to validate local setup.
     #[arg(long)]
     check: bool,
+
+    /// Seconds between background device list refreshes.
+    ///
+    /// Use 0 to start with auto-refresh paused (toggle with `p` in the TUI).
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_AUTO_REFRESH_INTERVAL.as_secs())]
+    refresh_interval: u64,
+
+    /// Format of the device summary printed instead of the TUI when stdout
+    /// is not a terminal (piped output, CI).
+    #[arg(long, value_enum, default_value_t = SummaryFormat::Text)]
+    format: SummaryFormat,
+
+    /// How many devices queued with Ctrl+a in the create dialog are created
+    /// at the same time (at least 1).
+    #[arg(long, value_name = "COUNT", default_value_t = DEFAULT_MAX_PARALLEL_CREATES)]
+    max_parallel_creates: usize,
+
+    /// Color theme of the TUI.
+    ///
+    /// `deuteranopia` and `tritanopia` use color-blind-friendly colors.
+    /// `monochrome` is used automatically when `NO_COLOR` is set or `CLICOLOR=0`.
+    #[arg(long, value_enum, env = EMU_THEME, default_value_t = ThemeName::Dark)]
+    theme: ThemeName,
+
+    /// Print how long startup took as JSON when emu exits.
+    ///
+    /// The breakdown covers SDK discovery, manager construction, the first
+    /// frame, and the first device list. It is written to stderr so stdout
+    /// stays attached to the terminal.
+    #[arg(long)]
+    profile_startup: bool,
+
+    /// Turn off creating, deleting, wiping, and installing.
+    ///
+    /// Devices can still be started, stopped, and inspected, which suits
+    /// demos and shared machines. The TUI marks itself read-only and no
+    /// longer offers those actions.
+    #[arg(long)]
+    read_only: bool,
+
+    /// Print the logs of a running device to stdout as plain lines instead
+    /// of starting the TUI, like zen log mode (`Shift+Z`) but for pipes.
+    ///
+    /// Takes a device ID (`android:<avd name>`, `ios:<udid>`), AVD name, or
+    /// iOS simulator name or UDID, and runs until the log stream ends.
+    #[arg(long, value_name = "DEVICE")]
+    zen_logs: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Subcommands that run instead of the TUI.
+#[derive(Subcommand, Debug, PartialEq)]
+enum Command {
+    /// Write a bug report zip to attach to a GitHub issue.
+    ///
+    /// It contains environment info, SDK tool versions, emu's log records,
+    /// and recently executed commands, with the home directory and user
+    /// name masked.
+    Report {
+        /// Where to write the zip (default: emu-report-<timestamp>.zip in
+        /// the working directory)
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+
+    /// Boot a device if it is stopped and wait until it has finished booting.
+    ///
+    /// Exits with 0 once the device has booted, 2 on timeout, and 1 on any
+    /// other error (for example an unknown device), so scripts can chain
+    /// `emu wait-for-boot Pixel_7 && adb install app.apk`.
+    WaitForBoot {
+        /// Device ID (`android:<avd name>`, `ios:<udid>`), AVD name, or iOS
+        /// simulator name or UDID
+        device: String,
+
+        /// Seconds to wait before giving up
+        #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_WAIT_FOR_BOOT_TIMEOUT.as_secs())]
+        timeout: u64,
+
+        /// Comma-separated readiness checks to pass before the device counts
+        /// as booted (boot_completed, package_manager, launcher,
+        /// animations_disabled). Overrides `[readiness]` in `.emu.toml`.
+        #[arg(long, value_name = "CHECKS", value_delimiter = ',')]
+        ready: Option<Vec<ReadinessCheck>>,
+    },
 }
 
 /// Main entry point for the Emu application.
@@ -86,17 +199,20 @@ struct Cli {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    if cli.profile_startup {
+        startup_profile::enable();
+    }
 
     // Install color-eyre for enhanced error reporting with colored output
     color_eyre::install().map_err(|e| anyhow::anyhow!("Failed to install color_eyre: {e}"))?;
+    // Leave no logcat or other helper process behind after a crash
+    processes::install_panic_hook();
 
-    // Configure logging based on debug mode
-    if cli.debug {
-        // Initialize env_logger with user-specified or default log level
-        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(&cli.log_level))
-            .format_timestamp_secs()
-            .init();
-    } else {
+    // emu's own log records are always kept for the in-app diagnostics
+    // viewer (F12); debug mode also prints them to the console
+    diagnostics::init(cli.debug.then_some(cli.log_level.as_str()))
+        .map_err(|e| anyhow::anyhow!("Failed to initialize logging: {e}"))?;
+    if !cli.debug {
         // Suppress Android emulator verbose output in normal TUI mode
         // These environment variables control Android SDK tool verbosity
         std::env::set_var(ANDROID_EMULATOR_LOG_ENABLE, ANDROID_LOGGING_DISABLED_VALUE);
@@ -104,40 +220,171 @@ async fn main() -> Result<()> {
         std::env::set_var(ANDROID_VERBOSE, ANDROID_LOGGING_DISABLED_VALUE);
     }
 
+    // Load before --check so an invalid .emu.toml is reported there too
+    let project_config = load_project_config()?;
+
+    match cli.command {
+        Some(Command::Report { output }) => {
+            let path = output.unwrap_or_else(report::default_report_path);
+            report::write_report(&path).await?;
+            println!(
+                "{}",
+                notifications::REPORT_SAVED.replace("{}", &path.display().to_string())
+            );
+            return Ok(());
+        }
+        Some(Command::WaitForBoot {
+            device,
+            timeout,
+            ready,
+        }) => {
+            let readiness = match ready {
+                Some(checks) => ReadinessConfig {
+                    checks: Some(checks),
+                    ..ReadinessConfig::default()
+                },
+                None => project_config
+                    .map(|config| config.readiness)
+                    .unwrap_or_default(),
+            };
+            let outcome =
+                boot_wait::wait_for_boot(&device, Duration::from_secs(timeout), &readiness).await?;
+            if outcome == BootWaitOutcome::TimedOut {
+                std::process::exit(WAIT_FOR_BOOT_TIMEOUT_EXIT_CODE);
+            }
+            return Ok(());
+        }
+        None => {}
+    }
+
     if cli.check {
-        return run_local_check().await;
+        return run_local_check(project_config.as_ref()).await;
     }
 
-    run_tui().await
+    if let Some(device) = cli.zen_logs {
+        let result = App::print_device_logs(&device).await;
+        processes::kill_all();
+        return result;
+    }
+
+    if !std::io::stdout().is_terminal() {
+        return print_device_summary(cli.format).await;
+    }
+
+    run_tui(
+        Duration::from_secs(cli.refresh_interval),
+        project_config,
+        Theme::from_env(cli.theme),
+        cli.max_parallel_creates,
+        cli.read_only,
+    )
+    .await
+}
+
+/// Loads `.emu.toml` from the working directory, exports its `[env]` table
+/// so the SDK tools spawned later inherit it, and applies its `[tools]`
+/// locations before any manager looks for the SDK.
+fn load_project_config() -> Result<Option<ProjectConfig>> {
+    let current_dir = std::env::current_dir().context("Failed to resolve working directory")?;
+    let config = ProjectConfig::load(&current_dir)?;
+    if let Some(config) = &config {
+        for (key, value) in &config.env {
+            std::env::set_var(key, value);
+        }
+        if let Some(android_sdk) = &config.tools.android_sdk {
+            std::env::set_var(env_vars::ANDROID_HOME, android_sdk);
+        }
+        for (tool, path) in config.tools.executables() {
+            tool_paths::set_tool_path(tool, Some(path.to_path_buf()));
+        }
+    }
+    Ok(config)
+}
+
+/// Discovers devices on every platform available on this host.
+/// iOS devices are `None` when simulators are not supported (non-macOS).
+async fn discover_devices() -> Result<(Vec<AndroidDevice>, Option<Vec<IosDevice>>)> {
+    let android_manager = AndroidManager::new().context(checks::ANDROID_MANAGER_CONTEXT)?;
+    let android_devices = android_manager
+        .list_devices()
+        .await
+        .context(checks::ANDROID_DEVICE_DISCOVERY_CONTEXT)?;
+
+    let ios_devices = if cfg!(target_os = "macos") {
+        let ios_manager = IosManager::new().context(checks::IOS_MANAGER_CONTEXT)?;
+        ios_manager
+            .probe()
+            .await
+            .context(checks::IOS_MANAGER_CONTEXT)?;
+        let devices = ios_manager
+            .list_devices()
+            .await
+            .context(checks::IOS_DEVICE_DISCOVERY_CONTEXT)?;
+        Some(devices)
+    } else {
+        None
+    };
+
+    Ok((android_devices, ios_devices))
+}
+
+/// Prints a one-shot device summary for non-interactive use, in place of the
+/// TUI which needs a terminal.
+async fn print_device_summary(format: SummaryFormat) -> Result<()> {
+    let (android_devices, ios_devices) = discover_devices().await?;
+    let summary = DeviceSummary::new(&android_devices, ios_devices.as_deref().unwrap_or_default());
+    let output = summary.render(format)?;
+    if !output.is_empty() {
+        println!("{output}");
+    }
+    Ok(())
 }
 
 /// Runs a non-interactive local environment check.
 ///
 /// This verifies that the local machine can initialize the platform managers,
 /// discover devices, and construct the application shell before entering TUI mode.
-async fn run_local_check() -> Result<()> {
+/// Further Android SDKs from `.emu.toml` are listed with their status.
+async fn run_local_check(project_config: Option<&ProjectConfig>) -> Result<()> {
     println!("{}", checks::RUNNING_LOCAL_CHECK);
 
-    let android_manager = AndroidManager::new().context(checks::ANDROID_MANAGER_CONTEXT)?;
-    let android_devices = <AndroidManager as DeviceManager>::list_devices(&android_manager)
-        .await
-        .context(checks::ANDROID_DEVICE_DISCOVERY_CONTEXT)?;
+    let (android_devices, ios_devices) = discover_devices().await?;
     println!(
         "{}",
         checks::ANDROID_MANAGER_READY.replace("{device_count}", &android_devices.len().to_string())
     );
-
-    if cfg!(target_os = "macos") {
-        let ios_manager = IosManager::new().context(checks::IOS_MANAGER_CONTEXT)?;
-        let ios_devices = <IosManager as DeviceManager>::list_devices(&ios_manager)
-            .await
-            .context(checks::IOS_DEVICE_DISCOVERY_CONTEXT)?;
-        println!(
+    if let Some(config) = project_config.filter(|config| !config.tools.android_sdks.is_empty()) {
+        let default_sdk = AndroidManager::new().map(|manager| manager.android_home().to_path_buf());
+        let sdks = std::iter::once((DEFAULT_ANDROID_SDK_NAME, default_sdk)).chain(
+            config.tools.android_sdks.iter().map(|(name, root)| {
+                let manager =
+                    AndroidManager::with_sdk_root(Arc::new(CommandRunner::new()), root.clone());
+                (name.as_str(), manager.map(|_| root.clone()))
+            }),
+        );
+        for (name, sdk) in sdks {
+            match sdk {
+                Ok(path) => println!(
+                    "{}",
+                    checks::ANDROID_SDK_READY
+                        .replace("{name}", name)
+                        .replace("{path}", &path.display().to_string())
+                ),
+                Err(e) => println!(
+                    "{}",
+                    checks::ANDROID_SDK_UNAVAILABLE
+                        .replace("{name}", name)
+                        .replace("{error}", &e.to_string())
+                ),
+            }
+        }
+    }
+    match ios_devices {
+        Some(ios_devices) => println!(
             "{}",
             checks::IOS_MANAGER_READY.replace("{device_count}", &ios_devices.len().to_string())
-        );
-    } else {
-        println!("{}", checks::IOS_MANAGER_SKIPPED);
+        ),
+        None => println!("{}", checks::IOS_MANAGER_SKIPPED),
     }
 
     let _app = App::new()
@@ -169,10 +416,22 @@ async fn run_local_check() -> Result<()> {
 /// - Terminal mode changes fail
 /// - Terminal backend creation fails
 /// - Application initialization or execution fails
-async fn run_tui() -> Result<()> {
+async fn run_tui(
+    refresh_interval: Duration,
+    project_config: Option<ProjectConfig>,
+    theme: Theme,
+    max_parallel_creates: usize,
+    read_only: bool,
+) -> Result<()> {
     use crossterm::{
+        event::{
+            KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+        },
         execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+        terminal::{
+            disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+            LeaveAlternateScreen,
+        },
     };
     use ratatui::{backend::CrosstermBackend, Terminal};
     use std::io;
@@ -183,24 +442,63 @@ async fn run_tui() -> Result<()> {
     let mut stdout = io::stdout();
     // Switch to alternate screen buffer to preserve terminal history
     execute!(stdout, EnterAlternateScreen)?;
+    // Have the terminal tell held keys from new presses, so holding `d`
+    // cannot complete the `d d` chord. Terminals without the kitty keyboard
+    // protocol report every key as a press.
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+            previous(info);
+        }));
+    }
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
 
+    // Detect host memory for the create form's RAM warning before any frame
+    // needs it; on macOS this runs sysctl
+    tokio::task::spawn_blocking(host::total_memory_mb);
+
+    // Record executed commands to the audit log for later inspection
+    if let Some(path) = audit::default_log_path() {
+        audit::enable_log_file(path).await;
+    }
+
     // Initialize and run the main application
-    let app = App::new().await?;
+    let mut app = App::new().await?;
+    app.set_auto_refresh_interval(refresh_interval).await;
+    app.set_theme(theme).await;
+    app.set_max_parallel_creates(max_parallel_creates).await;
+    if let Some(config) = project_config {
+        app.set_project_config(config).await;
+    }
+    app.set_read_only(read_only).await;
     let result = app.run(terminal).await;
+    processes::kill_all();
 
     // Restore terminal to original state
     // This cleanup runs even if the app returns an error
+    if keyboard_enhanced {
+        execute!(io::stdout(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(io::stdout(), LeaveAlternateScreen)?;
 
+    if startup_profile::is_enabled() {
+        eprintln!("{}", startup_profile::snapshot().to_json()?);
+    }
+
     result
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Cli;
+    use super::{Cli, Command, ReadinessCheck, SummaryFormat, ThemeName};
     use clap::Parser;
 
     #[test]
@@ -220,4 +518,129 @@ mod tests {
         assert!(cli.debug);
         assert_eq!(cli.log_level, "trace");
     }
+
+    #[test]
+    fn test_cli_parses_refresh_interval() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert_eq!(cli.refresh_interval, 5);
+
+        let cli = Cli::try_parse_from(["emu", "--refresh-interval", "0"]).unwrap();
+        assert_eq!(cli.refresh_interval, 0);
+    }
+
+    #[test]
+    fn test_cli_parses_max_parallel_creates() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert_eq!(cli.max_parallel_creates, 2);
+
+        let cli = Cli::try_parse_from(["emu", "--max-parallel-creates", "4"]).unwrap();
+        assert_eq!(cli.max_parallel_creates, 4);
+    }
+
+    #[test]
+    fn test_cli_parses_summary_format() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert_eq!(cli.format, SummaryFormat::Text);
+
+        let cli = Cli::try_parse_from(["emu", "--format", "json"]).unwrap();
+        assert_eq!(cli.format, SummaryFormat::Json);
+        assert!(Cli::try_parse_from(["emu", "--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parses_theme() {
+        let cli = Cli::try_parse_from(["emu", "--theme", "tritanopia"]).unwrap();
+        assert_eq!(cli.theme, ThemeName::Tritanopia);
+    }
+
+    #[test]
+    fn test_cli_parses_profile_startup_flag() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert!(!cli.profile_startup);
+
+        let cli = Cli::try_parse_from(["emu", "--profile-startup"]).unwrap();
+        assert!(cli.profile_startup);
+    }
+
+    #[test]
+    fn test_cli_parses_read_only_flag() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert!(!cli.read_only);
+
+        let cli = Cli::try_parse_from(["emu", "--read-only"]).unwrap();
+        assert!(cli.read_only);
+    }
+
+    #[test]
+    fn test_cli_parses_zen_logs() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert_eq!(cli.zen_logs, None);
+
+        let cli = Cli::try_parse_from(["emu", "--zen-logs", "Pixel_7"]).unwrap();
+        assert_eq!(cli.zen_logs.as_deref(), Some("Pixel_7"));
+        assert!(Cli::try_parse_from(["emu", "--zen-logs"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parses_report_subcommand() {
+        let cli = Cli::try_parse_from(["emu"]).unwrap();
+        assert_eq!(cli.command, None);
+
+        let cli = Cli::try_parse_from(["emu", "report", "--output", "bug.zip"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Report {
+                output: Some("bug.zip".into())
+            })
+        );
+    }
+
+    #[test]
+    fn test_cli_parses_wait_for_boot_subcommand() {
+        let cli = Cli::try_parse_from(["emu", "wait-for-boot", "Pixel_7"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::WaitForBoot {
+                device: "Pixel_7".to_string(),
+                timeout: 300,
+                ready: None,
+            })
+        );
+
+        let cli =
+            Cli::try_parse_from(["emu", "wait-for-boot", "iPhone 15", "--timeout", "60"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::WaitForBoot {
+                device: "iPhone 15".to_string(),
+                timeout: 60,
+                ready: None,
+            })
+        );
+
+        let cli = Cli::try_parse_from([
+            "emu",
+            "wait-for-boot",
+            "Pixel_7",
+            "--ready",
+            "boot_completed,package-manager,launcher",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::WaitForBoot {
+                device: "Pixel_7".to_string(),
+                timeout: 300,
+                ready: Some(vec![
+                    ReadinessCheck::BootCompleted,
+                    ReadinessCheck::PackageManager,
+                    ReadinessCheck::Launcher,
+                ]),
+            })
+        );
+        assert!(
+            Cli::try_parse_from(["emu", "wait-for-boot", "Pixel_7", "--ready", "visible"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["emu", "wait-for-boot"]).is_err());
+    }
 }
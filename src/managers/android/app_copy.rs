@@ -0,0 +1,123 @@
+//! Copies of an installed app, and optionally its data, between running AVDs.
+//!
+//! The base and split APKs that `pm path` lists are pulled to a temporary
+//! directory on the host and installed together on the other AVD, so an
+//! issue can be reproduced on another Android version without a build.
+
+use super::AndroidManager;
+use crate::constants::{
+    commands::{self, adb},
+    files::{APP_COPY_DIR_PREFIX, APP_DATA_ARCHIVE_EXTENSION},
+    messages::{device_busy, read_only},
+};
+use crate::utils::command::quote_shell_arg;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+impl AndroidManager {
+    /// Installs `package` of one running AVD on another, along with its data
+    /// when `with_data` is set, which needs a debuggable app.
+    pub async fn copy_app(
+        &self,
+        source_avd: &str,
+        target_avd: &str,
+        package: &str,
+        with_data: bool,
+    ) -> Result<()> {
+        self.read_only.ensure_writable(read_only::INSTALL_APP)?;
+        let _source_lock = self
+            .lock_device(source_avd, device_busy::COPYING_APP)
+            .await?;
+        let _target_lock = self
+            .lock_device(target_avd, device_busy::INSTALLING_APP)
+            .await?;
+        let dir = std::env::temp_dir().join(format!(
+            "{APP_COPY_DIR_PREFIX}{package}-{}",
+            std::process::id()
+        ));
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let copied = async {
+            let apks = self.pull_apks(source_avd, package, &dir).await?;
+            self.install_apks(target_avd, &apks).await?;
+            if with_data {
+                let archive = dir.join(format!("{package}{APP_DATA_ARCHIVE_EXTENSION}"));
+                self.archive_app_data(source_avd, package, &archive).await?;
+                self.unpack_app_data(target_avd, package, &archive).await?;
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = tokio::fs::remove_dir_all(&dir).await {
+            log::debug!("Failed to remove {}: {e:#}", dir.display());
+        }
+        copied
+    }
+
+    /// Pulls the APKs of `package` into `dir`, base APK first.
+    async fn pull_apks(&self, avd_name: &str, package: &str, dir: &Path) -> Result<Vec<PathBuf>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .adb_shell(&serial, &[adb::PM, adb::PM_PATH, &quote_shell_arg(package)])
+            .await?;
+        let remote_apks: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix(adb::PM_PATH_PREFIX))
+            .collect();
+        if remote_apks.is_empty() {
+            bail!("{package} is not installed on '{avd_name}'");
+        }
+
+        let mut apks = Vec::with_capacity(remote_apks.len());
+        for remote_apk in remote_apks {
+            let file_name = Path::new(remote_apk)
+                .file_name()
+                .with_context(|| format!("Unexpected APK path {remote_apk}"))?;
+            let apk = dir.join(file_name);
+            let apk_arg = apk.to_string_lossy();
+            self.command_executor
+                .run(
+                    Path::new(commands::ADB),
+                    &["-s", &serial, adb::PULL, remote_apk, &apk_arg],
+                )
+                .await
+                .with_context(|| format!("Failed to copy {remote_apk} off '{avd_name}'"))?;
+            apks.push(apk);
+        }
+        Ok(apks)
+    }
+
+    async fn install_apks(&self, avd_name: &str, apks: &[PathBuf]) -> Result<()> {
+        self.read_only.ensure_writable(read_only::INSTALL_APP)?;
+        let serial = self.running_serial(avd_name).await?;
+        let install = if apks.len() > 1 {
+            adb::INSTALL_MULTIPLE
+        } else {
+            adb::INSTALL
+        };
+        let apk_args: Vec<_> = apks.iter().map(|apk| apk.to_string_lossy()).collect();
+        let mut args = vec![
+            "-s",
+            &serial,
+            install,
+            adb::INSTALL_REPLACE,
+            adb::INSTALL_TEST_ONLY,
+        ];
+        args.extend(apk_args.iter().map(|apk| apk.as_ref()));
+        let output = self
+            .command_executor
+            .run(Path::new(commands::ADB), &args)
+            .await
+            .with_context(|| format!("Failed to install on '{avd_name}'"))?;
+        // e.g. "Failure [INSTALL_FAILED_OLDER_SDK: ...]"
+        if let Some(failure) = output
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with(adb::INSTALL_FAILURE))
+        {
+            bail!("{failure}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! Backups of the data of debuggable apps on running AVDs.
+//!
+//! `run-as` enters the data directory of a debuggable app, where tar
+//! archives or unpacks it through a file staged in `/data/local/tmp`. Apps
+//! that are not debuggable refuse `run-as`; `adb backup` would need
+//! confirming on the device and skips apps targeting Android 12 and later.
+
+use super::AndroidManager;
+use crate::constants::{
+    commands::{self, adb},
+    files::{APP_DATA_ARCHIVE_EXTENSION, APP_DATA_BACKUP_PREFIX},
+    messages::{device_busy, read_only},
+};
+use crate::utils::command::quote_shell_arg;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+impl AndroidManager {
+    /// Archives the data directory of `package` on a running AVD to `path`.
+    pub async fn backup_app_data(&self, avd_name: &str, package: &str, path: &Path) -> Result<()> {
+        let _lock = self
+            .lock_device(avd_name, device_busy::BACKING_UP_APP_DATA)
+            .await?;
+        self.archive_app_data(avd_name, package, path).await
+    }
+
+    /// Replaces the data of `package` on a running AVD with the archive at
+    /// `path`. The app is stopped and its data cleared first.
+    pub async fn restore_app_data(&self, avd_name: &str, package: &str, path: &Path) -> Result<()> {
+        self.read_only
+            .ensure_writable(read_only::RESTORE_APP_DATA)?;
+        let _lock = self
+            .lock_device(avd_name, device_busy::RESTORING_APP_DATA)
+            .await?;
+        self.unpack_app_data(avd_name, package, path).await
+    }
+
+    pub(super) async fn archive_app_data(
+        &self,
+        avd_name: &str,
+        package: &str,
+        path: &Path,
+    ) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let staged = staging_path(package);
+        let archived = self
+            .run_as_tar(
+                &serial,
+                package,
+                &[
+                    adb::TAR_CREATE,
+                    adb::TAR_STDIO,
+                    adb::TAR_CURRENT_DIR,
+                    adb::REDIRECT_OUTPUT,
+                    &quote_shell_arg(&staged),
+                ],
+            )
+            .await;
+        let pulled = match archived {
+            Ok(()) => {
+                let path_arg = path.to_string_lossy();
+                self.command_executor
+                    .run(
+                        Path::new(commands::ADB),
+                        &["-s", &serial, adb::PULL, &staged, &path_arg],
+                    )
+                    .await
+                    .map(|_| ())
+                    .context("Failed to copy the backup off the device")
+            }
+            Err(error) => Err(error),
+        };
+        self.remove_staged(&serial, &staged).await;
+        pulled
+    }
+
+    pub(super) async fn unpack_app_data(
+        &self,
+        avd_name: &str,
+        package: &str,
+        path: &Path,
+    ) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        let staged = staging_path(package);
+        let path_arg = path.to_string_lossy();
+        self.command_executor
+            .run(
+                Path::new(commands::ADB),
+                &["-s", &serial, adb::PUSH, &path_arg, &staged],
+            )
+            .await
+            .context("Failed to copy the backup onto the device")?;
+        let restored = async {
+            self.adb_shell(
+                &serial,
+                &[adb::PM, adb::PM_CLEAR, &quote_shell_arg(package)],
+            )
+            .await?;
+            self.run_as_tar(
+                &serial,
+                package,
+                &[
+                    adb::TAR_EXTRACT,
+                    adb::TAR_STDIO,
+                    adb::REDIRECT_INPUT,
+                    &quote_shell_arg(&staged),
+                ],
+            )
+            .await
+        }
+        .await;
+        self.remove_staged(&serial, &staged).await;
+        restored
+    }
+
+    /// Runs tar as `package` in its data directory. `run-as` reports
+    /// an app that is not debuggable or not installed on stderr.
+    async fn run_as_tar(&self, serial: &str, package: &str, tar_args: &[&str]) -> Result<()> {
+        let package = quote_shell_arg(package);
+        let mut args = vec![adb::RUN_AS, &package, adb::TAR];
+        args.extend(tar_args);
+        let output = self.adb_shell(serial, &args).await?;
+        if let Some(error) = output.lines().map(str::trim).find(|line| !line.is_empty()) {
+            bail!("{error}");
+        }
+        Ok(())
+    }
+
+    async fn remove_staged(&self, serial: &str, staged: &str) {
+        let staged = quote_shell_arg(staged);
+        let mut remove = adb::RM_FORCE.to_vec();
+        remove.push(&staged);
+        if let Err(e) = self.adb_shell(serial, &remove).await {
+            log::debug!("{e:#}");
+        }
+    }
+}
+
+/// Device path the archive of `package` is staged at. `adb push` and `pull`
+/// take it as is; the device shell needs it quoted.
+fn staging_path(package: &str) -> String {
+    format!(
+        "{}{APP_DATA_BACKUP_PREFIX}{package}{APP_DATA_ARCHIVE_EXTENSION}",
+        adb::APP_DATA_STAGING_DIR
+    )
+}
@@ -0,0 +1,27 @@
+//! Downloads of the recommended device catalog.
+
+use super::AndroidManager;
+use crate::constants::{
+    commands::{CURL, CURL_MAX_TIME, CURL_QUIET},
+    files::DEVICE_CATALOG_URL,
+    timeouts::DEVICE_CATALOG_DOWNLOAD_TIMEOUT,
+};
+use crate::models::DeviceCatalog;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+impl AndroidManager {
+    /// Downloads the latest device catalog from the repository.
+    pub async fn fetch_device_catalog(&self) -> Result<DeviceCatalog> {
+        let max_time = DEVICE_CATALOG_DOWNLOAD_TIMEOUT.as_secs().to_string();
+        let json = self
+            .command_executor
+            .run(
+                Path::new(CURL),
+                &[CURL_QUIET, CURL_MAX_TIME, &max_time, DEVICE_CATALOG_URL],
+            )
+            .await
+            .context("Failed to download the device catalog")?;
+        DeviceCatalog::parse(&json).context("The downloaded device catalog is invalid")
+    }
+}
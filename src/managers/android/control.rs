@@ -0,0 +1,482 @@
+//! Interactive controls for running emulators.
+//!
+//! Each control goes through the emulator's gRPC endpoint when emu is built
+//! with the `grpc` feature and the emulator announced one, and falls back to
+//! `adb` otherwise.
+
+use super::{parsers, AndroidManager};
+use crate::constants::{
+    commands::{self, adb},
+    files::{BUGREPORT_FILE_PREFIX, PERFETTO_TRACE_EXTENSION, REPORT_EXTENSION, TRACE_FILE_PREFIX},
+    messages::errors::CLOCK_NEEDS_ROOT,
+};
+use crate::models::{
+    AndroidIntent, AppLaunch, ClockChange, DeviceKey, DeviceProcess, MonkeyReport, MonkeyRun,
+    TestSettingsBackup, TraceCapture,
+};
+use crate::utils::{command::quote_shell_arg, command_executor::CommandPolicy};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDateTime;
+use std::path::{Path, PathBuf};
+
+impl AndroidManager {
+    /// Returns true once Android has finished booting on a running AVD.
+    pub async fn is_boot_completed(&self, avd_name: &str) -> Result<bool> {
+        #[cfg(feature = "grpc")]
+        if let Some(client) = self.grpc_client(avd_name).await {
+            match client.status().await {
+                Ok(status) => return Ok(status.booted),
+                Err(e) => log::debug!("gRPC status of '{avd_name}' failed, using adb: {e:#}"),
+            }
+        }
+
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .command_executor
+            .run(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::SHELL,
+                    commands::adb::GETPROP,
+                    commands::adb::PROP_BOOT_COMPLETED,
+                ],
+            )
+            .await
+            .context("Failed to read boot status")?;
+        Ok(output.trim() == "1")
+    }
+
+    /// Saves a PNG screenshot of a running AVD's display to `path`.
+    pub async fn save_screenshot(&self, avd_name: &str, path: &Path) -> Result<()> {
+        #[cfg(feature = "grpc")]
+        if let Some(client) = self.grpc_client(avd_name).await {
+            match client.screenshot().await {
+                Ok(png) => {
+                    return tokio::fs::write(path, png)
+                        .await
+                        .with_context(|| format!("Failed to write {}", path.display()));
+                }
+                Err(e) => log::debug!("gRPC screenshot of '{avd_name}' failed, using adb: {e:#}"),
+            }
+        }
+
+        let serial = self.running_serial(avd_name).await?;
+        let path = path.to_string_lossy();
+        self.command_executor
+            .run(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::EMU,
+                    commands::adb::SCREENRECORD,
+                    commands::adb::SCREENSHOT,
+                    &path,
+                ],
+            )
+            .await
+            .context("Failed to take screenshot")?;
+        Ok(())
+    }
+
+    /// Types `text` into the focused field of a running AVD.
+    pub async fn send_text(&self, avd_name: &str, text: &str) -> Result<()> {
+        #[cfg(feature = "grpc")]
+        if let Some(client) = self.grpc_client(avd_name).await {
+            match client.send_text(text).await {
+                Ok(()) => return Ok(()),
+                Err(e) => log::debug!("gRPC input to '{avd_name}' failed, using adb: {e:#}"),
+            }
+        }
+
+        let serial = self.running_serial(avd_name).await?;
+        let text = escape_input_text(text);
+        self.command_executor
+            .run(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::SHELL,
+                    commands::adb::INPUT,
+                    commands::adb::TEXT,
+                    &text,
+                ],
+            )
+            .await
+            .context("Failed to send text")?;
+        Ok(())
+    }
+
+    /// Presses a key such as Enter or Backspace on a running AVD.
+    pub async fn send_key(&self, avd_name: &str, key: DeviceKey) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        self.adb_shell(&serial, &[adb::INPUT, adb::KEYEVENT, key.android_keycode()])
+            .await?;
+        Ok(())
+    }
+
+    /// Collects an `adb bugreport` of a running AVD into a timestamped zip
+    /// in `dir` and returns its path. adb's progress, in percent, goes to
+    /// `on_progress`; a report usually takes one to three minutes.
+    pub async fn collect_bugreport(
+        &self,
+        avd_name: &str,
+        dir: &Path,
+        on_progress: &(dyn Fn(u8) + Send + Sync),
+    ) -> Result<PathBuf> {
+        let serial = self.running_serial(avd_name).await?;
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(format!(
+            "{BUGREPORT_FILE_PREFIX}{avd_name}-{}{REPORT_EXTENSION}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let path_arg = path.to_string_lossy();
+        let on_line = |line: &str| {
+            if let Some(percentage) = parsers::parse_bugreport_progress(line) {
+                on_progress(percentage);
+            }
+        };
+        self.command_executor
+            .run_streaming(
+                Path::new(commands::ADB),
+                &["-s", &serial, adb::BUGREPORT, &path_arg],
+                &on_line,
+            )
+            .await
+            .context("Failed to collect bug report")?;
+        Ok(path)
+    }
+
+    /// Records a Perfetto trace of a running AVD into a timestamped file in
+    /// `dir` and returns its path. Perfetto writes on the device, so the
+    /// trace is pulled and removed from there once the duration is over.
+    pub async fn capture_perfetto_trace(
+        &self,
+        avd_name: &str,
+        capture: &TraceCapture,
+        dir: &Path,
+    ) -> Result<PathBuf> {
+        let serial = self.running_serial(avd_name).await?;
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let file_name = format!(
+            "{TRACE_FILE_PREFIX}{avd_name}-{}{PERFETTO_TRACE_EXTENSION}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        );
+        let device_path = format!("{}{file_name}", adb::PERFETTO_TRACE_DIR);
+        let path = dir.join(&file_name);
+
+        let args = capture.perfetto_args(&device_path);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.adb_shell(&serial, &args)
+            .await
+            .context("Failed to record Perfetto trace")?;
+        let path_arg = path.to_string_lossy();
+        let pulled = self
+            .command_executor
+            .run(
+                Path::new(commands::ADB),
+                &["-s", &serial, adb::PULL, &device_path, &path_arg],
+            )
+            .await
+            .context("Failed to copy the trace off the device");
+        let mut remove = adb::RM_FORCE.to_vec();
+        remove.push(&device_path);
+        if let Err(e) = self.adb_shell(&serial, &remove).await {
+            log::debug!("{e:#}");
+        }
+        pulled?;
+        Ok(path)
+    }
+
+    /// Switches off the animation scales of a running AVD for UI tests and
+    /// returns the previous values for [`Self::restore_test_settings`].
+    pub async fn apply_test_settings(&self, avd_name: &str) -> Result<TestSettingsBackup> {
+        let serial = self.running_serial(avd_name).await?;
+        let mut animation_scales = Vec::new();
+        for setting in adb::ANIMATION_SCALE_SETTINGS {
+            let value = self
+                .adb_shell(&serial, &[adb::SETTINGS, adb::GET, adb::GLOBAL, setting])
+                .await?;
+            let value = value.trim();
+            animation_scales
+                .push((!value.is_empty() && value != "null").then(|| value.to_string()));
+        }
+        for setting in adb::ANIMATION_SCALE_SETTINGS {
+            self.adb_shell(
+                &serial,
+                &[adb::SETTINGS, adb::PUT, adb::GLOBAL, setting, "0"],
+            )
+            .await?;
+        }
+        Ok(TestSettingsBackup::Android { animation_scales })
+    }
+
+    /// Puts back the animation scales saved by [`Self::apply_test_settings`].
+    pub async fn restore_test_settings(
+        &self,
+        avd_name: &str,
+        backup: &TestSettingsBackup,
+    ) -> Result<()> {
+        let TestSettingsBackup::Android { animation_scales } = backup else {
+            anyhow::bail!("Not an Android settings backup");
+        };
+        let serial = self.running_serial(avd_name).await?;
+        for (setting, value) in adb::ANIMATION_SCALE_SETTINGS.iter().zip(animation_scales) {
+            match value {
+                Some(value) => {
+                    self.adb_shell(
+                        &serial,
+                        &[adb::SETTINGS, adb::PUT, adb::GLOBAL, setting, value],
+                    )
+                    .await?
+                }
+                None => {
+                    self.adb_shell(&serial, &[adb::SETTINGS, adb::DELETE, adb::GLOBAL, setting])
+                        .await?
+                }
+            };
+        }
+        Ok(())
+    }
+
+    /// Sends an intent with `adb shell am start` or `am broadcast`.
+    /// `am` exits with 0 even when no activity matches, so its output is
+    /// checked for errors as well.
+    pub async fn send_intent(&self, avd_name: &str, intent: &AndroidIntent) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        self.send_intent_to_serial(&serial, intent).await
+    }
+
+    /// Starts the launcher activity of `launch.app` with the launch
+    /// arguments as extras and returns the component it started. The app is
+    /// force-stopped first, so it starts with the extras instead of resuming.
+    pub async fn launch_app(&self, avd_name: &str, launch: &AppLaunch) -> Result<String> {
+        let serial = self.running_serial(avd_name).await?;
+        let app = quote_shell_arg(&launch.app);
+        let mut args = adb::RESOLVE_LAUNCHER_ACTIVITY.to_vec();
+        args.push(&app);
+        let resolved = self.adb_shell(&serial, &args).await?;
+        let Some(component) = parsers::parse_resolved_activity(&resolved) else {
+            bail!("{} has no launcher activity", launch.app);
+        };
+
+        self.adb_shell(&serial, &[adb::AM, adb::AM_FORCE_STOP, &app])
+            .await?;
+        self.send_intent_to_serial(&serial, &launch.intent(component))
+            .await?;
+        Ok(component.to_string())
+    }
+
+    async fn send_intent_to_serial(&self, serial: &str, intent: &AndroidIntent) -> Result<()> {
+        let args: Vec<String> = intent
+            .am_args()?
+            .iter()
+            .map(|arg| quote_shell_arg(arg))
+            .collect();
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.adb_shell(serial, &args).await?;
+        if let Some(error) = output
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with("Error"))
+        {
+            bail!("{error}");
+        }
+        Ok(())
+    }
+
+    /// Writes a line to logcat under `tag`, e.g. to mark a manual test step.
+    pub async fn write_log_marker(&self, avd_name: &str, tag: &str, message: &str) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        self.adb_shell(
+            &serial,
+            &[adb::LOG, adb::LOG_TAG, tag, &quote_shell_arg(message)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Sets a system property on a running AVD, e.g. `debug.layout`.
+    pub async fn set_property(&self, avd_name: &str, name: &str, value: &str) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .adb_shell(
+                &serial,
+                &[
+                    adb::SETPROP,
+                    &quote_shell_arg(name),
+                    &quote_shell_arg(value),
+                ],
+            )
+            .await?;
+        // Prints nothing on success and "Failed to set property ..." for
+        // read-only or SELinux-protected properties
+        if let Some(error) = output.lines().map(str::trim).find(|line| !line.is_empty()) {
+            bail!("{error}");
+        }
+        Ok(())
+    }
+
+    /// Sets or shifts the clock of a running AVD and returns the date it
+    /// shows afterwards. Network time is turned off while the clock is
+    /// changed, as it would move the clock back.
+    pub async fn change_clock(&self, avd_name: &str, change: &ClockChange) -> Result<String> {
+        let serial = self.running_serial(avd_name).await?;
+        let now = self
+            .adb_shell(&serial, &[adb::DATE, &format!("+{}", adb::DATE_NOW_FORMAT)])
+            .await?;
+        let now = NaiveDateTime::parse_from_str(now.trim(), adb::DATE_NOW_FORMAT)
+            .with_context(|| format!("Unexpected device time '{}'", now.trim()))?;
+
+        match change.apply_to(now) {
+            Some(time) => {
+                self.restart_adbd_as_root(&serial).await?;
+                self.adb_shell(
+                    &serial,
+                    &[
+                        adb::SETTINGS,
+                        adb::PUT,
+                        adb::GLOBAL,
+                        adb::AUTO_TIME_SETTING,
+                        "0",
+                    ],
+                )
+                .await?;
+                self.adb_shell(
+                    &serial,
+                    &[adb::DATE, &time.format(adb::DATE_SET_FORMAT).to_string()],
+                )
+                .await?;
+            }
+            None => {
+                self.adb_shell(
+                    &serial,
+                    &[
+                        adb::SETTINGS,
+                        adb::PUT,
+                        adb::GLOBAL,
+                        adb::AUTO_TIME_SETTING,
+                        "1",
+                    ],
+                )
+                .await?;
+            }
+        }
+        Ok(self
+            .adb_shell(&serial, &[adb::DATE])
+            .await?
+            .trim()
+            .to_string())
+    }
+
+    /// Runs monkey against a package on a running AVD, passing each output
+    /// line to `on_line` as it arrives. Monkey exits with an error once the
+    /// app crashes, so a run that found crashes or ANRs still returns its
+    /// report.
+    pub async fn run_monkey(
+        &self,
+        avd_name: &str,
+        run: &MonkeyRun,
+        on_line: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<MonkeyReport> {
+        let serial = self.running_serial(avd_name).await?;
+        let report = std::sync::Mutex::new(MonkeyReport::default());
+        let record_line = |line: &str| {
+            report.lock().unwrap().push_line(line);
+            on_line(line);
+        };
+
+        let run_args = run.args();
+        let mut args = vec!["-s", serial.as_str(), adb::SHELL];
+        args.extend(run_args.iter().map(String::as_str));
+        let result = self
+            .command_executor
+            .run_streaming(Path::new(commands::ADB), &args, &record_line)
+            .await;
+
+        let report = report.into_inner().unwrap();
+        match result {
+            Err(error) if !report.has_failures() => {
+                Err(error).context("Failed to run adb shell monkey")
+            }
+            _ => Ok(report),
+        }
+    }
+
+    /// Stops a process on a running AVD. App processes are force-stopped
+    /// through the activity manager, which needs no root and also stops the
+    /// app's other processes; native daemons get a plain `kill`.
+    pub async fn kill_process(&self, avd_name: &str, process: &DeviceProcess) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        let pid = process.pid.to_string();
+        let args = match process.package_name() {
+            Some(package) => vec![adb::AM, adb::AM_FORCE_STOP, package],
+            None => vec![adb::KILL, pid.as_str()],
+        };
+        let output = self.adb_shell(&serial, &args).await?;
+        // Both print nothing on success; adb before shell protocol v2
+        // exits with 0 whatever the command returned
+        if let Some(error) = output.lines().map(str::trim).find(|line| !line.is_empty()) {
+            bail!("{error}");
+        }
+        Ok(())
+    }
+
+    /// Restarts adbd as root and waits until the device is back.
+    async fn restart_adbd_as_root(&self, serial: &str) -> Result<()> {
+        let output = self
+            .command_executor
+            .run_with_policy(
+                Path::new(commands::ADB),
+                &["-s", serial, adb::ROOT],
+                &CommandPolicy::ADB_DEVICE,
+            )
+            .await;
+        match output {
+            Ok(output) if !output.contains(adb::ROOT_REFUSED) => {}
+            Ok(_) => bail!(CLOCK_NEEDS_ROOT),
+            Err(e) if format!("{e:#}").contains(adb::ROOT_REFUSED) => bail!(CLOCK_NEEDS_ROOT),
+            Err(e) => return Err(e).context("Failed to restart adbd as root"),
+        }
+        self.command_executor
+            .run_with_policy(
+                Path::new(commands::ADB),
+                &["-s", serial, adb::WAIT_FOR_DEVICE],
+                &CommandPolicy::ADB_DEVICE,
+            )
+            .await
+            .context("The device did not come back after adb root")?;
+        Ok(())
+    }
+
+    pub(super) async fn adb_shell(&self, serial: &str, args: &[&str]) -> Result<String> {
+        let mut command = vec!["-s", serial, adb::SHELL];
+        command.extend_from_slice(args);
+        self.command_executor
+            .run(Path::new(commands::ADB), &command)
+            .await
+            .with_context(|| format!("Failed to run adb shell {}", args.join(" ")))
+    }
+
+    #[cfg(feature = "grpc")]
+    async fn grpc_client(&self, avd_name: &str) -> Option<super::grpc::EmulatorGrpcClient> {
+        let endpoint = super::grpc::find_endpoint(avd_name).await?;
+        super::grpc::EmulatorGrpcClient::connect(&endpoint)
+            .await
+            .map_err(|e| log::debug!("{e:#}"))
+            .ok()
+    }
+}
+
+/// Prepares text for `adb shell input text`: `input` reads `%s` as a space,
+/// and the quoting keeps the device shell from expanding anything else.
+pub(super) fn escape_input_text(text: &str) -> String {
+    quote_shell_arg(&text.replace(' ', "%s"))
+}
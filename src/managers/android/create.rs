@@ -1,4 +1,4 @@
-use super::AndroidManager;
+use super::{parsers, AndroidManager};
 use crate::{
     constants::{
         defaults,
@@ -6,10 +6,14 @@ use crate::{
             MAX_DEVICE_NAME_CREATE_LENGTH, MAX_DEVICE_NAME_PARTS_PROCESS, MAX_ERROR_MESSAGE_LENGTH,
             MIN_STRING_LENGTH_FOR_MATCH,
         },
+        messages::read_only,
+        progress::{CREATE_CONFIGURE_PERCENTAGE, CREATE_RESOLVE_PERCENTAGE},
     },
-    managers::common::{DeviceConfig, DeviceManager},
+    managers::common::DeviceConfig,
+    models::{DeviceCreationPreview, DeviceError, InstallProgress},
+    utils::text::truncate_to_width,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 impl AndroidManager {
     /// Get appropriate skin name for device type using dynamic lookup
@@ -219,10 +223,10 @@ impl AndroidManager {
         })
     }
 
-    pub(super) async fn create_device_internal(&self, config: &DeviceConfig) -> Result<()> {
-        let safe_name = config
-            .name
-            .chars()
+    /// Converts a display name into the AVD name avdmanager will accept.
+    /// Spaces and underscores become `_`; other unsupported characters are dropped.
+    pub fn sanitize_avd_name(name: &str) -> String {
+        name.chars()
             .filter_map(|c| match c {
                 c if c.is_ascii_alphanumeric() || c == '.' || c == '-' => Some(c),
                 ' ' | '_' => Some('_'),
@@ -230,7 +234,129 @@ impl AndroidManager {
             })
             .collect::<String>()
             .trim_matches('_')
-            .to_string();
+            .to_string()
+    }
+
+    /// Picks the system image tag and ABI for the requested API level. A tag set
+    /// in the config wins; otherwise an installed image is preferred over the
+    /// default values.
+    async fn resolve_system_image(&self, config: &DeviceConfig) -> Result<(String, String)> {
+        let configured_abi = || {
+            config
+                .additional_options
+                .get("abi")
+                .map_or(defaults::default_abi(), String::as_str)
+                .to_string()
+        };
+        if let Some(tag) = config.additional_options.get("tag") {
+            return Ok((tag.clone(), configured_abi()));
+        }
+        if let Some(found) = self
+            .get_first_available_system_image(&config.version)
+            .await?
+        {
+            return Ok(found);
+        }
+        Ok(("google_apis_playstore".to_string(), configured_abi()))
+    }
+
+    /// Resolves the avdmanager device profile ID for the configured device type.
+    async fn resolve_device_id(&self, config: &DeviceConfig) -> Result<Option<String>> {
+        if config.device_type.is_empty() || config.device_type.to_lowercase() == "custom" {
+            return Ok(None);
+        }
+        let available_devices = self.list_available_devices().await?;
+        Ok(Self::find_matching_device_id(
+            &available_devices,
+            &config.device_type,
+        ))
+    }
+
+    /// Resolves everything a device creation would use without creating anything.
+    pub async fn preview_device_creation(
+        &self,
+        config: &DeviceConfig,
+    ) -> Result<DeviceCreationPreview> {
+        let (tag, abi) = self.resolve_system_image(config).await?;
+        let image_installed = self
+            .check_system_image_available(&config.version, &tag, &abi)
+            .await
+            .unwrap_or(false);
+        let device_id = self.resolve_device_id(config).await?;
+        let skin = self
+            .get_appropriate_skin(
+                device_id.as_deref().unwrap_or(&config.device_type),
+                &config.device_type,
+            )
+            .await;
+        let (resolution, dpi) = match &skin {
+            Some(skin) => self.read_skin_display(skin).await,
+            None => (None, None),
+        };
+
+        Ok(DeviceCreationPreview {
+            avdmanager_path: self.avdmanager_path.to_string_lossy().to_string(),
+            package_path: format!("system-images;android-{};{tag};{abi}", config.version),
+            tag,
+            abi,
+            image_installed,
+            device_id,
+            skin,
+            resolution,
+            dpi,
+        })
+    }
+
+    /// Removes what a cancelled `avdmanager create avd` may have written: the
+    /// `<name>.avd` directory and its `<name>.ini` pointer in the AVD home.
+    pub async fn discard_partial_avd(&self, name: &str) -> Result<()> {
+        let safe_name = Self::sanitize_avd_name(name);
+        let Some(avd_root) = Self::avd_home() else {
+            return Ok(());
+        };
+        if safe_name.is_empty() {
+            return Ok(());
+        }
+
+        let avd_dir = avd_root.join(format!("{safe_name}.avd"));
+        let ini_file = avd_root.join(format!("{safe_name}.ini"));
+
+        if avd_dir.exists() {
+            log::info!("Removing partially created AVD directory {avd_dir:?}");
+            tokio::fs::remove_dir_all(&avd_dir)
+                .await
+                .with_context(|| format!("Failed to remove {}", avd_dir.display()))?;
+        }
+        if ini_file.exists() {
+            tokio::fs::remove_file(&ini_file)
+                .await
+                .with_context(|| format!("Failed to remove {}", ini_file.display()))?;
+        }
+
+        self.invalidate_device_metadata_cache(Some(&safe_name))
+            .await;
+        self.device_list_refresh.invalidate();
+        Ok(())
+    }
+
+    /// Creates an AVD, reporting the phases `avdmanager` prints as it goes.
+    pub async fn create_device_with_progress(
+        &self,
+        config: &DeviceConfig,
+        on_progress: &(dyn Fn(InstallProgress) + Send + Sync),
+    ) -> Result<()> {
+        self.read_only.ensure_writable(read_only::CREATE_DEVICE)?;
+        let result = self.create_device_internal(config, on_progress).await;
+        self.device_list_refresh.invalidate();
+        result
+    }
+
+    async fn create_device_internal(
+        &self,
+        config: &DeviceConfig,
+        on_progress: &(dyn Fn(InstallProgress) + Send + Sync),
+    ) -> Result<()> {
+        let safe_name = Self::sanitize_avd_name(&config.name);
 
         if safe_name.is_empty() {
             return Err(anyhow::anyhow!(
@@ -249,22 +375,13 @@ impl AndroidManager {
             ));
         }
 
-        let (tag, abi) = if let Some((found_tag, found_abi)) = self
-            .get_first_available_system_image(&config.version)
-            .await?
-        {
-            (found_tag, found_abi)
-        } else {
-            let default_tag = config
-                .additional_options
-                .get("tag")
-                .map_or("google_apis_playstore", |value| value.as_str());
-            let default_abi = config
-                .additional_options
-                .get("abi")
-                .map_or(defaults::default_abi(), |value| value.as_str());
-            (default_tag.to_string(), default_abi.to_string())
-        };
+        on_progress(InstallProgress {
+            operation: "Resolving system image...".to_string(),
+            percentage: CREATE_RESOLVE_PERCENTAGE,
+            eta_seconds: None,
+            bytes_per_second: None,
+        });
+        let (tag, abi) = self.resolve_system_image(config).await?;
 
         let package_path = format!("system-images;android-{};{};{}", config.version, tag, abi);
 
@@ -283,13 +400,7 @@ impl AndroidManager {
 
         let mut args = vec!["create", "avd", "-n", &safe_name, "-k", &package_path];
 
-        let device_param =
-            if !config.device_type.is_empty() && config.device_type.to_lowercase() != "custom" {
-                let available_devices = self.list_available_devices().await?;
-                Self::find_matching_device_id(&available_devices, &config.device_type)
-            } else {
-                None
-            };
+        let device_param = self.resolve_device_id(config).await?;
 
         if let Some(ref device_id) = device_param {
             args.push("--device");
@@ -301,31 +412,31 @@ impl AndroidManager {
             );
         }
 
-        let skin_name = if let Some(ref device_id) = device_param {
-            self.get_appropriate_skin(device_id, &config.device_type)
-                .await
-        } else {
-            self.get_appropriate_skin(&config.device_type, &config.device_type)
-                .await
-        };
+        let skin_name = self
+            .get_appropriate_skin(
+                device_param.as_deref().unwrap_or(&config.device_type),
+                &config.device_type,
+            )
+            .await;
 
         if let Some(ref skin) = skin_name {
             args.push("--skin");
             args.push(skin);
         }
 
+        let on_line = |line: &str| {
+            if let Some(progress) = parsers::parse_avd_create_progress(line) {
+                on_progress(progress);
+            }
+        };
         let result = self
             .command_executor
-            .run(&self.avdmanager_path, &args)
+            .run_streaming(&self.avdmanager_path, &args, &on_line)
             .await;
 
-        let result = if result.is_err() && skin_name.is_some() {
-            let error_str = result.as_ref().unwrap_err().to_string();
-            if error_str.to_lowercase().contains("skin") {
-                log::warn!(
-                    "Skin '{}' failed, retrying without skin",
-                    skin_name.as_ref().unwrap()
-                );
+        let result = match (&result, &skin_name) {
+            (Err(error), Some(skin)) if error.to_string().to_lowercase().contains("skin") => {
+                log::warn!("Skin '{skin}' failed, retrying without skin");
                 let mut fallback_args =
                     vec!["create", "avd", "-n", &safe_name, "-k", &package_path];
                 if let Some(ref device_id) = device_param {
@@ -333,17 +444,20 @@ impl AndroidManager {
                     fallback_args.push(device_id);
                 }
                 self.command_executor
-                    .run(&self.avdmanager_path, &fallback_args)
+                    .run_streaming(&self.avdmanager_path, &fallback_args, &on_line)
                     .await
-            } else {
-                result
             }
-        } else {
-            result
+            _ => result,
         };
 
         match result {
             Ok(_) => {
+                on_progress(InstallProgress {
+                    operation: "Applying device settings...".to_string(),
+                    percentage: CREATE_CONFIGURE_PERCENTAGE,
+                    eta_seconds: None,
+                    bytes_per_second: None,
+                });
                 if let Err(error) = self
                     .fine_tune_avd_config(&safe_name, config, &tag, &abi)
                     .await
@@ -369,15 +483,9 @@ impl AndroidManager {
                     || error_str.contains("package path")
                     || error_str.contains("not installed")
                 {
-                    Err(anyhow::anyhow!(
-                        "System image not installed for API {}\nRun: sdkmanager \"{}\"",
-                        config.version,
-                        package_path
-                    ))
+                    Err(DeviceError::image_missing(package_path).into())
                 } else if error_str.contains("license") || error_str.contains("accept") {
-                    Err(anyhow::anyhow!(
-                        "Android SDK licenses not accepted\nRun: sdkmanager --licenses"
-                    ))
+                    Err(DeviceError::LicenseNotAccepted.into())
                 } else if error_str.contains("already exists") {
                     Err(anyhow::anyhow!(
                         "AVD '{}' already exists\nDelete existing or choose different name",
@@ -405,14 +513,7 @@ impl AndroidManager {
                         &error_str
                     };
 
-                    let short_error = if key_error.len() > MAX_ERROR_MESSAGE_LENGTH {
-                        format!(
-                            "{error}...",
-                            error = &key_error[..MAX_ERROR_MESSAGE_LENGTH - 3]
-                        )
-                    } else {
-                        key_error.to_string()
-                    };
+                    let short_error = truncate_to_width(key_error, MAX_ERROR_MESSAGE_LENGTH);
 
                     Err(anyhow::anyhow!(
                         "AVD creation failed: {}\nAVD: {} | API: {}",
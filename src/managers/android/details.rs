@@ -1,11 +1,14 @@
-use super::{AndroidManager, AVD_NAME_REGEX, IMAGE_SYSDIR_REGEX, PATH_REGEX};
+use super::{parsers, AndroidManager, IMAGE_SYSDIR_REGEX};
 use crate::{
-    constants::{defaults, env_vars::HOME, files, limits::STORAGE_MB_TO_GB_DIVISOR},
+    constants::{
+        commands, defaults, files, limits::STORAGE_MB_TO_GB_DIVISOR,
+        messages::errors::DEVICE_NOT_FOUND,
+    },
     managers::common::DeviceConfig,
-    models::{DeviceDetails, Platform},
+    models::{DeviceDetails, DeviceProcess, DeviceService, Platform, SystemProperty},
 };
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 impl AndroidManager {
@@ -17,20 +20,133 @@ impl AndroidManager {
             .await
             .context("Failed to list Android AVDs")?;
 
-        let mut current_name = String::new();
+        Ok(parsers::AvdListParser::new(&avd_output)
+            .find(|avd| avd.name == avd_name && !avd.path.is_empty())
+            .map(|avd| PathBuf::from(avd.path)))
+    }
 
-        for line in avd_output.lines() {
-            let trimmed = line.trim();
-            if let Some(caps) = AVD_NAME_REGEX.captures(trimmed) {
-                current_name = caps[1].to_string();
-            } else if let Some(caps) = PATH_REGEX.captures(trimmed) {
-                if current_name == avd_name {
-                    return Ok(Some(PathBuf::from(caps[1].to_string())));
-                }
-            }
+    /// Data directory of an AVD, looked up through avdmanager when it is not
+    /// where the `<name>.ini` pointer says.
+    pub(super) async fn resolve_avd_dir(&self, avd_name: &str) -> Result<PathBuf> {
+        match Self::avd_data_dir(avd_name)
+            .await
+            .filter(|dir| dir.is_dir())
+        {
+            Some(dir) => Ok(dir),
+            None => self
+                .get_avd_path(avd_name)
+                .await?
+                .with_context(|| DEVICE_NOT_FOUND.replace("{}", avd_name)),
         }
+    }
+
+    /// Reads every `config.ini` entry of an AVD, sorted by key.
+    pub async fn read_device_config(&self, avd_name: &str) -> Result<Vec<(String, String)>> {
+        let default_path = Self::avd_data_dir(avd_name)
+            .await
+            .map(|data_dir| data_dir.join(files::CONFIG_FILE));
+        // AVDs created with `--path` are only found through avdmanager, which is slower
+        let config_path = match default_path.filter(|path| path.exists()) {
+            Some(path) => path,
+            None => self
+                .get_avd_path(avd_name)
+                .await?
+                .with_context(|| format!("AVD '{avd_name}' not found"))?
+                .join(files::CONFIG_FILE),
+        };
+        let content = fs::read_to_string(&config_path)
+            .await
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        Ok(parsers::parse_config_ini(&content))
+    }
+
+    /// Lists third-party packages installed on a running AVD.
+    pub async fn list_installed_packages(&self, avd_name: &str) -> Result<Vec<String>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .command_executor
+            .run(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::SHELL,
+                    commands::adb::PM,
+                    "list",
+                    "packages",
+                    "-3",
+                ],
+            )
+            .await
+            .context("Failed to list installed packages")?;
+        Ok(parsers::parse_package_list(&output))
+    }
+
+    /// Lists every process of a running AVD with its resident memory.
+    pub async fn list_processes(&self, avd_name: &str) -> Result<Vec<DeviceProcess>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .adb_shell(
+                &serial,
+                &[
+                    commands::adb::PS,
+                    commands::adb::PS_ALL,
+                    commands::adb::PS_FORMAT,
+                    commands::adb::PS_COLUMNS,
+                ],
+            )
+            .await?;
+        Ok(parsers::parse_process_list(&output))
+    }
 
-        Ok(None)
+    /// Lists the system properties of a running AVD.
+    pub async fn list_properties(&self, avd_name: &str) -> Result<Vec<SystemProperty>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self.adb_shell(&serial, &[commands::adb::GETPROP]).await?;
+        Ok(parsers::parse_getprop(&output))
+    }
+
+    /// Lists the services running on an AVD and the processes hosting them.
+    pub async fn list_services(&self, avd_name: &str) -> Result<Vec<DeviceService>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .adb_shell(
+                &serial,
+                &[
+                    commands::adb::DUMPSYS,
+                    commands::adb::ACTIVITY,
+                    commands::adb::SERVICES,
+                ],
+            )
+            .await?;
+        Ok(parsers::parse_activity_services(&output))
+    }
+
+    /// Reads the enabled state of each emulated sensor on a running AVD.
+    pub async fn sensor_status(&self, avd_name: &str) -> Result<Vec<(String, String)>> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .command_executor
+            .run(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::EMU,
+                    commands::adb::SENSOR,
+                    "status",
+                ],
+            )
+            .await
+            .context("Failed to read sensor status")?;
+        Ok(parsers::parse_sensor_status(&output))
+    }
+
+    pub(super) async fn running_serial(&self, avd_name: &str) -> Result<String> {
+        self.get_running_avd_names()
+            .await?
+            .remove(avd_name)
+            .with_context(|| format!("Device '{avd_name}' is not running"))
     }
 
     /// Fine-tune AVD configuration after creation with avdmanager
@@ -189,12 +305,8 @@ impl AndroidManager {
             "Stopped".to_string()
         };
 
-        if let Ok(home_dir) = std::env::var(HOME) {
-            let config_path = PathBuf::from(&home_dir)
-                .join(files::android::AVD_DIR)
-                .join(files::android::AVD_SUBDIR)
-                .join(format!("{avd_name}.avd"))
-                .join(files::CONFIG_FILE);
+        if let Some(avd_path) = Self::avd_data_dir(avd_name).await {
+            let config_path = avd_path.join(files::CONFIG_FILE);
 
             log::debug!("Checking config path: {config_path:?}");
             if config_path.exists() {
@@ -268,14 +380,9 @@ impl AndroidManager {
                     }
                 }
 
-                details.device_path =
-                    Some(config_path.parent().unwrap().to_string_lossy().to_string());
+                details.device_path = Some(avd_path.to_string_lossy().to_string());
             } else {
                 log::debug!("Config file not found for {avd_name}: {config_path:?}");
-                let avd_path = PathBuf::from(&home_dir)
-                    .join(files::android::AVD_DIR)
-                    .join(files::android::AVD_SUBDIR)
-                    .join(format!("{avd_name}.avd"));
                 details.device_path = Some(avd_path.to_string_lossy().to_string());
 
                 if details.ram_size.is_none() {
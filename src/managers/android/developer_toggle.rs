@@ -0,0 +1,56 @@
+//! GPU overdraw, layout bounds, strict mode and GPU profile bars of running
+//! AVDs.
+
+use super::{parsers, AndroidManager};
+use crate::constants::commands::adb;
+use crate::models::{DeveloperToggle, DeveloperToggleStatus};
+use anyhow::{bail, Result};
+
+impl AndroidManager {
+    /// Reads which developer toggles of a running AVD are on.
+    pub async fn developer_toggle_status(&self, avd_name: &str) -> Result<DeveloperToggleStatus> {
+        let serial = self.running_serial(avd_name).await?;
+        let output = self.adb_shell(&serial, &[adb::GETPROP]).await?;
+        let properties = parsers::parse_getprop(&output);
+        let mut status = DeveloperToggleStatus::default();
+        for toggle in DeveloperToggle::ANDROID {
+            let enabled = properties
+                .iter()
+                .find(|property| property.name == toggle.property())
+                .is_some_and(|property| toggle.parse_value(&property.value));
+            status.set(toggle, enabled);
+        }
+        Ok(status)
+    }
+
+    /// Switches a developer toggle of a running AVD and makes running apps
+    /// pick it up, as the developer options do.
+    pub async fn set_developer_toggle(
+        &self,
+        avd_name: &str,
+        toggle: DeveloperToggle,
+        enabled: bool,
+    ) -> Result<()> {
+        let Some(value) = toggle.value(enabled) else {
+            bail!("{} is not an Android toggle", toggle.label());
+        };
+        let serial = self.running_serial(avd_name).await?;
+        let output = self
+            .adb_shell(&serial, &[adb::SETPROP, toggle.property(), value])
+            .await?;
+        if let Some(error) = output.lines().map(str::trim).find(|line| !line.is_empty()) {
+            bail!("{error}");
+        }
+        self.adb_shell(
+            &serial,
+            &[
+                adb::SERVICE,
+                adb::SERVICE_CALL,
+                adb::ACTIVITY,
+                adb::SYSPROPS_TRANSACTION,
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//! Emulator connections reported by `adb track-devices`.
+//!
+//! adb prints the device list again whenever a device connects, disconnects
+//! or changes state. While the watcher runs, [`get_running_avd_names`]
+//! takes the attached emulators from the last update instead of running
+//! `adb devices`, and the AVD name cached for a serial is dropped as soon
+//! as the serial goes away, so an emulator that later reuses its console
+//! port is resolved again. When adb exits, e.g. after `adb kill-server`,
+//! lookups fall back to `adb devices` until the watcher is back.
+//!
+//! [`get_running_avd_names`]: AndroidManager::get_running_avd_names
+
+use super::{parsers, AndroidManager};
+use crate::constants::{commands, timeouts::DEVICE_WATCH_RESTART_DELAY};
+use crate::utils::{command::format_command_line, processes, tasks, tool_paths};
+use std::process::Stdio;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+
+impl AndroidManager {
+    /// Starts following emulator connections in the background for the
+    /// rest of the session.
+    pub fn watch_device_connections(&self) {
+        let manager = self.clone();
+        tasks::spawn(async move {
+            loop {
+                manager.track_devices().await;
+                *manager.attached_emulators.write().await = None;
+                tokio::time::sleep(DEVICE_WATCH_RESTART_DELAY).await;
+            }
+        });
+    }
+
+    /// Runs `adb track-devices` until it exits.
+    async fn track_devices(&self) {
+        let mut command = Command::new(tool_paths::resolve_program(commands::ADB.as_ref()));
+        command
+            .arg(commands::adb::TRACK_DEVICES)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .stdin(Stdio::null());
+        let child = processes::spawn(
+            &mut command,
+            &format_command_line(commands::ADB, [commands::adb::TRACK_DEVICES]),
+        );
+        let mut child = match child {
+            Ok(child) => child,
+            Err(error) => {
+                log::debug!("Failed to start adb track-devices: {error}");
+                return;
+            }
+        };
+        let Some(mut stdout) = child.stdout.take() else {
+            return;
+        };
+        while let Some(update) = read_update(&mut stdout).await {
+            self.set_attached_emulators(parsers::parse_attached_emulators(&update))
+                .await;
+        }
+        let _ = child.kill().await;
+    }
+
+    /// Takes the emulators of one update as the attached ones and forgets
+    /// the cached AVD names when the set of serials changed.
+    pub(super) async fn set_attached_emulators(&self, emulator_ids: Vec<String>) {
+        self.emulator_avd_names_cache
+            .write()
+            .await
+            .sync_serials(&emulator_ids);
+        *self.attached_emulators.write().await = Some(emulator_ids);
+        *self.adb_server_healthy.write().await = Some(true);
+    }
+}
+
+/// Reads one length-prefixed device list. `None` once adb exits or prints
+/// something else.
+async fn read_update(reader: &mut (impl AsyncRead + Unpin)) -> Option<String> {
+    let mut length = [0u8; 4];
+    reader.read_exact(&mut length).await.ok()?;
+    let length = usize::from_str_radix(std::str::from_utf8(&length).ok()?, 16).ok()?;
+    let mut update = vec![0u8; length];
+    reader.read_exact(&mut update).await.ok()?;
+    Some(String::from_utf8_lossy(&update).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_update_splits_length_prefixed_lists() {
+        let mut stream: &[u8] = b"0015emulator-5554\tdevice\n0000zz";
+        assert_eq!(
+            read_update(&mut stream).await.as_deref(),
+            Some("emulator-5554\tdevice\n")
+        );
+        assert_eq!(read_update(&mut stream).await.as_deref(), Some(""));
+        assert_eq!(read_update(&mut stream).await, None);
+    }
+}
@@ -1,13 +1,13 @@
-use super::{AndroidManager, ID_REGEX, NAME_REGEX, OEM_REGEX};
+use super::{grpc, parsers, AndroidManager};
 use crate::{
     constants::{
         commands, env_vars,
-        limits::{ANDROID_COMMAND_PARTS_MINIMUM, SYSTEM_IMAGE_PARTS_REQUIRED},
+        files::{self, SKIN_HARDWARE_FILE, SKIN_LAYOUT_FILE},
     },
     models::device_info::{
         ApiLevelInfo, DeviceCategory, DeviceInfo, DynamicDeviceConfig, DynamicDeviceProvider,
     },
-    utils::command_executor::CommandExecutor,
+    utils::command_executor::{CommandExecutor, CommandPolicy},
 };
 use anyhow::{Context, Result};
 use std::{
@@ -22,20 +22,45 @@ impl AndroidManager {
     pub async fn get_running_avd_names(&self) -> Result<HashMap<String, String>> {
         let mut avd_map = HashMap::new();
 
-        let adb_output = self
-            .command_executor
-            .run(Path::new(commands::ADB), &[commands::adb::DEVICES])
-            .await
-            .unwrap_or_default();
+        // The device watcher knows the attached emulators without asking adb
+        let attached = self.attached_emulators.read().await.clone();
+        let emulator_ids = match attached {
+            Some(emulator_ids) => emulator_ids,
+            None => {
+                let adb_output = self
+                    .command_executor
+                    .run_with_policy(
+                        Path::new(commands::ADB),
+                        &[commands::adb::DEVICES],
+                        &CommandPolicy::ADB_DEVICE,
+                    )
+                    .await;
+                *self.adb_server_healthy.write().await = Some(adb_output.is_ok());
+                parsers::parse_attached_emulators(&adb_output.unwrap_or_default())
+            }
+        };
 
-        let emulator_ids: Vec<String> = adb_output
-            .lines()
-            .filter(|line| line.contains("emulator-") && line.contains("device"))
-            .filter_map(|line| line.split_whitespace().next().map(ToString::to_string))
-            .collect();
+        // Only emulators that appeared since the last query need resolving
+        let (mut running, unresolved) =
+            self.partition_cached_emulator_avd_names(emulator_ids).await;
+
+        // One read of the emulator discovery files names every emulator that
+        // announced itself; adb is asked only about the rest, one by one
+        let announced = if unresolved.is_empty() {
+            HashMap::new()
+        } else {
+            let files = grpc::discovery_files().await;
+            parsers::parse_discovery_serials(files.iter().map(|(_, content)| content.as_str()))
+        };
 
         let mut join_set = JoinSet::new();
-        for emulator_id in emulator_ids {
+        for emulator_id in unresolved {
+            if let Some(avd_name) = announced.get(&emulator_id) {
+                self.set_cached_emulator_avd_name(emulator_id.clone(), avd_name.clone())
+                    .await;
+                running.push((avd_name.clone(), emulator_id));
+                continue;
+            }
             let command_executor = self.command_executor.clone();
             join_set.spawn(async move {
                 Self::resolve_running_avd_name(command_executor, emulator_id).await
@@ -44,12 +69,18 @@ impl AndroidManager {
 
         while let Some(result) = join_set.join_next().await {
             if let Ok(Some((avd_name, emulator_id))) = result {
-                avd_map.insert(avd_name.clone(), emulator_id.clone());
+                self.set_cached_emulator_avd_name(emulator_id.clone(), avd_name.clone())
+                    .await;
+                running.push((avd_name, emulator_id));
+            }
+        }
 
-                let normalized = avd_name.replace(' ', "_");
-                if normalized != avd_name {
-                    avd_map.entry(normalized).or_insert(emulator_id);
-                }
+        for (avd_name, emulator_id) in running {
+            avd_map.insert(avd_name.clone(), emulator_id.clone());
+
+            let normalized = avd_name.replace(' ', "_");
+            if normalized != avd_name {
+                avd_map.entry(normalized).or_insert(emulator_id);
             }
         }
 
@@ -61,7 +92,7 @@ impl AndroidManager {
         emulator_id: String,
     ) -> Option<(String, String)> {
         if let Ok(boot_prop_output) = command_executor
-            .run(
+            .run_with_policy(
                 Path::new(commands::ADB),
                 &[
                     "-s",
@@ -70,6 +101,7 @@ impl AndroidManager {
                     "getprop",
                     "ro.boot.qemu.avd_name",
                 ],
+                &CommandPolicy::ADB_DEVICE,
             )
             .await
         {
@@ -80,9 +112,10 @@ impl AndroidManager {
         }
 
         if let Ok(avd_name_output) = command_executor
-            .run(
+            .run_with_policy(
                 Path::new(commands::ADB),
                 &["-s", &emulator_id, "emu", "avd", "name"],
+                &CommandPolicy::ADB_DEVICE,
             )
             .await
         {
@@ -104,7 +137,7 @@ impl AndroidManager {
         }
 
         if let Ok(prop_output) = command_executor
-            .run(
+            .run_with_policy(
                 Path::new(commands::ADB),
                 &[
                     "-s",
@@ -113,6 +146,7 @@ impl AndroidManager {
                     "getprop",
                     "ro.kernel.qemu.avd_name",
                 ],
+                &CommandPolicy::ADB_DEVICE,
             )
             .await
         {
@@ -131,20 +165,13 @@ impl AndroidManager {
         }
 
         log::debug!("list_available_targets called");
-        let installed_images = self.list_available_system_images().await?;
+        let installed_images = self.installed_system_images().await?;
         let mut targets = std::collections::HashMap::new();
 
         for image in installed_images {
-            let parts: Vec<&str> = image.split(';').collect();
-            if parts.len() >= SYSTEM_IMAGE_PARTS_REQUIRED {
-                if let Some(api_level) = parts.get(1).and_then(|part| part.strip_prefix("android-"))
-                {
-                    let api_num: u32 = api_level.parse().unwrap_or(0);
-                    let android_version = self.get_android_version_name(api_num);
-                    let display = format!("API {api_level} - {android_version}");
-                    targets.insert(api_level.to_string(), display);
-                }
-            }
+            let android_version = self.android_version_label(image.api_level().unwrap_or(0));
+            let display = format!("API {} - {android_version}", image.api);
+            targets.insert(image.api, display);
         }
 
         let mut result: Vec<(String, String)> = targets.into_iter().collect();
@@ -174,40 +201,13 @@ impl AndroidManager {
             .await
             .context("Failed to list Android devices")?;
 
-        let mut devices = Vec::new();
-        let mut current_id = String::new();
-        let mut current_name = String::new();
-        let mut current_oem = String::new();
-
-        for line in output.lines() {
-            if let Some(caps) = ID_REGEX.captures(line) {
-                current_id = caps[1].to_string();
-            } else if let Some(caps) = NAME_REGEX.captures(line) {
-                current_name = caps[1].to_string();
-            } else if let Some(caps) = OEM_REGEX.captures(line) {
-                current_oem = caps[1].to_string();
-            } else if line.contains("-----") && !current_id.is_empty() {
-                let display = if !current_oem.is_empty() && current_oem != "Generic" {
-                    format!("{current_name} ({current_oem})")
-                } else {
-                    current_name.clone()
-                };
-
-                devices.push((current_id.clone(), display));
-                current_id.clear();
-                current_name.clear();
-                current_oem.clear();
-            }
-        }
-
-        if !current_id.is_empty() {
-            let display = if !current_oem.is_empty() && current_oem != "Generic" {
-                format!("{current_name} ({current_oem})")
-            } else {
-                current_name.clone()
-            };
-            devices.push((current_id, display));
-        }
+        let mut devices: Vec<(String, String)> = parsers::parse_device_definitions(&output)
+            .into_iter()
+            .map(|definition| {
+                let display = definition.display_name();
+                (definition.id, display)
+            })
+            .collect();
 
         if devices.is_empty() {
             log::warn!(
@@ -227,104 +227,7 @@ impl AndroidManager {
     }
 
     pub fn get_device_category(&self, device_id: &str, device_display: &str) -> String {
-        let combined = format!(
-            "{} {}",
-            device_id.to_lowercase(),
-            device_display.to_lowercase()
-        );
-
-        if combined.contains("phone")
-            || combined.contains("pixel")
-                && !combined.contains("fold")
-                && !combined.contains("tablet")
-            || combined.contains("galaxy")
-                && !combined.contains("fold")
-                && !combined.contains("tablet")
-            || combined.contains("oneplus")
-            || combined.contains("iphone")
-            || Self::is_phone_size(&combined)
-            || (combined.contains("pro")
-                && !combined.contains("tablet")
-                && !combined.contains("fold"))
-        {
-            return "phone".to_string();
-        }
-
-        if combined.contains("tablet")
-            || combined.contains("pad")
-            || Self::is_tablet_size(&combined)
-        {
-            return "tablet".to_string();
-        }
-
-        if combined.contains("wear")
-            || combined.contains("watch")
-            || combined.contains("round") && !combined.contains("tablet")
-            || combined.contains("square") && !combined.contains("tablet")
-        {
-            return "wear".to_string();
-        }
-
-        if combined.contains("tv")
-            || combined.contains("1080p")
-            || combined.contains("4k")
-            || combined.contains("720p")
-        {
-            return "tv".to_string();
-        }
-
-        if combined.contains("auto") || combined.contains("car") || combined.contains("automotive")
-        {
-            return "automotive".to_string();
-        }
-
-        if combined.contains("desktop")
-            || combined.contains("foldable") && combined.contains("large")
-            || Self::is_desktop_size(&combined)
-        {
-            return "desktop".to_string();
-        }
-
-        "phone".to_string()
-    }
-
-    fn is_phone_size(combined: &str) -> bool {
-        if !combined.contains("inch") {
-            return false;
-        }
-
-        for size in ["5", "6"] {
-            if combined.contains(size) {
-                return true;
-            }
-        }
-        false
-    }
-
-    fn is_tablet_size(combined: &str) -> bool {
-        if !combined.contains("inch") {
-            return false;
-        }
-
-        for size in ["10", "11", "12", "13"] {
-            if combined.contains(size) {
-                return true;
-            }
-        }
-        false
-    }
-
-    fn is_desktop_size(combined: &str) -> bool {
-        if !combined.contains("inch") {
-            return false;
-        }
-
-        for size in ["15", "17"] {
-            if combined.contains(size) {
-                return true;
-            }
-        }
-        false
+        DynamicDeviceConfig::detect_android_device_category(device_id, device_display)
     }
 
     pub async fn list_devices_by_category(
@@ -400,6 +303,85 @@ impl AndroidManager {
         Ok(skins)
     }
 
+    /// Reads the screen resolution and density declared by a skin.
+    /// Looks in the SDK's `skins` directory first, then in each platform's skins.
+    pub(super) async fn read_skin_display(&self, skin: &str) -> (Option<String>, Option<u32>) {
+        let mut skin_dirs = vec![self.android_home.join(files::android::SKINS_DIR).join(skin)];
+        if let Ok(mut platform_entries) =
+            fs::read_dir(self.android_home.join(files::android::PLATFORMS_DIR)).await
+        {
+            while let Some(platform_entry) = platform_entries.next_entry().await.ok().flatten() {
+                skin_dirs.push(
+                    platform_entry
+                        .path()
+                        .join(files::android::SKINS_DIR)
+                        .join(skin),
+                );
+            }
+        }
+
+        for skin_dir in skin_dirs {
+            let hardware = fs::read_to_string(skin_dir.join(SKIN_HARDWARE_FILE))
+                .await
+                .unwrap_or_default();
+            let layout = fs::read_to_string(skin_dir.join(SKIN_LAYOUT_FILE))
+                .await
+                .unwrap_or_default();
+            if hardware.is_empty() && layout.is_empty() {
+                continue;
+            }
+            return Self::parse_skin_display(&hardware, &layout);
+        }
+
+        (None, None)
+    }
+
+    /// Parses `hardware.ini` (`hw.lcd.*` keys) and falls back to the display
+    /// block of the skin `layout` file for the resolution.
+    pub(super) fn parse_skin_display(
+        hardware: &str,
+        layout: &str,
+    ) -> (Option<String>, Option<u32>) {
+        let mut width = None;
+        let mut height = None;
+        let mut density = None;
+
+        for line in hardware.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                let value = value.trim().parse::<u32>().ok();
+                match key.trim() {
+                    "hw.lcd.width" => width = value,
+                    "hw.lcd.height" => height = value,
+                    "hw.lcd.density" => density = value,
+                    _ => {}
+                }
+            }
+        }
+
+        if width.is_none() || height.is_none() {
+            let mut words = layout.split_whitespace();
+            while let Some(word) = words.next() {
+                match word {
+                    "width" if width.is_none() => {
+                        width = words.next().and_then(|value| value.parse().ok())
+                    }
+                    "height" if height.is_none() => {
+                        height = words.next().and_then(|value| value.parse().ok())
+                    }
+                    _ => {}
+                }
+                if width.is_some() && height.is_some() {
+                    break;
+                }
+            }
+        }
+
+        let resolution = width
+            .zip(height)
+            .map(|(width, height)| format!("{width}x{height}"));
+        (resolution, density)
+    }
+
     async fn scan_skin_directory(&self, skin_dir: &std::path::Path, skins: &mut Vec<String>) {
         if let Ok(mut entries) = fs::read_dir(skin_dir).await {
             while let Some(entry) = entries.next_entry().await.ok().flatten() {
@@ -463,17 +445,12 @@ impl AndroidManager {
         &self,
         api_level: u32,
     ) -> Result<Vec<String>> {
-        let images = self.list_available_system_images().await?;
-        let mut tags = HashSet::new();
-
-        for image in images {
-            if image.contains(&format!("android-{api_level}")) {
-                let parts: Vec<&str> = image.split(';').collect();
-                if parts.len() >= ANDROID_COMMAND_PARTS_MINIMUM {
-                    tags.insert(parts[2].to_string());
-                }
-            }
-        }
+        let images = self.installed_system_images().await?;
+        let tags: HashSet<String> = images
+            .into_iter()
+            .filter(|image| image.api_level() == Some(api_level))
+            .map(|image| image.tag)
+            .collect();
 
         Ok(tags.into_iter().collect())
     }
@@ -532,7 +509,7 @@ impl DynamicDeviceProvider for AndroidManager {
             }
         }
 
-        api_infos.sort_by(|a, b| b.level.cmp(&a.level));
+        api_infos.sort_by_key(|info| std::cmp::Reverse(info.level));
         Ok(api_infos)
     }
 
@@ -0,0 +1,246 @@
+//! Emulator gRPC control channel
+//!
+//! Recent emulator versions serve `android.emulation.control.EmulatorController`
+//! over gRPC and announce the port in a discovery file
+//! (`<runtime dir>/avd/running/pid_<pid>.ini`). Talking to that endpoint
+//! avoids spawning `adb` or console commands for screenshots, input, and
+//! status checks. Finding the endpoint is always available; the client itself
+//! is built with the `grpc` feature.
+
+use super::parsers;
+use crate::constants::{env_vars, files};
+use std::path::PathBuf;
+
+/// Where a running emulator serves gRPC, read from its discovery file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmulatorGrpcEndpoint {
+    /// Name of the AVD the emulator runs
+    pub avd_name: String,
+    /// Local port of the gRPC server
+    pub port: u16,
+    /// Bearer token, when the emulator requires one
+    pub token: Option<String>,
+}
+
+impl EmulatorGrpcEndpoint {
+    /// Parses a discovery file (`grpc.port=8554`, `avd.name=Pixel_7`, ...).
+    /// Emulators started without gRPC have no `grpc.port` entry.
+    pub fn parse(content: &str) -> Option<Self> {
+        let entries = parsers::parse_config_ini(content);
+        let value = |key: &str| {
+            entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.clone())
+                .filter(|value| !value.is_empty())
+        };
+
+        Some(Self {
+            avd_name: value("avd.name")?,
+            port: value("grpc.port")?.parse().ok()?,
+            token: value("grpc.token"),
+        })
+    }
+}
+
+/// Directory the emulator writes its discovery files to.
+fn discovery_dir() -> Option<PathBuf> {
+    let base = if cfg!(target_os = "macos") {
+        PathBuf::from(std::env::var(env_vars::HOME).ok()?).join(files::android::MACOS_CACHES_DIR)
+    } else {
+        PathBuf::from(std::env::var(env_vars::XDG_RUNTIME_DIR).ok()?)
+    };
+    Some(base.join(files::android::EMULATOR_DISCOVERY_DIR))
+}
+
+/// Discovery files of the running emulators, as (path, content) pairs.
+pub(super) async fn discovery_files() -> Vec<(PathBuf, String)> {
+    let Some(dir) = discovery_dir() else {
+        return Vec::new();
+    };
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return Vec::new();
+    };
+
+    let mut files = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ini") {
+            continue;
+        }
+        if let Ok(content) = tokio::fs::read_to_string(&path).await {
+            files.push((path, content));
+        }
+    }
+    files
+}
+
+/// Finds the gRPC endpoint of the running emulator for `avd_name`, if it
+/// announced one.
+pub async fn find_endpoint(avd_name: &str) -> Option<EmulatorGrpcEndpoint> {
+    discovery_files()
+        .await
+        .iter()
+        .filter_map(|(_, content)| EmulatorGrpcEndpoint::parse(content))
+        .find(|endpoint| endpoint.avd_name == avd_name)
+}
+
+#[cfg(feature = "grpc")]
+pub use client::{EmulatorGrpcClient, EmulatorStatus};
+
+#[cfg(feature = "grpc")]
+mod client {
+    use super::EmulatorGrpcEndpoint;
+    use crate::constants::timeouts::EMULATOR_GRPC_CONNECT_TIMEOUT;
+    use anyhow::{Context, Result};
+    use tonic::{
+        codec::ProstCodec,
+        codegen::http::uri::PathAndQuery,
+        transport::{Channel, Endpoint},
+    };
+
+    const GET_STATUS: &str = "/android.emulation.control.EmulatorController/getStatus";
+    const GET_SCREENSHOT: &str = "/android.emulation.control.EmulatorController/getScreenshot";
+    const SEND_KEY: &str = "/android.emulation.control.EmulatorController/sendKey";
+
+    /// The subset of `emulator_controller.proto` emu uses. Fields emu does
+    /// not read are left out; prost skips them when decoding.
+    mod proto {
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Empty {}
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct EmulatorStatus {
+            #[prost(string, tag = "1")]
+            pub version: String,
+            #[prost(uint64, tag = "2")]
+            pub uptime: u64,
+            #[prost(bool, tag = "3")]
+            pub booted: bool,
+        }
+
+        /// `format` 0 is PNG
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct ImageFormat {
+            #[prost(int32, tag = "1")]
+            pub format: i32,
+        }
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Image {
+            #[prost(bytes = "vec", tag = "4")]
+            pub image: Vec<u8>,
+        }
+
+        /// `event_type` 2 is a key press (down and up)
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct KeyboardEvent {
+            #[prost(int32, tag = "2")]
+            pub event_type: i32,
+            #[prost(string, tag = "5")]
+            pub text: String,
+        }
+    }
+
+    /// What the emulator reports about itself.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct EmulatorStatus {
+        /// Emulator version
+        pub version: String,
+        /// Milliseconds since the emulator started
+        pub uptime_ms: u64,
+        /// Whether Android finished booting
+        pub booted: bool,
+    }
+
+    /// Client for the gRPC endpoint of one running emulator.
+    #[derive(Debug, Clone)]
+    pub struct EmulatorGrpcClient {
+        grpc: tonic::client::Grpc<Channel>,
+        token: Option<String>,
+    }
+
+    impl EmulatorGrpcClient {
+        /// Connects to the endpoint on localhost.
+        pub async fn connect(endpoint: &EmulatorGrpcEndpoint) -> Result<Self> {
+            let channel = Endpoint::from_shared(format!("http://127.0.0.1:{}", endpoint.port))?
+                .connect_timeout(EMULATOR_GRPC_CONNECT_TIMEOUT)
+                .connect()
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to connect to the gRPC endpoint of '{}' on port {}",
+                        endpoint.avd_name, endpoint.port
+                    )
+                })?;
+
+            Ok(Self {
+                grpc: tonic::client::Grpc::new(channel),
+                token: endpoint.token.clone(),
+            })
+        }
+
+        pub async fn status(&self) -> Result<EmulatorStatus> {
+            let status: proto::EmulatorStatus = self.unary(GET_STATUS, proto::Empty {}).await?;
+            Ok(EmulatorStatus {
+                version: status.version,
+                uptime_ms: status.uptime,
+                booted: status.booted,
+            })
+        }
+
+        /// Captures the default display as PNG.
+        pub async fn screenshot(&self) -> Result<Vec<u8>> {
+            let image: proto::Image = self
+                .unary(GET_SCREENSHOT, proto::ImageFormat { format: 0 })
+                .await?;
+            Ok(image.image)
+        }
+
+        /// Types `text` as if entered on a keyboard.
+        pub async fn send_text(&self, text: &str) -> Result<()> {
+            let _: proto::Empty = self
+                .unary(
+                    SEND_KEY,
+                    proto::KeyboardEvent {
+                        event_type: 2,
+                        text: text.to_string(),
+                    },
+                )
+                .await?;
+            Ok(())
+        }
+
+        async fn unary<Request, Response>(
+            &self,
+            method: &'static str,
+            message: Request,
+        ) -> Result<Response>
+        where
+            Request: prost::Message + Send + 'static,
+            Response: prost::Message + Default + Send + 'static,
+        {
+            let mut grpc = self.grpc.clone();
+            grpc.ready()
+                .await
+                .context("Emulator gRPC endpoint is not ready")?;
+
+            let mut request = tonic::Request::new(message);
+            if let Some(token) = &self.token {
+                request
+                    .metadata_mut()
+                    .insert("authorization", format!("Bearer {token}").parse()?);
+            }
+
+            let response = grpc
+                .unary(
+                    request,
+                    PathAndQuery::from_static(method),
+                    ProstCodec::default(),
+                )
+                .await
+                .with_context(|| format!("Emulator gRPC call {method} failed"))?;
+            Ok(response.into_inner())
+        }
+    }
+}
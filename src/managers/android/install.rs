@@ -1,21 +1,33 @@
-use super::AndroidManager;
+use super::{parsers, AndroidManager};
 use crate::{
     constants::{
-        android, commands,
-        keywords::{LOG_LEVEL_ERROR, LOG_LEVEL_FAILED},
-        limits::SYSTEM_IMAGE_PARTS_REQUIRED,
+        android, commands, files,
+        messages::{
+            errors::{AVD_HOME_NOT_FOUND, COMMAND_TIMED_OUT, OFFLINE_INSTALL},
+            read_only,
+        },
+        performance::API_LEVEL_STREAM_BATCH,
         progress::{
             COMPLETION_THRESHOLD_PERCENTAGE, DOWNLOAD_PHASE_INCREMENT,
-            DOWNLOAD_PHASE_START_PERCENTAGE, DOWNLOAD_PROGRESS_MULTIPLIER, EXTRACT_PHASE_INCREMENT,
+            DOWNLOAD_PHASE_START_PERCENTAGE, EXTRACT_PHASE_INCREMENT,
             EXTRACT_PHASE_START_PERCENTAGE, INSTALL_PHASE_START_PERCENTAGE,
-            LOADING_PHASE_INCREMENT, PROGRESS_PHASE_100_PERCENT, PROGRESS_PHASE_75_PERCENT,
-            PROGRESS_PHASE_85_PERCENT,
+            LOADING_PHASE_INCREMENT,
         },
-        timeouts::DEVICE_START_WAIT_TIME,
+        timeouts::{INSTALL_PROGRESS_TICK, SDKMANAGER_NETWORK_TIMEOUT},
     },
-    models::{ApiLevel, InstallProgress, SystemImageVariant},
+    models::{ApiLevel, DownloadEstimate, InstallProgress, SystemImageVariant},
+    utils::{processes, tasks, CommandLine, LossyLines},
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
+
+/// Sets the flag when dropped, so background progress stops with its operation.
+struct StopOnDrop(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+impl Drop for StopOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
 
 impl AndroidManager {
     /// Lists available API levels with their installation status and Android version names.
@@ -25,95 +37,201 @@ impl AndroidManager {
         }
 
         let output = self.get_sdkmanager_verbose_output().await?;
-        let api_levels = self.parse_api_levels_from_output(&output);
+        let mut api_levels = self.parse_api_levels_from_output(&output);
+        self.measure_installed_images(&mut api_levels).await;
         self.set_cached_api_levels(api_levels.clone()).await;
 
         Ok(api_levels)
     }
 
+    #[cfg_attr(not(feature = "tui"), allow(dead_code))]
     pub(crate) async fn list_api_levels_fresh(&self) -> Result<Vec<ApiLevel>> {
         let output = self.refresh_sdkmanager_verbose_output().await?;
-        let api_levels = self.parse_api_levels_from_output(&output);
+        let mut api_levels = self.parse_api_levels_from_output(&output);
+        self.measure_installed_images(&mut api_levels).await;
+        self.set_cached_api_levels(api_levels.clone()).await;
+
+        Ok(api_levels)
+    }
+
+    /// Like [`list_api_levels`](Self::list_api_levels), but parses
+    /// sdkmanager's output while it is still being printed and passes the
+    /// API levels found so far to `on_progress` every few system images.
+    /// Large SDK listings take several seconds, so callers can show the
+    /// first levels long before the command finishes.
+    pub async fn list_api_levels_progressive<F>(&self, on_progress: F) -> Result<Vec<ApiLevel>>
+    where
+        F: Fn(Vec<ApiLevel>) + Send + Sync,
+    {
+        if let Some(cached_levels) = self.get_cached_api_levels().await {
+            return Ok(cached_levels);
+        }
+
+        let output = match self.get_cached_sdkmanager_verbose_output().await {
+            Some(output) => output,
+            None => {
+                let output = match self.stream_sdkmanager_verbose_output(&on_progress).await {
+                    Ok(output) => output,
+                    Err(error) => {
+                        log::warn!("Streaming sdkmanager failed, retrying without: {error:#}");
+                        self.load_sdkmanager_verbose_output().await?
+                    }
+                };
+                self.set_cached_sdkmanager_verbose_output(output.clone())
+                    .await;
+                output
+            }
+        };
+
+        let mut api_levels = self.parse_api_levels_from_output(&output);
+        self.measure_installed_images(&mut api_levels).await;
         self.set_cached_api_levels(api_levels.clone()).await;
 
         Ok(api_levels)
     }
 
+    /// Runs `sdkmanager --list --verbose`, reporting the API levels parsed
+    /// so far after every [`API_LEVEL_STREAM_BATCH`] system images.
+    async fn stream_sdkmanager_verbose_output(
+        &self,
+        on_progress: &(dyn Fn(Vec<ApiLevel>) + Send + Sync),
+    ) -> Result<String> {
+        let sdkmanager_path = Self::find_tool(&self.android_home, commands::SDKMANAGER)?;
+        // The parser and the package count at the last report
+        let progress = std::sync::Mutex::new((parsers::SystemImageParser::default(), 0));
+        let on_line = |line: &str| {
+            let mut progress = progress.lock().unwrap();
+            let (parser, reported) = &mut *progress;
+            if parser.push_line(line)
+                && parser.packages().len() >= *reported + API_LEVEL_STREAM_BATCH
+            {
+                *reported = parser.packages().len();
+                let api_levels = self.api_levels_from_system_images(parser.packages().to_vec());
+                drop(progress);
+                on_progress(api_levels);
+            }
+        };
+
+        tokio::time::timeout(
+            SDKMANAGER_NETWORK_TIMEOUT,
+            self.command_executor.run_streaming(
+                &sdkmanager_path,
+                &self.sdkmanager_list_args(),
+                &on_line,
+            ),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                COMMAND_TIMED_OUT.replace("{}", &SDKMANAGER_NETWORK_TIMEOUT.as_secs().to_string())
+            )
+        })?
+    }
+
     fn parse_api_levels_from_output(&self, output_str: &str) -> Vec<ApiLevel> {
+        let system_images = parsers::parse_system_images(output_str);
+        if system_images.is_empty() {
+            return self.default_api_levels();
+        }
+        self.api_levels_from_system_images(system_images)
+    }
+
+    /// Groups system images by API level, newest first.
+    fn api_levels_from_system_images(
+        &self,
+        system_images: Vec<parsers::SystemImagePackage>,
+    ) -> Vec<ApiLevel> {
         let mut api_levels_map: std::collections::HashMap<u32, ApiLevel> =
             std::collections::HashMap::new();
-        let mut in_installed_section = false;
-        let mut found_system_images = false;
 
-        for line in output_str.lines() {
-            let line = line.trim();
-
-            if line.contains("Installed packages") || line.contains("Installed Packages") {
-                in_installed_section = true;
-                continue;
-            } else if line.contains("Available Packages") || line.contains("Available Updates") {
-                in_installed_section = false;
+        for image in system_images {
+            let Some(api_level) = image.api_level() else {
                 continue;
-            }
+            };
+
+            let mut variant = SystemImageVariant::new(image.tag, image.abi, image.package_id);
+            variant.is_installed = image.installed;
+            variant.download_size = image.download_size;
+
+            let api_entry = api_levels_map.entry(api_level).or_insert_with(|| {
+                let version_name = self.android_version_label(api_level);
+                ApiLevel::new(
+                    api_level,
+                    version_name,
+                    format!("system-images;android-{api_level};google_apis;x86_64"),
+                )
+            });
+            api_entry.variants.push(variant);
 
-            if line.starts_with("system-images;android-") {
-                found_system_images = true;
-                let package_id = line.split_whitespace().next().unwrap_or(line);
-
-                if let Some(api_level) = self.parse_api_level_from_package(package_id) {
-                    let is_installed = in_installed_section;
-                    let parts: Vec<&str> = package_id.split(';').collect();
-                    if parts.len() >= SYSTEM_IMAGE_PARTS_REQUIRED {
-                        let variant = parts[2].to_string();
-                        let architecture = parts[3].to_string();
-
-                        let system_variant = SystemImageVariant::new(
-                            variant.clone(),
-                            architecture,
-                            package_id.to_string(),
-                        );
+            if image.installed {
+                api_entry.is_installed = true;
+            }
+        }
 
-                        let api_entry = api_levels_map.entry(api_level).or_insert_with(|| {
-                            let version_name = self.get_android_version_name(api_level);
-                            ApiLevel::new(
-                                api_level,
-                                version_name,
-                                format!("system-images;android-{api_level};google_apis;x86_64"),
-                            )
-                        });
+        let mut api_levels: Vec<ApiLevel> = api_levels_map.into_values().collect();
+        api_levels.sort_by_key(|level| std::cmp::Reverse(level.api));
+        api_levels
+    }
 
-                        let mut variant_clone = system_variant;
-                        variant_clone.is_installed = is_installed;
-                        api_entry.variants.push(variant_clone);
+    /// The most recent API levels, offered when sdkmanager lists no system
+    /// images at all.
+    fn default_api_levels(&self) -> Vec<ApiLevel> {
+        let max_api: u32 = 35;
+        let start_api = max_api.saturating_sub(android::DEFAULT_API_LEVELS_COUNT as u32 - 1);
+        let start_api = start_api.max(android::DEFAULT_MIN_API_LEVEL);
+
+        (start_api..=max_api)
+            .rev()
+            .map(|api| {
+                ApiLevel::new(
+                    api,
+                    self.android_version_label(api),
+                    format!("system-images;android-{api};google_apis;x86_64"),
+                )
+            })
+            .collect()
+    }
 
-                        if is_installed {
-                            api_entry.is_installed = true;
-                        }
-                    }
-                }
-            }
+    /// Fills in the disk size of each installed image from its directory in
+    /// the SDK (`system-images/android-34/google_apis/x86_64`).
+    async fn measure_installed_images(&self, api_levels: &mut [ApiLevel]) {
+        let package_ids: Vec<String> = api_levels
+            .iter()
+            .flat_map(|api_level| &api_level.variants)
+            .filter(|variant| variant.is_installed)
+            .map(|variant| variant.package_id.clone())
+            .collect();
+        if package_ids.is_empty() {
+            return;
         }
 
-        if !found_system_images {
-            let max_api = api_levels_map.keys().max().copied().unwrap_or(35);
-            let start_api = max_api.saturating_sub(android::DEFAULT_API_LEVELS_COUNT as u32 - 1);
-            let start_api = start_api.max(android::DEFAULT_MIN_API_LEVEL);
-
-            for api in start_api..=max_api {
-                api_levels_map.entry(api).or_insert_with(|| {
-                    let version_name = self.get_android_version_name(api);
-                    ApiLevel::new(
-                        api,
-                        version_name,
-                        format!("system-images;android-{api};google_apis;x86_64"),
-                    )
-                });
+        let android_home = self.android_home.clone();
+        let sizes = tokio::task::spawn_blocking(move || {
+            package_ids
+                .into_iter()
+                .filter_map(|package_id| {
+                    let size = directory_size(&android_home.join(package_id.replace(';', "/")))?;
+                    Some((package_id, size))
+                })
+                .collect::<std::collections::HashMap<_, _>>()
+        })
+        .await
+        .unwrap_or_default();
+
+        for variant in api_levels
+            .iter_mut()
+            .flat_map(|api_level| &mut api_level.variants)
+        {
+            if variant.is_installed {
+                variant.installed_size = sizes.get(&variant.package_id).copied();
             }
         }
+    }
 
-        let mut api_levels: Vec<ApiLevel> = api_levels_map.into_values().collect();
-        api_levels.sort_by(|a, b| b.api.cmp(&a.api));
-        api_levels
+    /// The sdkmanager command used to install a system image package.
+    pub fn install_command(&self, package_id: &str) -> Result<CommandLine> {
+        let sdkmanager_path = Self::find_tool(&self.android_home, commands::SDKMANAGER)?;
+        Ok(CommandLine::new(sdkmanager_path, [package_id]))
     }
 
     /// Installs a system image with progress callback.
@@ -125,46 +243,62 @@ impl AndroidManager {
     where
         F: Fn(InstallProgress) + Send + Sync + 'static,
     {
+        self.read_only
+            .ensure_writable(read_only::INSTALL_SYSTEM_IMAGE)?;
+        if self.is_offline() {
+            anyhow::bail!(OFFLINE_INSTALL);
+        }
         progress_callback(InstallProgress {
             operation: "Preparing installation...".to_string(),
             percentage: 0,
             eta_seconds: None,
+            bytes_per_second: None,
         });
 
-        let sdkmanager_path = Self::find_tool(&self.android_home, commands::SDKMANAGER)?;
-        let mut child = tokio::process::Command::new(&sdkmanager_path)
-            .args([package_id])
+        let command = self.install_command(package_id)?;
+        let mut process = tokio::process::Command::new(&command.program);
+        process
+            .args(&command.args)
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()?;
+            .stderr(std::process::Stdio::piped());
+        let mut child = processes::spawn(&mut process, &command.to_string())?;
 
         if let Some(stdin) = child.stdin.as_mut() {
             use tokio::io::AsyncWriteExt;
-            stdin.write_all(b"y\n").await?;
-            stdin.flush().await?;
+            let answered = match stdin.write_all(b"y\n").await {
+                Ok(()) => stdin.flush().await,
+                Err(e) => Err(e),
+            };
+            // sdkmanager may fail before reading the answer; its exit status
+            // and stderr report why
+            if let Err(e) = answered {
+                if e.kind() != std::io::ErrorKind::BrokenPipe {
+                    return Err(e.into());
+                }
+            }
         }
 
         progress_callback(InstallProgress {
             operation: "Starting installation process...".to_string(),
             percentage: 5,
             eta_seconds: None,
+            bytes_per_second: None,
         });
 
         let progress_callback = std::sync::Arc::new(progress_callback);
         let progress_clone = progress_callback.clone();
         let stop_timer = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
         let stop_timer_clone = stop_timer.clone();
+        // Stops the simulated progress even if the caller cancels the install
+        let stop_timer_guard = StopOnDrop(stop_timer.clone());
 
-        tokio::spawn(async move {
+        tasks::spawn(async move {
             let mut progress = 10u8;
             let mut stage = 0;
 
             loop {
-                tokio::time::sleep(tokio::time::Duration::from_secs(
-                    DEVICE_START_WAIT_TIME.as_secs(),
-                ))
-                .await;
+                tokio::time::sleep(INSTALL_PROGRESS_TICK).await;
 
                 if stop_timer_clone.load(std::sync::atomic::Ordering::Relaxed) {
                     break;
@@ -176,6 +310,7 @@ impl AndroidManager {
                             operation: "Loading package information...".to_string(),
                             percentage: progress,
                             eta_seconds: None,
+                            bytes_per_second: None,
                         });
                         progress += LOADING_PHASE_INCREMENT;
                         if progress >= DOWNLOAD_PHASE_START_PERCENTAGE {
@@ -188,6 +323,7 @@ impl AndroidManager {
                             operation: "Downloading system image...".to_string(),
                             percentage: progress,
                             eta_seconds: None,
+                            bytes_per_second: None,
                         });
                         progress += DOWNLOAD_PHASE_INCREMENT;
                         if progress >= EXTRACT_PHASE_START_PERCENTAGE {
@@ -200,6 +336,7 @@ impl AndroidManager {
                             operation: "Extracting system image...".to_string(),
                             percentage: progress,
                             eta_seconds: None,
+                            bytes_per_second: None,
                         });
                         progress += EXTRACT_PHASE_INCREMENT;
                         if progress >= INSTALL_PHASE_START_PERCENTAGE {
@@ -212,6 +349,7 @@ impl AndroidManager {
                             operation: "Installing system image...".to_string(),
                             percentage: progress,
                             eta_seconds: None,
+                            bytes_per_second: None,
                         });
                         progress += 2;
                         if progress >= COMPLETION_THRESHOLD_PERCENTAGE {
@@ -225,82 +363,80 @@ impl AndroidManager {
 
         if let Some(stdout) = child.stdout.take() {
             let progress_stdout = progress_callback.clone();
-            tokio::spawn(async move {
-                use tokio::io::{AsyncBufReadExt, BufReader};
-                let reader = BufReader::new(stdout);
-                let mut lines = reader.lines();
+            let stop_timer_stdout = stop_timer.clone();
+            tasks::spawn(async move {
+                let mut lines = LossyLines::new(stdout).split_on_carriage_return();
+                let mut estimate = DownloadEstimate::default();
+                let mut last_percentage = 0;
 
                 while let Ok(Some(line)) = lines.next_line().await {
-                    if line.contains("Downloading") {
-                        if line.contains(" MiB") || line.contains(" MB") {
-                            if let Some(start) = line.find('(') {
-                                if let Some(end) = line.find('%') {
-                                    if let Ok(pct) = line[start + 1..end].trim().parse::<u8>() {
-                                        progress_stdout(InstallProgress {
-                                            operation: "Downloading system image...".to_string(),
-                                            percentage: (DOWNLOAD_PHASE_START_PERCENTAGE
-                                                + (pct * DOWNLOAD_PROGRESS_MULTIPLIER
-                                                    / PROGRESS_PHASE_100_PERCENT))
-                                                .min(EXTRACT_PHASE_START_PERCENTAGE),
-                                            eta_seconds: None,
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                    } else if line.contains("Unzipping") || line.contains("Extracting") {
-                        progress_stdout(InstallProgress {
-                            operation: "Extracting system image...".to_string(),
-                            percentage: PROGRESS_PHASE_75_PERCENT,
-                            eta_seconds: None,
-                        });
-                    } else if line.contains("Installing") {
-                        progress_stdout(InstallProgress {
-                            operation: "Installing system image...".to_string(),
-                            percentage: PROGRESS_PHASE_85_PERCENT,
-                            eta_seconds: None,
-                        });
+                    let Some(mut progress) = parsers::parse_sdkmanager_install_progress(&line)
+                    else {
+                        continue;
+                    };
+                    // sdkmanager reports real progress, so the simulated
+                    // phases are no longer needed
+                    stop_timer_stdout.store(true, std::sync::atomic::Ordering::Relaxed);
+                    if progress.percentage < last_percentage {
+                        continue;
                     }
+                    last_percentage = progress.percentage;
+                    if progress.percentage >= DOWNLOAD_PHASE_START_PERCENTAGE {
+                        estimate.update(
+                            &mut progress,
+                            parsers::parse_transfer_bytes(&line),
+                            std::time::Instant::now(),
+                        );
+                    }
+                    progress_stdout(progress);
                 }
             });
         }
 
-        if let Some(stderr) = child.stderr.take() {
-            tokio::spawn(async move {
-                use tokio::io::{AsyncBufReadExt, BufReader};
-                let reader = BufReader::new(stderr);
-                let mut lines = reader.lines();
-
+        // Kept for the error, which otherwise only has the exit status
+        let stderr_reader = child.stderr.take().map(|stderr| {
+            tasks::spawn(async move {
+                let mut lines = LossyLines::new(stderr);
+                let mut output = Vec::new();
                 while let Ok(Some(line)) = lines.next_line().await {
-                    if line.contains(LOG_LEVEL_ERROR)
-                        || line.contains("error")
-                        || line.contains(LOG_LEVEL_FAILED)
-                    {
-                        eprintln!("sdkmanager error: {line}");
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        output.push(line.to_string());
                     }
                 }
-            });
-        }
+                output
+            })
+        });
 
-        let output = child.wait_with_output().await?;
-        stop_timer.store(true, std::sync::atomic::Ordering::Relaxed);
+        // stdout and stderr were handed to the readers above
+        let status = child.wait().await?;
+        drop(stop_timer_guard);
 
-        if output.status.success() {
+        if status.success() {
             self.invalidate_sdk_list_caches().await;
-            Ok(())
+            return Ok(());
+        }
+        let stderr = match stderr_reader {
+            Some(reader) => reader.await.unwrap_or_default().join("\n"),
+            None => String::new(),
+        };
+        if stderr.is_empty() {
+            Err(anyhow::anyhow!("Failed to install system image: {status}"))
         } else {
             Err(anyhow::anyhow!(
-                "Failed to install system image: {}",
-                String::from_utf8_lossy(&output.stderr)
+                "Failed to install system image: {status}\n{stderr}"
             ))
         }
     }
 
     /// Uninstalls a system image.
     pub async fn uninstall_system_image(&self, package_id: &str) -> Result<()> {
+        self.read_only
+            .ensure_writable(read_only::UNINSTALL_SYSTEM_IMAGE)?;
         let sdkmanager_path = Self::find_tool(&self.android_home, commands::SDKMANAGER)?;
         let output = tokio::process::Command::new(&sdkmanager_path)
             .args(["--uninstall", package_id])
+            .kill_on_drop(true)
             .output()
             .await?;
 
@@ -315,17 +451,111 @@ impl AndroidManager {
         }
     }
 
-    /// Parses API level from package ID.
-    pub(super) fn parse_api_level_from_package(&self, package_id: &str) -> Option<u32> {
-        if let Some(start) = package_id.find("android-") {
-            let api_part = &package_id[start + 8..];
-            if let Some(end) = api_part.find(';') {
-                api_part[..end].parse().ok()
-            } else {
-                api_part.parse().ok()
+    /// Names of the AVDs whose `config.ini` boots from one of `package_ids`,
+    /// which would no longer start once those images are uninstalled.
+    pub async fn avds_using_system_images(&self, package_ids: &[String]) -> Result<Vec<String>> {
+        let images: Vec<_> = package_ids
+            .iter()
+            .filter_map(|package_id| parsers::SystemImagePackage::parse(package_id))
+            .collect();
+        let Some(avd_root) = Self::avd_home() else {
+            return Ok(Vec::new());
+        };
+        if images.is_empty() || !avd_root.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = tokio::fs::read_dir(&avd_root)
+            .await
+            .with_context(|| format!("Failed to read {}", avd_root.display()))?;
+        // Both `<name>.avd` directories and `<name>.ini` pointers name AVDs;
+        // the pointers also cover data moved off the AVD home
+        let mut candidates = std::collections::BTreeSet::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else {
+                continue;
+            };
+            if let Some(avd_name) = file_name
+                .strip_suffix(files::AVD_EXTENSION)
+                .or_else(|| file_name.strip_suffix(files::INI_EXTENSION))
+            {
+                candidates.insert(avd_name.to_string());
             }
-        } else {
-            None
+        }
+
+        let mut avd_names = Vec::new();
+        for avd_name in candidates {
+            let Some(data_dir) = Self::avd_data_dir(&avd_name).await else {
+                continue;
+            };
+            let Ok(config) =
+                tokio::fs::read_to_string(data_dir.join(files::android::CONFIG_INI)).await
+            else {
+                continue;
+            };
+            if images.iter().any(|image| image.is_used_by_config(&config)) {
+                avd_names.push(avd_name);
+            }
+        }
+        Ok(avd_names)
+    }
+
+    /// Points an AVD at another installed system image so it keeps booting
+    /// after its current image is uninstalled.
+    pub async fn retarget_avd(&self, avd_name: &str, package_id: &str) -> Result<()> {
+        let image = parsers::SystemImagePackage::parse(package_id)
+            .with_context(|| format!("'{package_id}' is not a system image"))?;
+        let avd_root = Self::avd_home().context(AVD_HOME_NOT_FOUND)?;
+        let config_path = Self::avd_data_dir(avd_name)
+            .await
+            .context(AVD_HOME_NOT_FOUND)?
+            .join(files::android::CONFIG_INI);
+        let config = tokio::fs::read_to_string(&config_path)
+            .await
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        let sysdir = image.sysdir();
+        let updated = parsers::set_config_values(
+            &config,
+            &[
+                ("image.sysdir.1", &sysdir),
+                ("tag.id", &image.tag),
+                ("abi.type", &image.abi),
+            ],
+        );
+        tokio::fs::write(&config_path, updated)
+            .await
+            .with_context(|| format!("Failed to write {}", config_path.display()))?;
+
+        // The `<name>.ini` pointer file records the platform as well
+        let ini_path = avd_root.join(format!("{avd_name}.ini"));
+        if let Ok(ini) = tokio::fs::read_to_string(&ini_path).await {
+            let target = format!("android-{}", image.api);
+            let updated = parsers::set_config_values(&ini, &[("target", &target)]);
+            tokio::fs::write(&ini_path, updated)
+                .await
+                .with_context(|| format!("Failed to write {}", ini_path.display()))?;
+        }
+
+        self.invalidate_device_metadata_cache(Some(avd_name)).await;
+        self.device_list_refresh.invalidate();
+        Ok(())
+    }
+}
+
+/// Total size of the files under `path`, or `None` if it is not a directory.
+/// Symlinks are not followed.
+pub(super) fn directory_size(path: &std::path::Path) -> Option<u64> {
+    let mut total = 0;
+    for entry in std::fs::read_dir(path).ok()?.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            total += directory_size(&entry.path()).unwrap_or(0);
+        } else if file_type.is_file() {
+            total += entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
         }
     }
+    Some(total)
 }
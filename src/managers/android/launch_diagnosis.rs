@@ -0,0 +1,156 @@
+//! Diagnosis of AVDs that fail to launch.
+//!
+//! Like `diagnose_avd_creation_issues` does for creation, this checks the
+//! usual reasons an emulator exits right away or never boots: a missing
+//! system image, lock files of a crashed emulator, no free console port,
+//! a nearly full disk, and unusable hardware acceleration.
+
+use super::{parsers, AndroidManager};
+use crate::{
+    constants::{
+        android::{EMULATOR_PORT_BASE, EMULATOR_PORT_INCREMENT, EMULATOR_PORT_MAX},
+        commands, files,
+        limits::MIN_LAUNCH_FREE_DISK_MB,
+    },
+    models::LaunchIssue,
+};
+use anyhow::{bail, Context, Result};
+use std::net::{Ipv4Addr, TcpListener};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+impl AndroidManager {
+    /// Checks why `avd_name` failed to launch. An empty list means none of
+    /// the known causes applies and the emulator output is the best lead.
+    pub async fn diagnose_launch(&self, avd_name: &str) -> Result<Vec<LaunchIssue>> {
+        let avd_dir = self.resolve_avd_dir(avd_name).await?;
+        let is_running = self
+            .get_running_avd_names()
+            .await
+            .is_ok_and(|running| running.contains_key(avd_name));
+
+        let mut issues = Vec::new();
+        if let Some(package_id) = self.missing_system_image(&avd_dir).await {
+            issues.push(LaunchIssue::MissingSystemImage { package_id });
+        }
+        if !is_running {
+            let lock_files = find_lock_files(&avd_dir).await;
+            if !lock_files.is_empty() {
+                issues.push(LaunchIssue::StaleLocks { lock_files });
+            }
+            if !has_free_console_port() {
+                issues.push(LaunchIssue::PortsInUse);
+            }
+        }
+        if let Some(available_mb) = self.available_disk_mb(&avd_dir).await {
+            if available_mb < MIN_LAUNCH_FREE_DISK_MB {
+                issues.push(LaunchIssue::LowDisk {
+                    available_mb,
+                    required_mb: MIN_LAUNCH_FREE_DISK_MB,
+                });
+            }
+        }
+        if let Some(details) = self.acceleration_problem().await {
+            issues.push(LaunchIssue::NoAcceleration { details });
+        }
+        Ok(issues)
+    }
+
+    /// Applies the fix of an issue [`LaunchIssue::can_fix`] accepts.
+    pub async fn fix_launch_issue(&self, issue: &LaunchIssue) -> Result<()> {
+        match issue {
+            LaunchIssue::MissingSystemImage { package_id } => {
+                self.install_system_image(package_id, |_| {}).await
+            }
+            LaunchIssue::StaleLocks { lock_files } => {
+                for path in lock_files {
+                    let removed = if path.is_dir() {
+                        fs::remove_dir_all(path).await
+                    } else {
+                        fs::remove_file(path).await
+                    };
+                    match removed {
+                        Ok(()) => {}
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(e) => {
+                            return Err(e)
+                                .with_context(|| format!("Failed to remove {}", path.display()))
+                        }
+                    }
+                }
+                Ok(())
+            }
+            _ => bail!(issue.fix_hint()),
+        }
+    }
+
+    /// Package id of the system image `config.ini` boots from, if it is
+    /// not installed.
+    async fn missing_system_image(&self, avd_dir: &Path) -> Option<String> {
+        let config = fs::read_to_string(avd_dir.join(files::CONFIG_FILE))
+            .await
+            .ok()?;
+        let (_, sysdir) = parsers::parse_config_ini(&config)
+            .into_iter()
+            .find(|(key, _)| key == "image.sysdir.1")?;
+        let image_dir = self.android_home.join(sysdir.replace('\\', "/"));
+        (!image_dir.is_dir()).then(|| parsers::package_id_from_sysdir(&sysdir))
+    }
+
+    async fn available_disk_mb(&self, avd_dir: &Path) -> Option<u64> {
+        let output = self
+            .command_executor
+            .run(
+                Path::new(commands::DF),
+                &[commands::DF_POSIX_KB, &avd_dir.to_string_lossy()],
+            )
+            .await
+            .map_err(|e| log::debug!("Failed to check free disk space: {e:#}"))
+            .ok()?;
+        parsers::parse_df_available_mb(&output)
+    }
+
+    /// Why the emulator cannot use hardware acceleration, if it cannot.
+    async fn acceleration_problem(&self) -> Option<String> {
+        // A failed check exits non-zero, with its report in the error
+        let output = match self
+            .command_executor
+            .run(&self.emulator_path, &[commands::emulator::ACCEL_CHECK])
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => format!("{e:#}"),
+        };
+        parsers::parse_accel_check(&output)
+    }
+}
+
+/// `*.lock` files and directories the emulator keeps in the AVD directory
+/// while it runs.
+async fn find_lock_files(avd_dir: &Path) -> Vec<PathBuf> {
+    let mut lock_files = Vec::new();
+    let Ok(mut entries) = fs::read_dir(avd_dir).await else {
+        return lock_files;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .ends_with(files::LOCK_EXTENSION)
+        {
+            lock_files.push(entry.path());
+        }
+    }
+    lock_files.sort();
+    lock_files
+}
+
+/// Whether some console port, and the adb port after it, can be bound.
+fn has_free_console_port() -> bool {
+    (EMULATOR_PORT_BASE..=EMULATOR_PORT_MAX)
+        .step_by(EMULATOR_PORT_INCREMENT as usize)
+        .any(|port| {
+            TcpListener::bind((Ipv4Addr::LOCALHOST, port)).is_ok()
+                && TcpListener::bind((Ipv4Addr::LOCALHOST, port + 1)).is_ok()
+        })
+}
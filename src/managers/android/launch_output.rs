@@ -0,0 +1,136 @@
+//! Output of the emulator processes emu launched.
+//!
+//! The `emulator` binary reports most launch failures (missing hardware
+//! acceleration, a locked AVD, a broken system image) on its console and
+//! exits. Its output is kept per AVD so the details panel can show it, and
+//! a failed exit is queued for the app to report.
+
+use super::AndroidManager;
+use crate::constants::{limits::MAX_LAUNCH_OUTPUT_LINES, messages::device_busy};
+use crate::utils::command_executor::ProcessOutput;
+use std::collections::{HashMap, VecDeque};
+
+/// An emulator that exited with an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchFailure {
+    pub avd_name: String,
+    /// The last error the emulator printed, or its exit status
+    pub reason: String,
+}
+
+/// Captured output of each launched AVD and the failures not reported yet.
+#[derive(Debug, Default)]
+pub(super) struct LaunchOutputs {
+    lines: HashMap<String, VecDeque<String>>,
+    failures: Vec<LaunchFailure>,
+}
+
+impl LaunchOutputs {
+    /// Forgets the output of the previous launch of `avd_name`.
+    pub(super) fn start(&mut self, avd_name: &str) {
+        self.lines.insert(avd_name.to_string(), VecDeque::new());
+        self.failures.retain(|failure| failure.avd_name != avd_name);
+    }
+
+    pub(super) fn record(&mut self, avd_name: &str, output: ProcessOutput) {
+        let lines = self.lines.entry(avd_name.to_string()).or_default();
+        match output {
+            ProcessOutput::Line(line) => {
+                if lines.len() == MAX_LAUNCH_OUTPUT_LINES {
+                    lines.pop_front();
+                }
+                lines.push_back(line);
+            }
+            ProcessOutput::Exited { success: true, .. } => {}
+            ProcessOutput::Exited { code, .. } => {
+                let status = code.map_or_else(
+                    || "exited after a signal".to_string(),
+                    |code| format!("exited with code {code}"),
+                );
+                let reason = lines
+                    .iter()
+                    .rev()
+                    .find(|line| is_error_line(line))
+                    .cloned()
+                    .unwrap_or_else(|| status.clone());
+                lines.push_back(format!("[{status}]"));
+                self.failures.push(LaunchFailure {
+                    avd_name: avd_name.to_string(),
+                    reason,
+                });
+            }
+        }
+    }
+}
+
+/// Emulator console errors look like `ERROR   | x86 emulation currently
+/// requires hardware acceleration!` or `PANIC: Missing emulator engine`.
+pub(super) fn is_error_line(line: &str) -> bool {
+    ["ERROR", "FATAL", "PANIC"]
+        .iter()
+        .any(|level| line.trim_start().starts_with(level))
+}
+
+impl AndroidManager {
+    /// Output of the last emulator process emu started for `avd_name`.
+    pub fn launch_output(&self, avd_name: &str) -> Vec<String> {
+        self.launch_outputs
+            .lock()
+            .unwrap()
+            .lines
+            .get(avd_name)
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Emulators that exited with an error since the last call.
+    pub fn take_launch_failures(&self) -> Vec<LaunchFailure> {
+        let failures = std::mem::take(&mut self.launch_outputs.lock().unwrap().failures);
+        for failure in &failures {
+            self.device_locks
+                .release(&failure.avd_name, device_busy::BOOTING);
+        }
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_exit_reports_last_error_line() {
+        let mut outputs = LaunchOutputs::default();
+        outputs.start("Pixel_7");
+        for line in [
+            "INFO    | Android emulator version 35.1.4.0",
+            "ERROR   | x86 emulation currently requires hardware acceleration!",
+            "CPU acceleration status: KVM requires a CPU that supports vmx or svm",
+        ] {
+            outputs.record("Pixel_7", ProcessOutput::Line(line.to_string()));
+        }
+        outputs.record(
+            "Pixel_7",
+            ProcessOutput::Exited {
+                success: false,
+                code: Some(1),
+            },
+        );
+
+        assert_eq!(outputs.lines["Pixel_7"].len(), 4);
+        assert_eq!(outputs.lines["Pixel_7"][3], "[exited with code 1]");
+        assert_eq!(
+            outputs.failures,
+            [LaunchFailure {
+                avd_name: "Pixel_7".to_string(),
+                reason: "ERROR   | x86 emulation currently requires hardware acceleration!"
+                    .to_string(),
+            }]
+        );
+
+        // A new launch starts from a clean slate
+        outputs.start("Pixel_7");
+        assert!(outputs.lines["Pixel_7"].is_empty());
+        assert!(outputs.failures.is_empty());
+    }
+}
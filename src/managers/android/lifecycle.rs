@@ -1,19 +1,21 @@
 use super::{
-    parser::AvdListParser, AndroidManager, API_LEVEL_REGEX, API_OR_ANDROID_REGEX, BASED_ON_REGEX,
+    parsers, window, AndroidManager, API_LEVEL_REGEX, API_OR_ANDROID_REGEX, BASED_ON_REGEX,
     IMAGE_SYSDIR_REGEX, TARGET_CONFIG_REGEX,
 };
 use crate::{
     constants::{
-        commands, defaults,
-        env_vars::HOME,
-        files,
+        commands, defaults, files,
         limits::STORAGE_MB_TO_GB_DIVISOR,
-        timeouts::{DEVICE_START_WAIT_TIME, DEVICE_STATUS_CHECK_DELAY},
+        messages::{device_busy, read_only},
+        timeouts::{DEVICE_BOOT_LOCK_TIMEOUT, DEVICE_STATUS_CHECK_DELAY, DEVICE_STOP_TIMEOUT},
     },
+    managers::common::DeviceLockGuard,
     models::{device_info::sort_android_devices_for_display, AndroidDevice, DeviceStatus},
+    utils::{boot_wait::poll_until, command_executor::CommandPolicy, CommandLine},
 };
 use anyhow::{Context, Result};
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::Arc;
 use tokio::fs;
 
 impl AndroidManager {
@@ -47,10 +49,16 @@ impl AndroidManager {
             }
         }
 
-        let mut parser = AvdListParser::new(&avd_output);
         let mut devices = Vec::new();
 
-        while let Some((name, _path, target, _abi, device)) = parser.parse_next_device() {
+        for avd in parsers::parse_avd_list(&avd_output) {
+            let parsers::AvdInfo {
+                name,
+                target,
+                abi,
+                device,
+                ..
+            } = avd;
             let is_running = running_avds.contains_key(&name);
             let metadata =
                 if let Some(metadata) = self.get_cached_device_metadata(&name, &target).await {
@@ -65,6 +73,7 @@ impl AndroidManager {
                         target: target.clone(),
                         api_level,
                         android_version_name,
+                        ram_size: Self::configured_ram_size(&name).await,
                     };
                     self.set_cached_device_metadata(name.clone(), metadata.clone())
                         .await;
@@ -82,11 +91,14 @@ impl AndroidManager {
                     DeviceStatus::Stopped
                 },
                 is_running,
-                ram_size: defaults::DEFAULT_RAM_MB.to_string(),
+                ram_size: metadata
+                    .ram_size
+                    .unwrap_or_else(|| defaults::DEFAULT_RAM_MB.to_string()),
                 storage_size: format!(
                     "{}M",
                     defaults::DEFAULT_STORAGE_MB / STORAGE_MB_TO_GB_DIVISOR
                 ),
+                abi: parsers::abi_from_tag_abi(&abi).to_string(),
             });
         }
 
@@ -94,15 +106,22 @@ impl AndroidManager {
         Ok(devices)
     }
 
+    /// `hw.ramSize` from the AVD's `config.ini`, in MB.
+    async fn configured_ram_size(name: &str) -> Option<String> {
+        let config_path = Self::avd_data_dir(name).await?.join(files::CONFIG_FILE);
+        let config_content = fs::read_to_string(&config_path).await.ok()?;
+        parsers::parse_config_ini(&config_content)
+            .into_iter()
+            .find(|(key, _)| key == files::android::RAM_SIZE_KEY)
+            .map(|(_, value)| value.trim_end_matches(['M', 'm']).to_string())
+            .filter(|value| !value.is_empty())
+    }
+
     pub(super) async fn detect_api_level_for_device(&self, name: &str, target: &str) -> u32 {
         let mut api = 0u32;
 
-        if let Ok(home) = std::env::var(HOME) {
-            let config_path = PathBuf::from(home)
-                .join(files::android::AVD_DIR)
-                .join("avd")
-                .join(format!("{name}.avd"))
-                .join(files::CONFIG_FILE);
+        if let Some(avd_path) = Self::avd_data_dir(name).await {
+            let config_path = avd_path.join(files::CONFIG_FILE);
 
             if let Ok(config_content) = fs::read_to_string(&config_path).await {
                 if let Some(caps) = IMAGE_SYSDIR_REGEX.captures(&config_content) {
@@ -148,29 +167,83 @@ impl AndroidManager {
         api
     }
 
+    /// The emulator command used to launch an AVD.
+    pub async fn start_command(&self, avd_name: &str) -> CommandLine {
+        let mut command = CommandLine::new(
+            &self.emulator_path,
+            [
+                "-avd",
+                avd_name,
+                "-no-audio",
+                "-no-snapshot-save",
+                "-no-boot-anim",
+                "-netfast",
+            ],
+        );
+        if self.boots_cold(avd_name).await {
+            command
+                .args
+                .push(commands::emulator::NO_SNAPSHOT_LOAD.to_string());
+        }
+        command
+    }
+
     pub(super) async fn start_device_internal(&self, identifier: &str) -> Result<()> {
-        let args = vec![
-            "-avd",
-            identifier,
-            "-no-audio",
-            "-no-snapshot-save",
-            "-no-boot-anim",
-            "-netfast",
-        ];
+        let command = self.start_command(identifier).await;
+        self.launch(identifier, command).await
+    }
 
+    /// Launches an AVD with `-read-only`, so nothing the session writes
+    /// outlives the emulator process.
+    pub async fn start_device_read_only(&self, avd_name: &str) -> Result<()> {
+        let _lock = self.lock_device(avd_name, device_busy::STARTING).await?;
+        let mut command = self.start_command(avd_name).await;
+        command.args.push(commands::emulator::READ_ONLY.to_string());
+        let result = self.launch(avd_name, command).await;
+        self.device_list_refresh.invalidate();
+        result
+    }
+
+    async fn launch(&self, identifier: &str, command: CommandLine) -> Result<()> {
+        self.set_cold_boot_once(identifier, false);
+        self.launch_outputs.lock().unwrap().start(identifier);
+        let launch_outputs = Arc::clone(&self.launch_outputs);
+        let avd_name = identifier.to_string();
         self.command_executor
-            .spawn(&self.emulator_path, &args)
+            .spawn_with_output(
+                &command.program,
+                &command.arg_refs(),
+                Arc::new(move |output| launch_outputs.lock().unwrap().record(&avd_name, output)),
+            )
             .await?;
+        self.device_locks
+            .hold(identifier, device_busy::BOOTING, DEVICE_BOOT_LOCK_TIMEOUT);
         Ok(())
     }
 
+    /// Takes `avd_name` for `operation`, failing if something else runs on
+    /// it. A boot emu began holds the AVD until it completes, which is only
+    /// asked when another operation wants the AVD.
+    pub(super) async fn lock_device(
+        &self,
+        avd_name: &str,
+        operation: &'static str,
+    ) -> Result<DeviceLockGuard> {
+        if self.device_locks.running(avd_name) == Some(device_busy::BOOTING)
+            && self.is_boot_completed(avd_name).await.unwrap_or(false)
+        {
+            self.device_locks.release(avd_name, device_busy::BOOTING);
+        }
+        self.device_locks.acquire(avd_name, operation)
+    }
+
     pub(super) async fn stop_device_internal(&self, identifier: &str) -> Result<()> {
         let running_avds = self.get_running_avd_names().await?;
 
         if let Some(emulator_id) = running_avds.get(identifier) {
             let shutdown_result = self
                 .command_executor
-                .run(
+                .run_with_policy(
                     Path::new(commands::ADB),
                     &[
                         "-s",
@@ -181,6 +254,7 @@ impl AndroidManager {
                         "-a",
                         "android.intent.action.ACTION_SHUTDOWN",
                     ],
+                    &CommandPolicy::ADB_SHUTDOWN,
                 )
                 .await;
 
@@ -192,26 +266,107 @@ impl AndroidManager {
 
                 let _ = self
                     .command_executor
-                    .run(
+                    .run_with_policy(
                         Path::new(commands::ADB),
                         &["-s", emulator_id, "shell", "reboot", "-p"],
+                        &CommandPolicy::ADB_SHUTDOWN,
                     )
                     .await;
             } else {
                 self.command_executor
-                    .run(
+                    .run_with_policy(
                         Path::new(commands::ADB),
                         &["-s", emulator_id, "emu", "kill"],
+                        &CommandPolicy::ADB_SHUTDOWN,
                     )
                     .await
                     .context(format!("Failed to stop emulator {emulator_id}"))?;
             }
+
+            // The serial may be reused by a different AVD on the next start
+            self.invalidate_emulator_avd_name(emulator_id).await;
         }
 
         Ok(())
     }
 
+    /// Whether a running AVD still answers `adb shell echo ok` in time.
+    pub async fn is_responsive(&self, avd_name: &str) -> Result<bool> {
+        let serial = self.running_serial(avd_name).await?;
+        let reply = self
+            .command_executor
+            .run_with_policy(
+                Path::new(commands::ADB),
+                &[
+                    "-s",
+                    &serial,
+                    commands::adb::SHELL,
+                    commands::adb::ECHO,
+                    commands::adb::HEALTH_CHECK_REPLY,
+                ],
+                &CommandPolicy::HEALTH_CHECK,
+            )
+            .await;
+        Ok(reply.is_ok_and(|reply| reply.trim() == commands::adb::HEALTH_CHECK_REPLY))
+    }
+
+    /// Kills the emulator of `avd_name` without asking the guest to shut
+    /// down, which a wedged guest never finishes, and starts it again.
+    pub async fn force_restart_device(&self, avd_name: &str) -> Result<()> {
+        // A boot that hangs is a reason to restart
+        self.device_locks.release(avd_name, device_busy::BOOTING);
+        let _lock = self.lock_device(avd_name, device_busy::RESTARTING).await?;
+        let running_avds = self.get_running_avd_names().await?;
+        if let Some(emulator_id) = running_avds.get(avd_name) {
+            let killed = self
+                .command_executor
+                .run_with_policy(
+                    Path::new(commands::ADB),
+                    &["-s", emulator_id, commands::adb::EMU, commands::adb::KILL],
+                    &CommandPolicy::HEALTH_CHECK,
+                )
+                .await;
+            if let Err(error) = killed {
+                // The console can hang along with the guest
+                let pid = window::emulator_pid(avd_name)
+                    .await
+                    .with_context(|| format!("Failed to stop emulator {emulator_id}: {error:#}"))?;
+                self.command_executor
+                    .run(
+                        Path::new(commands::KILL_PROCESS),
+                        &[commands::KILL_SIGNAL, &pid.to_string()],
+                    )
+                    .await
+                    .with_context(|| format!("Failed to kill emulator process {pid}"))?;
+            }
+            self.invalidate_emulator_avd_name(emulator_id).await;
+            self.wait_until_stopped(avd_name).await;
+        }
+        self.start_device_internal(avd_name).await
+    }
+
+    /// Waits until `identifier` has left the running emulators after a stop
+    /// request, polling instead of sleeping for a fixed time. Gives up after
+    /// `DEVICE_STOP_TIMEOUT` and lets the next step report any failure.
+    async fn wait_until_stopped(&self, identifier: &str) {
+        let stopped = poll_until(DEVICE_STATUS_CHECK_DELAY, DEVICE_STOP_TIMEOUT, || async {
+            !self
+                .get_running_avd_names()
+                .await
+                .unwrap_or_default()
+                .contains_key(identifier)
+        })
+        .await;
+        if !stopped {
+            log::warn!(
+                "'{identifier}' still running after {}s",
+                DEVICE_STOP_TIMEOUT.as_secs()
+            );
+        }
+    }
+
     pub(super) async fn delete_device_internal(&self, identifier: &str) -> Result<()> {
+        self.read_only.ensure_writable(read_only::DELETE_DEVICE)?;
         let running_avds = self.get_running_avd_names().await.unwrap_or_default();
         if running_avds.contains_key(identifier) {
             log::info!("Device '{identifier}' is running, stopping before deletion");
@@ -219,10 +374,7 @@ impl AndroidManager {
                 log::warn!("Failed to stop device '{identifier}' before deletion: {e}");
             }
 
-            tokio::time::sleep(tokio::time::Duration::from_secs(
-                DEVICE_START_WAIT_TIME.as_secs(),
-            ))
-            .await;
+            self.wait_until_stopped(identifier).await;
         }
 
         self.command_executor
@@ -235,22 +387,15 @@ impl AndroidManager {
     }
 
     pub(super) async fn wipe_device_internal(&self, identifier: &str) -> Result<()> {
+        self.read_only.ensure_writable(read_only::WIPE_DEVICE)?;
         let running_avds = self.get_running_avd_names().await?;
         if running_avds.contains_key(identifier) {
             log::info!("Device '{identifier}' is running, stopping before wipe");
             self.stop_device_internal(identifier).await?;
-            tokio::time::sleep(tokio::time::Duration::from_millis(
-                DEVICE_STATUS_CHECK_DELAY.as_millis() as u64,
-            ))
-            .await;
+            self.wait_until_stopped(identifier).await;
         }
 
-        if let Ok(home_dir) = std::env::var(HOME) {
-            let avd_path = PathBuf::from(home_dir)
-                .join(files::android::AVD_DIR)
-                .join("avd")
-                .join(format!("{identifier}.avd"));
-
+        if let Some(avd_path) = Self::avd_data_dir(identifier).await {
             if avd_path.exists() {
                 let files_to_delete = [
                     "userdata.img",
@@ -274,7 +419,7 @@ impl AndroidManager {
                     }
                 }
 
-                let snapshots_dir = avd_path.join("snapshots");
+                let snapshots_dir = avd_path.join(files::android::SNAPSHOTS_DIR);
                 if snapshots_dir.exists() {
                     if let Err(e) = tokio::fs::remove_dir_all(&snapshots_dir).await {
                         log::warn!("Failed to remove snapshots directory: {e}");
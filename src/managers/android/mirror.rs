@@ -0,0 +1,222 @@
+//! Mirroring AVDs with scrcpy.
+//!
+//! scrcpy shows the screen of a device in its own window and can record it
+//! at the same time. It is optional, so emu only looks for it when a mirror
+//! is requested. The scrcpy processes emu starts are tracked by AVD name:
+//! the same key closes the window again, and the remaining ones are stopped
+//! when emu exits. A scrcpy that exits with an error on its own is queued
+//! for the app to report, like a failed emulator launch.
+
+use super::{launch_output::is_error_line, AndroidManager, LaunchFailure};
+use crate::constants::{
+    commands::{self, scrcpy},
+    messages::errors::{ALREADY_MIRRORING, SCRCPY_NOT_FOUND},
+};
+use crate::utils::{command_executor::ProcessOutput, tool_paths};
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// scrcpy processes that are running, and failures not reported yet.
+#[derive(Debug, Default)]
+pub(super) struct MirrorSessions {
+    next_id: u64,
+    running: HashMap<String, MirrorSession>,
+    failures: Vec<LaunchFailure>,
+}
+
+#[derive(Debug)]
+struct MirrorSession {
+    /// Tells a session apart from a later one of the same AVD
+    id: u64,
+    /// `None` until the spawn returned
+    pid: Option<u32>,
+    /// The screen is also recorded to a file
+    recording: bool,
+    last_error: Option<String>,
+}
+
+impl MirrorSessions {
+    fn start(&mut self, avd_name: &str, recording: bool) -> u64 {
+        self.next_id += 1;
+        self.running.insert(
+            avd_name.to_string(),
+            MirrorSession {
+                id: self.next_id,
+                pid: None,
+                recording,
+                last_error: None,
+            },
+        );
+        self.next_id
+    }
+
+    fn session(&mut self, avd_name: &str, id: u64) -> Option<&mut MirrorSession> {
+        self.running
+            .get_mut(avd_name)
+            .filter(|session| session.id == id)
+    }
+
+    /// Handles the output of session `id`. A session stopped by emu is no
+    /// longer tracked, so its exit is not reported.
+    fn record(&mut self, avd_name: &str, id: u64, output: ProcessOutput) {
+        let Some(session) = self.session(avd_name, id) else {
+            return;
+        };
+        match output {
+            ProcessOutput::Line(line) => {
+                if is_error_line(&line) {
+                    session.last_error = Some(line);
+                }
+            }
+            ProcessOutput::Exited { success, code } => {
+                let last_error = session.last_error.take();
+                self.running.remove(avd_name);
+                if !success {
+                    let reason = last_error.unwrap_or_else(|| match code {
+                        Some(code) => format!("scrcpy exited with code {code}"),
+                        None => "scrcpy exited after a signal".to_string(),
+                    });
+                    self.failures.push(LaunchFailure {
+                        avd_name: avd_name.to_string(),
+                        reason,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl AndroidManager {
+    /// Whether scrcpy is on `PATH` or configured under `[tools]`.
+    pub async fn scrcpy_available(&self) -> bool {
+        let scrcpy = tool_paths::resolve_program(commands::SCRCPY.as_ref());
+        tokio::task::spawn_blocking(move || which::which(scrcpy).is_ok())
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Whether a scrcpy window emu opened for `avd_name` is still open.
+    pub fn is_mirroring(&self, avd_name: &str) -> bool {
+        self.mirror_sessions
+            .lock()
+            .unwrap()
+            .running
+            .contains_key(avd_name)
+    }
+
+    /// An AVD whose screen a scrcpy emu opened is recording, if any.
+    pub fn recording_mirror(&self) -> Option<String> {
+        self.mirror_sessions
+            .lock()
+            .unwrap()
+            .running
+            .iter()
+            .find(|(_, session)| session.recording)
+            .map(|(avd_name, _)| avd_name.clone())
+    }
+
+    /// Opens a scrcpy window for a running AVD, recording the screen to
+    /// `recording` (an `.mp4` file) when given.
+    pub async fn start_mirroring(&self, avd_name: &str, recording: Option<&Path>) -> Result<()> {
+        if self.is_mirroring(avd_name) {
+            bail!(ALREADY_MIRRORING.replace("{}", avd_name));
+        }
+        if !self.scrcpy_available().await {
+            bail!(SCRCPY_NOT_FOUND);
+        }
+        let serial = self.running_serial(avd_name).await?;
+        let mut args = vec![
+            scrcpy::SERIAL.to_string(),
+            serial,
+            scrcpy::WINDOW_TITLE.to_string(),
+            avd_name.to_string(),
+            scrcpy::STAY_AWAKE.to_string(),
+        ];
+        if let Some(recording) = recording {
+            if let Some(dir) = recording.parent() {
+                tokio::fs::create_dir_all(dir)
+                    .await
+                    .with_context(|| format!("Failed to create {}", dir.display()))?;
+            }
+            args.push(format!("{}={}", scrcpy::RECORD, recording.display()));
+        }
+        let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        let id = self
+            .mirror_sessions
+            .lock()
+            .unwrap()
+            .start(avd_name, recording.is_some());
+        let sessions = Arc::clone(&self.mirror_sessions);
+        let name = avd_name.to_string();
+        let spawned = self
+            .command_executor
+            .spawn_with_output(
+                Path::new(commands::SCRCPY),
+                &arg_refs,
+                Arc::new(move |output| sessions.lock().unwrap().record(&name, id, output)),
+            )
+            .await;
+
+        let mut sessions = self.mirror_sessions.lock().unwrap();
+        match spawned {
+            Ok(pid) => {
+                // scrcpy may already have exited
+                if let Some(session) = sessions.session(avd_name, id) {
+                    session.pid = Some(pid);
+                }
+                Ok(())
+            }
+            Err(error) => {
+                sessions.running.remove(avd_name);
+                Err(error).context("Failed to start scrcpy")
+            }
+        }
+    }
+
+    /// Closes the scrcpy window emu opened for `avd_name`. Returns false
+    /// when there was none.
+    pub async fn stop_mirroring(&self, avd_name: &str) -> Result<bool> {
+        let session = self
+            .mirror_sessions
+            .lock()
+            .unwrap()
+            .running
+            .remove(avd_name);
+        let Some(session) = session else {
+            return Ok(false);
+        };
+        if let Some(pid) = session.pid {
+            // SIGTERM, so scrcpy finishes the recording before exiting
+            self.command_executor
+                .run(Path::new(commands::KILL_PROCESS), &[&pid.to_string()])
+                .await
+                .with_context(|| format!("Failed to stop scrcpy (process {pid})"))?;
+        }
+        Ok(true)
+    }
+
+    /// Closes every scrcpy window emu opened, e.g. when emu exits.
+    pub async fn stop_all_mirroring(&self) {
+        let avd_names: Vec<String> = self
+            .mirror_sessions
+            .lock()
+            .unwrap()
+            .running
+            .keys()
+            .cloned()
+            .collect();
+        for avd_name in avd_names {
+            if let Err(error) = self.stop_mirroring(&avd_name).await {
+                log::warn!("Failed to stop mirroring '{avd_name}': {error:#}");
+            }
+        }
+    }
+
+    /// scrcpy windows that closed with an error since the last call.
+    pub fn take_mirror_failures(&self) -> Vec<LaunchFailure> {
+        std::mem::take(&mut self.mirror_sessions.lock().unwrap().failures)
+    }
+}
@@ -242,6 +242,9 @@
 //! - `-no-snapshot-save`: Skip snapshot saving on exit
 //! - `-no-boot-anim`: Skip boot animation for faster startup
 //! - `-netfast`: Use faster network emulation
+//! - `-no-snapshot-load`: Cold boot instead of resuming from Quick Boot, when
+//!   asked for once or always
+//! - `-read-only`: Leave the AVD's files untouched, for an ephemeral session
 //! - `-wipe-data`: Cold boot with data wipe (for reset operations)
 //!
 //! ### Background Operations
@@ -277,21 +280,41 @@
 //! This ensures consistent, predictable device ordering without hardcoded device lists.
 //!
 
+mod app_copy;
+mod app_data;
+mod catalog;
+mod control;
 mod create;
 mod details;
+mod developer_toggle;
+mod device_watch;
 mod discovery;
+pub mod grpc;
 mod install;
+mod launch_diagnosis;
+mod launch_output;
 mod lifecycle;
-mod parser;
+mod mirror;
+mod network_capture;
+pub mod parsers;
+mod quick_boot;
+mod radio;
+mod readiness;
+mod relocate;
 mod sdk;
 mod version;
+mod window;
+
+pub use launch_output::LaunchFailure;
 
 use crate::{
-    constants::{commands, performance::ANDROID_SDK_LIST_CACHE_TTL},
-    managers::common::{DeviceConfig, DeviceManager},
-    models::{AndroidDevice, ApiLevel},
+    constants::{commands, messages::device_busy, performance::ANDROID_SDK_LIST_CACHE_TTL},
+    managers::common::{DeviceConfig, DeviceLocks, DeviceManager, ReadOnlySwitch},
+    models::{AndroidDevice, ApiLevel, DeviceCapabilities, ManagedDevice, Platform},
     utils::command::CommandRunner,
-    utils::command_executor::CommandExecutor,
+    utils::command_executor::{CommandExecutor, CommandPolicy},
+    utils::refresh_coordinator::RefreshCoordinator,
+    utils::{tasks, AndroidVersionNameCache},
 };
 use anyhow::Result;
 use lazy_static::lazy_static;
@@ -309,18 +332,29 @@ type TimedStringCache = Arc<RwLock<Option<TimedCache<String>>>>;
 type TimedApiLevelsCache = Arc<RwLock<Option<TimedCache<Vec<ApiLevel>>>>>;
 type DeviceMetadataMap = std::collections::HashMap<String, CachedAndroidDeviceMetadata>;
 
+/// AVD names keyed by emulator serial, valid for one set of attached serials.
+#[derive(Default)]
+struct EmulatorAvdNames {
+    serials: Vec<String>,
+    names: std::collections::HashMap<String, String>,
+}
+
+impl EmulatorAvdNames {
+    /// Forgets every name once the attached serials differ from the last
+    /// `adb devices` answer, since a serial can be reused by another AVD.
+    fn sync_serials(&mut self, emulator_ids: &[String]) {
+        let mut serials = emulator_ids.to_vec();
+        serials.sort();
+        serials.dedup();
+        if serials != self.serials {
+            self.names.clear();
+            self.serials = serials;
+        }
+    }
+}
+
 lazy_static! {
-    // Device listing regexes
-    static ref ID_REGEX: Regex = Regex::new(r#"id:\s*\d+\s*or\s*\"(.+)\""#).unwrap();
-    static ref NAME_REGEX: Regex = Regex::new(r"Name:\s*(.+)").unwrap();
-    static ref OEM_REGEX: Regex = Regex::new(r"OEM\s*:\s*(.+)").unwrap();
-
-    // AVD listing regexes
-    static ref AVD_NAME_REGEX: Regex = Regex::new(r"Name:\s*(.+)").unwrap();
-    static ref PATH_REGEX: Regex = Regex::new(r"Path:\s*(.+)").unwrap();
-    static ref TARGET_REGEX: Regex = Regex::new(r"Target:\s*(.+)").unwrap();
-    static ref ABI_REGEX: Regex = Regex::new(r"Tag/ABI:\s*(.+)").unwrap();
-    static ref DEVICE_REGEX: Regex = Regex::new(r"Device:\s*(.+)").unwrap();
+    // Target parsing regexes
     static ref BASED_ON_REGEX: Regex =
         Regex::new(r"Based on:\s*Android(?:\s*API)?\s*([\d.]+)").unwrap();
 
@@ -372,6 +406,31 @@ pub struct AndroidManager {
     api_levels_cache: TimedApiLevelsCache,
     /// Session cache for per-device metadata derived from config parsing.
     device_metadata_cache: Arc<RwLock<DeviceMetadataMap>>,
+    /// Coalesces concurrent device list refreshes into one `avdmanager` run.
+    device_list_refresh: Arc<RefreshCoordinator<Vec<AndroidDevice>>>,
+    /// Session cache of AVD names keyed by emulator serial, so adb property
+    /// lookups only run while the set of attached serials stays the same.
+    emulator_avd_names_cache: Arc<RwLock<EmulatorAvdNames>>,
+    /// Serials of the attached emulators while `adb track-devices` reports
+    /// them; `None` when the watcher is not running.
+    attached_emulators: Arc<RwLock<Option<Vec<String>>>>,
+    /// Outcome of the last `adb devices` query; `None` until adb has been asked.
+    adb_server_healthy: Arc<RwLock<Option<bool>>>,
+    /// Console output of the emulators this manager launched.
+    launch_outputs: Arc<std::sync::Mutex<launch_output::LaunchOutputs>>,
+    /// AVDs whose next launch skips the Quick Boot snapshot.
+    cold_boot_once: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    /// scrcpy windows this manager opened.
+    mirror_sessions: Arc<std::sync::Mutex<mirror::MirrorSessions>>,
+    /// Android version names by API level, persisted across sessions.
+    android_version_names: Arc<std::sync::RwLock<AndroidVersionNameCache>>,
+    /// Refuses creating, deleting, wiping and installing with `--read-only`.
+    read_only: ReadOnlySwitch,
+    /// Operations running on each AVD, by AVD name.
+    device_locks: DeviceLocks,
+    /// Set when the SDK repository cannot be reached; sdkmanager then only
+    /// reads the local SDK.
+    offline: Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl AndroidManager {
@@ -389,7 +448,10 @@ impl AndroidManager {
     /// 1. `ANDROID_HOME` - Primary Android SDK location
     /// 2. `ANDROID_SDK_ROOT` - Alternative SDK location
     pub fn new() -> Result<Self> {
-        Self::with_executor(Arc::new(CommandRunner::new()))
+        let manager = Self::with_executor(Arc::new(CommandRunner::new()))?;
+        // Only the real SDK tools name versions worth keeping across sessions
+        *manager.android_version_names.write().unwrap() = AndroidVersionNameCache::load_from_disk();
+        Ok(manager)
     }
 
     /// Creates a new AndroidManager instance with a custom command executor.
@@ -402,7 +464,15 @@ impl AndroidManager {
     /// - `Ok(AndroidManager)` - If Android SDK and tools are found
     /// - `Err` - If Android SDK is not installed or tools are missing
     pub fn with_executor(executor: Arc<dyn CommandExecutor>) -> Result<Self> {
-        let android_home = Self::find_android_home()?;
+        Self::with_sdk_root(executor, Self::find_android_home()?)
+    }
+
+    /// Creates a manager for the SDK at `android_home` instead of the one
+    /// found through the environment, e.g. a second SDK with canary tools.
+    pub fn with_sdk_root(
+        executor: Arc<dyn CommandExecutor>,
+        android_home: PathBuf,
+    ) -> Result<Self> {
         let avdmanager_path = Self::find_tool(&android_home, commands::AVDMANAGER)?;
         let emulator_path = Self::find_tool(&android_home, commands::EMULATOR)?;
 
@@ -416,9 +486,65 @@ impl AndroidManager {
             sdkmanager_verbose_output_cache: Arc::new(RwLock::new(None)),
             api_levels_cache: Arc::new(RwLock::new(None)),
             device_metadata_cache: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            emulator_avd_names_cache: Arc::new(RwLock::new(EmulatorAvdNames::default())),
+            adb_server_healthy: Arc::new(RwLock::new(None)),
+            attached_emulators: Arc::new(RwLock::new(None)),
+            device_list_refresh: Arc::new(RefreshCoordinator::new()),
+            launch_outputs: Arc::default(),
+            cold_boot_once: Arc::default(),
+            mirror_sessions: Arc::default(),
+            android_version_names: Arc::default(),
+            read_only: ReadOnlySwitch::default(),
+            device_locks: DeviceLocks::default(),
+            offline: Arc::default(),
         })
     }
 
+    /// Turns operations that change AVDs or the SDK off or on again, for
+    /// this manager and all its clones.
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.set(read_only);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.is_on()
+    }
+
+    /// Switches to listing only the local SDK, or back to the repository,
+    /// for this manager and all its clones. Listings cached in the other
+    /// mode are dropped.
+    pub async fn set_offline(&self, offline: bool) {
+        let was_offline = self
+            .offline
+            .swap(offline, std::sync::atomic::Ordering::Relaxed);
+        if was_offline != offline {
+            self.invalidate_sdk_list_caches().await;
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Arguments of `sdkmanager --list`, reading only the local SDK while
+    /// offline.
+    fn sdkmanager_list_args(&self) -> Vec<&'static str> {
+        let mut args = vec![
+            commands::sdkmanager::LIST,
+            commands::sdkmanager::VERBOSE,
+            commands::sdkmanager::INCLUDE_OBSOLETE,
+        ];
+        if self.is_offline() {
+            args.push(commands::sdkmanager::OFFLINE);
+        }
+        args
+    }
+
+    /// Root directory of the Android SDK this manager uses.
+    pub fn android_home(&self) -> &Path {
+        &self.android_home
+    }
+
     pub(crate) async fn get_cached_available_targets(&self) -> Option<Vec<(String, String)>> {
         let cache = self.available_targets_cache.read().await;
         cache.as_ref().and_then(|cache| {
@@ -465,13 +591,10 @@ impl AndroidManager {
         let sdkmanager_path = Self::find_tool(&self.android_home, commands::SDKMANAGER)?;
         let output = self
             .command_executor
-            .run(
+            .run_with_policy(
                 &sdkmanager_path,
-                &[
-                    commands::sdkmanager::LIST,
-                    "--verbose",
-                    "--include_obsolete",
-                ],
+                &self.sdkmanager_list_args(),
+                &CommandPolicy::SDKMANAGER_NETWORK,
             )
             .await?;
         Ok(output)
@@ -488,6 +611,8 @@ impl AndroidManager {
         Ok(output)
     }
 
+    // Only the TUI refreshes after installing system images
+    #[cfg_attr(not(feature = "tui"), allow(dead_code))]
     pub(crate) async fn refresh_sdkmanager_verbose_output(&self) -> Result<String> {
         let output = self.load_sdkmanager_verbose_output().await?;
         self.set_cached_sdkmanager_verbose_output(output.clone())
@@ -538,6 +663,43 @@ impl AndroidManager {
         }
     }
 
+    /// Returns the cached AVD names for the given emulator serials and the
+    /// serials that still need to be resolved. The cache is dropped whenever
+    /// the set of attached serials changed.
+    async fn partition_cached_emulator_avd_names(
+        &self,
+        emulator_ids: Vec<String>,
+    ) -> (Vec<(String, String)>, Vec<String>) {
+        let mut cache = self.emulator_avd_names_cache.write().await;
+        cache.sync_serials(&emulator_ids);
+
+        let mut resolved = Vec::new();
+        let mut unresolved = Vec::new();
+        for emulator_id in emulator_ids {
+            match cache.names.get(&emulator_id) {
+                Some(avd_name) => resolved.push((avd_name.clone(), emulator_id)),
+                None => unresolved.push(emulator_id),
+            }
+        }
+        (resolved, unresolved)
+    }
+
+    async fn set_cached_emulator_avd_name(&self, emulator_id: String, avd_name: String) {
+        let mut cache = self.emulator_avd_names_cache.write().await;
+        cache.names.insert(emulator_id, avd_name);
+    }
+
+    /// Whether the adb server answered the last device query.
+    /// Returns `None` before adb has been queried.
+    pub async fn adb_server_health(&self) -> Option<bool> {
+        *self.adb_server_healthy.read().await
+    }
+
+    pub(crate) async fn invalidate_emulator_avd_name(&self, emulator_id: &str) {
+        let mut cache = self.emulator_avd_names_cache.write().await;
+        cache.names.remove(emulator_id);
+    }
+
     pub(crate) async fn invalidate_sdk_list_caches(&self) {
         {
             let mut cache = self.available_targets_cache.write().await;
@@ -586,7 +748,7 @@ impl AndroidManager {
                 let cmd_str = cmd.as_ref().to_string();
                 let args_vec: Vec<String> = args.iter().map(|s| s.as_ref().to_string()).collect();
 
-                tokio::spawn(async move {
+                tasks::spawn(async move {
                     executor
                         .run(
                             Path::new(&cmd_str),
@@ -632,33 +794,67 @@ struct CachedAndroidDeviceMetadata {
     target: String,
     api_level: u32,
     android_version_name: String,
+    ram_size: Option<String>,
+}
+
+impl AndroidManager {
+    /// Lists all AVDs with their running state. Concurrent calls share one
+    /// listing.
+    pub async fn list_devices(&self) -> Result<Vec<AndroidDevice>> {
+        let manager = self.clone();
+        self.device_list_refresh
+            .run(move || async move { manager.list_devices_parallel().await })
+            .await
+    }
 }
 
+#[async_trait::async_trait]
 impl DeviceManager for AndroidManager {
-    type Device = AndroidDevice;
+    fn platform(&self) -> Platform {
+        Platform::Android
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        DeviceCapabilities::ANDROID.with_read_only(self.is_read_only())
+    }
 
-    async fn list_devices(&self) -> Result<Vec<Self::Device>> {
-        self.list_devices_parallel().await
+    async fn list_devices(&self) -> Result<Vec<ManagedDevice>> {
+        let devices = AndroidManager::list_devices(self).await?;
+        Ok(devices.into_iter().map(ManagedDevice::Android).collect())
     }
 
     async fn start_device(&self, identifier: &str) -> Result<()> {
-        self.start_device_internal(identifier).await
+        let _lock = self.lock_device(identifier, device_busy::STARTING).await?;
+        let result = self.start_device_internal(identifier).await;
+        self.device_list_refresh.invalidate();
+        result
     }
 
     async fn stop_device(&self, identifier: &str) -> Result<()> {
-        self.stop_device_internal(identifier).await
+        // Stopping cancels a boot
+        self.device_locks.release(identifier, device_busy::BOOTING);
+        let _lock = self.lock_device(identifier, device_busy::STOPPING).await?;
+        let result = self.stop_device_internal(identifier).await;
+        self.device_list_refresh.invalidate();
+        result
     }
 
     async fn create_device(&self, config: &DeviceConfig) -> Result<()> {
-        self.create_device_internal(config).await
+        self.create_device_with_progress(config, &|_| {}).await
     }
 
     async fn delete_device(&self, identifier: &str) -> Result<()> {
-        self.delete_device_internal(identifier).await
+        let _lock = self.lock_device(identifier, device_busy::DELETING).await?;
+        let result = self.delete_device_internal(identifier).await;
+        self.device_list_refresh.invalidate();
+        result
     }
 
     async fn wipe_device(&self, identifier: &str) -> Result<()> {
-        self.wipe_device_internal(identifier).await
+        let _lock = self.lock_device(identifier, device_busy::WIPING).await?;
+        let result = self.wipe_device_internal(identifier).await;
+        self.device_list_refresh.invalidate();
+        result
     }
 
     async fn is_available(&self) -> bool {
@@ -667,41 +863,5 @@ impl DeviceManager for AndroidManager {
     }
 }
 
-/// Implementation of UnifiedDeviceManager for AndroidManager
-#[async_trait::async_trait]
-impl crate::managers::common::UnifiedDeviceManager for AndroidManager {
-    async fn list_devices(&self) -> Result<Vec<Box<dyn crate::models::device::Device>>> {
-        let devices = <Self as DeviceManager>::list_devices(self).await?;
-        Ok(devices
-            .into_iter()
-            .map(|d| Box::new(d) as Box<dyn crate::models::device::Device>)
-            .collect())
-    }
-
-    async fn start_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::start_device(self, device_id).await
-    }
-
-    async fn stop_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::stop_device(self, device_id).await
-    }
-
-    async fn create_device(&self, config: &crate::managers::common::DeviceConfig) -> Result<()> {
-        <Self as DeviceManager>::create_device(self, config).await
-    }
-
-    async fn delete_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::delete_device(self, device_id).await
-    }
-
-    async fn wipe_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::wipe_device(self, device_id).await
-    }
-
-    async fn is_available(&self) -> bool {
-        <Self as DeviceManager>::is_available(self).await
-    }
-}
-
 #[cfg(test)]
 mod tests;
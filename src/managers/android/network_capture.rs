@@ -0,0 +1,62 @@
+//! Packet captures of running AVDs.
+//!
+//! The emulator console writes the traffic of the virtual network to a pcap
+//! on the host, like `emulator -tcpdump` does from launch, so the image
+//! needs neither root nor a tcpdump binary.
+
+use super::AndroidManager;
+use crate::constants::{
+    commands, messages::errors::NETWORK_CAPTURE_PATH_HAS_SPACES, patterns::errors::ADB_KO,
+};
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+impl AndroidManager {
+    /// Starts writing the network traffic of a running AVD to `path`. The
+    /// console splits its commands at spaces, so `path` cannot have any.
+    pub async fn start_network_capture(&self, avd_name: &str, path: &Path) -> Result<()> {
+        if path.to_string_lossy().contains(char::is_whitespace) {
+            bail!(NETWORK_CAPTURE_PATH_HAS_SPACES.replace("{}", &path.display().to_string()));
+        }
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let path = path.to_string_lossy();
+        self.network_capture_command(avd_name, &[commands::adb::CAPTURE_START, &path])
+            .await
+            .context("Failed to start network capture")
+    }
+
+    /// Stops the network capture of a running AVD, completing the pcap.
+    pub async fn stop_network_capture(&self, avd_name: &str) -> Result<()> {
+        self.network_capture_command(avd_name, &[commands::adb::CAPTURE_STOP])
+            .await
+            .context("Failed to stop network capture")
+    }
+
+    async fn network_capture_command(&self, avd_name: &str, args: &[&str]) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        let mut command = vec![
+            "-s",
+            &serial,
+            commands::adb::EMU,
+            commands::adb::NETWORK,
+            commands::adb::CAPTURE,
+        ];
+        command.extend(args);
+        let output = self
+            .command_executor
+            .run(Path::new(commands::ADB), &command)
+            .await?;
+        // The console answers "OK", or "KO: <reason>"
+        if let Some(reason) = output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(ADB_KO))
+        {
+            bail!("{}", reason.trim_start_matches(':').trim());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,1255 @@
+//! Typed parsing of `avdmanager` and `sdkmanager` output.
+//!
+//! Every text format the Android SDK tools print is parsed here into plain
+//! structs, so `AndroidManager` never matches on raw lines itself. When a new
+//! SDK release changes its output, this is the only module that needs to
+//! follow, and the fixtures in `tests/fixtures/android_outputs.json` pin the
+//! formats that are known to work.
+
+use crate::constants::{
+    limits::SYSTEM_IMAGE_PARTS_REQUIRED,
+    progress::{
+        COMPLETION_THRESHOLD_PERCENTAGE, CREATE_COMPUTE_END_PERCENTAGE,
+        CREATE_COMPUTE_START_PERCENTAGE, CREATE_FETCH_START_PERCENTAGE,
+        CREATE_LOADING_START_PERCENTAGE, CREATE_PROFILE_PERCENTAGE, CREATE_WRITE_PERCENTAGE,
+        DOWNLOAD_PHASE_START_PERCENTAGE, EXTRACT_PHASE_START_PERCENTAGE,
+        INSTALL_PHASE_START_PERCENTAGE, PROGRESS_PHASE_100_PERCENT,
+    },
+};
+use crate::models::{DeviceProcess, DeviceService, InstallProgress, SystemProperty};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    // `avdmanager list avd`
+    static ref AVD_NAME_REGEX: Regex = Regex::new(r"Name:\s*(.+)").unwrap();
+    static ref PATH_REGEX: Regex = Regex::new(r"Path:\s*(.+)").unwrap();
+    static ref TARGET_REGEX: Regex = Regex::new(r"Target:\s*(.+)").unwrap();
+    static ref ABI_REGEX: Regex = Regex::new(r"Tag/ABI:\s*(.+)").unwrap();
+    static ref DEVICE_REGEX: Regex = Regex::new(r"Device:\s*(.+)").unwrap();
+
+    // `avdmanager list device`
+    static ref ID_REGEX: Regex = Regex::new(r#"id:\s*\d+\s*or\s*\"(.+)\""#).unwrap();
+    static ref NAME_REGEX: Regex = Regex::new(r"Name:\s*(.+)").unwrap();
+    static ref OEM_REGEX: Regex = Regex::new(r"OEM\s*:\s*(.+)").unwrap();
+
+    // `avdmanager create avd` progress bar ("[=====    ] 50% Loading local repository...")
+    static ref PROGRESS_BAR_REGEX: Regex = Regex::new(r"\]\s*(\d{1,3})%").unwrap();
+
+    // Download byte counts some sdkmanager versions print ("(12.5 MiB / 1.2 GiB)")
+    static ref TRANSFER_BYTES_REGEX: Regex = Regex::new(
+        r"(\d+(?:\.\d+)?)\s*([KMG]i?B|B)\s*(?:/|of)\s*(\d+(?:\.\d+)?)\s*([KMG]i?B|B)"
+    )
+    .unwrap();
+}
+
+const SYSTEM_IMAGE_PREFIX: &str = "system-images;android-";
+const PLATFORM_PREFIX: &str = "platforms;android-";
+const GENERIC_OEM: &str = "Generic";
+
+/// An AVD entry from `avdmanager list avd`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AvdInfo {
+    /// AVD name (e.g., "Pixel_7_API_34")
+    pub name: String,
+    /// Path of the `.avd` directory
+    pub path: String,
+    /// Target line, including the "Based on:" continuation when present
+    pub target: String,
+    /// Tag/ABI (e.g., "google_apis/arm64-v8a")
+    pub abi: String,
+    /// Device profile (e.g., "pixel_7 (Google)")
+    pub device: String,
+}
+
+/// A hardware profile from `avdmanager list device`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceDefinition {
+    /// Profile ID passed to `--device` (e.g., "pixel_7")
+    pub id: String,
+    /// Human readable name (e.g., "Pixel 7")
+    pub name: String,
+    /// Manufacturer, if listed
+    pub oem: Option<String>,
+}
+
+impl DeviceDefinition {
+    /// Name shown in the UI, with the OEM appended unless it is "Generic".
+    pub fn display_name(&self) -> String {
+        match self.oem.as_deref() {
+            Some(oem) if !oem.is_empty() && oem != GENERIC_OEM => {
+                format!("{} ({oem})", self.name)
+            }
+            _ => self.name.clone(),
+        }
+    }
+}
+
+/// A system image package from `sdkmanager --list`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SystemImagePackage {
+    /// Full package path (e.g., "system-images;android-34;google_apis;arm64-v8a")
+    pub package_id: String,
+    /// Platform part after "android-" (e.g., "34", "34-ext8")
+    pub api: String,
+    /// Image tag (e.g., "google_apis_playstore")
+    pub tag: String,
+    /// Image ABI (e.g., "arm64-v8a")
+    pub abi: String,
+    /// Whether the package was listed under "Installed packages"
+    pub installed: bool,
+    /// Download size from a `Size:` line of `sdkmanager --list --verbose`
+    pub download_size: Option<u64>,
+}
+
+impl SystemImagePackage {
+    /// Parses a package path such as "system-images;android-34;google_apis;x86_64".
+    pub fn parse(package_id: &str) -> Option<Self> {
+        if !package_id.starts_with(SYSTEM_IMAGE_PREFIX) {
+            return None;
+        }
+        let parts: Vec<&str> = package_id.split(';').collect();
+        if parts.len() < SYSTEM_IMAGE_PARTS_REQUIRED {
+            return None;
+        }
+
+        Some(Self {
+            package_id: package_id.to_string(),
+            api: parts[1].trim_start_matches("android-").to_string(),
+            tag: parts[2].to_string(),
+            abi: parts[3].to_string(),
+            installed: false,
+            download_size: None,
+        })
+    }
+
+    /// Numeric API level, if the platform part is a plain number.
+    pub fn api_level(&self) -> Option<u32> {
+        self.api.parse().ok()
+    }
+
+    /// Directory an AVD's `image.sysdir.1` points at for this image
+    /// (e.g., "system-images/android-34/google_apis/x86_64/").
+    pub fn sysdir(&self) -> String {
+        format!(
+            "system-images/android-{}/{}/{}/",
+            self.api, self.tag, self.abi
+        )
+    }
+
+    /// Returns true if an AVD `config.ini` boots from this image.
+    pub fn is_used_by_config(&self, config_ini: &str) -> bool {
+        let sysdir = self.sysdir();
+        parse_config_ini(config_ini)
+            .iter()
+            .find(|(key, _)| key == "image.sysdir.1")
+            .is_some_and(|(_, value)| {
+                value.replace('\\', "/").trim_end_matches('/') == sysdir.trim_end_matches('/')
+            })
+    }
+}
+
+/// Parses `avdmanager list avd` output one AVD at a time.
+pub struct AvdListParser<'a> {
+    lines: std::str::Lines<'a>,
+    pub(crate) current_device_info: Option<AvdInfo>,
+    pub(crate) current_target_full: String,
+}
+
+impl<'a> AvdListParser<'a> {
+    pub fn new(output: &'a str) -> Self {
+        Self {
+            lines: output.lines(),
+            current_device_info: None,
+            current_target_full: String::new(),
+        }
+    }
+
+    /// Returns the next AVD in the output, or `None` when the output is exhausted.
+    pub fn parse_next_device(&mut self) -> Option<AvdInfo> {
+        while let Some(line) = self.lines.next() {
+            let trimmed_line = line.trim();
+
+            if self.current_device_info.is_some() && line.starts_with("          Based on:") {
+                self.current_target_full.push(' ');
+                self.current_target_full.push_str(trimmed_line);
+            }
+
+            if trimmed_line.starts_with("---") || trimmed_line.is_empty() {
+                if let Some(info) = self.finish_device() {
+                    return Some(info);
+                }
+                continue;
+            }
+
+            if let Some(name) = capture(&AVD_NAME_REGEX, trimmed_line) {
+                self.current_device_info = Some(AvdInfo {
+                    name,
+                    ..AvdInfo::default()
+                });
+            } else if let Some(info) = self.current_device_info.as_mut() {
+                if let Some(path) = capture(&PATH_REGEX, trimmed_line) {
+                    info.path = path;
+                } else if let Some(target) = capture(&TARGET_REGEX, trimmed_line) {
+                    info.target = target;
+                } else if let Some(abi) = capture(&ABI_REGEX, trimmed_line) {
+                    info.abi = abi;
+                } else if let Some(device) = capture(&DEVICE_REGEX, trimmed_line) {
+                    info.device = device;
+                }
+            }
+        }
+
+        self.finish_device()
+    }
+
+    fn finish_device(&mut self) -> Option<AvdInfo> {
+        let mut info = self.current_device_info.take()?;
+        if !self.current_target_full.is_empty() {
+            info.target.push_str(&self.current_target_full);
+            self.current_target_full.clear();
+        }
+        Some(info)
+    }
+}
+
+impl Iterator for AvdListParser<'_> {
+    type Item = AvdInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parse_next_device()
+    }
+}
+
+/// Parses every AVD in `avdmanager list avd` output.
+pub fn parse_avd_list(output: &str) -> Vec<AvdInfo> {
+    AvdListParser::new(output).collect()
+}
+
+/// Parses the hardware profiles in `avdmanager list device` output.
+pub fn parse_device_definitions(output: &str) -> Vec<DeviceDefinition> {
+    let mut definitions = Vec::new();
+    let mut current = DeviceDefinition::default();
+
+    for line in output.lines() {
+        if let Some(id) = capture(&ID_REGEX, line) {
+            current.id = id;
+        } else if let Some(name) = capture(&NAME_REGEX, line) {
+            current.name = name;
+        } else if let Some(oem) = capture(&OEM_REGEX, line) {
+            current.oem = Some(oem);
+        } else if line.contains("-----") && !current.id.is_empty() {
+            definitions.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.id.is_empty() {
+        definitions.push(current);
+    }
+
+    definitions
+}
+
+/// Parses the system image packages in `sdkmanager --list` output, marking
+/// the ones listed in the installed section.
+pub fn parse_system_images(output: &str) -> Vec<SystemImagePackage> {
+    let mut parser = SystemImageParser::default();
+    for line in output.lines() {
+        parser.push_line(line);
+    }
+    parser.into_packages()
+}
+
+/// Line-by-line form of [`parse_system_images`] for output that is still
+/// being printed.
+#[derive(Debug, Default)]
+pub struct SystemImageParser {
+    packages: Vec<SystemImagePackage>,
+    in_installed_section: bool,
+}
+
+impl SystemImageParser {
+    /// Parses one line; returns true if it listed a system image package.
+    pub fn push_line(&mut self, line: &str) -> bool {
+        let line = line.trim();
+
+        if line.contains("Installed packages") || line.contains("Installed Packages") {
+            self.in_installed_section = true;
+            return false;
+        } else if line.contains("Available Packages") || line.contains("Available Updates") {
+            self.in_installed_section = false;
+            return false;
+        }
+
+        // Verbose output lists details indented under each package
+        if let Some(size) = line
+            .strip_prefix("Size:")
+            .or_else(|| line.strip_prefix("Download Size:"))
+        {
+            if let Some(package) = self.packages.last_mut() {
+                package.download_size = parse_byte_size(size);
+            }
+            return false;
+        }
+
+        let package_id = line.split_whitespace().next().unwrap_or(line);
+        match SystemImagePackage::parse(package_id) {
+            Some(mut package) => {
+                package.installed = self.in_installed_section;
+                self.packages.push(package);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The packages parsed so far.
+    pub fn packages(&self) -> &[SystemImagePackage] {
+        &self.packages
+    }
+
+    /// Finishes parsing.
+    pub fn into_packages(self) -> Vec<SystemImagePackage> {
+        self.packages
+    }
+}
+
+/// Parses a size such as "1.2 GB", "850 MB", "512 KiB" or a plain byte count.
+pub fn parse_byte_size(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" | "BYTES" => 1,
+        "K" | "KB" | "KIB" => 1 << 10,
+        "M" | "MB" | "MIB" => 1 << 20,
+        "G" | "GB" | "GIB" => 1 << 30,
+        _ => return None,
+    };
+    Some((number * multiplier as f64) as u64)
+}
+
+/// Finds the Android version name sdkmanager lists for a platform
+/// (e.g., "14" from `platforms;android-34 | ... | Android 14`).
+pub fn parse_platform_version_name(output: &str, api_level: u32) -> Option<String> {
+    parse_platform_version_names(output)
+        .into_iter()
+        .find_map(|(level, version_name)| (level == api_level).then_some(version_name))
+}
+
+/// Finds the Android version names sdkmanager lists for platforms
+/// (e.g., (34, "14") from `platforms;android-34 | ... | Android 14`).
+pub fn parse_platform_version_names(output: &str) -> Vec<(u32, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (package, rest) = line.trim().split_once('|')?;
+            let api_level = package
+                .trim()
+                .strip_prefix(PLATFORM_PREFIX)?
+                .parse::<u32>()
+                .ok()?;
+            let (_, version_name) = rest.rsplit_once("| Android ")?;
+            let version_name = version_name.trim();
+            (!version_name.is_empty()).then(|| (api_level, version_name.to_string()))
+        })
+        .collect()
+}
+
+/// Parses the API level from a package ID ("system-images;android-34;..." or
+/// "platforms;android-34").
+pub fn parse_api_level(package_id: &str) -> Option<u32> {
+    let start = package_id.find("android-")?;
+    let api_part = &package_id[start + "android-".len()..];
+    let end = api_part.find(';').unwrap_or(api_part.len());
+    api_part[..end].parse().ok()
+}
+
+/// Parses `key=value` lines from an AVD `config.ini`, sorted by key.
+pub fn parse_config_ini(content: &str) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            (!key.is_empty() && !key.starts_with('#'))
+                .then(|| (key.to_string(), value.trim().to_string()))
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+/// Sets `key=value` entries in an ini file, keeping every other line and its
+/// order. Keys that are not present yet are appended.
+pub fn set_config_values(content: &str, values: &[(&str, &str)]) -> String {
+    let mut pending: Vec<&(&str, &str)> = values.iter().collect();
+    let mut lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let key = line.split_once('=').map(|(key, _)| key.trim());
+            match pending.iter().position(|(k, _)| Some(*k) == key) {
+                Some(index) => {
+                    let (key, value) = pending.remove(index);
+                    format!("{key}={value}")
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    lines.extend(pending.iter().map(|(key, value)| format!("{key}={value}")));
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+/// Points every ini value under `old_dir` at the same file under `new_dir`,
+/// keeping keys, spacing, and every other line as they are.
+pub fn rewrite_ini_paths(content: &str, old_dir: &str, new_dir: &str) -> String {
+    let old_dir = old_dir.trim_end_matches(['/', '\\']);
+    let new_dir = new_dir.trim_end_matches(['/', '\\']);
+    content
+        .split_inclusive('\n')
+        .map(|line| {
+            let Some((key, value)) = line.split_once('=') else {
+                return line.to_string();
+            };
+            let trimmed = value.trim_start();
+            let leading = &value[..value.len() - trimmed.len()];
+            match trimmed.strip_prefix(old_dir) {
+                Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\', '\r', '\n']) => {
+                    format!("{key}={leading}{new_dir}{rest}")
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// The window with input focus in `dumpsys window` output, e.g.
+/// `Window{1a2b u0 com.android.launcher3/.Launcher}`. `None` while nothing
+/// has focus.
+pub fn parse_focused_window(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("mCurrentFocus="))
+        .map(str::trim)
+        .filter(|window| !window.is_empty() && *window != "null")
+}
+
+/// The `package/activity` component on the last line printed by `cmd
+/// package resolve-activity --brief`; "No activity found" yields `None`.
+pub fn parse_resolved_activity(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .filter(|component| {
+            !component.contains(char::is_whitespace)
+                && component
+                    .split_once('/')
+                    .is_some_and(|(package, _)| !package.is_empty())
+        })
+}
+
+/// Package of the home activity printed by `cmd package resolve-activity
+/// --brief`.
+pub fn parse_resolved_activity_package(output: &str) -> Option<&str> {
+    parse_resolved_activity(output)?
+        .split_once('/')
+        .map(|(package, _)| package)
+}
+
+/// Percentage of an `adb bugreport` progress line such as
+/// `[ 42%] generating bugreport-sdk_gphone64_arm64-2024-01-01-12-00-00.zip`.
+pub fn parse_bugreport_progress(line: &str) -> Option<u8> {
+    let (percentage, _) = line.trim().strip_prefix('[')?.split_once("%]")?;
+    percentage.trim().parse().ok().filter(|&value| value <= 100)
+}
+
+/// Parses `pm list packages` output into sorted package names.
+pub fn parse_package_list(output: &str) -> Vec<String> {
+    let mut packages: Vec<String> = output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("package:"))
+        .map(|package| package.trim().to_string())
+        .filter(|package| !package.is_empty())
+        .collect();
+    packages.sort();
+    packages
+}
+
+/// Parses `ps -A -o PID,USER,RSS,NAME` output. Rows that do not start with
+/// a numeric PID, like the header, are skipped.
+pub fn parse_process_list(output: &str) -> Vec<DeviceProcess> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let pid = columns.next()?.parse().ok()?;
+            let user = columns.next()?.to_string();
+            let memory_kb = columns.next()?.parse().ok();
+            let name = columns.collect::<Vec<_>>().join(" ");
+            (!name.is_empty()).then_some(DeviceProcess {
+                pid,
+                name,
+                user: Some(user),
+                memory_kb,
+            })
+        })
+        .collect()
+}
+
+/// Parses `dumpsys activity services` output into the running services,
+/// sorted by component. Each `* ServiceRecord{hash user component}` entry
+/// is followed by an `app=ProcessRecord{hash pid:process/uid}` line once the
+/// hosting process runs.
+pub fn parse_activity_services(output: &str) -> Vec<DeviceService> {
+    let mut services: Vec<DeviceService> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if let Some(record) = line.strip_prefix("* ServiceRecord{") {
+            if let Some(component) = record.trim_end_matches('}').split_whitespace().nth(2) {
+                services.push(DeviceService {
+                    name: component.to_string(),
+                    pid: None,
+                });
+            }
+        } else if let Some(process) = line.strip_prefix("app=ProcessRecord{") {
+            let pid = process
+                .split_whitespace()
+                .nth(1)
+                .and_then(|process| process.split_once(':'))
+                .and_then(|(pid, _)| pid.parse().ok());
+            if let Some(service) = services.last_mut().filter(|service| service.pid.is_none()) {
+                service.pid = pid;
+            }
+        }
+    }
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+    services.dedup_by(|a, b| a.name == b.name);
+    services
+}
+
+/// Parses `getprop` output, one `[name]: [value]` per line, sorted by name.
+/// Continuation lines of the rare multi-line values are skipped.
+pub fn parse_getprop(output: &str) -> Vec<SystemProperty> {
+    let mut properties: Vec<SystemProperty> = output
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line
+                .trim()
+                .strip_prefix('[')?
+                .strip_suffix(']')?
+                .split_once("]: [")?;
+            Some(SystemProperty {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect();
+    properties.sort_by(|a, b| a.name.cmp(&b.name));
+    properties
+}
+
+/// Parses `emulator -accel-check` output, where the status code and its
+/// explanation sit between `accel:` and `accel` lines:
+///
+/// ```text
+/// accel:
+/// 10
+/// /dev/kvm is not found: VT disabled in BIOS or KVM kernel module not loaded
+/// accel
+/// ```
+///
+/// Returns the explanation when acceleration is not usable, and `None` when
+/// it is or the output has no such block.
+pub fn parse_accel_check(output: &str) -> Option<String> {
+    let mut lines = output
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.ends_with("accel:"))
+        .skip(1);
+    let status: i32 = lines.next()?.parse().ok()?;
+    if status == 0 {
+        return None;
+    }
+    let details: Vec<&str> = lines
+        .take_while(|line| *line != "accel")
+        .filter(|line| !line.is_empty())
+        .collect();
+    Some(if details.is_empty() {
+        format!("status {status}")
+    } else {
+        details.join(" ")
+    })
+}
+
+/// Parses the available space in MB from `df -Pk` output.
+pub fn parse_df_available_mb(output: &str) -> Option<u64> {
+    let available_kb: u64 = output
+        .lines()
+        .nth(1)?
+        .split_whitespace()
+        .nth(3)?
+        .parse()
+        .ok()?;
+    Some(available_kb / 1024)
+}
+
+/// Package id of the system image an AVD `image.sysdir.1` value points at,
+/// e.g. "system-images;android-34;google_apis;x86_64".
+pub fn package_id_from_sysdir(sysdir: &str) -> String {
+    sysdir
+        .replace('\\', "/")
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// ABI of an `avdmanager list avd` "Tag/ABI" value, e.g. "arm64-v8a" for
+/// "google_apis/arm64-v8a".
+pub fn abi_from_tag_abi(tag_abi: &str) -> &str {
+    tag_abi.rsplit('/').next().unwrap_or_default().trim()
+}
+
+/// Serials of the emulators that `adb devices`, or one update of
+/// `adb track-devices`, lists as attached and online ("emulator-5554\tdevice").
+pub fn parse_attached_emulators(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter(|line| line.contains("emulator-") && line.contains("device"))
+        .filter_map(|line| line.split_whitespace().next().map(ToString::to_string))
+        .collect()
+}
+
+/// Maps emulator serials to AVD names from the contents of emulator
+/// discovery files (`port.serial=5554`, `avd.name=Pixel_7`). A serial that
+/// several files claim, as when a crashed emulator left its file behind, is
+/// left out.
+pub fn parse_discovery_serials<'a>(
+    contents: impl IntoIterator<Item = &'a str>,
+) -> HashMap<String, String> {
+    let mut serials: HashMap<String, Option<String>> = HashMap::new();
+    for content in contents {
+        let entries = parse_config_ini(content);
+        let value = |key: &str| {
+            entries
+                .iter()
+                .find(|(k, value)| k == key && !value.is_empty())
+                .map(|(_, value)| value.clone())
+        };
+        let (Some(port), Some(avd_name)) = (value("port.serial"), value("avd.name")) else {
+            continue;
+        };
+        serials
+            .entry(format!("emulator-{port}"))
+            .and_modify(|claimed| *claimed = None)
+            .or_insert(Some(avd_name));
+    }
+    serials
+        .into_iter()
+        .filter_map(|(serial, avd_name)| Some((serial, avd_name?)))
+        .collect()
+}
+
+/// Parses emulator console `sensor status` output ("acceleration: enabled.")
+/// into (sensor, state) pairs.
+pub fn parse_sensor_status(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (sensor, state) = line.trim().split_once(':')?;
+            let state = state.trim().trim_end_matches('.');
+            (!sensor.is_empty() && !state.is_empty())
+                .then(|| (sensor.trim().to_string(), state.to_string()))
+        })
+        .collect()
+}
+
+/// Maps one line of `avdmanager create avd` output to the creation phase it
+/// reports. avdmanager redraws its progress bar with `\r`, so each redrawn
+/// segment counts as a line. Lines that report no phase return `None`.
+pub fn parse_avd_create_progress(line: &str) -> Option<InstallProgress> {
+    let bar_percentage = || {
+        capture(&PROGRESS_BAR_REGEX, line)
+            .and_then(|value| value.parse::<u8>().ok())
+            .unwrap_or(0)
+            .min(PROGRESS_PHASE_100_PERCENT)
+    };
+    let within = |start: u8, end: u8| {
+        start + ((u16::from(end - start) * u16::from(bar_percentage())) / 100) as u8
+    };
+
+    let (operation, percentage) = if line.contains("Loading local repository") {
+        (
+            "Loading local repository...",
+            within(
+                CREATE_LOADING_START_PERCENTAGE,
+                CREATE_FETCH_START_PERCENTAGE,
+            ),
+        )
+    } else if line.contains("Fetch remote repository") {
+        (
+            "Fetching remote repository...",
+            within(
+                CREATE_FETCH_START_PERCENTAGE,
+                CREATE_COMPUTE_START_PERCENTAGE,
+            ),
+        )
+    } else if line.contains("Computing updates") {
+        (
+            "Computing updates...",
+            within(
+                CREATE_COMPUTE_START_PERCENTAGE,
+                CREATE_COMPUTE_END_PERCENTAGE,
+            ),
+        )
+    } else if line.contains("hardware profile") {
+        ("Writing hardware profile...", CREATE_PROFILE_PERCENTAGE)
+    } else if line.contains("userdata") {
+        ("Writing userdata image...", CREATE_WRITE_PERCENTAGE)
+    } else if line.contains("Created AVD") {
+        ("Writing AVD configuration...", CREATE_WRITE_PERCENTAGE)
+    } else {
+        return None;
+    };
+
+    Some(InstallProgress {
+        operation: operation.to_string(),
+        percentage,
+        eta_seconds: None,
+        bytes_per_second: None,
+    })
+}
+
+/// Maps one line of `sdkmanager <package>` output to the install phase it
+/// reports, placing sdkmanager's own bar percentage, or the share of bytes
+/// downloaded, within the phase. sdkmanager redraws its bar with `\r` like
+/// avdmanager does. Lines that report no phase return `None`.
+pub fn parse_sdkmanager_install_progress(line: &str) -> Option<InstallProgress> {
+    let bar_percentage = capture(&PROGRESS_BAR_REGEX, line)
+        .and_then(|value| value.parse::<u8>().ok())
+        .map(|percentage| percentage.min(PROGRESS_PHASE_100_PERCENT));
+    let fraction = bar_percentage
+        .map(u64::from)
+        .or_else(|| {
+            parse_transfer_bytes(line)
+                .filter(|(_, total)| *total > 0)
+                .map(|(downloaded, total)| downloaded.min(total) * 100 / total)
+        })
+        .unwrap_or(0);
+    let within = |start: u8, end: u8| start + (u64::from(end - start) * fraction / 100) as u8;
+
+    let (operation, percentage) = if line.contains("Downloading") {
+        (
+            "Downloading system image...",
+            within(
+                DOWNLOAD_PHASE_START_PERCENTAGE,
+                EXTRACT_PHASE_START_PERCENTAGE,
+            ),
+        )
+    } else if line.contains("Unzipping") || line.contains("Extracting") {
+        (
+            "Extracting system image...",
+            within(
+                EXTRACT_PHASE_START_PERCENTAGE,
+                INSTALL_PHASE_START_PERCENTAGE,
+            ),
+        )
+    } else if line.contains("Installing") {
+        (
+            "Installing system image...",
+            within(
+                INSTALL_PHASE_START_PERCENTAGE,
+                COMPLETION_THRESHOLD_PERCENTAGE,
+            ),
+        )
+    } else if bar_percentage.is_some() {
+        // Loading the local and remote repositories and computing updates
+        (
+            "Loading package information...",
+            within(0, DOWNLOAD_PHASE_START_PERCENTAGE),
+        )
+    } else {
+        return None;
+    };
+
+    Some(InstallProgress {
+        operation: operation.to_string(),
+        percentage,
+        eta_seconds: None,
+        bytes_per_second: None,
+    })
+}
+
+/// Bytes downloaded and the total ("12.5 MiB / 1.2 GiB") when a line of
+/// sdkmanager output has them.
+pub fn parse_transfer_bytes(line: &str) -> Option<(u64, u64)> {
+    let captures = TRANSFER_BYTES_REGEX.captures(line)?;
+    let bytes = |value: usize, unit: usize| {
+        let value: f64 = captures.get(value)?.as_str().parse().ok()?;
+        let multiplier = match captures.get(unit)?.as_str().chars().next()? {
+            'K' => 1u64 << 10,
+            'M' => 1 << 20,
+            'G' => 1 << 30,
+            _ => 1,
+        };
+        Some((value * multiplier as f64) as u64)
+    };
+    Some((bytes(1, 2)?, bytes(3, 4)?))
+}
+
+fn capture(regex: &Regex, line: &str) -> Option<String> {
+    regex
+        .captures(line)
+        .and_then(|caps| caps.get(1))
+        .map(|value| value.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDKMANAGER_VERBOSE: &str = r#"
+Installed packages:
+--------------------------------------
+system-images;android-34;google_apis_playstore;arm64-v8a
+    Description:        Google Play ARM 64 v8a System Image
+    Version:            14
+
+Available Packages:
+--------------------------------------
+system-images;android-35;google_apis;x86_64
+    Description:        Google APIs Intel x86_64 Atom System Image
+system-images;android-34-ext8;google_apis;x86_64
+platforms;android-35
+"#;
+
+    #[test]
+    fn test_parse_system_images_tracks_sections() {
+        let packages = parse_system_images(SDKMANAGER_VERBOSE);
+        assert_eq!(packages.len(), 3);
+
+        assert_eq!(packages[0].api_level(), Some(34));
+        assert_eq!(packages[0].tag, "google_apis_playstore");
+        assert_eq!(packages[0].abi, "arm64-v8a");
+        assert!(packages[0].installed);
+
+        assert!(!packages[1].installed);
+        assert_eq!(packages[2].api, "34-ext8");
+        assert_eq!(packages[2].api_level(), None);
+    }
+
+    #[test]
+    fn test_system_image_parser_reports_packages_as_they_arrive() {
+        let mut parser = SystemImageParser::default();
+        let mut counts = Vec::new();
+        for line in SDKMANAGER_VERBOSE.lines() {
+            if parser.push_line(line) {
+                counts.push(parser.packages().len());
+            }
+        }
+
+        assert_eq!(counts, vec![1, 2, 3]);
+        assert_eq!(
+            parser.into_packages(),
+            parse_system_images(SDKMANAGER_VERBOSE)
+        );
+    }
+
+    #[test]
+    fn test_system_image_package_rejects_other_packages() {
+        assert!(SystemImagePackage::parse("platforms;android-34").is_none());
+        assert!(SystemImagePackage::parse("system-images;android-34;google_apis").is_none());
+        assert!(SystemImagePackage::parse("").is_none());
+    }
+
+    #[test]
+    fn test_device_definition_display_name() {
+        let mut definition = DeviceDefinition {
+            id: "pixel_7".to_string(),
+            name: "Pixel 7".to_string(),
+            oem: Some("Google".to_string()),
+        };
+        assert_eq!(definition.display_name(), "Pixel 7 (Google)");
+
+        definition.oem = Some("Generic".to_string());
+        assert_eq!(definition.display_name(), "Pixel 7");
+        definition.oem = None;
+        assert_eq!(definition.display_name(), "Pixel 7");
+    }
+
+    #[test]
+    fn test_parse_device_definitions_without_trailing_separator() {
+        let output = "    id: 0 or \"pixel_7\"\n    Name: Pixel 7\n    OEM : Google\n";
+        let definitions = parse_device_definitions(output);
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].id, "pixel_7");
+    }
+
+    #[test]
+    fn test_parse_config_ini_sorts_and_skips_comments() {
+        let entries = parse_config_ini("hw.ramSize=2048\n# comment=1\nAvdId = Pixel\nbroken\n");
+        assert_eq!(
+            entries,
+            vec![
+                ("AvdId".to_string(), "Pixel".to_string()),
+                ("hw.ramSize".to_string(), "2048".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_system_image_matches_config_sysdir() {
+        let image =
+            SystemImagePackage::parse("system-images;android-34;google_apis;arm64-v8a").unwrap();
+        assert_eq!(
+            image.sysdir(),
+            "system-images/android-34/google_apis/arm64-v8a/"
+        );
+
+        assert!(image
+            .is_used_by_config("image.sysdir.1=system-images/android-34/google_apis/arm64-v8a/\n"));
+        assert!(image.is_used_by_config(
+            "AvdId=Pixel\nimage.sysdir.1 = system-images\\android-34\\google_apis\\arm64-v8a\\\n"
+        ));
+        assert!(!image.is_used_by_config(
+            "image.sysdir.1=system-images/android-34/google_apis_playstore/arm64-v8a/\n"
+        ));
+        assert!(!image.is_used_by_config("hw.ramSize=2048\n"));
+    }
+
+    #[test]
+    fn test_parse_system_images_reads_verbose_sizes() {
+        let output = "Installed packages:\n\
+            system-images;android-34;google_apis;x86_64\n\
+            \x20   Description: Google APIs Intel x86_64 Atom System Image\n\
+            Available Packages:\n\
+            system-images;android-35;google_apis;x86_64\n\
+            \x20   Description: Google APIs Intel x86_64 Atom System Image\n\
+            \x20   Size:        1.5 GB\n";
+        let packages = parse_system_images(output);
+
+        assert_eq!(packages.len(), 2);
+        assert!(packages[0].installed);
+        assert_eq!(packages[0].download_size, None);
+        assert_eq!(packages[1].download_size, Some(1_610_612_736));
+    }
+
+    #[test]
+    fn test_parse_byte_size_units() {
+        assert_eq!(parse_byte_size("2048"), Some(2048));
+        assert_eq!(parse_byte_size("850 MB"), Some(850 * 1024 * 1024));
+        assert_eq!(parse_byte_size("512KiB"), Some(512 * 1024));
+        assert_eq!(parse_byte_size("1.5 GB"), Some(1_610_612_736));
+        assert_eq!(parse_byte_size("lots"), None);
+        assert_eq!(parse_byte_size("3 parsecs"), None);
+    }
+
+    #[test]
+    fn test_set_config_values_keeps_other_lines() {
+        let updated = set_config_values(
+            "AvdId=Pixel\nimage.sysdir.1=system-images/android-33/google_apis/x86_64/\ntag.id=google_apis\n",
+            &[
+                ("image.sysdir.1", "system-images/android-34/default/x86_64/"),
+                ("tag.id", "default"),
+                ("abi.type", "x86_64"),
+            ],
+        );
+        assert_eq!(
+            updated,
+            "AvdId=Pixel\nimage.sysdir.1=system-images/android-34/default/x86_64/\ntag.id=default\nabi.type=x86_64\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_ini_paths_only_touches_the_moved_directory() {
+        let updated = rewrite_ini_paths(
+            "disk.dataPartition.path = /home/u/.android/avd/Pixel.avd/userdata-qemu.img\n\
+             skin.path=/home/u/.android/avd/Pixel.avd\n\
+             other.path=/home/u/.android/avd/Pixel.avd2/file\n\
+             image.sysdir.1=system-images/android-34/default/x86_64/\n",
+            "/home/u/.android/avd/Pixel.avd/",
+            "/mnt/data/Pixel.avd",
+        );
+        assert_eq!(
+            updated,
+            "disk.dataPartition.path = /mnt/data/Pixel.avd/userdata-qemu.img\n\
+             skin.path=/mnt/data/Pixel.avd\n\
+             other.path=/home/u/.android/avd/Pixel.avd2/file\n\
+             image.sysdir.1=system-images/android-34/default/x86_64/\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_focused_window_and_home_package() {
+        let dumpsys = "WINDOW MANAGER WINDOWS (dumpsys window windows)\n  \
+            mCurrentFocus=Window{5e1d3c u0 com.google.android.apps.nexuslauncher/com.google.android.apps.nexuslauncher.NexusLauncherActivity}\n  \
+            mFocusedApp=ActivityRecord{...}\n";
+        assert!(parse_focused_window(dumpsys)
+            .is_some_and(|window| window.contains("com.google.android.apps.nexuslauncher/")));
+        assert_eq!(parse_focused_window("  mCurrentFocus=null\n"), None);
+
+        let resolved =
+            "priority=0 preferredOrder=0 match=0x108000 specificIndex=-1 isDefault=true\n\
+            com.google.android.apps.nexuslauncher/.NexusLauncherActivity\n";
+        assert_eq!(
+            parse_resolved_activity_package(resolved),
+            Some("com.google.android.apps.nexuslauncher")
+        );
+        assert_eq!(
+            parse_resolved_activity(resolved),
+            Some("com.google.android.apps.nexuslauncher/.NexusLauncherActivity")
+        );
+        assert_eq!(parse_resolved_activity_package("No activity found\n"), None);
+    }
+
+    #[test]
+    fn test_parse_bugreport_progress() {
+        assert_eq!(
+            parse_bugreport_progress("[ 42%] generating bugreport-sdk_gphone64_arm64.zip"),
+            Some(42)
+        );
+        assert_eq!(
+            parse_bugreport_progress("[100%] generating bugreport.zip"),
+            Some(100)
+        );
+        assert_eq!(
+            parse_bugreport_progress(
+                "/data/user_de/0/com.android.shell/files/bugreports/x.zip: 1 file pulled"
+            ),
+            None
+        );
+        assert_eq!(parse_bugreport_progress("[ 42 ] not progress"), None);
+    }
+
+    #[test]
+    fn test_parse_process_list_and_activity_services() {
+        let processes = parse_process_list(
+            "  PID USER            RSS NAME\n\
+                 1 root           9800 init\n\
+               612 system       251340 system_server\n\
+              2051 u0_a123       40112 com.example:sync\n",
+        );
+        assert_eq!(processes.len(), 3);
+        assert_eq!(processes[1].pid, 612);
+        assert_eq!(processes[1].memory_kb, Some(251_340));
+        assert_eq!(processes[2].user.as_deref(), Some("u0_a123"));
+        assert_eq!(processes[2].name, "com.example:sync");
+
+        let services = parse_activity_services(
+            "ACTIVITY MANAGER SERVICES (dumpsys activity services)\n\
+               User 0 active services:\n\
+               * ServiceRecord{5f1e0a1 u0 com.example/.SyncService}\n\
+                 intent={cmp=com.example/.SyncService}\n\
+                 app=ProcessRecord{1c4f0b8 2051:com.example:sync/u0a123}\n\
+               * ServiceRecord{77aa012 u0 com.android.systemui/.SystemUIService}\n\
+                 app=null\n",
+        );
+        assert_eq!(
+            services,
+            vec![
+                DeviceService {
+                    name: "com.android.systemui/.SystemUIService".to_string(),
+                    pid: None,
+                },
+                DeviceService {
+                    name: "com.example/.SyncService".to_string(),
+                    pid: Some(2051),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_getprop() {
+        let properties = parse_getprop(
+            "[ro.build.version.sdk]: [34]\n\
+             [debug.layout]: [false]\n\
+             [persist.sys.locale]: []\n\
+             [ro.multi.line]: [first\n\
+             second]\n",
+        );
+        let names: Vec<_> = properties.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["debug.layout", "persist.sys.locale", "ro.build.version.sdk"]
+        );
+        assert_eq!(properties[0].value, "false");
+        assert_eq!(properties[1].value, "");
+    }
+
+    #[test]
+    fn test_parse_attached_emulators_skips_offline_ones() {
+        let output = "List of devices attached\nemulator-5554\tdevice\n\
+                      emulator-5556\toffline\nR58M123ABC\tdevice\n";
+        assert_eq!(parse_attached_emulators(output), vec!["emulator-5554"]);
+        assert!(parse_attached_emulators("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_discovery_serials_skips_contested_ports() {
+        let serials = parse_discovery_serials([
+            "port.serial=5554\nport.adb=5555\navd.name=Pixel_7_API_34\n",
+            "port.serial=5556\navd.name=Tablet\n",
+            "port.serial=5556\navd.name=Stale\n",
+            "avd.name=No_Port\n",
+        ]);
+        assert_eq!(serials.len(), 1);
+        assert_eq!(
+            serials.get("emulator-5554").map(String::as_str),
+            Some("Pixel_7_API_34")
+        );
+    }
+
+    #[test]
+    fn test_parse_package_list_and_sensor_status() {
+        let packages = parse_package_list("package:com.example.b\npackage:com.example.a\n\n");
+        assert_eq!(packages, vec!["com.example.a", "com.example.b"]);
+
+        let sensors = parse_sensor_status("acceleration: enabled.\ngyroscope: disabled.\nOK\n");
+        assert_eq!(
+            sensors,
+            vec![
+                ("acceleration".to_string(), "enabled".to_string()),
+                ("gyroscope".to_string(), "disabled".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_platform_version_name_requires_exact_package() {
+        let output = "  platforms;android-340 | 1 | Platform | p | Android 99\n  \
+                      platforms;android-34 | 3 | Platform | p | Android 14\n  \
+                      platforms;android-34-ext8 | 1 | Platform | p | Android 14\n";
+        assert_eq!(
+            parse_platform_version_name(output, 34).as_deref(),
+            Some("14")
+        );
+        assert_eq!(parse_platform_version_name(output, 33), None);
+        assert_eq!(
+            parse_platform_version_names(output),
+            vec![(340, "99".to_string()), (34, "14".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_avd_create_progress_maps_phases() {
+        let stage = |line: &str| {
+            parse_avd_create_progress(line)
+                .map(|progress| (progress.operation, progress.percentage))
+        };
+
+        assert_eq!(
+            stage("[====                 ] 10% Loading local repository..."),
+            Some(("Loading local repository...".to_string(), 12))
+        );
+        assert_eq!(
+            stage("[=====================] 100% Fetch remote repository..."),
+            Some((
+                "Fetching remote repository...".to_string(),
+                CREATE_COMPUTE_START_PERCENTAGE
+            ))
+        );
+        assert_eq!(
+            stage("Do you wish to create a custom hardware profile? [no]"),
+            Some((
+                "Writing hardware profile...".to_string(),
+                CREATE_PROFILE_PERCENTAGE
+            ))
+        );
+        assert_eq!(
+            stage("Writing userdata image..."),
+            Some((
+                "Writing userdata image...".to_string(),
+                CREATE_WRITE_PERCENTAGE
+            ))
+        );
+        assert_eq!(stage("Warning: skin not found"), None);
+    }
+
+    #[test]
+    fn test_parse_sdkmanager_install_progress_uses_bar_and_bytes() {
+        let stage = |line: &str| {
+            parse_sdkmanager_install_progress(line)
+                .map(|progress| (progress.operation, progress.percentage))
+        };
+
+        assert_eq!(
+            stage("[=====                ] 50% Fetch remote repository..."),
+            Some(("Loading package information...".to_string(), 10))
+        );
+        assert_eq!(
+            stage("[==========           ] 40% Downloading x86_64-34_r13.zip..."),
+            Some(("Downloading system image...".to_string(), 40))
+        );
+        assert_eq!(
+            stage("Downloading x86_64-34_r13.zip (256.0 MiB / 1.0 GiB)"),
+            Some(("Downloading system image...".to_string(), 32))
+        );
+        assert_eq!(
+            stage("[=====================] 100% Unzipping... x86_64/system.img"),
+            Some((
+                "Extracting system image...".to_string(),
+                INSTALL_PHASE_START_PERCENTAGE
+            ))
+        );
+        assert_eq!(stage("Accept? (y/N): y"), None);
+
+        assert_eq!(
+            parse_transfer_bytes("(512 KiB of 2 MiB)"),
+            Some((512 * 1024, 2 * 1024 * 1024))
+        );
+        assert_eq!(
+            parse_transfer_bytes("Downloading x86_64-34_r13.zip..."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_launch_diagnosis_outputs() {
+        assert_eq!(
+            parse_accel_check(
+                "accel:\n10\n/dev/kvm is not found: VT disabled in BIOS or KVM kernel module not loaded\naccel\n"
+            ),
+            Some(
+                "/dev/kvm is not found: VT disabled in BIOS or KVM kernel module not loaded"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse_accel_check("accel:\n0\nKVM (version 12) is installed and usable.\naccel\n"),
+            None
+        );
+        assert_eq!(parse_accel_check("unknown option"), None);
+
+        assert_eq!(
+            parse_df_available_mb(
+                "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                 /dev/nvme0n1p2   490617784 480000000    524288      99% /\n"
+            ),
+            Some(512)
+        );
+        assert_eq!(parse_df_available_mb(""), None);
+
+        assert_eq!(
+            package_id_from_sysdir("system-images/android-34/google_apis/x86_64/"),
+            "system-images;android-34;google_apis;x86_64"
+        );
+        assert_eq!(
+            package_id_from_sysdir("system-images\\android-34\\google_apis\\x86_64\\"),
+            "system-images;android-34;google_apis;x86_64"
+        );
+
+        assert_eq!(abi_from_tag_abi("google_apis/arm64-v8a"), "arm64-v8a");
+        assert_eq!(abi_from_tag_abi("x86_64"), "x86_64");
+        assert_eq!(abi_from_tag_abi(""), "");
+    }
+}
@@ -0,0 +1,108 @@
+//! Quick Boot snapshots and cold boots of AVDs.
+//!
+//! A cold boot for the next launch only is kept in memory, while "always
+//! cold boot" is stored as `fastboot.forceColdBoot` in the AVD's
+//! `config.ini`, where Android Studio keeps the same setting. Either one
+//! adds `-no-snapshot-load` to the launch command.
+
+use super::{install::directory_size, parsers, AndroidManager};
+use crate::{
+    constants::{
+        files::{
+            self,
+            android::{
+                CONFIG_NO, CONFIG_YES, FORCE_COLD_BOOT_KEY, QUICK_BOOT_SNAPSHOT, SNAPSHOTS_DIR,
+            },
+        },
+        messages::errors::QUICK_BOOT_SNAPSHOT_IN_USE,
+    },
+    models::QuickBootStatus,
+};
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use tokio::fs;
+
+impl AndroidManager {
+    /// Whether `avd_name` has a Quick Boot snapshot and how it boots next.
+    pub async fn quick_boot_status(&self, avd_name: &str) -> Result<QuickBootStatus> {
+        let avd_dir = self.resolve_avd_dir(avd_name).await?;
+        let snapshot_dir = avd_dir.join(SNAPSHOTS_DIR).join(QUICK_BOOT_SNAPSHOT);
+        let snapshot_size = tokio::task::spawn_blocking(move || directory_size(&snapshot_dir))
+            .await
+            .unwrap_or_default();
+        let cold_boot_once = self.cold_boot_once.lock().unwrap().contains(avd_name);
+        Ok(QuickBootStatus {
+            snapshot_size,
+            cold_boot_once,
+            always_cold_boot: forces_cold_boot(&avd_dir).await,
+        })
+    }
+
+    /// Deletes the Quick Boot snapshot of a stopped AVD, so its next launch
+    /// boots cold and saves a fresh one.
+    pub async fn delete_quick_boot_snapshot(&self, avd_name: &str) -> Result<()> {
+        if self.get_running_avd_names().await?.contains_key(avd_name) {
+            bail!(QUICK_BOOT_SNAPSHOT_IN_USE.replace("{}", avd_name));
+        }
+        let snapshot_dir = self
+            .resolve_avd_dir(avd_name)
+            .await?
+            .join(SNAPSHOTS_DIR)
+            .join(QUICK_BOOT_SNAPSHOT);
+        match fs::remove_dir_all(&snapshot_dir).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to remove {}", snapshot_dir.display()))
+            }
+        }
+    }
+
+    /// Makes only the next launch of `avd_name` skip its Quick Boot snapshot.
+    pub fn set_cold_boot_once(&self, avd_name: &str, enabled: bool) {
+        let mut cold_boot_once = self.cold_boot_once.lock().unwrap();
+        if enabled {
+            cold_boot_once.insert(avd_name.to_string());
+        } else {
+            cold_boot_once.remove(avd_name);
+        }
+    }
+
+    /// Makes every launch of `avd_name` skip its Quick Boot snapshot.
+    pub async fn set_always_cold_boot(&self, avd_name: &str, enabled: bool) -> Result<()> {
+        let config_path = self
+            .resolve_avd_dir(avd_name)
+            .await?
+            .join(files::CONFIG_FILE);
+        let config = fs::read_to_string(&config_path)
+            .await
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        let value = if enabled { CONFIG_YES } else { CONFIG_NO };
+        let updated = parsers::set_config_values(&config, &[(FORCE_COLD_BOOT_KEY, value)]);
+        fs::write(&config_path, updated)
+            .await
+            .with_context(|| format!("Failed to write {}", config_path.display()))
+    }
+
+    /// Whether the next launch of `avd_name` skips its Quick Boot snapshot.
+    /// Only looks at the default data directory, so building the launch
+    /// command never has to wait for avdmanager.
+    pub(super) async fn boots_cold(&self, avd_name: &str) -> bool {
+        if self.cold_boot_once.lock().unwrap().contains(avd_name) {
+            return true;
+        }
+        match Self::avd_data_dir(avd_name).await {
+            Some(avd_dir) => forces_cold_boot(&avd_dir).await,
+            None => false,
+        }
+    }
+}
+
+async fn forces_cold_boot(avd_dir: &Path) -> bool {
+    let Ok(config) = fs::read_to_string(avd_dir.join(files::CONFIG_FILE)).await else {
+        return false;
+    };
+    parsers::parse_config_ini(&config)
+        .into_iter()
+        .any(|(key, value)| key == FORCE_COLD_BOOT_KEY && value == CONFIG_YES)
+}
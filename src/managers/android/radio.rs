@@ -0,0 +1,73 @@
+//! Wi-Fi, Bluetooth and airplane mode of running AVDs.
+
+use super::AndroidManager;
+use crate::constants::commands::adb;
+use crate::models::{Radio, RadioStatus};
+use anyhow::Result;
+
+impl AndroidManager {
+    /// Reads whether each radio of a running AVD is on.
+    pub async fn radio_status(&self, avd_name: &str) -> Result<RadioStatus> {
+        let serial = self.running_serial(avd_name).await?;
+        let mut status = RadioStatus::default();
+        for radio in Radio::ALL {
+            let value = self
+                .adb_shell(
+                    &serial,
+                    &[adb::SETTINGS, adb::GET, adb::GLOBAL, radio.setting()],
+                )
+                .await?;
+            status.set(radio, radio.parse_setting(&value));
+        }
+        Ok(status)
+    }
+
+    /// Switches a radio of a running AVD on or off.
+    pub async fn set_radio(&self, avd_name: &str, radio: Radio, enabled: bool) -> Result<()> {
+        let serial = self.running_serial(avd_name).await?;
+        let action = if enabled { adb::ENABLE } else { adb::DISABLE };
+        match radio {
+            Radio::Wifi => {
+                self.adb_shell(&serial, &[adb::SVC, adb::WIFI, action])
+                    .await?;
+            }
+            Radio::Bluetooth => {
+                self.adb_shell(&serial, &[adb::CMD, adb::BLUETOOTH_MANAGER, action])
+                    .await?;
+            }
+            Radio::AirplaneMode => {
+                let value = if enabled { "1" } else { "0" };
+                self.adb_shell(
+                    &serial,
+                    &[adb::SETTINGS, adb::PUT, adb::GLOBAL, radio.setting(), value],
+                )
+                .await?;
+                let output = self
+                    .adb_shell(
+                        &serial,
+                        &[
+                            adb::AM,
+                            adb::AM_BROADCAST,
+                            adb::INTENT_ACTION,
+                            adb::AIRPLANE_MODE_ACTION,
+                            adb::INTENT_EXTRA_BOOL,
+                            adb::AIRPLANE_MODE_STATE_EXTRA,
+                            &enabled.to_string(),
+                        ],
+                    )
+                    .await?;
+                if adb::BROADCAST_REFUSED
+                    .iter()
+                    .any(|refused| output.contains(refused))
+                {
+                    self.adb_shell(
+                        &serial,
+                        &[adb::CMD, adb::CONNECTIVITY, adb::AIRPLANE_MODE, action],
+                    )
+                    .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+//! Readiness checks on a booted emulator.
+
+use super::{parsers, AndroidManager};
+use crate::constants::commands::adb;
+use crate::models::ReadinessCheck;
+use anyhow::Result;
+
+/// Fallback for recognizing the home screen when the home activity cannot
+/// be resolved.
+const LAUNCHER_KEYWORD: &str = "launcher";
+
+impl AndroidManager {
+    /// Whether `check` passes on a running AVD.
+    ///
+    /// [`ReadinessCheck::AnimationsDisabled`] switches the animations off
+    /// before checking, so it passes on the first call once the device
+    /// accepts settings.
+    pub async fn check_readiness(&self, avd_name: &str, check: ReadinessCheck) -> Result<bool> {
+        match check {
+            ReadinessCheck::BootCompleted => self.is_boot_completed(avd_name).await,
+            ReadinessCheck::PackageManager => {
+                let serial = self.running_serial(avd_name).await?;
+                self.is_package_manager_ready(&serial).await
+            }
+            ReadinessCheck::Launcher => {
+                let serial = self.running_serial(avd_name).await?;
+                self.is_launcher_focused(&serial).await
+            }
+            ReadinessCheck::AnimationsDisabled => {
+                let serial = self.running_serial(avd_name).await?;
+                self.disable_animations(&serial).await
+            }
+        }
+    }
+
+    /// The first check of `checks` that does not pass yet, or `None` once
+    /// the device is ready. Checks run in order and stop at the first that
+    /// fails; errors count as not ready, since adb often fails while the
+    /// emulator is still starting.
+    pub async fn pending_readiness_check(
+        &self,
+        avd_name: &str,
+        checks: &[ReadinessCheck],
+    ) -> Option<ReadinessCheck> {
+        for &check in checks {
+            match self.check_readiness(avd_name, check).await {
+                Ok(true) => {}
+                Ok(false) => return Some(check),
+                Err(e) => {
+                    log::debug!("Readiness check {check} on '{avd_name}' failed: {e:#}");
+                    return Some(check);
+                }
+            }
+        }
+        None
+    }
+
+    async fn is_package_manager_ready(&self, serial: &str) -> Result<bool> {
+        let output = self
+            .adb_shell(serial, &[adb::PM, adb::PM_PATH, adb::ANDROID_PACKAGE])
+            .await?;
+        Ok(output
+            .lines()
+            .any(|line| line.trim().starts_with("package:")))
+    }
+
+    /// Whether the home activity has input focus.
+    async fn is_launcher_focused(&self, serial: &str) -> Result<bool> {
+        let resolved = self
+            .adb_shell(serial, adb::RESOLVE_HOME_ACTIVITY)
+            .await
+            .unwrap_or_default();
+        let home_package = parsers::parse_resolved_activity_package(&resolved)
+            .map(|package| format!("{package}/"));
+        let windows = self.adb_shell(serial, &[adb::DUMPSYS, adb::WINDOW]).await?;
+        Ok(
+            parsers::parse_focused_window(&windows).is_some_and(|window| match &home_package {
+                Some(package) => window.contains(package.as_str()),
+                None => window.to_lowercase().contains(LAUNCHER_KEYWORD),
+            }),
+        )
+    }
+
+    /// Sets every animation scale to 0 and reports whether all of them read
+    /// back as 0.
+    async fn disable_animations(&self, serial: &str) -> Result<bool> {
+        for setting in adb::ANIMATION_SCALE_SETTINGS {
+            self.adb_shell(
+                serial,
+                &[adb::SETTINGS, adb::PUT, adb::GLOBAL, setting, "0"],
+            )
+            .await?;
+        }
+        for setting in adb::ANIMATION_SCALE_SETTINGS {
+            let value = self
+                .adb_shell(serial, &[adb::SETTINGS, adb::GET, adb::GLOBAL, setting])
+                .await?;
+            if value.trim().parse::<f32>().ok() != Some(0.0) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
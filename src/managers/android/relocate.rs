@@ -0,0 +1,169 @@
+use super::{parsers, AndroidManager};
+use crate::constants::{
+    files,
+    messages::{
+        device_busy,
+        errors::{
+            AVD_HOME_NOT_FOUND, DEVICE_DATA_ALREADY_THERE, DEVICE_DATA_MOVE_WHILE_RUNNING,
+            DEVICE_DATA_TARGET_EXISTS, DEVICE_DATA_TARGET_NOT_ABSOLUTE, DEVICE_NOT_FOUND,
+        },
+        read_only,
+    },
+};
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+impl AndroidManager {
+    /// Moves the data directory of a stopped AVD into `target_dir`, e.g. on
+    /// a larger disk, and returns its new location.
+    ///
+    /// The `<name>.ini` pointer in the AVD home is updated to the new
+    /// location, and paths into the old directory recorded in `config.ini`
+    /// and `hardware-qemu.ini` are rewritten to match.
+    pub async fn move_device_data(&self, avd_name: &str, target_dir: &Path) -> Result<PathBuf> {
+        self.read_only
+            .ensure_writable(read_only::MOVE_DEVICE_DATA)?;
+        let _lock = self.lock_device(avd_name, device_busy::MOVING_DATA).await?;
+        if !target_dir.is_absolute() {
+            bail!(DEVICE_DATA_TARGET_NOT_ABSOLUTE.replace("{}", &target_dir.to_string_lossy()));
+        }
+        if self.get_running_avd_names().await?.contains_key(avd_name) {
+            bail!(DEVICE_DATA_MOVE_WHILE_RUNNING.replace("{}", avd_name));
+        }
+
+        let avd_home = Self::avd_home().context(AVD_HOME_NOT_FOUND)?;
+        let ini_path = avd_home.join(format!("{avd_name}{}", files::INI_EXTENSION));
+        let ini = fs::read_to_string(&ini_path)
+            .await
+            .with_context(|| DEVICE_NOT_FOUND.replace("{}", avd_name))?;
+        let old_dir = Self::avd_data_dir(avd_name)
+            .await
+            .filter(|dir| dir.is_dir())
+            .with_context(|| DEVICE_NOT_FOUND.replace("{}", avd_name))?;
+        let dir_name = old_dir
+            .file_name()
+            .with_context(|| DEVICE_NOT_FOUND.replace("{}", avd_name))?;
+
+        let new_dir = target_dir.join(dir_name);
+        if new_dir == old_dir {
+            bail!(DEVICE_DATA_ALREADY_THERE
+                .replacen("{}", avd_name, 1)
+                .replacen("{}", &target_dir.to_string_lossy(), 1));
+        }
+        if new_dir.exists() {
+            bail!(DEVICE_DATA_TARGET_EXISTS.replace("{}", &new_dir.to_string_lossy()));
+        }
+
+        fs::create_dir_all(target_dir)
+            .await
+            .with_context(|| format!("Failed to create {}", target_dir.display()))?;
+        log::info!("Moving data of '{avd_name}' from {old_dir:?} to {new_dir:?}");
+        let originals = read_data_ini_files(&old_dir).await;
+        let moved = move_directory(&old_dir, &new_dir).await?;
+
+        // The old data stays in place until the AVD points at the new copy
+        let old_path = old_dir.to_string_lossy();
+        let new_path = new_dir.to_string_lossy();
+        let repointed = async {
+            for (file_name, content) in &originals {
+                let path = new_dir.join(file_name);
+                fs::write(
+                    &path,
+                    parsers::rewrite_ini_paths(content, &old_path, &new_path),
+                )
+                .await
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+            let updated =
+                parsers::set_config_values(&ini, &[(files::android::AVD_INI_PATH_KEY, &new_path)]);
+            fs::write(&ini_path, updated)
+                .await
+                .with_context(|| format!("Failed to write {}", ini_path.display()))
+        }
+        .await;
+        if let Err(e) = repointed {
+            undo_move(moved, &old_dir, &new_dir, &originals).await;
+            return Err(e);
+        }
+        if moved == Move::Copied {
+            if let Err(e) = fs::remove_dir_all(&old_dir).await {
+                log::warn!("Failed to remove the old data of '{avd_name}' at {old_dir:?}: {e}");
+            }
+        }
+
+        self.invalidate_device_metadata_cache(Some(avd_name)).await;
+        self.device_list_refresh.invalidate();
+        Ok(new_dir)
+    }
+}
+
+/// How [`move_directory`] moved a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Move {
+    /// `from` was renamed and no longer exists
+    Renamed,
+    /// `from` was copied to another disk and still exists
+    Copied,
+}
+
+/// Renames `from` to `to`, falling back to copying when they are on
+/// different disks. A failed copy leaves `from` untouched and removes what
+/// was copied.
+pub(super) async fn move_directory(from: &Path, to: &Path) -> Result<Move> {
+    if fs::rename(from, to).await.is_ok() {
+        return Ok(Move::Renamed);
+    }
+
+    let (source, destination) = (from.to_path_buf(), to.to_path_buf());
+    let copied = tokio::task::spawn_blocking(move || copy_directory(&source, &destination)).await?;
+    if let Err(e) = copied {
+        let _ = fs::remove_dir_all(to).await;
+        return Err(e)
+            .with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()));
+    }
+    Ok(Move::Copied)
+}
+
+/// Puts the data back at `from` after the AVD could not be pointed at `to`.
+pub(super) async fn undo_move(moved: Move, from: &Path, to: &Path, originals: &[(&str, String)]) {
+    let undone = match moved {
+        Move::Copied => fs::remove_dir_all(to).await,
+        Move::Renamed => {
+            for (file_name, content) in originals {
+                let _ = fs::write(to.join(file_name), content).await;
+            }
+            fs::rename(to, from).await
+        }
+    };
+    if let Err(e) = undone {
+        log::warn!("Failed to undo the move of {from:?} to {to:?}: {e}");
+    }
+}
+
+/// `config.ini` and `hardware-qemu.ini` of an AVD data directory, as far as
+/// they exist.
+async fn read_data_ini_files(dir: &Path) -> Vec<(&'static str, String)> {
+    let mut contents = Vec::new();
+    for file_name in [files::CONFIG_FILE, files::HARDWARE_FILE] {
+        if let Ok(content) = fs::read_to_string(dir.join(file_name)).await {
+            contents.push((file_name, content));
+        }
+    }
+    contents
+}
+
+/// Copies the directory tree at `from` to the new directory `to`.
+fn copy_directory(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let destination = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_directory(&entry.path(), &destination)?;
+        } else {
+            std::fs::copy(entry.path(), &destination)?;
+        }
+    }
+    Ok(())
+}
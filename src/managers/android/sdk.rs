@@ -1,5 +1,10 @@
-use super::AndroidManager;
-use crate::constants::{env_vars, files, limits::SYSTEM_IMAGE_PARTS_REQUIRED};
+use super::{
+    parsers::{self, SystemImagePackage},
+    AndroidManager,
+};
+use crate::constants::{env_vars, files};
+use crate::models::DeviceError;
+use crate::utils::tool_paths;
 use anyhow::{bail, Result};
 use std::path::{Path, PathBuf};
 
@@ -17,8 +22,61 @@ impl AndroidManager {
         bail!("Android SDK not found. Please set ANDROID_HOME or ANDROID_SDK_ROOT")
     }
 
-    /// Finds a specific tool within the Android SDK directory structure.
+    /// Directory holding the `<name>.ini` pointers of every AVD, resolved the
+    /// way the emulator does: `ANDROID_AVD_HOME`, then `avd` under
+    /// `ANDROID_EMULATOR_HOME` or `ANDROID_USER_HOME`, then `~/.android/avd`.
+    pub fn avd_home() -> Option<PathBuf> {
+        let non_empty_var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+        if let Some(path) = non_empty_var(env_vars::ANDROID_AVD_HOME) {
+            return Some(PathBuf::from(path));
+        }
+        if let Some(path) = non_empty_var(env_vars::ANDROID_EMULATOR_HOME)
+            .or_else(|| non_empty_var(env_vars::ANDROID_USER_HOME))
+        {
+            return Some(PathBuf::from(path).join(files::android::AVD_SUBDIR));
+        }
+        non_empty_var(env_vars::HOME).map(|home| {
+            PathBuf::from(home)
+                .join(files::android::AVD_DIR)
+                .join(files::android::AVD_SUBDIR)
+        })
+    }
+
+    /// Data directory of an AVD: the `path` recorded in its `<name>.ini`,
+    /// which may point to another disk, or `<name>.avd` in the AVD home.
+    pub async fn avd_data_dir(avd_name: &str) -> Option<PathBuf> {
+        let avd_home = Self::avd_home()?;
+        let ini_path = avd_home.join(format!("{avd_name}{}", files::INI_EXTENSION));
+        let recorded = tokio::fs::read_to_string(&ini_path)
+            .await
+            .ok()
+            .and_then(|ini| {
+                parsers::parse_config_ini(&ini)
+                    .into_iter()
+                    .find(|(key, _)| key == files::android::AVD_INI_PATH_KEY)
+            })
+            .map(|(_, path)| PathBuf::from(path))
+            .filter(|path| path.is_dir());
+        Some(
+            recorded
+                .unwrap_or_else(|| avd_home.join(format!("{avd_name}{}", files::AVD_EXTENSION))),
+        )
+    }
+
+    /// Finds a specific tool within the Android SDK directory structure,
+    /// unless `.emu.toml` configures another location for it.
     pub(super) fn find_tool(android_home: &Path, tool: &str) -> Result<PathBuf> {
+        if let Some(path) = tool_paths::tool_path(tool) {
+            if path.exists() {
+                return Ok(path);
+            }
+            bail!(
+                "Tool '{tool}' configured at {} does not exist",
+                path.display()
+            )
+        }
+
         let paths = [
             android_home
                 .join(files::android::CMDLINE_TOOLS_LATEST_BIN)
@@ -33,7 +91,22 @@ impl AndroidManager {
             }
         }
 
-        bail!("Tool '{tool}' not found in Android SDK")
+        Err(DeviceError::tool_missing(tool).into())
+    }
+
+    /// Version of the command-line tools package that provides avdmanager,
+    /// read from the `source.properties` next to its `bin` directory.
+    pub fn cmdline_tools_version(&self) -> Option<String> {
+        let properties = self
+            .avdmanager_path
+            .parent()?
+            .parent()?
+            .join(files::android::SOURCE_PROPERTIES);
+        std::fs::read_to_string(properties)
+            .ok()?
+            .lines()
+            .find_map(|line| line.strip_prefix(files::android::PKG_REVISION_PREFIX))
+            .map(|revision| revision.trim().to_string())
     }
 
     pub async fn check_system_image_available(
@@ -48,56 +121,32 @@ impl AndroidManager {
     }
 
     pub async fn list_available_system_images(&self) -> Result<Vec<String>> {
-        let mut images = Vec::new();
-        let output = self.get_sdkmanager_verbose_output().await?;
-        let mut in_installed_section = false;
-
-        for line in output.lines() {
-            let trimmed = line.trim();
-
-            if trimmed.starts_with("Installed packages:") {
-                in_installed_section = true;
-                continue;
-            }
-
-            if in_installed_section
-                && (trimmed.starts_with("Available Packages:")
-                    || trimmed.starts_with("Available Updates:"))
-            {
-                in_installed_section = false;
-                continue;
-            }
-
-            if in_installed_section && trimmed.starts_with("system-images;") {
-                if let Some(space_pos) = trimmed.find(' ') {
-                    let package_path = &trimmed[..space_pos];
-                    images.push(package_path.to_string());
-                } else {
-                    images.push(trimmed.to_string());
-                }
-            }
-        }
+        Ok(self
+            .installed_system_images()
+            .await?
+            .into_iter()
+            .map(|image| image.package_id)
+            .collect())
+    }
 
-        Ok(images)
+    /// Installed system image packages as reported by sdkmanager.
+    pub(super) async fn installed_system_images(&self) -> Result<Vec<SystemImagePackage>> {
+        let output = self.get_sdkmanager_verbose_output().await?;
+        Ok(parsers::parse_system_images(&output)
+            .into_iter()
+            .filter(|image| image.installed)
+            .collect())
     }
 
     pub async fn get_first_available_system_image(
         &self,
         api_level: &str,
     ) -> Result<Option<(String, String)>> {
-        let installed_images = self.list_available_system_images().await?;
-
-        for image in installed_images {
-            let parts: Vec<&str> = image.split(';').collect();
-            if parts.len() >= SYSTEM_IMAGE_PARTS_REQUIRED {
-                if let Some(android_part) = parts.get(1) {
-                    if android_part == &format!("android-{api_level}") {
-                        return Ok(Some((parts[2].to_string(), parts[3].to_string())));
-                    }
-                }
-            }
-        }
-
-        Ok(None)
+        Ok(self
+            .installed_system_images()
+            .await?
+            .into_iter()
+            .find(|image| image.api == api_level)
+            .map(|image| (image.tag, image.abi)))
     }
 }
@@ -1,8 +1,12 @@
 use super::*;
-use crate::managers::android::parser::AvdListParser;
+use crate::constants::commands::adb;
+use crate::managers::android::parsers::{self, AvdListParser};
 use crate::managers::common::DeviceConfig;
 use crate::models::device_info::DynamicDeviceProvider;
-use crate::models::ApiLevel;
+use crate::models::{
+    AndroidIntent, ApiLevel, AppLaunch, ClockChange, DeveloperToggle, DeviceKey, IntentKind,
+    LaunchIssue, MonkeyRun, Platform, Radio, ReadinessCheck, TestSettingsBackup, TraceCapture,
+};
 use crate::utils::command_executor::mock::MockCommandExecutor;
 use crate::utils::ApiLevelCache;
 use std::collections::HashMap;
@@ -179,8 +183,8 @@ fn test_find_android_home_not_set() {
     env::remove_var("ANDROID_SDK_ROOT");
 
     let result = AndroidManager::find_android_home();
-    if result.is_err() {
-        assert!(result.unwrap_err().to_string().contains("Android"));
+    if let Err(error) = result {
+        assert!(error.to_string().contains("Android"));
     }
 }
 
@@ -296,57 +300,38 @@ fn test_get_android_version_name() {
 
 #[test]
 fn test_parse_api_level_from_package() {
-    let temp_dir = setup_test_android_sdk();
-    env::set_var("ANDROID_HOME", temp_dir.path());
-
-    let executor = std::sync::Arc::new(MockCommandExecutor::new());
-    let manager = AndroidManager::with_executor(executor).expect("Failed to create manager");
-
     assert_eq!(
-        manager.parse_api_level_from_package("system-images;android-34;google_apis;arm64-v8a"),
+        parsers::parse_api_level("system-images;android-34;google_apis;arm64-v8a"),
         Some(34)
     );
     assert_eq!(
-        manager.parse_api_level_from_package(
-            "system-images;android-33;google_apis_playstore;arm64-v8a"
-        ),
+        parsers::parse_api_level("system-images;android-33;google_apis_playstore;arm64-v8a"),
         Some(33)
     );
     assert_eq!(
-        manager.parse_api_level_from_package("system-images;android-31;default;x86_64"),
+        parsers::parse_api_level("system-images;android-31;default;x86_64"),
         Some(31)
     );
     assert_eq!(
-        manager.parse_api_level_from_package("system-images;android-28;google_apis;x86"),
+        parsers::parse_api_level("system-images;android-28;google_apis;x86"),
         Some(28)
     );
+    assert_eq!(parsers::parse_api_level("platforms;android-34"), Some(34));
+    assert_eq!(parsers::parse_api_level("platforms;android-21"), Some(21));
+    assert_eq!(parsers::parse_api_level("invalid-package"), None);
+    assert_eq!(parsers::parse_api_level(""), None);
     assert_eq!(
-        manager.parse_api_level_from_package("platforms;android-34"),
-        Some(34)
-    );
-    assert_eq!(
-        manager.parse_api_level_from_package("platforms;android-21"),
-        Some(21)
-    );
-    assert_eq!(
-        manager.parse_api_level_from_package("invalid-package"),
+        parsers::parse_api_level("system-images;invalid;google_apis;arm64-v8a"),
         None
     );
-    assert_eq!(manager.parse_api_level_from_package(""), None);
     assert_eq!(
-        manager.parse_api_level_from_package("system-images;invalid;google_apis;arm64-v8a"),
+        parsers::parse_api_level("system-images;android-abc;google_apis;arm64-v8a"),
         None
     );
     assert_eq!(
-        manager.parse_api_level_from_package("system-images;android-abc;google_apis;arm64-v8a"),
+        parsers::parse_api_level("system-images;android-;google_apis;arm64-v8a"),
         None
     );
-    assert_eq!(
-        manager.parse_api_level_from_package("system-images;android-;google_apis;arm64-v8a"),
-        None
-    );
-
-    env::remove_var("ANDROID_HOME");
 }
 
 #[test]
@@ -548,6 +533,61 @@ async fn test_list_available_targets_uses_session_cache() {
     assert_eq!(sdkmanager_calls, 1);
 }
 
+#[tokio::test]
+async fn test_offline_listing_reads_only_the_local_sdk() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path());
+
+    let sdkmanager_output = "Installed packages:\n  Path | Version | Description | Location\n  system-images;android-34;google_apis_playstore;arm64-v8a | 1 | Android SDK Platform 34 | system-images/android-34/google_apis_playstore/arm64-v8a\n\nAvailable Packages:\n";
+    let mock_executor = MockCommandExecutor::new().with_success(
+        "sdkmanager",
+        &["--list", "--verbose", "--include_obsolete", "--offline"],
+        sdkmanager_output,
+    );
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager.set_offline(true).await;
+    let api_levels = manager.list_api_levels().await.unwrap();
+    assert!(api_levels
+        .iter()
+        .any(|level| level.api == 34 && level.is_installed));
+    assert!(call_history_executor
+        .call_history()
+        .iter()
+        .any(|(_, args)| args.last().map(String::as_str) == Some("--offline")));
+
+    let error = manager
+        .install_system_image("system-images;android-35;google_apis;x86_64", |_| {})
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        crate::constants::messages::errors::OFFLINE_INSTALL
+    );
+}
+
+#[tokio::test]
+async fn test_failed_install_reports_sdkmanager_stderr() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path());
+    let sdkmanager = temp_dir.path().join("cmdline-tools/latest/bin/sdkmanager");
+    std::fs::write(
+        &sdkmanager,
+        "#!/bin/sh\necho 'Error: Failed to find package' >&2\nexit 1\n",
+    )
+    .unwrap();
+
+    let manager = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+    let error = manager
+        .install_system_image("system-images;android-99;google_apis;x86_64", |_| {})
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Error: Failed to find package"));
+}
+
 #[tokio::test]
 async fn test_list_available_devices_uses_session_cache() {
     let _env_lock = acquire_test_env_lock().await;
@@ -593,6 +633,65 @@ Available Android Virtual Devices:
     assert_eq!(avdmanager_calls, 1);
 }
 
+#[tokio::test]
+async fn test_preview_device_creation_resolves_image_profile_and_skin() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let skin_dir = temp_dir.path().join("skins/pixel_9");
+    std::fs::create_dir_all(&skin_dir).unwrap();
+    std::fs::write(skin_dir.join("hardware.ini"), "hw.lcd.density = 420\n").unwrap();
+    std::fs::write(
+        skin_dir.join("layout"),
+        "parts {\n    device {\n        display {\n            width   1080\n            height  2424\n        }\n    }\n}\n",
+    )
+    .unwrap();
+
+    let device_list_output = r#"
+    id: 0 or "pixel_9"
+    Name: Pixel 9
+    OEM : Google
+---------
+"#;
+    let sdkmanager_output = "Installed packages:\n  system-images;android-34;google_apis_playstore;arm64-v8a | 1 | Google Play ARM 64 v8a System Image | system-images/android-34/google_apis_playstore/arm64-v8a\n";
+    let avdmanager_path = temp_dir.path().join("cmdline-tools/latest/bin/avdmanager");
+    let sdkmanager_path = temp_dir.path().join("cmdline-tools/latest/bin/sdkmanager");
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            &avdmanager_path.to_string_lossy(),
+            &["list", "device"],
+            device_list_output,
+        )
+        .with_success(
+            &sdkmanager_path.to_string_lossy(),
+            &["--list", "--verbose", "--include_obsolete"],
+            sdkmanager_output,
+        );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    let config = DeviceConfig::new(
+        "Pixel 9 API 34".to_string(),
+        "Pixel 9 (Google)".to_string(),
+        "34".to_string(),
+    );
+    let preview = manager.preview_device_creation(&config).await.unwrap();
+
+    assert_eq!(
+        preview.package_path,
+        "system-images;android-34;google_apis_playstore;arm64-v8a"
+    );
+    assert!(preview.image_installed);
+    assert_eq!(preview.device_id.as_deref(), Some("pixel_9"));
+    assert_eq!(preview.skin.as_deref(), Some("pixel_9"));
+    assert_eq!(preview.resolution.as_deref(), Some("1080x2424"));
+    assert_eq!(preview.dpi, Some(420));
+    assert!(preview
+        .command(&AndroidManager::sanitize_avd_name(&config.name))
+        .to_string()
+        .contains("create avd -n Pixel_9_API_34 -k"));
+}
+
 #[tokio::test]
 async fn test_list_devices_parallel_avoids_sdkmanager_when_targets_cache_is_empty() {
     let _env_lock = acquire_test_env_lock().await;
@@ -735,6 +834,46 @@ async fn test_list_api_levels_reuses_sdkmanager_output_warmed_by_targets() {
     assert_eq!(sdkmanager_calls, 1);
 }
 
+#[tokio::test]
+async fn test_list_api_levels_progressive_reports_partial_lists() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path());
+
+    let mut sdkmanager_output = String::from("Available Packages:\n");
+    for api in 21..=35 {
+        for abi in ["x86_64", "arm64-v8a"] {
+            sdkmanager_output.push_str(&format!(
+                "  system-images;android-{api};google_apis;{abi} | 1 | Google APIs\n"
+            ));
+        }
+    }
+    let sdkmanager_path = temp_dir.path().join("cmdline-tools/latest/bin/sdkmanager");
+    let mock_executor = MockCommandExecutor::new().with_success(
+        &sdkmanager_path.to_string_lossy(),
+        &["--list", "--verbose", "--include_obsolete"],
+        &sdkmanager_output,
+    );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    let updates = std::sync::Mutex::new(Vec::new());
+    let api_levels = manager
+        .list_api_levels_progressive(|levels| {
+            let variants = levels
+                .iter()
+                .map(|level| level.variants.len())
+                .sum::<usize>();
+            updates.lock().unwrap().push(variants);
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(*updates.lock().unwrap(), vec![10, 20, 30]);
+    assert_eq!(api_levels.len(), 15);
+    assert_eq!(api_levels[0].api, 35);
+    assert_eq!(manager.list_api_levels().await.unwrap().len(), 15);
+}
+
 #[tokio::test]
 async fn test_list_api_levels_fresh_bypasses_stale_session_cache() {
     let _env_lock = acquire_test_env_lock().await;
@@ -859,6 +998,196 @@ Available Android Virtual Devices:
     assert!(removed.is_none());
 }
 
+#[tokio::test]
+async fn test_running_avd_names_only_resolve_new_emulators() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        );
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    let first = manager.get_running_avd_names().await.unwrap();
+    let second = manager.get_running_avd_names().await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(
+        second.get("Pixel_7_API_34").map(String::as_str),
+        Some("emulator-5554")
+    );
+
+    let getprop_calls = call_history_executor
+        .call_history()
+        .into_iter()
+        .filter(|(_, args)| args.iter().any(|arg| arg == "getprop"))
+        .count();
+    assert_eq!(getprop_calls, 1);
+}
+
+#[tokio::test]
+async fn test_running_avd_names_come_from_discovery_files_before_adb() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+    let _runtime_dir = EnvVarGuard::set("XDG_RUNTIME_DIR", temp_dir.path().as_os_str());
+    let discovery_dir = if cfg!(target_os = "macos") {
+        temp_dir
+            .path()
+            .join(crate::constants::files::android::MACOS_CACHES_DIR)
+    } else {
+        temp_dir.path().to_path_buf()
+    }
+    .join(crate::constants::files::android::EMULATOR_DISCOVERY_DIR);
+    std::fs::create_dir_all(&discovery_dir).unwrap();
+    std::fs::write(
+        discovery_dir.join("pid_4242.ini"),
+        "port.serial=5554\nport.adb=5555\navd.name=Pixel_7_API_34\n",
+    )
+    .unwrap();
+
+    // emulator-5556 announced no discovery file and is asked through adb
+    let mock_executor = MockCommandExecutor::new().with_success(
+        "adb",
+        &[
+            "-s",
+            "emulator-5556",
+            "shell",
+            "getprop",
+            "ro.boot.qemu.avd_name",
+        ],
+        "Pixel_8_API_35\n",
+    );
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager
+        .set_attached_emulators(vec![
+            "emulator-5554".to_string(),
+            "emulator-5556".to_string(),
+        ])
+        .await;
+    let running = manager.get_running_avd_names().await.unwrap();
+    assert_eq!(
+        running.get("Pixel_7_API_34").map(String::as_str),
+        Some("emulator-5554")
+    );
+    assert_eq!(
+        running.get("Pixel_8_API_35").map(String::as_str),
+        Some("emulator-5556")
+    );
+
+    let calls = call_history_executor.call_history();
+    assert!(!calls
+        .iter()
+        .any(|(_, args)| args.iter().any(|arg| arg == "emulator-5554")));
+    assert_eq!(calls.len(), 1);
+}
+
+#[tokio::test]
+async fn test_tracked_emulators_replace_adb_devices_and_drop_stale_names() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    // No `adb devices` response: the tracked list has to be used
+    let mock_executor = MockCommandExecutor::new().with_success(
+        "adb",
+        &[
+            "-s",
+            "emulator-5554",
+            "shell",
+            "getprop",
+            "ro.boot.qemu.avd_name",
+        ],
+        "Pixel_7_API_34\n",
+    );
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager
+        .set_attached_emulators(vec!["emulator-5554".to_string()])
+        .await;
+    let running = manager.get_running_avd_names().await.unwrap();
+    assert_eq!(
+        running.get("Pixel_7_API_34").map(String::as_str),
+        Some("emulator-5554")
+    );
+    assert_eq!(manager.adb_server_health().await, Some(true));
+
+    // The serial disconnects and comes back, e.g. another AVD on the same port
+    manager.set_attached_emulators(Vec::new()).await;
+    assert!(manager.get_running_avd_names().await.unwrap().is_empty());
+    manager
+        .set_attached_emulators(vec!["emulator-5554".to_string()])
+        .await;
+    manager.get_running_avd_names().await.unwrap();
+
+    let calls = call_history_executor.call_history();
+    assert!(!calls
+        .iter()
+        .any(|(_, args)| args.iter().any(|arg| arg == "devices")));
+    let getprop_calls = calls
+        .iter()
+        .filter(|(_, args)| args.iter().any(|arg| arg == "getprop"))
+        .count();
+    assert_eq!(getprop_calls, 2);
+}
+
+#[tokio::test]
+async fn test_changed_serial_set_resolves_every_emulator_again() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let getprop =
+        |serial: &'static str| ["-s", serial, "shell", "getprop", "ro.boot.qemu.avd_name"];
+    let mock_executor = MockCommandExecutor::new()
+        .with_success("adb", &getprop("emulator-5554"), "Pixel_7_API_34\n")
+        .with_success("adb", &getprop("emulator-5556"), "Pixel_8_API_35\n");
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager
+        .set_attached_emulators(vec!["emulator-5554".to_string()])
+        .await;
+    manager.get_running_avd_names().await.unwrap();
+    manager
+        .set_attached_emulators(vec![
+            "emulator-5554".to_string(),
+            "emulator-5556".to_string(),
+        ])
+        .await;
+    let running = manager.get_running_avd_names().await.unwrap();
+    assert_eq!(running.len(), 2);
+
+    let calls = call_history_executor.call_history();
+    let count = |serial| {
+        calls
+            .iter()
+            .filter(|(_, args)| args.as_slice() == getprop(serial))
+            .count()
+    };
+    assert_eq!(count("emulator-5554"), 2);
+    assert_eq!(count("emulator-5556"), 1);
+}
+
 #[test]
 fn test_avd_list_parser_new() {
     let output = "Sample AVD list output";
@@ -883,12 +1212,12 @@ fn test_avd_list_parser_parse_single_device() {
     let device = parser.parse_next_device();
     assert!(device.is_some());
 
-    let (name, path, target, abi, device_id) = device.unwrap();
-    assert_eq!(name, "Pixel_7_API_34");
-    assert_eq!(path, "/Users/user/.android/avd/Pixel_7_API_34.avd");
-    assert_eq!(target, "Google APIs (Google Inc.)");
-    assert_eq!(abi, "google_apis/arm64-v8a");
-    assert_eq!(device_id, "pixel_7 (Google)");
+    let avd = device.unwrap();
+    assert_eq!(avd.name, "Pixel_7_API_34");
+    assert_eq!(avd.path, "/Users/user/.android/avd/Pixel_7_API_34.avd");
+    assert_eq!(avd.target, "Google APIs (Google Inc.)");
+    assert_eq!(avd.abi, "google_apis/arm64-v8a");
+    assert_eq!(avd.device, "pixel_7 (Google)");
     assert!(parser.parse_next_device().is_none());
 }
 
@@ -912,13 +1241,11 @@ fn test_avd_list_parser_parse_multiple_devices() {
     let mut parser = AvdListParser::new(avd_output);
     let device1 = parser.parse_next_device();
     assert!(device1.is_some());
-    let (name1, _, _, _, _) = device1.unwrap();
-    assert_eq!(name1, "Pixel_7_API_34");
+    assert_eq!(device1.unwrap().name, "Pixel_7_API_34");
 
     let device2 = parser.parse_next_device();
     assert!(device2.is_some());
-    let (name2, _, _, _, _) = device2.unwrap();
-    assert_eq!(name2, "Galaxy_S22_API_33");
+    assert_eq!(device2.unwrap().name, "Galaxy_S22_API_33");
 
     assert!(parser.parse_next_device().is_none());
 }
@@ -1158,8 +1485,11 @@ async fn test_fine_tune_avd_config_avd_not_found() {
 
 #[tokio::test]
 async fn test_get_dynamic_android_version_name() {
+    let _env_lock = acquire_test_env_lock().await;
     let temp_dir = setup_test_android_sdk();
-    env::set_var("ANDROID_HOME", temp_dir.path());
+    let config_dir = tempfile::tempdir().unwrap();
+    let cache_path = config_dir.path().join("android_version_names.json");
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path());
     let sdkmanager_path = temp_dir.path().join("cmdline-tools/latest/bin/sdkmanager");
 
     let platforms_output = r#"
@@ -1181,14 +1511,11 @@ Installed packages:
             &["--list"],
             platforms_output,
         );
-
-    let manager = match AndroidManager::with_executor(Arc::new(mock_executor)) {
-        Ok(manager) => manager,
-        Err(_) => {
-            env::remove_var("ANDROID_HOME");
-            return;
-        }
-    };
+    let call_history_executor = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    *manager.android_version_names.write().unwrap() =
+        AndroidVersionNameCache::load_from(Some(cache_path.clone()));
+    assert_eq!(manager.get_android_version_name(34), "API 34");
 
     let version_name = manager.get_dynamic_android_version_name(34).await;
     assert_eq!(version_name, Some("14".to_string()));
@@ -1196,7 +1523,72 @@ Installed packages:
     let version_name = manager.get_dynamic_android_version_name(999).await;
     assert!(version_name.is_none());
 
-    env::remove_var("ANDROID_HOME");
+    // One listing named every platform, so 33 needs no further sdkmanager run
+    let list_calls = || {
+        call_history_executor
+            .call_history()
+            .into_iter()
+            .filter(|(_, args)| args == &["--list".to_string()])
+            .count()
+    };
+    let calls_before = list_calls();
+    let version_name = manager.get_dynamic_android_version_name(33).await;
+    assert_eq!(version_name, Some("13".to_string()));
+    assert_eq!(list_calls(), calls_before);
+
+    // A later session names the levels from disk without sdkmanager
+    let next_session = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+    *next_session.android_version_names.write().unwrap() =
+        AndroidVersionNameCache::load_from(Some(cache_path));
+    assert_eq!(next_session.get_android_version_name(34), "14");
+    assert_eq!(next_session.android_version_label(33), "Android 13");
+    assert_eq!(next_session.android_version_label(999), "API 999");
+}
+
+#[tokio::test]
+async fn test_version_names_stay_in_memory_and_skip_empty_listings() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let config_dir = tempfile::tempdir().unwrap();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path());
+    let _home = EnvVarGuard::set("HOME", config_dir.path());
+    let _config_home = EnvVarGuard::set("XDG_CONFIG_HOME", config_dir.path());
+    let sdkmanager_path = temp_dir.path().join("cmdline-tools/latest/bin/sdkmanager");
+    let mock_executor = MockCommandExecutor::new()
+        .with_error(
+            &sdkmanager_path.to_string_lossy(),
+            &["--list", "--verbose", "--include_obsolete"],
+            "verbose list failed",
+        )
+        .with_success(
+            &sdkmanager_path.to_string_lossy(),
+            &["--list"],
+            "Installed packages:\n  platforms;android-34 | 3 | Android SDK Platform 34 | platforms/android-34 | Android API 34, revision 2 | Android 14\n",
+        );
+
+    // Managers built around another executor never touch the user's file
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    assert_eq!(
+        manager.get_dynamic_android_version_name(34).await,
+        Some("14".to_string())
+    );
+    let mut entries = std::fs::read_dir(config_dir.path()).unwrap();
+    assert!(entries.next().is_none());
+
+    // A listing that names no platform does not overwrite the saved names
+    let cache_path = config_dir.path().join("android_version_names.json");
+    let mock_executor = MockCommandExecutor::new()
+        .with_error(
+            &sdkmanager_path.to_string_lossy(),
+            &["--list", "--verbose", "--include_obsolete"],
+            "verbose list failed",
+        )
+        .with_success(&sdkmanager_path.to_string_lossy(), &["--list"], "");
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    *manager.android_version_names.write().unwrap() =
+        AndroidVersionNameCache::load_from(Some(cache_path.clone()));
+    assert!(manager.get_dynamic_android_version_name(34).await.is_none());
+    assert!(!cache_path.exists());
 }
 
 #[tokio::test]
@@ -1559,3 +1951,2146 @@ async fn test_get_available_api_levels() {
         None => env::remove_var("ANDROID_HOME"),
     }
 }
+
+#[tokio::test]
+async fn test_cmdline_tools_version_reads_source_properties() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let manager = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+
+    assert_eq!(manager.cmdline_tools_version(), None);
+
+    std::fs::write(
+        temp_dir
+            .path()
+            .join("cmdline-tools/latest/source.properties"),
+        "Pkg.Desc=Android SDK Command-line Tools\nPkg.Revision=16.0\n",
+    )
+    .unwrap();
+    assert_eq!(manager.cmdline_tools_version(), Some("16.0".to_string()));
+}
+
+#[tokio::test]
+async fn test_discard_partial_avd_removes_only_the_named_avd() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let avd_root = temp_dir.path().join(".android/avd");
+    for name in ["Pixel_9_Test", "Pixel_7_API_34"] {
+        std::fs::create_dir_all(avd_root.join(format!("{name}.avd"))).unwrap();
+        std::fs::write(avd_root.join(format!("{name}.avd/config.ini")), "").unwrap();
+        std::fs::write(avd_root.join(format!("{name}.ini")), "").unwrap();
+    }
+
+    let manager = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+    manager.discard_partial_avd("Pixel 9 Test").await.unwrap();
+
+    assert!(!avd_root.join("Pixel_9_Test.avd").exists());
+    assert!(!avd_root.join("Pixel_9_Test.ini").exists());
+    assert!(avd_root.join("Pixel_7_API_34.avd/config.ini").exists());
+    assert!(avd_root.join("Pixel_7_API_34.ini").exists());
+
+    // Nothing left to remove is not an error
+    manager.discard_partial_avd("Pixel 9 Test").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_avds_using_system_images_and_retarget() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let avd_root = temp_dir.path().join(".android/avd");
+    for (name, sysdir) in [
+        (
+            "Pixel_7_API_34",
+            "system-images/android-34/google_apis/arm64-v8a/",
+        ),
+        (
+            "Pixel_8_API_35",
+            "system-images/android-35/google_apis/arm64-v8a/",
+        ),
+    ] {
+        std::fs::create_dir_all(avd_root.join(format!("{name}.avd"))).unwrap();
+        std::fs::write(
+            avd_root.join(format!("{name}.avd/config.ini")),
+            format!("AvdId={name}\nimage.sysdir.1={sysdir}\ntag.id=google_apis\n"),
+        )
+        .unwrap();
+        std::fs::write(avd_root.join(format!("{name}.ini")), "target=android-34\n").unwrap();
+    }
+
+    let manager = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+    let api_34 = vec!["system-images;android-34;google_apis;arm64-v8a".to_string()];
+    assert_eq!(
+        manager.avds_using_system_images(&api_34).await.unwrap(),
+        vec!["Pixel_7_API_34".to_string()]
+    );
+
+    manager
+        .retarget_avd(
+            "Pixel_7_API_34",
+            "system-images;android-35;google_apis_playstore;arm64-v8a",
+        )
+        .await
+        .unwrap();
+
+    let config = std::fs::read_to_string(avd_root.join("Pixel_7_API_34.avd/config.ini")).unwrap();
+    assert!(
+        config.contains("image.sysdir.1=system-images/android-35/google_apis_playstore/arm64-v8a/")
+    );
+    assert!(config.contains("tag.id=google_apis_playstore"));
+    assert!(config.contains("AvdId=Pixel_7_API_34"));
+    let ini = std::fs::read_to_string(avd_root.join("Pixel_7_API_34.ini")).unwrap();
+    assert_eq!(ini, "target=android-35\n");
+    assert!(manager
+        .avds_using_system_images(&api_34)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_move_device_data_honors_avd_home_and_rewrites_paths() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+    let avd_home = temp_dir.path().join("avd-home");
+    let _avd_home = EnvVarGuard::set("ANDROID_AVD_HOME", avd_home.as_os_str());
+    assert_eq!(AndroidManager::avd_home(), Some(avd_home.clone()));
+
+    let old_dir = avd_home.join("Pixel_7_API_34.avd");
+    std::fs::create_dir_all(old_dir.join("snapshots")).unwrap();
+    std::fs::write(old_dir.join("snapshots/default_boot"), "snapshot").unwrap();
+    std::fs::write(
+        old_dir.join("config.ini"),
+        format!(
+            "AvdId=Pixel_7_API_34\nimage.sysdir.1=system-images/android-34/google_apis/x86_64/\nskin.path={}\n",
+            old_dir.display()
+        ),
+    )
+    .unwrap();
+    std::fs::write(
+        old_dir.join("hardware-qemu.ini"),
+        format!(
+            "disk.dataPartition.path = {}/userdata-qemu.img\n",
+            old_dir.display()
+        ),
+    )
+    .unwrap();
+    std::fs::write(
+        avd_home.join("Pixel_7_API_34.ini"),
+        format!(
+            "avd.ini.encoding=UTF-8\npath={}\ntarget=android-34\n",
+            old_dir.display()
+        ),
+    )
+    .unwrap();
+
+    let manager = AndroidManager::with_executor(Arc::new(MockCommandExecutor::new())).unwrap();
+    let error = manager
+        .move_device_data("Pixel_7_API_34", Path::new("relative/dir"))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("not an absolute path"));
+
+    let target_dir = temp_dir.path().join("big-disk");
+    let new_dir = manager
+        .move_device_data("Pixel_7_API_34", &target_dir)
+        .await
+        .unwrap();
+    assert_eq!(new_dir, target_dir.join("Pixel_7_API_34.avd"));
+    assert!(!old_dir.exists());
+    assert!(new_dir.join("snapshots/default_boot").exists());
+
+    let ini = std::fs::read_to_string(avd_home.join("Pixel_7_API_34.ini")).unwrap();
+    assert!(ini.contains(&format!("path={}\n", new_dir.display())));
+    assert!(ini.contains("target=android-34"));
+    let hardware = std::fs::read_to_string(new_dir.join("hardware-qemu.ini")).unwrap();
+    assert_eq!(
+        hardware,
+        format!(
+            "disk.dataPartition.path = {}/userdata-qemu.img\n",
+            new_dir.display()
+        )
+    );
+
+    // The relocated config is still found without avdmanager
+    let config = manager.read_device_config("Pixel_7_API_34").await.unwrap();
+    assert!(config.contains(&("skin.path".to_string(), new_dir.display().to_string())));
+
+    let error = manager
+        .move_device_data("Pixel_7_API_34", &target_dir)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("already in"));
+}
+
+#[tokio::test]
+async fn test_undone_move_leaves_the_old_data_as_it_was() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let old_dir = temp_dir.path().join("Pixel_7_API_34.avd");
+    let new_dir = temp_dir.path().join("moved.avd");
+    std::fs::create_dir_all(&old_dir).unwrap();
+    std::fs::write(old_dir.join("config.ini"), "skin.path=old\n").unwrap();
+    let originals = [("config.ini", "skin.path=old\n".to_string())];
+
+    // A renamed directory is renamed back with its ini files restored
+    let moved = relocate::move_directory(&old_dir, &new_dir).await.unwrap();
+    assert_eq!(moved, relocate::Move::Renamed);
+    std::fs::write(new_dir.join("config.ini"), "skin.path=new\n").unwrap();
+    relocate::undo_move(moved, &old_dir, &new_dir, &originals).await;
+    assert!(!new_dir.exists());
+    assert_eq!(
+        std::fs::read_to_string(old_dir.join("config.ini")).unwrap(),
+        "skin.path=old\n"
+    );
+
+    // A copy is removed and the original is left alone
+    std::fs::create_dir_all(&new_dir).unwrap();
+    std::fs::write(new_dir.join("config.ini"), "skin.path=new\n").unwrap();
+    relocate::undo_move(relocate::Move::Copied, &old_dir, &new_dir, &originals).await;
+    assert!(!new_dir.exists());
+    assert!(old_dir.join("config.ini").exists());
+}
+
+#[test]
+fn test_grpc_endpoint_parses_discovery_file() {
+    let endpoint = grpc::EmulatorGrpcEndpoint::parse(
+        "port.serial=5554\nport.adb=5555\navd.name=Pixel_7_API_34\ngrpc.port=8554\ngrpc.token=c2VjcmV0\n",
+    )
+    .unwrap();
+    assert_eq!(endpoint.avd_name, "Pixel_7_API_34");
+    assert_eq!(endpoint.port, 8554);
+    assert_eq!(endpoint.token.as_deref(), Some("c2VjcmV0"));
+
+    // Emulators started without gRPC announce no port
+    assert!(grpc::EmulatorGrpcEndpoint::parse("port.serial=5554\navd.name=Pixel\n").is_none());
+}
+
+#[test]
+fn test_escape_input_text_for_adb_shell() {
+    assert_eq!(control::escape_input_text("hello world"), "hello%sworld");
+    assert_eq!(control::escape_input_text("a&b;c"), "'a&b;c'");
+    assert_eq!(control::escape_input_text("{a,b}"), "'{a,b}'");
+    assert_eq!(control::escape_input_text("[x]^y"), "'[x]^y'");
+    assert_eq!(control::escape_input_text("it's"), r"'it'\''s'");
+    assert_eq!(control::escape_input_text("50%"), "50%");
+}
+
+#[tokio::test]
+async fn test_is_boot_completed_falls_back_to_adb() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    // No discovery files, so even a gRPC-enabled build uses adb
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+    let _runtime_dir = EnvVarGuard::set("XDG_RUNTIME_DIR", temp_dir.path().as_os_str());
+
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "sys.boot_completed",
+            ],
+            "1\n",
+        );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    assert!(manager.is_boot_completed("Pixel_7_API_34").await.unwrap());
+    assert!(manager.is_boot_completed("Pixel_8_API_35").await.is_err());
+}
+
+#[tokio::test]
+async fn test_pending_readiness_check_stops_at_first_failing_check() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+    let _runtime_dir = EnvVarGuard::set("XDG_RUNTIME_DIR", temp_dir.path().as_os_str());
+
+    let shell = |args: &[&'static str]| {
+        let mut command = vec!["-s", "emulator-5554", "shell"];
+        command.extend_from_slice(args);
+        command
+    };
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &shell(&["getprop", "ro.boot.qemu.avd_name"]),
+            "Pixel_7_API_34\n",
+        )
+        .with_success("adb", &shell(&["getprop", "sys.boot_completed"]), "1\n")
+        .with_success(
+            "adb",
+            &shell(&["pm", "path", "android"]),
+            "package:/system/framework/framework-res.apk\n",
+        )
+        .with_success(
+            "adb",
+            &shell(&[
+                "cmd",
+                "package",
+                "resolve-activity",
+                "--brief",
+                "-a",
+                "android.intent.action.MAIN",
+                "-c",
+                "android.intent.category.HOME",
+            ]),
+            "priority=0 preferredOrder=0 match=0x108000 specificIndex=-1 isDefault=true\n\
+             com.google.android.apps.nexuslauncher/.NexusLauncherActivity\n",
+        )
+        .with_success(
+            "adb",
+            &shell(&["dumpsys", "window"]),
+            "  mCurrentFocus=Window{1a2b u0 com.android.systemui/.ImageWallpaper}\n",
+        );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    assert_eq!(
+        manager
+            .pending_readiness_check("Pixel_7_API_34", &[ReadinessCheck::BootCompleted])
+            .await,
+        None
+    );
+    assert_eq!(
+        manager
+            .pending_readiness_check(
+                "Pixel_7_API_34",
+                &[
+                    ReadinessCheck::BootCompleted,
+                    ReadinessCheck::PackageManager,
+                    ReadinessCheck::Launcher,
+                    ReadinessCheck::AnimationsDisabled,
+                ],
+            )
+            .await,
+        Some(ReadinessCheck::Launcher)
+    );
+    // adb errors, here from an unknown AVD, count as not ready
+    assert_eq!(
+        manager
+            .pending_readiness_check("Pixel_8_API_35", &[ReadinessCheck::BootCompleted])
+            .await,
+        Some(ReadinessCheck::BootCompleted)
+    );
+}
+
+#[tokio::test]
+async fn test_test_settings_turn_off_animations_and_restore_them() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let settings = |args: &[&'static str]| {
+        let mut command = vec!["-s", "emulator-5554", "shell", "settings"];
+        command.extend_from_slice(args);
+        command
+    };
+    let mut mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        )
+        .with_success(
+            "adb",
+            &settings(&["get", "global", "window_animation_scale"]),
+            "1.0\n",
+        )
+        .with_success(
+            "adb",
+            &settings(&["get", "global", "transition_animation_scale"]),
+            "null\n",
+        )
+        .with_success(
+            "adb",
+            &settings(&["get", "global", "animator_duration_scale"]),
+            "0.5\n",
+        )
+        .with_success(
+            "adb",
+            &settings(&["put", "global", "window_animation_scale", "1.0"]),
+            "",
+        )
+        .with_success(
+            "adb",
+            &settings(&["delete", "global", "transition_animation_scale"]),
+            "",
+        )
+        .with_success(
+            "adb",
+            &settings(&["put", "global", "animator_duration_scale", "0.5"]),
+            "",
+        );
+    for setting in commands::adb::ANIMATION_SCALE_SETTINGS {
+        mock_executor =
+            mock_executor.with_success("adb", &settings(&["put", "global", setting, "0"]), "");
+    }
+    let mock_executor = Arc::new(mock_executor);
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    let backup = manager.apply_test_settings("Pixel_7_API_34").await.unwrap();
+    assert_eq!(
+        backup,
+        TestSettingsBackup::Android {
+            animation_scales: vec![Some("1.0".to_string()), None, Some("0.5".to_string())],
+        }
+    );
+    let zeroed = mock_executor
+        .call_history()
+        .iter()
+        .filter(|(_, args)| args.get(3..5) == Some(&["settings".to_string(), "put".to_string()]))
+        .filter(|(_, args)| args.last().map(String::as_str) == Some("0"))
+        .count();
+    assert_eq!(zeroed, 3);
+
+    mock_executor.clear_history();
+    manager
+        .restore_test_settings("Pixel_7_API_34", &backup)
+        .await
+        .unwrap();
+    let restored: Vec<String> = mock_executor
+        .call_history()
+        .into_iter()
+        .filter(|(_, args)| args.get(3).map(String::as_str) == Some("settings"))
+        .map(|(_, args)| args[4..].join(" "))
+        .collect();
+    assert_eq!(
+        restored,
+        vec![
+            "put global window_animation_scale 1.0",
+            "delete global transition_animation_scale",
+            "put global animator_duration_scale 0.5",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_collect_bugreport_targets_a_zip_in_the_output_dir() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    // No response for `adb bugreport`, so the mock fails it like adb would
+    // on a device that went away
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+    let dir = temp_dir.path().join("reports/android");
+
+    let error = manager
+        .collect_bugreport("Pixel_7_API_34", &dir, &|_| {})
+        .await
+        .unwrap_err();
+    assert!(format!("{error:#}").contains("Failed to collect bug report"));
+    assert!(dir.is_dir());
+
+    let (_, args) = mock_executor.call_history().pop().unwrap();
+    assert_eq!(args[..3], ["-s", "emulator-5554", "bugreport"]);
+    let path = Path::new(&args[3]);
+    assert_eq!(path.parent(), Some(dir.as_path()));
+    let file_name = path.file_name().unwrap().to_string_lossy();
+    assert!(file_name.starts_with("bugreport-Pixel_7_API_34-"));
+    assert!(file_name.ends_with(".zip"));
+    assert!(manager
+        .collect_bugreport("Pixel_8_API_35", &dir, &|_| {})
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_perfetto_trace_records_on_device_under_trace_dir() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    // No response for `perfetto`, so the mock fails it like an image
+    // without Perfetto would
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+    let dir = temp_dir.path().join("traces");
+    let capture = TraceCapture::parse("com.example", "5").unwrap();
+
+    let error = manager
+        .capture_perfetto_trace("Pixel_7_API_34", &capture, &dir)
+        .await
+        .unwrap_err();
+    assert!(format!("{error:#}").contains("Failed to record Perfetto trace"));
+    assert!(dir.is_dir());
+
+    let (_, args) = mock_executor.call_history().pop().unwrap();
+    assert_eq!(
+        args[..5],
+        ["-s", "emulator-5554", "shell", "perfetto", "-o"]
+    );
+    let device_path = args[5].strip_prefix(adb::PERFETTO_TRACE_DIR).unwrap();
+    assert!(device_path.starts_with("trace-Pixel_7_API_34-"));
+    assert!(device_path.ends_with(".perfetto-trace"));
+    assert!(args.windows(2).any(|pair| pair == ["--app", "com.example"]));
+}
+
+#[tokio::test]
+async fn test_developer_toggle_sets_property_and_pokes_apps() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "getprop"],
+                "[debug.hwui.overdraw]: [show]\n[debug.layout]: [false]\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "setprop",
+                    "debug.layout",
+                    "true",
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "service",
+                    "call",
+                    "activity",
+                    "1599295570",
+                ],
+                "Result: Parcel(00000000    '....')\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    let status = manager
+        .developer_toggle_status("Pixel_7_API_34")
+        .await
+        .unwrap();
+    assert!(status.is_enabled(DeveloperToggle::GpuOverdraw));
+    assert!(!status.is_enabled(DeveloperToggle::LayoutBounds));
+    assert!(!status.is_enabled(DeveloperToggle::StrictMode));
+
+    manager
+        .set_developer_toggle("Pixel_7_API_34", DeveloperToggle::LayoutBounds, true)
+        .await
+        .unwrap();
+    let (_, args) = mock_executor.call_history().pop().unwrap();
+    assert_eq!(args[3..], ["service", "call", "activity", "1599295570"]);
+}
+
+#[tokio::test]
+async fn test_network_capture_uses_emulator_console() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let path = temp_dir.path().join("captures").join("capture.pcap");
+    let path_arg = path.to_string_lossy().into_owned();
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "emu",
+                    "network",
+                    "capture",
+                    "start",
+                    &path_arg,
+                ],
+                "OK\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "emu", "network", "capture", "stop"],
+                "KO: no capture in progress\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    manager
+        .start_network_capture("Pixel_7_API_34", &path)
+        .await
+        .unwrap();
+    assert!(path.parent().unwrap().is_dir());
+
+    let error = manager
+        .stop_network_capture("Pixel_7_API_34")
+        .await
+        .unwrap_err();
+    assert!(format!("{error:#}").contains("no capture in progress"));
+
+    // The console would split a path with spaces into several arguments
+    let spaced = temp_dir.path().join("my captures").join("capture.pcap");
+    let error = manager
+        .start_network_capture("Pixel_7_API_34", &spaced)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("has spaces"));
+    assert!(!spaced.parent().unwrap().exists());
+}
+
+#[tokio::test]
+async fn test_app_data_backup_uses_run_as_tar() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let path = temp_dir.path().join("app-data").join("backup.tar");
+    let path_arg = path.to_string_lossy().into_owned();
+    let staged = "/data/local/tmp/app-data-com.example.tar";
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "run-as",
+                    "com.example",
+                    "tar",
+                    "cf",
+                    "-",
+                    ".",
+                    ">",
+                    staged,
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "pull", staged, &path_arg],
+                "1 file pulled\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "rm", "-f", staged],
+                "",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "push", &path_arg, staged],
+                "1 file pushed\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "pm", "clear", "com.example"],
+                "Success\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "run-as",
+                    "com.example",
+                    "tar",
+                    "xf",
+                    "-",
+                    "<",
+                    staged,
+                ],
+                "run-as: package not debuggable: com.example\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    manager
+        .backup_app_data("Pixel_7_API_34", "com.example", &path)
+        .await
+        .unwrap();
+    assert!(path.parent().unwrap().is_dir());
+
+    let error = manager
+        .restore_app_data("Pixel_7_API_34", "com.example", &path)
+        .await
+        .unwrap_err();
+    assert!(format!("{error:#}").contains("not debuggable"));
+    // The staged archive is removed even when the restore fails
+    let removals = mock_executor
+        .call_history()
+        .iter()
+        .filter(|(_, args)| args.iter().any(|arg| arg == "rm"))
+        .count();
+    assert_eq!(removals, 2);
+
+    // Restoring replaces app data, which read-only mode refuses
+    manager.set_read_only(true);
+    let calls_before = mock_executor.call_history().len();
+    let error = manager
+        .restore_app_data("Pixel_7_API_34", "com.example", &path)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<crate::models::DeviceError>(),
+        Some(crate::models::DeviceError::ReadOnly { .. })
+    ));
+    assert_eq!(mock_executor.call_history().len(), calls_before);
+
+    // Package-derived words reach the device shell quoted
+    assert!(manager
+        .backup_app_data("Pixel_7_API_34", "com.foo;reboot", &path)
+        .await
+        .is_err());
+    let history = mock_executor.call_history();
+    let shell_args: Vec<&String> = history
+        .iter()
+        .filter(|(_, args)| args.get(2).is_some_and(|arg| arg == "shell"))
+        .flat_map(|(_, args)| args.iter())
+        .filter(|arg| arg.contains("reboot"))
+        .collect();
+    assert_eq!(
+        shell_args,
+        [
+            "'com.foo;reboot'",
+            "'/data/local/tmp/app-data-com.foo;reboot.tar'",
+            "'/data/local/tmp/app-data-com.foo;reboot.tar'",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_copy_app_installs_pulled_apks_on_target() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let dir = std::env::temp_dir().join(format!("emu-app-copy-com.example-{}", std::process::id()));
+    let base = dir.join("base.apk").to_string_lossy().into_owned();
+    let split = dir
+        .join("split_config.xxhdpi.apk")
+        .to_string_lossy()
+        .into_owned();
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\nemulator-5556\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5556",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_4_API_28\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "pm", "path", "com.example"],
+                "package:/data/app/com.example-1/base.apk\npackage:/data/app/com.example-1/split_config.xxhdpi.apk\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "pull",
+                    "/data/app/com.example-1/base.apk",
+                    &base,
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "pull",
+                    "/data/app/com.example-1/split_config.xxhdpi.apk",
+                    &split,
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5556",
+                    "install-multiple",
+                    "-r",
+                    "-t",
+                    &base,
+                    &split,
+                ],
+                "Failure [INSTALL_FAILED_OLDER_SDK: Requires newer sdk version #29]\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    let error = manager
+        .copy_app("Pixel_7_API_34", "Pixel_4_API_28", "com.example", false)
+        .await
+        .unwrap_err();
+    assert!(format!("{error:#}").contains("INSTALL_FAILED_OLDER_SDK"));
+    // The pulled APKs are removed even when the install fails
+    assert!(!dir.exists());
+
+    // The package reaches the device shell as one word
+    assert!(manager
+        .copy_app("Pixel_7_API_34", "Pixel_4_API_28", "com.foo;reboot", false)
+        .await
+        .is_err());
+    let history = mock_executor.call_history();
+    let (_, pm_path) = history
+        .iter()
+        .find(|(_, args)| args.iter().any(|arg| arg.contains("reboot")))
+        .unwrap();
+    assert_eq!(
+        pm_path[3..],
+        ["pm", "path", "'com.foo;reboot'"].map(String::from)
+    );
+
+    // Read-only mode refuses before anything is pulled
+    manager.set_read_only(true);
+    let error = manager
+        .copy_app("Pixel_7_API_34", "Pixel_4_API_28", "com.example", false)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<crate::models::DeviceError>(),
+        Some(crate::models::DeviceError::ReadOnly { .. })
+    ));
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_pid_from_discovery_file_name() {
+    assert_eq!(
+        window::pid_from_discovery_file(Path::new("/run/user/1000/avd/running/pid_4242.ini")),
+        Some(4242)
+    );
+    assert_eq!(
+        window::pid_from_discovery_file(Path::new("pid_abc.ini")),
+        None
+    );
+    assert_eq!(
+        window::pid_from_discovery_file(Path::new("other.ini")),
+        None
+    );
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn test_focus_emulator_window_activates_window_by_title() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = MockCommandExecutor::new().with_success(
+        "wmctrl",
+        &["-a", "Android Emulator - Pixel_7_API_34:"],
+        "",
+    );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager
+        .focus_emulator_window("Pixel_7_API_34")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_list_devices_survives_corrupted_tool_output() {
+    use crate::utils::fault_injection::{Fault, FaultyCommandExecutor};
+
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let avd_list_output = r#"
+Available Android Virtual Devices:
+    Name: Pixel_7_API_34
+    Device: pixel_7 (Google)
+    Path: /Users/test/.android/avd/Pixel_7_API_34.avd
+    Target: Google APIs (Google Inc.)
+            Based on: Android 14.0 (API level 34) Tag/ABI: google_apis_playstore/arm64-v8a
+---------
+    Name: Pixel_9_API_35
+    Device: pixel_9 (Google)
+    Path: /Users/test/.android/avd/Pixel_9_API_35.avd
+    Target: Google APIs (Google Inc.)
+            Based on: Android 15.0 (API level 35) Tag/ABI: google_apis_playstore/arm64-v8a
+---------
+"#;
+    let executor = || {
+        MockCommandExecutor::new()
+            .with_success("avdmanager", &["list", "avd"], avd_list_output)
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+    };
+
+    for fault in [Fault::PartialOutput, Fault::NonUtf8] {
+        let faulty = FaultyCommandExecutor::new(executor())
+            .with_fault("avdmanager", &[], fault)
+            .with_fault("adb", &[], fault);
+        let manager = AndroidManager::with_executor(Arc::new(faulty)).unwrap();
+
+        let devices = manager.list_devices().await.unwrap();
+        assert!(
+            devices.iter().all(|device| !device.is_running),
+            "{fault:?}: a corrupted adb listing must not mark devices as running"
+        );
+    }
+
+    let faulty = FaultyCommandExecutor::new(executor()).with_fault(
+        "avdmanager",
+        &[],
+        Fault::Hang(std::time::Duration::from_millis(10)),
+    );
+    let manager = AndroidManager::with_executor(Arc::new(faulty)).unwrap();
+    assert!(manager.list_devices().await.is_err());
+}
+
+#[tokio::test]
+async fn test_send_intent_quotes_arguments_and_reports_am_errors() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let serial_args = ["-s", "emulator-5554", "shell"];
+    let view_args = [
+        "am",
+        "start",
+        "-a",
+        "android.intent.action.VIEW",
+        "-d",
+        "'myapp://search?q=it'\\''s&page=2'",
+    ];
+    let missing_args = ["am", "start", "-n", "com.example/.Missing"];
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[&serial_args[..], &view_args[..]].concat(),
+                "Starting: Intent { act=android.intent.action.VIEW }\n",
+            )
+            .with_success(
+                "adb",
+                &[&serial_args[..], &missing_args[..]].concat(),
+                "Starting: Intent { cmp=com.example/.Missing }\n\
+                 Error type 3\n\
+                 Error: Activity class {com.example/com.example.Missing} does not exist.\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    let view = AndroidIntent {
+        kind: IntentKind::Activity,
+        action: "android.intent.action.VIEW".to_string(),
+        data_uri: "myapp://search?q=it's&page=2".to_string(),
+        ..Default::default()
+    };
+    manager.send_intent("Pixel_7_API_34", &view).await.unwrap();
+
+    let missing = AndroidIntent {
+        component: "com.example/.Missing".to_string(),
+        ..Default::default()
+    };
+    let error = manager
+        .send_intent("Pixel_7_API_34", &missing)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Error type 3"));
+}
+
+#[tokio::test]
+async fn test_launch_app_restarts_launcher_activity_with_extras() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let serial_args = ["-s", "emulator-5554", "shell"];
+    let resolve_args = [adb::RESOLVE_LAUNCHER_ACTIVITY, &["com.example"]].concat();
+    let start_args = [
+        "am",
+        "start",
+        "-n",
+        "com.example/.MainActivity",
+        "--es",
+        "user",
+        "'Jane Doe'",
+        "--ei",
+        "retries",
+        "3",
+    ];
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[&serial_args[..], &resolve_args[..]].concat(),
+                "priority=0 preferredOrder=0 match=0x108000 specificIndex=-1 isDefault=true\n\
+                 com.example/.MainActivity\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "am",
+                    "force-stop",
+                    "com.example",
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &[&serial_args[..], &start_args[..]].concat(),
+                "Starting: Intent { cmp=com.example/.MainActivity (has extras) }\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    let launch = AppLaunch::parse(
+        Platform::Android,
+        "com.example",
+        "user=Jane Doe, retries=i:3",
+        "",
+    )
+    .unwrap();
+    let component = manager.launch_app("Pixel_7_API_34", &launch).await.unwrap();
+    assert_eq!(component, "com.example/.MainActivity");
+    let history = mock_executor.call_history();
+    let position = |command: &str| {
+        history
+            .iter()
+            .position(|(_, args)| args.get(4).is_some_and(|arg| arg == command))
+            .unwrap()
+    };
+    assert!(position(adb::AM_FORCE_STOP) < position("start"));
+
+    // The package name reaches the device shell as one word
+    let launch = AppLaunch::parse(Platform::Android, "com.example;reboot", "", "").unwrap();
+    assert!(manager.launch_app("Pixel_7_API_34", &launch).await.is_err());
+    let history = mock_executor.call_history();
+    let (_, resolve) = history.last().unwrap();
+    assert_eq!(
+        resolve.last().map(String::as_str),
+        Some("'com.example;reboot'")
+    );
+}
+
+#[tokio::test]
+async fn test_list_and_kill_processes_on_running_avd() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "ps",
+                    "-A",
+                    "-o",
+                    "PID,USER,RSS,NAME",
+                ],
+                "  PID USER            RSS NAME\n\
+                   388 root           6120 logd\n\
+                  2051 u0_a123       40112 com.example:sync\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "am",
+                    "force-stop",
+                    "com.example",
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "kill", "388"],
+                "/system/bin/sh: kill: 388: Operation not permitted\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    let processes = manager.list_processes("Pixel_7_API_34").await.unwrap();
+    assert_eq!(processes.len(), 2);
+
+    // App processes are force-stopped by package, daemons are killed by PID
+    manager
+        .kill_process("Pixel_7_API_34", &processes[1])
+        .await
+        .unwrap();
+    let error = manager
+        .kill_process("Pixel_7_API_34", &processes[0])
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Operation not permitted"));
+}
+
+#[tokio::test]
+async fn test_write_log_marker_quotes_the_message_for_the_device_shell() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "log",
+                    "-t",
+                    "EMU",
+                    "'=== emu marker 1 at 10:00:00 ==='",
+                ],
+                "",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    manager
+        .write_log_marker("Pixel_7_API_34", "EMU", "=== emu marker 1 at 10:00:00 ===")
+        .await
+        .unwrap();
+    assert!(manager
+        .write_log_marker("Pixel_8_API_35", "EMU", "=== marker ===")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_list_and_set_system_properties() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "getprop"],
+                "[ro.debuggable]: [1]\n[debug.layout]: [false]\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "setprop",
+                    "debug.layout",
+                    "true",
+                ],
+                "",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "setprop",
+                    "ro.debuggable",
+                    "0",
+                ],
+                "Failed to set property 'ro.debuggable' to '0'.\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    let properties = manager.list_properties("Pixel_7_API_34").await.unwrap();
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties[0].name, "debug.layout");
+
+    manager
+        .set_property("Pixel_7_API_34", "debug.layout", "true")
+        .await
+        .unwrap();
+    let error = manager
+        .set_property("Pixel_7_API_34", "ro.debuggable", "0")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Failed to set property"));
+}
+
+#[tokio::test]
+async fn test_run_monkey_streams_output_and_reports_crashes() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "monkey",
+                    "-p",
+                    "com.example",
+                    "--throttle",
+                    "100",
+                    "-v",
+                    "500",
+                ],
+                ":Monkey: seed=1 count=500\n\
+                 // CRASH: com.example (pid 4242)\n\
+                 // Short Msg: java.lang.IllegalStateException\n\
+                 ** Monkey aborted due to error.\n\
+                 Events injected: 37\n",
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    let run = MonkeyRun::parse("com.example", "500", "100").unwrap();
+    let lines = std::sync::Mutex::new(Vec::new());
+    let report = manager
+        .run_monkey("Pixel_7_API_34", &run, &|line: &str| {
+            lines.lock().unwrap().push(line.to_string())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(lines.into_inner().unwrap().len(), 5);
+    assert_eq!(
+        report.crashes,
+        ["com.example (pid 4242) - java.lang.IllegalStateException"]
+    );
+    assert_eq!(report.events_injected, Some(37));
+}
+
+#[tokio::test]
+async fn test_failed_emulator_launch_keeps_output_and_reports_error() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let start_args = [
+        "-avd",
+        "Pixel_7_API_34",
+        "-no-audio",
+        "-no-snapshot-save",
+        "-no-boot-anim",
+        "-netfast",
+    ];
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_spawn_response("emulator", &start_args, 12345)
+            .with_spawn_output(
+                "emulator",
+                &start_args,
+                "INFO    | Android emulator version 35.1.4.0\n\
+                 ERROR   | x86 emulation currently requires hardware acceleration!\n",
+                false,
+            ),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+    assert!(manager.launch_output("Pixel_7_API_34").is_empty());
+
+    manager
+        .start_device_internal("Pixel_7_API_34")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        manager.launch_output("Pixel_7_API_34"),
+        [
+            "INFO    | Android emulator version 35.1.4.0",
+            "ERROR   | x86 emulation currently requires hardware acceleration!",
+            "[exited with code 1]",
+        ]
+    );
+    let failures = manager.take_launch_failures();
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].reason.contains("hardware acceleration"));
+    assert!(manager.take_launch_failures().is_empty());
+}
+
+#[tokio::test]
+async fn test_read_only_start_adds_read_only_flag() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let start_args = [
+        "-avd",
+        "Pixel_7_API_34",
+        "-no-audio",
+        "-no-snapshot-save",
+        "-no-boot-anim",
+        "-netfast",
+        "-read-only",
+    ];
+    let mock_executor =
+        Arc::new(MockCommandExecutor::new().with_spawn_response("emulator", &start_args, 12345));
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    manager
+        .start_device_read_only("Pixel_7_API_34")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_diagnose_launch_finds_and_fixes_issues() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let avd_home = temp_dir.path().join("avd-home");
+    let _avd_home = EnvVarGuard::set("ANDROID_AVD_HOME", avd_home.as_os_str());
+
+    let avd_dir = avd_home.join("Pixel_7_API_34.avd");
+    std::fs::create_dir_all(avd_dir.join("hardware-qemu.ini.lock")).unwrap();
+    std::fs::write(avd_dir.join("multiinstance.lock"), "").unwrap();
+    std::fs::write(
+        avd_dir.join("config.ini"),
+        "AvdId=Pixel_7_API_34\nimage.sysdir.1=system-images/android-34/google_apis/x86_64/\n",
+    )
+    .unwrap();
+
+    let avd_dir_arg = avd_dir.to_string_lossy().to_string();
+    let mock_executor = MockCommandExecutor::new()
+        .with_success("adb", &["devices"], "List of devices attached\n")
+        .with_success(
+            "df",
+            &["-Pk", &avd_dir_arg],
+            "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+             /dev/sda1 490617784 480000000 524288 99% /\n",
+        )
+        .with_error(
+            "emulator",
+            &["-accel-check"],
+            "Command failed with exit code 1: stderr:  stdout: accel:\n10\n\
+             /dev/kvm is not found: VT disabled in BIOS or KVM kernel module not loaded\naccel",
+        );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    let issues = manager.diagnose_launch("Pixel_7_API_34").await.unwrap();
+    assert!(issues.contains(&LaunchIssue::MissingSystemImage {
+        package_id: "system-images;android-34;google_apis;x86_64".to_string(),
+    }));
+    let lock_files = vec![
+        avd_dir.join("hardware-qemu.ini.lock"),
+        avd_dir.join("multiinstance.lock"),
+    ];
+    let stale_locks = LaunchIssue::StaleLocks {
+        lock_files: lock_files.clone(),
+    };
+    assert!(issues.contains(&stale_locks));
+    assert!(issues.contains(&LaunchIssue::LowDisk {
+        available_mb: 512,
+        required_mb: 2048,
+    }));
+    assert!(issues.contains(&LaunchIssue::NoAcceleration {
+        details: "/dev/kvm is not found: VT disabled in BIOS or KVM kernel module not loaded"
+            .to_string(),
+    }));
+
+    manager.fix_launch_issue(&stale_locks).await.unwrap();
+    assert!(lock_files.iter().all(|path| !path.exists()));
+    assert!(manager
+        .fix_launch_issue(&LaunchIssue::PortsInUse)
+        .await
+        .is_err());
+
+    // Once the image is installed it is no longer reported
+    std::fs::create_dir_all(
+        temp_dir
+            .path()
+            .join("system-images/android-34/google_apis/x86_64"),
+    )
+    .unwrap();
+    let issues = manager.diagnose_launch("Pixel_7_API_34").await.unwrap();
+    assert!(!issues
+        .iter()
+        .any(|issue| matches!(issue, LaunchIssue::MissingSystemImage { .. })));
+}
+
+#[tokio::test]
+async fn test_is_responsive_pings_the_device_shell() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let running = || {
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+    };
+    let echo_args = ["-s", "emulator-5554", "shell", "echo", "ok"];
+
+    let responsive = running().with_success("adb", &echo_args, "ok\n");
+    let manager = AndroidManager::with_executor(Arc::new(responsive)).unwrap();
+    assert!(manager.is_responsive("Pixel_7_API_34").await.unwrap());
+
+    let wedged = running().with_error("adb", &echo_args, "Command timed out after 5s");
+    let manager = AndroidManager::with_executor(Arc::new(wedged)).unwrap();
+    assert!(!manager.is_responsive("Pixel_7_API_34").await.unwrap());
+    assert!(manager.is_responsive("Other_AVD").await.is_err());
+}
+
+#[tokio::test]
+async fn test_quick_boot_toggles_add_no_snapshot_load() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let avd_home = temp_dir.path().join("avd-home");
+    let _avd_home = EnvVarGuard::set("ANDROID_AVD_HOME", avd_home.as_os_str());
+
+    let avd_dir = avd_home.join("Pixel_7_API_34.avd");
+    let snapshot_dir = avd_dir.join("snapshots/default_boot");
+    std::fs::create_dir_all(&snapshot_dir).unwrap();
+    std::fs::write(snapshot_dir.join("ram.bin"), vec![0u8; 1024]).unwrap();
+    std::fs::write(avd_dir.join("config.ini"), "AvdId=Pixel_7_API_34\n").unwrap();
+
+    let mock_executor =
+        MockCommandExecutor::new().with_success("adb", &["devices"], "List of devices attached\n");
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    let status = manager.quick_boot_status("Pixel_7_API_34").await.unwrap();
+    assert_eq!(status.snapshot_size, Some(1024));
+    assert!(!status.boots_cold());
+    let command = manager.start_command("Pixel_7_API_34").await;
+    assert!(!command.args.contains(&"-no-snapshot-load".to_string()));
+
+    manager.set_cold_boot_once("Pixel_7_API_34", true);
+    let command = manager.start_command("Pixel_7_API_34").await;
+    assert_eq!(command.args.last().unwrap(), "-no-snapshot-load");
+    manager.set_cold_boot_once("Pixel_7_API_34", false);
+
+    manager
+        .set_always_cold_boot("Pixel_7_API_34", true)
+        .await
+        .unwrap();
+    let config = std::fs::read_to_string(avd_dir.join("config.ini")).unwrap();
+    assert_eq!(config, "AvdId=Pixel_7_API_34\nfastboot.forceColdBoot=yes\n");
+    let status = manager.quick_boot_status("Pixel_7_API_34").await.unwrap();
+    assert!(status.always_cold_boot && !status.cold_boot_once);
+    let command = manager.start_command("Pixel_7_API_34").await;
+    assert_eq!(command.args.last().unwrap(), "-no-snapshot-load");
+
+    manager
+        .delete_quick_boot_snapshot("Pixel_7_API_34")
+        .await
+        .unwrap();
+    assert!(!snapshot_dir.exists());
+    let status = manager.quick_boot_status("Pixel_7_API_34").await.unwrap();
+    assert_eq!(status.snapshot_size, None);
+}
+
+#[tokio::test]
+async fn test_change_clock_shifts_device_time_as_root() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let running = || {
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_success(
+                "adb",
+                &["-s", "emulator-5554", "shell", "date", "+%Y-%m-%dT%H:%M:%S"],
+                "2025-06-01T12:00:00\n",
+            )
+    };
+
+    let mock_executor = running()
+        .with_success(
+            "adb",
+            &["-s", "emulator-5554", "root"],
+            "restarting adbd as root\n",
+        )
+        .with_success("adb", &["-s", "emulator-5554", "wait-for-device"], "")
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "settings",
+                "put",
+                "global",
+                "auto_time",
+                "0",
+            ],
+            "",
+        )
+        .with_success(
+            "adb",
+            &["-s", "emulator-5554", "shell", "date", "060812002025.00"],
+            "",
+        )
+        .with_success(
+            "adb",
+            &["-s", "emulator-5554", "shell", "date"],
+            "Sun Jun  8 12:00:00 GMT 2025\n",
+        );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    let time = manager
+        .change_clock("Pixel_7_API_34", &ClockChange::parse("+7d").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(time, "Sun Jun  8 12:00:00 GMT 2025");
+
+    // Google Play images refuse adb root
+    let mock_executor = running().with_success(
+        "adb",
+        &["-s", "emulator-5554", "root"],
+        "adbd cannot run as root in production builds\n",
+    );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    let error = manager
+        .change_clock("Pixel_7_API_34", &ClockChange::parse("+7d").unwrap())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("adb root"));
+}
+
+#[tokio::test]
+async fn test_send_key_presses_keyevent() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "input",
+                "keyevent",
+                "KEYCODE_DEL",
+            ],
+            "",
+        );
+    let mock_executor = Arc::new(mock_executor);
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+    manager
+        .send_key("Pixel_7_API_34", DeviceKey::Backspace)
+        .await
+        .unwrap();
+    assert!(mock_executor
+        .call_history()
+        .iter()
+        .any(|(_, args)| args.iter().any(|arg| arg == "KEYCODE_DEL")));
+
+    assert!(manager
+        .send_key("Pixel_3a_API_30", DeviceKey::Enter)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_mirroring_tracks_and_stops_scrcpy() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let scrcpy = temp_dir.path().join("scrcpy");
+    std::fs::write(&scrcpy, "#!/bin/sh\n").unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&scrcpy, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    crate::utils::tool_paths::set_tool_path("scrcpy", Some(scrcpy));
+
+    let scrcpy_args = [
+        "--serial",
+        "emulator-5554",
+        "--window-title",
+        "Pixel_7_API_34",
+        "--stay-awake",
+    ];
+    let running = || {
+        MockCommandExecutor::new()
+            .with_success(
+                "adb",
+                &["devices"],
+                "List of devices attached\nemulator-5554\tdevice\n",
+            )
+            .with_success(
+                "adb",
+                &[
+                    "-s",
+                    "emulator-5554",
+                    "shell",
+                    "getprop",
+                    "ro.boot.qemu.avd_name",
+                ],
+                "Pixel_7_API_34\n",
+            )
+            .with_spawn_response("scrcpy", &scrcpy_args, 4242)
+    };
+
+    let mock_executor = Arc::new(running().with_success("kill", &["4242"], ""));
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+    manager
+        .start_mirroring("Pixel_7_API_34", None)
+        .await
+        .unwrap();
+    assert!(manager.is_mirroring("Pixel_7_API_34"));
+    assert!(manager
+        .start_mirroring("Pixel_7_API_34", None)
+        .await
+        .is_err());
+
+    manager.stop_all_mirroring().await;
+    assert!(!manager.is_mirroring("Pixel_7_API_34"));
+    assert!(mock_executor
+        .call_history()
+        .iter()
+        .any(|(command, args)| command == "kill" && args == &["4242"]));
+    assert!(!manager.stop_mirroring("Pixel_7_API_34").await.unwrap());
+    assert!(manager.take_mirror_failures().is_empty());
+
+    // scrcpy that exits on its own with an error is reported once
+    let mock_executor = running().with_spawn_output(
+        "scrcpy",
+        &scrcpy_args,
+        "INFO: scrcpy 2.4\nERROR: Could not find any ADB device",
+        false,
+    );
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    manager
+        .start_mirroring("Pixel_7_API_34", None)
+        .await
+        .unwrap();
+    assert!(!manager.is_mirroring("Pixel_7_API_34"));
+    let failures = manager.take_mirror_failures();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].reason, "ERROR: Could not find any ADB device");
+    assert!(manager.take_mirror_failures().is_empty());
+
+    // Only a window that records the screen is reported as recording
+    let recording = temp_dir.path().join("emu-mirror.mp4");
+    let record_arg = format!("--record={}", recording.display());
+    let mut recording_args = scrcpy_args.to_vec();
+    recording_args.push(&record_arg);
+    let mock_executor = running()
+        .with_spawn_response("scrcpy", &recording_args, 4343)
+        .with_success("kill", &["4242"], "")
+        .with_success("kill", &["4343"], "");
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+    manager
+        .start_mirroring("Pixel_7_API_34", None)
+        .await
+        .unwrap();
+    assert_eq!(manager.recording_mirror(), None);
+    manager.stop_mirroring("Pixel_7_API_34").await.unwrap();
+    manager
+        .start_mirroring("Pixel_7_API_34", Some(&recording))
+        .await
+        .unwrap();
+    assert_eq!(
+        manager.recording_mirror().as_deref(),
+        Some("Pixel_7_API_34")
+    );
+    manager.stop_mirroring("Pixel_7_API_34").await.unwrap();
+    assert_eq!(manager.recording_mirror(), None);
+
+    crate::utils::tool_paths::set_tool_path("scrcpy", None);
+}
+
+#[tokio::test]
+async fn test_radio_status_and_airplane_mode_fallback() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+
+    let shell = |args: &[&str]| {
+        ["-s", "emulator-5554", "shell"]
+            .into_iter()
+            .chain(args.iter().copied())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        );
+    let mock_executor = [
+        (vec!["settings", "get", "global", "wifi_on"], "1\n"),
+        (vec!["settings", "get", "global", "bluetooth_on"], "null\n"),
+        (vec!["settings", "get", "global", "airplane_mode_on"], "0\n"),
+        (
+            vec!["settings", "put", "global", "airplane_mode_on", "1"],
+            "",
+        ),
+        (
+            vec![
+                "am",
+                "broadcast",
+                "-a",
+                "android.intent.action.AIRPLANE_MODE",
+                "--ez",
+                "state",
+                "true",
+            ],
+            "Broadcasting: Intent { act=android.intent.action.AIRPLANE_MODE (has extras) }\n\
+             Security exception: Permission Denial: not allowed to send broadcast\n",
+        ),
+        (vec!["cmd", "connectivity", "airplane-mode", "enable"], ""),
+        (vec!["svc", "wifi", "disable"], ""),
+    ]
+    .into_iter()
+    .fold(mock_executor, |mock, (args, output)| {
+        let args = shell(&args);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        mock.with_success("adb", &args, output)
+    });
+    let mock_executor = Arc::new(mock_executor);
+    let manager = AndroidManager::with_executor(mock_executor.clone()).unwrap();
+
+    let status = manager.radio_status("Pixel_7_API_34").await.unwrap();
+    assert_eq!(status.get(Radio::Wifi), Some(true));
+    assert_eq!(status.get(Radio::Bluetooth), None);
+    assert_eq!(status.get(Radio::AirplaneMode), Some(false));
+
+    manager
+        .set_radio("Pixel_7_API_34", Radio::Wifi, false)
+        .await
+        .unwrap();
+    manager
+        .set_radio("Pixel_7_API_34", Radio::AirplaneMode, true)
+        .await
+        .unwrap();
+    // The refused broadcast falls back to cmd connectivity
+    assert!(mock_executor
+        .call_history()
+        .iter()
+        .any(|(_, args)| args.ends_with(&shell(&[
+            "cmd",
+            "connectivity",
+            "airplane-mode",
+            "enable"
+        ]))));
+}
+
+#[tokio::test]
+async fn test_booting_avd_refuses_wipe_until_stopped() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let start_args = [
+        "-avd",
+        "Pixel_7_API_34",
+        "-no-audio",
+        "-no-snapshot-save",
+        "-no-boot-anim",
+        "-netfast",
+    ];
+    let mock_executor = Arc::new(
+        MockCommandExecutor::new()
+            .with_spawn_response("emulator", &start_args, 12345)
+            // Not attached to adb yet, so the boot has not completed
+            .with_success("adb", &["devices"], "List of devices attached\n"),
+    );
+    let manager = AndroidManager::with_executor(mock_executor).unwrap();
+
+    DeviceManager::start_device(&manager, "Pixel_7_API_34")
+        .await
+        .unwrap();
+    let error = DeviceManager::wipe_device(&manager, "Pixel_7_API_34")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<crate::models::DeviceError>(),
+        Some(crate::models::DeviceError::Busy { operation, .. })
+            if operation == device_busy::BOOTING
+    ));
+    assert!(DeviceManager::start_device(&manager, "Pixel_7_API_34")
+        .await
+        .is_err());
+
+    DeviceManager::stop_device(&manager, "Pixel_7_API_34")
+        .await
+        .unwrap();
+    assert_eq!(manager.device_locks.running("Pixel_7_API_34"), None);
+}
+
+#[tokio::test]
+async fn test_stop_does_not_retry_shutdown_commands() {
+    let _env_lock = acquire_test_env_lock().await;
+    let temp_dir = setup_test_android_sdk();
+    let _android_home = EnvVarGuard::set("ANDROID_HOME", temp_dir.path().as_os_str());
+    let _home = EnvVarGuard::set("HOME", temp_dir.path().as_os_str());
+
+    let broadcast = [
+        "-s",
+        "emulator-5554",
+        "shell",
+        "am",
+        "broadcast",
+        "-a",
+        "android.intent.action.ACTION_SHUTDOWN",
+    ];
+    let mock_executor = MockCommandExecutor::new()
+        .with_success(
+            "adb",
+            &["devices"],
+            "List of devices attached\nemulator-5554\tdevice\n",
+        )
+        .with_success(
+            "adb",
+            &[
+                "-s",
+                "emulator-5554",
+                "shell",
+                "getprop",
+                "ro.boot.qemu.avd_name",
+            ],
+            "Pixel_7_API_34\n",
+        )
+        .with_error("adb", &broadcast, "error: device offline")
+        .with_success("adb", &["-s", "emulator-5554", "emu", "kill"], "OK");
+    let call_history = mock_executor.clone();
+    let manager = AndroidManager::with_executor(Arc::new(mock_executor)).unwrap();
+
+    manager
+        .stop_device_internal("Pixel_7_API_34")
+        .await
+        .unwrap();
+
+    let calls = call_history.call_history();
+    let count = |args: &[&str]| calls.iter().filter(|(_, called)| called == args).count();
+    assert_eq!(count(&broadcast), 1);
+    assert_eq!(count(&["-s", "emulator-5554", "emu", "kill"]), 1);
+}
@@ -1,6 +1,7 @@
-use super::AndroidManager;
+use super::{parsers, AndroidManager};
 use crate::constants::commands;
-use regex::Regex;
+use crate::utils::command_executor::CommandPolicy;
+use anyhow::Result;
 
 impl AndroidManager {
     pub(super) fn parse_android_version_to_api_level(version: &str) -> u32 {
@@ -28,46 +29,101 @@ impl AndroidManager {
         }
     }
 
+    /// The version name of `api_level` ("14" for 34) if sdkmanager named it
+    /// in this or an earlier session, "API N" otherwise. Never runs
+    /// sdkmanager, so details can be shown right away.
     pub(super) fn get_android_version_name(&self, api_level: u32) -> String {
-        format!("API {api_level}")
+        self.cached_android_version_name(api_level)
+            .unwrap_or_else(|| format!("API {api_level}"))
     }
 
+    /// "Android 14" when the version name of `api_level` is known, "API 34"
+    /// otherwise.
+    pub(super) fn android_version_label(&self, api_level: u32) -> String {
+        match self.cached_android_version_name(api_level) {
+            Some(version_name) => format!("Android {version_name}"),
+            None => format!("API {api_level}"),
+        }
+    }
+
+    pub(super) fn cached_android_version_name(&self, api_level: u32) -> Option<String> {
+        self.android_version_names
+            .read()
+            .unwrap()
+            .version_name(api_level)
+            .map(str::to_string)
+    }
+
+    /// Looks the version name of `api_level` up with sdkmanager unless the
+    /// on-disk cache named it within the last week. A stale name is still
+    /// returned when sdkmanager cannot answer.
     pub(super) async fn get_dynamic_android_version_name(&self, api_level: u32) -> Option<String> {
-        if let Ok(targets) = self.list_available_targets().await {
-            for (level_str, display) in targets {
-                if let Ok(level) = level_str.parse::<u32>() {
-                    if level == api_level {
-                        if let Some(dash_pos) = display.find(" - Android ") {
-                            return Some(display[dash_pos + 11..].to_string());
-                        }
-                    }
-                }
-            }
+        let fresh = self
+            .android_version_names
+            .read()
+            .unwrap()
+            .fresh_version_name(api_level)
+            .map(str::to_string);
+        if fresh.is_some() {
+            return fresh;
         }
 
-        if let Ok(sdkmanager_path) = Self::find_tool(&self.android_home, commands::SDKMANAGER) {
-            if let Ok(output) = self
-                .command_executor
-                .run(&sdkmanager_path, &[commands::sdkmanager::LIST])
-                .await
-            {
-                let package_name = format!("platforms;android-{api_level}");
-                let pattern = format!(r"{package_name}\s*\|");
-                if let Ok(regex) = Regex::new(&pattern) {
-                    for line in output.lines() {
-                        if regex.is_match(line) {
-                            if let Some((_, version_name)) = line.rsplit_once("| Android ") {
-                                let version_name = version_name.trim();
-                                if !version_name.is_empty() {
-                                    return Some(version_name.to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        self.lookup_android_version_names().await;
+        self.cached_android_version_name(api_level)
+    }
+
+    /// Names the installed API levels with one sdkmanager run when any of
+    /// them has no name from the last week, so details never have to.
+    pub async fn refresh_android_version_names(&self) -> Result<()> {
+        let targets = self.list_available_targets().await?;
+        let stale = {
+            let version_names = self.android_version_names.read().unwrap();
+            targets
+                .iter()
+                .filter_map(|(level, _)| level.parse::<u32>().ok())
+                .any(|level| version_names.fresh_version_name(level).is_none())
+        };
+        if stale {
+            self.lookup_android_version_names().await;
         }
+        Ok(())
+    }
+
+    /// Stores every version name `sdkmanager --list` reports.
+    async fn lookup_android_version_names(&self) {
+        let Ok(sdkmanager_path) = Self::find_tool(&self.android_home, commands::SDKMANAGER) else {
+            return;
+        };
+        let output = match self
+            .command_executor
+            .run_with_policy(
+                &sdkmanager_path,
+                &[commands::sdkmanager::LIST],
+                &CommandPolicy::SDKMANAGER_NETWORK,
+            )
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => {
+                log::debug!("Failed to look up Android version names: {e}");
+                return;
+            }
+        };
 
-        None
+        let parsed = parsers::parse_platform_version_names(&output);
+        if parsed.is_empty() {
+            return;
+        }
+        // Saved from a copy, so readers never wait on the file write
+        let snapshot = {
+            let mut version_names = self.android_version_names.write().unwrap();
+            for (api_level, version_name) in parsed {
+                version_names.insert(api_level, version_name);
+            }
+            version_names.clone()
+        };
+        if let Err(e) = snapshot.save_to_disk() {
+            log::warn!("Failed to save Android version names: {e}");
+        }
     }
 }
@@ -0,0 +1,58 @@
+//! Bringing emulator windows to the foreground.
+//!
+//! On macOS the emulator process is raised through System Events, found by
+//! the PID in its discovery file name (`pid_<pid>.ini`). On Linux the window
+//! is activated by title with `wmctrl`.
+
+use super::{grpc, parsers, AndroidManager};
+use crate::constants::commands::{self, emulator};
+use anyhow::{Context, Result};
+use std::path::Path;
+
+impl AndroidManager {
+    /// Brings the window of a running AVD to the foreground.
+    pub async fn focus_emulator_window(&self, avd_name: &str) -> Result<()> {
+        if cfg!(target_os = "macos") {
+            let pid = emulator_pid(avd_name)
+                .await
+                .with_context(|| format!("No running emulator window found for '{avd_name}'"))?;
+            let script = emulator::FOCUS_PROCESS_SCRIPT.replace("{}", &pid.to_string());
+            self.command_executor
+                .run(Path::new(commands::OSASCRIPT), &["-e", &script])
+                .await
+                .context("Failed to focus the emulator window")?;
+        } else {
+            let title = format!("{}{avd_name}:", emulator::WINDOW_TITLE_PREFIX);
+            self.command_executor
+                .run(
+                    Path::new(commands::WMCTRL),
+                    &[commands::WMCTRL_ACTIVATE, &title],
+                )
+                .await
+                .context("Failed to focus the emulator window (is wmctrl installed?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// PID of the emulator running `avd_name`, read from the discovery files.
+pub(super) async fn emulator_pid(avd_name: &str) -> Option<u32> {
+    grpc::discovery_files()
+        .await
+        .iter()
+        .find(|(_, content)| {
+            parsers::parse_config_ini(content)
+                .iter()
+                .any(|(key, value)| key == "avd.name" && value == avd_name)
+        })
+        .and_then(|(path, _)| pid_from_discovery_file(path))
+}
+
+/// Extracts the PID from a discovery file name such as `pid_4242.ini`.
+pub(super) fn pid_from_discovery_file(path: &Path) -> Option<u32> {
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix("pid_")?
+        .parse()
+        .ok()
+}
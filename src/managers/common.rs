@@ -4,19 +4,148 @@
 //! It defines the common interface that both Android and iOS managers implement,
 //! along with helper functions for device name sanitization and tool discovery.
 
-use crate::models::device::Device;
+use crate::models::{DeviceCapabilities, DeviceError, ManagedDevice, Platform};
 use anyhow::Result;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Switch for `--read-only`, which turns off the operations that change
+/// devices or the SDK. Clones share the switch, so turning it on also covers
+/// the copies of a manager held by the app and its background tasks.
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlySwitch(Arc<AtomicBool>);
+
+impl ReadOnlySwitch {
+    pub fn set(&self, read_only: bool) {
+        self.0.store(read_only, Ordering::Relaxed);
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fails with [`DeviceError::ReadOnly`] naming `operation` while the
+    /// switch is on.
+    pub fn ensure_writable(&self, operation: &str) -> Result<()> {
+        if self.is_on() {
+            return Err(DeviceError::read_only(operation).into());
+        }
+        Ok(())
+    }
+}
+
+/// Operations running on each device, so one that conflicts is refused
+/// with [`DeviceError::Busy`] instead of racing the tools of the other, e.g.
+/// wiping an emulator while it boots. Clones share the locks, like
+/// [`ReadOnlySwitch`].
+#[derive(Debug, Clone, Default)]
+pub struct DeviceLocks(Arc<Mutex<HashMap<String, DeviceLock>>>);
+
+#[derive(Debug)]
+struct DeviceLock {
+    operation: &'static str,
+    /// Set for operations that outlive the call that began them, such as
+    /// a boot, which give the device up by themselves after a while.
+    expires_at: Option<Instant>,
+}
+
+impl DeviceLock {
+    fn is_held(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| Instant::now() < expires_at)
+    }
+}
+
+impl DeviceLocks {
+    /// Marks `operation` as running on `identifier` until the returned
+    /// guard is dropped. Fails naming the operation that already runs.
+    pub fn acquire(&self, identifier: &str, operation: &'static str) -> Result<DeviceLockGuard> {
+        let mut locks = self.0.lock().unwrap();
+        if let Some(running) = locks.get(identifier).filter(|lock| lock.is_held()) {
+            return Err(DeviceError::busy(identifier, running.operation).into());
+        }
+        locks.insert(
+            identifier.to_string(),
+            DeviceLock {
+                operation,
+                expires_at: None,
+            },
+        );
+        Ok(DeviceLockGuard {
+            locks: self.clone(),
+            identifier: identifier.to_string(),
+        })
+    }
+
+    /// Marks `operation` as running on `identifier` for at most `timeout`,
+    /// or until [`release`](Self::release) ends it.
+    pub fn hold(&self, identifier: &str, operation: &'static str, timeout: Duration) {
+        self.0.lock().unwrap().insert(
+            identifier.to_string(),
+            DeviceLock {
+                operation,
+                expires_at: Some(Instant::now() + timeout),
+            },
+        );
+    }
+
+    /// Ends `operation` on `identifier` if it is what runs there.
+    pub fn release(&self, identifier: &str, operation: &'static str) {
+        let mut locks = self.0.lock().unwrap();
+        if locks
+            .get(identifier)
+            .is_some_and(|lock| lock.operation == operation)
+        {
+            locks.remove(identifier);
+        }
+    }
+
+    /// The operation running on `identifier`, if any.
+    pub fn running(&self, identifier: &str) -> Option<&'static str> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(identifier)
+            .filter(|lock| lock.is_held())
+            .map(|lock| lock.operation)
+    }
+}
+
+/// Ends the operation [`DeviceLocks::acquire`] began when dropped.
+#[derive(Debug)]
+#[must_use]
+pub struct DeviceLockGuard {
+    locks: DeviceLocks,
+    identifier: String,
+}
+
+impl Drop for DeviceLockGuard {
+    fn drop(&mut self) {
+        let mut locks = self.locks.0.lock().unwrap();
+        // A hold placed while the guard lived, e.g. the boot that a start
+        // began, outlasts the guard
+        if locks
+            .get(&self.identifier)
+            .is_some_and(|lock| lock.expires_at.is_none())
+        {
+            locks.remove(&self.identifier);
+        }
+    }
+}
 
 /// Unified interface for managing virtual devices across platforms.
 ///
 /// This trait provides a common API for device operations that works
-/// consistently across Android AVDs and iOS simulators. All methods
-/// are async to handle potentially long-running operations.
+/// consistently across Android AVDs and iOS simulators. It is object safe,
+/// so the app can hold its managers as `Arc<dyn DeviceManager>` and handle
+/// both platforms through one code path.
 ///
-/// # Type Parameters
-/// * `Device` - The platform-specific device type (AndroidDevice, IosDevice)
+/// Devices are listed as [`ManagedDevice`]. The concrete managers also have
+/// an inherent `list_devices` returning their own device type, which method
+/// calls on a concrete manager resolve to.
 ///
 /// # Device Lifecycle
 /// 1. `list_devices()` - Discover available devices
@@ -28,9 +157,17 @@ use std::collections::HashMap;
 /// # Error Handling
 /// All methods return `Result<T>` to handle platform-specific errors
 /// like missing SDKs, invalid configurations, or device conflicts.
-pub trait DeviceManager {
-    /// The platform-specific device type this manager handles
-    type Device;
+#[async_trait::async_trait]
+pub trait DeviceManager: Send + Sync {
+    /// The platform whose devices this manager handles.
+    fn platform(&self) -> Platform;
+
+    /// The operations the devices of this platform support. The key
+    /// handlers and the shortcut bar only offer these, so platform checks
+    /// do not have to be repeated for every action.
+    fn capabilities(&self) -> DeviceCapabilities {
+        DeviceCapabilities::for_platform(self.platform())
+    }
 
     /// Lists all available devices for this platform.
     ///
@@ -38,9 +175,9 @@ pub trait DeviceManager {
     /// list is sorted by device priority for optimal user experience.
     ///
     /// # Returns
-    /// * `Ok(Vec<Device>)` - List of available devices
+    /// * `Ok(Vec<ManagedDevice>)` - List of available devices
     /// * `Err(anyhow::Error)` - If device discovery fails
-    fn list_devices(&self) -> impl std::future::Future<Output = Result<Vec<Self::Device>>> + Send;
+    async fn list_devices(&self) -> Result<Vec<ManagedDevice>>;
 
     /// Starts a virtual device by its identifier.
     ///
@@ -54,10 +191,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `Ok(())` - If device starts successfully
     /// * `Err(anyhow::Error)` - If device start fails or device not found
-    fn start_device(
-        &self,
-        identifier: &str,
-    ) -> impl std::future::Future<Output = Result<()>> + Send;
+    async fn start_device(&self, identifier: &str) -> Result<()>;
 
     /// Stops a running virtual device.
     ///
@@ -70,8 +204,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `Ok(())` - If device stops successfully or was already stopped
     /// * `Err(anyhow::Error)` - If stop operation fails
-    fn stop_device(&self, identifier: &str)
-        -> impl std::future::Future<Output = Result<()>> + Send;
+    async fn stop_device(&self, identifier: &str) -> Result<()>;
 
     /// Creates a new virtual device with the specified configuration.
     ///
@@ -84,10 +217,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `Ok(())` - If device creation succeeds
     /// * `Err(anyhow::Error)` - If creation fails (name conflict, missing image, etc.)
-    fn create_device(
-        &self,
-        config: &DeviceConfig,
-    ) -> impl std::future::Future<Output = Result<()>> + Send;
+    async fn create_device(&self, config: &DeviceConfig) -> Result<()>;
 
     /// Permanently deletes a virtual device.
     ///
@@ -100,10 +230,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `Ok(())` - If device deletion succeeds
     /// * `Err(anyhow::Error)` - If deletion fails or device not found
-    fn delete_device(
-        &self,
-        identifier: &str,
-    ) -> impl std::future::Future<Output = Result<()>> + Send;
+    async fn delete_device(&self, identifier: &str) -> Result<()>;
 
     /// Wipes a virtual device, resetting it to factory state.
     ///
@@ -116,8 +243,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `Ok(())` - If device wipe succeeds
     /// * `Err(anyhow::Error)` - If wipe operation fails
-    fn wipe_device(&self, identifier: &str)
-        -> impl std::future::Future<Output = Result<()>> + Send;
+    async fn wipe_device(&self, identifier: &str) -> Result<()>;
 
     /// Checks if the platform's development tools are available.
     ///
@@ -127,7 +253,7 @@ pub trait DeviceManager {
     /// # Returns
     /// * `true` - If platform tools are available and functional
     /// * `false` - If platform is not supported or tools are missing
-    fn is_available(&self) -> impl std::future::Future<Output = bool> + Send;
+    async fn is_available(&self) -> bool;
 }
 
 /// Configuration for creating new virtual devices.
@@ -457,41 +583,37 @@ pub async fn check_tool_version(tool_path: &std::path::Path) -> Result<String> {
     Ok(version)
 }
 
-/// Unified device manager trait for use with trait objects.
-///
-/// This trait provides a simplified interface that works with trait objects,
-/// using boxed Device objects instead of associated types. This allows for
-/// dependency injection and easier testing with mock implementations.
-#[async_trait::async_trait]
-pub trait UnifiedDeviceManager: Send + Sync {
-    /// Lists all available devices for this platform.
-    ///
-    /// Returns devices as trait objects to enable platform-agnostic handling.
-    async fn list_devices(&self) -> Result<Vec<Box<dyn Device>>>;
-
-    /// Starts a virtual device by its identifier.
-    async fn start_device(&self, device_id: &str) -> Result<()>;
-
-    /// Stops a virtual device by its identifier.
-    async fn stop_device(&self, device_id: &str) -> Result<()>;
-
-    /// Creates a new virtual device with the given configuration.
-    async fn create_device(&self, config: &DeviceConfig) -> Result<()>;
-
-    /// Permanently deletes a virtual device and its data.
-    async fn delete_device(&self, device_id: &str) -> Result<()>;
-
-    /// Wipes a virtual device to factory defaults.
-    async fn wipe_device(&self, device_id: &str) -> Result<()>;
-
-    /// Checks if the required tools for this platform are available.
-    async fn is_available(&self) -> bool;
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_device_locks_refuse_conflicting_operations() {
+        let locks = DeviceLocks::default();
+        let wipe = locks.acquire("Pixel_7", "wiping").unwrap();
+        let error = locks.acquire("Pixel_7", "deleting").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Pixel_7 is busy wiping, try again once it finishes"
+        );
+        assert!(locks.acquire("Pixel_8", "deleting").is_ok());
+        drop(wipe);
+        assert_eq!(locks.running("Pixel_7"), None);
+
+        let start = locks.acquire("Pixel_7", "starting").unwrap();
+        locks.hold("Pixel_7", "booting", Duration::from_secs(60));
+        drop(start);
+        assert_eq!(locks.running("Pixel_7"), Some("booting"));
+        locks.release("Pixel_7", "wiping");
+        assert_eq!(locks.running("Pixel_7"), Some("booting"));
+        locks.release("Pixel_7", "booting");
+        assert!(locks.acquire("Pixel_7", "wiping").is_ok());
+
+        locks.hold("Pixel_7", "booting", Duration::ZERO);
+        assert_eq!(locks.running("Pixel_7"), None);
+        assert!(locks.acquire("Pixel_7", "wiping").is_ok());
+    }
+
     #[test]
     fn test_format_device_name() {
         assert_eq!(format_device_name("Short", 20), "Short");
@@ -1,6 +1,15 @@
 #[cfg(target_os = "macos")]
 use super::IosManager;
 #[cfg(target_os = "macos")]
+use crate::constants::commands::{
+    defaults,
+    ios::{
+        APP_CONTAINER, DATA_CONTAINER, GET_APP_CONTAINER, INSTALL, LAUNCH, LAUNCH_CONSOLE_PTY,
+        LAUNCH_ENV_PREFIX, LAUNCH_TERMINATE_RUNNING, SPAWN, TERMINATE,
+    },
+    launchctl, DEFAULTS, DITTO, SIMCTL, XCRUN,
+};
+#[cfg(target_os = "macos")]
 use crate::constants::ios::{
     IOS_DEVICE_STATUS_BOOTED, IOS_DEVICE_STATUS_CREATING, IOS_DEVICE_STATUS_SHUTDOWN,
     IOS_RUNTIME_PREFIX,
@@ -8,57 +17,39 @@ use crate::constants::ios::{
 #[cfg(target_os = "macos")]
 use crate::constants::{
     defaults::UNKNOWN_VALUE,
+    files::APP_COPY_DIR_PREFIX,
     ios_devices::{
         DEVICE_KEYWORD_AIR, DEVICE_KEYWORD_IPAD, DEVICE_KEYWORD_IPHONE, DEVICE_KEYWORD_MINI,
         DEVICE_KEYWORD_PLUS, DEVICE_KEYWORD_PRO, DEVICE_KEYWORD_PRO_MAX, DEVICE_KEYWORD_SE,
         DEVICE_SIZE_11, DEVICE_SIZE_12_9, DEVICE_VERSION_13, DEVICE_VERSION_14, DEVICE_VERSION_15,
         DEVICE_VERSION_16,
     },
+    messages::device_busy,
     numeric::BYTES_PER_MB,
     resolutions::*,
 };
 #[cfg(target_os = "macos")]
-use crate::models::{DeviceStatus, IosDevice};
-#[cfg(target_os = "macos")]
-use anyhow::{Context, Result};
+use crate::models::{
+    process::parse_launchctl_list, simctl::parse_listapps_bundle_ids, AppLaunch, DeveloperToggle,
+    DeveloperToggleStatus, DeviceProcess, DeviceService, DeviceStatus, IosDevice, SimctlDevice,
+};
 #[cfg(target_os = "macos")]
-use serde_json::Value;
+use anyhow::{bail, Context, Result};
 #[cfg(target_os = "macos")]
 use std::path::Path;
 
 #[cfg(target_os = "macos")]
 impl IosManager {
-    pub(super) fn parse_device_from_json(
-        &self,
-        device_json: &Value,
-        runtime_str: &str,
-    ) -> Result<Option<IosDevice>> {
-        let device_name = device_json
-            .get("name")
-            .and_then(|v| v.as_str())
-            .unwrap_or(UNKNOWN_VALUE);
-        let udid = device_json
-            .get("udid")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-        if udid.is_empty() {
-            return Ok(None);
-        }
-
-        let state_str = device_json
-            .get("state")
-            .and_then(|v| v.as_str())
-            .unwrap_or(UNKNOWN_VALUE);
-        let is_available_json = device_json
-            .get("isAvailable")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-        let device_type_identifier = device_json
-            .get("deviceTypeIdentifier")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Unknown")
-            .to_string();
+    pub(super) fn parse_device(&self, device: &SimctlDevice, runtime_str: &str) -> IosDevice {
+        let device_name = if device.name.is_empty() {
+            UNKNOWN_VALUE
+        } else {
+            device.name.as_str()
+        };
+        let device_type_identifier = device
+            .device_type_identifier
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
 
         let ios_version_str = runtime_str
             .replace(IOS_RUNTIME_PREFIX, "")
@@ -67,108 +58,426 @@ impl IosManager {
         let ios_version_display = ios_version_str.replace("iOS.", "");
         let name = format!("{device_name} (iOS {ios_version_display})");
 
-        let status = match state_str {
+        let status = match device.state.as_str() {
             IOS_DEVICE_STATUS_BOOTED => DeviceStatus::Running,
             IOS_DEVICE_STATUS_SHUTDOWN => DeviceStatus::Stopped,
             IOS_DEVICE_STATUS_CREATING => DeviceStatus::Creating,
             _ => DeviceStatus::Unknown,
         };
-        let is_running_bool = state_str == IOS_DEVICE_STATUS_BOOTED;
+        let is_running_bool = device.state == IOS_DEVICE_STATUS_BOOTED;
 
-        Ok(Some(IosDevice {
+        IosDevice {
             name,
-            udid,
+            udid: device.udid.clone(),
             device_type: device_type_identifier,
             ios_version: ios_version_str.clone(),
             runtime_version: ios_version_str,
             status,
             is_running: is_running_bool,
-            is_available: is_available_json,
-        }))
+            is_available: device.is_available,
+            availability_error: device.availability_error.clone(),
+        }
     }
 
     pub async fn get_device_details(&self, udid: &str) -> Result<crate::models::DeviceDetails> {
-        let device_output = self
+        let device_list = self
+            .device_list()
+            .await
+            .context("Failed to get device list")?;
+        let (runtime, device) = device_list
+            .find(udid)
+            .ok_or_else(|| anyhow::anyhow!("Device with UDID {udid} not found"))?;
+
+        let name = if device.name.is_empty() {
+            UNKNOWN_VALUE.to_string()
+        } else {
+            device.name.clone()
+        };
+        let state = if device.state.is_empty() {
+            UNKNOWN_VALUE.to_string()
+        } else {
+            device.state.clone()
+        };
+
+        let version = runtime
+            .replace("com.apple.CoreSimulator.SimRuntime.iOS-", "")
+            .replace("-", ".");
+
+        let device_type = device
+            .device_type_identifier
+            .as_deref()
+            .map(Self::parse_device_type_display_name)
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let storage_size = device
+            .data_path_size
+            .map(|size| format!("{} MB", size / BYTES_PER_MB));
+
+        let resolution = self.get_device_resolution(&device_type);
+
+        Ok(crate::models::DeviceDetails {
+            name,
+            status: state,
+            platform: crate::models::Platform::Ios,
+            device_type,
+            api_level_or_version: format!("iOS {version}"),
+            ram_size: None,
+            storage_size,
+            resolution,
+            dpi: Some(RETINA_DISPLAY.to_string()),
+            device_path: device.data_path.clone(),
+            system_image: None,
+            identifier: udid.to_string(),
+        })
+    }
+
+    /// Simulator settings reported by simctl, as (key, value) pairs.
+    pub async fn device_config(&self, udid: &str) -> Result<Vec<(String, String)>> {
+        let device_list = self
+            .device_list()
+            .await
+            .context("Failed to get device list")?;
+        let (runtime, device) = device_list
+            .find(udid)
+            .ok_or_else(|| anyhow::anyhow!("Device with UDID {udid} not found"))?;
+
+        let mut entries = vec![
+            ("udid".to_string(), device.udid.clone()),
+            ("name".to_string(), device.name.clone()),
+            ("state".to_string(), device.state.clone()),
+            ("runtime".to_string(), runtime.to_string()),
+            ("isAvailable".to_string(), device.is_available.to_string()),
+        ];
+        if let Some(error) = &device.availability_error {
+            entries.push(("availabilityError".to_string(), error.clone()));
+        }
+        if let Some(device_type) = &device.device_type_identifier {
+            entries.push(("deviceTypeIdentifier".to_string(), device_type.clone()));
+        }
+        if let Some(data_path) = &device.data_path {
+            entries.push(("dataPath".to_string(), data_path.clone()));
+        }
+        Ok(entries)
+    }
+
+    /// Lists the bundle identifiers installed on a booted simulator.
+    pub async fn list_installed_apps(&self, udid: &str) -> Result<Vec<String>> {
+        let output = self
             .command_executor
+            .run(Path::new(XCRUN), &[SIMCTL, "listapps", udid])
+            .await
+            .context(format!(
+                "Failed to list apps on iOS device {udid}. Make sure it is booted."
+            ))?;
+        Ok(parse_listapps_bundle_ids(&output))
+    }
+
+    /// Lists the running launchd jobs of a booted simulator as processes.
+    /// launchctl reports no memory, so `memory_kb` stays `None`.
+    pub async fn list_processes(&self, udid: &str) -> Result<Vec<DeviceProcess>> {
+        Ok(self
+            .launchctl_jobs(udid)
+            .await?
+            .into_iter()
+            .filter_map(|(pid, label)| {
+                Some(DeviceProcess {
+                    pid: pid?,
+                    name: label,
+                    user: None,
+                    memory_kb: None,
+                })
+            })
+            .collect())
+    }
+
+    /// Lists every launchd job of a booted simulator, running or not.
+    pub async fn list_services(&self, udid: &str) -> Result<Vec<DeviceService>> {
+        let mut services: Vec<DeviceService> = self
+            .launchctl_jobs(udid)
+            .await?
+            .into_iter()
+            .map(|(pid, name)| DeviceService { name, pid })
+            .collect();
+        services.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(services)
+    }
+
+    /// Stops a launchd job of a booted simulator. launchd starts jobs marked
+    /// KeepAlive again, as it would on a device.
+    pub async fn kill_process(&self, udid: &str, process: &DeviceProcess) -> Result<()> {
+        self.command_executor
             .run(
-                Path::new(crate::constants::commands::XCRUN),
-                &[crate::constants::commands::SIMCTL, "list", "devices", "-j"],
+                Path::new(XCRUN),
+                &[
+                    SIMCTL,
+                    SPAWN,
+                    udid,
+                    launchctl::LAUNCHCTL,
+                    launchctl::STOP,
+                    &process.name,
+                ],
             )
             .await
-            .context("Failed to get device list")?;
+            .with_context(|| format!("Failed to stop {} on iOS device {udid}", process.name))?;
+        Ok(())
+    }
 
-        let json: Value =
-            serde_json::from_str(&device_output).context("Failed to parse device JSON")?;
-
-        let mut device_details = None;
-
-        if let Some(devices) = json.get("devices").and_then(|v| v.as_object()) {
-            for (runtime, device_list) in devices {
-                if let Some(devices_array) = device_list.as_array() {
-                    for device in devices_array {
-                        if let Some(device_udid) = device.get("udid").and_then(|v| v.as_str()) {
-                            if device_udid == udid {
-                                let name = device
-                                    .get("name")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or(UNKNOWN_VALUE)
-                                    .to_string();
-
-                                let state = device
-                                    .get("state")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or(UNKNOWN_VALUE)
-                                    .to_string();
-
-                                let version = runtime
-                                    .replace("com.apple.CoreSimulator.SimRuntime.iOS-", "")
-                                    .replace("-", ".");
-
-                                let device_type = device
-                                    .get("deviceTypeIdentifier")
-                                    .and_then(|v| v.as_str())
-                                    .map(Self::parse_device_type_display_name)
-                                    .unwrap_or_else(|| "Unknown".to_string());
-
-                                let storage_size = device
-                                    .get("dataPathSize")
-                                    .and_then(|v| v.as_u64())
-                                    .map(|size| format!("{} MB", size / BYTES_PER_MB));
-
-                                let device_path = device
-                                    .get("dataPath")
-                                    .and_then(|v| v.as_str())
-                                    .map(|s| s.to_string());
-
-                                let resolution = self.get_device_resolution(&device_type);
-
-                                device_details = Some(crate::models::DeviceDetails {
-                                    name: name.clone(),
-                                    status: state,
-                                    platform: crate::models::Platform::Ios,
-                                    device_type,
-                                    api_level_or_version: format!("iOS {version}"),
-                                    ram_size: None,
-                                    storage_size,
-                                    resolution,
-                                    dpi: Some(RETINA_DISPLAY.to_string()),
-                                    device_path,
-                                    system_image: None,
-                                    identifier: udid.to_string(),
-                                });
-
-                                break;
-                            }
-                        }
-                    }
-                    if device_details.is_some() {
-                        break;
-                    }
-                }
+    /// Launches an app on a booted simulator, restarting it if it runs, and
+    /// passes each line it prints to `on_line` until it exits. Its variables
+    /// reach it as `SIMCTL_CHILD_` variables of simctl.
+    pub async fn launch_app_with_console(
+        &self,
+        udid: &str,
+        launch: &AppLaunch,
+        on_line: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<()> {
+        let arguments = launch.argument_list();
+        let mut args = vec![
+            SIMCTL,
+            LAUNCH,
+            LAUNCH_CONSOLE_PTY,
+            LAUNCH_TERMINATE_RUNNING,
+            udid,
+            &launch.app,
+        ];
+        args.extend(arguments.iter().map(String::as_str));
+        let envs: Vec<(String, String)> = launch
+            .environment
+            .iter()
+            .map(|(key, value)| (format!("{LAUNCH_ENV_PREFIX}{key}"), value.clone()))
+            .collect();
+        self.command_executor
+            .run_streaming_with_env(Path::new(XCRUN), &args, &envs, on_line)
+            .await
+            .with_context(|| format!("Failed to launch {} on iOS device {udid}", launch.app))?;
+        Ok(())
+    }
+
+    /// Quits an app on a booted simulator.
+    pub async fn terminate_app(&self, udid: &str, bundle_id: &str) -> Result<()> {
+        self.command_executor
+            .run(Path::new(XCRUN), &[SIMCTL, TERMINATE, udid, bundle_id])
+            .await
+            .with_context(|| format!("Failed to quit {bundle_id} on iOS device {udid}"))?;
+        Ok(())
+    }
+
+    /// Reads whether the Metal HUD and slow animations of a booted simulator
+    /// are on.
+    pub async fn developer_toggle_status(&self, udid: &str) -> Result<DeveloperToggleStatus> {
+        let hud = self
+            .command_executor
+            .run(
+                Path::new(XCRUN),
+                &[
+                    SIMCTL,
+                    SPAWN,
+                    udid,
+                    launchctl::LAUNCHCTL,
+                    launchctl::GETENV,
+                    DeveloperToggle::MetalHud.property(),
+                ],
+            )
+            .await
+            .with_context(|| format!("Failed to read the Metal HUD of iOS device {udid}"))?;
+        // `defaults read` fails while the default is unset
+        let coefficient = self
+            .command_executor
+            .run(
+                Path::new(XCRUN),
+                &[
+                    SIMCTL,
+                    SPAWN,
+                    udid,
+                    DEFAULTS,
+                    defaults::READ,
+                    defaults::GLOBAL_DOMAIN,
+                    DeveloperToggle::SlowAnimations.property(),
+                ],
+            )
+            .await
+            .unwrap_or_default();
+
+        let mut status = DeveloperToggleStatus::default();
+        status.set(
+            DeveloperToggle::MetalHud,
+            DeveloperToggle::MetalHud.parse_value(&hud),
+        );
+        status.set(
+            DeveloperToggle::SlowAnimations,
+            DeveloperToggle::SlowAnimations.parse_value(&coefficient),
+        );
+        Ok(status)
+    }
+
+    /// Switches the Metal HUD or slow animations of a booted simulator. Apps
+    /// launched afterwards pick the change up.
+    pub async fn set_developer_toggle(
+        &self,
+        udid: &str,
+        toggle: DeveloperToggle,
+        enabled: bool,
+    ) -> Result<()> {
+        let property = toggle.property();
+        let command: Vec<&str> = match (toggle, toggle.value(enabled)) {
+            (DeveloperToggle::MetalHud, Some(value)) => {
+                vec![launchctl::LAUNCHCTL, launchctl::SETENV, property, value]
+            }
+            (DeveloperToggle::MetalHud, None) => {
+                vec![launchctl::LAUNCHCTL, launchctl::UNSETENV, property]
             }
+            (DeveloperToggle::SlowAnimations, Some(value)) => vec![
+                DEFAULTS,
+                defaults::WRITE,
+                defaults::GLOBAL_DOMAIN,
+                property,
+                defaults::FLOAT,
+                value,
+            ],
+            (DeveloperToggle::SlowAnimations, None) => {
+                vec![
+                    DEFAULTS,
+                    defaults::DELETE,
+                    defaults::GLOBAL_DOMAIN,
+                    property,
+                ]
+            }
+            _ => bail!("{} is not a simulator toggle", toggle.label()),
+        };
+        let mut args = vec![SIMCTL, SPAWN, udid];
+        args.extend(command);
+        self.command_executor
+            .run(Path::new(XCRUN), &args)
+            .await
+            .with_context(|| format!("Failed to switch {} of iOS device {udid}", toggle.label()))?;
+        Ok(())
+    }
+
+    /// Copies the data container of an app on a booted simulator to the
+    /// directory `path`.
+    pub async fn backup_app_data(&self, udid: &str, bundle_id: &str, path: &Path) -> Result<()> {
+        let _lock = self
+            .device_locks
+            .acquire(udid, device_busy::BACKING_UP_APP_DATA)?;
+        self.archive_app_data(udid, bundle_id, path).await
+    }
+
+    /// Replaces the data container of an app on a booted simulator with the
+    /// copy at `path`. The app is quit first.
+    pub async fn restore_app_data(&self, udid: &str, bundle_id: &str, path: &Path) -> Result<()> {
+        let _lock = self
+            .device_locks
+            .acquire(udid, device_busy::RESTORING_APP_DATA)?;
+        self.unpack_app_data(udid, bundle_id, path).await
+    }
+
+    async fn archive_app_data(&self, udid: &str, bundle_id: &str, path: &Path) -> Result<()> {
+        let container = self.app_container(udid, bundle_id, DATA_CONTAINER).await?;
+        let path_arg = path.to_string_lossy();
+        self.command_executor
+            .run(Path::new(DITTO), &[&container, &path_arg])
+            .await
+            .with_context(|| format!("Failed to copy the data of {bundle_id}"))?;
+        Ok(())
+    }
+
+    async fn unpack_app_data(&self, udid: &str, bundle_id: &str, path: &Path) -> Result<()> {
+        let container = self.app_container(udid, bundle_id, DATA_CONTAINER).await?;
+        // Fails when the app is not running
+        if let Err(e) = self.terminate_app(udid, bundle_id).await {
+            log::debug!("{e:#}");
         }
+        let mut entries = tokio::fs::read_dir(&container)
+            .await
+            .with_context(|| format!("Failed to read {container}"))?;
+        while let Some(entry) = entries.next_entry().await? {
+            let entry_path = entry.path();
+            let removed = if entry.file_type().await?.is_dir() {
+                tokio::fs::remove_dir_all(&entry_path).await
+            } else {
+                tokio::fs::remove_file(&entry_path).await
+            };
+            removed.with_context(|| format!("Failed to remove {}", entry_path.display()))?;
+        }
+        let path_arg = path.to_string_lossy();
+        self.command_executor
+            .run(Path::new(DITTO), &[&path_arg, &container])
+            .await
+            .with_context(|| format!("Failed to copy the data of {bundle_id} back"))?;
+        Ok(())
+    }
 
-        device_details.ok_or_else(|| anyhow::anyhow!("Device with UDID {udid} not found"))
+    /// Installs an app of one booted simulator on another from its bundle,
+    /// along with a copy of its data container when `with_data` is set.
+    pub async fn copy_app(
+        &self,
+        source_udid: &str,
+        target_udid: &str,
+        bundle_id: &str,
+        with_data: bool,
+    ) -> Result<()> {
+        let _source_lock = self
+            .device_locks
+            .acquire(source_udid, device_busy::COPYING_APP)?;
+        let _target_lock = self
+            .device_locks
+            .acquire(target_udid, device_busy::INSTALLING_APP)?;
+        let app_bundle = self
+            .app_container(source_udid, bundle_id, APP_CONTAINER)
+            .await?;
+        self.command_executor
+            .run(
+                Path::new(XCRUN),
+                &[SIMCTL, INSTALL, target_udid, &app_bundle],
+            )
+            .await
+            .with_context(|| {
+                format!("Failed to install {bundle_id} on iOS device {target_udid}")
+            })?;
+        if !with_data {
+            return Ok(());
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "{APP_COPY_DIR_PREFIX}{bundle_id}-{}",
+            std::process::id()
+        ));
+        let copied = async {
+            self.archive_app_data(source_udid, bundle_id, &dir).await?;
+            self.unpack_app_data(target_udid, bundle_id, &dir).await
+        }
+        .await;
+        if let Err(e) = tokio::fs::remove_dir_all(&dir).await {
+            log::debug!("Failed to remove {}: {e:#}", dir.display());
+        }
+        copied
+    }
+
+    /// Host path of an app's bundle (`APP_CONTAINER`) or data container.
+    async fn app_container(&self, udid: &str, bundle_id: &str, container: &str) -> Result<String> {
+        let output = self
+            .command_executor
+            .run(
+                Path::new(XCRUN),
+                &[SIMCTL, GET_APP_CONTAINER, udid, bundle_id, container],
+            )
+            .await
+            .with_context(|| format!("{bundle_id} is not installed on iOS device {udid}"))?;
+        Ok(output.trim().to_string())
+    }
+
+    async fn launchctl_jobs(&self, udid: &str) -> Result<Vec<(Option<u32>, String)>> {
+        let output = self
+            .command_executor
+            .run(
+                Path::new(XCRUN),
+                &[SIMCTL, SPAWN, udid, launchctl::LAUNCHCTL, launchctl::LIST],
+            )
+            .await
+            .context(format!(
+                "Failed to list processes on iOS device {udid}. Make sure it is booted."
+            ))?;
+        Ok(parse_launchctl_list(&output))
     }
 
     pub(super) fn get_device_resolution(&self, device_type: &str) -> Option<String> {
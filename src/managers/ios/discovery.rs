@@ -4,15 +4,16 @@ use super::{extract_ios_version, IosManager};
 use crate::constants::ios::{IOS_DEVICE_TYPE_PREFIX, IOS_INCH_PATTERN, IOS_INCH_REPLACEMENT};
 #[cfg(target_os = "macos")]
 use crate::constants::{
-    commands::{SIMCTL, XCRUN},
+    commands::{xcodebuild, SIMCTL, XCODEBUILD, XCRUN},
     ios_devices::*,
+    messages::read_only,
     patterns::text_patterns::{
         APPLE_DEVICE_IPAD, APPLE_DEVICE_IPHONE, APPLE_DEVICE_IPOD, APPLE_DEVICE_PREFIX_I,
         CHIP_PREFIX_A, CHIP_PREFIX_M, INCH_INDICATOR, MEMORY_CLOSE_BRACKET, MEMORY_OPEN_BRACKET,
     },
 };
 #[cfg(target_os = "macos")]
-use crate::models::device_info::DynamicDeviceConfig;
+use crate::models::{device_info::DynamicDeviceConfig, DeviceError, SimctlRuntimeList};
 #[cfg(target_os = "macos")]
 use anyhow::{Context, Result};
 #[cfg(target_os = "macos")]
@@ -28,8 +29,8 @@ impl IosManager {
             .run(Path::new(XCRUN), &[SIMCTL, "list", "devicetypes", "--json"])
             .await
             .context("Failed to list device types")?;
-        let json: Value =
-            serde_json::from_str(&output).context("Failed to parse device types JSON")?;
+        let json: Value = serde_json::from_str(&output)
+            .map_err(|e| DeviceError::parse_failure("simctl device types", e))?;
         let mut device_types = Vec::new();
         if let Some(types_array) = json.get("devicetypes").and_then(|v| v.as_array()) {
             for device_type_json in types_array {
@@ -49,8 +50,8 @@ impl IosManager {
             .run(Path::new(XCRUN), &[SIMCTL, "list", "devicetypes", "--json"])
             .await
             .context("Failed to list device types")?;
-        let json: Value =
-            serde_json::from_str(&output).context("Failed to parse device types JSON")?;
+        let json: Value = serde_json::from_str(&output)
+            .map_err(|e| DeviceError::parse_failure("simctl device types", e))?;
         let mut device_types = Vec::new();
 
         if let Some(types_array) = json.get("devicetypes").and_then(|v| v.as_array()) {
@@ -141,34 +142,34 @@ impl IosManager {
             .run(Path::new(XCRUN), &[SIMCTL, "list", "runtimes", "--json"])
             .await
             .context("Failed to list runtimes")?;
-        let json: Value = serde_json::from_str(&output).context("Failed to parse runtimes JSON")?;
+        let runtime_list = SimctlRuntimeList::parse(&output)?;
         let mut runtimes = Vec::new();
-        if let Some(runtimes_array) = json.get("runtimes").and_then(|v| v.as_array()) {
-            for runtime_json in runtimes_array {
-                if let Some(identifier) = runtime_json.get("identifier").and_then(|v| v.as_str()) {
-                    if runtime_json
-                        .get("isAvailable")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false)
-                    {
-                        let display_name =
-                            if let Some(name) = runtime_json.get("name").and_then(|v| v.as_str()) {
-                                name.to_string()
-                            } else if let Some(version) =
-                                runtime_json.get("version").and_then(|v| v.as_str())
-                            {
-                                format!("iOS {version}")
-                            } else {
-                                identifier
-                                    .replace("com.apple.CoreSimulator.SimRuntime.", "")
-                                    .replace("-", ".")
-                                    .replace("iOS.", "iOS ")
-                            };
-
-                        runtimes.push((identifier.to_string(), display_name));
-                    }
-                }
+        for runtime in runtime_list.runtimes {
+            if runtime.identifier.is_empty() {
+                continue;
             }
+            if !runtime.is_available {
+                log::debug!(
+                    "Skipping unavailable runtime {}: {}",
+                    runtime.identifier,
+                    runtime.availability_error.as_deref().unwrap_or("unknown")
+                );
+                continue;
+            }
+
+            let display_name = if let Some(name) = runtime.name {
+                name
+            } else if let Some(version) = runtime.version {
+                format!("iOS {version}")
+            } else {
+                runtime
+                    .identifier
+                    .replace("com.apple.CoreSimulator.SimRuntime.", "")
+                    .replace("-", ".")
+                    .replace("iOS.", "iOS ")
+            };
+
+            runtimes.push((runtime.identifier, display_name));
         }
 
         runtimes.sort_by(|a, b| {
@@ -181,4 +182,24 @@ impl IosManager {
 
         Ok(runtimes)
     }
+
+    /// Downloads and installs the iOS runtime with the given version (e.g.,
+    /// "17.0") through Xcode. This can take several minutes.
+    pub async fn install_runtime(&self, version: &str) -> Result<()> {
+        self.read_only.ensure_writable(read_only::INSTALL_RUNTIME)?;
+        self.command_executor
+            .run(
+                Path::new(XCODEBUILD),
+                &[
+                    xcodebuild::DOWNLOAD_PLATFORM,
+                    xcodebuild::PLATFORM_IOS,
+                    xcodebuild::BUILD_VERSION,
+                    version,
+                ],
+            )
+            .await
+            .with_context(|| format!("Failed to install the iOS {version} runtime"))?;
+        self.invalidate_device_list_snapshot().await;
+        Ok(())
+    }
 }
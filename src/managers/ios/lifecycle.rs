@@ -1,24 +1,46 @@
 #[cfg(target_os = "macos")]
-use super::IosManager;
+use super::{DeviceListSnapshot, IosManager};
 #[cfg(target_os = "macos")]
 use crate::constants::{
-    commands::{KILLALL, OSASCRIPT, SIMCTL, XCRUN},
+    commands::{
+        defaults,
+        ios::{
+            CLONE, DELETE, DIAGNOSE, DIAGNOSE_ALL_LOGS, DIAGNOSE_NO_FINDER, DIAGNOSE_OUTPUT_PREFIX,
+            DIAGNOSE_UDID_PREFIX, GETENV, HOME_ENV, LOGGER, LOGGER_TAG, SHUTDOWN, SPAWN,
+            STATUS_BAR, STATUS_BAR_CLEAR, STATUS_BAR_OVERRIDE, STATUS_BAR_TIME,
+            STATUS_BAR_TIME_FORMAT, UNAVAILABLE,
+        },
+        tcpdump, DEFAULTS, KILLALL, OPEN, OSASCRIPT, SIMCTL, TCPDUMP, XCRUN,
+    },
+    files::{
+        DIAGNOSE_ARCHIVE_EXTENSION, DIAGNOSE_FILE_PREFIX, TRACE_FILE_PREFIX, XCTRACE_EXTENSION,
+    },
     ios::{
-        IOS_ALREADY_BOOTED_ERROR, IOS_ALREADY_SHUTDOWN_ERROR, IOS_DEVICE_STATUS_BOOTED,
-        SIMULATOR_APP_NAME, SIMULATOR_OPEN_FLAG, SIMULATOR_QUIT_COMMAND,
+        CONNECT_HARDWARE_KEYBOARD_KEY, IOS_ALREADY_BOOTED_ERROR, IOS_ALREADY_SHUTDOWN_ERROR,
+        IOS_DEVICE_STATUS_BOOTED, SIMULATOR_APP_NAME, SIMULATOR_ARGS_FLAG,
+        SIMULATOR_CURRENT_DEVICE_FLAG, SIMULATOR_DEFAULTS_DOMAIN, SIMULATOR_OPEN_FLAG,
+        SIMULATOR_QUIT_COMMAND,
     },
-    numeric::IOS_DEVICE_PARSE_BATCH_SIZE,
+    messages::{device_busy, read_only},
+    performance::IOS_DEVICE_LIST_SNAPSHOT_TTL,
+    progress::{CREATE_CONFIGURE_PERCENTAGE, CREATE_RESOLVE_PERCENTAGE},
+    timeouts::DEVICE_STOP_TIMEOUT,
 };
 #[cfg(target_os = "macos")]
 use crate::managers::common::DeviceConfig;
 #[cfg(target_os = "macos")]
-use crate::models::{device_info::DynamicDeviceConfig, IosDevice};
+use crate::models::{
+    device_info::DynamicDeviceConfig, ClockChange, DiagnoseScope, InstallProgress, IosDevice,
+    SimctlDeviceList, TestSettingsBackup, TraceCapture,
+};
 #[cfg(target_os = "macos")]
-use anyhow::{Context, Result};
+use crate::utils::{command_executor::CommandPolicy, tool_paths};
 #[cfg(target_os = "macos")]
-use serde_json::Value;
+use anyhow::{Context, Result};
 #[cfg(target_os = "macos")]
 use std::path::{Path, PathBuf};
+#[cfg(target_os = "macos")]
+use std::time::Instant;
 
 #[cfg(target_os = "macos")]
 impl IosManager {
@@ -61,82 +83,144 @@ impl IosManager {
             .run(Path::new(XCRUN), &[SIMCTL, "list", "devices", "--json"])
             .await
             .context("Failed to list iOS devices")?;
-        let json: Value =
-            serde_json::from_str(&output).context("Failed to parse simctl JSON output")?;
-
-        let mut devices = Vec::new();
-        if let Some(devices_obj) = json.get("devices") {
-            if let Some(devices_map) = devices_obj.as_object() {
-                let mut raw_devices = Vec::new();
-
-                for (runtime, device_list_json) in devices_map {
-                    if let Some(device_array_json) = device_list_json.as_array() {
-                        for device_json_val in device_array_json {
-                            raw_devices.push((device_json_val, runtime));
-                        }
-                    }
-                }
+        let list = SimctlDeviceList::parse(&output)?;
 
-                for batch in raw_devices.chunks(IOS_DEVICE_PARSE_BATCH_SIZE) {
-                    for (device_json_val, runtime) in batch {
-                        if let Some(parsed_device) =
-                            self.parse_device_from_json(device_json_val, runtime)?
-                        {
-                            devices.push(parsed_device);
-                        }
-                    }
-                }
+        let mut snapshot = self.device_list_snapshot.write().await;
+        if let Some(previous) = snapshot.as_mut() {
+            let diff = list.diff(&previous.list);
+            if diff.is_empty() {
+                previous.fetched_at = Instant::now();
+                return Ok(previous.devices.clone());
             }
+            log::debug!(
+                "iOS device list changed: {} added, {} removed, {} changed",
+                diff.added.len(),
+                diff.removed.len(),
+                diff.changed.len()
+            );
         }
 
+        let mut devices: Vec<IosDevice> = list
+            .iter()
+            .map(|(runtime, device)| self.parse_device(device, runtime))
+            .collect();
         devices.sort_by(|a, b| {
             let priority_a = DynamicDeviceConfig::calculate_ios_device_priority(&a.name);
             let priority_b = DynamicDeviceConfig::calculate_ios_device_priority(&b.name);
             priority_a.cmp(&priority_b)
         });
 
+        *snapshot = Some(DeviceListSnapshot {
+            list,
+            devices: devices.clone(),
+            fetched_at: Instant::now(),
+        });
         Ok(devices)
     }
 
+    /// Returns the parsed device list, reusing the last snapshot while it is
+    /// fresh so a single UI action does not list devices repeatedly.
+    pub(super) async fn device_list(&self) -> Result<SimctlDeviceList> {
+        {
+            let snapshot = self.device_list_snapshot.read().await;
+            if let Some(snapshot) = snapshot.as_ref() {
+                if snapshot.fetched_at.elapsed() < IOS_DEVICE_LIST_SNAPSHOT_TTL {
+                    return Ok(snapshot.list.clone());
+                }
+            }
+        }
+
+        self.list_devices_internal().await?;
+        let snapshot = self.device_list_snapshot.read().await;
+        Ok(snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.list.clone())
+            .unwrap_or_default())
+    }
+
+    /// Forces the next device list request to query simctl again.
+    pub(super) async fn invalidate_device_list_snapshot(&self) {
+        *self.device_list_snapshot.write().await = None;
+        self.device_list_refresh.invalidate();
+    }
+
     pub(super) async fn start_device_internal(&self, identifier: &str) -> Result<()> {
-        log::info!("Attempting to start iOS device: {identifier}");
+        self.boot_device(identifier).await?;
 
-        let status_output = self
+        if let Err(e) = self
             .command_executor
-            .run(Path::new(XCRUN), &[SIMCTL, "list", "devices", "-j"])
+            .spawn(
+                Path::new("open"),
+                &[SIMULATOR_OPEN_FLAG, SIMULATOR_APP_NAME],
+            )
             .await
-            .context("Failed to get device status")?;
-
-        let json: Value =
-            serde_json::from_str(&status_output).context("Failed to parse device status")?;
-
-        let mut is_already_booted = false;
-        if let Some(devices) = json.get("devices").and_then(|v| v.as_object()) {
-            for (_, device_list) in devices {
-                if let Some(devices_array) = device_list.as_array() {
-                    for device in devices_array {
-                        if let Some(udid) = device.get("udid").and_then(|v| v.as_str()) {
-                            if udid == identifier {
-                                if let Some(state) = device.get("state").and_then(|v| v.as_str()) {
-                                    if state == IOS_DEVICE_STATUS_BOOTED {
-                                        is_already_booted = true;
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+        {
+            log::warn!("Failed to open Simulator app: {e}. Device might be booting in headless mode or Simulator app needs to be opened manually.");
+        }
+
+        Ok(())
+    }
+
+    /// Boots a simulator without opening Simulator.app, e.g. for running
+    /// tests. [`focus_device_window`](Self::focus_device_window) shows it later.
+    pub async fn start_device_headless(&self, udid: &str) -> Result<()> {
+        let _lock = self.device_locks.acquire(udid, device_busy::STARTING)?;
+        self.boot_device(udid).await
+    }
+
+    /// Whether a booted simulator still answers `simctl getenv` in time.
+    pub async fn is_responsive(&self, udid: &str) -> Result<bool> {
+        let home = self
+            .command_executor
+            .run_with_policy(
+                Path::new(XCRUN),
+                &[SIMCTL, GETENV, udid, HOME_ENV],
+                &CommandPolicy::HEALTH_CHECK,
+            )
+            .await;
+        Ok(home.is_ok_and(|home| !home.trim().is_empty()))
+    }
+
+    /// Shuts a simulator down, giving up after `DEVICE_STOP_TIMEOUT`, and
+    /// boots it again.
+    pub async fn force_restart_device(&self, udid: &str) -> Result<()> {
+        let _lock = self.device_locks.acquire(udid, device_busy::RESTARTING)?;
+        let shutdown = self
+            .command_executor
+            .run_with_policy(
+                Path::new(XCRUN),
+                &[SIMCTL, SHUTDOWN, udid],
+                &CommandPolicy::new().with_timeout(DEVICE_STOP_TIMEOUT),
+            )
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        if let Err(e) = shutdown {
+            if !e.to_string().contains(IOS_ALREADY_SHUTDOWN_ERROR) {
+                return Err(e).context(format!("Failed to shutdown iOS device {udid}"));
             }
         }
+        self.start_device_internal(udid).await
+    }
+
+    async fn boot_device(&self, identifier: &str) -> Result<()> {
+        log::info!("Attempting to start iOS device: {identifier}");
+
+        let is_already_booted = self
+            .device_list()
+            .await
+            .context("Failed to get device status")?
+            .find(identifier)
+            .is_some_and(|(_, device)| device.state == IOS_DEVICE_STATUS_BOOTED);
 
         if is_already_booted {
             log::info!("Device {identifier} is already booted");
         } else {
+            let boot_command = Self::boot_command(identifier);
             let boot_result = self
                 .command_executor
-                .run(Path::new(XCRUN), &[SIMCTL, "boot", identifier])
+                .run(&boot_command.program, &boot_command.arg_refs())
                 .await;
+            self.invalidate_device_list_snapshot().await;
 
             match boot_result {
                 Ok(_) => log::info!("Successfully booted iOS device {identifier}"),
@@ -151,17 +235,6 @@ impl IosManager {
             }
         }
 
-        if let Err(e) = self
-            .command_executor
-            .spawn(
-                Path::new("open"),
-                &[SIMULATOR_OPEN_FLAG, SIMULATOR_APP_NAME],
-            )
-            .await
-        {
-            log::warn!("Failed to open Simulator app: {e}. Device might be booting in headless mode or Simulator app needs to be opened manually.");
-        }
-
         Ok(())
     }
 
@@ -172,6 +245,7 @@ impl IosManager {
             .command_executor
             .run(Path::new(XCRUN), &[SIMCTL, "shutdown", identifier])
             .await;
+        self.invalidate_device_list_snapshot().await;
 
         match shutdown_result {
             Ok(_) => {
@@ -192,35 +266,71 @@ impl IosManager {
         }
     }
 
-    pub(super) async fn create_device_internal(&self, config: &DeviceConfig) -> Result<()> {
+    /// Creates a simulator. simctl prints nothing until it prints the new
+    /// UDID, so progress moves from creating to registering at that point.
+    pub async fn create_device_with_progress(
+        &self,
+        config: &DeviceConfig,
+        on_progress: &(dyn Fn(InstallProgress) + Send + Sync),
+    ) -> Result<()> {
+        self.read_only.ensure_writable(read_only::CREATE_DEVICE)?;
         log::info!(
             "Attempting to create iOS device: {} of type {} with runtime {}",
             config.name,
             config.device_type,
             config.version
         );
-        let output = self
+        on_progress(InstallProgress {
+            operation: "Creating simulator...".to_string(),
+            percentage: CREATE_RESOLVE_PERCENTAGE,
+            eta_seconds: None,
+            bytes_per_second: None,
+        });
+        let on_line = |line: &str| {
+            if is_simulator_udid(line.trim()) {
+                on_progress(InstallProgress {
+                    operation: "Registering simulator...".to_string(),
+                    percentage: CREATE_CONFIGURE_PERCENTAGE,
+                    eta_seconds: None,
+                    bytes_per_second: None,
+                });
+            }
+        };
+        let create_command = Self::create_command(config);
+        let result = self
             .command_executor
-            .run(
-                Path::new(XCRUN),
-                &[
-                    SIMCTL,
-                    "create",
-                    &config.name,
-                    &config.device_type,
-                    &config.version,
-                ],
+            .run_streaming(
+                &create_command.program,
+                &create_command.arg_refs(),
+                &on_line,
             )
-            .await
-            .context(format!(
-                "Failed to create iOS device '{}' with type '{}' and runtime '{}'",
-                config.name, config.device_type, config.version
-            ))?;
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        let output = result.context(format!(
+            "Failed to create iOS device '{}' with type '{}' and runtime '{}'",
+            config.name, config.device_type, config.version
+        ))?;
         log::info!("Successfully created iOS device. UDID: {}", output.trim());
         Ok(())
     }
 
+    /// Copies a shut down simulator, data included, as `name` and returns
+    /// the UDID of the copy.
+    pub async fn clone_device(&self, udid: &str, name: &str) -> Result<String> {
+        self.read_only.ensure_writable(read_only::CREATE_DEVICE)?;
+        let result = self
+            .command_executor
+            .run(Path::new(XCRUN), &[SIMCTL, CLONE, udid, name])
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        let output = result.context(format!("Failed to clone iOS device {udid}"))?;
+        let clone_udid = output.trim().to_string();
+        log::info!("Cloned iOS device {udid} as {clone_udid}");
+        Ok(clone_udid)
+    }
+
     pub(super) async fn delete_device_internal(&self, identifier: &str) -> Result<()> {
+        self.read_only.ensure_writable(read_only::DELETE_DEVICE)?;
         log::info!("Attempting to delete iOS device: {identifier}");
 
         let _ = self
@@ -228,10 +338,12 @@ impl IosManager {
             .run(Path::new(XCRUN), &[SIMCTL, "shutdown", identifier])
             .await;
 
-        self.command_executor
+        let result = self
+            .command_executor
             .run(Path::new(XCRUN), &[SIMCTL, "delete", identifier])
-            .await
-            .context(format!(
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        result.context(format!(
                 "Failed to delete iOS device {identifier}. Make sure the device exists and is not in use."
             ))?;
 
@@ -239,13 +351,286 @@ impl IosManager {
         Ok(())
     }
 
+    /// Brings the Simulator.app window of a booted simulator to the foreground.
+    pub async fn focus_device_window(&self, udid: &str) -> Result<()> {
+        self.command_executor
+            .run(
+                Path::new(OPEN),
+                &[
+                    SIMULATOR_OPEN_FLAG,
+                    SIMULATOR_APP_NAME,
+                    SIMULATOR_ARGS_FLAG,
+                    SIMULATOR_CURRENT_DEVICE_FLAG,
+                    udid,
+                ],
+            )
+            .await
+            .context("Failed to bring Simulator.app to the foreground")?;
+        Ok(())
+    }
+
+    /// Writes a line to the unified log of a booted simulator under `tag`,
+    /// where `log stream` and Console.app show it.
+    pub async fn write_log_marker(&self, udid: &str, tag: &str, message: &str) -> Result<()> {
+        self.command_executor
+            .run(
+                Path::new(XCRUN),
+                &[SIMCTL, SPAWN, udid, LOGGER, LOGGER_TAG, tag, message],
+            )
+            .await
+            .with_context(|| format!("Failed to write to the log of iOS device {udid}"))?;
+        Ok(())
+    }
+
+    /// Shows the time `change` leads to in the status bar of a booted
+    /// simulator, or clears the override for [`ClockChange::Automatic`].
+    /// Simulators run on the Mac's clock, so apps keep seeing the real time.
+    /// Returns the time shown.
+    pub async fn override_status_bar_time(
+        &self,
+        udid: &str,
+        change: &ClockChange,
+    ) -> Result<Option<String>> {
+        let now = chrono::Local::now().naive_local();
+        let time = change
+            .apply_to(now)
+            .map(|time| time.format(STATUS_BAR_TIME_FORMAT).to_string());
+        let result = match &time {
+            Some(time) => {
+                self.command_executor
+                    .run(
+                        Path::new(XCRUN),
+                        &[
+                            SIMCTL,
+                            STATUS_BAR,
+                            udid,
+                            STATUS_BAR_OVERRIDE,
+                            STATUS_BAR_TIME,
+                            time,
+                        ],
+                    )
+                    .await
+            }
+            None => {
+                self.command_executor
+                    .run(
+                        Path::new(XCRUN),
+                        &[SIMCTL, STATUS_BAR, udid, STATUS_BAR_CLEAR],
+                    )
+                    .await
+            }
+        };
+        result.with_context(|| format!("Failed to change the status bar of iOS device {udid}"))?;
+        Ok(time)
+    }
+
+    /// Collects `simctl diagnose` output into an archive in `dir` and
+    /// returns its path. [`DiagnoseScope::Device`] limits it to `udid` and
+    /// recent logs; collecting everything can take several minutes.
+    pub async fn collect_diagnostics(
+        &self,
+        udid: &str,
+        dir: &Path,
+        scope: DiagnoseScope,
+    ) -> Result<PathBuf> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let output_dir = dir.join(format!(
+            "{DIAGNOSE_FILE_PREFIX}{udid}-{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let output_arg = format!("{DIAGNOSE_OUTPUT_PREFIX}{}", output_dir.display());
+        let udid_arg = format!("{DIAGNOSE_UDID_PREFIX}{udid}");
+        let mut args = vec![SIMCTL, DIAGNOSE, DIAGNOSE_NO_FINDER, output_arg.as_str()];
+        match scope {
+            DiagnoseScope::Device => args.push(&udid_arg),
+            DiagnoseScope::All => args.push(DIAGNOSE_ALL_LOGS),
+        }
+
+        // Streaming keeps stdin closed, so the privacy notice simctl shows
+        // before collecting does not wait for the TUI's keyboard
+        let output = self
+            .command_executor
+            .run_streaming(Path::new(XCRUN), &args, &|_: &str| {})
+            .await
+            .context("Failed to collect simulator diagnostics")?;
+        Ok(output
+            .split_whitespace()
+            .find(|word| word.ends_with(DIAGNOSE_ARCHIVE_EXTENSION))
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                let mut archive = output_dir.into_os_string();
+                archive.push(DIAGNOSE_ARCHIVE_EXTENSION);
+                PathBuf::from(archive)
+            }))
+    }
+
+    /// Records an Instruments time profile of a booted simulator into a
+    /// timestamped `.trace` bundle in `dir` and returns its path.
+    pub async fn record_time_profile(
+        &self,
+        udid: &str,
+        capture: &TraceCapture,
+        dir: &Path,
+    ) -> Result<PathBuf> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(format!(
+            "{TRACE_FILE_PREFIX}{udid}-{}{XCTRACE_EXTENSION}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let args = capture.xctrace_args(udid, &path);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        // Streaming keeps stdin closed, like simctl diagnose
+        self.command_executor
+            .run_streaming(Path::new(XCRUN), &args, &|_: &str| {})
+            .await
+            .context("Failed to record Instruments time profile")?;
+        Ok(path)
+    }
+
+    /// Writes all network traffic of the Mac, which booted simulators
+    /// share, to `path` until tcpdump is stopped. Simulators have no
+    /// interface of their own, so other apps are captured too. Capturing
+    /// needs root or access to the BPF devices, e.g. through Wireshark's
+    /// ChmodBPF.
+    pub async fn capture_network_traffic(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let path = path.to_string_lossy();
+        self.command_executor
+            .run_streaming(
+                Path::new(TCPDUMP),
+                &[
+                    tcpdump::INTERFACE,
+                    tcpdump::ALL_INTERFACES,
+                    tcpdump::PACKET_BUFFERED,
+                    tcpdump::WRITE,
+                    &path,
+                ],
+                &|_: &str| {},
+            )
+            .await
+            .context("Failed to capture network traffic")?;
+        Ok(())
+    }
+
+    /// Turns off Simulator.app's "Connect Hardware Keyboard", so UI tests
+    /// get the software keyboard, and returns the previous preference for
+    /// [`Self::restore_test_settings`]. The preference belongs to
+    /// Simulator.app, so it applies to every simulator it shows.
+    pub async fn apply_test_settings(&self) -> Result<TestSettingsBackup> {
+        let connect_hardware_keyboard = self
+            .command_executor
+            .run(
+                Path::new(DEFAULTS),
+                &[
+                    defaults::READ,
+                    SIMULATOR_DEFAULTS_DOMAIN,
+                    CONNECT_HARDWARE_KEYBOARD_KEY,
+                ],
+            )
+            .await
+            .ok()
+            .map(|value| value.trim() == "1");
+        self.write_hardware_keyboard_preference(Some(false)).await?;
+        Ok(TestSettingsBackup::Ios {
+            connect_hardware_keyboard,
+        })
+    }
+
+    /// Puts back the preference saved by [`Self::apply_test_settings`].
+    pub async fn restore_test_settings(&self, backup: &TestSettingsBackup) -> Result<()> {
+        let TestSettingsBackup::Ios {
+            connect_hardware_keyboard,
+        } = backup
+        else {
+            anyhow::bail!("Not an iOS settings backup");
+        };
+        self.write_hardware_keyboard_preference(*connect_hardware_keyboard)
+            .await
+    }
+
+    /// Writes the preference, or deletes it for `None`.
+    async fn write_hardware_keyboard_preference(&self, connect: Option<bool>) -> Result<()> {
+        let args = match connect {
+            Some(connect) => vec![
+                defaults::WRITE,
+                SIMULATOR_DEFAULTS_DOMAIN,
+                CONNECT_HARDWARE_KEYBOARD_KEY,
+                defaults::BOOL,
+                if connect { "true" } else { "false" },
+            ],
+            None => vec![
+                defaults::DELETE,
+                SIMULATOR_DEFAULTS_DOMAIN,
+                CONNECT_HARDWARE_KEYBOARD_KEY,
+            ],
+        };
+        self.command_executor
+            .run(Path::new(DEFAULTS), &args)
+            .await
+            .context("Failed to change the Simulator keyboard preference")?;
+        Ok(())
+    }
+
+    /// Deletes every simulator simctl reports as unavailable and returns the
+    /// UDIDs that are gone afterwards.
+    pub async fn prune_unavailable_devices(&self) -> Result<Vec<String>> {
+        self.read_only
+            .ensure_writable(read_only::PRUNE_UNAVAILABLE)?;
+        self.invalidate_device_list_snapshot().await;
+        let unavailable: Vec<String> = self
+            .list_devices_internal()
+            .await?
+            .into_iter()
+            .filter(|device| !device.is_available)
+            .map(|device| device.udid)
+            .collect();
+
+        let result = self
+            .command_executor
+            .run(Path::new(XCRUN), &[SIMCTL, DELETE, UNAVAILABLE])
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        result.context("Failed to delete unavailable iOS simulators")?;
+
+        let remaining = self.list_devices_internal().await?;
+        let removed: Vec<String> = unavailable
+            .into_iter()
+            .filter(|udid| !remaining.iter().any(|device| &device.udid == udid))
+            .collect();
+        log::info!("Pruned {} unavailable iOS simulators", removed.len());
+        Ok(removed)
+    }
+
+    /// Deletes a simulator left behind by a cancelled creation: one named
+    /// `name` whose UDID is not among the devices known before it started.
+    pub async fn discard_cancelled_device(&self, name: &str, known_udids: &[String]) -> Result<()> {
+        let devices = self.list_devices_internal().await?;
+        for device in devices
+            .iter()
+            .filter(|device| device.name == name && !known_udids.contains(&device.udid))
+        {
+            log::info!("Removing simulator '{name}' left by a cancelled creation");
+            self.delete_device_internal(&device.udid).await?;
+        }
+        Ok(())
+    }
+
     pub(super) async fn wipe_device_internal(&self, identifier: &str) -> Result<()> {
+        self.read_only.ensure_writable(read_only::WIPE_DEVICE)?;
         log::info!("Attempting to wipe iOS device: {identifier}");
         self.erase_device(identifier).await
     }
 
     pub(super) async fn is_available_internal(&self) -> bool {
-        if which::which("xcrun").is_err() {
+        if which::which(tool_paths::resolve_program(XCRUN.as_ref())).is_err() {
             return false;
         }
 
@@ -255,3 +640,13 @@ impl IosManager {
             .is_ok()
     }
 }
+
+/// Whether a line is a bare simulator UDID, as printed by `simctl create`.
+#[cfg(target_os = "macos")]
+fn is_simulator_udid(line: &str) -> bool {
+    let groups: Vec<&str> = line.split('-').collect();
+    groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12])
+        && groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()))
+}
@@ -201,14 +201,21 @@ use std::path::Path;
 // - Non-macOS platforms get stub implementations that return appropriate errors
 // - The `which` crate is used to verify `xcrun` availability at runtime
 
+use crate::constants::commands;
 #[cfg(target_os = "macos")]
 use crate::constants::{
-    commands::{SIMCTL, XCRUN},
+    commands::{xcrun, SIMCTL, XCRUN},
     limits::{IOS_NAME_PARTS_MINIMUM, SINGLE_VERSION_PART},
+    messages::{device_busy, read_only},
     numeric::{VERSION_DEFAULT, VERSION_MINOR_DIVISOR, VERSION_PATCH_DIVISOR},
 };
 use crate::managers::common::{DeviceConfig, DeviceManager};
-use crate::models::IosDevice;
+#[cfg(target_os = "macos")]
+use crate::managers::common::{DeviceLocks, ReadOnlySwitch};
+#[cfg(target_os = "macos")]
+use crate::models::{DeviceCapabilities, SimctlDeviceList};
+use crate::models::{IosDevice, ManagedDevice, Platform};
+use crate::utils::CommandLine;
 #[cfg(target_os = "macos")]
 use anyhow::Context;
 use anyhow::{bail, Result};
@@ -218,8 +225,16 @@ use crate::utils::command::CommandRunner;
 #[cfg(target_os = "macos")]
 use crate::utils::command_executor::CommandExecutor;
 #[cfg(target_os = "macos")]
+use crate::utils::refresh_coordinator::RefreshCoordinator;
+#[cfg(target_os = "macos")]
+use crate::utils::tool_paths;
+#[cfg(target_os = "macos")]
 use std::sync::Arc;
 #[cfg(target_os = "macos")]
+use std::time::Instant;
+#[cfg(target_os = "macos")]
+use tokio::sync::RwLock;
+#[cfg(target_os = "macos")]
 use which;
 
 #[cfg(target_os = "macos")]
@@ -283,6 +298,22 @@ fn extract_ios_version(display_name: &str) -> f32 {
 pub struct IosManager {
     /// Command executor for executing xcrun simctl commands (abstracted for testability)
     command_executor: Arc<dyn CommandExecutor>,
+    /// Last parsed `simctl list devices` output, shared by refreshes and actions.
+    device_list_snapshot: Arc<RwLock<Option<DeviceListSnapshot>>>,
+    /// Coalesces concurrent device list refreshes into one `simctl` run.
+    device_list_refresh: Arc<RefreshCoordinator<Vec<IosDevice>>>,
+    /// Refuses creating, deleting, wiping and installing with `--read-only`.
+    read_only: ReadOnlySwitch,
+    /// Operations running on each simulator, by UDID.
+    device_locks: DeviceLocks,
+}
+
+#[cfg(target_os = "macos")]
+/// A parsed device list together with the `IosDevice` values built from it.
+struct DeviceListSnapshot {
+    list: SimctlDeviceList,
+    devices: Vec<IosDevice>,
+    fetched_at: Instant,
 }
 
 #[cfg(target_os = "macos")]
@@ -294,56 +325,119 @@ impl IosManager {
 
     /// Creates a new IosManager instance with a custom command executor.
     /// This is primarily used for testing with mock executors.
+    /// The Xcode tooling is not checked here; `probe` does that off the
+    /// startup path.
     pub fn with_executor(executor: Arc<dyn CommandExecutor>) -> Result<Self> {
-        // Quick check for Xcode Command Line Tools
-        if which::which(XCRUN).is_err() {
-            bail!("Xcode Command Line Tools not found. Please install Xcode or run 'xcode-select --install'.")
-        }
-
-        // Skip expensive simctl verification at startup - will be validated on first use
-        // This improves startup performance significantly (saves ~30 seconds)
         Ok(Self {
             command_executor: executor,
+            device_list_snapshot: Arc::new(RwLock::new(None)),
+            device_list_refresh: Arc::new(RefreshCoordinator::new()),
+            read_only: ReadOnlySwitch::default(),
+            device_locks: DeviceLocks::default(),
         })
     }
 
+    /// Turns operations that change simulators or runtimes off or on
+    /// again, for this manager and all its clones.
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.set(read_only);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.is_on()
+    }
+
+    /// Checks that the Xcode Command Line Tools are installed and that
+    /// `xcrun` resolves simctl. Resolving can take seconds on a cold start,
+    /// so the app runs this in the background.
+    pub async fn probe(&self) -> Result<()> {
+        let xcrun = tool_paths::resolve_program(XCRUN.as_ref());
+        let found = tokio::task::spawn_blocking(move || which::which(xcrun).is_ok())
+            .await
+            .unwrap_or(false);
+        if !found {
+            bail!("Xcode Command Line Tools not found. Please install Xcode or run 'xcode-select --install'.")
+        }
+
+        self.command_executor
+            .run(Path::new(XCRUN), &[xcrun::FIND, SIMCTL])
+            .await
+            .context(
+                "xcrun could not find simctl, check the active Xcode with 'xcode-select -p'",
+            )?;
+        Ok(())
+    }
+
     // These helper methods remain in the inherent impl block as they are specific to IosManager's way of handling things
     // and not directly part of the DeviceManager trait's public API contract for all managers.
 
     pub async fn erase_device(&self, udid: &str) -> Result<()> {
-        self.command_executor
+        self.read_only.ensure_writable(read_only::WIPE_DEVICE)?;
+        let result = self
+            .command_executor
             .run(Path::new(XCRUN), &[SIMCTL, "erase", udid])
-            .await
-            .context(format!("Failed to erase iOS device {udid}"))?;
+            .await;
+        self.invalidate_device_list_snapshot().await;
+        result.context(format!("Failed to erase iOS device {udid}"))?;
         Ok(())
     }
 }
 
 #[cfg(target_os = "macos")]
+impl IosManager {
+    /// Lists all simulators with their state. Concurrent calls share one
+    /// `simctl list`.
+    pub async fn list_devices(&self) -> Result<Vec<IosDevice>> {
+        let manager = self.clone();
+        self.device_list_refresh
+            .run(move || async move { manager.list_devices_internal().await })
+            .await
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[async_trait::async_trait]
 impl DeviceManager for IosManager {
-    type Device = IosDevice;
+    fn platform(&self) -> Platform {
+        Platform::Ios
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        DeviceCapabilities::IOS.with_read_only(self.is_read_only())
+    }
 
-    async fn list_devices(&self) -> Result<Vec<Self::Device>> {
-        self.list_devices_internal().await
+    async fn list_devices(&self) -> Result<Vec<ManagedDevice>> {
+        let devices = IosManager::list_devices(self).await?;
+        Ok(devices.into_iter().map(ManagedDevice::Ios).collect())
     }
 
     async fn start_device(&self, identifier: &str) -> Result<()> {
+        let _lock = self
+            .device_locks
+            .acquire(identifier, device_busy::STARTING)?;
         self.start_device_internal(identifier).await
     }
 
     async fn stop_device(&self, identifier: &str) -> Result<()> {
+        let _lock = self
+            .device_locks
+            .acquire(identifier, device_busy::STOPPING)?;
         self.stop_device_internal(identifier).await
     }
 
     async fn create_device(&self, config: &DeviceConfig) -> Result<()> {
-        self.create_device_internal(config).await
+        self.create_device_with_progress(config, &|_| {}).await
     }
 
     async fn delete_device(&self, identifier: &str) -> Result<()> {
+        let _lock = self
+            .device_locks
+            .acquire(identifier, device_busy::DELETING)?;
         self.delete_device_internal(identifier).await
     }
 
     async fn wipe_device(&self, identifier: &str) -> Result<()> {
+        let _lock = self.device_locks.acquire(identifier, device_busy::WIPING)?;
         self.wipe_device_internal(identifier).await
     }
 
@@ -352,40 +446,28 @@ impl DeviceManager for IosManager {
     }
 }
 
-/// Implementation of UnifiedDeviceManager for IosManager (macOS)
-#[cfg(target_os = "macos")]
-#[async_trait::async_trait]
-impl crate::managers::common::UnifiedDeviceManager for IosManager {
-    async fn list_devices(&self) -> Result<Vec<Box<dyn crate::models::device::Device>>> {
-        let devices = <Self as DeviceManager>::list_devices(self).await?;
-        Ok(devices
-            .into_iter()
-            .map(|d| Box::new(d) as Box<dyn crate::models::device::Device>)
-            .collect())
-    }
-
-    async fn start_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::start_device(self, device_id).await
-    }
-
-    async fn stop_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::stop_device(self, device_id).await
-    }
-
-    async fn create_device(&self, config: &crate::managers::common::DeviceConfig) -> Result<()> {
-        <Self as DeviceManager>::create_device(self, config).await
-    }
-
-    async fn delete_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::delete_device(self, device_id).await
-    }
-
-    async fn wipe_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::wipe_device(self, device_id).await
-    }
-
-    async fn is_available(&self) -> bool {
-        <Self as DeviceManager>::is_available(self).await
+// Command builders only format arguments, so they are shared with the non-macOS stub.
+impl IosManager {
+    /// The simctl command used to boot a simulator.
+    pub fn boot_command(udid: &str) -> CommandLine {
+        CommandLine::new(
+            commands::XCRUN,
+            [commands::SIMCTL, commands::ios::BOOT, udid],
+        )
+    }
+
+    /// The simctl command used to create a simulator from a device type and runtime.
+    pub fn create_command(config: &DeviceConfig) -> CommandLine {
+        CommandLine::new(
+            commands::XCRUN,
+            [
+                commands::SIMCTL,
+                commands::ios::CREATE,
+                &config.name,
+                &config.device_type,
+                &config.version,
+            ],
+        )
     }
 }
 
@@ -406,6 +488,16 @@ impl IosManager {
         Ok(Self) // Allow creation, but is_available will be false
     }
 
+    pub fn set_read_only(&self, _read_only: bool) {}
+
+    pub fn is_read_only(&self) -> bool {
+        false
+    }
+
+    pub async fn probe(&self) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
     pub async fn list_device_types_with_names(&self) -> Result<Vec<(String, String)>> {
         bail!("iOS simulator management is only available on macOS")
     }
@@ -414,74 +506,221 @@ impl IosManager {
         bail!("iOS simulator management is only available on macOS")
     }
 
+    pub async fn install_runtime(&self, _version: &str) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn prune_unavailable_devices(&self) -> Result<Vec<String>> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn focus_device_window(&self, _udid: &str) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn is_responsive(&self, _udid: &str) -> Result<bool> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn force_restart_device(&self, _udid: &str) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn clone_device(&self, _udid: &str, _name: &str) -> Result<String> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn start_device_headless(&self, _udid: &str) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn collect_diagnostics(
+        &self,
+        _udid: &str,
+        _dir: &std::path::Path,
+        _scope: crate::models::DiagnoseScope,
+    ) -> Result<std::path::PathBuf> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn record_time_profile(
+        &self,
+        _udid: &str,
+        _capture: &crate::models::TraceCapture,
+        _dir: &std::path::Path,
+    ) -> Result<std::path::PathBuf> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn capture_network_traffic(&self, _path: &std::path::Path) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn write_log_marker(&self, _udid: &str, _tag: &str, _message: &str) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn override_status_bar_time(
+        &self,
+        _udid: &str,
+        _change: &crate::models::ClockChange,
+    ) -> Result<Option<String>> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn list_processes(&self, _udid: &str) -> Result<Vec<crate::models::DeviceProcess>> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn list_services(&self, _udid: &str) -> Result<Vec<crate::models::DeviceService>> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn kill_process(
+        &self,
+        _udid: &str,
+        _process: &crate::models::DeviceProcess,
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn apply_test_settings(&self) -> Result<crate::models::TestSettingsBackup> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn restore_test_settings(
+        &self,
+        _backup: &crate::models::TestSettingsBackup,
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
     pub async fn get_device_details(&self, _udid: &str) -> Result<crate::models::DeviceDetails> {
         bail!("iOS simulator management is only available on macOS")
     }
-}
 
-#[cfg(not(target_os = "macos"))]
-impl DeviceManager for IosManager {
-    type Device = IosDevice; // This will use the potentially simplified IosDevice from models.rs for non-macOS
+    pub async fn device_config(&self, _udid: &str) -> Result<Vec<(String, String)>> {
+        bail!("iOS simulator management is only available on macOS")
+    }
 
-    async fn list_devices(&self) -> Result<Vec<Self::Device>> {
+    pub async fn list_installed_apps(&self, _udid: &str) -> Result<Vec<String>> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn start_device(&self, _identifier: &str) -> Result<()> {
+    pub async fn launch_app_with_console(
+        &self,
+        _udid: &str,
+        _launch: &crate::models::AppLaunch,
+        _on_line: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn stop_device(&self, _identifier: &str) -> Result<()> {
+    pub async fn terminate_app(&self, _udid: &str, _bundle_id: &str) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn create_device(&self, _config: &DeviceConfig) -> Result<()> {
+    pub async fn developer_toggle_status(
+        &self,
+        _udid: &str,
+    ) -> Result<crate::models::DeveloperToggleStatus> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn delete_device(&self, _identifier: &str) -> Result<()> {
+    pub async fn set_developer_toggle(
+        &self,
+        _udid: &str,
+        _toggle: crate::models::DeveloperToggle,
+        _enabled: bool,
+    ) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn wipe_device(&self, _identifier: &str) -> Result<()> {
+    pub async fn backup_app_data(
+        &self,
+        _udid: &str,
+        _bundle_id: &str,
+        _path: &std::path::Path,
+    ) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn is_available(&self) -> bool {
-        false // Not available on non-macOS
+    pub async fn restore_app_data(
+        &self,
+        _udid: &str,
+        _bundle_id: &str,
+        _path: &std::path::Path,
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn copy_app(
+        &self,
+        _source_udid: &str,
+        _target_udid: &str,
+        _bundle_id: &str,
+        _with_data: bool,
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn create_device_with_progress(
+        &self,
+        _config: &DeviceConfig,
+        _on_progress: &(dyn Fn(crate::models::InstallProgress) + Send + Sync),
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+
+    pub async fn discard_cancelled_device(
+        &self,
+        _name: &str,
+        _known_udids: &[String],
+    ) -> Result<()> {
+        bail!("iOS simulator management is only available on macOS")
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+impl IosManager {
+    pub async fn list_devices(&self) -> Result<Vec<IosDevice>> {
+        bail!("iOS simulator management is only available on macOS")
     }
 }
 
-/// Implementation of UnifiedDeviceManager for IosManager (non-macOS)
 #[cfg(not(target_os = "macos"))]
 #[async_trait::async_trait]
-impl crate::managers::common::UnifiedDeviceManager for IosManager {
-    async fn list_devices(&self) -> Result<Vec<Box<dyn crate::models::device::Device>>> {
+impl DeviceManager for IosManager {
+    fn platform(&self) -> Platform {
+        Platform::Ios
+    }
+
+    async fn list_devices(&self) -> Result<Vec<ManagedDevice>> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn start_device(&self, _device_id: &str) -> Result<()> {
+    async fn start_device(&self, _identifier: &str) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn stop_device(&self, _device_id: &str) -> Result<()> {
+    async fn stop_device(&self, _identifier: &str) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn create_device(&self, _config: &crate::managers::common::DeviceConfig) -> Result<()> {
+    async fn create_device(&self, _config: &DeviceConfig) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn delete_device(&self, _device_id: &str) -> Result<()> {
+    async fn delete_device(&self, _identifier: &str) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
-    async fn wipe_device(&self, _device_id: &str) -> Result<()> {
+    async fn wipe_device(&self, _identifier: &str) -> Result<()> {
         bail!("iOS simulator management is only available on macOS")
     }
 
     async fn is_available(&self) -> bool {
-        false
+        false // Not available on non-macOS
     }
 }
 
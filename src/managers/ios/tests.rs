@@ -114,56 +114,10 @@ async fn test_ios_manager_non_macos_operations_disabled() {
             .await
             .is_err()
     );
-}
-
-#[allow(dead_code)]
-#[cfg(not(target_os = "macos"))]
-async fn test_ios_manager_unified_device_manager_non_macos_disabled() {
-    use crate::managers::common::{DeviceConfig, UnifiedDeviceManager};
-    use std::collections::HashMap;
-
-    let _manager = IosManager::new().expect("Failed to create IosManager");
-
-    assert!(
-        <IosManager as UnifiedDeviceManager>::list_devices(&_manager)
-            .await
-            .is_err()
-    );
-    assert!(
-        <IosManager as UnifiedDeviceManager>::start_device(&_manager, "test")
-            .await
-            .is_err()
-    );
-    assert!(
-        <IosManager as UnifiedDeviceManager>::stop_device(&_manager, "test")
-            .await
-            .is_err()
-    );
-
-    let config = DeviceConfig {
-        name: "Test Device".to_string(),
-        device_type: "iPhone-15".to_string(),
-        version: "iOS 17.0".to_string(),
-        ram_size: None,
-        storage_size: None,
-        additional_options: HashMap::new(),
-    };
-    assert!(
-        <IosManager as UnifiedDeviceManager>::create_device(&_manager, &config)
-            .await
-            .is_err()
-    );
-    assert!(
-        <IosManager as UnifiedDeviceManager>::delete_device(&_manager, "test")
-            .await
-            .is_err()
-    );
-    assert!(
-        <IosManager as UnifiedDeviceManager>::wipe_device(&_manager, "test")
-            .await
-            .is_err()
-    );
-    assert!(!<IosManager as UnifiedDeviceManager>::is_available(&_manager).await);
+    assert!(_manager.install_runtime("17.0").await.is_err());
+    assert!(_manager.prune_unavailable_devices().await.is_err());
+    assert!(_manager.focus_device_window("test").await.is_err());
+    assert!(_manager.start_device_headless("test").await.is_err());
 }
 
 #[allow(dead_code)]
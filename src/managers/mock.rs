@@ -5,7 +5,7 @@
 
 use crate::constants::android::{EMULATOR_PORT_BASE, EMULATOR_PORT_INCREMENT};
 use crate::managers::common::{DeviceConfig, DeviceManager};
-use crate::models::{AndroidDevice, DeviceStatus, IosDevice};
+use crate::models::{AndroidDevice, DeviceStatus, IosDevice, ManagedDevice, Platform};
 use anyhow::Result;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -195,16 +195,23 @@ impl MockDeviceManager {
     }
 }
 
+#[async_trait::async_trait]
 impl DeviceManager for MockDeviceManager {
-    type Device = Box<dyn crate::models::device::Device>;
+    fn platform(&self) -> Platform {
+        if self.platform == "android" {
+            Platform::Android
+        } else {
+            Platform::Ios
+        }
+    }
 
-    async fn list_devices(&self) -> Result<Vec<Self::Device>> {
+    async fn list_devices(&self) -> Result<Vec<ManagedDevice>> {
         self.record_operation(MockOperation::ListDevices);
         self.check_failure("list_devices")?;
         self.apply_delay("list_devices").await;
 
         let devices = self.devices.lock().unwrap();
-        let mut result: Vec<Self::Device> = Vec::new();
+        let mut result = Vec::new();
 
         for device in devices.values() {
             if self.platform == "android" {
@@ -214,7 +221,7 @@ impl DeviceManager for MockDeviceManager {
                     .unwrap_or_default()
                     .parse()
                     .unwrap_or(30);
-                result.push(Box::new(AndroidDevice {
+                result.push(ManagedDevice::Android(AndroidDevice {
                     name: device.name.clone(),
                     device_type: device.device_type.clone(),
                     api_level,
@@ -223,9 +230,10 @@ impl DeviceManager for MockDeviceManager {
                     is_running: device.status == DeviceStatus::Running,
                     ram_size: "2048".to_string(),
                     storage_size: "8192M".to_string(),
+                    abi: String::new(),
                 }));
             } else {
-                result.push(Box::new(IosDevice {
+                result.push(ManagedDevice::Ios(IosDevice {
                     name: device.name.clone(),
                     udid: device.id.clone(),
                     device_type: device.device_type.clone(),
@@ -237,6 +245,7 @@ impl DeviceManager for MockDeviceManager {
                     status: device.status,
                     is_running: device.status == DeviceStatus::Running,
                     is_available: true,
+                    availability_error: None,
                 }));
             }
         }
@@ -373,39 +382,6 @@ impl DeviceManager for MockDeviceManager {
     }
 }
 
-/// Implementation of UnifiedDeviceManager for MockDeviceManager
-#[cfg(any(test, feature = "test-utils"))]
-#[async_trait::async_trait]
-impl crate::managers::common::UnifiedDeviceManager for MockDeviceManager {
-    async fn list_devices(&self) -> Result<Vec<Box<dyn crate::models::device::Device>>> {
-        <Self as DeviceManager>::list_devices(self).await
-    }
-
-    async fn start_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::start_device(self, device_id).await
-    }
-
-    async fn stop_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::stop_device(self, device_id).await
-    }
-
-    async fn create_device(&self, config: &crate::managers::common::DeviceConfig) -> Result<()> {
-        <Self as DeviceManager>::create_device(self, config).await
-    }
-
-    async fn delete_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::delete_device(self, device_id).await
-    }
-
-    async fn wipe_device(&self, device_id: &str) -> Result<()> {
-        <Self as DeviceManager>::wipe_device(self, device_id).await
-    }
-
-    async fn is_available(&self) -> bool {
-        <Self as DeviceManager>::is_available(self).await
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -469,10 +445,35 @@ mod tests {
 
         // Find and delete the new device
         let new_device = devices.iter().find(|d| d.name() == "Test Device").unwrap();
-        manager.delete_device(new_device.id()).await.unwrap();
+        manager
+            .delete_device(&new_device.id().identifier)
+            .await
+            .unwrap();
 
         // Verify deletion
         let devices = manager.list_devices().await.unwrap();
         assert_eq!(devices.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_managers_work_as_trait_objects() {
+        let managers: Vec<Arc<dyn DeviceManager>> = vec![
+            Arc::new(MockDeviceManager::new_android()),
+            Arc::new(MockDeviceManager::new_ios()),
+        ];
+
+        let mut android_count = 0;
+        let mut ios_count = 0;
+        for manager in &managers {
+            for device in manager.list_devices().await.unwrap() {
+                assert_eq!(device.platform(), manager.platform());
+                match device {
+                    ManagedDevice::Android(_) => android_count += 1,
+                    ManagedDevice::Ios(_) => ios_count += 1,
+                }
+            }
+        }
+        assert_eq!(android_count, 2);
+        assert!(ios_count > 0);
+    }
 }
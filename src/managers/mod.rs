@@ -8,5 +8,5 @@ pub mod ios;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod mock;
 
-pub use android::AndroidManager;
+pub use android::{AndroidManager, LaunchFailure};
 pub use ios::IosManager;
@@ -0,0 +1,149 @@
+//! User-defined device tags and notes.
+//!
+//! Annotations are stored per device in the config directory so devices can
+//! be grouped by project or customer and found again with search.
+
+use crate::constants::files::DEVICE_ANNOTATIONS_FILE;
+use crate::models::DeviceId;
+use crate::utils::cache::cache_file_path;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+/// Tags and a freeform note attached to one device.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceAnnotation {
+    pub tags: Vec<String>,
+    pub note: String,
+}
+
+impl DeviceAnnotation {
+    /// Builds an annotation from the comma-separated tag input of the editor.
+    /// Tags are trimmed and duplicates are dropped.
+    pub fn from_input(tags: &str, note: &str) -> Self {
+        let mut parsed: Vec<String> = Vec::new();
+        for tag in tags.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if !parsed
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(tag))
+            {
+                parsed.push(tag.to_string());
+            }
+        }
+        Self {
+            tags: parsed,
+            note: note.trim().to_string(),
+        }
+    }
+
+    /// Adds a tag unless the device already has it.
+    pub fn add_tag(&mut self, tag: &str) {
+        if !self
+            .tags
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(tag))
+        {
+            self.tags.push(tag.to_string());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.note.is_empty()
+    }
+
+    /// Case-insensitive match against tags and note; `query` must be lowercase.
+    pub fn matches(&self, query: &str) -> bool {
+        self.tags
+            .iter()
+            .any(|tag| tag.to_lowercase().contains(query))
+            || self.note.to_lowercase().contains(query)
+    }
+}
+
+/// Annotations for every device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceAnnotations {
+    devices: HashMap<DeviceId, DeviceAnnotation>,
+    /// Whether changes are written back to disk, as for `DeviceHistory`.
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl DeviceAnnotations {
+    pub fn get(&self, device: &DeviceId) -> Option<&DeviceAnnotation> {
+        self.devices.get(device)
+    }
+
+    /// Stores a device's annotation; an empty one removes the entry.
+    pub fn set(&mut self, device: &DeviceId, annotation: DeviceAnnotation) {
+        if annotation.is_empty() {
+            self.devices.remove(device);
+        } else {
+            self.devices.insert(device.clone(), annotation);
+        }
+        self.persist();
+    }
+
+    /// Forgets a device, e.g. after it has been deleted.
+    pub fn remove_device(&mut self, device: &DeviceId) {
+        if self.devices.remove(device).is_some() {
+            self.persist();
+        }
+    }
+
+    /// Loads the saved annotations, falling back to none.
+    /// The returned value saves itself after every change.
+    pub fn load_from_disk() -> Self {
+        let mut annotations = cache_file_path(DEVICE_ANNOTATIONS_FILE)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .unwrap_or_default();
+        annotations.persistent = true;
+        annotations
+    }
+
+    /// Writes the annotations to disk.
+    pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
+        let path = cache_file_path(DEVICE_ANNOTATIONS_FILE)?;
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn persist(&self) {
+        if self.persistent {
+            if let Err(e) = self.save_to_disk() {
+                log::warn!("Failed to save device annotations: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_input_trims_and_dedups_tags() {
+        let annotation = DeviceAnnotation::from_input(" acme, Beta ,, ACME,beta ", "  login flow ");
+        assert_eq!(annotation.tags, vec!["acme", "Beta"]);
+        assert_eq!(annotation.note, "login flow");
+        assert!(annotation.matches("bet"));
+        assert!(annotation.matches("login"));
+        assert!(!annotation.matches("checkout"));
+        assert!(DeviceAnnotation::from_input(" , ", " ").is_empty());
+    }
+
+    #[test]
+    fn test_set_empty_annotation_removes_entry() {
+        let mut annotations = DeviceAnnotations::default();
+        let pixel = DeviceId::android("Pixel_7");
+        let ios = DeviceId::ios("Pixel_7");
+        annotations.set(&pixel, DeviceAnnotation::from_input("acme", ""));
+        assert!(annotations.get(&pixel).is_some());
+        assert!(annotations.get(&ios).is_none());
+
+        annotations.set(&pixel, DeviceAnnotation::default());
+        assert!(annotations.get(&pixel).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! Android API level management structures.
 
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 
 /// Represents an Android API level with its installation status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +33,12 @@ pub struct SystemImageVariant {
     pub is_installed: bool,
     /// Display name for UI
     pub display_name: String,
+    /// Disk space the installed image takes, in bytes
+    #[serde(default)]
+    pub installed_size: Option<u64>,
+    /// Download size sdkmanager reports for the package, in bytes
+    #[serde(default)]
+    pub download_size: Option<u64>,
 }
 
 /// API level installation progress information.
@@ -43,6 +50,53 @@ pub struct InstallProgress {
     pub percentage: u8,
     /// Estimated time remaining in seconds
     pub eta_seconds: Option<u32>,
+    /// Download speed, when the tool reports how many bytes arrived
+    pub bytes_per_second: Option<u64>,
+}
+
+/// Estimates the speed and time left of an install from the progress seen
+/// since its download started.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadEstimate {
+    first: Option<(Instant, u8, Option<u64>)>,
+}
+
+impl DownloadEstimate {
+    /// Fills in the time left, and the speed when `bytes` (downloaded,
+    /// total) are known, from `progress` reported at `now`. The time left
+    /// follows the byte count when there is one and the percentage
+    /// otherwise.
+    pub fn update(
+        &mut self,
+        progress: &mut InstallProgress,
+        bytes: Option<(u64, u64)>,
+        now: Instant,
+    ) {
+        let downloaded = bytes.map(|(downloaded, _)| downloaded);
+        let (started_at, first_percentage, first_bytes) =
+            *self
+                .first
+                .get_or_insert((now, progress.percentage, downloaded));
+        let elapsed = now.duration_since(started_at).as_secs_f64();
+        if elapsed < 1.0 {
+            return;
+        }
+
+        if let (Some((downloaded, total)), Some(first_bytes)) = (bytes, first_bytes) {
+            if downloaded > first_bytes {
+                let speed = (downloaded - first_bytes) as f64 / elapsed;
+                progress.bytes_per_second = Some(speed as u64);
+                progress.eta_seconds =
+                    Some((total.saturating_sub(downloaded) as f64 / speed) as u32);
+                return;
+            }
+        }
+        if progress.percentage > first_percentage {
+            let rate = f64::from(progress.percentage - first_percentage) / elapsed;
+            let left = f64::from(100u8.saturating_sub(progress.percentage));
+            progress.eta_seconds = Some((left / rate) as u32);
+        }
+    }
 }
 
 impl ApiLevel {
@@ -97,6 +151,36 @@ impl ApiLevel {
             .or_else(|| self.variants.first())
     }
 
+    /// Size shown next to the API level: the disk space its installed
+    /// images take, or the download size of the recommended image when none
+    /// is installed.
+    pub fn listed_size(&self) -> Option<u64> {
+        if self.is_installed {
+            let installed: u64 = self
+                .variants
+                .iter()
+                .filter(|variant| variant.is_installed)
+                .filter_map(|variant| variant.installed_size)
+                .sum();
+            (installed > 0).then_some(installed)
+        } else {
+            self.get_recommended_variant()
+                .and_then(|variant| variant.download_size)
+        }
+    }
+
+    /// Whether the name, version or one of the image variants contains
+    /// `query`, which must be lowercase.
+    pub fn matches_search(&self, query: &str) -> bool {
+        let contains = |text: &str| text.to_lowercase().contains(query);
+        contains(&self.display_name)
+            || contains(&self.version)
+            || self
+                .variants
+                .iter()
+                .any(|variant| contains(&variant.display_name) || contains(&variant.package_id))
+    }
+
     /// Determines the preferred architecture based on the host system.
     fn get_preferred_architecture() -> &'static str {
         #[cfg(target_arch = "x86_64")]
@@ -131,6 +215,8 @@ impl SystemImageVariant {
             package_id,
             is_installed: false,
             display_name,
+            installed_size: None,
+            download_size: None,
         }
     }
 
@@ -302,6 +388,7 @@ mod tests {
             operation: "Downloading system image".to_string(),
             percentage: 45,
             eta_seconds: Some(120),
+            bytes_per_second: None,
         };
 
         assert_eq!(progress.operation, "Downloading system image");
@@ -309,6 +396,38 @@ mod tests {
         assert_eq!(progress.eta_seconds, Some(120));
     }
 
+    #[test]
+    fn test_download_estimate_uses_bytes_then_percentage() {
+        let start = Instant::now();
+        let progress = |percentage| InstallProgress {
+            operation: "Downloading system image...".to_string(),
+            percentage,
+            eta_seconds: None,
+            bytes_per_second: None,
+        };
+
+        let mut estimate = DownloadEstimate::default();
+        let mut first = progress(20);
+        estimate.update(&mut first, Some((0, 3_000)), start);
+        assert_eq!(first.eta_seconds, None);
+        let mut later = progress(30);
+        estimate.update(
+            &mut later,
+            Some((1_000, 3_000)),
+            start + std::time::Duration::from_secs(10),
+        );
+        assert_eq!(later.bytes_per_second, Some(100));
+        assert_eq!(later.eta_seconds, Some(20));
+
+        // Without byte counts, 10% in 10 seconds leaves 60 seconds for 60%
+        let mut estimate = DownloadEstimate::default();
+        estimate.update(&mut progress(30), None, start);
+        let mut later = progress(40);
+        estimate.update(&mut later, None, start + std::time::Duration::from_secs(10));
+        assert_eq!(later.bytes_per_second, None);
+        assert_eq!(later.eta_seconds, Some(60));
+    }
+
     /// Test preferred architecture detection
     #[test]
     fn test_preferred_architecture() {
@@ -0,0 +1,97 @@
+//! Backups of an app's data, to save a test state and apply it again, e.g.
+//! after a wipe.
+//!
+//! A backup is named after the app and when it was taken, so the backups of
+//! an app can be restored on any device of the same platform. An Android
+//! backup is a tar archive of the app's data directory; an iOS backup is a
+//! copy of the app's data container.
+
+use crate::constants::files::{APP_DATA_ARCHIVE_EXTENSION, APP_DATA_BACKUP_PREFIX};
+use crate::models::Platform;
+use chrono::NaiveDateTime;
+use std::path::{Path, PathBuf};
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Where a backup of `app` taken at `taken_at` goes in `dir`.
+pub fn backup_path(dir: &Path, platform: Platform, app: &str, taken_at: NaiveDateTime) -> PathBuf {
+    let extension = match platform {
+        Platform::Android => APP_DATA_ARCHIVE_EXTENSION,
+        Platform::Ios => "",
+    };
+    dir.join(format!(
+        "{APP_DATA_BACKUP_PREFIX}{app}-{}{extension}",
+        taken_at.format(TIMESTAMP_FORMAT)
+    ))
+}
+
+/// The backups of `app` in `dir`, newest first. Apps whose identifier
+/// starts with `app`, e.g. a `.debug` variant, are told apart by the
+/// timestamp that must follow it.
+pub fn list_backups(dir: &Path, platform: Platform, app: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let prefix = format!("{APP_DATA_BACKUP_PREFIX}{app}-");
+    let mut backups: Vec<(NaiveDateTime, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = entry.file_name().into_string().ok()?;
+            let timestamp = name.strip_prefix(&prefix)?;
+            let timestamp = match platform {
+                Platform::Android if path.is_file() => {
+                    timestamp.strip_suffix(APP_DATA_ARCHIVE_EXTENSION)?
+                }
+                Platform::Ios if path.is_dir() => timestamp,
+                _ => return None,
+            };
+            let taken_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+            Some((taken_at, path))
+        })
+        .collect();
+    backups.sort_by_key(|(taken_at, _)| std::cmp::Reverse(*taken_at));
+    backups.into_iter().map(|(_, path)| path).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_backups_of_one_app_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let at = |time: &str| NaiveDateTime::parse_from_str(time, TIMESTAMP_FORMAT).unwrap();
+        let older = backup_path(
+            dir.path(),
+            Platform::Android,
+            "com.example",
+            at("20240101-090000"),
+        );
+        let newer = backup_path(
+            dir.path(),
+            Platform::Android,
+            "com.example",
+            at("20240102-090000"),
+        );
+        let variant = backup_path(
+            dir.path(),
+            Platform::Android,
+            "com.example.debug",
+            at("20240103-090000"),
+        );
+        for path in [&older, &newer, &variant] {
+            std::fs::write(path, b"").unwrap();
+        }
+        assert!(newer
+            .to_string_lossy()
+            .ends_with("app-data-com.example-20240102-090000.tar"));
+
+        assert_eq!(
+            list_backups(dir.path(), Platform::Android, "com.example"),
+            [newer, older]
+        );
+        assert!(list_backups(dir.path(), Platform::Ios, "com.example").is_empty());
+        assert!(list_backups(&dir.path().join("missing"), Platform::Android, "x").is_empty());
+    }
+}
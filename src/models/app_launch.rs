@@ -0,0 +1,168 @@
+//! Launching an installed app with arguments and environment variables.
+//!
+//! On iOS the arguments follow the bundle identifier of `simctl launch`, and
+//! each variable reaches the app through a `SIMCTL_CHILD_` variable of
+//! simctl. On Android the arguments are the extras of an `am start` of the
+//! app's launcher activity, written as in the intent launcher; Android apps
+//! have no launch environment.
+
+use crate::models::{AndroidIntent, Platform};
+use anyhow::{bail, Result};
+
+/// An app to launch and what to launch it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppLaunch {
+    /// Package name or bundle identifier
+    pub app: String,
+    /// Launch arguments on iOS, intent extras on Android
+    pub arguments: String,
+    /// `KEY=VALUE` pairs, iOS only
+    pub environment: Vec<(String, String)>,
+}
+
+impl AppLaunch {
+    /// Checks the dialog fields. Fails without an app, on an extra that is
+    /// not a `key=value` pair, or on a variable without a name.
+    pub fn parse(
+        platform: Platform,
+        app: &str,
+        arguments: &str,
+        environment: &str,
+    ) -> Result<Self> {
+        let app = app.trim();
+        if app.is_empty() {
+            bail!("Choose an app to launch");
+        }
+        let mut environment_vars = Vec::new();
+        for variable in split_words(environment) {
+            match variable.split_once('=') {
+                Some((key, value)) if is_variable_name(key) => {
+                    environment_vars.push((key.to_string(), value.to_string()));
+                }
+                _ => bail!("'{variable}' is not a KEY=VALUE variable"),
+            }
+        }
+
+        let launch = Self {
+            app: app.to_string(),
+            arguments: arguments.trim().to_string(),
+            environment: environment_vars,
+        };
+        if platform == Platform::Android {
+            launch.intent(app).am_args()?;
+        }
+        Ok(launch)
+    }
+
+    /// The iOS launch arguments, split at whitespace outside quotes.
+    pub fn argument_list(&self) -> Vec<String> {
+        split_words(&self.arguments)
+    }
+
+    /// The `am start` of `component` with the arguments as extras.
+    pub fn intent(&self, component: &str) -> AndroidIntent {
+        AndroidIntent {
+            component: component.to_string(),
+            extras: self.arguments.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits `text` at whitespace like a shell does, with single and double
+/// quotes keeping words together.
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    for c in text.chars() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => word.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            None => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_splits_arguments_and_environment() {
+        let launch = AppLaunch::parse(
+            Platform::Ios,
+            " com.example.app ",
+            "-UITests YES -Name 'Jane Doe' \"\"",
+            "API_URL=http://localhost:8080 GREETING=\"hello world\"",
+        )
+        .unwrap();
+        assert_eq!(launch.app, "com.example.app");
+        assert_eq!(
+            launch.argument_list(),
+            ["-UITests", "YES", "-Name", "Jane Doe", ""]
+        );
+        assert_eq!(
+            launch.environment,
+            [
+                ("API_URL".to_string(), "http://localhost:8080".to_string()),
+                ("GREETING".to_string(), "hello world".to_string()),
+            ]
+        );
+
+        assert!(AppLaunch::parse(Platform::Ios, "", "", "").is_err());
+        assert!(AppLaunch::parse(Platform::Ios, "com.example.app", "", "1X=2").is_err());
+        assert!(AppLaunch::parse(Platform::Ios, "com.example.app", "", "DEBUG").is_err());
+        assert!(AppLaunch::parse(Platform::Android, "com.example", "debug", "").is_err());
+
+        let launch = AppLaunch::parse(
+            Platform::Android,
+            "com.example",
+            "user=42, debug=b:true",
+            "",
+        )
+        .unwrap();
+        assert_eq!(
+            launch
+                .intent("com.example/.MainActivity")
+                .am_args()
+                .unwrap(),
+            [
+                "am",
+                "start",
+                "-n",
+                "com.example/.MainActivity",
+                "--es",
+                "user",
+                "42",
+                "--ez",
+                "debug",
+                "true",
+            ]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! What the devices of a platform support.
+//!
+//! Starting, stopping, creating and deleting work on both platforms, but a
+//! good part of the shortcuts only exist on one of them: Quick Boot
+//! snapshots, system properties and scrcpy mirroring are Android features,
+//! repairing a simulator and booting it headless are iOS ones. The key
+//! handlers and the shortcut bar read them from here instead of checking
+//! the active panel themselves.
+
+use super::Platform;
+
+/// The operations the devices of a platform support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceCapabilities {
+    /// Creating and duplicating devices
+    pub create: bool,
+    /// Deleting devices
+    pub delete: bool,
+    /// Wiping the user data of devices
+    pub wipe: bool,
+    /// Installing and uninstalling system images
+    pub install_system_images: bool,
+    /// Moving the data of a device to another directory
+    pub move_data: bool,
+    /// Quick Boot snapshots and cold boot options
+    pub snapshots: bool,
+    /// Browsing and setting system properties
+    pub system_properties: bool,
+    /// Monkey stress test runs
+    pub stress_test: bool,
+    /// Sending activities and intents
+    pub intents: bool,
+    /// Forwarding keystrokes to a running device
+    pub type_into_device: bool,
+    /// Mirroring and recording the screen with scrcpy
+    pub mirroring: bool,
+    /// Switching Wi-Fi, Bluetooth and airplane mode
+    pub radios: bool,
+    /// Repairing a device that no longer boots
+    pub repair: bool,
+    /// Booting a device without a window
+    pub headless_boot: bool,
+}
+
+impl DeviceCapabilities {
+    /// Android Virtual Devices
+    pub const ANDROID: Self = Self {
+        create: true,
+        delete: true,
+        wipe: true,
+        install_system_images: true,
+        move_data: true,
+        snapshots: true,
+        system_properties: true,
+        stress_test: true,
+        intents: true,
+        type_into_device: true,
+        mirroring: true,
+        radios: true,
+        repair: false,
+        headless_boot: false,
+    };
+
+    /// iOS simulators
+    pub const IOS: Self = Self {
+        create: true,
+        delete: true,
+        wipe: true,
+        install_system_images: false,
+        move_data: false,
+        snapshots: false,
+        system_properties: false,
+        stress_test: false,
+        intents: false,
+        type_into_device: false,
+        mirroring: false,
+        radios: false,
+        repair: true,
+        headless_boot: true,
+    };
+
+    pub const fn for_platform(platform: Platform) -> Self {
+        match platform {
+            Platform::Android => Self::ANDROID,
+            Platform::Ios => Self::IOS,
+        }
+    }
+
+    /// Turns off the operations that change devices or the SDK when
+    /// `read_only` is set, as `--read-only` does.
+    pub const fn with_read_only(self, read_only: bool) -> Self {
+        if !read_only {
+            return self;
+        }
+        Self {
+            create: false,
+            delete: false,
+            wipe: false,
+            install_system_images: false,
+            move_data: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_keeps_platform_features() {
+        let android = DeviceCapabilities::for_platform(Platform::Android).with_read_only(true);
+        assert!(!android.create && !android.delete && !android.wipe);
+        assert!(!android.install_system_images && !android.move_data);
+        assert!(android.snapshots && android.mirroring);
+
+        let ios = DeviceCapabilities::for_platform(Platform::Ios);
+        assert_eq!(ios.with_read_only(false), ios);
+        assert!(ios.repair && !ios.snapshots);
+    }
+}
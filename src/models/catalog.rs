@@ -0,0 +1,168 @@
+//! Recommended Android device configurations.
+//!
+//! Picking a device profile, system image tag and ABI is the hardest part of
+//! creating a first AVD. The catalog lists a few configurations that are
+//! known to work, such as a Pixel 8 on API 35 with Google Play, so one of
+//! them can be created, image download included, with a single key. A copy
+//! ships with emu; refreshing downloads the latest one from the repository
+//! and keeps it in the config directory.
+
+use crate::constants::{defaults, files::DEVICE_CATALOG_FILE};
+use crate::managers::common::DeviceConfig;
+use crate::utils::cache::cache_file_path;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Catalog that ships with emu.
+const BUNDLED_CATALOG: &str = include_str!("../../catalog/devices.json");
+
+/// One recommended configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    /// Name of the created AVD, e.g. "Pixel 8 API 35"
+    pub name: String,
+    /// avdmanager device profile ID, e.g. "pixel_8"
+    pub device_type: String,
+    pub api_level: u32,
+    /// System image tag, e.g. "google_apis_playstore"
+    pub tag: String,
+    /// System image ABI; the host's when left out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ram_mb: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_mb: Option<u32>,
+    /// What the configuration is good for
+    #[serde(default)]
+    pub description: String,
+}
+
+impl CatalogEntry {
+    /// ABI of the system image on this host.
+    pub fn abi(&self) -> &str {
+        self.abi.as_deref().unwrap_or(defaults::default_abi())
+    }
+
+    /// sdkmanager package of the system image.
+    pub fn package_id(&self) -> String {
+        format!(
+            "system-images;android-{};{};{}",
+            self.api_level,
+            self.tag,
+            self.abi()
+        )
+    }
+
+    /// What to create for the entry, named `name`.
+    pub fn device_config(&self, name: &str) -> DeviceConfig {
+        let mut config = DeviceConfig::new(
+            name.to_string(),
+            self.device_type.clone(),
+            self.api_level.to_string(),
+        );
+        if let Some(ram_mb) = self.ram_mb {
+            config = config.with_ram(ram_mb.to_string());
+        }
+        if let Some(storage_mb) = self.storage_mb {
+            config = config.with_storage(storage_mb.to_string());
+        }
+        config
+            .additional_options
+            .insert("tag".to_string(), self.tag.clone());
+        config
+            .additional_options
+            .insert("abi".to_string(), self.abi().to_string());
+        config
+    }
+
+    /// The entry's name, numbered when an AVD of that name exists.
+    /// `is_taken` is asked for each candidate.
+    pub fn unused_name(&self, is_taken: impl Fn(&str) -> bool) -> String {
+        if !is_taken(&self.name) {
+            return self.name.clone();
+        }
+        (2..)
+            .map(|number| format!("{} {number}", self.name))
+            .find(|name| !is_taken(name))
+            .unwrap_or_else(|| self.name.clone())
+    }
+}
+
+/// The recommended configurations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceCatalog {
+    pub devices: Vec<CatalogEntry>,
+}
+
+impl DeviceCatalog {
+    /// Reads a catalog, rejecting one without devices.
+    pub fn parse(json: &str) -> Result<Self> {
+        let catalog: Self = serde_json::from_str(json)?;
+        if catalog.devices.is_empty() {
+            bail!("The device catalog lists no devices");
+        }
+        Ok(catalog)
+    }
+
+    /// The catalog that ships with emu.
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_CATALOG).expect("bundled device catalog is valid")
+    }
+
+    /// The last downloaded catalog, or the bundled one.
+    pub fn load() -> Self {
+        cache_file_path(DEVICE_CATALOG_FILE)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| Self::parse(&content).ok())
+            .unwrap_or_else(Self::bundled)
+    }
+
+    /// Keeps a downloaded catalog for the next sessions.
+    pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
+        let path = cache_file_path(DEVICE_CATALOG_FILE)?;
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_entries_describe_what_to_create() {
+        let catalog = DeviceCatalog::bundled();
+        assert!(catalog
+            .devices
+            .iter()
+            .any(|entry| entry.tag == "google_apis_playstore"));
+
+        let entry: CatalogEntry = serde_json::from_str(
+            r#"{"name": "Pixel 8 API 35", "device_type": "pixel_8", "api_level": 35,
+                "tag": "google_apis", "abi": "x86_64", "ram_mb": 2048}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            entry.package_id(),
+            "system-images;android-35;google_apis;x86_64"
+        );
+        let config = entry.device_config("Pixel 8 API 35 2");
+        assert_eq!(config.version, "35");
+        assert_eq!(config.ram_size.as_deref(), Some("2048"));
+        assert_eq!(config.storage_size, None);
+        assert_eq!(config.additional_options["tag"], "google_apis");
+        assert_eq!(config.additional_options["abi"], "x86_64");
+
+        let taken = ["Pixel 8 API 35", "Pixel 8 API 35 2"];
+        assert_eq!(
+            entry.unused_name(|name| taken.contains(&name)),
+            "Pixel 8 API 35 3"
+        );
+
+        assert!(DeviceCatalog::parse(r#"{"devices": []}"#).is_err());
+        assert!(DeviceCatalog::parse("<html>").is_err());
+    }
+}
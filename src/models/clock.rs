@@ -0,0 +1,112 @@
+//! Device clock changes.
+//!
+//! Code that depends on the date, such as subscription renewals or
+//! certificate expiry, is tested by moving the device clock. The clock
+//! dialog takes a date, an offset from the current device time, or `auto`
+//! to follow the network time again.
+
+use crate::constants::keywords::CLOCK_AUTOMATIC_INPUT;
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// One change to a device clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockChange {
+    /// Sets the clock to a date and time in the device's time zone
+    Set(NaiveDateTime),
+    /// Moves the clock forward (or back) from the current device time
+    Shift(Duration),
+    /// Lets the device follow the network time again
+    Automatic,
+}
+
+impl ClockChange {
+    /// Parses the dialog input: `2025-12-31 23:59[:59]`, `2025-12-31`,
+    /// `+7d`, `-90m` (units `s`, `m`, `h`, `d`, `w`), or `auto`.
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case(CLOCK_AUTOMATIC_INPUT) {
+            return Ok(Self::Automatic);
+        }
+        if let Some(offset) = input.strip_prefix('+') {
+            return parse_offset(offset).map(Self::Shift);
+        }
+        if let Some(offset) = input.strip_prefix('-') {
+            return parse_offset(offset).map(|offset| Self::Shift(-offset));
+        }
+        for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+            if let Ok(date_time) = NaiveDateTime::parse_from_str(input, format) {
+                return Ok(Self::Set(date_time));
+            }
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+            return Ok(Self::Set(date.and_time(NaiveTime::MIN)));
+        }
+        bail!("'{input}' is not a date (2025-12-31 23:59), an offset (+7d, -3h), or 'auto'")
+    }
+
+    /// The clock time after this change, given the current one.
+    /// `None` for [`ClockChange::Automatic`].
+    pub fn apply_to(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            Self::Set(date_time) => Some(*date_time),
+            Self::Shift(offset) => Some(now + *offset),
+            Self::Automatic => None,
+        }
+    }
+}
+
+/// Parses `7d`, `3h`, `90m` and the like.
+fn parse_offset(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (amount, unit) = input.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("'{input}' is not an offset such as 7d or 3h"))?;
+    let offset = match unit.trim() {
+        "s" => Duration::try_seconds(amount),
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => bail!("'{input}' needs one of the units s, m, h, d, or w"),
+    };
+    offset.with_context(|| format!("Offset '{input}' is too large"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dates_offsets_and_auto() {
+        let new_year = NaiveDate::from_ymd_opt(2025, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 0)
+            .unwrap();
+        assert_eq!(
+            ClockChange::parse("2025-12-31 23:59").unwrap(),
+            ClockChange::Set(new_year)
+        );
+        assert_eq!(
+            ClockChange::parse(" +7d ").unwrap(),
+            ClockChange::Shift(Duration::days(7))
+        );
+        assert_eq!(
+            ClockChange::parse("-90m").unwrap(),
+            ClockChange::Shift(Duration::minutes(-90))
+        );
+        assert_eq!(ClockChange::parse("AUTO").unwrap(), ClockChange::Automatic);
+        assert!(ClockChange::parse("+3y").is_err());
+        assert!(ClockChange::parse("tomorrow").is_err());
+
+        assert_eq!(
+            ClockChange::parse("+1d").unwrap().apply_to(new_year),
+            Some(new_year + Duration::days(1))
+        );
+        assert_eq!(ClockChange::Automatic.apply_to(new_year), None);
+    }
+}
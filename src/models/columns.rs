@@ -0,0 +1,136 @@
+//! Columns of the device lists.
+//!
+//! Each device row is split into aligned columns: the status glyph, the
+//! name, the API level or runtime, the ABI, the RAM size and how long the
+//! device has been up or idle. A project can hide columns, reorder them and
+//! cap their widths; the name column takes whatever width is left.
+
+use crate::constants::ui_layout::{
+    DEVICE_COLUMN_ABI_WIDTH, DEVICE_COLUMN_RAM_WIDTH, DEVICE_COLUMN_STATUS_WIDTH,
+    DEVICE_COLUMN_UPTIME_WIDTH, DEVICE_COLUMN_VERSION_WIDTH,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A column of the device lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceColumn {
+    /// Running glyph and pin marker
+    Status,
+    /// Device name, tags and availability
+    Name,
+    /// API level of an AVD, runtime of a simulator
+    Version,
+    /// CPU architecture of the system image (Android only)
+    Abi,
+    /// RAM size (Android only)
+    Ram,
+    /// Uptime of a running device, time since last use of a stopped one
+    Uptime,
+}
+
+impl DeviceColumn {
+    /// Every column in the default order.
+    pub const ALL: [Self; 6] = [
+        Self::Status,
+        Self::Name,
+        Self::Version,
+        Self::Abi,
+        Self::Ram,
+        Self::Uptime,
+    ];
+
+    /// Widest the column gets unless `.emu.toml` says otherwise. The name
+    /// column has no maximum and fills the row.
+    pub fn default_width(self) -> Option<usize> {
+        match self {
+            Self::Status => Some(DEVICE_COLUMN_STATUS_WIDTH),
+            Self::Name => None,
+            Self::Version => Some(DEVICE_COLUMN_VERSION_WIDTH),
+            Self::Abi => Some(DEVICE_COLUMN_ABI_WIDTH),
+            Self::Ram => Some(DEVICE_COLUMN_RAM_WIDTH),
+            Self::Uptime => Some(DEVICE_COLUMN_UPTIME_WIDTH),
+        }
+    }
+}
+
+/// The `[columns]` table of `.emu.toml`.
+///
+/// ```toml
+/// [columns]
+/// show = ["status", "name", "version", "uptime"]
+/// widths = { version = 12, uptime = 16 }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeviceListColumns {
+    /// Columns to show, in order
+    pub show: Vec<DeviceColumn>,
+    /// Maximum widths in terminal cells, replacing the defaults
+    pub widths: BTreeMap<DeviceColumn, u16>,
+}
+
+impl Default for DeviceListColumns {
+    fn default() -> Self {
+        Self {
+            show: DeviceColumn::ALL.to_vec(),
+            widths: BTreeMap::new(),
+        }
+    }
+}
+
+impl DeviceListColumns {
+    /// The columns to show in order, without duplicates. The name column is
+    /// always shown, after the status column when it was left out.
+    pub fn visible(&self) -> Vec<DeviceColumn> {
+        let mut columns: Vec<DeviceColumn> = Vec::new();
+        for column in &self.show {
+            if !columns.contains(column) {
+                columns.push(*column);
+            }
+        }
+        if !columns.contains(&DeviceColumn::Name) {
+            let position = usize::from(columns.first() == Some(&DeviceColumn::Status));
+            columns.insert(position, DeviceColumn::Name);
+        }
+        columns
+    }
+
+    /// Widest `column` gets; `None` for one that fills the row.
+    pub fn max_width(&self, column: DeviceColumn) -> Option<usize> {
+        match self.widths.get(&column) {
+            Some(width) if column != DeviceColumn::Name => Some(usize::from(*width)),
+            _ => column.default_width(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_list_columns_keep_the_name_column() {
+        assert_eq!(DeviceListColumns::default().visible(), DeviceColumn::ALL);
+
+        let columns: DeviceListColumns =
+            toml::from_str("show = [\"status\", \"uptime\", \"uptime\"]\nwidths = { uptime = 8 }")
+                .unwrap();
+        assert_eq!(
+            columns.visible(),
+            [
+                DeviceColumn::Status,
+                DeviceColumn::Name,
+                DeviceColumn::Uptime
+            ]
+        );
+        assert_eq!(columns.max_width(DeviceColumn::Uptime), Some(8));
+        assert_eq!(
+            columns.max_width(DeviceColumn::Abi),
+            Some(DEVICE_COLUMN_ABI_WIDTH)
+        );
+        assert_eq!(columns.max_width(DeviceColumn::Name), None);
+        assert!(toml::from_str::<DeviceListColumns>("show = [\"cpu\"]").is_err());
+    }
+}
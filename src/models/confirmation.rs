@@ -0,0 +1,85 @@
+//! When destructive operations ask for confirmation.
+//!
+//! Deleting and wiping devices ask before they run. A project that creates
+//! and throws away devices all day can turn that off, or keep it only for
+//! devices that are running, where a wipe loses an app session in progress.
+
+use serde::Deserialize;
+
+/// The `[confirm]` table of `.emu.toml`.
+///
+/// ```toml
+/// [confirm]
+/// delete = "always"
+/// wipe = "running"
+/// prune_unavailable = "always"
+/// batch = "each"
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfirmationPolicy {
+    /// Deleting a device
+    pub delete: ConfirmWhen,
+    /// Wiping the user data of a device
+    pub wipe: ConfirmWhen,
+    /// Deleting the unavailable iOS simulators
+    pub prune_unavailable: ConfirmWhen,
+    /// How operations on several devices at once are confirmed
+    pub batch: BatchConfirmation,
+}
+
+/// Which devices an operation asks for confirmation on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmWhen {
+    /// Every device
+    #[default]
+    Always,
+    /// Only running devices
+    Running,
+    /// No device; the operation runs right away
+    Never,
+}
+
+impl ConfirmWhen {
+    /// Whether an operation on a device that is, or is not, running needs
+    /// confirmation first.
+    pub fn requires_confirmation(self, is_running: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Running => is_running,
+            Self::Never => false,
+        }
+    }
+}
+
+/// How an operation on several devices at once is confirmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchConfirmation {
+    /// One confirmation listing every device
+    #[default]
+    Aggregate,
+    /// One confirmation per device, skipping the ones declined
+    Each,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirm_when_follows_running_state() {
+        assert!(ConfirmWhen::Always.requires_confirmation(false));
+        assert!(ConfirmWhen::Running.requires_confirmation(true));
+        assert!(!ConfirmWhen::Running.requires_confirmation(false));
+        assert!(!ConfirmWhen::Never.requires_confirmation(true));
+
+        let policy: ConfirmationPolicy =
+            toml::from_str("wipe = \"running\"\nbatch = \"each\"").unwrap();
+        assert_eq!(policy.delete, ConfirmWhen::Always);
+        assert_eq!(policy.wipe, ConfirmWhen::Running);
+        assert_eq!(policy.batch, BatchConfirmation::Each);
+        assert!(toml::from_str::<ConfirmationPolicy>("delete = \"sometimes\"").is_err());
+    }
+}
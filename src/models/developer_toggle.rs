@@ -0,0 +1,169 @@
+//! Rendering and debugging aids of the developer options.
+//!
+//! On Android each toggle is a `debug.*` or `persist.sys.*` property that the
+//! developer options write, which running apps read again after a
+//! `SYSPROPS_TRANSACTION`. On the simulator the Metal HUD is a launchd
+//! variable and slow animations a global default, both picked up by apps
+//! launched afterwards.
+
+use crate::constants::{
+    commands::adb,
+    ios::{
+        ANIMATION_DRAG_COEFFICIENT_KEY, METAL_HUD_ON, METAL_HUD_VARIABLE,
+        SLOW_ANIMATION_COEFFICIENT,
+    },
+};
+use crate::models::Platform;
+
+/// A switch of the developer toggles dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeveloperToggle {
+    GpuOverdraw,
+    LayoutBounds,
+    StrictMode,
+    ProfileBars,
+    MetalHud,
+    SlowAnimations,
+}
+
+impl DeveloperToggle {
+    pub const ANDROID: [DeveloperToggle; 4] = [
+        DeveloperToggle::GpuOverdraw,
+        DeveloperToggle::LayoutBounds,
+        DeveloperToggle::StrictMode,
+        DeveloperToggle::ProfileBars,
+    ];
+    pub const IOS: [DeveloperToggle; 2] =
+        [DeveloperToggle::MetalHud, DeveloperToggle::SlowAnimations];
+
+    pub fn for_platform(platform: Platform) -> &'static [DeveloperToggle] {
+        match platform {
+            Platform::Android => &Self::ANDROID,
+            Platform::Ios => &Self::IOS,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DeveloperToggle::GpuOverdraw => "GPU overdraw",
+            DeveloperToggle::LayoutBounds => "Layout bounds",
+            DeveloperToggle::StrictMode => "Strict mode flash",
+            DeveloperToggle::ProfileBars => "GPU profile bars",
+            DeveloperToggle::MetalHud => "Metal HUD",
+            DeveloperToggle::SlowAnimations => "Slow animations",
+        }
+    }
+
+    /// Key that switches the toggle in the dialog.
+    pub fn key(self) -> char {
+        match self {
+            DeveloperToggle::GpuOverdraw => 'o',
+            DeveloperToggle::LayoutBounds => 'l',
+            DeveloperToggle::StrictMode => 's',
+            DeveloperToggle::ProfileBars => 'p',
+            DeveloperToggle::MetalHud => 'm',
+            DeveloperToggle::SlowAnimations => 'a',
+        }
+    }
+
+    /// The toggle of `platform` switched by `key`.
+    pub fn from_key(platform: Platform, key: char) -> Option<DeveloperToggle> {
+        Self::for_platform(platform)
+            .iter()
+            .copied()
+            .find(|toggle| toggle.key() == key)
+    }
+
+    /// Property, launchd variable, or global default that holds the state.
+    pub fn property(self) -> &'static str {
+        match self {
+            DeveloperToggle::GpuOverdraw => adb::OVERDRAW_PROPERTY,
+            DeveloperToggle::LayoutBounds => adb::LAYOUT_BOUNDS_PROPERTY,
+            DeveloperToggle::StrictMode => adb::STRICT_MODE_PROPERTY,
+            DeveloperToggle::ProfileBars => adb::PROFILE_BARS_PROPERTY,
+            DeveloperToggle::MetalHud => METAL_HUD_VARIABLE,
+            DeveloperToggle::SlowAnimations => ANIMATION_DRAG_COEFFICIENT_KEY,
+        }
+    }
+
+    /// Value that switches the toggle on or off. `None` removes the
+    /// simulator variable or default, which is how it is off.
+    pub fn value(self, enabled: bool) -> Option<&'static str> {
+        match (self, enabled) {
+            (DeveloperToggle::GpuOverdraw, true) => Some(adb::OVERDRAW_ON),
+            (DeveloperToggle::LayoutBounds, true) => Some(adb::PROPERTY_TRUE),
+            (DeveloperToggle::StrictMode, true) => Some(adb::PROPERTY_ONE),
+            (DeveloperToggle::ProfileBars, true) => Some(adb::PROFILE_BARS_ON),
+            (DeveloperToggle::MetalHud, true) => Some(METAL_HUD_ON),
+            (DeveloperToggle::SlowAnimations, true) => Some(SLOW_ANIMATION_COEFFICIENT),
+            (DeveloperToggle::StrictMode, false) => Some(adb::PROPERTY_ZERO),
+            (
+                DeveloperToggle::GpuOverdraw
+                | DeveloperToggle::LayoutBounds
+                | DeveloperToggle::ProfileBars,
+                false,
+            ) => Some(adb::PROPERTY_FALSE),
+            (DeveloperToggle::MetalHud | DeveloperToggle::SlowAnimations, false) => None,
+        }
+    }
+
+    /// Whether a value read from the device means on. Unset properties
+    /// read as empty and are off.
+    pub fn parse_value(self, value: &str) -> bool {
+        let value = value.trim();
+        match self {
+            DeveloperToggle::SlowAnimations => value
+                .parse::<f64>()
+                .map(|coefficient| coefficient > 1.0)
+                .unwrap_or(false),
+            _ => self.value(true) == Some(value),
+        }
+    }
+}
+
+/// Which toggles of a device are on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeveloperToggleStatus {
+    enabled: Vec<DeveloperToggle>,
+}
+
+impl DeveloperToggleStatus {
+    pub fn is_enabled(&self, toggle: DeveloperToggle) -> bool {
+        self.enabled.contains(&toggle)
+    }
+
+    pub fn set(&mut self, toggle: DeveloperToggle, enabled: bool) {
+        self.enabled.retain(|other| *other != toggle);
+        if enabled {
+            self.enabled.push(toggle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_values_round_trip() {
+        for platform in [Platform::Android, Platform::Ios] {
+            for &toggle in DeveloperToggle::for_platform(platform) {
+                assert_eq!(
+                    DeveloperToggle::from_key(platform, toggle.key()),
+                    Some(toggle)
+                );
+                assert!(toggle.parse_value(toggle.value(true).unwrap()));
+                assert!(!toggle.parse_value(toggle.value(false).unwrap_or("")));
+            }
+        }
+        assert_eq!(DeveloperToggle::from_key(Platform::Ios, 'o'), None);
+        assert!(!DeveloperToggle::SlowAnimations.parse_value("1"));
+        assert!(DeveloperToggle::LayoutBounds.parse_value("true\n"));
+
+        let mut status = DeveloperToggleStatus::default();
+        status.set(DeveloperToggle::MetalHud, true);
+        assert!(status.is_enabled(DeveloperToggle::MetalHud));
+        status.set(DeveloperToggle::MetalHud, false);
+        assert!(!status.is_enabled(DeveloperToggle::MetalHud));
+    }
+}
@@ -4,8 +4,74 @@
 //! in the application. Each platform has its own device type with platform-specific
 //! fields, while sharing common status enumerations.
 
+use super::{simctl::SimulatorUnavailableReason, Platform};
 use crate::constants::{android::DEFAULT_STORAGE_FALLBACK, defaults::DEFAULT_RAM_MB};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Stable identifier of a device on any platform: the AVD name for Android,
+/// the UDID for iOS.
+///
+/// Written as `android:<avd name>` or `ios:<udid>`. Everything after the
+/// first colon is the identifier, so AVD names with spaces or colons
+/// round-trip unchanged. This is the form used for CLI arguments, the JSON
+/// summary, and per-device data saved on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceId {
+    pub platform: Platform,
+    pub identifier: String,
+}
+
+impl DeviceId {
+    pub fn new(platform: Platform, identifier: impl Into<String>) -> Self {
+        Self {
+            platform,
+            identifier: identifier.into(),
+        }
+    }
+
+    pub fn android(avd_name: impl Into<String>) -> Self {
+        Self::new(Platform::Android, avd_name)
+    }
+
+    pub fn ios(udid: impl Into<String>) -> Self {
+        Self::new(Platform::Ios, udid)
+    }
+}
+
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.platform.short_name(), self.identifier)
+    }
+}
+
+impl FromStr for DeviceId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (platform, identifier) = s.split_once(':').ok_or_else(|| {
+            format!("Device ID '{s}' must look like android:<name> or ios:<udid>")
+        })?;
+        if identifier.is_empty() {
+            return Err(format!("Device ID '{s}' has no identifier"));
+        }
+        Ok(Self::new(platform.parse()?, identifier))
+    }
+}
+
+impl Serialize for DeviceId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
 
 /// Common interface for all device types.
 ///
@@ -23,6 +89,14 @@ pub trait Device: Send + Sync + std::fmt::Debug {
 
     /// Returns whether the device is currently running
     fn is_running(&self) -> bool;
+
+    /// Returns the platform the device runs on
+    fn platform(&self) -> Platform;
+
+    /// Returns the platform-qualified identifier of the device
+    fn device_id(&self) -> DeviceId {
+        DeviceId::new(self.platform(), self.id())
+    }
 }
 
 /// Represents an Android Virtual Device (AVD).
@@ -47,6 +121,10 @@ pub struct AndroidDevice {
     pub ram_size: String,
     /// Storage size (e.g., "8192M", "4G")
     pub storage_size: String,
+    /// CPU architecture of the system image (e.g., "arm64-v8a"), empty when
+    /// unknown
+    #[serde(default)]
+    pub abi: String,
 }
 
 /// Represents an iOS Simulator device.
@@ -71,6 +149,9 @@ pub struct IosDevice {
     pub is_running: bool,
     /// Whether the device is available for use (not corrupted)
     pub is_available: bool,
+    /// simctl's explanation when the device is unavailable
+    #[serde(default)]
+    pub availability_error: Option<String>,
 }
 
 /// Represents the current operational state of a virtual device.
@@ -133,6 +214,10 @@ impl Device for AndroidDevice {
     fn is_running(&self) -> bool {
         self.is_running
     }
+
+    fn platform(&self) -> Platform {
+        Platform::Android
+    }
 }
 
 impl Device for IosDevice {
@@ -151,6 +236,62 @@ impl Device for IosDevice {
     fn is_running(&self) -> bool {
         self.is_running
     }
+
+    fn platform(&self) -> Platform {
+        Platform::Ios
+    }
+}
+
+/// A device of either platform.
+#[derive(Debug, Clone)]
+pub enum ManagedDevice {
+    Android(AndroidDevice),
+    Ios(IosDevice),
+}
+
+impl ManagedDevice {
+    /// Platform-qualified identifier of the device.
+    pub fn id(&self) -> DeviceId {
+        self.as_device().device_id()
+    }
+
+    pub fn name(&self) -> &str {
+        self.as_device().name()
+    }
+
+    pub fn status(&self) -> &DeviceStatus {
+        self.as_device().status()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.as_device().is_running()
+    }
+
+    pub fn platform(&self) -> Platform {
+        self.as_device().platform()
+    }
+
+    fn as_device(&self) -> &dyn Device {
+        match self {
+            ManagedDevice::Android(device) => device,
+            ManagedDevice::Ios(device) => device,
+        }
+    }
+}
+
+impl IosDevice {
+    /// Why the simulator cannot be used, or `None` while it is available.
+    pub fn unavailable_reason(&self) -> Option<SimulatorUnavailableReason> {
+        if self.is_available {
+            return None;
+        }
+        Some(
+            self.availability_error
+                .as_deref()
+                .map(SimulatorUnavailableReason::from_availability_error)
+                .unwrap_or_else(|| SimulatorUnavailableReason::Other(String::new())),
+        )
+    }
 }
 
 impl Default for AndroidDevice {
@@ -164,6 +305,7 @@ impl Default for AndroidDevice {
             is_running: false,
             ram_size: DEFAULT_RAM_MB.to_string(),
             storage_size: DEFAULT_STORAGE_FALLBACK.to_string(),
+            abi: String::new(),
         }
     }
 }
@@ -183,6 +325,7 @@ mod tests {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         assert_eq!(device.id(), "test_device");
@@ -202,6 +345,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         assert_eq!(device.id(), "ABC123");
@@ -290,6 +434,7 @@ mod tests {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         let json = serde_json::to_string(&device).unwrap();
@@ -317,6 +462,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         let json = serde_json::to_string(&device).unwrap();
@@ -343,6 +489,7 @@ mod tests {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         let cloned = device.clone();
@@ -370,6 +517,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         let cloned = device.clone();
@@ -398,6 +546,7 @@ mod tests {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         let debug_str = format!("{android_device:?}");
@@ -415,6 +564,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         let debug_str = format!("{ios_device:?}");
@@ -435,6 +585,7 @@ mod tests {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         let ios_device = IosDevice {
@@ -446,6 +597,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         // Test that both devices implement the Device trait consistently
@@ -507,6 +659,7 @@ mod tests {
             is_running: false,
             ram_size: "0".to_string(),
             storage_size: "".to_string(),
+            abi: String::new(),
         };
 
         assert_eq!(android.id(), "");
@@ -523,6 +676,7 @@ mod tests {
             status: DeviceStatus::Error,
             is_running: true, // Contradictory state
             is_available: false,
+            availability_error: None,
         };
 
         assert_eq!(ios.id(), "");
@@ -531,6 +685,28 @@ mod tests {
         assert_eq!(*ios.status(), DeviceStatus::Error);
     }
 
+    #[test]
+    fn test_ios_device_unavailable_reason() {
+        let mut ios = IosDevice {
+            name: "iPhone 13".to_string(),
+            udid: "DDDD".to_string(),
+            device_type: "iPhone 13".to_string(),
+            ios_version: "15.0".to_string(),
+            runtime_version: "15.0".to_string(),
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            is_available: true,
+            availability_error: Some("runtime profile not found".to_string()),
+        };
+        assert_eq!(ios.unavailable_reason(), None);
+
+        ios.is_available = false;
+        assert_eq!(
+            ios.unavailable_reason(),
+            Some(SimulatorUnavailableReason::RuntimeMissing)
+        );
+    }
+
     #[test]
     fn test_device_status_inequalities() {
         // Test comprehensive inequality checks
@@ -561,4 +737,31 @@ mod tests {
         assert_eq!(stopped, stopped);
         assert_eq!(unknown, unknown);
     }
+
+    #[test]
+    fn test_device_id_round_trips_names_with_spaces_and_colons() {
+        let id = DeviceId::android("Pixel 7: work");
+        assert_eq!(id.to_string(), "android:Pixel 7: work");
+        assert_eq!("android:Pixel 7: work".parse::<DeviceId>(), Ok(id));
+
+        let id: DeviceId = "ios:ABC-123".parse().unwrap();
+        assert_eq!(id, DeviceId::ios("ABC-123"));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"ios:ABC-123\"");
+    }
+
+    #[test]
+    fn test_device_id_rejects_malformed_values() {
+        assert!("Pixel_7".parse::<DeviceId>().is_err());
+        assert!("android:".parse::<DeviceId>().is_err());
+        assert!("windows:Pixel_7".parse::<DeviceId>().is_err());
+    }
+
+    #[test]
+    fn test_device_id_from_device() {
+        let device = AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(device.device_id(), DeviceId::android("Pixel_7_API_34"));
+    }
 }
@@ -0,0 +1,106 @@
+use super::DynamicDeviceConfig;
+
+impl DynamicDeviceConfig {
+    /// Detects the device category (phone/tablet/wear/tv/automotive/desktop) from an
+    /// Android device definition id and display name. Falls back to "phone".
+    pub fn detect_android_device_category(device_id: &str, device_display: &str) -> String {
+        let combined = format!(
+            "{} {}",
+            device_id.to_lowercase(),
+            device_display.to_lowercase()
+        );
+
+        if combined.contains("phone")
+            || combined.contains("pixel")
+                && !combined.contains("fold")
+                && !combined.contains("tablet")
+            || combined.contains("galaxy")
+                && !combined.contains("fold")
+                && !combined.contains("tablet")
+            || combined.contains("oneplus")
+            || combined.contains("iphone")
+            || Self::is_phone_size(&combined)
+            || (combined.contains("pro")
+                && !combined.contains("tablet")
+                && !combined.contains("fold"))
+        {
+            return "phone".to_string();
+        }
+
+        if combined.contains("tablet")
+            || combined.contains("pad")
+            || Self::is_tablet_size(&combined)
+        {
+            return "tablet".to_string();
+        }
+
+        if combined.contains("wear")
+            || combined.contains("watch")
+            || combined.contains("round") && !combined.contains("tablet")
+            || combined.contains("square") && !combined.contains("tablet")
+        {
+            return "wear".to_string();
+        }
+
+        if combined.contains("tv")
+            || combined.contains("1080p")
+            || combined.contains("4k")
+            || combined.contains("720p")
+        {
+            return "tv".to_string();
+        }
+
+        if combined.contains("auto") || combined.contains("car") || combined.contains("automotive")
+        {
+            return "automotive".to_string();
+        }
+
+        if combined.contains("desktop")
+            || combined.contains("foldable") && combined.contains("large")
+            || Self::is_desktop_size(&combined)
+        {
+            return "desktop".to_string();
+        }
+
+        "phone".to_string()
+    }
+
+    fn is_phone_size(combined: &str) -> bool {
+        if !combined.contains("inch") {
+            return false;
+        }
+
+        for size in ["5", "6"] {
+            if combined.contains(size) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_tablet_size(combined: &str) -> bool {
+        if !combined.contains("inch") {
+            return false;
+        }
+
+        for size in ["10", "11", "12", "13"] {
+            if combined.contains(size) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_desktop_size(combined: &str) -> bool {
+        if !combined.contains("inch") {
+            return false;
+        }
+
+        for size in ["15", "17"] {
+            if combined.contains(size) {
+                return true;
+            }
+        }
+        false
+    }
+}
@@ -33,6 +33,7 @@ use crate::constants::{
 };
 use std::collections::HashMap;
 
+mod category;
 mod parsing;
 mod priority;
 pub use self::priority::sort_android_devices_for_display;
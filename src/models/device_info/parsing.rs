@@ -28,12 +28,11 @@ impl DynamicDeviceConfig {
                 ')' => {
                     in_parentheses = false;
                 }
-                ' ' if !in_parentheses => {
-                    if !current_word.is_empty() {
-                        parts.push(current_word.clone());
-                        current_word.clear();
-                    }
+                ' ' if !in_parentheses && !current_word.is_empty() => {
+                    parts.push(current_word.clone());
+                    current_word.clear();
                 }
+                ' ' if !in_parentheses => {}
                 _ if !in_parentheses => {
                     current_word.push(ch);
                 }
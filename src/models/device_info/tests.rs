@@ -34,6 +34,7 @@ fn test_parse_device_name() {
     assert_eq!(parts, vec!["Pixel", "9", "Pro", "XL"]);
 }
 
+#[cfg(feature = "tui")]
 #[test]
 fn test_placeholder_generation() {
     use crate::app::state::CreateDeviceForm;
@@ -53,3 +54,24 @@ fn test_placeholder_generation() {
     form.generate_placeholder_name();
     assert_eq!(form.name, "Pixel 9 Pro API 36");
 }
+
+#[cfg(feature = "tui")]
+#[test]
+fn test_placeholder_follows_naming_template() {
+    use crate::app::state::CreateDeviceForm;
+    use crate::models::NamingConfig;
+
+    let mut form = CreateDeviceForm::new();
+    form.device_type_id = "pixel_9_pro".to_string();
+    form.device_type = "Pixel 9 Pro (Google)".to_string();
+    form.version = "36".to_string();
+    form.version_display = "API 36 - Android 15".to_string();
+    form.naming = NamingConfig {
+        template: Some("{name} {api}".to_string()),
+        ..NamingConfig::default()
+    };
+    form.taken_names = vec!["Pixel_9_Pro_36".to_string()];
+
+    form.generate_placeholder_name();
+    assert_eq!(form.name, "Pixel 9 Pro 36_2");
+}
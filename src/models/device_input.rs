@@ -0,0 +1,99 @@
+//! Keystrokes forwarded to a device.
+//!
+//! While typing into a device, printable keys are collected into text for
+//! `adb shell input text`, and editing and navigation keys become
+//! `adb shell input keyevent` key codes.
+
+/// A key without a character that is forwarded as a key event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKey {
+    Enter,
+    Backspace,
+    Delete,
+    Tab,
+    Escape,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+}
+
+impl DeviceKey {
+    /// Android key code name passed to `input keyevent`.
+    pub fn android_keycode(self) -> &'static str {
+        match self {
+            Self::Enter => "KEYCODE_ENTER",
+            Self::Backspace => "KEYCODE_DEL",
+            Self::Delete => "KEYCODE_FORWARD_DEL",
+            Self::Tab => "KEYCODE_TAB",
+            Self::Escape => "KEYCODE_ESCAPE",
+            Self::Up => "KEYCODE_DPAD_UP",
+            Self::Down => "KEYCODE_DPAD_DOWN",
+            Self::Left => "KEYCODE_DPAD_LEFT",
+            Self::Right => "KEYCODE_DPAD_RIGHT",
+            Self::Home => "KEYCODE_MOVE_HOME",
+            Self::End => "KEYCODE_MOVE_END",
+        }
+    }
+
+    /// Symbol shown in the echo of what was typed.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Enter => "⏎",
+            Self::Backspace => "⌫",
+            Self::Delete => "⌦",
+            Self::Tab => "⇥",
+            Self::Escape => "⎋",
+            Self::Up => "↑",
+            Self::Down => "↓",
+            Self::Left => "←",
+            Self::Right => "→",
+            Self::Home => "⇱",
+            Self::End => "⇲",
+        }
+    }
+}
+
+/// One keystroke, or several printable ones, on their way to the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceInput {
+    Text(String),
+    Key(DeviceKey),
+}
+
+impl DeviceInput {
+    /// Joins consecutive text so a burst of typing is sent in one command.
+    /// Returns the input back when it cannot be merged.
+    pub fn merge(&mut self, next: DeviceInput) -> Option<DeviceInput> {
+        match (self, next) {
+            (Self::Text(text), Self::Text(more)) => {
+                text.push_str(&more);
+                None
+            }
+            (_, next) => Some(next),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_joins_text_but_not_keys() {
+        let mut input = DeviceInput::Text("he".to_string());
+        assert_eq!(input.merge(DeviceInput::Text("llo".to_string())), None);
+        assert_eq!(input, DeviceInput::Text("hello".to_string()));
+
+        let enter = DeviceInput::Key(DeviceKey::Enter);
+        assert_eq!(input.merge(enter.clone()), Some(enter.clone()));
+        let mut key = enter;
+        assert_eq!(
+            key.merge(DeviceInput::Text("a".to_string())),
+            Some(DeviceInput::Text("a".to_string()))
+        );
+        assert_eq!(DeviceKey::Backspace.android_keycode(), "KEYCODE_DEL");
+    }
+}
@@ -5,9 +5,11 @@
 //! messages suitable for display in the TUI.
 
 use crate::constants::{
+    commands,
     env_vars::{ANDROID_HOME, ANDROID_SDK_ROOT},
-    messages::error_formatting::{ERROR_MESSAGE_TRUNCATED_LENGTH, MAX_ERROR_MESSAGE_LENGTH},
+    messages::error_formatting::MAX_ERROR_MESSAGE_LENGTH,
 };
+use crate::utils::text::truncate_to_width;
 use thiserror::Error;
 
 /// Comprehensive error type for device management operations.
@@ -50,6 +52,12 @@ pub enum DeviceError {
     #[error("Invalid configuration: {message}")]
     InvalidConfig { message: String },
 
+    #[error("Read-only mode: {operation} is turned off")]
+    ReadOnly { operation: String },
+
+    #[error("{name} is busy {operation}, try again once it finishes")]
+    Busy { name: String, operation: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -59,6 +67,24 @@ pub enum DeviceError {
     #[error("Regex error: {0}")]
     Regex(#[from] regex::Error),
 
+    #[error("Tool '{tool}' not found")]
+    ToolMissing { tool: String },
+
+    #[error("Android SDK licenses not accepted")]
+    LicenseNotAccepted,
+
+    #[error("System image not installed: {package}")]
+    ImageMissing { package: String },
+
+    #[error("Port {port} is already in use")]
+    PortConflict { port: u16 },
+
+    #[error("{operation} timed out after {seconds}s")]
+    Timeout { operation: String, seconds: u64 },
+
+    #[error("Failed to parse {what}: {reason}")]
+    ParseFailure { what: String, reason: String },
+
     #[error("Other error: {message}")]
     Other { message: String },
 }
@@ -97,12 +123,84 @@ impl DeviceError {
         }
     }
 
+    /// Creates a ReadOnly error for an operation refused by `--read-only`.
+    pub fn read_only(operation: impl Into<String>) -> Self {
+        Self::ReadOnly {
+            operation: operation.into(),
+        }
+    }
+
+    /// Creates a Busy error for an operation refused while `operation`
+    /// runs on the same device.
+    pub fn busy(name: impl Into<String>, operation: impl Into<String>) -> Self {
+        Self::Busy {
+            name: name.into(),
+            operation: operation.into(),
+        }
+    }
+
     pub fn other(message: impl Into<String>) -> Self {
         Self::Other {
             message: message.into(),
         }
     }
 
+    pub fn tool_missing(tool: impl Into<String>) -> Self {
+        Self::ToolMissing { tool: tool.into() }
+    }
+
+    pub fn image_missing(package: impl Into<String>) -> Self {
+        Self::ImageMissing {
+            package: package.into(),
+        }
+    }
+
+    pub fn timeout(operation: impl Into<String>, seconds: u64) -> Self {
+        Self::Timeout {
+            operation: operation.into(),
+            seconds,
+        }
+    }
+
+    pub fn parse_failure(what: impl Into<String>, reason: impl ToString) -> Self {
+        Self::ParseFailure {
+            what: what.into(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Next step the user can take to resolve the error, if there is one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::ToolMissing { tool } => Some(match tool.as_str() {
+                commands::XCRUN | commands::SIMCTL => {
+                    "Run 'xcode-select --install' to install the command line tools".to_string()
+                }
+                commands::ADB
+                | commands::EMULATOR
+                | commands::AVDMANAGER
+                | commands::SDKMANAGER => {
+                    format!("Install the Android SDK command-line tools and check {ANDROID_HOME}")
+                }
+                _ => format!("Install '{tool}' and make sure it is on PATH"),
+            }),
+            Self::LicenseNotAccepted => {
+                Some("Run 'sdkmanager --licenses' in a terminal to accept them".to_string())
+            }
+            Self::ImageMissing { package } => Some(format!("Run: sdkmanager \"{package}\"")),
+            Self::PortConflict { .. } => Some(
+                "Stop the process holding it (e.g. 'adb kill-server') and try again".to_string(),
+            ),
+            Self::Timeout { .. } => {
+                Some("Check that the device is responsive and try again".to_string())
+            }
+            Self::ParseFailure { .. } => {
+                Some("Update the SDK tools; their output format may have changed".to_string())
+            }
+            _ => None,
+        }
+    }
+
     /// Convert an anyhow error to a user-friendly message for TUI display
     pub fn user_friendly_message(&self) -> String {
         match self {
@@ -141,9 +239,18 @@ impl DeviceError {
                 format!("{sdk} SDK not found. Check environment variables")
             }
             Self::InvalidConfig { message } => format!("Configuration error: {message}"),
+            Self::ReadOnly { .. } | Self::Busy { .. } => self.to_string(),
             Self::Io(_) => "File access error occurred".to_string(),
             Self::Parse(_) => "Data parsing failed".to_string(),
             Self::Regex(_) => "Pattern matching error occurred".to_string(),
+            Self::ToolMissing { tool } => format!("Required tool '{tool}' not found"),
+            Self::LicenseNotAccepted => "Android SDK licenses not accepted".to_string(),
+            Self::ImageMissing { package } => format!("System image '{package}' not installed"),
+            Self::PortConflict { port } => format!("Port {port} is already in use"),
+            Self::Timeout { operation, seconds } => {
+                format!("{operation} timed out after {seconds}s")
+            }
+            Self::ParseFailure { what, .. } => format!("Could not read {what}"),
             Self::Other { message } => message.clone(),
         }
     }
@@ -162,9 +269,17 @@ impl DeviceError {
             Self::PlatformNotSupported { .. } => "Platform Error".to_string(),
             Self::SdkNotFound { .. } => "SDK Error".to_string(),
             Self::InvalidConfig { .. } => "Config Error".to_string(),
+            Self::ReadOnly { .. } => "Read-only Mode".to_string(),
+            Self::Busy { .. } => "Device Busy".to_string(),
             Self::Io(_) => "IO Error".to_string(),
             Self::Parse(_) => "Parse Error".to_string(),
             Self::Regex(_) => "Regex Error".to_string(),
+            Self::ToolMissing { .. } => "Tool Missing".to_string(),
+            Self::LicenseNotAccepted => "License Error".to_string(),
+            Self::ImageMissing { .. } => "Image Missing".to_string(),
+            Self::PortConflict { .. } => "Port Conflict".to_string(),
+            Self::Timeout { .. } => "Timeout".to_string(),
+            Self::ParseFailure { .. } => "Parse Error".to_string(),
             Self::Other { .. } => "Error".to_string(),
         }
     }
@@ -172,9 +287,11 @@ impl DeviceError {
 
 /// Converts an anyhow::Error to a user-friendly message for TUI display.
 ///
-/// This function analyzes error messages for common patterns and provides
-/// helpful suggestions to users. It handles SDK configuration issues,
-/// missing tools, permission errors, and other common problems.
+/// Structured [`DeviceError`]s anywhere in the error chain are rendered as
+/// their message followed by their remediation hint. Other errors are
+/// analyzed for common patterns to provide helpful suggestions. It handles
+/// SDK configuration issues, missing tools, permission errors, and other
+/// common problems.
 ///
 /// # Arguments
 /// * `error` - The anyhow error to format
@@ -187,6 +304,26 @@ impl DeviceError {
 /// - "ANDROID_HOME not found" → "Set ANDROID_HOME environment variable"
 /// - Long technical errors → Truncated to 150 characters
 pub fn format_user_error(error: &anyhow::Error) -> String {
+    // Names operations such as installing system images, which the
+    // patterns below would mistake for a missing image
+    if let Some(error @ (DeviceError::ReadOnly { .. } | DeviceError::Busy { .. })) =
+        error.downcast_ref::<DeviceError>()
+    {
+        return error.to_string();
+    }
+
+    let structured = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<DeviceError>())
+        .and_then(|device_error| {
+            device_error
+                .hint()
+                .map(|hint| format!("{}. {hint}", device_error.user_friendly_message()))
+        });
+    if let Some(message) = structured {
+        return truncate_to_width(&message, MAX_ERROR_MESSAGE_LENGTH);
+    }
+
     let error_str = error.to_string();
 
     // Check for common error patterns and provide user-friendly messages
@@ -236,11 +373,7 @@ pub fn format_user_error(error: &anyhow::Error) -> String {
     }
 
     // Truncate very long error messages for display
-    if error_str.len() > MAX_ERROR_MESSAGE_LENGTH {
-        format!("{}...", &error_str[..ERROR_MESSAGE_TRUNCATED_LENGTH])
-    } else {
-        error_str
-    }
+    truncate_to_width(&error_str, MAX_ERROR_MESSAGE_LENGTH)
 }
 
 /// Convenience type alias for Results with DeviceError.
@@ -450,6 +583,54 @@ mod tests {
         assert_eq!(result.len(), 150); // Truncated to exactly 150 characters
     }
 
+    #[test]
+    fn test_structured_errors_render_hints() {
+        let error = anyhow::Error::from(DeviceError::LicenseNotAccepted);
+        assert_eq!(
+            format_user_error(&error),
+            "Android SDK licenses not accepted. Run 'sdkmanager --licenses' in a terminal to accept them"
+        );
+
+        let error = anyhow::Error::from(DeviceError::image_missing(
+            "system-images;android-34;google_apis;x86_64",
+        ))
+        .context("Failed to create device");
+        assert_eq!(
+            format_user_error(&error),
+            "System image 'system-images;android-34;google_apis;x86_64' not installed. Run: sdkmanager \"system-images;android-34;google_apis;x86_64\""
+        );
+
+        let error = anyhow::Error::from(DeviceError::tool_missing("xcrun"));
+        assert_eq!(
+            format_user_error(&error),
+            "Required tool 'xcrun' not found. Run 'xcode-select --install' to install the command line tools"
+        );
+
+        let error = anyhow::Error::from(DeviceError::PortConflict { port: 5037 });
+        assert!(format_user_error(&error).starts_with("Port 5037 is already in use. Stop"));
+
+        let error = anyhow::Error::from(DeviceError::timeout("adb", 10));
+        assert!(format_user_error(&error).starts_with("adb timed out after 10s. "));
+
+        let error = anyhow::Error::from(DeviceError::parse_failure("simctl runtimes", "EOF"));
+        assert!(format_user_error(&error).starts_with("Could not read simctl runtimes. Update"));
+    }
+
+    #[test]
+    fn test_only_structured_errors_have_hints() {
+        assert!(DeviceError::tool_missing("adb").hint().is_some());
+        assert!(DeviceError::not_found("test").hint().is_none());
+        assert!(DeviceError::other("msg").hint().is_none());
+        assert_eq!(
+            DeviceError::LicenseNotAccepted.error_title(),
+            "License Error"
+        );
+        assert_eq!(
+            DeviceError::PortConflict { port: 5554 }.error_title(),
+            "Port Conflict"
+        );
+    }
+
     #[test]
     fn test_error_display() {
         let err = DeviceError::not_found("test");
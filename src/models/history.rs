@@ -0,0 +1,258 @@
+//! Per-device operation history.
+//!
+//! Lifecycle operations (create, boot, stop, wipe, install) are recorded with their
+//! outcome so questions like "when did I last wipe this device?" can be
+//! answered from the details panel, across sessions. Usage markers (boot
+//! time, last time seen running) are tracked alongside to surface uptime and
+//! stale devices.
+
+use crate::constants::{files::DEVICE_HISTORY_FILE, limits::MAX_DEVICE_HISTORY_ENTRIES};
+use crate::models::DeviceId;
+use crate::utils::cache::cache_file_path;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+/// A lifecycle operation performed on a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeviceOperation {
+    Create,
+    Boot,
+    Stop,
+    Wipe,
+    Install,
+}
+
+impl DeviceOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeviceOperation::Create => "create",
+            DeviceOperation::Boot => "boot",
+            DeviceOperation::Stop => "stop",
+            DeviceOperation::Wipe => "wipe",
+            DeviceOperation::Install => "install",
+        }
+    }
+}
+
+/// One recorded operation and its outcome.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceOperationRecord {
+    pub at: DateTime<Local>,
+    pub operation: DeviceOperation,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl DeviceOperationRecord {
+    /// Single-line form used by the History tab.
+    pub fn display_line(&self) -> String {
+        let outcome = if self.success { "ok" } else { "failed" };
+        let mut line = format!(
+            "{} {:<7} [{outcome}]",
+            self.at.format("%Y-%m-%d %H:%M"),
+            self.operation.label()
+        );
+        if let Some(error) = &self.error {
+            line.push_str(&format!(" {error}"));
+        }
+        line
+    }
+}
+
+/// When a device was booted and when it was last seen running.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceUsage {
+    /// Boot time of the current run; `None` while the device is stopped
+    pub booted_at: Option<DateTime<Local>>,
+    /// Last refresh that saw the device running
+    pub last_seen_running: Option<DateTime<Local>>,
+}
+
+/// Operation history for every known device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceHistory {
+    devices: HashMap<DeviceId, Vec<DeviceOperationRecord>>,
+    #[serde(default)]
+    usage: HashMap<DeviceId, DeviceUsage>,
+    /// Whether changes are written back to disk. Only the history loaded by
+    /// the running app is persistent, so tests never touch the user's config.
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl DeviceHistory {
+    /// Records an operation, dropping the oldest entries beyond the cap.
+    pub fn record(
+        &mut self,
+        device: &DeviceId,
+        operation: DeviceOperation,
+        result: Result<(), String>,
+    ) {
+        let records = self.devices.entry(device.clone()).or_default();
+        records.push(DeviceOperationRecord {
+            at: Local::now(),
+            operation,
+            success: result.is_ok(),
+            error: result.err(),
+        });
+        if records.len() > MAX_DEVICE_HISTORY_ENTRIES {
+            let excess = records.len() - MAX_DEVICE_HISTORY_ENTRIES;
+            records.drain(..excess);
+        }
+        self.persist();
+    }
+
+    /// Recorded operations for a device, newest first.
+    pub fn entries_for(&self, device: &DeviceId) -> Vec<&DeviceOperationRecord> {
+        self.devices
+            .get(device)
+            .map(|records| records.iter().rev().collect())
+            .unwrap_or_default()
+    }
+
+    /// Updates usage markers from a refresh that observed the device.
+    /// Only boot and stop transitions are written to disk; the last-seen
+    /// time of a running device is saved by [`Self::flush`].
+    pub fn observe_device(&mut self, device: &DeviceId, is_running: bool) {
+        if !is_running && !self.usage.contains_key(device) {
+            return;
+        }
+        let usage = self.usage.entry(device.clone()).or_default();
+        let transitioned = if is_running {
+            let now = Local::now();
+            usage.last_seen_running = Some(now);
+            let just_booted = usage.booted_at.is_none();
+            if just_booted {
+                usage.booted_at = Some(now);
+            }
+            just_booted
+        } else {
+            usage.booted_at.take().is_some()
+        };
+        if transitioned {
+            self.persist();
+        }
+    }
+
+    /// Usage markers for a device, if it has ever been seen running.
+    pub fn usage_for(&self, device: &DeviceId) -> Option<&DeviceUsage> {
+        self.usage.get(device)
+    }
+
+    /// Forgets a device, e.g. after it has been deleted.
+    pub fn remove_device(&mut self, device: &DeviceId) {
+        let removed_records = self.devices.remove(device).is_some();
+        let removed_usage = self.usage.remove(device).is_some();
+        if removed_records || removed_usage {
+            self.persist();
+        }
+    }
+
+    /// Writes pending usage markers, e.g. before the app quits.
+    pub fn flush(&self) {
+        self.persist();
+    }
+
+    /// Loads the persisted history, falling back to an empty one.
+    /// The returned history saves itself after every change.
+    pub fn load_from_disk() -> Self {
+        let mut history = cache_file_path(DEVICE_HISTORY_FILE)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .unwrap_or_default();
+        history.persistent = true;
+        history
+    }
+
+    /// Writes the history to disk.
+    pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
+        let path = cache_file_path(DEVICE_HISTORY_FILE)?;
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn persist(&self) {
+        if self.persistent {
+            if let Err(e) = self.save_to_disk() {
+                log::warn!("Failed to save device history: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_is_per_device_newest_first_and_capped() {
+        let mut history = DeviceHistory::default();
+        let pixel = DeviceId::android("Pixel_7");
+        let ios = DeviceId::ios("Pixel_7");
+        history.record(&pixel, DeviceOperation::Boot, Ok(()));
+        history.record(
+            &pixel,
+            DeviceOperation::Wipe,
+            Err("emulator is running".to_string()),
+        );
+        history.record(&ios, DeviceOperation::Stop, Ok(()));
+
+        let entries = history.entries_for(&pixel);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].operation, DeviceOperation::Wipe);
+        assert!(!entries[0].success);
+        assert!(entries[0].display_line().contains("emulator is running"));
+        assert_eq!(entries[1].operation, DeviceOperation::Boot);
+
+        for _ in 0..MAX_DEVICE_HISTORY_ENTRIES {
+            history.record(&pixel, DeviceOperation::Stop, Ok(()));
+        }
+        let entries = history.entries_for(&pixel);
+        assert_eq!(entries.len(), MAX_DEVICE_HISTORY_ENTRIES);
+        assert!(entries
+            .iter()
+            .all(|entry| entry.operation == DeviceOperation::Stop));
+
+        history.remove_device(&pixel);
+        assert!(history.entries_for(&pixel).is_empty());
+        assert_eq!(history.entries_for(&ios).len(), 1);
+    }
+
+    #[test]
+    fn test_observe_device_tracks_boot_and_last_seen() {
+        let mut history = DeviceHistory::default();
+        let pixel = DeviceId::android("Pixel_7");
+        history.observe_device(&pixel, false);
+        assert!(history.usage_for(&pixel).is_none());
+
+        history.observe_device(&pixel, true);
+        let booted_at = history
+            .usage_for(&pixel)
+            .and_then(|usage| usage.booted_at)
+            .unwrap();
+
+        history.observe_device(&pixel, true);
+        let usage = history.usage_for(&pixel).unwrap();
+        assert_eq!(usage.booted_at, Some(booted_at));
+        assert!(usage.last_seen_running.unwrap() >= booted_at);
+
+        history.observe_device(&pixel, false);
+        let usage = history.usage_for(&pixel).unwrap();
+        assert!(usage.booted_at.is_none());
+        assert!(usage.last_seen_running.is_some());
+    }
+
+    #[test]
+    fn test_history_keys_keep_platform_prefixed_format() {
+        let mut history = DeviceHistory::default();
+        history.record(&DeviceId::android("Pixel 7"), DeviceOperation::Boot, Ok(()));
+
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.contains("\"android:Pixel 7\""));
+        let restored: DeviceHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.entries_for(&DeviceId::android("Pixel 7")).len(), 1);
+    }
+}
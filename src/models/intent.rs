@@ -0,0 +1,258 @@
+//! Intents sent to a running emulator with `adb shell am`.
+//!
+//! Deep links, push-notification handlers, and broadcast receivers are
+//! usually exercised by hand-typing `am start` or `am broadcast` commands.
+//! An [`AndroidIntent`] holds the fields of such a command, and the
+//! [`IntentHistory`] keeps the recently sent ones per device so they can be
+//! sent again.
+
+use crate::constants::{
+    commands::adb, files::INTENT_HISTORY_FILE, limits::MAX_INTENT_HISTORY_ENTRIES,
+};
+use crate::models::DeviceId;
+use crate::utils::cache::cache_file_path;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+/// How an intent is delivered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentKind {
+    /// `am start`, opening an activity
+    #[default]
+    Activity,
+    /// `am broadcast`, delivered to broadcast receivers
+    Broadcast,
+}
+
+impl IntentKind {
+    pub fn toggle(self) -> Self {
+        match self {
+            IntentKind::Activity => IntentKind::Broadcast,
+            IntentKind::Broadcast => IntentKind::Activity,
+        }
+    }
+
+    fn am_command(self) -> &'static str {
+        match self {
+            IntentKind::Activity => adb::AM_START,
+            IntentKind::Broadcast => adb::AM_BROADCAST,
+        }
+    }
+}
+
+/// The fields of an `am start` or `am broadcast` command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AndroidIntent {
+    pub kind: IntentKind,
+    /// e.g. `android.intent.action.VIEW`
+    pub action: String,
+    /// e.g. `myapp://orders/42`
+    pub data_uri: String,
+    /// `package/.Activity` or `package/.Receiver`
+    pub component: String,
+    /// Comma-separated `key=value` pairs; a value prefixed with `i:`, `l:`,
+    /// `f:`, or `b:` is sent as an int, long, float, or boolean extra
+    pub extras: String,
+}
+
+impl AndroidIntent {
+    /// Arguments of `adb shell` that send this intent, unquoted.
+    /// Fails when neither an action nor a component is set, or when an
+    /// extra is not a `key=value` pair.
+    pub fn am_args(&self) -> Result<Vec<String>> {
+        let action = self.action.trim();
+        let data_uri = self.data_uri.trim();
+        let component = self.component.trim();
+        if action.is_empty() && component.is_empty() {
+            bail!("An intent needs an action or a component");
+        }
+
+        let mut args = vec![adb::AM.to_string(), self.kind.am_command().to_string()];
+        for (flag, value) in [
+            (adb::INTENT_ACTION, action),
+            (adb::INTENT_DATA, data_uri),
+            (adb::INTENT_COMPONENT, component),
+        ] {
+            if !value.is_empty() {
+                args.extend([flag.to_string(), value.to_string()]);
+            }
+        }
+        for extra in self.extras.split(',').map(str::trim) {
+            if extra.is_empty() {
+                continue;
+            }
+            let Some((key, value)) = extra.split_once('=') else {
+                bail!("Extra '{extra}' is not a key=value pair");
+            };
+            let key = key.trim();
+            if key.is_empty() {
+                bail!("Extra '{extra}' has no key");
+            }
+            let value = value.trim();
+            let (flag, value) = match value.split_once(':') {
+                Some(("i", number)) => (adb::INTENT_EXTRA_INT, number),
+                Some(("l", number)) => (adb::INTENT_EXTRA_LONG, number),
+                Some(("f", number)) => (adb::INTENT_EXTRA_FLOAT, number),
+                Some(("b", flag)) => (adb::INTENT_EXTRA_BOOL, flag),
+                _ => (adb::INTENT_EXTRA_STRING, value),
+            };
+            args.extend([flag.to_string(), key.to_string(), value.to_string()]);
+        }
+        Ok(args)
+    }
+
+    /// One-line form for the history list, e.g.
+    /// `start -a android.intent.action.VIEW -d myapp://orders/42`.
+    pub fn summary(&self) -> String {
+        self.am_args()
+            .map(|args| args[1..].join(" "))
+            .unwrap_or_default()
+    }
+}
+
+/// Recently sent intents for every device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntentHistory {
+    devices: HashMap<DeviceId, Vec<AndroidIntent>>,
+    /// Whether changes are written back to disk, as for `DeviceHistory`.
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl IntentHistory {
+    /// Sent intents of a device, most recent first.
+    pub fn entries_for(&self, device: &DeviceId) -> &[AndroidIntent] {
+        self.devices.get(device).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Records a sent intent. Sending the same intent again moves it to the
+    /// top rather than listing it twice; the oldest entries beyond the cap
+    /// are dropped.
+    pub fn record(&mut self, device: &DeviceId, intent: AndroidIntent) {
+        let intents = self.devices.entry(device.clone()).or_default();
+        intents.retain(|existing| *existing != intent);
+        intents.insert(0, intent);
+        intents.truncate(MAX_INTENT_HISTORY_ENTRIES);
+        self.persist();
+    }
+
+    /// Forgets a device, e.g. after it has been deleted.
+    pub fn remove_device(&mut self, device: &DeviceId) {
+        if self.devices.remove(device).is_some() {
+            self.persist();
+        }
+    }
+
+    /// Loads the saved history, falling back to none.
+    /// The returned value saves itself after every change.
+    pub fn load_from_disk() -> Self {
+        let mut history = cache_file_path(INTENT_HISTORY_FILE)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .unwrap_or_default();
+        history.persistent = true;
+        history
+    }
+
+    /// Writes the history to disk.
+    pub fn save_to_disk(&self) -> Result<()> {
+        let path = cache_file_path(INTENT_HISTORY_FILE)?;
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn persist(&self) {
+        if self.persistent {
+            if let Err(e) = self.save_to_disk() {
+                log::warn!("Failed to save intent history: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_am_args_include_set_fields_and_typed_extras() {
+        let intent = AndroidIntent {
+            kind: IntentKind::Activity,
+            action: " android.intent.action.VIEW ".to_string(),
+            data_uri: "myapp://orders/42".to_string(),
+            component: String::new(),
+            extras: "source=push, count=i:3,, debug = b:true".to_string(),
+        };
+
+        assert_eq!(
+            intent.am_args().unwrap(),
+            [
+                "am",
+                "start",
+                "-a",
+                "android.intent.action.VIEW",
+                "-d",
+                "myapp://orders/42",
+                "--es",
+                "source",
+                "push",
+                "--ei",
+                "count",
+                "3",
+                "--ez",
+                "debug",
+                "true",
+            ]
+        );
+
+        let broadcast = AndroidIntent {
+            kind: IntentKind::Broadcast,
+            component: "com.example/.SyncReceiver".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            broadcast.summary(),
+            "broadcast -n com.example/.SyncReceiver"
+        );
+    }
+
+    #[test]
+    fn test_am_args_reject_incomplete_intents() {
+        assert!(AndroidIntent::default().am_args().is_err());
+        let intent = AndroidIntent {
+            action: "android.intent.action.VIEW".to_string(),
+            extras: "source".to_string(),
+            ..Default::default()
+        };
+        assert!(intent.am_args().is_err());
+    }
+
+    #[test]
+    fn test_history_moves_repeated_intents_to_top_and_caps_entries() {
+        let device = DeviceId::android("Pixel_7_API_34");
+        let intent = |index: usize| AndroidIntent {
+            action: format!("com.example.ACTION_{index}"),
+            ..Default::default()
+        };
+        let mut history = IntentHistory::default();
+        for index in 0..MAX_INTENT_HISTORY_ENTRIES + 2 {
+            history.record(&device, intent(index));
+        }
+        history.record(&device, intent(5));
+
+        let entries = history.entries_for(&device);
+        assert_eq!(entries.len(), MAX_INTENT_HISTORY_ENTRIES);
+        assert_eq!(entries[0], intent(5));
+        assert_eq!(entries[1], intent(MAX_INTENT_HISTORY_ENTRIES + 1));
+        assert_eq!(
+            entries.iter().filter(|entry| **entry == intent(5)).count(),
+            1
+        );
+        assert!(history.entries_for(&DeviceId::android("Other")).is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+//! Problems that keep an AVD from launching.
+//!
+//! When the emulator exits with an error, or an AVD never shows up as
+//! running, emu checks the usual causes and lists what it found together
+//! with a fix. Some fixes (installing the system image, removing stale lock
+//! files) emu can apply itself.
+
+use crate::constants::{
+    android::{EMULATOR_PORT_BASE, EMULATOR_PORT_MAX},
+    messages::launch_issues::*,
+};
+use std::path::PathBuf;
+
+/// One problem found by the launch diagnosis of an AVD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchIssue {
+    /// The system image the AVD boots from is not installed
+    MissingSystemImage { package_id: String },
+    /// Lock files of an emulator that did not shut down cleanly
+    StaleLocks { lock_files: Vec<PathBuf> },
+    /// No console port is free for another emulator
+    PortsInUse,
+    /// Too little free space on the disk holding the AVD data
+    LowDisk { available_mb: u64, required_mb: u64 },
+    /// The emulator cannot use hardware acceleration
+    NoAcceleration { details: String },
+}
+
+impl LaunchIssue {
+    /// What is wrong, in one line.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::MissingSystemImage { package_id } => {
+                MISSING_SYSTEM_IMAGE.replace("{}", package_id)
+            }
+            Self::StaleLocks { lock_files } => {
+                STALE_LOCKS.replace("{}", &lock_files.len().to_string())
+            }
+            Self::PortsInUse => PORTS_IN_USE
+                .replacen("{}", &EMULATOR_PORT_BASE.to_string(), 1)
+                .replacen("{}", &EMULATOR_PORT_MAX.to_string(), 1),
+            Self::LowDisk {
+                available_mb,
+                required_mb,
+            } => LOW_DISK
+                .replacen("{}", &available_mb.to_string(), 1)
+                .replacen("{}", &required_mb.to_string(), 1),
+            Self::NoAcceleration { details } => NO_ACCELERATION.replace("{}", details),
+        }
+    }
+
+    /// How to fix it.
+    pub fn fix_hint(&self) -> &'static str {
+        match self {
+            Self::MissingSystemImage { .. } => MISSING_SYSTEM_IMAGE_FIX,
+            Self::StaleLocks { .. } => STALE_LOCKS_FIX,
+            Self::PortsInUse => PORTS_IN_USE_FIX,
+            Self::LowDisk { .. } => LOW_DISK_FIX,
+            Self::NoAcceleration { .. } => NO_ACCELERATION_FIX,
+        }
+    }
+
+    /// Whether emu can apply the fix itself.
+    pub fn can_fix(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingSystemImage { .. } | Self::StaleLocks { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summaries_and_fixable_issues() {
+        let missing = LaunchIssue::MissingSystemImage {
+            package_id: "system-images;android-34;google_apis;x86_64".to_string(),
+        };
+        assert_eq!(
+            missing.summary(),
+            "System image system-images;android-34;google_apis;x86_64 is not installed"
+        );
+        assert!(missing.can_fix());
+
+        let low_disk = LaunchIssue::LowDisk {
+            available_mb: 512,
+            required_mb: 2048,
+        };
+        assert_eq!(
+            low_disk.summary(),
+            "Only 512 MB free where the AVD is stored, 2048 MB recommended"
+        );
+        assert!(!low_disk.can_fix());
+        assert_eq!(
+            LaunchIssue::PortsInUse.summary(),
+            "Every emulator console port from 5554 to 5584 is in use"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Resizable split ratios of the main screen.
+//!
+//! The split between the device panels and the log panel, and the width of
+//! the device details column, can be adjusted from the keyboard. The ratios
+//! are saved to the config directory and restored on the next start.
+
+use crate::constants::{
+    files::PANEL_LAYOUT_FILE,
+    ui_layout::{
+        DEVICE_DETAILS_PANEL_PERCENTAGE, DEVICE_PANELS_PERCENTAGE, MAX_DETAILS_PANEL_PERCENTAGE,
+        MAX_DEVICE_PANELS_PERCENTAGE, MIN_DETAILS_PANEL_PERCENTAGE, MIN_DEVICE_PANELS_PERCENTAGE,
+        PANEL_RESIZE_STEP,
+    },
+};
+use crate::utils::cache::cache_file_path;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Percentages used to split the main screen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PanelLayout {
+    /// Height share of the device panels; the log panel gets the rest
+    pub device_panels_percentage: u16,
+    /// Width share of the details column; Android and iOS split the rest
+    pub details_panel_percentage: u16,
+    /// Whether changes are written back to disk, as for `DeviceHistory`.
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        Self {
+            device_panels_percentage: DEVICE_PANELS_PERCENTAGE,
+            details_panel_percentage: DEVICE_DETAILS_PANEL_PERCENTAGE,
+            persistent: false,
+        }
+    }
+}
+
+impl PanelLayout {
+    /// Width percentages of the Android, iOS, and details columns.
+    pub fn column_percentages(&self) -> (u16, u16, u16) {
+        let details = self.details_panel_percentage;
+        let android = (100 - details) / 2;
+        (android, 100 - details - android, details)
+    }
+
+    /// Grows or shrinks the device panels against the log panel.
+    /// Returns false when the limit has already been reached.
+    pub fn resize_device_panels(&mut self, grow: bool) -> bool {
+        let resized = Self::step(
+            self.device_panels_percentage,
+            grow,
+            MIN_DEVICE_PANELS_PERCENTAGE,
+            MAX_DEVICE_PANELS_PERCENTAGE,
+        );
+        self.apply(resized, self.details_panel_percentage)
+    }
+
+    /// Widens or narrows the details column against the device lists.
+    /// Returns false when the limit has already been reached.
+    pub fn resize_details_panel(&mut self, grow: bool) -> bool {
+        let resized = Self::step(
+            self.details_panel_percentage,
+            grow,
+            MIN_DETAILS_PANEL_PERCENTAGE,
+            MAX_DETAILS_PANEL_PERCENTAGE,
+        );
+        self.apply(self.device_panels_percentage, resized)
+    }
+
+    /// Restores the default split. Returns false if nothing changed.
+    pub fn reset(&mut self) -> bool {
+        let default = Self::default();
+        self.apply(
+            default.device_panels_percentage,
+            default.details_panel_percentage,
+        )
+    }
+
+    /// Loads the saved layout, falling back to the default split.
+    /// Out-of-range values from a hand-edited file are clamped.
+    /// The returned layout saves itself after every change.
+    pub fn load_from_disk() -> Self {
+        let mut layout = cache_file_path(PANEL_LAYOUT_FILE)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .unwrap_or_default();
+        layout.device_panels_percentage = layout
+            .device_panels_percentage
+            .clamp(MIN_DEVICE_PANELS_PERCENTAGE, MAX_DEVICE_PANELS_PERCENTAGE);
+        layout.details_panel_percentage = layout
+            .details_panel_percentage
+            .clamp(MIN_DETAILS_PANEL_PERCENTAGE, MAX_DETAILS_PANEL_PERCENTAGE);
+        layout.persistent = true;
+        layout
+    }
+
+    /// Writes the layout to disk.
+    pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
+        let path = cache_file_path(PANEL_LAYOUT_FILE)?;
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn step(value: u16, grow: bool, min: u16, max: u16) -> u16 {
+        if grow {
+            value.saturating_add(PANEL_RESIZE_STEP).min(max)
+        } else {
+            value.saturating_sub(PANEL_RESIZE_STEP).max(min)
+        }
+    }
+
+    fn apply(&mut self, device_panels_percentage: u16, details_panel_percentage: u16) -> bool {
+        if device_panels_percentage == self.device_panels_percentage
+            && details_panel_percentage == self.details_panel_percentage
+        {
+            return false;
+        }
+        self.device_panels_percentage = device_panels_percentage;
+        self.details_panel_percentage = details_panel_percentage;
+        if self.persistent {
+            if let Err(e) = self.save_to_disk() {
+                log::warn!("Failed to save panel layout: {e}");
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_is_clamped_and_reset_restores_defaults() {
+        let mut layout = PanelLayout::default();
+        assert_eq!(
+            layout.column_percentages(),
+            (
+                (100 - DEVICE_DETAILS_PANEL_PERCENTAGE) / 2,
+                100 - DEVICE_DETAILS_PANEL_PERCENTAGE - (100 - DEVICE_DETAILS_PANEL_PERCENTAGE) / 2,
+                DEVICE_DETAILS_PANEL_PERCENTAGE
+            )
+        );
+
+        while layout.resize_device_panels(true) {}
+        assert_eq!(
+            layout.device_panels_percentage,
+            MAX_DEVICE_PANELS_PERCENTAGE
+        );
+        while layout.resize_details_panel(false) {}
+        assert_eq!(
+            layout.details_panel_percentage,
+            MIN_DETAILS_PANEL_PERCENTAGE
+        );
+        let (android, ios, details) = layout.column_percentages();
+        assert_eq!(android + ios + details, 100);
+
+        assert!(layout.reset());
+        assert_eq!(layout, PanelLayout::default());
+        assert!(!layout.reset());
+    }
+
+    #[test]
+    fn test_missing_fields_fall_back_to_defaults() {
+        let layout: PanelLayout =
+            serde_json::from_str(r#"{"details_panel_percentage": 50}"#).unwrap();
+        assert_eq!(layout.details_panel_percentage, 50);
+        assert_eq!(layout.device_panels_percentage, DEVICE_PANELS_PERCENTAGE);
+    }
+}
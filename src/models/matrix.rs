@@ -0,0 +1,153 @@
+//! Test matrices for release QA.
+//!
+//! A matrix crosses a set of device types with a set of API levels, or iOS
+//! runtimes, and creates one device per combination. Every device is named
+//! the same way, e.g. "Pixel 7 API 34" or "iPhone 15 iOS 17.5", and joins a
+//! group through a `matrix:<group>` tag so the whole group boots at once.
+
+use super::{DeviceAnnotation, NameParts, NamingConfig, Platform};
+use crate::constants::defaults::MATRIX_GROUP_TAG_PREFIX;
+use crate::managers::common::DeviceConfig;
+
+/// Device types and versions to cross, each as `(identifier, display name)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestMatrix {
+    pub device_types: Vec<(String, String)>,
+    pub versions: Vec<(String, String)>,
+}
+
+impl TestMatrix {
+    /// Number of devices in the matrix.
+    pub fn len(&self) -> usize {
+        self.device_types.len() * self.versions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// One device per combination, grouped by device type. A naming
+    /// template wins over the default names, and names that come out the
+    /// same are suffixed.
+    pub fn configs(&self, platform: Platform, naming: &NamingConfig) -> Vec<DeviceConfig> {
+        let mut configs: Vec<DeviceConfig> = Vec::with_capacity(self.len());
+        for (device_type, device_display) in &self.device_types {
+            for (version, version_display) in &self.versions {
+                let name = naming
+                    .render(NameParts {
+                        device_type,
+                        device_display,
+                        version,
+                        version_display,
+                    })
+                    .unwrap_or_else(|| {
+                        device_name(platform, device_display, version, version_display)
+                    });
+                let name = naming.unused_name(&name, |candidate| {
+                    configs.iter().any(|config| config.name == candidate)
+                });
+                configs.push(DeviceConfig::new(
+                    name,
+                    device_type.clone(),
+                    version.clone(),
+                ));
+            }
+        }
+        configs
+    }
+}
+
+/// Name of a matrix device: the device type without its manufacturer,
+/// then "API <level>" on Android or the runtime name on iOS.
+pub fn device_name(
+    platform: Platform,
+    device_display: &str,
+    version: &str,
+    version_display: &str,
+) -> String {
+    let device = device_display
+        .split(" (")
+        .next()
+        .unwrap_or(device_display)
+        .trim();
+    match platform {
+        Platform::Android => format!("{device} API {version}"),
+        Platform::Ios => format!("{device} {version_display}"),
+    }
+}
+
+/// Tag that puts a device into `group`.
+pub fn group_tag(group: &str) -> String {
+    format!("{MATRIX_GROUP_TAG_PREFIX}{}", group.trim())
+}
+
+/// The first matrix group a device belongs to.
+pub fn group_of(annotation: &DeviceAnnotation) -> Option<&str> {
+    annotation
+        .tags
+        .iter()
+        .find_map(|tag| tag.strip_prefix(MATRIX_GROUP_TAG_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_names_every_combination_consistently() {
+        let matrix = TestMatrix {
+            device_types: vec![
+                ("pixel_7".to_string(), "Pixel 7 (Google)".to_string()),
+                (
+                    "pixel_tablet".to_string(),
+                    "Pixel Tablet (Google)".to_string(),
+                ),
+            ],
+            versions: vec![
+                ("35".to_string(), "API 35 - Android 15".to_string()),
+                ("34".to_string(), "API 34 - Android 14".to_string()),
+            ],
+        };
+        let names: Vec<String> = matrix
+            .configs(Platform::Android, &NamingConfig::default())
+            .into_iter()
+            .map(|config| config.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Pixel 7 API 35",
+                "Pixel 7 API 34",
+                "Pixel Tablet API 35",
+                "Pixel Tablet API 34"
+            ]
+        );
+        let naming = NamingConfig {
+            template: Some("{type}".to_string()),
+            ..NamingConfig::default()
+        };
+        let names: Vec<String> = matrix
+            .configs(Platform::Android, &naming)
+            .into_iter()
+            .map(|config| config.name)
+            .collect();
+        assert_eq!(
+            names,
+            ["pixel_7", "pixel_7_2", "pixel_tablet", "pixel_tablet_2"]
+        );
+        assert_eq!(
+            device_name(
+                Platform::Ios,
+                "iPhone 15",
+                "com.apple.CoreSimulator.SimRuntime.iOS-17-5",
+                "iOS 17.5"
+            ),
+            "iPhone 15 iOS 17.5"
+        );
+
+        let annotation =
+            DeviceAnnotation::from_input(&format!("smoke, {}", group_tag(" rc1 ")), "");
+        assert_eq!(group_of(&annotation), Some("rc1"));
+        assert_eq!(group_of(&DeviceAnnotation::default()), None);
+    }
+}
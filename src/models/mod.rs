@@ -5,21 +5,107 @@
 //!
 //! # Module Organization
 //!
+//! - `annotations` - User-defined device tags and notes
+//! - `app_data` - Backups of an app's data that a test state is restored from
+//! - `app_launch` - Launching an app with arguments and environment variables
+//! - `capabilities` - The operations the devices of a platform support
+//! - `catalog` - Recommended Android device configurations
+//! - `clock` - Changes to a device clock
+//! - `columns` - Columns of the device lists
+//! - `confirmation` - When destructive operations ask for confirmation
+//! - `developer_toggle` - Rendering and debugging aids of the developer options
 //! - `device` - Device structures for Android and iOS virtual devices
+//! - `device_input` - Keystrokes forwarded to a device
 //! - `device_info` - Dynamic device information and discovery system
 //! - `error` - Custom error types and error handling utilities
+//! - `history` - Per-device operation history
+//! - `intent` - Android intents and their per-device history
+//! - `launch_issue` - Problems that keep an AVD from launching
+//! - `layout` - Resizable panel split ratios
+//! - `matrix` - Test matrices of device types and versions for release QA
+//! - `monkey` - Monkey stress test runs and the failures they find
+//! - `naming` - Naming templates for new devices
 //! - `platform` - Platform definitions and platform-specific information
+//! - `preview` - Preview of a pending device creation
+//! - `process` - Processes and services running inside a device
+//! - `property` - Android system properties
+//! - `quick_boot` - Quick Boot snapshot and cold boot settings of an AVD
+//! - `radio` - Wi-Fi, Bluetooth and airplane mode of a device
+//! - `project` - Project-local `.emu.toml` configuration
+//! - `readiness` - Checks that decide when a booted device is ready for tests
+//! - `simctl` - Typed `simctl list devices/runtimes --json` output
+//! - `snippet` - Shell one-liners run against the selected device
+//! - `test_settings` - Device settings that UI tests rely on
+//! - `trace` - Perfetto traces and Instruments time profiles of a device
 
+pub mod annotations;
 pub mod api_level;
+pub mod app_data;
+pub mod app_launch;
+pub mod capabilities;
+pub mod catalog;
+pub mod clock;
+pub mod columns;
+pub mod confirmation;
 pub mod details;
+pub mod developer_toggle;
 pub mod device;
 pub mod device_info;
+pub mod device_input;
 pub mod error;
+pub mod history;
+pub mod intent;
+pub mod launch_issue;
+pub mod layout;
+pub mod matrix;
+pub mod monkey;
+pub mod naming;
 pub mod platform;
+pub mod preview;
+pub mod process;
+pub mod project;
+pub mod property;
+pub mod quick_boot;
+pub mod radio;
+pub mod readiness;
+pub mod simctl;
+pub mod snippet;
+pub mod test_settings;
+pub mod trace;
 
 // Re-export commonly used types for convenience
-pub use api_level::{ApiLevel, InstallProgress, SystemImageVariant};
+pub use annotations::{DeviceAnnotation, DeviceAnnotations};
+pub use api_level::{ApiLevel, DownloadEstimate, InstallProgress, SystemImageVariant};
+pub use app_launch::AppLaunch;
+pub use capabilities::DeviceCapabilities;
+pub use catalog::{CatalogEntry, DeviceCatalog};
+pub use clock::ClockChange;
+pub use columns::{DeviceColumn, DeviceListColumns};
+pub use confirmation::{BatchConfirmation, ConfirmWhen, ConfirmationPolicy};
 pub use details::DeviceDetails;
-pub use device::{AndroidDevice, DeviceStatus, IosDevice};
+pub use developer_toggle::{DeveloperToggle, DeveloperToggleStatus};
+pub use device::{AndroidDevice, DeviceId, DeviceStatus, IosDevice, ManagedDevice};
+pub use device_input::{DeviceInput, DeviceKey};
 pub use error::DeviceError;
+pub use history::{DeviceHistory, DeviceOperation, DeviceOperationRecord};
+pub use intent::{AndroidIntent, IntentHistory, IntentKind};
+pub use launch_issue::LaunchIssue;
+pub use layout::PanelLayout;
+pub use matrix::TestMatrix;
+pub use monkey::{MonkeyReport, MonkeyRun};
+pub use naming::{NameParts, NamingConfig};
 pub use platform::Platform;
+pub use preview::DeviceCreationPreview;
+pub use process::{DeviceProcess, DeviceService, ProcessSort};
+pub use project::{DiagnoseScope, OutputPaths, ProjectConfig, ToolPaths};
+pub use property::SystemProperty;
+pub use quick_boot::QuickBootStatus;
+pub use radio::{Radio, RadioStatus};
+pub use readiness::{ReadinessCheck, ReadinessConfig};
+pub use simctl::{
+    SimctlDevice, SimctlDeviceList, SimctlDeviceListDiff, SimctlRuntime, SimctlRuntimeList,
+    SimulatorUnavailableReason,
+};
+pub use snippet::{Snippet, SnippetTarget, SnippetsConfig};
+pub use test_settings::{TestSettingsBackup, TestSettingsConfig};
+pub use trace::TraceCapture;
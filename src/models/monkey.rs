@@ -0,0 +1,160 @@
+//! UI/Application Exerciser Monkey runs.
+//!
+//! `adb shell monkey` fires random taps, swipes, and key events at one
+//! package. A [`MonkeyRun`] holds the options of a run and a
+//! [`MonkeyReport`] collects the crashes and ANRs monkey prints while it runs.
+
+use crate::constants::{commands::adb, patterns::JAVA_PACKAGE};
+use anyhow::{bail, Context, Result};
+
+/// Options of one monkey run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyRun {
+    /// Package the events are confined to
+    pub package: String,
+    /// Number of events to inject
+    pub event_count: u32,
+    /// Pause between events in milliseconds
+    pub throttle_ms: u32,
+}
+
+impl MonkeyRun {
+    /// Builds a run from the text fields of the launcher.
+    pub fn parse(package: &str, event_count: &str, throttle_ms: &str) -> Result<Self> {
+        let package = package.trim();
+        if package.is_empty() {
+            bail!("Choose a package to run monkey against");
+        }
+        if !JAVA_PACKAGE.is_match(package) {
+            bail!("'{package}' is not a valid package name");
+        }
+        let event_count = event_count
+            .trim()
+            .parse()
+            .ok()
+            .filter(|count| *count > 0)
+            .with_context(|| format!("'{}' is not a valid event count", event_count.trim()))?;
+        let throttle_ms = throttle_ms
+            .trim()
+            .parse()
+            .with_context(|| format!("'{}' is not a valid throttle", throttle_ms.trim()))?;
+        Ok(Self {
+            package: package.to_string(),
+            event_count,
+            throttle_ms,
+        })
+    }
+
+    /// Arguments of `adb shell`, e.g.
+    /// `monkey -p com.example --throttle 100 -v 500`.
+    pub fn args(&self) -> Vec<String> {
+        vec![
+            adb::MONKEY.to_string(),
+            adb::MONKEY_PACKAGE.to_string(),
+            self.package.clone(),
+            adb::MONKEY_THROTTLE.to_string(),
+            self.throttle_ms.to_string(),
+            adb::MONKEY_VERBOSE.to_string(),
+            self.event_count.to_string(),
+        ]
+    }
+}
+
+/// What a monkey run found, collected from its output line by line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonkeyReport {
+    /// `// CRASH:` entries, with the exception message once it is printed
+    pub crashes: Vec<String>,
+    /// `// NOT RESPONDING:` entries
+    pub anrs: Vec<String>,
+    /// Events injected before monkey stopped, if it got that far
+    pub events_injected: Option<u32>,
+}
+
+impl MonkeyReport {
+    /// Records a line of monkey output.
+    pub fn push_line(&mut self, line: &str) {
+        let line = line.trim();
+        if let Some(crash) = line.strip_prefix("// CRASH: ") {
+            self.crashes.push(crash.to_string());
+        } else if let Some(message) = line.strip_prefix("// Short Msg: ") {
+            if let Some(crash) = self.crashes.last_mut() {
+                crash.push_str(" - ");
+                crash.push_str(message);
+            }
+        } else if let Some(anr) = line.strip_prefix("// NOT RESPONDING: ") {
+            self.anrs.push(anr.to_string());
+        } else if let Some(count) = line.strip_prefix("Events injected: ") {
+            self.events_injected = count.trim().parse().ok();
+        }
+    }
+
+    /// Whether the app crashed or stopped responding during the run.
+    pub fn has_failures(&self) -> bool {
+        !self.crashes.is_empty() || !self.anrs.is_empty()
+    }
+
+    /// Whether a line of monkey output reports a crash or an ANR.
+    pub fn is_failure_line(line: &str) -> bool {
+        let line = line.trim();
+        line.starts_with("// CRASH: ") || line.starts_with("// NOT RESPONDING: ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_run_and_args() {
+        let run = MonkeyRun::parse(" com.example ", "500", "100").unwrap();
+        assert_eq!(
+            run.args(),
+            [
+                "monkey",
+                "-p",
+                "com.example",
+                "--throttle",
+                "100",
+                "-v",
+                "500"
+            ]
+        );
+        assert!(MonkeyRun::parse("", "500", "0").is_err());
+        assert!(MonkeyRun::parse("example", "500", "0").is_err());
+        for package in ["com.foo;reboot", "com.foo rm", "com.foo'x"] {
+            assert!(MonkeyRun::parse(package, "500", "0").is_err());
+        }
+        assert!(MonkeyRun::parse("com.example", "0", "0").is_err());
+        assert!(MonkeyRun::parse("com.example", "500", "fast").is_err());
+    }
+
+    #[test]
+    fn test_report_collects_crashes_and_anrs() {
+        let mut report = MonkeyReport::default();
+        for line in [
+            ":Monkey: seed=1700000000000 count=500",
+            ":AllowPackage: com.example",
+            "// CRASH: com.example (pid 4242)",
+            "// Short Msg: java.lang.NullPointerException",
+            "// Long Msg: java.lang.NullPointerException: Attempt to invoke",
+            "// NOT RESPONDING: com.example (pid 4300)",
+            "** Monkey aborted due to error.",
+            "Events injected: 137",
+        ] {
+            report.push_line(line);
+        }
+
+        assert!(report.has_failures());
+        assert_eq!(
+            report.crashes,
+            ["com.example (pid 4242) - java.lang.NullPointerException"]
+        );
+        assert_eq!(report.anrs, ["com.example (pid 4300)"]);
+        assert_eq!(report.events_injected, Some(137));
+        assert!(MonkeyReport::is_failure_line(
+            "// CRASH: com.example (pid 4242)"
+        ));
+        assert!(!MonkeyReport::is_failure_line("Events injected: 137"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Naming conventions for new devices.
+//!
+//! Without a template, new devices get names like "Pixel 7 API 34". A team
+//! that wants every AVD named the same way, e.g. `pixel_7_34_arm64-v8a`, sets
+//! a template in `.emu.toml`. It names the device in the create form and the
+//! devices of a test matrix, and a suffix keeps names from colliding.
+
+use crate::constants::{defaults, ios::IOS_DEVICE_TYPE_PREFIX};
+use serde::Deserialize;
+
+/// The `[naming]` table of `.emu.toml`.
+///
+/// ```toml
+/// [naming]
+/// template = "{type}_{api}_{abi}"
+/// suffix = "_{n}"
+/// ```
+///
+/// `{type}` is the device type ID, such as `pixel_7` or `iPhone-15`, `{name}`
+/// its display name without the manufacturer, `{api}` the API level or iOS
+/// version, and `{abi}` the system image ABI (empty on iOS).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingConfig {
+    pub template: Option<String>,
+    /// Appended when a name is taken, `{n}` counting from 2
+    pub suffix: String,
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        Self {
+            template: None,
+            suffix: "_{n}".to_string(),
+        }
+    }
+}
+
+/// What a new device is, as the create form and matrix know it.
+#[derive(Debug, Clone, Copy)]
+pub struct NameParts<'a> {
+    /// Device type ID
+    pub device_type: &'a str,
+    /// Device type display name
+    pub device_display: &'a str,
+    /// API level, or iOS runtime identifier
+    pub version: &'a str,
+    /// Version display name, e.g. "iOS 17.5"
+    pub version_display: &'a str,
+}
+
+impl NamingConfig {
+    /// The template filled in for `parts`, or `None` without a template.
+    pub fn render(&self, parts: NameParts) -> Option<String> {
+        let template = self.template.as_deref()?;
+        let display = parts
+            .device_display
+            .split(" (")
+            .next()
+            .unwrap_or(parts.device_display)
+            .trim();
+        let (device_type, api, abi) = match parts.device_type.strip_prefix(IOS_DEVICE_TYPE_PREFIX) {
+            // "iOS 17.5" becomes "17.5"
+            Some(device_type) => {
+                let version = parts.version_display.rsplit(' ').next().unwrap_or("");
+                (device_type, version, "")
+            }
+            None => (parts.device_type, parts.version, defaults::default_abi()),
+        };
+
+        let name = template
+            .replace("{type}", device_type)
+            .replace("{name}", display)
+            .replace("{api}", api)
+            .replace("{abi}", abi);
+        let name = name.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// `name`, or the first suffixed variant of it that `is_taken` rejects.
+    pub fn unused_name(&self, name: &str, is_taken: impl Fn(&str) -> bool) -> String {
+        if !is_taken(name) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|number| format!("{name}{}", self.suffix.replace("{n}", &number.to_string())))
+            .find(|candidate| !is_taken(candidate))
+            .unwrap_or_else(|| name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_names_devices_and_avoids_collisions() {
+        let naming = NamingConfig {
+            template: Some("{type}_{api}_{abi}".to_string()),
+            ..NamingConfig::default()
+        };
+        let android = NameParts {
+            device_type: "pixel_7",
+            device_display: "Pixel 7 (Google)",
+            version: "34",
+            version_display: "API 34 - Android 14",
+        };
+        assert_eq!(
+            naming.render(android).unwrap(),
+            format!("pixel_7_34_{}", defaults::default_abi())
+        );
+
+        let naming = NamingConfig {
+            template: Some("{name} {api}".to_string()),
+            suffix: " ({n})".to_string(),
+        };
+        let ios = NameParts {
+            device_type: "com.apple.CoreSimulator.SimDeviceType.iPhone-15",
+            device_display: "iPhone 15",
+            version: "com.apple.CoreSimulator.SimRuntime.iOS-17-5",
+            version_display: "iOS 17.5",
+        };
+        assert_eq!(naming.render(ios).unwrap(), "iPhone 15 17.5");
+        assert_eq!(naming.render(android).unwrap(), "Pixel 7 34");
+
+        let taken = ["Pixel 7 34", "Pixel 7 34 (2)"];
+        assert_eq!(
+            naming.unused_name("Pixel 7 34", |name| taken.contains(&name)),
+            "Pixel 7 34 (3)"
+        );
+        assert_eq!(naming.unused_name("Pixel 8 34", |_| false), "Pixel 8 34");
+        assert_eq!(NamingConfig::default().render(android), None);
+    }
+}
@@ -0,0 +1,113 @@
+//! Preview of what a device creation request will produce.
+//!
+//! The create dialog shows this next to the form so the user can check the
+//! resolved system image, hardware profile, and the exact `avdmanager`
+//! invocation before anything is written to disk.
+
+use crate::constants::commands::avdmanager;
+use crate::utils::CommandLine;
+
+/// Resolved settings for a pending Android device creation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceCreationPreview {
+    /// Path of the avdmanager binary that will be run
+    pub avdmanager_path: String,
+    /// Full system image package (e.g., "system-images;android-34;google_apis;x86_64")
+    pub package_path: String,
+    /// System image tag (e.g., "google_apis_playstore")
+    pub tag: String,
+    /// System image ABI (e.g., "arm64-v8a")
+    pub abi: String,
+    /// Whether the system image is already installed
+    pub image_installed: bool,
+    /// Device profile ID passed to `--device`, if one matched
+    pub device_id: Option<String>,
+    /// Skin passed to `--skin`, if one matched
+    pub skin: Option<String>,
+    /// Screen resolution from the skin definition (e.g., "1080x2400")
+    pub resolution: Option<String>,
+    /// Screen density from the skin definition
+    pub dpi: Option<u32>,
+}
+
+impl DeviceCreationPreview {
+    /// Arguments passed to avdmanager for the given AVD name.
+    pub fn avdmanager_args(&self, avd_name: &str) -> Vec<String> {
+        let mut args = vec![
+            avdmanager::CREATE.to_string(),
+            avdmanager::AVD.to_string(),
+            "-n".to_string(),
+            avd_name.to_string(),
+            "-k".to_string(),
+            self.package_path.clone(),
+        ];
+        if let Some(device_id) = &self.device_id {
+            args.push(avdmanager::DEVICE_ARG.to_string());
+            args.push(device_id.clone());
+        }
+        if let Some(skin) = &self.skin {
+            args.push(avdmanager::SKIN_ARG.to_string());
+            args.push(skin.clone());
+        }
+        args
+    }
+
+    /// The avdmanager command that creates the given AVD.
+    pub fn command(&self, avd_name: &str) -> CommandLine {
+        CommandLine::new(&self.avdmanager_path, self.avdmanager_args(avd_name))
+    }
+
+    /// Rough disk usage in MB: the data partition plus a Quick Boot snapshot
+    /// roughly the size of RAM.
+    pub fn estimated_disk_mb(ram_mb: u32, storage_mb: u32) -> u32 {
+        storage_mb.saturating_add(ram_mb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview() -> DeviceCreationPreview {
+        DeviceCreationPreview {
+            avdmanager_path: "avdmanager".to_string(),
+            package_path: "system-images;android-34;google_apis;x86_64".to_string(),
+            tag: "google_apis".to_string(),
+            abi: "x86_64".to_string(),
+            image_installed: true,
+            device_id: Some("pixel_7".to_string()),
+            skin: None,
+            resolution: Some("1080x2400".to_string()),
+            dpi: Some(420),
+        }
+    }
+
+    #[test]
+    fn test_avdmanager_args_include_device_and_skin() {
+        let mut preview = preview();
+        assert_eq!(
+            preview.avdmanager_args("Pixel_7"),
+            vec![
+                "create",
+                "avd",
+                "-n",
+                "Pixel_7",
+                "-k",
+                "system-images;android-34;google_apis;x86_64",
+                "--device",
+                "pixel_7",
+            ]
+        );
+
+        preview.skin = Some("pixel_7".to_string());
+        assert!(preview
+            .command("Pixel_7")
+            .to_string()
+            .ends_with("--device pixel_7 --skin pixel_7"));
+    }
+
+    #[test]
+    fn test_estimated_disk_mb() {
+        assert_eq!(DeviceCreationPreview::estimated_disk_mb(2048, 8192), 10240);
+    }
+}
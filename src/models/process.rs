@@ -0,0 +1,141 @@
+//! Processes and services running inside a device.
+//!
+//! The process inspector lists what runs in a booted emulator or simulator,
+//! so a runaway service eating the emulator's RAM can be spotted and killed.
+//! Android reports processes with `ps -A` and services with
+//! `dumpsys activity services`; a simulator's launchd jobs come from
+//! `launchctl list`, which has no memory figures.
+
+/// One process on a device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceProcess {
+    pub pid: u32,
+    /// Process name, or the launchd label on iOS
+    pub name: String,
+    /// Owning user (Android only)
+    pub user: Option<String>,
+    /// Resident memory in KiB (Android only)
+    pub memory_kb: Option<u64>,
+}
+
+impl DeviceProcess {
+    /// The app package this process belongs to, e.g. `com.example` for
+    /// `com.example:sync`. `None` for native daemons like `surfaceflinger`.
+    pub fn package_name(&self) -> Option<&str> {
+        let package = self.name.split(':').next()?;
+        (package.contains('.') && !package.contains('/')).then_some(package)
+    }
+}
+
+/// One service registered on a device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceService {
+    /// Component (`package/.Service`) on Android, launchd label on iOS
+    pub name: String,
+    /// Process hosting the service, if it is running
+    pub pid: Option<u32>,
+}
+
+/// Order of the process inspector lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProcessSort {
+    /// Largest resident memory first; unknown memory sorts last
+    #[default]
+    Memory,
+    /// Alphabetical by name
+    Name,
+}
+
+impl ProcessSort {
+    pub fn toggle(self) -> Self {
+        match self {
+            ProcessSort::Memory => ProcessSort::Name,
+            ProcessSort::Name => ProcessSort::Memory,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProcessSort::Memory => "memory",
+            ProcessSort::Name => "name",
+        }
+    }
+
+    /// Sorts processes in place.
+    pub fn sort(self, processes: &mut [DeviceProcess]) {
+        match self {
+            ProcessSort::Memory => processes.sort_by(|a, b| {
+                b.memory_kb
+                    .cmp(&a.memory_kb)
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+            ProcessSort::Name => processes.sort_by(|a, b| a.name.cmp(&b.name)),
+        }
+    }
+}
+
+/// Parses `launchctl list` run inside a simulator: a `PID Status Label`
+/// header followed by tab-separated rows, with `-` as the PID of jobs that
+/// are not running. Returns `(pid, label)` pairs.
+pub fn parse_launchctl_list(output: &str) -> Vec<(Option<u32>, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let pid = columns.next()?;
+            let _status = columns.next()?;
+            let label = columns.next()?;
+            if pid == "PID" {
+                return None;
+            }
+            Some((pid.parse().ok(), label.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, name: &str, memory_kb: Option<u64>) -> DeviceProcess {
+        DeviceProcess {
+            pid,
+            name: name.to_string(),
+            user: None,
+            memory_kb,
+        }
+    }
+
+    #[test]
+    fn test_sort_by_memory_puts_unknown_memory_last() {
+        let mut processes = vec![
+            process(1, "init", None),
+            process(2, "com.example:sync", Some(40_000)),
+            process(3, "system_server", Some(250_000)),
+        ];
+
+        ProcessSort::Memory.sort(&mut processes);
+        let names: Vec<_> = processes.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["system_server", "com.example:sync", "init"]);
+
+        ProcessSort::Name.sort(&mut processes);
+        assert_eq!(processes[0].name, "com.example:sync");
+        assert_eq!(processes[0].package_name(), Some("com.example"));
+        assert_eq!(processes[1].package_name(), None);
+    }
+
+    #[test]
+    fn test_parse_launchctl_list() {
+        let output = "PID\tStatus\tLabel\n\
+                      412\t0\tcom.apple.SpringBoard\n\
+                      -\t0\tcom.apple.backboardd.watchdog\n";
+
+        assert_eq!(
+            parse_launchctl_list(output),
+            [
+                (Some(412), "com.apple.SpringBoard".to_string()),
+                (None, "com.apple.backboardd.watchdog".to_string()),
+            ]
+        );
+    }
+}
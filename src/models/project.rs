@@ -0,0 +1,428 @@
+//! Project-local configuration from `.emu.toml`.
+//!
+//! A repository can pin the devices it is usually tested on, set environment
+//! variables for the SDK tools, and run hooks around device lifecycle events.
+//!
+//! ```toml
+//! [devices]
+//! android = ["Pixel_7_API_34"]
+//! ios = ["iPhone 15"]
+//!
+//! [env]
+//! ANDROID_EMULATOR_HOME = "/path/to/.android"
+//!
+//! [hooks]
+//! after_start = "./scripts/seed-test-data.sh"
+//! after_stop = "echo stopped $EMU_DEVICE_NAME"
+//!
+//! [tools]
+//! android_sdk = "/opt/android-sdk"
+//! adb = "./tools/platform-tools/adb"
+//!
+//! [tools.android_sdks]
+//! canary = "/opt/android-sdk-canary"
+//!
+//! [readiness]
+//! checks = ["boot_completed", "package_manager"]
+//!
+//! [test_settings]
+//! apply_on_start = true
+//!
+//! [confirm]
+//! wipe = "running"
+//!
+//! [columns]
+//! show = ["status", "name", "version", "uptime"]
+//!
+//! [naming]
+//! template = "{type}_{api}_{abi}"
+//!
+//! [snippets.android]
+//! "Grant camera" = "{adb} -s {serial} shell pm grant com.example android.permission.CAMERA"
+//!
+//! [output]
+//! bugreport_dir = "./build/bugreports"
+//! recording_dir = "./build/recordings"
+//! trace_dir = "./build/traces"
+//! app_data_dir = "./build/app-data"
+//! diagnose_scope = "all"
+//! ```
+
+use crate::constants::{commands, files::PROJECT_CONFIG_FILE};
+use crate::models::{
+    AndroidDevice, ConfirmationPolicy, DeviceListColumns, DeviceOperation, IosDevice, NamingConfig,
+    ReadinessConfig, SnippetsConfig, TestSettingsConfig,
+};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Devices the project prefers, listed in display order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PinnedDevices {
+    /// AVD names (underscores and spaces are interchangeable)
+    pub android: Vec<String>,
+    /// Simulator names (without the iOS version suffix) or UDIDs
+    pub ios: Vec<String>,
+}
+
+/// Shell commands run after lifecycle events, from the project directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectHooks {
+    pub after_start: Option<String>,
+    pub after_stop: Option<String>,
+}
+
+impl ProjectHooks {
+    /// Hook name and command to run after an operation succeeded.
+    pub fn for_operation(&self, operation: DeviceOperation) -> Option<(&'static str, &str)> {
+        match operation {
+            DeviceOperation::Boot => self.after_start.as_deref().map(|cmd| ("after_start", cmd)),
+            DeviceOperation::Stop => self.after_stop.as_deref().map(|cmd| ("after_stop", cmd)),
+            DeviceOperation::Create | DeviceOperation::Wipe | DeviceOperation::Install => None,
+        }
+    }
+}
+
+/// Tool locations used instead of SDK discovery and `PATH` lookup.
+/// Relative paths are resolved against the project directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolPaths {
+    /// Android SDK root, used instead of `ANDROID_HOME` and `ANDROID_SDK_ROOT`
+    pub android_sdk: Option<PathBuf>,
+    pub adb: Option<PathBuf>,
+    pub emulator: Option<PathBuf>,
+    pub avdmanager: Option<PathBuf>,
+    pub sdkmanager: Option<PathBuf>,
+    /// `xcrun` used to run simctl, e.g. from a custom Xcode toolchain
+    pub xcrun: Option<PathBuf>,
+    /// `scrcpy` used to mirror Android devices
+    pub scrcpy: Option<PathBuf>,
+    /// Further Android SDK roots by name, selectable when creating a device
+    pub android_sdks: BTreeMap<String, PathBuf>,
+}
+
+impl ToolPaths {
+    /// Configured executables as (tool name, path).
+    pub fn executables(&self) -> Vec<(&'static str, &Path)> {
+        [
+            (commands::ADB, &self.adb),
+            (commands::EMULATOR, &self.emulator),
+            (commands::AVDMANAGER, &self.avdmanager),
+            (commands::SDKMANAGER, &self.sdkmanager),
+            (commands::XCRUN, &self.xcrun),
+            (commands::SCRCPY, &self.scrcpy),
+        ]
+        .into_iter()
+        .filter_map(|(tool, path)| Some((tool, path.as_deref()?)))
+        .collect()
+    }
+
+    fn resolve_relative_to(&mut self, root: &Path) {
+        for path in [
+            &mut self.android_sdk,
+            &mut self.adb,
+            &mut self.emulator,
+            &mut self.avdmanager,
+            &mut self.sdkmanager,
+            &mut self.xcrun,
+            &mut self.scrcpy,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.android_sdks.values_mut())
+        {
+            if path.is_relative() {
+                *path = root.join(&*path);
+            }
+        }
+    }
+}
+
+/// Where collected files are written. Relative paths are resolved against
+/// the project directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutputPaths {
+    /// Directory for `adb bugreport` zips and `simctl diagnose` archives,
+    /// instead of the working directory
+    pub bugreport_dir: Option<PathBuf>,
+    /// Directory for screen recordings made while mirroring with scrcpy,
+    /// instead of the working directory
+    pub recording_dir: Option<PathBuf>,
+    /// Directory for Perfetto traces, Instruments time profiles and network
+    /// captures, instead of the working directory
+    pub trace_dir: Option<PathBuf>,
+    /// Directory for app data backups, instead of the working directory
+    pub app_data_dir: Option<PathBuf>,
+    /// How much `simctl diagnose` collects
+    pub diagnose_scope: DiagnoseScope,
+}
+
+/// What `simctl diagnose` collects for a simulator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnoseScope {
+    /// The selected simulator and recent system logs, which keeps the
+    /// archive small and quick to collect
+    #[default]
+    Device,
+    /// Every simulator and all system logs
+    All,
+}
+
+/// Parsed `.emu.toml` together with the directory it was found in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub devices: PinnedDevices,
+    pub env: BTreeMap<String, String>,
+    pub hooks: ProjectHooks,
+    pub tools: ToolPaths,
+    pub readiness: ReadinessConfig,
+    pub test_settings: TestSettingsConfig,
+    pub confirm: ConfirmationPolicy,
+    pub columns: DeviceListColumns,
+    pub naming: NamingConfig,
+    pub snippets: SnippetsConfig,
+    pub output: OutputPaths,
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+impl ProjectConfig {
+    /// Reads `.emu.toml` from `dir`. Returns `Ok(None)` when there is no file,
+    /// and an error when the file exists but cannot be parsed.
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(PROJECT_CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut config = Self::parse(&content)
+            .with_context(|| format!("Invalid project config {}", path.display()))?;
+        config.tools.resolve_relative_to(dir);
+        for output_dir in [
+            &mut config.output.bugreport_dir,
+            &mut config.output.recording_dir,
+            &mut config.output.trace_dir,
+            &mut config.output.app_data_dir,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if output_dir.is_relative() {
+                *output_dir = dir.join(&*output_dir);
+            }
+        }
+        config.root = dir.to_path_buf();
+        Ok(Some(config))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Position of an Android device in the pin list, if pinned.
+    pub fn android_pin_index(&self, device: &AndroidDevice) -> Option<usize> {
+        let name = device.name.replace(' ', "_");
+        self.devices
+            .android
+            .iter()
+            .position(|pin| pin.trim().replace(' ', "_") == name)
+    }
+
+    /// Position of an iOS simulator in the pin list, if pinned.
+    pub fn ios_pin_index(&self, device: &IosDevice) -> Option<usize> {
+        let base_name = device.name.split(" (iOS ").next().unwrap_or(&device.name);
+        self.devices.ios.iter().position(|pin| {
+            let pin = pin.trim();
+            pin == device.udid || pin == device.name || pin == base_name
+        })
+    }
+
+    /// Moves pinned Android devices to the front in pin order, keeping the
+    /// existing order of everything else.
+    pub fn sort_android_pinned_first(&self, devices: &mut [AndroidDevice]) {
+        devices.sort_by_key(|device| self.android_pin_index(device).unwrap_or(usize::MAX));
+    }
+
+    /// Moves pinned iOS simulators to the front in pin order.
+    pub fn sort_ios_pinned_first(&self, devices: &mut [IosDevice]) {
+        devices.sort_by_key(|device| self.ios_pin_index(device).unwrap_or(usize::MAX));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeviceStatus;
+
+    fn ios_device(name: &str, udid: &str) -> IosDevice {
+        IosDevice {
+            name: name.to_string(),
+            udid: udid.to_string(),
+            device_type: String::new(),
+            ios_version: "17.0".to_string(),
+            runtime_version: "17.0".to_string(),
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            is_available: true,
+            availability_error: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_full_config() {
+        let config = ProjectConfig::parse(
+            r#"
+            [devices]
+            android = ["Pixel 7 API 34"]
+            ios = ["iPhone 15"]
+
+            [env]
+            ANDROID_EMULATOR_HOME = "/tmp/emu"
+
+            [hooks]
+            after_start = "make seed"
+
+            [test_settings]
+            apply_on_start = true
+
+            [confirm]
+            delete = "never"
+
+            [columns]
+            show = ["name", "uptime"]
+
+            [naming]
+            template = "{type}_{api}"
+
+            [snippets.ios]
+            "Open home" = "{xcrun} simctl openurl {udid} myapp://home"
+
+            [output]
+            diagnose_scope = "all"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.devices.android, vec!["Pixel 7 API 34"]);
+        assert_eq!(config.env["ANDROID_EMULATOR_HOME"], "/tmp/emu");
+        assert_eq!(
+            config.hooks.for_operation(DeviceOperation::Boot),
+            Some(("after_start", "make seed"))
+        );
+        assert!(config.hooks.for_operation(DeviceOperation::Stop).is_none());
+        assert!(config.test_settings.apply_on_start);
+        assert_eq!(config.confirm.delete, crate::models::ConfirmWhen::Never);
+        assert_eq!(
+            config.columns.visible(),
+            [
+                crate::models::DeviceColumn::Name,
+                crate::models::DeviceColumn::Uptime
+            ]
+        );
+        assert_eq!(config.naming.template.as_deref(), Some("{type}_{api}"));
+        assert_eq!(config.naming.suffix, "_{n}");
+        assert_eq!(
+            config.snippets.ios["Open home"],
+            "{xcrun} simctl openurl {udid} myapp://home"
+        );
+        assert_eq!(config.output.diagnose_scope, DiagnoseScope::All);
+        assert_eq!(
+            ProjectConfig::default().output.diagnose_scope,
+            DiagnoseScope::Device
+        );
+
+        assert!(ProjectConfig::parse("").unwrap().devices.android.is_empty());
+        assert!(ProjectConfig::parse("[devices]\nandriod = []").is_err());
+    }
+
+    #[test]
+    fn test_tool_paths_resolve_against_project_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(PROJECT_CONFIG_FILE),
+            r#"
+            [tools]
+            android_sdk = "/opt/android-sdk"
+            adb = "tools/adb"
+
+            [tools.android_sdks]
+            canary = "canary"
+
+            [output]
+            bugreport_dir = "bugreports"
+            recording_dir = "/tmp/recordings"
+            trace_dir = "traces"
+            app_data_dir = "app-data"
+            "#,
+        )
+        .unwrap();
+
+        let config = ProjectConfig::load(dir.path()).unwrap().unwrap();
+        assert_eq!(
+            config.tools.android_sdk,
+            Some(PathBuf::from("/opt/android-sdk"))
+        );
+        assert_eq!(
+            config.tools.android_sdks["canary"],
+            dir.path().join("canary")
+        );
+        assert_eq!(
+            config.output.bugreport_dir,
+            Some(dir.path().join("bugreports"))
+        );
+        assert_eq!(
+            config.output.recording_dir,
+            Some(PathBuf::from("/tmp/recordings"))
+        );
+        assert_eq!(config.output.trace_dir, Some(dir.path().join("traces")));
+        assert_eq!(
+            config.output.app_data_dir,
+            Some(dir.path().join("app-data"))
+        );
+        let adb = dir.path().join("tools/adb");
+        assert_eq!(
+            config.tools.executables(),
+            vec![(commands::ADB, adb.as_path())]
+        );
+        assert!(ProjectConfig::parse("[tools]\nsimctl = \"/usr/bin/simctl\"").is_err());
+    }
+
+    #[test]
+    fn test_pinned_devices_sort_first_in_pin_order() {
+        let config = ProjectConfig {
+            devices: PinnedDevices {
+                android: vec!["Wear OS".to_string(), "Pixel_7".to_string()],
+                ios: vec!["UDID-2".to_string(), "iPhone 15".to_string()],
+            },
+            ..Default::default()
+        };
+
+        let mut android: Vec<AndroidDevice> = ["Pixel_Tablet", "Pixel_7", "Wear_OS"]
+            .into_iter()
+            .map(|name| AndroidDevice {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        config.sort_android_pinned_first(&mut android);
+        let names: Vec<_> = android.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["Wear_OS", "Pixel_7", "Pixel_Tablet"]);
+
+        let mut ios = vec![
+            ios_device("iPhone 15 (iOS 17.0)", "UDID-1"),
+            ios_device("iPad Air (iOS 17.0)", "UDID-3"),
+            ios_device("iPhone SE (iOS 17.0)", "UDID-2"),
+        ];
+        config.sort_ios_pinned_first(&mut ios);
+        let udids: Vec<_> = ios.iter().map(|d| d.udid.as_str()).collect();
+        assert_eq!(udids, vec!["UDID-2", "UDID-1", "UDID-3"]);
+    }
+}
@@ -0,0 +1,56 @@
+//! Android system properties.
+//!
+//! The property browser lists `getprop` output for a running AVD and sets
+//! properties with `setprop`, e.g. `debug.layout` to draw layout bounds
+//! without digging through the developer options.
+
+/// Prefixes of properties that `setprop` refuses to change once set.
+const READ_ONLY_PREFIXES: &[&str] = &["ro.", "init.svc."];
+
+/// One `getprop` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemProperty {
+    pub name: String,
+    pub value: String,
+}
+
+impl SystemProperty {
+    /// Whether the property can be changed from the shell. `ro.*` properties
+    /// are fixed at boot and `init.svc.*` mirror service state.
+    pub fn is_writable(&self) -> bool {
+        !READ_ONLY_PREFIXES
+            .iter()
+            .any(|prefix| self.name.starts_with(prefix))
+    }
+
+    /// Case-insensitive match of `query` against the name or the value.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query) || self.value.to_lowercase().contains(&query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, value: &str) -> SystemProperty {
+        SystemProperty {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_writable_and_matches() {
+        assert!(property("debug.layout", "false").is_writable());
+        assert!(property("persist.sys.locale", "en-US").is_writable());
+        assert!(!property("ro.build.version.sdk", "34").is_writable());
+        assert!(!property("init.svc.adbd", "running").is_writable());
+
+        let locale = property("persist.sys.locale", "en-US");
+        assert!(locale.matches("LOCALE"));
+        assert!(locale.matches("en-us"));
+        assert!(!locale.matches("debug"));
+    }
+}
@@ -0,0 +1,24 @@
+//! Quick Boot state of an AVD.
+//!
+//! The emulator saves the state of a running AVD to its `default_boot`
+//! snapshot and resumes from it on the next launch. A stale snapshot can
+//! leave the AVD in a broken state, so emu shows whether one exists and can
+//! skip it for the next launch or for every launch.
+
+/// Quick Boot snapshot and cold boot settings of one AVD.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickBootStatus {
+    /// Size of the Quick Boot snapshot in bytes, `None` without one
+    pub snapshot_size: Option<u64>,
+    /// The next launch skips the snapshot
+    pub cold_boot_once: bool,
+    /// Every launch skips the snapshot, as `fastboot.forceColdBoot` says
+    pub always_cold_boot: bool,
+}
+
+impl QuickBootStatus {
+    /// Whether the next launch boots cold rather than resuming.
+    pub fn boots_cold(&self) -> bool {
+        self.cold_boot_once || self.always_cold_boot
+    }
+}
@@ -0,0 +1,97 @@
+//! Radios of a device that tests switch on and off.
+//!
+//! Their state is read from global settings: `wifi_on` and `bluetooth_on`
+//! are 1 while the radio is on, or 2 when it was turned back on during
+//! airplane mode; `airplane_mode_on` is 1 while airplane mode is on.
+
+use crate::constants::commands::adb::{
+    AIRPLANE_MODE_ON_SETTING, BLUETOOTH_ON_SETTING, WIFI_ON_SETTING,
+};
+
+/// A radio, or airplane mode, which switches the others off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radio {
+    Wifi,
+    Bluetooth,
+    AirplaneMode,
+}
+
+impl Radio {
+    pub const ALL: [Radio; 3] = [Radio::Wifi, Radio::Bluetooth, Radio::AirplaneMode];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Radio::Wifi => "Wi-Fi",
+            Radio::Bluetooth => "Bluetooth",
+            Radio::AirplaneMode => "Airplane mode",
+        }
+    }
+
+    /// Global setting that holds the state.
+    pub fn setting(self) -> &'static str {
+        match self {
+            Radio::Wifi => WIFI_ON_SETTING,
+            Radio::Bluetooth => BLUETOOTH_ON_SETTING,
+            Radio::AirplaneMode => AIRPLANE_MODE_ON_SETTING,
+        }
+    }
+
+    /// Whether the value of [`Self::setting`] means on. `None` for `null`
+    /// and other values the device should not report.
+    pub fn parse_setting(self, value: &str) -> Option<bool> {
+        match (self, value.trim()) {
+            (_, "0") => Some(false),
+            (_, "1") => Some(true),
+            (Radio::Wifi | Radio::Bluetooth, "2") => Some(true),
+            // Wi-Fi that airplane mode switched off
+            (Radio::Wifi, "3") => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Whether each radio of a device is on; `None` when it is unknown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RadioStatus {
+    pub wifi: Option<bool>,
+    pub bluetooth: Option<bool>,
+    pub airplane_mode: Option<bool>,
+}
+
+impl RadioStatus {
+    pub fn get(&self, radio: Radio) -> Option<bool> {
+        match radio {
+            Radio::Wifi => self.wifi,
+            Radio::Bluetooth => self.bluetooth,
+            Radio::AirplaneMode => self.airplane_mode,
+        }
+    }
+
+    pub fn set(&mut self, radio: Radio, enabled: Option<bool>) {
+        match radio {
+            Radio::Wifi => self.wifi = enabled,
+            Radio::Bluetooth => self.bluetooth = enabled,
+            Radio::AirplaneMode => self.airplane_mode = enabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_setting_values() {
+        assert_eq!(Radio::Wifi.parse_setting("1\n"), Some(true));
+        assert_eq!(Radio::Wifi.parse_setting("2"), Some(true));
+        assert_eq!(Radio::Wifi.parse_setting("3"), Some(false));
+        assert_eq!(Radio::Bluetooth.parse_setting("0"), Some(false));
+        assert_eq!(Radio::AirplaneMode.parse_setting("2"), None);
+        assert_eq!(Radio::AirplaneMode.parse_setting("null"), None);
+
+        let mut status = RadioStatus::default();
+        status.set(Radio::Bluetooth, Some(true));
+        assert_eq!(status.get(Radio::Bluetooth), Some(true));
+        assert_eq!(status.get(Radio::Wifi), None);
+    }
+}
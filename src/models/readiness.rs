@@ -0,0 +1,152 @@
+//! Checks that decide when a booted device is ready for tests.
+//!
+//! `sys.boot_completed` flips before the package manager answers and long
+//! before the launcher is on screen, so test runners that only wait for it
+//! still race the device. A readiness pipeline runs several checks in
+//! order and the device counts as ready once all of them pass.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// One step of a readiness pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessCheck {
+    /// Android reports `sys.boot_completed`; an iOS simulator is Booted
+    BootCompleted,
+    /// The package manager answers, so `adb install` does not fail
+    PackageManager,
+    /// The home screen has window focus
+    Launcher,
+    /// Window, transition, and animator animations are switched off.
+    /// Unlike the other checks this one changes the device settings.
+    AnimationsDisabled,
+}
+
+impl ReadinessCheck {
+    /// Every check, in the order a pipeline usually runs them.
+    pub const ALL: [ReadinessCheck; 4] = [
+        ReadinessCheck::BootCompleted,
+        ReadinessCheck::PackageManager,
+        ReadinessCheck::Launcher,
+        ReadinessCheck::AnimationsDisabled,
+    ];
+
+    /// Name used in `.emu.toml` and on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            ReadinessCheck::BootCompleted => "boot_completed",
+            ReadinessCheck::PackageManager => "package_manager",
+            ReadinessCheck::Launcher => "launcher",
+            ReadinessCheck::AnimationsDisabled => "animations_disabled",
+        }
+    }
+}
+
+impl fmt::Display for ReadinessCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ReadinessCheck {
+    type Err = String;
+
+    /// Accepts the names with `_` or `-`, e.g. `package-manager`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|check| check.name() == name)
+            .ok_or_else(|| {
+                let known: Vec<_> = Self::ALL.iter().map(|check| check.name()).collect();
+                format!(
+                    "unknown readiness check '{s}' (expected one of: {})",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+/// The `[readiness]` table of `.emu.toml`.
+///
+/// ```toml
+/// [readiness]
+/// checks = ["boot_completed", "package_manager"]
+///
+/// [readiness.devices]
+/// Pixel_7_API_34 = ["boot_completed", "launcher", "animations_disabled"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReadinessConfig {
+    /// Pipeline of devices without their own entry
+    pub checks: Option<Vec<ReadinessCheck>>,
+    /// Pipelines by AVD name, simulator name, or UDID
+    pub devices: BTreeMap<String, Vec<ReadinessCheck>>,
+}
+
+impl ReadinessConfig {
+    /// The pipeline for a device known by any of `names`. Spaces and
+    /// underscores in AVD names are interchangeable.
+    pub fn checks_for(&self, names: &[&str]) -> Vec<ReadinessCheck> {
+        let normalize = |name: &str| name.trim().replace(' ', "_");
+        self.devices
+            .iter()
+            .find(|(key, _)| names.iter().any(|name| normalize(key) == normalize(name)))
+            .map(|(_, checks)| checks.clone())
+            .or_else(|| self.checks.clone())
+            .unwrap_or_else(|| vec![ReadinessCheck::BootCompleted])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readiness_check_names_round_trip() {
+        for check in ReadinessCheck::ALL {
+            assert_eq!(check.name().parse::<ReadinessCheck>(), Ok(check));
+        }
+        assert_eq!(
+            "package-manager".parse::<ReadinessCheck>(),
+            Ok(ReadinessCheck::PackageManager)
+        );
+        assert!("launcher_visible"
+            .parse::<ReadinessCheck>()
+            .unwrap_err()
+            .contains("boot_completed"));
+    }
+
+    #[test]
+    fn test_checks_for_prefers_device_entry() {
+        let config: ReadinessConfig = toml::from_str(
+            r#"
+            checks = ["boot_completed", "package_manager"]
+
+            [devices]
+            Pixel_7_API_34 = ["launcher"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.checks_for(&["Pixel 7 API 34"]),
+            vec![ReadinessCheck::Launcher]
+        );
+        assert_eq!(
+            config.checks_for(&["iPhone 15", "ABC-123"]),
+            vec![
+                ReadinessCheck::BootCompleted,
+                ReadinessCheck::PackageManager
+            ]
+        );
+        assert_eq!(
+            ReadinessConfig::default().checks_for(&["Pixel_7_API_34"]),
+            vec![ReadinessCheck::BootCompleted]
+        );
+    }
+}
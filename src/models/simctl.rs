@@ -0,0 +1,357 @@
+//! Typed output of `xcrun simctl list devices --json` and
+//! `xcrun simctl list runtimes --json`.
+//!
+//! `IosManager` parses the device list once per refresh into these structs and
+//! compares it against the previous snapshot, so an unchanged list does not
+//! need to be turned into `IosDevice` values again.
+
+use super::DeviceError;
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// The `devices` section of `simctl list devices --json`, keyed by runtime
+/// identifier (e.g., "com.apple.CoreSimulator.SimRuntime.iOS-17-0").
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SimctlDeviceList {
+    #[serde(default)]
+    pub devices: BTreeMap<String, Vec<SimctlDevice>>,
+}
+
+/// A single simulator entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimctlDevice {
+    #[serde(default)]
+    pub udid: String,
+    #[serde(default)]
+    pub name: String,
+    /// "Booted", "Shutdown", "Creating", ...
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub is_available: bool,
+    #[serde(default)]
+    pub device_type_identifier: Option<String>,
+    #[serde(default)]
+    pub data_path: Option<String>,
+    #[serde(default)]
+    pub data_path_size: Option<u64>,
+    /// Why simctl considers the device unusable, set when `is_available` is false
+    #[serde(default)]
+    pub availability_error: Option<String>,
+}
+
+/// The output of `simctl list runtimes --json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SimctlRuntimeList {
+    #[serde(default)]
+    pub runtimes: Vec<SimctlRuntime>,
+}
+
+impl SimctlRuntimeList {
+    /// Parses the JSON printed by `simctl list runtimes --json`.
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| DeviceError::parse_failure("simctl runtimes", e).into())
+    }
+}
+
+/// A single runtime entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimctlRuntime {
+    /// e.g., "com.apple.CoreSimulator.SimRuntime.iOS-17-0"
+    #[serde(default)]
+    pub identifier: String,
+    /// e.g., "iOS 17.0"
+    #[serde(default)]
+    pub name: Option<String>,
+    /// e.g., "17.0"
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub is_available: bool,
+    #[serde(default)]
+    pub availability_error: Option<String>,
+}
+
+/// Why a simulator cannot be used, derived from its simctl `availabilityError`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatorUnavailableReason {
+    /// The runtime the simulator was created with is not installed
+    RuntimeMissing,
+    /// The installed Xcode no longer supports the device type or runtime
+    Unsupported,
+    /// Any other error, as reported by simctl
+    Other(String),
+}
+
+impl SimulatorUnavailableReason {
+    /// Classifies an `availabilityError` string such as
+    /// `runtime profile not found using "System" match policy`.
+    pub fn from_availability_error(error: &str) -> Self {
+        let error_lower = error.to_lowercase();
+        if error_lower.contains("runtime profile not found")
+            || error_lower.contains("runtime") && error_lower.contains("not installed")
+        {
+            Self::RuntimeMissing
+        } else if error_lower.contains("device type profile not found")
+            || error_lower.contains("unsupported")
+            || error_lower.contains("not supported")
+        {
+            Self::Unsupported
+        } else {
+            Self::Other(error.trim().to_string())
+        }
+    }
+
+    /// Short label shown next to the simulator name.
+    pub fn label(&self) -> &str {
+        match self {
+            Self::RuntimeMissing => "runtime missing",
+            Self::Unsupported => "unsupported",
+            Self::Other(error) => error,
+        }
+    }
+}
+
+/// UDIDs that differ between two device list snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimctlDeviceListDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Devices whose state, name, or availability changed
+    pub changed: Vec<String>,
+}
+
+impl SimctlDeviceListDiff {
+    /// Returns true when both snapshots describe the same devices.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl SimctlDeviceList {
+    /// Parses the JSON printed by `simctl list devices --json`.
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| DeviceError::parse_failure("simctl device list", e).into())
+    }
+
+    /// Iterates over every device with a UDID, paired with its runtime identifier.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SimctlDevice)> {
+        self.devices.iter().flat_map(|(runtime, devices)| {
+            devices
+                .iter()
+                .filter(|device| !device.udid.is_empty())
+                .map(move |device| (runtime.as_str(), device))
+        })
+    }
+
+    /// Finds a device and its runtime identifier by UDID.
+    pub fn find(&self, udid: &str) -> Option<(&str, &SimctlDevice)> {
+        self.iter().find(|(_, device)| device.udid == udid)
+    }
+
+    /// Compares this snapshot against an earlier one.
+    pub fn diff(&self, previous: &Self) -> SimctlDeviceListDiff {
+        let current: HashMap<&str, &SimctlDevice> = self
+            .iter()
+            .map(|(_, device)| (device.udid.as_str(), device))
+            .collect();
+        let before: HashMap<&str, &SimctlDevice> = previous
+            .iter()
+            .map(|(_, device)| (device.udid.as_str(), device))
+            .collect();
+
+        let mut diff = SimctlDeviceListDiff::default();
+        for (udid, device) in &current {
+            match before.get(udid) {
+                None => diff.added.push(udid.to_string()),
+                Some(old) if old != device => diff.changed.push(udid.to_string()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = before
+            .keys()
+            .filter(|udid| !current.contains_key(*udid))
+            .map(|udid| udid.to_string())
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff
+    }
+}
+
+/// Extracts the sorted bundle identifiers from `simctl listapps` output,
+/// which is printed as an old-style property list.
+pub fn parse_listapps_bundle_ids(output: &str) -> Vec<String> {
+    let mut bundle_ids: Vec<String> = output
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            (key.trim() == "CFBundleIdentifier").then(|| {
+                value
+                    .trim()
+                    .trim_end_matches(';')
+                    .trim_matches('"')
+                    .to_string()
+            })
+        })
+        .filter(|bundle_id| !bundle_id.is_empty())
+        .collect();
+    bundle_ids.sort();
+    bundle_ids.dedup();
+    bundle_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEVICES_JSON: &str = r#"{
+        "devices": {
+            "com.apple.CoreSimulator.SimRuntime.iOS-17-0": [
+                {
+                    "udid": "AAAA",
+                    "name": "iPhone 15",
+                    "state": "Booted",
+                    "isAvailable": true,
+                    "deviceTypeIdentifier": "com.apple.CoreSimulator.SimDeviceType.iPhone-15",
+                    "dataPathSize": 1048576
+                },
+                {
+                    "udid": "BBBB",
+                    "name": "iPad Air",
+                    "state": "Shutdown",
+                    "isAvailable": true,
+                    "lastBootedAt": "2024-01-01T00:00:00Z"
+                }
+            ],
+            "com.apple.CoreSimulator.SimRuntime.iOS-15-0": [
+                {
+                    "udid": "DDDD",
+                    "name": "iPhone 13",
+                    "state": "Shutdown",
+                    "isAvailable": false,
+                    "availabilityError": "runtime profile not found using \"System\" match policy"
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_device_list() {
+        let list = SimctlDeviceList::parse(DEVICES_JSON).unwrap();
+        assert_eq!(list.iter().count(), 3);
+
+        let (runtime, device) = list.find("AAAA").unwrap();
+        assert_eq!(runtime, "com.apple.CoreSimulator.SimRuntime.iOS-17-0");
+        assert_eq!(device.state, "Booted");
+        assert_eq!(device.data_path_size, Some(1_048_576));
+        assert!(list.find("CCCC").is_none());
+
+        let (_, unavailable) = list.find("DDDD").unwrap();
+        assert!(!unavailable.is_available);
+        assert_eq!(
+            unavailable
+                .availability_error
+                .as_deref()
+                .map(SimulatorUnavailableReason::from_availability_error),
+            Some(SimulatorUnavailableReason::RuntimeMissing)
+        );
+
+        assert!(SimctlDeviceList::parse("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_runtime_list() {
+        let list = SimctlRuntimeList::parse(
+            r#"{
+            "runtimes": [
+                {
+                    "identifier": "com.apple.CoreSimulator.SimRuntime.iOS-17-0",
+                    "name": "iOS 17.0",
+                    "version": "17.0",
+                    "isAvailable": true
+                },
+                {
+                    "identifier": "com.apple.CoreSimulator.SimRuntime.iOS-12-4",
+                    "name": "iOS 12.4",
+                    "isAvailable": false,
+                    "availabilityError": "This runtime is not supported by this version of Xcode"
+                }
+            ]
+        }"#,
+        )
+        .unwrap();
+
+        assert_eq!(list.runtimes.len(), 2);
+        assert!(list.runtimes[0].is_available);
+        assert_eq!(list.runtimes[1].version, None);
+        assert_eq!(
+            list.runtimes[1]
+                .availability_error
+                .as_deref()
+                .map(SimulatorUnavailableReason::from_availability_error),
+            Some(SimulatorUnavailableReason::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_unavailable_reason_classification() {
+        assert_eq!(
+            SimulatorUnavailableReason::from_availability_error("device type profile not found"),
+            SimulatorUnavailableReason::Unsupported
+        );
+        let other = SimulatorUnavailableReason::from_availability_error(" data is corrupt ");
+        assert_eq!(other.label(), "data is corrupt");
+        assert_eq!(
+            SimulatorUnavailableReason::RuntimeMissing.label(),
+            "runtime missing"
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed_devices() {
+        let previous = SimctlDeviceList::parse(DEVICES_JSON).unwrap();
+        assert!(previous.diff(&previous).is_empty());
+
+        let mut current = previous.clone();
+        let devices = current
+            .devices
+            .get_mut("com.apple.CoreSimulator.SimRuntime.iOS-17-0")
+            .unwrap();
+        devices[0].state = "Shutdown".to_string();
+        devices.remove(1);
+        devices.push(SimctlDevice {
+            udid: "CCCC".to_string(),
+            ..SimctlDevice::default()
+        });
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.added, vec!["CCCC"]);
+        assert_eq!(diff.removed, vec!["BBBB"]);
+        assert_eq!(diff.changed, vec!["AAAA"]);
+    }
+
+    #[test]
+    fn test_parse_listapps_bundle_ids() {
+        let output = r#"{
+    "com.apple.mobilesafari" =     {
+        CFBundleIdentifier = "com.apple.mobilesafari";
+        CFBundleName = Safari;
+    };
+    "com.example.app" =     {
+        CFBundleIdentifier = "com.example.app";
+    };
+}"#;
+        assert_eq!(
+            parse_listapps_bundle_ids(output),
+            vec!["com.apple.mobilesafari", "com.example.app"]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Shell one-liners run against the selected device.
+//!
+//! emu ships a few common `adb` and `simctl` commands, and a project adds
+//! its own under `[snippets]` in `.emu.toml`. Placeholders in a command are
+//! replaced with the selected device before it runs through `sh -c`.
+
+use crate::constants::commands::{self, snippets};
+use crate::constants::messages::errors::SNIPPET_NEEDS_RUNNING_DEVICE;
+use crate::models::Platform;
+use crate::utils::{command::quote_shell_arg, tool_paths::tool_path};
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The `[snippets]` table of `.emu.toml`, commands by name per platform.
+///
+/// ```toml
+/// [snippets.android]
+/// "Grant camera" = "{adb} -s {serial} shell pm grant com.example android.permission.CAMERA"
+///
+/// [snippets.ios]
+/// "Open deep link" = "{xcrun} simctl openurl {udid} myapp://home"
+/// ```
+///
+/// `{serial}` is the adb serial of a running AVD, `{avd}` the AVD name,
+/// `{udid}` the simulator UDID and `{name}` the device name. `{adb}` and
+/// `{xcrun}` run the tools configured under `[tools]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SnippetsConfig {
+    pub android: BTreeMap<String, String>,
+    pub ios: BTreeMap<String, String>,
+}
+
+impl SnippetsConfig {
+    /// The project's snippets for `platform`, then the built-in ones it
+    /// does not override by name.
+    pub fn for_platform(&self, platform: Platform) -> Vec<Snippet> {
+        let (project, builtin) = match platform {
+            Platform::Android => (&self.android, snippets::ANDROID),
+            Platform::Ios => (&self.ios, snippets::IOS),
+        };
+        project
+            .iter()
+            .map(|(name, command)| Snippet::new(name, command))
+            .chain(
+                builtin
+                    .iter()
+                    .filter(|(name, _)| !project.contains_key(*name))
+                    .map(|(name, command)| Snippet::new(name, command)),
+            )
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub name: String,
+    pub command: String,
+}
+
+/// What the placeholders of a snippet stand for.
+#[derive(Debug, Clone)]
+pub struct SnippetTarget {
+    pub platform: Platform,
+    /// AVD name or simulator UDID
+    pub identifier: String,
+    pub name: String,
+    /// adb serial, known while the AVD runs
+    pub serial: Option<String>,
+}
+
+impl Snippet {
+    fn new(name: &str, command: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            command: command.to_string(),
+        }
+    }
+
+    /// The command with its placeholders filled in and shell-quoted. Fails
+    /// when it needs the serial of an AVD that is not running.
+    pub fn render(&self, target: &SnippetTarget) -> Result<String> {
+        let serial = match (&target.serial, self.command.contains("{serial}")) {
+            (Some(serial), _) => serial.as_str(),
+            (None, false) => "",
+            (None, true) => bail!(SNIPPET_NEEDS_RUNNING_DEVICE.replace("{}", &target.name)),
+        };
+        let (avd, udid) = match target.platform {
+            Platform::Android => (target.identifier.as_str(), ""),
+            Platform::Ios => ("", target.identifier.as_str()),
+        };
+        let tool = |name: &str| {
+            tool_path(name).map_or_else(
+                || name.to_string(),
+                |path| quote_shell_arg(&path.to_string_lossy()),
+            )
+        };
+
+        Ok(self
+            .command
+            .replace("{adb}", &tool(commands::ADB))
+            .replace("{xcrun}", &tool(commands::XCRUN))
+            .replace("{serial}", &quote_shell_arg(serial))
+            .replace("{avd}", &quote_shell_arg(avd))
+            .replace("{udid}", &quote_shell_arg(udid))
+            .replace("{name}", &quote_shell_arg(&target.name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_snippets_override_builtin_and_fill_placeholders() {
+        let (builtin_name, _) = snippets::ANDROID[0];
+        let mut config = SnippetsConfig::default();
+        config.android.insert(
+            builtin_name.to_string(),
+            "{adb} -s {serial} shell echo {name}".to_string(),
+        );
+        let android = config.for_platform(Platform::Android);
+        assert_eq!(android.len(), snippets::ANDROID.len());
+        assert_eq!(
+            android
+                .iter()
+                .filter(|snippet| snippet.name == builtin_name)
+                .count(),
+            1
+        );
+        assert_eq!(
+            config.for_platform(Platform::Ios).len(),
+            snippets::IOS.len()
+        );
+
+        let mut target = SnippetTarget {
+            platform: Platform::Android,
+            identifier: "Pixel_7".to_string(),
+            name: "Pixel 7's".to_string(),
+            serial: None,
+        };
+        assert!(android[0].render(&target).is_err());
+        target.serial = Some("emulator-5554".to_string());
+        assert_eq!(
+            android[0].render(&target).unwrap(),
+            "adb -s emulator-5554 shell echo 'Pixel 7'\\''s'"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Device settings that UI tests commonly rely on.
+//!
+//! Espresso and XCUITest runs are flaky while animations play, and an iOS
+//! simulator with the host keyboard connected hides the software keyboard
+//! that typing tests expect. Applying the test settings switches both off;
+//! the returned backup holds what the device had before, so the change can
+//! be undone later.
+
+use serde::Deserialize;
+
+/// The `[test_settings]` table of `.emu.toml`.
+///
+/// ```toml
+/// [test_settings]
+/// apply_on_start = true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TestSettingsConfig {
+    /// Apply the test settings to every device once emu has started it
+    pub apply_on_start: bool,
+}
+
+/// Values a device had before the test settings were applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestSettingsBackup {
+    /// Window, transition, and animator scales, `None` where never set
+    Android {
+        animation_scales: Vec<Option<String>>,
+    },
+    /// Simulator.app's "Connect Hardware Keyboard" preference, `None` when
+    /// it was never changed
+    Ios {
+        connect_hardware_keyboard: Option<bool>,
+    },
+}
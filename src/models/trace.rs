@@ -0,0 +1,131 @@
+//! Performance traces of a running device.
+//!
+//! An AVD records a Perfetto system trace with `adb shell perfetto`, which
+//! opens in ui.perfetto.dev. A simulator records an Instruments time profile
+//! with `xcrun xctrace record`, which opens in Instruments. Either runs for a
+//! fixed duration and leaves one file behind.
+
+use crate::constants::{
+    commands::{adb, xctrace},
+    limits::MAX_TRACE_DURATION_SECS,
+};
+use crate::utils::command::quote_shell_arg;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// What to trace and for how long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceCapture {
+    /// Package whose app sections Perfetto records, or the process xctrace
+    /// attaches to; `None` traces the whole system
+    pub target: Option<String>,
+    pub duration_secs: u32,
+}
+
+impl TraceCapture {
+    /// Checks the dialog fields. Fails on a duration that is not a number
+    /// of seconds between 1 and `MAX_TRACE_DURATION_SECS`.
+    pub fn parse(target: &str, duration_secs: &str) -> Result<Self> {
+        let target = target.trim();
+        let duration_secs = duration_secs
+            .trim()
+            .parse()
+            .ok()
+            .filter(|secs| *secs > 0)
+            .with_context(|| format!("'{}' is not a valid duration", duration_secs.trim()))?;
+        if duration_secs > MAX_TRACE_DURATION_SECS {
+            bail!("Traces are limited to {MAX_TRACE_DURATION_SECS} seconds");
+        }
+        Ok(Self {
+            target: (!target.is_empty()).then(|| target.to_string()),
+            duration_secs,
+        })
+    }
+
+    /// Arguments of `adb shell` that record to `device_path`, e.g.
+    /// `perfetto -o /data/misc/perfetto-traces/t -t 10s -b 64mb sched ...`.
+    /// The device shell joins them, so the package is quoted.
+    pub fn perfetto_args(&self, device_path: &str) -> Vec<String> {
+        let mut args = vec![
+            adb::PERFETTO.to_string(),
+            adb::PERFETTO_OUTPUT.to_string(),
+            device_path.to_string(),
+            adb::PERFETTO_DURATION.to_string(),
+            format!("{}s", self.duration_secs),
+            adb::PERFETTO_BUFFER.to_string(),
+            adb::PERFETTO_BUFFER_SIZE.to_string(),
+        ];
+        if let Some(package) = &self.target {
+            args.extend([adb::PERFETTO_APP.to_string(), quote_shell_arg(package)]);
+        }
+        args.extend(adb::PERFETTO_CATEGORIES.iter().map(|c| c.to_string()));
+        args
+    }
+
+    /// Arguments of `xcrun` that record a time profile of `udid` to
+    /// `output`.
+    pub fn xctrace_args(&self, udid: &str, output: &Path) -> Vec<String> {
+        let mut args = vec![
+            xctrace::XCTRACE.to_string(),
+            xctrace::RECORD.to_string(),
+            xctrace::DEVICE.to_string(),
+            udid.to_string(),
+            xctrace::TEMPLATE.to_string(),
+            xctrace::TIME_PROFILER.to_string(),
+            xctrace::TIME_LIMIT.to_string(),
+            format!("{}s", self.duration_secs),
+            xctrace::OUTPUT.to_string(),
+            output.to_string_lossy().into_owned(),
+            xctrace::NO_PROMPT.to_string(),
+        ];
+        match &self.target {
+            Some(process) => args.extend([xctrace::ATTACH.to_string(), process.clone()]),
+            None => args.push(xctrace::ALL_PROCESSES.to_string()),
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_build_trace_commands() {
+        let capture = TraceCapture::parse(" com.example ", "5").unwrap();
+        assert_eq!(capture.target.as_deref(), Some("com.example"));
+        let args = capture.perfetto_args("/data/misc/perfetto-traces/t");
+        assert_eq!(
+            args[..9],
+            [
+                "perfetto",
+                "-o",
+                "/data/misc/perfetto-traces/t",
+                "-t",
+                "5s",
+                "-b",
+                "64mb",
+                "--app",
+                "com.example"
+            ]
+        );
+        assert_eq!(args.len(), 9 + adb::PERFETTO_CATEGORIES.len());
+
+        let system = TraceCapture::parse("", "30").unwrap();
+        assert_eq!(system.target, None);
+        let args = system.xctrace_args("UDID", Path::new("/tmp/t.trace"));
+        assert_eq!(args.last().map(String::as_str), Some("--all-processes"));
+        assert!(args.windows(2).any(|pair| pair == ["--time-limit", "30s"]));
+
+        // The package reaches the device shell as one word
+        let injected = TraceCapture::parse("com.foo; reboot", "5").unwrap();
+        let args = injected.perfetto_args("/data/misc/perfetto-traces/t");
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["--app", "'com.foo; reboot'"]));
+
+        assert!(TraceCapture::parse("", "0").is_err());
+        assert!(TraceCapture::parse("", "ten").is_err());
+        assert!(TraceCapture::parse("", &(MAX_TRACE_DURATION_SECS + 1).to_string()).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+//! UI-free device management for embedding emu in other programs.
+//!
+//! [`DeviceService`] wraps the Android and iOS managers behind one set of
+//! methods keyed by [`DeviceId`], returning [`DeviceError`] instead of
+//! platform-specific `anyhow` errors. It does not depend on the TUI, so it
+//! is available with `default-features = false`:
+//!
+//! ```toml
+//! [dependencies]
+//! emu = { version = "1", default-features = false }
+//! ```
+//!
+//! ```no_run
+//! use emu::models::DeviceId;
+//! use emu::service::DeviceService;
+//!
+//! # async fn example() -> Result<(), emu::models::DeviceError> {
+//! let service = DeviceService::new().await?;
+//! for device in service.list_devices().await? {
+//!     println!("{} {:?}", device.id(), device.status());
+//! }
+//! service.start(&DeviceId::android("Pixel_7_API_34")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::managers::{common::DeviceManager, AndroidManager, IosManager};
+use crate::models::{DeviceError, DeviceId, Platform, ReadinessCheck};
+
+pub use crate::models::ManagedDevice;
+
+/// Lists and controls Android emulators and iOS simulators without the TUI.
+///
+/// A platform whose tooling is missing is left out: its devices are not
+/// listed, and operations on them fail with
+/// [`DeviceError::PlatformNotSupported`].
+#[derive(Clone)]
+pub struct DeviceService {
+    android: Option<AndroidManager>,
+    ios: Option<IosManager>,
+}
+
+impl DeviceService {
+    /// Sets up every platform whose tooling is installed on this host.
+    ///
+    /// Fails with [`DeviceError::SdkNotFound`] when neither the Android SDK
+    /// nor (on macOS) the Xcode tooling is usable.
+    pub async fn new() -> Result<Self, DeviceError> {
+        let android = AndroidManager::new()
+            .inspect_err(|e| log::debug!("Android SDK unavailable: {e:#}"))
+            .ok();
+        let ios = if cfg!(target_os = "macos") {
+            match IosManager::new() {
+                Ok(manager) => match manager.probe().await {
+                    Ok(()) => Some(manager),
+                    Err(e) => {
+                        log::debug!("Xcode tooling unavailable: {e:#}");
+                        None
+                    }
+                },
+                Err(e) => {
+                    log::debug!("Xcode tooling unavailable: {e:#}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        if android.is_none() && ios.is_none() {
+            return Err(DeviceError::SdkNotFound {
+                sdk: "Android SDK or Xcode".to_string(),
+            });
+        }
+        Ok(Self::with_managers(android, ios))
+    }
+
+    /// Uses the given managers, e.g. ones built with a custom command executor.
+    pub fn with_managers(android: Option<AndroidManager>, ios: Option<IosManager>) -> Self {
+        Self { android, ios }
+    }
+
+    /// Whether devices of `platform` can be managed.
+    pub fn supports(&self, platform: Platform) -> bool {
+        match platform {
+            Platform::Android => self.android.is_some(),
+            Platform::Ios => self.ios.is_some(),
+        }
+    }
+
+    /// All devices, Android first, then iOS.
+    pub async fn list_devices(&self) -> Result<Vec<ManagedDevice>, DeviceError> {
+        let mut devices = Vec::new();
+        if let Some(android) = &self.android {
+            let android_devices = android.list_devices().await.map_err(to_device_error)?;
+            devices.extend(android_devices.into_iter().map(ManagedDevice::Android));
+        }
+        if let Some(ios) = &self.ios {
+            let ios_devices = ios.list_devices().await.map_err(to_device_error)?;
+            devices.extend(ios_devices.into_iter().map(ManagedDevice::Ios));
+        }
+        Ok(devices)
+    }
+
+    /// The device with the given ID.
+    pub async fn find(&self, id: &DeviceId) -> Result<ManagedDevice, DeviceError> {
+        let device = match id.platform {
+            Platform::Android => self
+                .android()?
+                .list_devices()
+                .await
+                .map_err(to_device_error)?
+                .into_iter()
+                .find(|device| device.name == id.identifier)
+                .map(ManagedDevice::Android),
+            Platform::Ios => self
+                .ios()?
+                .list_devices()
+                .await
+                .map_err(to_device_error)?
+                .into_iter()
+                .find(|device| device.udid == id.identifier)
+                .map(ManagedDevice::Ios),
+        };
+        device.ok_or_else(|| DeviceError::not_found(id.to_string()))
+    }
+
+    /// Devices whose display name is `name`. AVD names are unique, but
+    /// several iOS simulators can share a name.
+    pub async fn find_by_name(&self, name: &str) -> Result<Vec<ManagedDevice>, DeviceError> {
+        Ok(self
+            .list_devices()
+            .await?
+            .into_iter()
+            .filter(|device| device.name() == name)
+            .collect())
+    }
+
+    /// Boots a device. Android emulators are launched in the background.
+    pub async fn start(&self, id: &DeviceId) -> Result<(), DeviceError> {
+        let result = match id.platform {
+            Platform::Android => self.android()?.start_device(&id.identifier).await,
+            Platform::Ios => self.ios()?.start_device(&id.identifier).await,
+        };
+        result.map_err(|e| DeviceError::start_failed(id.to_string(), format!("{e:#}")))
+    }
+
+    /// Shuts a device down.
+    pub async fn stop(&self, id: &DeviceId) -> Result<(), DeviceError> {
+        let result = match id.platform {
+            Platform::Android => self.android()?.stop_device(&id.identifier).await,
+            Platform::Ios => self.ios()?.stop_device(&id.identifier).await,
+        };
+        result.map_err(|e| DeviceError::stop_failed(id.to_string(), format!("{e:#}")))
+    }
+
+    /// Resets a device to its factory state.
+    pub async fn wipe(&self, id: &DeviceId) -> Result<(), DeviceError> {
+        let result = match id.platform {
+            Platform::Android => self.android()?.wipe_device(&id.identifier).await,
+            Platform::Ios => self.ios()?.wipe_device(&id.identifier).await,
+        };
+        result.map_err(to_device_error)
+    }
+
+    /// Deletes a device permanently.
+    pub async fn delete(&self, id: &DeviceId) -> Result<(), DeviceError> {
+        let result = match id.platform {
+            Platform::Android => self.android()?.delete_device(&id.identifier).await,
+            Platform::Ios => self.ios()?.delete_device(&id.identifier).await,
+        };
+        result.map_err(|e| to_delete_error(id, e))
+    }
+
+    /// Whether a device has finished booting: Android reports
+    /// `sys.boot_completed`, an iOS simulator is in the Booted state.
+    /// A device that is still launching reports `false`.
+    pub async fn is_booted(&self, id: &DeviceId) -> Result<bool, DeviceError> {
+        match id.platform {
+            Platform::Android => Ok(self
+                .android()?
+                .is_boot_completed(&id.identifier)
+                .await
+                .unwrap_or(false)),
+            Platform::Ios => Ok(self.find(id).await?.is_running()),
+        }
+    }
+
+    /// The first check of `checks` a device does not pass yet, or `None`
+    /// once it is ready. iOS simulators only know
+    /// [`ReadinessCheck::BootCompleted`]; the Android-only checks are
+    /// skipped for them.
+    pub async fn pending_readiness_check(
+        &self,
+        id: &DeviceId,
+        checks: &[ReadinessCheck],
+    ) -> Result<Option<ReadinessCheck>, DeviceError> {
+        match id.platform {
+            Platform::Android => Ok(self
+                .android()?
+                .pending_readiness_check(&id.identifier, checks)
+                .await),
+            Platform::Ios => {
+                let booted = !checks.contains(&ReadinessCheck::BootCompleted)
+                    || self.find(id).await?.is_running();
+                Ok((!booted).then_some(ReadinessCheck::BootCompleted))
+            }
+        }
+    }
+
+    fn android(&self) -> Result<&AndroidManager, DeviceError> {
+        self.android
+            .as_ref()
+            .ok_or_else(|| platform_not_supported(Platform::Android))
+    }
+
+    fn ios(&self) -> Result<&IosManager, DeviceError> {
+        self.ios
+            .as_ref()
+            .ok_or_else(|| platform_not_supported(Platform::Ios))
+    }
+}
+
+fn platform_not_supported(platform: Platform) -> DeviceError {
+    DeviceError::PlatformNotSupported {
+        platform: platform.display_name().to_string(),
+    }
+}
+
+/// Keeps a `DeviceError` raised by a manager, otherwise wraps the message.
+fn to_device_error(error: anyhow::Error) -> DeviceError {
+    match error.downcast::<DeviceError>() {
+        Ok(error) => error,
+        Err(error) => DeviceError::other(format!("{error:#}")),
+    }
+}
+
+/// Keeps a `DeviceError` raised by a manager, such as a read-only refusal,
+/// otherwise reports the failed delete of `id`.
+fn to_delete_error(id: &DeviceId, error: anyhow::Error) -> DeviceError {
+    match error.downcast::<DeviceError>() {
+        Ok(error) => error,
+        Err(error) => DeviceError::DeleteFailed {
+            name: id.to_string(),
+            reason: format!("{error:#}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AndroidDevice;
+
+    #[tokio::test]
+    async fn test_missing_platform_is_reported_as_not_supported() {
+        let service = DeviceService::with_managers(None, None);
+        assert!(!service.supports(Platform::Ios));
+        assert!(service.list_devices().await.unwrap().is_empty());
+
+        let error = service.start(&DeviceId::ios("ABC-123")).await.unwrap_err();
+        assert!(matches!(
+            error,
+            DeviceError::PlatformNotSupported { ref platform } if platform == "iOS"
+        ));
+    }
+
+    #[test]
+    fn test_to_device_error_keeps_device_errors() {
+        let error = to_device_error(DeviceError::not_found("Pixel_7").into());
+        assert!(matches!(error, DeviceError::NotFound { ref name } if name == "Pixel_7"));
+
+        let error = to_device_error(anyhow::anyhow!("adb exploded"));
+        assert!(matches!(error, DeviceError::Other { ref message } if message == "adb exploded"));
+    }
+
+    #[test]
+    fn test_to_delete_error_keeps_read_only_refusals() {
+        let id = DeviceId::android("Pixel_7");
+        let error = to_delete_error(&id, DeviceError::read_only("delete devices").into());
+        assert!(matches!(error, DeviceError::ReadOnly { .. }));
+
+        let error = to_delete_error(&id, anyhow::anyhow!("avdmanager exploded"));
+        assert!(matches!(
+            error,
+            DeviceError::DeleteFailed { ref reason, .. } if reason == "avdmanager exploded"
+        ));
+    }
+
+    #[test]
+    fn test_managed_device_exposes_device_id() {
+        let device = ManagedDevice::Android(AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(device.id(), DeviceId::android("Pixel_7_API_34"));
+        assert_eq!(device.name(), "Pixel_7_API_34");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    app::{AnnotationField, AppState},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM, DIALOG_WIDTH_SMALL},
+        ui_text::annotations::{
+            DIALOG_SHORTCUTS, DIALOG_TITLE, NOTE_FIELD, SEARCH_NO_MATCH, SEARCH_SHORTCUTS,
+            SEARCH_TITLE, TAGS_FIELD,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Height of the one-line search prompt including borders and hints.
+const SEARCH_PROMPT_HEIGHT: u16 = 4;
+
+pub(crate) fn render_annotation_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.annotation_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(DIALOG_TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let fields = [
+        (AnnotationField::Tags, TAGS_FIELD, &dialog.tags_input),
+        (AnnotationField::Note, NOTE_FIELD, &dialog.note_input),
+    ];
+    for (index, (field, label, value)) in fields.into_iter().enumerate() {
+        let is_active = dialog.active_field == field;
+        let border_color = if is_active {
+            theme.primary
+        } else {
+            UI_COLOR_BORDER
+        };
+        let cursor = if is_active { "_" } else { "" };
+        let input = Paragraph::new(format!("{value}{cursor}"))
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .title(label)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color)),
+            );
+        frame.render_widget(input, chunks[index]);
+    }
+
+    let shortcuts = Paragraph::new(DIALOG_SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[3]);
+}
+
+/// Renders the search prompt near the top of the screen so the device
+/// lists stay visible while the selection follows the query.
+pub(crate) fn render_search_prompt(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let area = frame.area();
+    let prompt_width = DIALOG_WIDTH_SMALL.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let prompt_area = Rect::new(
+        area.width.saturating_sub(prompt_width) / 2,
+        area.y + 1,
+        prompt_width,
+        SEARCH_PROMPT_HEIGHT.min(area.height),
+    );
+
+    frame.render_widget(Clear, prompt_area);
+    let block = Block::default()
+        .title(SEARCH_TITLE)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(prompt_area);
+    frame.render_widget(block, prompt_area);
+
+    let mut query_line = vec![
+        Span::styled(
+            "/",
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{}_", state.search_query),
+            Style::default().fg(theme.text),
+        ),
+    ];
+    if !state.search_query.trim().is_empty() && state.find_search_match(false).is_none() {
+        query_line.push(Span::styled(
+            format!("  {SEARCH_NO_MATCH}"),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        ));
+    }
+
+    let content = Paragraph::new(vec![
+        Line::from(query_line),
+        Line::from(Span::styled(
+            SEARCH_SHORTCUTS,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        )),
+    ]);
+    frame.render_widget(content, inner_area);
+}
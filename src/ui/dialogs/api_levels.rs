@@ -1,5 +1,5 @@
 use crate::{
-    app::AppState,
+    app::{state::QueuedInstallStatus, AppState},
     constants::{
         colors::*,
         messages::notifications::INSTALL_PROGRESS_COMPLETE,
@@ -8,14 +8,16 @@ use crate::{
         },
         ui_text::{api_management::*, progress::*},
     },
-    ui::{widgets::get_animated_moon, Theme},
+    ui::{render::format_age, widgets::get_animated_moon, Theme},
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
+    text::Line,
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
     Frame,
 };
+use std::time::Duration;
 
 pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
     let size = frame.area();
@@ -43,7 +45,14 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         .filter(|api| api.is_installed)
         .count();
     let total_count = api_mgmt.api_levels.len();
-    let title = format!("📦 Android System Images ({installed_count}/{total_count} installed)");
+    let offline_badge = if api_mgmt.is_offline {
+        OFFLINE_BADGE
+    } else {
+        ""
+    };
+    let title = format!(
+        "📦 Android System Images ({installed_count}/{total_count} installed){offline_badge}"
+    );
 
     let dialog_block = Block::default()
         .title(title)
@@ -64,15 +73,31 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         ])
         .split(inner_area);
 
-    let instruction_text = "✅ Green = Installed  📦 Gray = Available  Select and press Enter/d";
-    let instructions = Paragraph::new(instruction_text)
+    let search = if api_mgmt.is_searching {
+        format!("{}{SEARCH_CURSOR}", api_mgmt.search_query)
+    } else {
+        api_mgmt.search_query.clone()
+    };
+    let view_options = VIEW_OPTIONS
+        .replacen("{}", api_mgmt.filter.label(), 1)
+        .replacen("{}", api_mgmt.sort.label(), 1)
+        .replacen("{}", &search, 1);
+    let view_options = Paragraph::new(view_options)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(view_options, chunks[0]);
+
+    let instructions = Paragraph::new(API_INSTRUCTIONS)
         .style(Style::default().fg(theme.text))
         .alignment(Alignment::Center);
     frame.render_widget(instructions, chunks[1]);
 
-    if api_mgmt.api_levels.is_empty() {
+    let api_levels = api_mgmt.visible_api_levels();
+    if api_levels.is_empty() {
         let empty_msg = if api_mgmt.is_loading {
             ""
+        } else if !api_mgmt.api_levels.is_empty() {
+            NO_MATCHING_API_LEVELS
         } else {
             "No API levels found. Please check your Android SDK installation."
         };
@@ -89,12 +114,11 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         frame.render_widget(empty_widget, chunks[2]);
     } else {
         let available_height = chunks[2].height.saturating_sub(2) as usize;
-        let total_items = api_mgmt.api_levels.len();
+        let total_items = api_levels.len();
         let scroll_offset = api_mgmt.get_scroll_offset(available_height);
 
-        let visible_items: Vec<_> = api_mgmt
-            .api_levels
-            .iter()
+        let visible_items: Vec<_> = api_levels
+            .into_iter()
             .enumerate()
             .skip(scroll_offset)
             .take(available_height)
@@ -112,7 +136,39 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
                     String::new()
                 };
 
-                let text = format!("{status_icon} {}{variant_info}", api.display_name);
+                let size_info = api
+                    .listed_size()
+                    .map(|size| {
+                        let size = format_bytes(size);
+                        if api.is_installed {
+                            format!("  ({size})")
+                        } else {
+                            format!("  ({})", DOWNLOAD_SIZE.replace("{}", &size))
+                        }
+                    })
+                    .unwrap_or_default();
+
+                let queue_status = api.get_recommended_variant().and_then(|variant| {
+                    api_mgmt
+                        .install_queue
+                        .as_ref()
+                        .and_then(|queue| queue.get(&variant.package_id))
+                        .map(|item| &item.status)
+                });
+                let queue_info = match queue_status {
+                    Some(QueuedInstallStatus::Pending) => ROW_QUEUED.to_string(),
+                    Some(QueuedInstallStatus::Installing(percentage)) => {
+                        ROW_INSTALLING.replace("{}", &percentage.to_string())
+                    }
+                    Some(QueuedInstallStatus::Failed(_)) => ROW_INSTALL_FAILED.to_string(),
+                    _ if api_mgmt.is_marked(api) => ROW_MARKED.to_string(),
+                    _ => String::new(),
+                };
+
+                let text = format!(
+                    "{status_icon} {}{variant_info}{size_info}{queue_info}",
+                    api.display_name
+                );
 
                 let style = if selected {
                     if api.is_installed {
@@ -155,16 +211,37 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
             format!("API Levels ({total_items})")
         };
 
-        let list = List::new(items).block(
-            Block::default()
-                .title(list_title)
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(theme.text)),
-        );
+        let mut list_block = Block::default()
+            .title(list_title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.text));
+        let disk_usage = api_mgmt.installed_disk_usage();
+        if disk_usage > 0 {
+            list_block = list_block.title_bottom(
+                Line::from(DISK_USED.replace("{}", &format_bytes(disk_usage))).right_aligned(),
+            );
+        }
+        let list = List::new(items).block(list_block);
         frame.render_widget(list, chunks[2]);
     }
 
-    if api_mgmt.is_loading {
+    if let Some(ref confirmation) = api_mgmt.uninstall_confirmation {
+        let mut warning =
+            UNINSTALL_DEPENDENTS_WARNING.replace("{}", &confirmation.dependent_avds.join(", "));
+        if let Some((_, ref display_name)) = confirmation.retarget {
+            warning.push(' ');
+            warning.push_str(&UNINSTALL_RETARGET_HINT.replace("{}", display_name));
+        }
+        let warning_widget = Paragraph::new(warning)
+            .style(
+                Style::default()
+                    .fg(STATUS_COLOR_WARNING)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(warning_widget, chunks[3]);
+    } else if api_mgmt.is_loading {
         let loading_msg = Paragraph::new(format!("{} Loading API levels...", get_animated_moon()))
             .style(
                 Style::default()
@@ -177,9 +254,32 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         let (progress_text, color) = if progress.percentage >= 100 {
             (INSTALL_PROGRESS_COMPLETE.to_string(), STATUS_COLOR_SUCCESS)
         } else {
+            let mut position = String::new();
+            let mut remaining = progress
+                .eta_seconds
+                .map(|seconds| Duration::from_secs(u64::from(seconds)));
+            if let Some(queue) = api_mgmt
+                .install_queue
+                .as_ref()
+                .filter(|queue| queue.items.len() > 1)
+            {
+                if let Some((index, _)) = queue.current() {
+                    position = QUEUE_POSITION
+                        .replacen("{}", &index.to_string(), 1)
+                        .replacen("{}", &queue.items.len().to_string(), 1);
+                }
+                remaining = queue.remaining_time(queue.started_at.elapsed());
+            }
+            let speed = progress
+                .bytes_per_second
+                .map(|speed| TRANSFER_SPEED.replace("{}", &format_bytes(speed)))
+                .unwrap_or_default();
+            let time_left = remaining
+                .map(|remaining| INSTALL_TIME_LEFT.replace("{}", &format_age(remaining)))
+                .unwrap_or_default();
             (
                 format!(
-                    "{} {} - {}%",
+                    "{} {position}{} - {}%{speed}{time_left}",
                     get_animated_moon(),
                     progress.operation,
                     progress.percentage
@@ -210,11 +310,28 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         frame.render_widget(error_widget, chunks[3]);
     }
 
-    let shortcuts = if api_mgmt.is_busy() {
+    let nav_install_marked;
+    let shortcuts = if api_mgmt.is_searching {
+        NAV_SEARCH
+    } else if let Some(ref confirmation) = api_mgmt.uninstall_confirmation {
+        if confirmation.retarget.is_some() {
+            NAV_UNINSTALL_DEPENDENTS
+        } else {
+            NAV_UNINSTALL_DEPENDENTS_NO_RETARGET
+        }
+    } else if api_mgmt.is_busy() && api_mgmt.confirm_cancel {
+        CONFIRM_CANCEL
+    } else if api_mgmt.is_busy() {
         PROCESSING_WAIT
+    } else if !api_mgmt.marked_packages.is_empty() {
+        nav_install_marked =
+            NAV_INSTALL_MARKED.replace("{}", &api_mgmt.marked_packages.len().to_string());
+        &nav_install_marked
     } else if let Some(selected_api) = api_mgmt.get_selected_api_level() {
         if selected_api.is_installed {
             NAV_UNINSTALL
+        } else if api_mgmt.is_offline {
+            NAV_INSTALL_OFFLINE
         } else {
             NAV_INSTALL
         }
@@ -230,3 +347,19 @@ pub(crate) fn render_api_level_dialog(frame: &mut Frame, state: &AppState, theme
         .alignment(Alignment::Center);
     frame.render_widget(shortcuts_widget, chunks[4]);
 }
+
+/// Formats a byte count with one decimal in the largest fitting binary unit.
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::app_data::{
+            ANDROID_HINT, APPS_TITLE, BACKUPS_TITLE, IOS_HINT, LOADING_APPS, NO_BACKUPS, NO_TARGET,
+            SHORTCUTS, TARGET_KEYS, TARGET_LABEL, TITLE,
+        },
+    },
+    models::Platform,
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_app_data_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.app_data_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    let apps_block = Block::default().title(APPS_TITLE).borders(Borders::ALL);
+    if dialog.apps.is_empty() {
+        frame.render_widget(
+            Paragraph::new(LOADING_APPS)
+                .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+                .block(apps_block),
+            columns[0],
+        );
+    } else {
+        let apps = dialog.apps.iter().map(String::as_str).collect();
+        render_list(
+            frame,
+            columns[0],
+            apps_block,
+            apps,
+            dialog.selected_app,
+            theme,
+        );
+    }
+
+    let backups_block = Block::default().title(BACKUPS_TITLE).borders(Borders::ALL);
+    if dialog.backups.is_empty() {
+        frame.render_widget(
+            Paragraph::new(NO_BACKUPS)
+                .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+                .block(backups_block),
+            columns[1],
+        );
+    } else {
+        let names = dialog
+            .backups
+            .iter()
+            .map(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default()
+            })
+            .collect();
+        render_list(
+            frame,
+            columns[1],
+            backups_block,
+            names,
+            dialog.selected_backup,
+            theme,
+        );
+    }
+
+    let target = match dialog.selected_target() {
+        Some((_, name)) => Line::from(vec![
+            Span::styled(TARGET_LABEL, Style::default().fg(theme.text)),
+            Span::styled(
+                name.as_str(),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(TARGET_KEYS, Style::default().fg(UI_COLOR_TEXT_DIM)),
+        ]),
+        None => Line::styled(
+            format!("{TARGET_LABEL}{NO_TARGET}"),
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ),
+    };
+    frame.render_widget(Paragraph::new(target), chunks[1]);
+
+    let (status, color) = match (&dialog.status, dialog.device.platform) {
+        (Some(status), _) => (status.as_str(), STATUS_COLOR_WARNING),
+        (None, Platform::Android) => (ANDROID_HINT, UI_COLOR_TEXT_DIM),
+        (None, Platform::Ios) => (IOS_HINT, UI_COLOR_TEXT_DIM),
+    };
+    frame.render_widget(
+        Paragraph::new(status).style(Style::default().fg(color)),
+        chunks[2],
+    );
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[3]);
+}
+
+/// Renders `entries` in `block`, scrolled just far enough to keep the
+/// highlighted one visible.
+fn render_list(
+    frame: &mut Frame,
+    area: Rect,
+    block: Block,
+    entries: Vec<&str>,
+    selected: usize,
+    theme: &Theme,
+) {
+    // Inside the block's borders
+    let visible = usize::from(area.height.saturating_sub(2)).max(1);
+    let first = selected.saturating_sub(visible - 1);
+    let items: Vec<ListItem> = entries
+        .into_iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, entry)| {
+            let style = if index == selected {
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            ListItem::new(Line::styled(entry, style))
+        })
+        .collect();
+    frame.render_widget(List::new(items).block(block), area);
+}
@@ -0,0 +1,84 @@
+use crate::{
+    app::{AppState, Panel},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_MEDIUM, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::clock::{
+            ANDROID_HINT, INPUT_FIELD, IOS_APP_TIME_HINT, IOS_HINT, SHORTCUTS, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_device_clock_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.device_clock_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_MEDIUM.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let input = Paragraph::new(format!("{}_", dialog.input))
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .title(INPUT_FIELD)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.primary)),
+        );
+    frame.render_widget(input, chunks[0]);
+
+    let mut lines = match &dialog.error {
+        Some(error) => vec![Line::styled(
+            error.as_str(),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        )],
+        None => Vec::new(),
+    };
+    let dim = Style::default().fg(UI_COLOR_TEXT_DIM);
+    match dialog.panel {
+        Panel::Android => lines.push(Line::styled(ANDROID_HINT, dim)),
+        Panel::Ios => {
+            lines.push(Line::styled(IOS_HINT, dim));
+            lines.push(Line::styled(IOS_APP_TIME_HINT, dim));
+        }
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), chunks[1]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[2]);
+}
@@ -0,0 +1,108 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_WIDTH_LARGE},
+        ui_text::command_history::{EMPTY_HISTORY, HISTORY_SHORTCUTS, LOG_FILE_PREFIX},
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_command_history_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let size = frame.area();
+
+    let Some(dialog) = &state.command_history_dialog else {
+        return;
+    };
+
+    let dialog_width = DIALOG_WIDTH_LARGE.min(size.width - 2);
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(size.height - 2);
+
+    let dialog_area = ratatui::layout::Rect {
+        x: (size.width - dialog_width) / 2,
+        y: (size.height - dialog_height) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = format!("📜 Command History ({})", dialog.entries.len());
+    let dialog_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary));
+
+    let inner_area = dialog_block.inner(dialog_area);
+    frame.render_widget(dialog_block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    if dialog.entries.is_empty() {
+        let empty = Paragraph::new(EMPTY_HISTORY)
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+            .alignment(Alignment::Center);
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = dialog
+            .entries
+            .iter()
+            .skip(dialog.scroll_offset)
+            .take(chunks[0].height as usize)
+            .map(|entry| {
+                let status_color = if entry.success {
+                    STATUS_COLOR_SUCCESS
+                } else {
+                    STATUS_COLOR_ERROR
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        entry.started_at.format("%H:%M:%S ").to_string(),
+                        Style::default().fg(UI_COLOR_TEXT_DIM),
+                    ),
+                    Span::styled(
+                        format!("{:<8}", entry.status_label()),
+                        Style::default()
+                            .fg(status_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{:>7} ", entry.duration_label()),
+                        Style::default().fg(theme.text),
+                    ),
+                    Span::styled(entry.command.clone(), Style::default().fg(theme.text)),
+                ]))
+            })
+            .collect();
+        frame.render_widget(List::new(items), chunks[0]);
+    }
+
+    if let Some(ref path) = dialog.log_path {
+        let log_file = Paragraph::new(format!("{LOG_FILE_PREFIX}{}", path.display()))
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM));
+        frame.render_widget(log_file, chunks[1]);
+    }
+
+    let shortcuts = Paragraph::new(HISTORY_SHORTCUTS)
+        .style(
+            Style::default()
+                .fg(UI_COLOR_TEXT_DIM)
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[2]);
+}
@@ -1,15 +1,26 @@
 use crate::{
-    app::{state::CreateDeviceField, AppState, Panel},
+    app::{
+        state::{CreateDeviceField, CreateDeviceForm},
+        AppState, Panel,
+    },
     constants::{
         colors::*,
-        ui_layout::{DIALOG_HEIGHT_MEDIUM, DIALOG_WIDTH_MEDIUM, FORM_LABEL_WIDTH},
+        ui_layout::{
+            CREATE_DIALOG_PREVIEW_WIDTH, DIALOG_HEIGHT_MEDIUM, DIALOG_WIDTH_MEDIUM,
+            FORM_LABEL_WIDTH,
+        },
+        ui_text::progress::CONFIRM_CANCEL,
     },
+    managers::AndroidManager,
+    models::DeviceCreationPreview,
     ui::{widgets::get_animated_moon, Theme},
+    utils::host,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
-    widgets::{Block, Borders, Clear, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
@@ -19,6 +30,7 @@ fn render_input_field(
     label: &str,
     value: &str,
     is_active: bool,
+    is_invalid: bool,
     theme: &Theme,
 ) {
     let chunks = Layout::default()
@@ -29,12 +41,15 @@ fn render_input_field(
     let label_widget = Paragraph::new(label).style(Style::default().fg(theme.text));
     frame.render_widget(label_widget, chunks[0]);
 
-    let input_style = if is_active {
-        Style::default()
+    let input_style = match (is_active, is_invalid) {
+        (true, true) => Style::default()
+            .fg(STATUS_COLOR_ERROR)
+            .add_modifier(Modifier::BOLD),
+        (false, true) => Style::default().fg(STATUS_COLOR_ERROR),
+        (true, false) => Style::default()
             .fg(theme.primary)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default().fg(theme.text)
+            .add_modifier(Modifier::BOLD),
+        (false, false) => Style::default().fg(theme.text),
     };
 
     let display_value = if is_active {
@@ -88,8 +103,22 @@ fn render_select_field(
 
 pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
     let size = frame.area();
-    let dialog_width = DIALOG_WIDTH_MEDIUM.min(size.width - 4);
-    let dialog_height = DIALOG_HEIGHT_MEDIUM.min(size.height - 4);
+    let preview_width = match state.active_panel {
+        Panel::Android => CREATE_DIALOG_PREVIEW_WIDTH,
+        Panel::Ios => 0,
+    };
+    let dialog_width = (DIALOG_WIDTH_MEDIUM + preview_width).min(size.width - 4);
+    // A second SDK adds a selector row above the API level
+    let sdk_row_height = if state.create_device_form.has_sdk_choice() {
+        2
+    } else {
+        0
+    };
+    // An invalid name gets its error on a line under the name field
+    let name_error = state.validate_create_device_name().err();
+    let name_error_height = u16::from(name_error.is_some());
+    let dialog_height =
+        (DIALOG_HEIGHT_MEDIUM + sdk_row_height + name_error_height).min(size.height - 4);
     let x = (size.width.saturating_sub(dialog_width)) / 2;
     let y = (size.height.saturating_sub(dialog_height)) / 2;
 
@@ -99,9 +128,11 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
     let background_block = Block::default().style(Style::default().bg(UI_COLOR_BACKGROUND));
     frame.render_widget(background_block, dialog_area);
 
-    let title = match state.active_panel {
-        Panel::Android => "🤖 Create Android Device",
-        Panel::Ios => "🍎 Create iOS Device",
+    let title = match (&state.create_device_form.template, state.active_panel) {
+        (Some(template), Panel::Android) => format!("🤖 Duplicate {}", template.source_name),
+        (Some(template), Panel::Ios) => format!("🍎 Duplicate {}", template.source_name),
+        (None, Panel::Android) => "🤖 Create Android Device".to_string(),
+        (None, Panel::Ios) => "🍎 Create iOS Device".to_string(),
     };
 
     let dialog_block = Block::default()
@@ -112,35 +143,62 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
     let inner_area = dialog_block.inner(dialog_area);
     frame.render_widget(dialog_block, dialog_area);
 
+    // The preview pane is only shown when the form still gets its full width.
+    let inner_area = if preview_width > 0 && dialog_width == DIALOG_WIDTH_MEDIUM + preview_width {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(preview_width)])
+            .split(inner_area);
+        render_creation_preview(frame, columns[1], state, theme);
+        columns[0]
+    } else {
+        inner_area
+    };
+
+    let form = &state.create_device_form;
+
+    let name_row = match state.active_panel {
+        Panel::Android => 7,
+        Panel::Ios => 4,
+    };
+    let mut row_heights = [1, sdk_row_height, 2, 2, 2, 2, 2, 2];
+    row_heights[name_row] += name_error_height;
     let form_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(1),
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Min(1),
-        ])
+        .constraints(
+            row_heights
+                .into_iter()
+                .map(Constraint::Length)
+                .chain(std::iter::once(Constraint::Min(1))),
+        )
         .split(inner_area);
 
-    let form = &state.create_device_form;
+    if let Some(sdk) = form.selected_sdk() {
+        render_select_field(
+            frame,
+            form_chunks[1],
+            "SDK:",
+            sdk,
+            &form.android_sdks,
+            form.active_field == CreateDeviceField::Sdk,
+            theme,
+        );
+    }
 
     if form.available_versions.is_empty() {
         render_input_field(
             frame,
-            form_chunks[1],
+            form_chunks[2],
             "API Level:",
             &form.version,
             form.active_field == CreateDeviceField::ApiLevel,
+            false,
             theme,
         );
     } else {
         render_select_field(
             frame,
-            form_chunks[1],
+            form_chunks[2],
             "API Level:",
             &form.version_display,
             &form
@@ -162,7 +220,7 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
 
         render_select_field(
             frame,
-            form_chunks[2],
+            form_chunks[3],
             "Category:",
             current_category,
             &form
@@ -185,9 +243,9 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
     }
 
     let device_type_chunk = if matches!(state.active_panel, Panel::Android) {
-        form_chunks[3]
+        form_chunks[4]
     } else {
-        form_chunks[2]
+        form_chunks[3]
     };
     render_select_field(
         frame,
@@ -204,47 +262,73 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
     );
 
     if matches!(state.active_panel, Panel::Android) {
-        render_input_field(
+        let ram_options = CreateDeviceForm::ram_size_options()
+            .iter()
+            .map(|size| CreateDeviceForm::format_size_mb(&size.to_string()))
+            .collect::<Vec<String>>();
+        render_select_field(
             frame,
-            form_chunks[4],
-            "RAM Size (MB):",
-            &form.ram_size,
+            form_chunks[5],
+            "RAM Size:",
+            &CreateDeviceForm::format_size_mb(&form.ram_size),
+            &ram_options,
             form.active_field == CreateDeviceField::RamSize,
             theme,
         );
 
-        render_input_field(
+        let storage_options = CreateDeviceForm::storage_size_options()
+            .iter()
+            .map(|size| CreateDeviceForm::format_size_mb(&size.to_string()))
+            .collect::<Vec<String>>();
+        render_select_field(
             frame,
-            form_chunks[5],
-            "Storage Size (MB):",
-            &form.storage_size,
+            form_chunks[6],
+            "Storage Size:",
+            &CreateDeviceForm::format_size_mb(&form.storage_size),
+            &storage_options,
             form.active_field == CreateDeviceField::StorageSize,
             theme,
         );
     }
 
-    let name_chunk = if matches!(state.active_panel, Panel::Android) {
-        form_chunks[6]
-    } else {
-        form_chunks[3]
-    };
+    let name_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(form_chunks[name_row]);
     render_input_field(
         frame,
-        name_chunk,
+        name_chunks[0],
         "Name:",
         &form.name,
         form.active_field == CreateDeviceField::Name,
+        name_error.is_some(),
         theme,
     );
+    if let Some(error) = &name_error {
+        let field_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(FORM_LABEL_WIDTH), Constraint::Min(1)])
+            .split(name_chunks[1]);
+        let name_error_msg =
+            Paragraph::new(error.as_str()).style(Style::default().fg(STATUS_COLOR_ERROR));
+        frame.render_widget(name_error_msg, field_chunks[1]);
+    }
 
-    let msg_chunk = if matches!(state.active_panel, Panel::Android) {
-        form_chunks[7]
-    } else {
-        form_chunks[4]
+    let ram_warning = match state.active_panel {
+        Panel::Android => form.ram_warning(host::cached_total_memory_mb()),
+        Panel::Ios => None,
     };
 
+    let msg_chunk = form_chunks[name_row + 1];
+
     if form.is_creating {
-        let progress_msg = if let Some(ref status) = form.creation_status {
+        let progress_msg = if form.confirm_cancel {
+            CONFIRM_CANCEL.to_string()
+        } else if let (Some(status), Some(percentage)) =
+            (&form.creation_status, form.creation_progress)
+        {
+            format!("{} {status} - {percentage}%", get_animated_moon())
+        } else if let Some(ref status) = form.creation_status {
             format!("{} {status}", get_animated_moon())
         } else {
             format!("{} Creating device... Please wait...", get_animated_moon())
@@ -271,5 +355,94 @@ pub(crate) fn render_create_device_dialog(frame: &mut Frame, state: &AppState, t
             .style(Style::default().fg(STATUS_COLOR_ERROR))
             .alignment(Alignment::Center);
         frame.render_widget(error_msg, msg_chunk);
+    } else if let Some(warning) = ram_warning {
+        let ram_warning_msg = Paragraph::new(warning)
+            .style(Style::default().fg(STATUS_COLOR_WARNING))
+            .alignment(Alignment::Center);
+        frame.render_widget(ram_warning_msg, msg_chunk);
     }
 }
+
+fn render_creation_preview(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
+    let form = &state.create_device_form;
+    let block = Block::default()
+        .title(" Preview ")
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(UI_COLOR_TEXT_DIM));
+
+    let Some(preview) = &form.preview else {
+        let message = if form.is_loading_cache {
+            "Loading..."
+        } else {
+            "Select an API level and device type"
+        };
+        let placeholder = Paragraph::new(message)
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+            .block(block)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(placeholder, area);
+        return;
+    };
+
+    let label_style = Style::default().fg(UI_COLOR_TEXT_DIM);
+    let value_style = Style::default().fg(theme.text);
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{label:<8}"), label_style),
+            Span::styled(value, value_style),
+        ])
+    };
+
+    let image_status = if preview.image_installed {
+        Span::styled("installed", Style::default().fg(STATUS_COLOR_SUCCESS))
+    } else {
+        Span::styled("not installed", Style::default().fg(STATUS_COLOR_WARNING))
+    };
+    let screen = match (&preview.resolution, preview.dpi) {
+        (Some(resolution), Some(dpi)) => format!("{resolution} @ {dpi} dpi"),
+        (Some(resolution), None) => resolution.clone(),
+        (None, Some(dpi)) => format!("{dpi} dpi"),
+        (None, None) => "From device profile".to_string(),
+    };
+    let disk = DeviceCreationPreview::estimated_disk_mb(
+        form.ram_size.parse().unwrap_or(0),
+        form.storage_size.parse().unwrap_or(0),
+    );
+    let avd_name = AndroidManager::sanitize_avd_name(&form.name);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<8}", "Image"), label_style),
+            image_status,
+        ]),
+        Line::from(Span::styled(preview.package_path.clone(), value_style)),
+        field("Tag", preview.tag.clone()),
+        field("ABI", preview.abi.clone()),
+        field(
+            "Profile",
+            preview
+                .device_id
+                .clone()
+                .unwrap_or_else(|| "default".to_string()),
+        ),
+        field("Screen", screen),
+        field(
+            "Disk",
+            format!(
+                "~{} (est.)",
+                CreateDeviceForm::format_size_mb(&disk.to_string())
+            ),
+        ),
+        Line::from(""),
+        Line::from(Span::styled("Command", label_style)),
+        Line::from(Span::styled(
+            preview.command(&avd_name).to_string(),
+            Style::default().fg(theme.primary),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,119 @@
+use crate::{
+    app::{
+        state::{QueuedCreation, QueuedCreationStatus},
+        AppState, Panel,
+    },
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_WIDTH_LARGE},
+        ui_text::creation_queue::{
+            EMPTY_QUEUE, QUEUE_SHORTCUTS, STATUS_COMPLETED, STATUS_CREATING, STATUS_FAILED,
+            STATUS_PENDING,
+        },
+    },
+    ui::Theme,
+    utils::text::{pad_to_width, truncate_to_width},
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_creation_queue_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let size = frame.area();
+    let queue = &state.creation_queue;
+
+    let dialog_width = DIALOG_WIDTH_LARGE.min(size.width - 2);
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(size.height - 2);
+
+    let dialog_area = ratatui::layout::Rect {
+        x: (size.width - dialog_width) / 2,
+        y: (size.height - dialog_height) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = format!(
+        "📋 Creation Queue ({} pending, {} active of {}, {} done)",
+        queue.pending_count(),
+        queue.active_count(),
+        queue.max_parallel(),
+        queue.finished_count()
+    );
+    let dialog_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary));
+
+    let inner_area = dialog_block.inner(dialog_area);
+    frame.render_widget(dialog_block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    if queue.items.is_empty() {
+        let empty = Paragraph::new(EMPTY_QUEUE)
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+            .alignment(Alignment::Center);
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = queue
+            .items
+            .iter()
+            .skip(queue.scroll_offset)
+            .take(chunks[0].height as usize)
+            .map(|item| queue_row(item, theme))
+            .collect();
+        frame.render_widget(List::new(items), chunks[0]);
+    }
+
+    let shortcuts = Paragraph::new(QUEUE_SHORTCUTS)
+        .style(
+            Style::default()
+                .fg(UI_COLOR_TEXT_DIM)
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
+
+fn queue_row<'a>(item: &'a QueuedCreation, theme: &Theme) -> ListItem<'a> {
+    let platform = match item.panel {
+        Panel::Android => "🤖",
+        Panel::Ios => "🍎",
+    };
+
+    let (status, color) = match &item.status {
+        QueuedCreationStatus::Pending => (STATUS_PENDING.to_string(), UI_COLOR_TEXT_DIM),
+        QueuedCreationStatus::Active { stage, progress } => {
+            let mut status = stage.clone().unwrap_or_else(|| STATUS_CREATING.to_string());
+            if let Some(progress) = progress {
+                status.push_str(&format!(" - {progress}%"));
+            }
+            (status, theme.primary)
+        }
+        QueuedCreationStatus::Completed => (STATUS_COMPLETED.to_string(), theme.running),
+        QueuedCreationStatus::Failed(message) => {
+            (format!("{STATUS_FAILED}: {message}"), theme.error)
+        }
+    };
+
+    ListItem::new(Line::from(vec![
+        Span::raw(format!("{platform} ")),
+        Span::styled(
+            format!(
+                "{} ",
+                pad_to_width(&truncate_to_width(&item.config.name, 24), 24)
+            ),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(status, Style::default().fg(color)),
+    ]))
+}
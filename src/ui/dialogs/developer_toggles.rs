@@ -0,0 +1,94 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_SMALL},
+        ui_text::developer_toggles::{
+            ANDROID_HINT, IOS_HINT, LOADING, OFF, ON, SHORTCUTS, SWITCHING, TITLE,
+        },
+    },
+    models::{DeveloperToggle, Platform},
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_developer_toggles_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.developer_toggles_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_SMALL.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let lines = match &dialog.status {
+        None => vec![Line::styled(
+            LOADING,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        )],
+        Some(status) => DeveloperToggle::for_platform(dialog.device.platform)
+            .iter()
+            .map(|&toggle| {
+                let (value, color) = if dialog.switching == Some(toggle) {
+                    (SWITCHING, STATUS_COLOR_WARNING)
+                } else if status.is_enabled(toggle) {
+                    (ON, STATUS_COLOR_SUCCESS)
+                } else {
+                    (OFF, UI_COLOR_TEXT_DIM)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("[{}] {}: ", toggle.key(), toggle.label()),
+                        Style::default().fg(theme.text),
+                    ),
+                    Span::styled(value, Style::default().fg(color)),
+                ])
+            })
+            .collect(),
+    };
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let hint = match dialog.device.platform {
+        Platform::Android => ANDROID_HINT,
+        Platform::Ios => IOS_HINT,
+    };
+    frame.render_widget(
+        Paragraph::new(hint).style(Style::default().fg(UI_COLOR_TEXT_DIM)),
+        chunks[1],
+    );
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[2]);
+}
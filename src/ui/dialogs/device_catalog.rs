@@ -0,0 +1,116 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::device_catalog::{IMAGE, SHORTCUTS, SHORTCUTS_BUSY, TITLE},
+    },
+    models::CatalogEntry,
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Lines each entry takes: name, then image and description.
+const ENTRY_HEIGHT: usize = 2;
+
+pub(crate) fn render_device_catalog_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.device_catalog_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    // Scrolls just far enough to keep the highlighted entry visible
+    let visible_entries = (usize::from(chunks[0].height) / ENTRY_HEIGHT).max(1);
+    let first = dialog.selected.saturating_sub(visible_entries - 1);
+    let items: Vec<ListItem> = dialog
+        .catalog
+        .devices
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible_entries)
+        .map(|(index, entry)| catalog_row(entry, index == dialog.selected, theme))
+        .collect();
+    frame.render_widget(List::new(items), chunks[0]);
+
+    let status = match (&dialog.status, &dialog.error) {
+        (Some(status), _) => {
+            let mut status = status.clone();
+            if let Some(progress) = dialog.progress {
+                status.push_str(&format!(" {progress}%"));
+            }
+            Line::styled(status, Style::default().fg(STATUS_COLOR_WARNING))
+        }
+        (None, Some(error)) => Line::styled(error.as_str(), Style::default().fg(theme.error)),
+        (None, None) => Line::raw(""),
+    };
+    frame.render_widget(Paragraph::new(status), chunks[1]);
+
+    let shortcuts = if dialog.is_busy() {
+        SHORTCUTS_BUSY
+    } else {
+        SHORTCUTS
+    };
+    let shortcuts = Paragraph::new(shortcuts)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[2]);
+}
+
+fn catalog_row<'a>(entry: &'a CatalogEntry, selected: bool, theme: &Theme) -> ListItem<'a> {
+    let name_style = if selected {
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+    };
+    let image = IMAGE
+        .replacen("{}", &entry.api_level.to_string(), 1)
+        .replacen("{}", &entry.tag, 1)
+        .replacen("{}", entry.abi(), 1);
+
+    ListItem::new(vec![
+        Line::styled(format!("🤖 {}", entry.name), name_style),
+        Line::from(vec![
+            Span::styled(format!("   {image}  "), Style::default().fg(theme.primary)),
+            Span::styled(
+                entry.description.as_str(),
+                Style::default().fg(UI_COLOR_TEXT_DIM),
+            ),
+        ]),
+    ])
+}
@@ -0,0 +1,134 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_WIDTH_LARGE},
+        ui_text::diagnostics::{
+            DIAGNOSTICS_SHORTCUTS, DIAGNOSTICS_SHORTCUTS_WITH_XCODE, EMPTY_DIAGNOSTICS,
+            XCODE_LABEL, XCODE_SOURCE_EMU, XCODE_SOURCE_ENV, XCODE_SOURCE_SELECT, XCODE_UNKNOWN,
+        },
+    },
+    ui::Theme,
+    utils::xcode::{DeveloperDir, DeveloperDirSource},
+};
+use log::Level;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_diagnostics_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let size = frame.area();
+
+    let Some(dialog) = &state.diagnostics_dialog else {
+        return;
+    };
+
+    let dialog_width = DIALOG_WIDTH_LARGE.min(size.width - 2);
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(size.height - 2);
+
+    let dialog_area = ratatui::layout::Rect {
+        x: (size.width - dialog_width) / 2,
+        y: (size.height - dialog_height) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = format!("🐞 Diagnostics ({})", dialog.entries.len());
+    let dialog_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary));
+
+    let inner_area = dialog_block.inner(dialog_area);
+    frame.render_widget(dialog_block, dialog_area);
+
+    // The Xcode line only applies where simctl exists
+    let show_xcode = dialog.developer_dir.is_some() || !dialog.installed_xcodes.is_empty();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(u16::from(show_xcode)),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    if show_xcode {
+        let xcode = Paragraph::new(xcode_line(dialog.developer_dir.as_ref()))
+            .style(Style::default().fg(theme.text));
+        frame.render_widget(xcode, chunks[0]);
+    }
+    let chunks = [chunks[1], chunks[2]];
+
+    if dialog.entries.is_empty() {
+        let empty = Paragraph::new(EMPTY_DIAGNOSTICS)
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+            .alignment(Alignment::Center);
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = dialog
+            .entries
+            .iter()
+            .skip(dialog.scroll_offset)
+            .take(chunks[0].height as usize)
+            .map(|entry| {
+                let level_color = match entry.level {
+                    Level::Error => LOG_COLOR_ERROR,
+                    Level::Warn => LOG_COLOR_WARN,
+                    Level::Info => LOG_COLOR_INFO,
+                    Level::Debug => LOG_COLOR_DEBUG,
+                    Level::Trace => LOG_COLOR_VERBOSE,
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        entry.timestamp.format("%H:%M:%S ").to_string(),
+                        Style::default().fg(UI_COLOR_TEXT_DIM),
+                    ),
+                    Span::styled(
+                        format!("{:<6}", entry.level),
+                        Style::default()
+                            .fg(level_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(entry.message.clone(), Style::default().fg(theme.text)),
+                ]))
+            })
+            .collect();
+        frame.render_widget(List::new(items), chunks[0]);
+    }
+
+    let shortcuts = if dialog.installed_xcodes.len() > 1 {
+        DIAGNOSTICS_SHORTCUTS_WITH_XCODE
+    } else {
+        DIAGNOSTICS_SHORTCUTS
+    };
+    let shortcuts = Paragraph::new(shortcuts)
+        .style(
+            Style::default()
+                .fg(UI_COLOR_TEXT_DIM)
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
+
+fn xcode_line(developer_dir: Option<&DeveloperDir>) -> String {
+    let Some(developer_dir) = developer_dir else {
+        return XCODE_LABEL.replace("{}", XCODE_UNKNOWN);
+    };
+    let source = match developer_dir.source {
+        DeveloperDirSource::Emu => XCODE_SOURCE_EMU,
+        DeveloperDirSource::Environment => XCODE_SOURCE_ENV,
+        DeveloperDirSource::XcodeSelect => XCODE_SOURCE_SELECT,
+    };
+    format!(
+        "{}{source}",
+        XCODE_LABEL.replace("{}", &developer_dir.path.display().to_string())
+    )
+}
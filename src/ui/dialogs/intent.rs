@@ -0,0 +1,135 @@
+use crate::{
+    app::{AppState, IntentField},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::intent::{
+            ACTION_FIELD, COMPONENT_FIELD, DATA_URI_FIELD, EXTRAS_FIELD, HISTORY_POSITION,
+            KIND_ACTIVITY, KIND_BROADCAST, KIND_FIELD, SHORTCUTS, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_intent_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.intent_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let kind = if dialog.is_broadcast() {
+        format!("◀ {KIND_BROADCAST} ▶")
+    } else {
+        format!("◀ {KIND_ACTIVITY} ▶")
+    };
+    let fields = [
+        (IntentField::Kind, KIND_FIELD, kind),
+        (
+            IntentField::Action,
+            ACTION_FIELD,
+            dialog.intent.action.clone(),
+        ),
+        (
+            IntentField::DataUri,
+            DATA_URI_FIELD,
+            dialog.intent.data_uri.clone(),
+        ),
+        (
+            IntentField::Component,
+            COMPONENT_FIELD,
+            dialog.intent.component.clone(),
+        ),
+        (
+            IntentField::Extras,
+            EXTRAS_FIELD,
+            dialog.intent.extras.clone(),
+        ),
+    ];
+    for (index, (field, label, value)) in fields.into_iter().enumerate() {
+        let is_active = dialog.active_field == field;
+        let border_color = if is_active {
+            theme.primary
+        } else {
+            UI_COLOR_BORDER
+        };
+        let cursor = if is_active && field != IntentField::Kind {
+            "_"
+        } else {
+            ""
+        };
+        let input = Paragraph::new(format!("{value}{cursor}"))
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .title(label)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color)),
+            );
+        frame.render_widget(input, chunks[index]);
+    }
+
+    if !dialog.history.is_empty() {
+        let position = match dialog.history_index {
+            Some(index) => format!("{}/{}", index + 1, dialog.history.len()),
+            None => format!("-/{}", dialog.history.len()),
+        };
+        let mut lines = vec![Line::styled(
+            HISTORY_POSITION.replace("{}", &position),
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        )];
+        lines.extend(dialog.history.iter().enumerate().map(|(index, intent)| {
+            let style = if dialog.history_index == Some(index) {
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(UI_COLOR_TEXT_DIM)
+            };
+            Line::styled(format!("  {}", intent.summary()), style)
+        }));
+        frame.render_widget(Paragraph::new(lines), chunks[5]);
+    }
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[6]);
+}
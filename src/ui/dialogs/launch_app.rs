@@ -0,0 +1,103 @@
+use crate::{
+    app::{AppState, LaunchAppField},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_MEDIUM, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::launch_app::{
+            ANDROID_ARGUMENTS_FIELD, ANDROID_HINT, APP_FIELD, ENVIRONMENT_FIELD,
+            IOS_ARGUMENTS_FIELD, IOS_HINT, SHORTCUTS, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_launch_app_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.launch_app_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_MEDIUM.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let mut fields = vec![(LaunchAppField::App, APP_FIELD, &dialog.app)];
+    let hint = if dialog.takes_environment() {
+        fields.extend([
+            (
+                LaunchAppField::Arguments,
+                IOS_ARGUMENTS_FIELD,
+                &dialog.arguments,
+            ),
+            (
+                LaunchAppField::Environment,
+                ENVIRONMENT_FIELD,
+                &dialog.environment,
+            ),
+        ]);
+        IOS_HINT
+    } else {
+        fields.push((
+            LaunchAppField::Arguments,
+            ANDROID_ARGUMENTS_FIELD,
+            &dialog.arguments,
+        ));
+        ANDROID_HINT
+    };
+
+    let mut constraints = vec![Constraint::Length(3); fields.len()];
+    constraints.extend([Constraint::Min(0), Constraint::Length(1)]);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    for (index, (field, label, value)) in fields.iter().enumerate() {
+        let is_active = dialog.active_field == *field;
+        let border_color = if is_active {
+            theme.primary
+        } else {
+            UI_COLOR_BORDER
+        };
+        let cursor = if is_active { "_" } else { "" };
+        let input = Paragraph::new(format!("{value}{cursor}"))
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .title(*label)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color)),
+            );
+        frame.render_widget(input, chunks[index]);
+    }
+
+    let hint = Paragraph::new(hint)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(hint, chunks[fields.len()]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[fields.len() + 1]);
+}
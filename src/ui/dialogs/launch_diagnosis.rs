@@ -0,0 +1,84 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_MEDIUM, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::launch_diagnosis::{AUTO_FIX, FIXING, MANUAL_FIX, REASON, SHORTCUTS, TITLE},
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_launch_diagnosis_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.launch_diagnosis_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_MEDIUM.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(STATUS_COLOR_WARNING))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let mut lines = Vec::new();
+    if let Some(reason) = &dialog.reason {
+        lines.push(Line::styled(
+            REASON.replace("{}", reason),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        ));
+        lines.push(Line::raw(""));
+    }
+    for (index, issue) in dialog.issues.iter().enumerate() {
+        let summary_style = if index == dialog.selected {
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        lines.push(Line::styled(
+            format!("• {}", issue.summary()),
+            summary_style,
+        ));
+        let fix = if dialog.fixing.as_ref() == Some(issue) {
+            FIXING.to_string()
+        } else if issue.can_fix() {
+            format!("{AUTO_FIX}{}", issue.fix_hint())
+        } else {
+            format!("{MANUAL_FIX}{}", issue.fix_hint())
+        };
+        lines.push(Line::styled(fix, Style::default().fg(UI_COLOR_TEXT_DIM)));
+    }
+
+    let issues = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(issues, chunks[0]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
@@ -1,9 +1,55 @@
+mod annotations;
 mod api_levels;
+mod app_data;
+mod clock;
+mod command_history;
 mod confirmation;
 mod create_device;
+mod creation_queue;
+mod developer_toggles;
+mod device_catalog;
+mod diagnostics;
+mod intent;
+mod launch_app;
+mod launch_diagnosis;
+mod monkey;
+mod move_device_data;
 mod notifications;
+mod processes;
+mod properties;
+mod prune_unavailable;
+mod quick_boot;
+mod radios;
+mod repair_device;
+mod snippets;
+mod test_matrix;
+mod trace;
+mod type_into_device;
 
-pub(crate) use api_levels::render_api_level_dialog;
+pub(crate) use annotations::{render_annotation_dialog, render_search_prompt};
+pub(crate) use api_levels::{format_bytes, render_api_level_dialog};
+pub(crate) use app_data::render_app_data_dialog;
+pub(crate) use clock::render_device_clock_dialog;
+pub(crate) use command_history::render_command_history_dialog;
 pub(crate) use confirmation::{render_confirm_delete_dialog, render_confirm_wipe_dialog};
 pub(crate) use create_device::render_create_device_dialog;
+pub(crate) use creation_queue::render_creation_queue_dialog;
+pub(crate) use developer_toggles::render_developer_toggles_dialog;
+pub(crate) use device_catalog::render_device_catalog_dialog;
+pub(crate) use diagnostics::render_diagnostics_dialog;
+pub(crate) use intent::render_intent_dialog;
+pub(crate) use launch_app::render_launch_app_dialog;
+pub(crate) use launch_diagnosis::render_launch_diagnosis_dialog;
+pub(crate) use monkey::render_monkey_dialog;
+pub(crate) use move_device_data::render_move_device_data_dialog;
 pub(crate) use notifications::render_notifications;
+pub(crate) use processes::render_process_inspector_dialog;
+pub(crate) use properties::render_system_properties_dialog;
+pub(crate) use prune_unavailable::render_prune_unavailable_dialog;
+pub(crate) use quick_boot::render_quick_boot_dialog;
+pub(crate) use radios::render_radios_dialog;
+pub(crate) use repair_device::render_repair_device_dialog;
+pub(crate) use snippets::render_snippets_dialog;
+pub(crate) use test_matrix::render_test_matrix_dialog;
+pub(crate) use trace::render_trace_dialog;
+pub(crate) use type_into_device::render_type_into_device_dialog;
@@ -0,0 +1,91 @@
+use crate::{
+    app::{AppState, MonkeyField},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_MEDIUM, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::monkey::{
+            EVENT_COUNT_FIELD, HINT, PACKAGE_FIELD, SHORTCUTS, THROTTLE_FIELD, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_monkey_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.monkey_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_MEDIUM.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let fields = [
+        (MonkeyField::Package, PACKAGE_FIELD, &dialog.package),
+        (
+            MonkeyField::EventCount,
+            EVENT_COUNT_FIELD,
+            &dialog.event_count,
+        ),
+        (MonkeyField::Throttle, THROTTLE_FIELD, &dialog.throttle_ms),
+    ];
+    for (index, (field, label, value)) in fields.into_iter().enumerate() {
+        let is_active = dialog.active_field == field;
+        let border_color = if is_active {
+            theme.primary
+        } else {
+            UI_COLOR_BORDER
+        };
+        let cursor = if is_active { "_" } else { "" };
+        let input = Paragraph::new(format!("{value}{cursor}"))
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .title(label)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color)),
+            );
+        frame.render_widget(input, chunks[index]);
+    }
+
+    let hint = Paragraph::new(HINT)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(hint, chunks[3]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[4]);
+}
@@ -0,0 +1,87 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::move_device_data::{
+            CURRENT_LOCATION, RESULT_LOCATION, SHORTCUTS, TARGET_FIELD, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_move_device_data_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.move_device_data_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let current =
+        Paragraph::new(CURRENT_LOCATION.replace("{}", &dialog.current_dir.to_string_lossy()))
+            .style(Style::default().fg(theme.text))
+            .wrap(Wrap { trim: true });
+    frame.render_widget(current, chunks[0]);
+
+    let input = Paragraph::new(format!("{}_", dialog.target_input))
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .title(TARGET_FIELD)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.primary)),
+        );
+    frame.render_widget(input, chunks[1]);
+
+    let destination = dialog
+        .target_dir()
+        .zip(dialog.current_dir.file_name())
+        .map(|(target_dir, dir_name)| target_dir.join(dir_name));
+    if let Some(destination) = destination {
+        let result = Paragraph::new(Line::styled(
+            RESULT_LOCATION.replace("{}", &destination.to_string_lossy()),
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(result, chunks[2]);
+    }
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[3]);
+}
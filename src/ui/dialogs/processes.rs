@@ -0,0 +1,166 @@
+use crate::{
+    app::{state::ProcessInspectorTab, AppState},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::processes::{
+            EMPTY, LOADING, PROCESSES_TAB, PROCESS_HEADER, SERVICES_TAB, SERVICE_HEADER, SHORTCUTS,
+            SORTED_BY, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs},
+    Frame,
+};
+
+pub(crate) fn render_process_inspector_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.process_inspector_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let [tabs_area, sort_area] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(20)])
+        .areas(chunks[0]);
+    let selected_tab = match dialog.tab {
+        ProcessInspectorTab::Processes => 0,
+        ProcessInspectorTab::Services => 1,
+    };
+    let tabs = Tabs::new([PROCESSES_TAB, SERVICES_TAB])
+        .select(selected_tab)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .highlight_style(
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )
+        .divider("│");
+    frame.render_widget(tabs, tabs_area);
+    let sort = Paragraph::new(SORTED_BY.replace("{}", dialog.sort.label()))
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Right);
+    frame.render_widget(sort, sort_area);
+
+    let (header, loaded) = match dialog.tab {
+        ProcessInspectorTab::Processes => (
+            PROCESS_HEADER,
+            dialog.processes.as_ref().map(|processes| {
+                processes.as_ref().map(|processes| {
+                    processes
+                        .iter()
+                        .map(|process| {
+                            format!(
+                                "{:>7}  {:>6}  {:<12}  {}",
+                                process.pid,
+                                format_memory(process.memory_kb),
+                                process.user.as_deref().unwrap_or("-"),
+                                process.name
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }),
+        ),
+        ProcessInspectorTab::Services => (
+            SERVICE_HEADER,
+            dialog.services.as_ref().map(|services| {
+                services.as_ref().map(|services| {
+                    services
+                        .iter()
+                        .map(|service| {
+                            let pid = service
+                                .pid
+                                .map_or_else(|| "-".to_string(), |pid| pid.to_string());
+                            let memory = service.pid.and_then(|pid| dialog.memory_of(pid));
+                            format!("{pid:>7}  {:>6}  {}", format_memory(memory), service.name)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }),
+        ),
+    };
+
+    let list_area = chunks[2];
+    let rows = match loaded {
+        None => vec![ListItem::new(Span::styled(
+            LOADING,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))],
+        Some(Err(error)) => vec![ListItem::new(Span::styled(
+            error.clone(),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        ))],
+        Some(Ok(rows)) if rows.is_empty() => vec![ListItem::new(Span::styled(
+            EMPTY,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))],
+        Some(Ok(rows)) => {
+            let visible = list_area.height.saturating_sub(1).max(1) as usize;
+            let offset = dialog.selected.saturating_sub(visible - 1);
+            let mut items = vec![ListItem::new(Span::styled(
+                header,
+                Style::default()
+                    .fg(UI_COLOR_TEXT_DIM)
+                    .add_modifier(Modifier::BOLD),
+            ))];
+            items.extend(rows.into_iter().enumerate().skip(offset).take(visible).map(
+                |(index, row)| {
+                    let style = if index == dialog.selected {
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                    } else {
+                        Style::default().fg(theme.text)
+                    };
+                    ListItem::new(Line::styled(row, style))
+                },
+            ));
+            items
+        }
+    };
+    frame.render_widget(List::new(rows), list_area);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[3]);
+}
+
+/// Resident memory in MiB, or `-` when the platform does not report it.
+fn format_memory(memory_kb: Option<u64>) -> String {
+    memory_kb.map_or_else(|| "-".to_string(), |kb| format!("{}M", kb / 1024))
+}
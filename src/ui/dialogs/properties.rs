@@ -0,0 +1,118 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::properties::{
+            EDIT_FIELD, EMPTY, LOADING, READ_ONLY, SEARCH_FIELD, SHORTCUTS, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_system_properties_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.system_properties_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let selected = dialog.selected_property();
+    let (input_title, input_value) = match (&dialog.edit_value, selected) {
+        (Some(value), Some(property)) => (EDIT_FIELD.replace("{}", &property.name), value),
+        _ => (SEARCH_FIELD.to_string(), &dialog.filter),
+    };
+    let input = Paragraph::new(format!("{input_value}_"))
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .title(input_title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.primary)),
+        );
+    frame.render_widget(input, chunks[0]);
+
+    let list_area = chunks[1];
+    let filtered = dialog.filtered();
+    let rows = match &dialog.properties {
+        None => vec![ListItem::new(Span::styled(
+            LOADING,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))],
+        Some(Err(error)) => vec![ListItem::new(Span::styled(
+            error.clone(),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        ))],
+        Some(Ok(_)) if filtered.is_empty() => vec![ListItem::new(Span::styled(
+            EMPTY,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))],
+        Some(Ok(_)) => {
+            let visible = list_area.height.max(1) as usize;
+            let offset = dialog.selected.saturating_sub(visible - 1);
+            filtered
+                .iter()
+                .enumerate()
+                .skip(offset)
+                .take(visible)
+                .map(|(index, property)| {
+                    let lock = if property.is_writable() {
+                        "  "
+                    } else {
+                        READ_ONLY
+                    };
+                    let row = format!("{lock} {} = {}", property.name, property.value);
+                    let style = if index == dialog.selected {
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                    } else if property.is_writable() {
+                        Style::default().fg(theme.text)
+                    } else {
+                        Style::default().fg(UI_COLOR_TEXT_DIM)
+                    };
+                    ListItem::new(Line::styled(row, style))
+                })
+                .collect()
+        }
+    };
+    frame.render_widget(List::new(rows), list_area);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[2]);
+}
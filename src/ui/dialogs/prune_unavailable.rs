@@ -0,0 +1,111 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_WIDTH_LARGE},
+        ui_text::{
+            device_states::IOS_UNAVAILABLE,
+            prune_unavailable::{SHORTCUTS, SHORTCUTS_EACH, TITLE, TITLE_EACH},
+        },
+    },
+    models::IosDevice,
+    ui::Theme,
+    utils::text::{pad_to_width, truncate_to_width},
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_prune_unavailable_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.prune_unavailable_dialog else {
+        return;
+    };
+
+    let size = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(size.width - 2);
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(size.height - 2);
+    let dialog_area = Rect::new(
+        (size.width - dialog_width) / 2,
+        (size.height - dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = if dialog.one_at_a_time {
+        TITLE_EACH
+            .replacen("{}", &(dialog.current + 1).to_string(), 1)
+            .replacen("{}", &dialog.devices.len().to_string(), 1)
+    } else {
+        TITLE.replace("{}", &dialog.devices.len().to_string())
+    };
+    let dialog_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(STATUS_COLOR_WARNING));
+    let inner_area = dialog_block.inner(dialog_area);
+    frame.render_widget(dialog_block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let items: Vec<ListItem> = dialog
+        .devices
+        .iter()
+        .enumerate()
+        .skip(dialog.scroll_offset)
+        .take(chunks[0].height as usize)
+        .map(|(index, device)| {
+            let asked = dialog.one_at_a_time && index == dialog.current;
+            prune_row(device, asked, theme)
+        })
+        .collect();
+    frame.render_widget(List::new(items), chunks[0]);
+
+    let shortcuts = if dialog.one_at_a_time {
+        SHORTCUTS_EACH
+    } else {
+        SHORTCUTS
+    };
+    let shortcuts = Paragraph::new(shortcuts)
+        .style(
+            Style::default()
+                .fg(UI_COLOR_TEXT_DIM)
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
+
+/// One simulator; `asked` marks the one a step-by-step dialog asks about.
+fn prune_row<'a>(device: &'a IosDevice, asked: bool, theme: &Theme) -> ListItem<'a> {
+    let reason = device
+        .unavailable_reason()
+        .map(|reason| reason.label().to_string())
+        .filter(|label| !label.is_empty())
+        .unwrap_or_else(|| IOS_UNAVAILABLE.trim().to_string());
+
+    let item = ListItem::new(Line::from(vec![
+        Span::raw("🍎 "),
+        Span::styled(
+            format!(
+                "{} ",
+                pad_to_width(&truncate_to_width(&device.name, 32), 32)
+            ),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(reason, Style::default().fg(UI_COLOR_TEXT_DIM)),
+    ]));
+    if asked {
+        item.style(Style::default().add_modifier(Modifier::REVERSED))
+    } else {
+        item
+    }
+}
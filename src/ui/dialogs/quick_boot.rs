@@ -0,0 +1,88 @@
+use super::api_levels::format_bytes;
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_SMALL},
+        ui_text::quick_boot::{
+            ALWAYS_COLD_BOOT, COLD_BOOT_ONCE, LOADING, NO_SNAPSHOT, OFF, ON, SHORTCUTS, SNAPSHOT,
+            TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub(crate) fn render_quick_boot_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.quick_boot_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_SMALL.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let lines = match &dialog.status {
+        None => vec![Line::styled(
+            LOADING,
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        )],
+        Some(status) => {
+            let snapshot = status
+                .snapshot_size
+                .map_or_else(|| NO_SNAPSHOT.to_string(), format_bytes);
+            let toggle = |label: &'static str, enabled: bool| {
+                let (value, color) = if enabled {
+                    (ON, STATUS_COLOR_SUCCESS)
+                } else {
+                    (OFF, UI_COLOR_TEXT_DIM)
+                };
+                Line::from(vec![
+                    Span::styled(label, Style::default().fg(theme.text)),
+                    Span::styled(value, Style::default().fg(color)),
+                ])
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(SNAPSHOT, Style::default().fg(theme.text)),
+                    Span::styled(snapshot, Style::default().fg(theme.primary)),
+                ]),
+                Line::raw(""),
+                toggle(COLD_BOOT_ONCE, status.cold_boot_once),
+                toggle(ALWAYS_COLD_BOOT, status.always_cold_boot),
+            ]
+        }
+    };
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
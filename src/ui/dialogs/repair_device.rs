@@ -0,0 +1,97 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_SMALL},
+        ui_text::repair_device::{
+            REASON, RUNTIME_MISSING_HINT, SHORTCUTS, SHORTCUTS_WITH_INSTALL, TITLE, UNAVAILABLE,
+            UNKNOWN_REASON, UNSUPPORTED_HINT,
+        },
+    },
+    models::SimulatorUnavailableReason,
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_repair_device_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.repair_device_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_SMALL.min(area.width - DIALOG_MARGIN);
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height - DIALOG_MARGIN);
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(TITLE)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(STATUS_COLOR_WARNING))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(2), Constraint::Length(1)])
+        .split(inner_area);
+
+    let reason = match dialog.reason.label() {
+        "" => UNKNOWN_REASON,
+        label => label,
+    };
+    let mut lines = vec![
+        Line::styled(
+            UNAVAILABLE.replace("{}", &dialog.device_name),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Line::styled(
+            REASON.replace("{}", reason),
+            Style::default().fg(STATUS_COLOR_WARNING),
+        ),
+        Line::raw(""),
+    ];
+    match dialog.reason {
+        SimulatorUnavailableReason::RuntimeMissing => lines.push(Line::styled(
+            RUNTIME_MISSING_HINT.replace("{}", &dialog.ios_version),
+            Style::default().fg(theme.text),
+        )),
+        SimulatorUnavailableReason::Unsupported => lines.push(Line::styled(
+            UNSUPPORTED_HINT,
+            Style::default().fg(theme.text),
+        )),
+        SimulatorUnavailableReason::Other(_) => {}
+    }
+
+    let message = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(message, chunks[0]);
+
+    let shortcuts = if dialog.can_install_runtime() {
+        SHORTCUTS_WITH_INSTALL
+    } else {
+        SHORTCUTS
+    };
+    let shortcuts = Paragraph::new(shortcuts)
+        .style(
+            Style::default()
+                .fg(UI_COLOR_TEXT_DIM)
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
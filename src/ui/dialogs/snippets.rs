@@ -0,0 +1,85 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::snippets::{SHORTCUTS, TITLE},
+    },
+    models::Snippet,
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Lines each snippet takes: name, then command.
+const ENTRY_HEIGHT: usize = 2;
+
+pub(crate) fn render_snippets_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.snippets_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    // Scrolls just far enough to keep the highlighted snippet visible
+    let visible_entries = (usize::from(chunks[0].height) / ENTRY_HEIGHT).max(1);
+    let first = dialog.selected.saturating_sub(visible_entries - 1);
+    let items: Vec<ListItem> = dialog
+        .snippets
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible_entries)
+        .map(|(index, snippet)| snippet_row(snippet, index == dialog.selected, theme))
+        .collect();
+    frame.render_widget(List::new(items), chunks[0]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
+
+fn snippet_row<'a>(snippet: &'a Snippet, selected: bool, theme: &Theme) -> ListItem<'a> {
+    let name_style = if selected {
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+    };
+    ListItem::new(vec![
+        Line::styled(snippet.name.as_str(), name_style),
+        Line::styled(
+            format!("   {}", snippet.command),
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ),
+    ])
+}
@@ -0,0 +1,174 @@
+use crate::{
+    app::{state::TestMatrixField, AppState, Panel},
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_LARGE, DIALOG_MARGIN, DIALOG_WIDTH_LARGE},
+        ui_text::test_matrix::{
+            API_LEVELS, DEVICE_TYPES, GROUP, LOADING, RUNTIMES, SHORTCUTS, SUMMARY, TITLE,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+use std::collections::BTreeSet;
+
+pub(crate) fn render_test_matrix_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.test_matrix_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_LARGE.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_LARGE.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    if dialog.is_loading {
+        let loading = Paragraph::new(LOADING).style(Style::default().fg(UI_COLOR_TEXT_DIM));
+        frame.render_widget(loading, chunks[0]);
+    } else {
+        let lists = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+        let versions_title = match dialog.panel {
+            Panel::Android => API_LEVELS,
+            Panel::Ios => RUNTIMES,
+        };
+        render_list(
+            frame,
+            lists[0],
+            DEVICE_TYPES,
+            &dialog.device_types,
+            &dialog.marked_device_types,
+            (dialog.field == TestMatrixField::DeviceTypes).then_some(dialog.selected_device_type),
+            theme,
+        );
+        render_list(
+            frame,
+            lists[1],
+            versions_title,
+            &dialog.versions,
+            &dialog.marked_versions,
+            (dialog.field == TestMatrixField::Versions).then_some(dialog.selected_version),
+            theme,
+        );
+    }
+
+    let group_style = if dialog.field == TestMatrixField::Group {
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    let cursor = if dialog.field == TestMatrixField::Group {
+        "_"
+    } else {
+        ""
+    };
+    let group = Line::from(vec![
+        Span::styled(format!("{GROUP}: "), group_style),
+        Span::styled(
+            format!("{}{cursor}", dialog.group),
+            Style::default().fg(theme.text),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(group), chunks[1]);
+
+    let status = match &dialog.error {
+        Some(error) => Line::styled(error.as_str(), Style::default().fg(theme.error)),
+        None => {
+            let matrix = dialog.matrix();
+            Line::styled(
+                SUMMARY
+                    .replace("{devices}", &matrix.len().to_string())
+                    .replace("{types}", &matrix.device_types.len().to_string())
+                    .replace("{versions}", &matrix.versions.len().to_string()),
+                Style::default().fg(UI_COLOR_TEXT_DIM),
+            )
+        }
+    };
+    frame.render_widget(Paragraph::new(status), chunks[2]);
+
+    let shortcuts = Paragraph::new(SHORTCUTS)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[3]);
+}
+
+/// One column of the wizard. `selected` is the highlighted row while the
+/// column has focus, and the list scrolls to keep it visible.
+fn render_list(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    items: &[(String, String)],
+    marked: &BTreeSet<usize>,
+    selected: Option<usize>,
+    theme: &Theme,
+) {
+    let border_color = if selected.is_some() {
+        theme.primary
+    } else {
+        UI_COLOR_TEXT_DIM
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color));
+    let visible_rows = usize::from(block.inner(area).height).max(1);
+    let first = selected.unwrap_or(0).saturating_sub(visible_rows - 1);
+
+    let rows: Vec<ListItem> = items
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible_rows)
+        .map(|(index, (_, display))| {
+            let check = if marked.contains(&index) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let style = if selected == Some(index) {
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            ListItem::new(Line::styled(format!("{check} {display}"), style))
+        })
+        .collect();
+    frame.render_widget(List::new(rows).block(block), area);
+}
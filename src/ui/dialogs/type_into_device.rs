@@ -0,0 +1,62 @@
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_layout::{DIALOG_HEIGHT_SMALL, DIALOG_MARGIN, DIALOG_WIDTH_MEDIUM},
+        ui_text::type_into_device::{ESCAPE_ARMED, PLACEHOLDER, SHORTCUTS, TITLE},
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub(crate) fn render_type_into_device_dialog(frame: &mut Frame, state: &AppState, theme: &Theme) {
+    let Some(dialog) = &state.type_into_device_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = DIALOG_WIDTH_MEDIUM.min(area.width.saturating_sub(DIALOG_MARGIN));
+    let dialog_height = DIALOG_HEIGHT_SMALL.min(area.height.saturating_sub(DIALOG_MARGIN));
+    let dialog_area = Rect::new(
+        area.width.saturating_sub(dialog_width) / 2,
+        area.height.saturating_sub(dialog_height) / 2,
+        dialog_width,
+        dialog_height,
+    );
+
+    frame.render_widget(Clear, dialog_area);
+    let block = Block::default()
+        .title(TITLE.replace("{}", &dialog.device_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(UI_COLOR_BACKGROUND));
+    let inner_area = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let echo = if dialog.echo.is_empty() {
+        Paragraph::new(PLACEHOLDER).style(Style::default().fg(UI_COLOR_TEXT_DIM))
+    } else {
+        Paragraph::new(format!("{}_", dialog.echo)).style(Style::default().fg(theme.text))
+    };
+    frame.render_widget(echo.wrap(Wrap { trim: false }), chunks[0]);
+
+    let (hint, color) = if dialog.escape_armed {
+        (ESCAPE_ARMED, STATUS_COLOR_WARNING)
+    } else {
+        (SHORTCUTS, UI_COLOR_TEXT_DIM)
+    };
+    let shortcuts = Paragraph::new(hint)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center);
+    frame.render_widget(shortcuts, chunks[1]);
+}
@@ -2,12 +2,33 @@
 //!
 //! This module provides a mock implementation of ratatui's Backend trait
 //! for testing UI rendering without requiring an actual terminal.
+//!
+//! [`render_snapshot`] and [`assert_snapshot`] build on it for golden-file
+//! tests: render an `AppState` at a fixed size and compare the screen text
+//! with a file checked into the repository.
+//!
+//! ```no_run
+//! use emu::app::AppState;
+//! use emu::ui::mock_backend::{assert_snapshot, render_snapshot};
+//!
+//! let mut state = AppState::new();
+//! let screen = render_snapshot(&mut state, 120, 40);
+//! assert_snapshot(&screen, "tests/snapshots/empty_state.txt");
+//! ```
+//!
+//! Run with `EMU_UPDATE_SNAPSHOTS=1` to write the golden files instead of
+//! comparing against them.
 
+use crate::app::AppState;
+use crate::constants::env_vars::EMU_UPDATE_SNAPSHOTS;
+use crate::ui::{render::draw_app, widgets::MOON_PHASES};
 use ratatui::backend::Backend;
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Position, Rect, Size};
 use ratatui::style::Style;
+use ratatui::Terminal;
 use std::io::Result;
+use std::path::Path;
 
 /// Mock Terminal Backend for testing UI components
 #[derive(Debug, Clone)]
@@ -223,6 +244,51 @@ impl Backend for MockBackend {
     }
 }
 
+/// Renders the whole app for `state` on a `width` x `height` screen and
+/// returns it as text, one line per row with trailing spaces removed.
+///
+/// The result only depends on `state`: the refresh age is reset to zero
+/// and loading animations are shown in their first frame. Device uptimes
+/// come from the state's history, so set them explicitly if they matter.
+pub fn render_snapshot(state: &mut AppState, width: u16, height: u16) -> String {
+    state.mark_refreshed();
+    let theme = state.theme.clone();
+    let mut terminal =
+        Terminal::new(MockBackend::new(width, height)).expect("mock terminal never fails");
+    terminal
+        .draw(|frame| draw_app(frame, state, &theme))
+        .expect("mock terminal never fails");
+
+    MOON_PHASES[1..]
+        .iter()
+        .fold(terminal.backend().get_buffer_text(), |text, phase| {
+            text.replace(phase, MOON_PHASES[0])
+        })
+}
+
+/// Compares `actual` with the golden file at `path`, panicking with both
+/// versions on a mismatch. The file is (re)written instead when it does not
+/// exist yet or `EMU_UPDATE_SNAPSHOTS` is set.
+pub fn assert_snapshot(actual: &str, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    if std::env::var_os(EMU_UPDATE_SNAPSHOTS).is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("snapshot directory should be writable");
+        }
+        std::fs::write(path, format!("{actual}\n")).expect("snapshot should be writable");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(path).expect("snapshot should be readable");
+    let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+    assert!(
+        expected == actual,
+        "snapshot {} does not match; rerun with {EMU_UPDATE_SNAPSHOTS}=1 to accept\n\
+         --- expected\n{expected}\n--- actual\n{actual}",
+        path.display()
+    );
+}
+
 impl Default for MockBackend {
     fn default() -> Self {
         Self::new(80, 24) // Standard terminal size
@@ -233,7 +299,6 @@ impl Default for MockBackend {
 mod tests {
     use super::*;
     use ratatui::widgets::{Block, Borders, Paragraph};
-    use ratatui::Terminal;
 
     #[test]
     fn test_mock_backend_creation() {
@@ -317,4 +382,40 @@ mod tests {
         assert!(backend.assert_contains_text("Test Content"));
         assert!(!backend.assert_contains_text("Not Present"));
     }
+
+    #[test]
+    fn test_render_snapshot_is_stable() {
+        let mut state = AppState::new();
+        state.is_loading = true;
+
+        let first = render_snapshot(&mut state, 100, 30);
+        let second = render_snapshot(&mut state, 100, 30);
+        assert_eq!(first, second);
+        assert_eq!(first.lines().count(), 30);
+        assert!(first.lines().all(|line| line == line.trim_end()));
+        assert!(MOON_PHASES[1..].iter().all(|phase| !first.contains(phase)));
+    }
+
+    #[test]
+    fn test_assert_snapshot_writes_missing_golden_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("screen.txt");
+
+        assert_snapshot("line one\nline two", &path);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "line one\nline two\n"
+        );
+        assert_snapshot("line one\nline two", &path);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match")]
+    fn test_assert_snapshot_reports_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("screen.txt");
+        std::fs::write(&path, "before\n").unwrap();
+
+        assert_snapshot("after", &path);
+    }
 }
@@ -7,7 +7,7 @@
 //!
 //! - `render` - Main rendering logic and layout management
 //! - `theme` - Color themes and styling configuration
-//! - `widgets` - Custom UI widgets and components
+//! - `widgets` - Custom UI widgets and components, including the global `status_bar`
 //!
 //! # Architecture
 //!
@@ -26,9 +26,9 @@ pub mod widgets;
 pub mod mock_backend;
 
 // Re-export commonly used UI types
-pub use theme::Theme;
+pub use theme::{Theme, ThemeName};
 pub use widgets::*;
 
 // Re-export testing utilities when available
 #[cfg(any(test, feature = "test-utils"))]
-pub use mock_backend::MockBackend;
+pub use mock_backend::{assert_snapshot, render_snapshot, MockBackend};
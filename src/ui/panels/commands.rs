@@ -1,5 +1,5 @@
 use crate::{
-    app::{AppState, Mode, Panel},
+    app::{AppState, Mode},
     constants::{
         colors::*,
         ui_layout::{
@@ -9,20 +9,22 @@ use crate::{
         ui_text::{
             log_shortcuts::LOG_MODE_SHORTCUTS,
             shortcuts::{
-                CREATE, DELETE, HORIZONTAL_NAV, INSTALL, REFRESH, START_STOP, SWITCH_PANELS,
-                VERTICAL_NAV, WIPE,
+                ANNOTATE, COMMAND_HISTORY, COPY_COMMAND, CREATE, DELETE, DETAILS_TABS, DUPLICATE,
+                FULLSCREEN_DEVICES, HORIZONTAL_NAV, INSTALL, PAUSE_REFRESH, READ_ONLY, REFRESH,
+                RESIZE_PANELS, SEARCH, START_STOP, SWITCH_PANELS, VERTICAL_NAV, WIPE,
             },
         },
     },
     ui::{widgets::get_animated_moon, Theme},
+    utils::text::display_width,
 };
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Modifier, Style},
-    text::Line,
     widgets::Paragraph,
     Frame,
 };
+use unicode_segmentation::UnicodeSegmentation;
 
 pub(crate) fn render_device_commands(
     frame: &mut Frame,
@@ -58,13 +60,34 @@ fn device_command_lines(state: &AppState) -> Vec<String> {
                 HORIZONTAL_NAV,
                 START_STOP,
                 VERTICAL_NAV,
+                SEARCH,
             ]
             .join("  ");
 
-            let mut actions = vec![CREATE, DELETE, WIPE];
-            if matches!(state.active_panel, Panel::Android) {
+            let capabilities = state.active_capabilities();
+            let mut actions = Vec::new();
+            if state.read_only {
+                actions.push(READ_ONLY);
+            }
+            if capabilities.create {
+                actions.extend([CREATE, DUPLICATE]);
+            }
+            if capabilities.delete {
+                actions.push(DELETE);
+            }
+            if capabilities.wipe {
+                actions.push(WIPE);
+            }
+            if capabilities.install_system_images {
                 actions.push(INSTALL);
             }
+            actions.push(COPY_COMMAND);
+            actions.push(COMMAND_HISTORY);
+            actions.push(DETAILS_TABS);
+            actions.push(ANNOTATE);
+            actions.push(FULLSCREEN_DEVICES);
+            actions.push(RESIZE_PANELS);
+            actions.push(PAUSE_REFRESH);
             let action_line = actions.join("  ");
 
             vec![navigation_line, action_line]
@@ -250,7 +273,7 @@ fn push_long_word_chunks(
 ) {
     let mut chunk = String::new();
 
-    for ch in word.chars() {
+    for grapheme in word.graphemes(true) {
         if display_width(&chunk) >= width {
             if lines.len() < max_lines {
                 lines.push(std::mem::take(&mut chunk));
@@ -260,19 +283,16 @@ fn push_long_word_chunks(
                 return;
             }
         }
-        chunk.push(ch);
+        chunk.push_str(grapheme);
     }
 
     *current = chunk;
 }
 
-fn display_width(text: &str) -> usize {
-    Line::from(text).width()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::Panel;
     use crate::constants::ui_text::shortcuts::{
         ANDROID_NORMAL_MODE_SHORTCUTS, IOS_NORMAL_MODE_SHORTCUTS,
     };
@@ -320,6 +340,25 @@ mod tests {
         assert!(lines[0].contains("[k/j/↑/↓]move"));
         assert!(lines[1].contains("[c]reate"));
         assert!(lines[1].contains("[i]nstall"));
+        assert!(lines[1].contains("[y]ank command"));
+    }
+
+    #[test]
+    fn test_device_commands_only_offer_supported_actions() {
+        let mut state = AppState::new();
+        state.mode = Mode::Normal;
+        state.active_panel = Panel::Ios;
+        let formatted = format_device_commands_text(&state, 240);
+        assert!(formatted.contains("[w]ipe"));
+        assert!(!formatted.contains("[i]nstall"));
+
+        state.active_panel = Panel::Android;
+        state.read_only = true;
+        let formatted = format_device_commands_text(&state, 240);
+        assert!(formatted.contains("read-only"));
+        assert!(!formatted.contains("[c]reate"));
+        assert!(!formatted.contains("[d]elete"));
+        assert!(!formatted.contains("[i]nstall"));
     }
 
     #[test]
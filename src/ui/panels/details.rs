@@ -1,18 +1,22 @@
 use crate::{
-    app::AppState,
+    app::{AppState, DetailsTab},
     constants::{
         colors::*,
         ui_layout::{LOADING_INDICATOR_MARGIN, SEPARATOR_LENGTH},
-        ui_text::{architectures::*, progress::*},
+        ui_text::{
+            annotations, architectures::*, details_tabs, device_usage, network_capture,
+            progress::*, simulator_window,
+        },
     },
-    models::Platform,
-    ui::{widgets::get_animated_moon, Theme},
+    models::{DeviceDetails, Platform},
+    ui::{dialogs::format_bytes, render::format_age, widgets::get_animated_moon, Theme},
+    utils::text::display_width,
 };
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
     Frame,
 };
 
@@ -25,160 +29,288 @@ pub(crate) fn render_device_details_panel(
     let border_style = Style::default().fg(theme.text);
 
     if let Some(details) = state.get_selected_device_details() {
-        let mut lines = Vec::new();
+        let block = Block::default()
+            .title("Device Details")
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
 
-        let platform_icon = match details.platform {
-            Platform::Android => "🤖",
-            Platform::Ios => "🍎",
-        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(inner);
+        render_details_tabs(frame, chunks[0], state, theme);
+
+        if state.details_tab != DetailsTab::Info {
+            render_tab_content(frame, chunks[1], state, theme);
+            return;
+        }
+
+        let is_loading = details.platform == Platform::Android && details.device_path.is_none();
+        let paragraph = Paragraph::new(info_lines(details, state, theme)).wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, chunks[1]);
+
+        if is_loading {
+            render_loading_indicator(frame, area);
+        }
+    } else {
+        let no_device_text = Paragraph::new("No device selected")
+            .block(
+                Block::default()
+                    .title("Device Details")
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            )
+            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+            .alignment(Alignment::Center);
+
+        frame.render_widget(no_device_text, area);
+    }
+}
+
+fn render_details_tabs(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
+    let selected = DetailsTab::ALL
+        .iter()
+        .position(|tab| *tab == state.details_tab)
+        .unwrap_or(0);
+    let tabs = Tabs::new(DetailsTab::ALL.iter().map(|tab| tab.title()))
+        .select(selected)
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .highlight_style(
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )
+        .divider("│")
+        .padding("", " ");
+    frame.render_widget(tabs, area);
+}
+
+fn render_tab_content(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
+    let lines: Vec<Line> = match state.current_details_tab_content().map(|c| &c.lines) {
+        None => vec![Line::from(Span::styled(
+            format!("{} {}", get_animated_moon(), details_tabs::LOADING),
+            Style::default().fg(UI_COLOR_TEXT_DIM),
+        ))],
+        Some(Ok(lines)) => lines
+            .iter()
+            .map(|line| Line::from(Span::styled(line.clone(), Style::default().fg(theme.text))))
+            .collect(),
+        Some(Err(error)) => vec![Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(STATUS_COLOR_ERROR),
+        ))],
+    };
+
+    let mut content = vec![Line::from(Span::styled(
+        details_tabs::TAB_HINT,
+        Style::default().fg(UI_COLOR_TEXT_DIM),
+    ))];
+    content.extend(lines);
+    frame.render_widget(Paragraph::new(content).wrap(Wrap { trim: true }), area);
+}
+
+fn info_lines(details: DeviceDetails, state: &AppState, theme: &Theme) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    let platform_icon = match details.platform {
+        Platform::Android => "🤖",
+        Platform::Ios => "🍎",
+    };
+    lines.push(Line::from(vec![
+        Span::styled(platform_icon, Style::default().fg(theme.primary)),
+        Span::raw(" "),
+        Span::styled(
+            details.name.replace('_', " "),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    lines.push(Line::from(vec![Span::styled(
+        "━".repeat(SEPARATOR_LENGTH as usize),
+        Style::default().fg(UI_COLOR_TEXT_DIM),
+    )]));
+
+    let (status_icon, status_color) = if details.status == "Running" || details.status == "Booted" {
+        ("●", STATUS_COLOR_ACTIVE)
+    } else {
+        ("○", STATUS_COLOR_INACTIVE)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(status_icon, Style::default().fg(status_color)),
+        Span::raw(" "),
+        Span::styled(
+            details.status.clone(),
+            Style::default()
+                .fg(status_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    if let Some(uptime) = state.device_uptime(state.active_panel, &details.identifier) {
+        lines.push(Line::from(vec![
+            Span::raw(device_usage::UPTIME_LABEL),
+            Span::styled(format_age(uptime), Style::default().fg(STATUS_COLOR_ACTIVE)),
+        ]));
+    } else if let Some(idle) = state.device_idle_time(state.active_panel, &details.identifier) {
         lines.push(Line::from(vec![
-            Span::styled(platform_icon, Style::default().fg(theme.primary)),
-            Span::raw(" "),
+            Span::raw(device_usage::LAST_USED_LABEL),
             Span::styled(
-                details.name.replace('_', " "),
-                Style::default()
-                    .fg(theme.primary)
-                    .add_modifier(Modifier::BOLD),
+                device_usage::LAST_USED_AGO.replace("{}", &format_age(idle)),
+                Style::default().fg(UI_COLOR_TEXT_DIM),
             ),
         ]));
+    }
 
-        lines.push(Line::from(vec![Span::styled(
-            "━".repeat(SEPARATOR_LENGTH as usize),
-            Style::default().fg(UI_COLOR_TEXT_DIM),
-        )]));
+    if details.platform == Platform::Ios
+        && state
+            .ios_devices
+            .iter()
+            .any(|device| device.udid == details.identifier && device.is_running)
+    {
+        let window = if state.is_ios_device_headless(&details.identifier) {
+            simulator_window::HEADLESS
+        } else {
+            simulator_window::WINDOWED
+        };
+        lines.push(Line::from(vec![
+            Span::raw(simulator_window::WINDOW_LABEL),
+            Span::styled(window, Style::default().fg(STATUS_COLOR_INFO)),
+        ]));
+    }
 
-        let (status_icon, status_color) =
-            if details.status == "Running" || details.status == "Booted" {
-                ("●", STATUS_COLOR_ACTIVE)
-            } else {
-                ("○", STATUS_COLOR_INACTIVE)
-            };
+    let device = state.active_panel.device_id(details.identifier.clone());
+    if let Some(capture) = state.network_capture(&device) {
         lines.push(Line::from(vec![
-            Span::styled(status_icon, Style::default().fg(status_color)),
-            Span::raw(" "),
+            Span::raw(network_capture::CAPTURE_LABEL),
+            Span::styled(
+                format_bytes(capture.bytes),
+                Style::default().fg(STATUS_COLOR_ACTIVE),
+            ),
             Span::styled(
-                &details.status,
-                Style::default()
-                    .fg(status_color)
-                    .add_modifier(Modifier::BOLD),
+                format!(" → {}", capture.path.display()),
+                Style::default().fg(UI_COLOR_TEXT_DIM),
             ),
         ]));
+    }
 
-        lines.push(Line::from(""));
-
-        if let Some(ref resolution) = details.resolution {
-            let dpi_info = details
-                .dpi
-                .as_ref()
-                .map(|dpi| format!(" ({dpi})"))
-                .unwrap_or_default();
+    if let Some(annotation) = state.device_annotation(state.active_panel, &details.identifier) {
+        if !annotation.tags.is_empty() {
             lines.push(Line::from(vec![
-                Span::raw("📱 Display: "),
+                Span::raw(annotations::TAGS_LABEL),
                 Span::styled(
-                    format!("{resolution}{dpi_info}"),
-                    Style::default().fg(STATUS_COLOR_WARNING),
+                    annotation.tags.join(", "),
+                    Style::default().fg(STATUS_COLOR_INFO),
                 ),
             ]));
         }
-
-        if let Some(ref ram) = details.ram_size {
+        if !annotation.note.is_empty() {
             lines.push(Line::from(vec![
-                Span::raw("🧠 RAM: "),
-                Span::styled(ram.clone(), Style::default().fg(STATUS_COLOR_DEBUG)),
+                Span::raw(annotations::NOTE_LABEL),
+                Span::raw(annotation.note.clone()),
             ]));
         }
+    }
 
-        if let Some(ref storage) = details.storage_size {
-            lines.push(Line::from(vec![
-                Span::raw("💾 Storage: "),
-                Span::styled(storage.clone(), Style::default().fg(STATUS_COLOR_DEBUG)),
-            ]));
-        }
-
-        if details.platform == Platform::Android {
-            if let Some(ref sys_img) = details.system_image {
-                let architecture = if sys_img.contains("arm64") {
-                    ARM64
-                } else if sys_img.contains("x86_64") {
-                    X86_64
-                } else if sys_img.contains("x86") {
-                    X86
-                } else {
-                    UNKNOWN
-                };
-                lines.push(Line::from(vec![
-                    Span::raw("🔧 Arch: "),
-                    Span::styled(architecture, Style::default().fg(LOG_COLOR_VERBOSE)),
-                ]));
-            }
-        }
-
-        lines.push(Line::from(""));
+    lines.push(Line::from(""));
 
+    if let Some(ref resolution) = details.resolution {
+        let dpi_info = details
+            .dpi
+            .as_ref()
+            .map(|dpi| format!(" ({dpi})"))
+            .unwrap_or_default();
         lines.push(Line::from(vec![
-            Span::raw("📋 Version: "),
+            Span::raw("📱 Display: "),
             Span::styled(
-                details.api_level_or_version,
-                Style::default().fg(theme.primary),
+                format!("{resolution}{dpi_info}"),
+                Style::default().fg(STATUS_COLOR_WARNING),
             ),
         ]));
+    }
 
+    if let Some(ref ram) = details.ram_size {
         lines.push(Line::from(vec![
-            Span::raw("🏷️  Type: "),
-            Span::raw(details.device_type),
+            Span::raw("🧠 RAM: "),
+            Span::styled(ram.clone(), Style::default().fg(STATUS_COLOR_DEBUG)),
         ]));
+    }
 
-        lines.push(Line::from(""));
+    if let Some(ref storage) = details.storage_size {
         lines.push(Line::from(vec![
-            Span::raw("🆔 ID: "),
-            Span::styled(
-                details.identifier.clone(),
-                Style::default().fg(STATUS_COLOR_INFO),
-            ),
+            Span::raw("💾 Storage: "),
+            Span::styled(storage.clone(), Style::default().fg(STATUS_COLOR_DEBUG)),
         ]));
+    }
 
-        if let Some(ref path) = details.device_path {
-            lines.push(Line::from(""));
+    if details.platform == Platform::Android {
+        if let Some(ref sys_img) = details.system_image {
+            let architecture = if sys_img.contains("arm64") {
+                ARM64
+            } else if sys_img.contains("x86_64") {
+                X86_64
+            } else if sys_img.contains("x86") {
+                X86
+            } else {
+                UNKNOWN
+            };
             lines.push(Line::from(vec![
-                Span::raw("📂 "),
-                Span::styled(path.clone(), Style::default().fg(UI_COLOR_TEXT_DIM)),
+                Span::raw("🔧 Arch: "),
+                Span::styled(architecture, Style::default().fg(LOG_COLOR_VERBOSE)),
             ]));
         }
+    }
 
-        let paragraph = Paragraph::new(lines)
-            .block(
-                Block::default()
-                    .title("Device Details")
-                    .borders(Borders::ALL)
-                    .border_style(border_style),
-            )
-            .wrap(Wrap { trim: true });
+    lines.push(Line::from(""));
 
-        frame.render_widget(paragraph, area);
+    lines.push(Line::from(vec![
+        Span::raw("📋 Version: "),
+        Span::styled(
+            details.api_level_or_version,
+            Style::default().fg(theme.primary),
+        ),
+    ]));
 
-        let is_loading = details.platform == Platform::Android && details.device_path.is_none();
-        if is_loading {
-            render_loading_indicator(frame, area);
-        }
-    } else {
-        let no_device_text = Paragraph::new("No device selected")
-            .block(
-                Block::default()
-                    .title("Device Details")
-                    .borders(Borders::ALL)
-                    .border_style(border_style),
-            )
-            .style(Style::default().fg(UI_COLOR_TEXT_DIM))
-            .alignment(Alignment::Center);
+    lines.push(Line::from(vec![
+        Span::raw("🏷️  Type: "),
+        Span::raw(details.device_type),
+    ]));
 
-        frame.render_widget(no_device_text, area);
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::raw("🆔 ID: "),
+        Span::styled(
+            details.identifier.clone(),
+            Style::default().fg(STATUS_COLOR_INFO),
+        ),
+    ]));
+
+    if let Some(ref path) = details.device_path {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw("📂 "),
+            Span::styled(path.clone(), Style::default().fg(UI_COLOR_TEXT_DIM)),
+        ]));
     }
+
+    if let Some(ref command) = state.shown_command {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw("💻 "),
+            Span::styled(command.clone(), Style::default().fg(theme.primary)),
+        ]));
+    }
+
+    lines
 }
 
 fn render_loading_indicator(frame: &mut Frame, area: Rect) {
     let moon_icon = get_animated_moon();
     let loading_text = format!("{moon_icon} {LOADING}");
-    let loading_width = "🌙 Loading".len() as u16;
+    let loading_width = display_width(&loading_text) as u16;
     let loading_area = Rect::new(
         area.x
             + area
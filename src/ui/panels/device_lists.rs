@@ -1,16 +1,30 @@
 use crate::{
-    app::{AppState, FocusedPanel, Panel},
+    app::{AppState, FocusedPanel, IosToolingStatus, Panel},
     constants::{
         colors::*,
+        limits::INVALID_API_LEVEL,
+        messages::formats::{API_LEVEL, SIZE_MB},
+        ui_layout::{DEVICE_COLUMN_NAME_WIDTH, MIN_DEVICE_NAME_DISPLAY_WIDTH},
         ui_text::{
-            device_states::IOS_UNAVAILABLE, navigation::*, status_indicators::*, text_formatting::*,
+            device_activity::{BOOTING, CAPTURING},
+            device_states::{
+                EPHEMERAL_BADGE, IOS_UNAVAILABLE, IOS_UNAVAILABLE_REASON, WEDGED_BADGE,
+            },
+            device_usage::{ROW_LAST_USED, ROW_UPTIME},
+            ios_tooling,
+            navigation::*,
+            status_indicators::*,
+            text_formatting::*,
         },
     },
-    ui::Theme,
+    models::{DeviceColumn, DeviceListColumns},
+    ui::{render::format_age, widgets::get_animated_moon, Theme},
+    utils::text::{display_width, pad_to_width, truncate_to_width},
 };
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem},
     Frame,
 };
@@ -44,20 +58,33 @@ pub(crate) fn render_android_panel(
         .take(available_height)
         .collect();
 
+    let rows: Vec<_> = visible_devices
+        .iter()
+        .map(|(_, device)| DeviceRowCells {
+            status: status_cell(device.is_running, state.is_android_device_pinned(device)),
+            name: device.name.replace(UNDERSCORE_STR, SPACE_STR_SINGLE),
+            badges: tag_badges(state, Panel::Android, &device.name),
+            version: if device.api_level > INVALID_API_LEVEL {
+                API_LEVEL.replace("{}", &device.api_level.to_string())
+            } else {
+                String::new()
+            },
+            abi: device.abi.clone(),
+            ram: if device.ram_size.is_empty() {
+                String::new()
+            } else {
+                SIZE_MB.replace("{}", &device.ram_size)
+            },
+            uptime: usage_cell(state, Panel::Android, &device.name),
+        })
+        .collect();
+    let columns = fit_columns(&state.device_list_columns(), &rows, area.width);
+
     let items: Vec<ListItem> = visible_devices
         .into_iter()
-        .map(|(i, device)| {
+        .zip(rows)
+        .map(|((i, device), row)| {
             let selected = i == state.selected_android && is_active;
-            let status_indicator = if device.is_running {
-                ACTIVE_INDICATOR
-            } else {
-                INACTIVE_INDICATOR
-            };
-            let text = format!(
-                "{status_indicator} {}",
-                device.name.replace(UNDERSCORE_STR, SPACE_STR_SINGLE)
-            );
-
             let style = if selected {
                 Style::default().bg(theme.primary).fg(UI_COLOR_BACKGROUND)
             } else if device.is_running {
@@ -66,7 +93,7 @@ pub(crate) fn render_android_panel(
                 Style::default().fg(theme.text)
             };
 
-            ListItem::new(text).style(style)
+            ListItem::new(row.into_line(&columns)).style(style)
         })
         .collect();
 
@@ -120,22 +147,32 @@ pub(crate) fn render_ios_panel(frame: &mut Frame, area: Rect, state: &mut AppSta
         .take(available_height)
         .collect();
 
+    let rows: Vec<_> = visible_devices
+        .iter()
+        .map(|(_, device)| {
+            let availability = match device.unavailable_reason() {
+                None => String::new(),
+                Some(reason) if reason.label().is_empty() => IOS_UNAVAILABLE.to_string(),
+                Some(reason) => IOS_UNAVAILABLE_REASON.replace("{}", reason.label()),
+            };
+            DeviceRowCells {
+                status: status_cell(device.is_running, state.is_ios_device_pinned(device)),
+                name: format!("{}{availability}", device.name),
+                badges: tag_badges(state, Panel::Ios, &device.udid),
+                version: device.runtime_version.clone(),
+                abi: String::new(),
+                ram: String::new(),
+                uptime: usage_cell(state, Panel::Ios, &device.udid),
+            }
+        })
+        .collect();
+    let columns = fit_columns(&state.device_list_columns(), &rows, area.width);
+
     let items: Vec<ListItem> = visible_devices
         .into_iter()
-        .map(|(i, device)| {
+        .zip(rows)
+        .map(|((i, device), row)| {
             let selected = i == state.selected_ios && is_active;
-            let status_indicator = if device.is_running {
-                ACTIVE_INDICATOR
-            } else {
-                INACTIVE_INDICATOR
-            };
-            let availability = if device.is_available {
-                ""
-            } else {
-                IOS_UNAVAILABLE
-            };
-            let text = format!("{status_indicator} {}{availability}", device.name);
-
             let style = if selected {
                 Style::default().bg(theme.primary).fg(UI_COLOR_BACKGROUND)
             } else if device.is_running {
@@ -146,21 +183,33 @@ pub(crate) fn render_ios_panel(frame: &mut Frame, area: Rect, state: &mut AppSta
                 Style::default().fg(theme.text)
             };
 
-            ListItem::new(text).style(style)
+            ListItem::new(row.into_line(&columns)).style(style)
         })
         .collect();
 
-    let title = if cfg!(target_os = "macos") {
-        build_panel_title(
-            "🍎 iOS",
-            is_active,
-            total_devices,
-            available_height,
-            scroll_offset,
-            state.selected_ios,
-        )
-    } else {
+    let title = if !cfg!(target_os = "macos") {
         "🍎 iOS (macOS only)".to_string()
+    } else {
+        match &state.ios_tooling {
+            IosToolingStatus::Detecting => ios_tooling::DETECTING_TITLE.to_string(),
+            IosToolingStatus::Unavailable(_) => ios_tooling::UNAVAILABLE_TITLE.to_string(),
+            IosToolingStatus::Ready => build_panel_title(
+                "🍎 iOS",
+                is_active,
+                total_devices,
+                available_height,
+                scroll_offset,
+                state.selected_ios,
+            ),
+        }
+    };
+
+    // Without devices, the panel explains why the tooling is unavailable
+    let items = match &state.ios_tooling {
+        IosToolingStatus::Unavailable(reason) if items.is_empty() => {
+            vec![ListItem::new(reason.clone()).style(Style::default().fg(UI_COLOR_TEXT_DIM))]
+        }
+        _ => items,
     };
 
     let block_style = if is_active {
@@ -180,6 +229,209 @@ pub(crate) fn render_ios_panel(frame: &mut Frame, area: Rect, state: &mut AppSta
     frame.render_widget(list, area);
 }
 
+/// The cells of one device row, before they are fitted to the panel.
+struct DeviceRowCells {
+    status: String,
+    name: String,
+    badges: Vec<Span<'static>>,
+    version: String,
+    abi: String,
+    ram: String,
+    uptime: String,
+}
+
+impl DeviceRowCells {
+    fn text(&self, column: DeviceColumn) -> &str {
+        match column {
+            DeviceColumn::Status => &self.status,
+            DeviceColumn::Name => &self.name,
+            DeviceColumn::Version => &self.version,
+            DeviceColumn::Abi => &self.abi,
+            DeviceColumn::Ram => &self.ram,
+            DeviceColumn::Uptime => &self.uptime,
+        }
+    }
+
+    fn width(&self, column: DeviceColumn) -> usize {
+        let badges = match column {
+            DeviceColumn::Name => self.badges.iter().map(Span::width).sum(),
+            _ => 0,
+        };
+        display_width(self.text(column)) + badges
+    }
+
+    /// Lays the cells out in `columns`. The name is truncated by display
+    /// width so the tag badges stay visible, and every other cell is padded
+    /// or cut to its column.
+    fn into_line(self, columns: &[(DeviceColumn, usize)]) -> Line<'static> {
+        let mut spans = Vec::new();
+        let mut previous = None;
+        for &(column, width) in columns {
+            if needs_gap(previous) {
+                spans.push(Span::raw(SPACE_STR_SINGLE));
+            }
+            previous = Some(column);
+            if column != DeviceColumn::Name {
+                spans.push(Span::raw(pad_to_width(self.text(column), width)));
+                continue;
+            }
+            let badges_width: usize = self.badges.iter().map(Span::width).sum();
+            let name_width = width
+                .saturating_sub(badges_width)
+                .max(MIN_DEVICE_NAME_DISPLAY_WIDTH.min(width));
+            let name = truncate_to_width(&self.name, name_width);
+            let padding = width.saturating_sub(display_width(&name) + badges_width);
+            spans.push(Span::raw(name));
+            spans.extend(self.badges.iter().cloned());
+            spans.push(Span::raw(" ".repeat(padding)));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Cells are separated by a space, except after the status cell, which
+/// ends in one.
+fn needs_gap(previous: Option<DeviceColumn>) -> bool {
+    previous.is_some_and(|column| column != DeviceColumn::Status)
+}
+
+/// Widths of the columns shown in a panel `panel_width` cells wide. Each
+/// column is as wide as its widest cell, up to its maximum, and columns
+/// without content are left out. When the name would be cut short, the
+/// optional columns are dropped from the end until it fits; the name column
+/// then fills the rest of the row so the other columns line up.
+fn fit_columns(
+    config: &DeviceListColumns,
+    rows: &[DeviceRowCells],
+    panel_width: u16,
+) -> Vec<(DeviceColumn, usize)> {
+    let row_width = usize::from(panel_width.saturating_sub(2));
+    let content_width = |column| rows.iter().map(|row| row.width(column)).max().unwrap_or(0);
+    let mut columns: Vec<(DeviceColumn, usize)> = config
+        .visible()
+        .into_iter()
+        .filter_map(|column| match config.max_width(column) {
+            None => Some((column, 0)),
+            Some(max_width) => {
+                let width = content_width(column).min(max_width);
+                (width > 0).then_some((column, width))
+            }
+        })
+        .collect();
+
+    let name_target = content_width(DeviceColumn::Name)
+        .clamp(MIN_DEVICE_NAME_DISPLAY_WIDTH, DEVICE_COLUMN_NAME_WIDTH);
+    let fixed_width = |columns: &[(DeviceColumn, usize)]| {
+        let mut previous = None;
+        let mut width = 0;
+        for &(column, column_width) in columns {
+            width += column_width + usize::from(needs_gap(previous));
+            previous = Some(column);
+        }
+        width
+    };
+    while fixed_width(&columns) + name_target > row_width {
+        let Some(optional) = columns
+            .iter()
+            .rposition(|(column, _)| !matches!(column, DeviceColumn::Status | DeviceColumn::Name))
+        else {
+            break;
+        };
+        columns.remove(optional);
+    }
+
+    let name_width = row_width
+        .saturating_sub(fixed_width(&columns))
+        .max(MIN_DEVICE_NAME_DISPLAY_WIDTH);
+    for (column, width) in &mut columns {
+        if *column == DeviceColumn::Name {
+            *width = name_width;
+        }
+    }
+    columns
+}
+
+/// Running glyph, followed by the pin marker of a pinned device.
+fn status_cell(is_running: bool, pinned: bool) -> String {
+    let indicator = if is_running {
+        ACTIVE_INDICATOR
+    } else {
+        INACTIVE_INDICATOR
+    };
+    let pinned = if pinned { PINNED_INDICATOR } else { "" };
+    format!("{indicator} {pinned}")
+}
+
+/// Uptime for running devices, time since last use for stopped ones.
+fn usage_cell(state: &AppState, panel: Panel, identifier: &str) -> String {
+    if let Some(uptime) = state.device_uptime(panel, identifier) {
+        ROW_UPTIME.replace("{}", &format_age(uptime))
+    } else if let Some(idle) = state.device_idle_time(panel, identifier) {
+        ROW_LAST_USED.replace("{}", &format_age(idle))
+    } else {
+        String::new()
+    }
+}
+
+/// Colored badges for the device's tags, after what runs against the
+/// device and a red one for a wedged device. Each tag keeps the same color.
+fn tag_badges(state: &AppState, panel: Panel, identifier: &str) -> Vec<Span<'static>> {
+    let mut badges = Vec::new();
+    let activity = state.device_activity(panel, identifier).or_else(|| {
+        state
+            .is_device_booting(panel, identifier)
+            .then_some(BOOTING)
+    });
+    let capturing = state
+        .network_capture(&panel.device_id(identifier))
+        .is_some();
+    if let Some(activity) = activity {
+        badges.push(Span::styled(
+            format!(" {} {activity}", get_animated_moon()),
+            Style::default().fg(STATUS_COLOR_WARNING),
+        ));
+    } else if capturing {
+        badges.push(Span::styled(
+            format!(" {CAPTURING}"),
+            Style::default().fg(STATUS_COLOR_WARNING),
+        ));
+    }
+    if state.is_device_wedged(panel, identifier) {
+        badges.push(Span::raw(SPACE_STR_SINGLE));
+        badges.push(Span::styled(
+            WEDGED_BADGE,
+            Style::default()
+                .fg(UI_COLOR_BACKGROUND)
+                .bg(STATUS_COLOR_ERROR),
+        ));
+    }
+    if state.is_ephemeral_device(panel, identifier) {
+        badges.push(Span::raw(SPACE_STR_SINGLE));
+        badges.push(Span::styled(
+            EPHEMERAL_BADGE,
+            Style::default()
+                .fg(UI_COLOR_BACKGROUND)
+                .bg(STATUS_COLOR_INFO),
+        ));
+    }
+    let Some(annotation) = state.device_annotation(panel, identifier) else {
+        return badges;
+    };
+    badges.extend(annotation.tags.iter().flat_map(|tag| {
+        let color_index = tag.bytes().map(usize::from).sum::<usize>() % TAG_BADGE_COLORS.len();
+        [
+            Span::raw(SPACE_STR_SINGLE),
+            Span::styled(
+                format!(" {tag} "),
+                Style::default()
+                    .fg(UI_COLOR_BACKGROUND)
+                    .bg(TAG_BADGE_COLORS[color_index]),
+            ),
+        ]
+    }));
+    badges
+}
+
 fn build_panel_title(
     title_prefix: &str,
     is_active: bool,
@@ -208,3 +460,63 @@ fn build_panel_title(
         format!("{title_prefix} ({total_devices})")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, abi: &str) -> DeviceRowCells {
+        DeviceRowCells {
+            status: status_cell(true, false),
+            name: name.to_string(),
+            badges: Vec::new(),
+            version: "API 34".to_string(),
+            abi: abi.to_string(),
+            ram: "2048 MB".to_string(),
+            uptime: "up 5m".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fit_columns_aligns_and_drops_columns_that_do_not_fit() {
+        let rows = vec![row("Pixel 7", "arm64-v8a"), row("Tablet", "")];
+        let columns = fit_columns(&DeviceListColumns::default(), &rows, 60);
+        assert_eq!(
+            columns,
+            [
+                (DeviceColumn::Status, 2),
+                (DeviceColumn::Name, 25),
+                (DeviceColumn::Version, 6),
+                (DeviceColumn::Abi, 9),
+                (DeviceColumn::Ram, 7),
+                (DeviceColumn::Uptime, 5),
+            ]
+        );
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.into_line(&columns).to_string())
+            .collect();
+        assert_eq!(lines[0].find("API 34"), lines[1].find("API 34"));
+
+        // Narrow panels keep the name readable and drop columns from the end
+        let rows = vec![row("Pixel 7 API 34", "arm64-v8a")];
+        let columns = fit_columns(&DeviceListColumns::default(), &rows, 34);
+        assert_eq!(
+            columns
+                .iter()
+                .map(|(column, _)| *column)
+                .collect::<Vec<_>>(),
+            [
+                DeviceColumn::Status,
+                DeviceColumn::Name,
+                DeviceColumn::Version
+            ]
+        );
+
+        // Hidden columns and empty ones are left out
+        let config: DeviceListColumns =
+            toml::from_str("show = [\"name\", \"ram\", \"abi\"]").unwrap();
+        let columns = fit_columns(&config, &[row("Pixel", "")], 60);
+        assert_eq!(columns, [(DeviceColumn::Name, 50), (DeviceColumn::Ram, 7)]);
+    }
+}
@@ -2,9 +2,10 @@ use crate::{
     app::{AppState, Panel},
     constants::{
         colors::*,
-        ui_layout::{LOG_LEVEL_WIDTH, LOG_TIMESTAMP_WIDTH, MESSAGE_TRUNCATE_SUFFIX_LENGTH},
+        ui_layout::{LOG_LEVEL_WIDTH, LOG_TIMESTAMP_WIDTH},
     },
     ui::Theme,
+    utils::text::{display_width, pad_to_width, truncate_to_width},
 };
 use ratatui::{
     layout::Rect,
@@ -70,26 +71,12 @@ pub(crate) fn render_log_panel(frame: &mut Frame, area: Rect, state: &AppState,
     let log_lines: Vec<Line> = visible_logs
         .into_iter()
         .map(|entry| {
-            let message = if entry.message.chars().count() > message_width
-                && message_width > MESSAGE_TRUNCATE_SUFFIX_LENGTH
-            {
-                let truncate_len = message_width.saturating_sub(MESSAGE_TRUNCATE_SUFFIX_LENGTH);
-                let truncated: String = entry.message.chars().take(truncate_len).collect();
-                format!("{truncated}...")
-            } else {
-                entry.message.clone()
-            };
-
-            let used_width = entry.timestamp.chars().count()
-                + 1
-                + entry.level.chars().count()
-                + 3
-                + message.chars().count();
-            let padding = if used_width < available_width {
-                " ".repeat(available_width - used_width)
-            } else {
-                String::new()
-            };
+            // Pad the message so each line clears what was drawn before it
+            let used_width = display_width(&entry.timestamp) + 1 + display_width(&entry.level) + 3;
+            let message = pad_to_width(
+                &truncate_to_width(&entry.message, message_width),
+                available_width.saturating_sub(used_width),
+            );
 
             Line::from(vec![
                 Span::styled(
@@ -103,7 +90,6 @@ pub(crate) fn render_log_panel(frame: &mut Frame, area: Rect, state: &AppState,
                 ),
                 Span::raw(" "),
                 Span::raw(message),
-                Span::raw(padding),
             ])
         })
         .collect();
@@ -126,6 +112,19 @@ pub(crate) fn render_log_panel(frame: &mut Frame, area: Rect, state: &AppState,
     frame.render_widget(logs, area);
 }
 
+/// Zen log mode: the newest filtered log lines as plain text filling the
+/// whole area, without borders, title, colors, or truncation markers.
+pub(crate) fn render_zen_log_panel(frame: &mut Frame, area: Rect, state: &AppState) {
+    let filtered_logs = state.get_filtered_logs();
+    let start_idx = filtered_logs.len().saturating_sub(area.height as usize);
+    let lines: Vec<Line> = filtered_logs[start_idx..]
+        .iter()
+        .map(|entry| Line::raw(entry.plain_line()))
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
 fn filter_style(filter: &str, theme: &Theme) -> Style {
     match filter {
         "ERROR" => Style::default()
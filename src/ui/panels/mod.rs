@@ -10,4 +10,4 @@ pub(crate) use commands::{
 };
 pub(crate) use details::render_device_details_panel;
 pub(crate) use device_lists::{render_android_panel, render_ios_panel};
-pub(crate) use logs::render_log_panel;
+pub(crate) use logs::{render_log_panel, render_zen_log_panel};
@@ -1,32 +1,45 @@
 //! UI rendering
 
 use super::dialogs::{
-    render_api_level_dialog, render_confirm_delete_dialog, render_confirm_wipe_dialog,
-    render_create_device_dialog, render_notifications,
+    render_annotation_dialog, render_api_level_dialog, render_app_data_dialog,
+    render_command_history_dialog, render_confirm_delete_dialog, render_confirm_wipe_dialog,
+    render_create_device_dialog, render_creation_queue_dialog, render_developer_toggles_dialog,
+    render_device_catalog_dialog, render_device_clock_dialog, render_diagnostics_dialog,
+    render_intent_dialog, render_launch_app_dialog, render_launch_diagnosis_dialog,
+    render_monkey_dialog, render_move_device_data_dialog, render_notifications,
+    render_process_inspector_dialog, render_prune_unavailable_dialog, render_quick_boot_dialog,
+    render_radios_dialog, render_repair_device_dialog, render_search_prompt,
+    render_snippets_dialog, render_system_properties_dialog, render_test_matrix_dialog,
+    render_trace_dialog, render_type_into_device_dialog,
 };
 use super::panels::{
     device_commands_height, log_commands_height, render_android_panel, render_device_commands,
     render_device_details_panel, render_ios_panel, render_log_commands, render_log_panel,
+    render_zen_log_panel,
 };
 use crate::{
     app::AppState,
     constants::{
         colors::*,
         messages::ui::TERMINAL_TOO_SMALL_ERROR,
-        ui_layout::{
-            ANDROID_PANEL_PERCENTAGE, DEVICE_DETAILS_PANEL_PERCENTAGE, DEVICE_PANELS_PERCENTAGE,
-            HEADER_HEIGHT, IOS_PANEL_PERCENTAGE, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH,
-            STATUS_BAR_HEIGHT,
+        ui_layout::{HEADER_HEIGHT, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH, STATUS_BAR_HEIGHT},
+        ui_text::refresh_status::{CACHED, PAUSED, REFRESHED_AGO, REFRESHING},
+    },
+    ui::{
+        widgets::{
+            get_animated_moon, perf_overlay::render_perf_overlay, status_bar::render_status_bar,
         },
+        Theme,
     },
-    ui::Theme,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
+    text::Line,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use std::time::Duration;
 
 pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
     let size = frame.area();
@@ -36,6 +49,14 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
         let msg =
             Paragraph::new(TERMINAL_TOO_SMALL_ERROR).style(Style::default().fg(STATUS_COLOR_ERROR));
         frame.render_widget(msg, size);
+        theme.finish_frame(frame.buffer_mut());
+        return;
+    }
+
+    if state.zen_logs {
+        render_zen_log_panel(frame, size, state);
+        render_overlays(frame, state, theme);
+        theme.finish_frame(frame.buffer_mut());
         return;
     }
 
@@ -50,16 +71,28 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
 
     // Header with icon and version
     let version = env!("CARGO_PKG_VERSION");
-    let header_text = if state.fullscreen_logs {
+    let mut header_text = if state.fullscreen_logs {
         format!(" 🦤 Emu v{version} - Device Manager [FULLSCREEN LOGS]")
+    } else if state.fullscreen_devices {
+        format!(" 🦤 Emu v{version} - Device Manager [DEVICES ONLY]")
     } else {
         format!(" 🦤 Emu v{version} - Device Manager")
     };
+    if state.read_only {
+        header_text.push_str(" [READ-ONLY]");
+    }
+    let header_block = Block::default().borders(Borders::ALL);
+    let header_inner = header_block.inner(chunks[0]);
     let header = Paragraph::new(header_text)
-        .block(Block::default().borders(Borders::ALL))
+        .block(header_block)
         .style(Style::default().fg(theme.primary));
     frame.render_widget(header, chunks[0]);
 
+    let refresh_status = Paragraph::new(format!("{} ", format_refresh_status(state)))
+        .style(Style::default().fg(UI_COLOR_TEXT_DIM))
+        .alignment(Alignment::Right);
+    frame.render_widget(refresh_status, header_inner);
+
     let log_shortcut_height = log_commands_height(state, chunks[1].width);
 
     // Split main content based on fullscreen mode
@@ -72,14 +105,20 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
                 Constraint::Length(log_shortcut_height), // Log commands
             ])
             .split(chunks[1])
+    } else if state.fullscreen_devices {
+        // Device lists take all space; details and logs are hidden
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10)])
+            .split(chunks[1])
     } else {
         // Normal mode
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Percentage(DEVICE_PANELS_PERCENTAGE), // Device panels with device commands
-                Constraint::Min(10),                              // Log panel
-                Constraint::Length(log_shortcut_height),          // Log commands
+                Constraint::Percentage(state.panel_layout.device_panels_percentage), // Device panels with device commands
+                Constraint::Min(10),                                                 // Log panel
+                Constraint::Length(log_shortcut_height),                             // Log commands
             ])
             .split(chunks[1])
     };
@@ -98,12 +137,17 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
             .split(main_chunks[0]);
 
         // Device panels (Android | iOS | Details - 3 columns)
+        let (android_percentage, ios_percentage, details_percentage) = if state.fullscreen_devices {
+            (50, 50, 0)
+        } else {
+            state.panel_layout.column_percentages()
+        };
         let device_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Percentage(ANDROID_PANEL_PERCENTAGE), // Android
-                Constraint::Percentage(IOS_PANEL_PERCENTAGE),     // iOS
-                Constraint::Percentage(DEVICE_DETAILS_PANEL_PERCENTAGE), // Device Details
+                Constraint::Percentage(android_percentage), // Android
+                Constraint::Percentage(ios_percentage),     // iOS
+                Constraint::Percentage(details_percentage), // Device Details
             ])
             .split(device_area_chunks[0]);
 
@@ -113,32 +157,43 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
         // iOS panel
         render_ios_panel(frame, device_chunks[1], state, theme);
 
-        // Device details panel
-        render_device_details_panel(frame, device_chunks[2], state, theme);
-
         // Device commands (no border, smaller, centered)
         render_device_commands(frame, device_area_chunks[1], state, theme);
 
-        // Log panel
-        let log_index = 1;
-        render_log_panel(frame, main_chunks[log_index], state, theme);
+        if !state.fullscreen_devices {
+            // Device details panel
+            render_device_details_panel(frame, device_chunks[2], state, theme);
+
+            // Log panel
+            let log_index = 1;
+            render_log_panel(frame, main_chunks[log_index], state, theme);
 
-        // Log commands
-        render_log_commands(frame, main_chunks[log_index + 1], state, theme);
+            // Log commands
+            render_log_commands(frame, main_chunks[log_index + 1], state, theme);
+        }
     } else {
         // Fullscreen logs mode
         render_log_panel(frame, main_chunks[0], state, theme);
         render_log_commands(frame, main_chunks[1], state, theme);
     }
 
-    // Global status bar (now only shows app-level commands)
+    // Global status bar: session summary on the left, app-level commands on the right
     let status_text = match state.mode {
         crate::app::Mode::Normal => "[q/Ctrl+q]:Quit",
         crate::app::Mode::CreateDevice => {
-            "[Tab]next field [Shift+Tab]prev field [Enter]submit [Esc]cancel"
+            "[Tab]next field [Shift+Tab]prev field [Enter]submit [Ctrl+a]queue [Ctrl+y]copy command [Esc]cancel"
         }
         crate::app::Mode::ConfirmDelete => "[q/Ctrl+q]:Quit",
         crate::app::Mode::ConfirmWipe => "[q/Ctrl+q]:Quit",
+        crate::app::Mode::EditAnnotation
+        | crate::app::Mode::Search
+        | crate::app::Mode::SendIntent
+        | crate::app::Mode::SystemProperties
+        | crate::app::Mode::RunMonkey
+        | crate::app::Mode::LaunchApp
+        | crate::app::Mode::CaptureTrace
+        | crate::app::Mode::DeviceClock
+        | crate::app::Mode::TypeIntoDevice => "[Ctrl+q]:Quit",
         _ => "[q/Ctrl+q]:Quit",
     };
 
@@ -150,6 +205,13 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
         crate::app::Mode::ConfirmWipe => status_text.to_string(),
         _ => status_text.to_string(),
     };
+    let status_width = Line::from(status_with_icon.as_str()).width() as u16 + 1;
+    let status_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(status_width)])
+        .split(chunks[2]);
+    render_status_bar(frame, status_chunks[0], state, theme);
+
     let status = Paragraph::new(status_with_icon)
         .style(
             Style::default()
@@ -157,8 +219,14 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
                 .add_modifier(Modifier::DIM),
         )
         .alignment(Alignment::Right);
-    frame.render_widget(status, chunks[2]);
+    frame.render_widget(status, status_chunks[1]);
+
+    render_overlays(frame, state, theme);
+    theme.finish_frame(frame.buffer_mut());
+}
 
+/// Modal dialogs and notifications, drawn on top of every display mode.
+fn render_overlays(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
     // Render modal dialogs on top
     match state.mode {
         crate::app::Mode::CreateDevice => {
@@ -170,12 +238,153 @@ pub fn draw_app(frame: &mut Frame, state: &mut AppState, theme: &Theme) {
         crate::app::Mode::ConfirmWipe => {
             render_confirm_wipe_dialog(frame, state, theme);
         }
+        crate::app::Mode::RepairDevice => {
+            render_repair_device_dialog(frame, state, theme);
+        }
+        crate::app::Mode::PruneUnavailable => {
+            render_prune_unavailable_dialog(frame, state, theme);
+        }
         crate::app::Mode::ManageApiLevels => {
             render_api_level_dialog(frame, state, theme);
         }
+        crate::app::Mode::CommandHistory => {
+            render_command_history_dialog(frame, state, theme);
+        }
+        crate::app::Mode::Diagnostics => {
+            render_diagnostics_dialog(frame, state, theme);
+        }
+        crate::app::Mode::CreationQueue => {
+            render_creation_queue_dialog(frame, state, theme);
+        }
+        crate::app::Mode::EditAnnotation => {
+            render_annotation_dialog(frame, state, theme);
+        }
+        crate::app::Mode::Search => {
+            render_search_prompt(frame, state, theme);
+        }
+        crate::app::Mode::MoveDeviceData => {
+            render_move_device_data_dialog(frame, state, theme);
+        }
+        crate::app::Mode::SendIntent => {
+            render_intent_dialog(frame, state, theme);
+        }
+        crate::app::Mode::ProcessInspector => {
+            render_process_inspector_dialog(frame, state, theme);
+        }
+        crate::app::Mode::SystemProperties => {
+            render_system_properties_dialog(frame, state, theme);
+        }
+        crate::app::Mode::RunMonkey => {
+            render_monkey_dialog(frame, state, theme);
+        }
+        crate::app::Mode::LaunchDiagnosis => {
+            render_launch_diagnosis_dialog(frame, state, theme);
+        }
+        crate::app::Mode::QuickBoot => {
+            render_quick_boot_dialog(frame, state, theme);
+        }
+        crate::app::Mode::DeviceClock => {
+            render_device_clock_dialog(frame, state, theme);
+        }
+        crate::app::Mode::Radios => {
+            render_radios_dialog(frame, state, theme);
+        }
+        crate::app::Mode::DeviceCatalog => {
+            render_device_catalog_dialog(frame, state, theme);
+        }
+        crate::app::Mode::TestMatrix => {
+            render_test_matrix_dialog(frame, state, theme);
+        }
+        crate::app::Mode::Snippets => {
+            render_snippets_dialog(frame, state, theme);
+        }
+        crate::app::Mode::LaunchApp => {
+            render_launch_app_dialog(frame, state, theme);
+        }
+        crate::app::Mode::CaptureTrace => {
+            render_trace_dialog(frame, state, theme);
+        }
+        crate::app::Mode::DeveloperToggles => {
+            render_developer_toggles_dialog(frame, state, theme);
+        }
+        crate::app::Mode::AppData => {
+            render_app_data_dialog(frame, state, theme);
+        }
+        crate::app::Mode::TypeIntoDevice => {
+            render_type_into_device_dialog(frame, state, theme);
+        }
         _ => {}
     }
 
+    if state.show_perf_overlay {
+        render_perf_overlay(frame, theme);
+    }
+
     // Render notifications on top of everything
     render_notifications(frame, state, theme);
 }
+
+/// Header text describing the refresh state: a spinner while a manual
+/// refresh runs, otherwise the age of the device lists with cached and
+/// paused markers.
+fn format_refresh_status(state: &AppState) -> String {
+    if state.manual_refresh_pending {
+        return format!("{} {REFRESHING}", get_animated_moon());
+    }
+    if state.is_loading {
+        return String::new();
+    }
+
+    let mut age = REFRESHED_AGO.replace("{}", &format_age(state.refresh_age()));
+    if state.showing_cached_inventory {
+        age = format!("{CACHED} · {age}");
+    }
+    if state.auto_refresh_paused {
+        format!("{PAUSED} · {age}")
+    } else {
+        age
+    }
+}
+
+/// Formats a duration using its largest whole unit (e.g. "5m", "3d").
+pub(crate) fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=59 => format!("{secs}s"),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age_uses_largest_unit() {
+        assert_eq!(format_age(Duration::from_secs(0)), "0s");
+        assert_eq!(format_age(Duration::from_secs(59)), "59s");
+        assert_eq!(format_age(Duration::from_secs(150)), "2m");
+        assert_eq!(format_age(Duration::from_secs(7200)), "2h");
+        assert_eq!(format_age(Duration::from_secs(3 * 86400 + 60)), "3d");
+    }
+
+    #[test]
+    fn test_format_refresh_status() {
+        let mut state = AppState::new();
+        assert_eq!(format_refresh_status(&state), "");
+
+        state.is_loading = false;
+        assert_eq!(format_refresh_status(&state), "refreshed 0s ago");
+
+        state.showing_cached_inventory = true;
+        assert_eq!(format_refresh_status(&state), "cached · refreshed 0s ago");
+
+        state.auto_refresh_paused = true;
+        assert!(format_refresh_status(&state).starts_with(PAUSED));
+
+        state.request_manual_refresh();
+        assert!(format_refresh_status(&state).ends_with(REFRESHING));
+    }
+}
@@ -3,9 +3,48 @@
 //! This module provides color themes for the terminal interface, supporting
 //! both dark and light modes with carefully chosen colors for accessibility
 //! and visual clarity.
+//!
+//! Color-blind-friendly and monochrome themes are applied to the finished
+//! frame by [`Theme::finish_frame`], so every widget is covered, including the
+//! ones that use the color constants directly. Monochrome is picked
+//! automatically when the `NO_COLOR` or `CLICOLOR` conventions ask for it.
+
+use crate::constants::{
+    colors::*,
+    env_vars::{CLICOLOR, CLICOLOR_FORCE, NO_COLOR},
+};
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier, Style},
+};
+
+/// Built-in themes selectable with `--theme`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    /// Red-green safe palette (deuteranopia and protanopia)
+    Deuteranopia,
+    /// Blue-yellow safe palette
+    Tritanopia,
+    /// No colors; status is shown by symbols and text attributes only
+    Monochrome,
+}
 
-use crate::constants::colors::*;
-use ratatui::style::{Color, Modifier, Style};
+/// How the colors of a finished frame are adjusted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Colors are drawn as the widgets specify them
+    #[default]
+    Full,
+    /// Red and green are swapped for Okabe-Ito vermillion and sky blue
+    Deuteranopia,
+    /// Blue and cyan are swapped for Okabe-Ito reddish purple and orange
+    Tritanopia,
+    /// All colors are removed; highlighted cells are drawn reversed
+    Monochrome,
+}
 
 /// Theme configuration for the terminal user interface.
 ///
@@ -37,6 +76,8 @@ pub struct Theme {
     pub header: Style,
     /// Style for status information
     pub status: Style,
+    /// Adjustment applied to every finished frame
+    pub color_mode: ColorMode,
 }
 
 impl Theme {
@@ -60,6 +101,7 @@ impl Theme {
                 .fg(UI_COLOR_HIGHLIGHT)
                 .add_modifier(Modifier::BOLD),
             status: Style::default().fg(STATUS_COLOR_DEBUG),
+            color_mode: ColorMode::Full,
         }
     }
 
@@ -83,6 +125,89 @@ impl Theme {
                 .fg(STATUS_COLOR_INFO)
                 .add_modifier(Modifier::BOLD),
             status: Style::default().fg(UI_COLOR_TEXT_DIM),
+            color_mode: ColorMode::Full,
+        }
+    }
+
+    /// Creates a dark theme whose colors stay distinguishable with
+    /// red-green color blindness.
+    pub fn deuteranopia() -> Self {
+        Self::dark().with_color_mode(ColorMode::Deuteranopia)
+    }
+
+    /// Creates a dark theme whose colors stay distinguishable with
+    /// blue-yellow color blindness.
+    pub fn tritanopia() -> Self {
+        Self::dark().with_color_mode(ColorMode::Tritanopia)
+    }
+
+    /// Creates a theme without colors, relying on the status symbols and on
+    /// reversed text for selections.
+    pub fn monochrome() -> Self {
+        Self::dark().with_color_mode(ColorMode::Monochrome)
+    }
+
+    /// Creates the named theme.
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::Deuteranopia => Self::deuteranopia(),
+            ThemeName::Tritanopia => Self::tritanopia(),
+            ThemeName::Monochrome => Self::monochrome(),
+        }
+    }
+
+    /// Creates the named theme unless the environment disables colors, in
+    /// which case the monochrome theme is used instead.
+    pub fn from_env(name: ThemeName) -> Self {
+        let var = |key| std::env::var(key).ok();
+        if colors_disabled(
+            var(NO_COLOR).as_deref(),
+            var(CLICOLOR).as_deref(),
+            var(CLICOLOR_FORCE).as_deref(),
+        ) {
+            Self::monochrome()
+        } else {
+            Self::from_name(name)
+        }
+    }
+
+    fn with_color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+
+    /// Applies the theme's color mode to a fully rendered frame.
+    pub fn finish_frame(&self, buffer: &mut Buffer) {
+        if self.color_mode == ColorMode::Full {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            if self.color_mode == ColorMode::Monochrome {
+                if is_highlight_background(cell.bg) {
+                    cell.modifier.insert(Modifier::REVERSED);
+                }
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            } else {
+                cell.fg = self.remap_color(cell.fg);
+                cell.bg = self.remap_color(cell.bg);
+            }
+        }
+    }
+
+    fn remap_color(&self, color: Color) -> Color {
+        match (self.color_mode, color) {
+            (ColorMode::Deuteranopia, Color::Green | Color::LightGreen) => CB_SKY_BLUE,
+            (ColorMode::Deuteranopia, Color::Red | Color::LightRed) => CB_VERMILLION,
+            (ColorMode::Deuteranopia, Color::Yellow | Color::LightYellow) => CB_YELLOW,
+            (ColorMode::Deuteranopia, Color::Blue | Color::LightBlue) => CB_BLUE,
+            (ColorMode::Deuteranopia, Color::Magenta | Color::LightMagenta) => CB_REDDISH_PURPLE,
+            (ColorMode::Tritanopia, Color::Blue | Color::LightBlue) => CB_REDDISH_PURPLE,
+            (ColorMode::Tritanopia, Color::Cyan | Color::LightCyan) => CB_ORANGE,
+            (ColorMode::Tritanopia, Color::Green | Color::LightGreen) => CB_BLUISH_GREEN,
+            _ => color,
         }
     }
 
@@ -107,6 +232,34 @@ impl Theme {
     }
 }
 
+/// Whether the `NO_COLOR` and `CLICOLOR` conventions ask for colorless
+/// output. A non-empty `NO_COLOR` or `CLICOLOR=0` disables colors unless
+/// `CLICOLOR_FORCE` is set to something other than `0`.
+fn colors_disabled(
+    no_color: Option<&str>,
+    clicolor: Option<&str>,
+    clicolor_force: Option<&str>,
+) -> bool {
+    if clicolor_force.is_some_and(|value| !value.is_empty() && value != "0") {
+        return false;
+    }
+    no_color.is_some_and(|value| !value.is_empty()) || clicolor == Some("0")
+}
+
+/// Backgrounds that mark something (selections, badges) rather than fill a
+/// panel or dialog, and so must stay visible without colors.
+fn is_highlight_background(color: Color) -> bool {
+    !matches!(
+        color,
+        Color::Reset
+            | UI_COLOR_BACKGROUND
+            | DARK_THEME_BG_PRIMARY
+            | DARK_THEME_BG_SECONDARY
+            | LIGHT_THEME_BG_PRIMARY
+            | LIGHT_THEME_BG_SECONDARY
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +375,53 @@ mod tests {
         // Should contain "Theme" in debug output
         assert!(debug_output.contains("Theme"));
     }
+
+    #[test]
+    fn test_colors_disabled_follows_conventions() {
+        assert!(!colors_disabled(None, None, None));
+        assert!(colors_disabled(Some("1"), None, None));
+        assert!(!colors_disabled(Some(""), None, None));
+        assert!(colors_disabled(None, Some("0"), None));
+        assert!(!colors_disabled(None, Some("1"), None));
+        assert!(!colors_disabled(Some("1"), Some("0"), Some("1")));
+        assert!(colors_disabled(Some("1"), None, Some("0")));
+    }
+
+    #[test]
+    fn test_monochrome_frame_has_no_colors_and_keeps_selection_visible() {
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 3, 1));
+        buffer[(0, 0)].set_fg(STATUS_COLOR_ACTIVE);
+        buffer[(1, 0)].set_bg(UI_COLOR_HIGHLIGHT);
+        buffer[(2, 0)].set_bg(DARK_THEME_BG_PRIMARY);
+
+        Theme::monochrome().finish_frame(&mut buffer);
+
+        assert!(buffer
+            .content
+            .iter()
+            .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+        assert!(!buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(buffer[(1, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(!buffer[(2, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_color_blind_themes_separate_status_colors() {
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 2, 1));
+        buffer[(0, 0)].set_fg(STATUS_COLOR_ACTIVE);
+        buffer[(1, 0)].set_fg(STATUS_COLOR_ERROR);
+        Theme::from_name(ThemeName::Deuteranopia).finish_frame(&mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, CB_SKY_BLUE);
+        assert_eq!(buffer[(1, 0)].fg, CB_VERMILLION);
+
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)].set_fg(STATUS_COLOR_INFO);
+        Theme::tritanopia().finish_frame(&mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, CB_REDDISH_PURPLE);
+
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)].set_fg(STATUS_COLOR_ACTIVE);
+        Theme::dark().finish_frame(&mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, STATUS_COLOR_ACTIVE);
+    }
 }
@@ -4,12 +4,16 @@
 //! primitive widgets. These widgets encapsulate common UI patterns and styling
 //! logic for consistency across the application.
 
+pub mod perf_overlay;
+pub mod status_bar;
+
 use crate::{
     constants::colors::*,
     constants::limits::INVALID_API_LEVEL,
     constants::performance::*,
     constants::ui_layout::*,
     models::{AndroidDevice, DeviceStatus, IosDevice},
+    utils::text::truncate_to_width,
 };
 use ratatui::{
     style::{Modifier, Style},
@@ -227,13 +231,8 @@ impl EnhancedDeviceListWidget {
                             "Unknown API".to_string()
                         };
 
-                        let device_type_short = if device.device_type.len()
-                            > DEVICE_TYPE_DISPLAY_MAX_LENGTH
-                        {
-                            format!("{}...", &device.device_type[..DEVICE_TYPE_TRUNCATED_LENGTH])
-                        } else {
-                            device.device_type.clone()
-                        };
+                        let device_type_short =
+                            truncate_to_width(&device.device_type, DEVICE_TYPE_DISPLAY_MAX_LENGTH);
 
                         let display_text = format!(
                             "{} {} | {} | {}",
@@ -275,13 +274,8 @@ impl EnhancedDeviceListWidget {
                             "Unknown Runtime"
                         };
 
-                        let device_type_short = if device.device_type.len()
-                            > DEVICE_TYPE_DISPLAY_MAX_LENGTH
-                        {
-                            format!("{}...", &device.device_type[..DEVICE_TYPE_TRUNCATED_LENGTH])
-                        } else {
-                            device.device_type.clone()
-                        };
+                        let device_type_short =
+                            truncate_to_width(&device.device_type, DEVICE_TYPE_DISPLAY_MAX_LENGTH);
 
                         let display_text = format!(
                             "{} {} | {} | {}",
@@ -458,6 +452,9 @@ pub fn create_help_text() -> Vec<Line<'static>> {
     ]
 }
 
+/// Frames of the loading animation shown by [`get_animated_moon`].
+pub const MOON_PHASES: [&str; 8] = ["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"];
+
 /// Returns an animated moon emoji based on the current time.
 /// The moon cycles through different phases to create a loading animation.
 pub fn get_animated_moon() -> &'static str {
@@ -466,10 +463,9 @@ pub fn get_animated_moon() -> &'static str {
         .unwrap_or_default()
         .as_millis();
 
-    let moon_phases = ["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"];
     let index =
-        ((elapsed / ANIMATION_TIMING_DURATION_MS as u128) % moon_phases.len() as u128) as usize;
-    moon_phases[index]
+        ((elapsed / ANIMATION_TIMING_DURATION_MS as u128) % MOON_PHASES.len() as u128) as usize;
+    MOON_PHASES[index]
 }
 
 #[cfg(test)]
@@ -622,6 +618,7 @@ mod tests {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         };
 
         let ios_device = IosDevice {
@@ -633,6 +630,7 @@ mod tests {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
 
         let widget = EnhancedDeviceListWidget::new("Test".to_string())
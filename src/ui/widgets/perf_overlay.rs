@@ -0,0 +1,94 @@
+//! On-screen perf overlay (Shift+P).
+//!
+//! A small box in the top-right corner with the last, p95, and max timings of
+//! frame rendering, state lock waits, device refreshes, and external commands.
+
+use crate::{
+    constants::{
+        colors::*,
+        ui_text::perf::{NO_DATA, TITLE},
+    },
+    ui::Theme,
+    utils::{
+        metrics::{self, format_duration, Histogram, Metric},
+        text::display_width,
+    },
+};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// One overlay line per metric, e.g. `frame    last 2.1ms  p95 4.0ms  max 12ms`.
+pub fn perf_lines() -> Vec<String> {
+    Metric::ALL
+        .iter()
+        .map(|&metric| format_metric_line(metric, &metrics::snapshot(metric)))
+        .collect()
+}
+
+fn format_metric_line(metric: Metric, histogram: &Histogram) -> String {
+    let last = histogram
+        .last()
+        .map_or_else(|| NO_DATA.to_string(), format_duration);
+    let p95 = histogram
+        .percentile(0.95)
+        .map_or_else(|| NO_DATA.to_string(), format_duration);
+    let max = if histogram.count() == 0 {
+        NO_DATA.to_string()
+    } else {
+        format_duration(histogram.max())
+    };
+    format!(
+        "{:<8}last {last:>6}  p95 {p95:>6}  max {max:>6}",
+        metric.label()
+    )
+}
+
+pub fn render_perf_overlay(frame: &mut Frame, theme: &Theme) {
+    let lines = perf_lines();
+    let size = frame.area();
+    let content_width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or_default() as u16;
+    let width = (content_width + 2).min(size.width);
+    let height = (lines.len() as u16 + 2).min(size.height);
+    let area = Rect::new(size.x + size.width - width, size.y, width, height);
+
+    frame.render_widget(Clear, area);
+    let overlay = Paragraph::new(lines.join("\n"))
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .title(TITLE)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(UI_COLOR_TEXT_DIM)),
+        );
+    frame.render_widget(overlay, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_metric_line_without_data_shows_placeholders() {
+        let line = format_metric_line(Metric::Refresh, &Histogram::default());
+        assert!(line.starts_with("refresh"));
+        assert_eq!(line.matches(NO_DATA).count(), 3);
+    }
+
+    #[test]
+    fn test_metric_line_formats_timings() {
+        let mut histogram = Histogram::default();
+        histogram.record(Duration::from_millis(20));
+        let line = format_metric_line(Metric::Frame, &histogram);
+        assert!(line.contains("last   20ms"));
+        assert!(line.contains("max   20ms"));
+    }
+}
@@ -0,0 +1,157 @@
+//! Global status bar summarizing the whole session.
+//!
+//! The bar sits at the bottom of the screen and stays visible in every mode,
+//! so running devices, background work, and adb health can be seen without
+//! switching panels.
+
+use crate::{
+    app::AppState,
+    constants::{
+        colors::*,
+        ui_text::status_bar::{
+            ACTIVE_OPERATIONS, ADB_HEALTHY, ADB_UNHEALTHY, ADB_UNKNOWN, ANDROID_RUNNING,
+            IOS_RUNNING, LOG_FILTER, RECORDING, SEPARATOR,
+        },
+    },
+    ui::Theme,
+};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Values shown in the status bar, captured from `AppState` once per frame.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub android_running: usize,
+    pub ios_running: usize,
+    pub active_operations: usize,
+    pub log_filter: Option<String>,
+    pub recording: bool,
+    pub adb_server_healthy: Option<bool>,
+}
+
+impl StatusSummary {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            android_running: state
+                .android_devices
+                .iter()
+                .filter(|d| d.is_running)
+                .count(),
+            ios_running: state.ios_devices.iter().filter(|d| d.is_running).count(),
+            active_operations: state.active_operation_count(),
+            log_filter: state.log_filter_level.clone(),
+            recording: state.active_recording.is_some(),
+            adb_server_healthy: state.adb_server_healthy,
+        }
+    }
+
+    /// Builds the styled status line. Segments that have nothing to report
+    /// (no background work, no filter, no recording) are left out.
+    pub fn to_line(&self) -> Line<'static> {
+        let dim = Style::default().fg(UI_COLOR_TEXT_DIM);
+        let count = |template: &str, value: usize| template.replace("{}", &value.to_string());
+
+        let mut segments = vec![
+            Span::styled(count(ANDROID_RUNNING, self.android_running), dim),
+            Span::styled(count(IOS_RUNNING, self.ios_running), dim),
+        ];
+        if self.active_operations > 0 {
+            segments.push(Span::styled(
+                count(ACTIVE_OPERATIONS, self.active_operations),
+                Style::default().fg(STATUS_COLOR_WARNING),
+            ));
+        }
+        if let Some(level) = &self.log_filter {
+            segments.push(Span::styled(
+                LOG_FILTER.replace("{}", level),
+                Style::default().fg(STATUS_COLOR_INFO),
+            ));
+        }
+        if self.recording {
+            segments.push(Span::styled(
+                RECORDING,
+                Style::default()
+                    .fg(STATUS_COLOR_ERROR)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        segments.push(match self.adb_server_healthy {
+            Some(true) => Span::styled(ADB_HEALTHY, Style::default().fg(STATUS_COLOR_SUCCESS)),
+            Some(false) => Span::styled(ADB_UNHEALTHY, Style::default().fg(STATUS_COLOR_ERROR)),
+            None => Span::styled(ADB_UNKNOWN, dim),
+        });
+
+        let mut spans = Vec::with_capacity(segments.len() * 2 + 1);
+        spans.push(Span::raw(" "));
+        for (index, segment) in segments.into_iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::styled(SEPARATOR, dim));
+            }
+            spans.push(segment);
+        }
+        Line::from(spans)
+    }
+}
+
+/// Renders the status summary into `area`.
+pub fn render_status_bar(frame: &mut Frame, area: Rect, state: &AppState, _theme: &Theme) {
+    let summary = StatusSummary::from_state(state);
+    frame.render_widget(Paragraph::new(summary.to_line()), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AndroidDevice;
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_summary_counts_running_devices_and_operations() {
+        let mut state = AppState::new();
+        state.android_devices = vec![
+            AndroidDevice {
+                name: "Running".to_string(),
+                is_running: true,
+                ..Default::default()
+            },
+            AndroidDevice {
+                name: "Stopped".to_string(),
+                ..Default::default()
+            },
+        ];
+        state.set_device_operation_status("Wiping".to_string());
+        state.log_filter_level = Some("ERROR".to_string());
+        state.adb_server_healthy = Some(false);
+
+        let summary = StatusSummary::from_state(&state);
+        assert_eq!(summary.android_running, 1);
+        assert_eq!(summary.ios_running, 0);
+        assert_eq!(summary.active_operations, 1);
+
+        let text = line_text(&summary.to_line());
+        assert!(text.contains("🤖 1 running"));
+        assert!(text.contains("⚙ 1 active"));
+        assert!(text.contains("filter: ERROR"));
+        assert!(text.contains(ADB_UNHEALTHY));
+        assert!(!text.contains(RECORDING));
+    }
+
+    #[test]
+    fn test_summary_hides_idle_segments() {
+        let text = line_text(&StatusSummary::default().to_line());
+        assert!(!text.contains("active"));
+        assert!(!text.contains("filter"));
+        assert!(text.ends_with(ADB_UNKNOWN));
+    }
+}
@@ -0,0 +1,191 @@
+//! Audit trail of external commands
+//!
+//! Every command executed through `CommandRunner` is recorded here with its
+//! duration and exit status. The most recent entries are kept in memory for the
+//! command history view, and when a log file has been configured each entry is
+//! also appended to it, rotating the file once it grows too large.
+
+use crate::constants::{
+    files::{APP_DATA_DIR, COMMAND_AUDIT_LOG_FILE, ROTATED_LOG_SUFFIX},
+    limits::{COMMAND_AUDIT_LOG_MAX_BYTES, MAX_COMMAND_HISTORY_ENTRIES},
+};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+static AUDIT: OnceLock<Mutex<CommandAudit>> = OnceLock::new();
+
+/// A single executed command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandAuditEntry {
+    /// When the command was started
+    pub started_at: chrono::DateTime<chrono::Local>,
+    /// The command line as it would be typed in a shell
+    pub command: String,
+    /// How long the command ran (or took to spawn, for background processes)
+    pub duration: Duration,
+    /// Exit code, if the command ran to completion
+    pub exit_code: Option<i32>,
+    /// Whether the command succeeded (or was spawned successfully)
+    pub success: bool,
+}
+
+impl CommandAuditEntry {
+    /// Short status label such as "exit 0", "exit 1", "spawned", or "failed".
+    pub fn status_label(&self) -> String {
+        match (self.exit_code, self.success) {
+            (Some(code), _) => format!("exit {code}"),
+            (None, true) => "spawned".to_string(),
+            (None, false) => "failed".to_string(),
+        }
+    }
+
+    /// Duration formatted for display ("850ms", "12.3s").
+    pub fn duration_label(&self) -> String {
+        let millis = self.duration.as_millis();
+        if millis < 1000 {
+            format!("{millis}ms")
+        } else {
+            format!("{:.1}s", self.duration.as_secs_f64())
+        }
+    }
+
+    /// One line of the audit log file.
+    pub fn to_log_line(&self) -> String {
+        format!(
+            "{} [{}] {} {}",
+            self.started_at.format("%Y-%m-%d %H:%M:%S"),
+            self.status_label(),
+            self.duration_label(),
+            self.command
+        )
+    }
+}
+
+#[derive(Default)]
+struct CommandAudit {
+    entries: VecDeque<CommandAuditEntry>,
+    log_path: Option<PathBuf>,
+}
+
+fn audit() -> &'static Mutex<CommandAudit> {
+    AUDIT.get_or_init(|| Mutex::new(CommandAudit::default()))
+}
+
+/// Default location of the audit log (e.g. `~/.local/share/emu/commands.log`).
+pub fn default_log_path() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join(APP_DATA_DIR).join(COMMAND_AUDIT_LOG_FILE))
+}
+
+/// Starts appending recorded commands to the given file.
+pub async fn enable_log_file(path: PathBuf) {
+    audit().lock().await.log_path = Some(path);
+}
+
+/// Path of the audit log file, if one is enabled.
+pub async fn log_path() -> Option<PathBuf> {
+    audit().lock().await.log_path.clone()
+}
+
+/// Records an executed command in memory and in the audit log file.
+///
+/// Failures to write the file are logged and otherwise ignored so that
+/// auditing never breaks the command itself.
+pub async fn record(entry: CommandAuditEntry) {
+    let log_path = {
+        let mut audit = audit().lock().await;
+        if audit.entries.len() >= MAX_COMMAND_HISTORY_ENTRIES {
+            audit.entries.pop_front();
+        }
+        audit.entries.push_back(entry.clone());
+        audit.log_path.clone()
+    };
+
+    if let Some(path) = log_path {
+        if let Err(e) = append_to_log(&path, &entry.to_log_line()).await {
+            log::debug!("Failed to write command audit log: {e}");
+        }
+    }
+}
+
+/// Recorded commands, newest first.
+pub async fn recent_entries() -> Vec<CommandAuditEntry> {
+    audit().lock().await.entries.iter().rev().cloned().collect()
+}
+
+async fn append_to_log(path: &Path, line: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    rotate_if_needed(path, COMMAND_AUDIT_LOG_MAX_BYTES).await?;
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(format!("{line}\n").as_bytes()).await?;
+    // tokio files write in the background; flush so the line lands before close
+    file.flush().await
+}
+
+/// Moves the log aside to `<name>.1` once it reaches `max_bytes`, replacing
+/// any previous rotation.
+async fn rotate_if_needed(path: &Path, max_bytes: u64) -> std::io::Result<()> {
+    let Ok(metadata) = tokio::fs::metadata(path).await else {
+        return Ok(());
+    };
+    if metadata.len() < max_bytes {
+        return Ok(());
+    }
+
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(ROTATED_LOG_SUFFIX);
+    tokio::fs::rename(path, PathBuf::from(rotated)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(exit_code: Option<i32>, success: bool, millis: u64) -> CommandAuditEntry {
+        CommandAuditEntry {
+            started_at: chrono::Local::now(),
+            command: "adb devices".to_string(),
+            duration: Duration::from_millis(millis),
+            exit_code,
+            success,
+        }
+    }
+
+    #[test]
+    fn test_entry_labels() {
+        assert_eq!(entry(Some(0), true, 850).status_label(), "exit 0");
+        assert_eq!(entry(None, true, 5).status_label(), "spawned");
+        assert_eq!(entry(None, false, 5).status_label(), "failed");
+        assert_eq!(entry(Some(0), true, 850).duration_label(), "850ms");
+        assert_eq!(entry(Some(1), false, 12_340).duration_label(), "12.3s");
+        assert!(entry(Some(1), false, 10)
+            .to_log_line()
+            .ends_with("[exit 1] 10ms adb devices"));
+    }
+
+    #[tokio::test]
+    async fn test_log_file_rotates_when_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(COMMAND_AUDIT_LOG_FILE);
+
+        append_to_log(&path, "first").await.unwrap();
+        rotate_if_needed(&path, 1).await.unwrap();
+        append_to_log(&path, "second").await.unwrap();
+
+        let rotated = dir
+            .path()
+            .join(format!("{COMMAND_AUDIT_LOG_FILE}{ROTATED_LOG_SUFFIX}"));
+        assert_eq!(std::fs::read_to_string(rotated).unwrap(), "first\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second\n");
+    }
+}
@@ -0,0 +1,156 @@
+//! Booting a device from scripts.
+//!
+//! `emu wait-for-boot <device>` starts a stopped device and blocks until it
+//! has finished booting, so shell scripts can chain "boot, install, test"
+//! without guessing sleep times. Devices are matched by [`DeviceId`]
+//! (`android:<avd name>`, `ios:<udid>`), by AVD name, or by iOS simulator
+//! name or UDID.
+//!
+//! "Booted" is decided by a readiness pipeline (see [`ReadinessConfig`]):
+//! by default only `boot_completed`, but `.emu.toml` or `--ready` can add
+//! checks such as a responsive package manager or a visible launcher.
+
+use crate::constants::{messages::wait_for_boot, timeouts::WAIT_FOR_BOOT_POLL_INTERVAL};
+use crate::models::{DeviceId, ReadinessCheck, ReadinessConfig};
+use crate::service::DeviceService;
+use anyhow::{bail, Result};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// How waiting for a device ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootWaitOutcome {
+    Booted,
+    TimedOut,
+}
+
+/// Boots `device` if it is stopped and waits until it passes its readiness
+/// checks or `timeout` passes. Progress goes to stderr so stdout stays clean.
+pub async fn wait_for_boot(
+    device: &str,
+    timeout: Duration,
+    readiness: &ReadinessConfig,
+) -> Result<BootWaitOutcome> {
+    let service = DeviceService::new().await?;
+    let id = resolve_device(&service, device).await?;
+    let name = service.find(&id).await?.name().to_string();
+    let checks = readiness.checks_for(&[device, &name, &id.identifier]);
+
+    if !service.find(&id).await?.is_running() {
+        eprintln!("{}", wait_for_boot::BOOTING.replace("{}", device));
+        service.start(&id).await?;
+    }
+
+    let pending = Mutex::new(Some(ReadinessCheck::BootCompleted));
+    let ready = poll_until(WAIT_FOR_BOOT_POLL_INTERVAL, timeout, || async {
+        // The emulator has no adb serial for a few seconds after launch,
+        // so errors mean "not booted yet" rather than failure
+        let next = service
+            .pending_readiness_check(&id, &checks)
+            .await
+            .unwrap_or(Some(ReadinessCheck::BootCompleted));
+        let mut pending = pending.lock().await;
+        if next != *pending {
+            if *pending == Some(ReadinessCheck::BootCompleted) {
+                eprintln!("{}", wait_for_boot::BOOTED.replace("{}", device));
+            }
+            if let Some(check) = next.filter(|check| *check != ReadinessCheck::BootCompleted) {
+                eprintln!(
+                    "{}",
+                    wait_for_boot::WAITING_FOR
+                        .replace("{check}", check.name())
+                        .replace("{device}", device)
+                );
+            }
+            *pending = next;
+        }
+        next.is_none()
+    })
+    .await;
+
+    if ready {
+        if checks != [ReadinessCheck::BootCompleted] {
+            eprintln!("{}", wait_for_boot::READY.replace("{}", device));
+        }
+        return Ok(BootWaitOutcome::Booted);
+    }
+
+    let seconds = timeout.as_secs().to_string();
+    let message = match *pending.lock().await {
+        Some(check) if check != ReadinessCheck::BootCompleted => wait_for_boot::TIMED_OUT_ON_CHECK
+            .replace("{seconds}", &seconds)
+            .replace("{check}", check.name())
+            .replace("{device}", device),
+        _ => wait_for_boot::TIMED_OUT
+            .replace("{seconds}", &seconds)
+            .replace("{device}", device),
+    };
+    eprintln!("{message}");
+    Ok(BootWaitOutcome::TimedOut)
+}
+
+/// Turns the command-line argument into a device ID: either it already is
+/// one, or it is a UDID or a unique device name.
+pub(crate) async fn resolve_device(service: &DeviceService, device: &str) -> Result<DeviceId> {
+    if let Ok(id) = device.parse::<DeviceId>() {
+        return Ok(id);
+    }
+
+    let devices = service.list_devices().await?;
+    if let Some(found) = devices.iter().find(|found| found.id().identifier == device) {
+        return Ok(found.id());
+    }
+    let mut by_name = devices.iter().filter(|found| found.name() == device);
+    match (by_name.next(), by_name.next()) {
+        (Some(found), None) => Ok(found.id()),
+        (Some(_), Some(_)) => bail!(wait_for_boot::AMBIGUOUS_DEVICE.replace("{}", device)),
+        (None, _) => bail!(wait_for_boot::DEVICE_NOT_FOUND.replace("{}", device)),
+    }
+}
+
+/// Calls `check` every `interval` until it returns true or `timeout` has
+/// passed. Returns whether `check` succeeded in time.
+pub(crate) async fn poll_until<F, Fut>(interval: Duration, timeout: Duration, mut check: F) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    tokio::time::timeout(timeout, async {
+        while !check().await {
+            tokio::time::sleep(interval).await;
+        }
+    })
+    .await
+    .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn test_poll_until_returns_once_check_passes() {
+        let calls = AtomicU32::new(0);
+        let booted = poll_until(Duration::from_millis(1), Duration::from_secs(5), || async {
+            calls.fetch_add(1, Ordering::SeqCst) >= 2
+        })
+        .await;
+
+        assert!(booted);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_times_out() {
+        let booted = poll_until(
+            Duration::from_millis(1),
+            Duration::from_millis(20),
+            || async { false },
+        )
+        .await;
+
+        assert!(!booted);
+    }
+}
@@ -1,9 +1,27 @@
 //! Shared cache helpers that are used outside of application UI state.
 
-use crate::models::ApiLevel;
+use crate::constants::{
+    files::{
+        ANDROID_VERSION_NAME_CACHE_FILE, API_LEVEL_CACHE_FILE, APP_DATA_DIR,
+        DEVICE_INVENTORY_CACHE_FILE,
+    },
+    timeouts::{ANDROID_VERSION_NAME_CACHE_TTL, DEVICE_INVENTORY_CACHE_TTL},
+};
+use crate::models::{AndroidDevice, ApiLevel, IosDevice};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Get the path of a cache file in the user's config directory.
+pub(crate) fn cache_file_path(file_name: &str) -> Result<PathBuf, anyhow::Error> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+    let emu_config_dir = config_dir.join(APP_DATA_DIR);
+    fs::create_dir_all(&emu_config_dir)?;
+    Ok(emu_config_dir.join(file_name))
+}
 
 /// Persistent API level cache stored on disk for faster device creation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,18 +31,9 @@ pub struct ApiLevelCache {
 }
 
 impl ApiLevelCache {
-    /// Get the API cache file path in the user's config directory.
-    fn cache_file_path() -> Result<PathBuf, anyhow::Error> {
-        let config_dir = dirs::config_dir()
-            .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
-        let emu_config_dir = config_dir.join("emu");
-        fs::create_dir_all(&emu_config_dir)?;
-        Ok(emu_config_dir.join("api_level_cache.json"))
-    }
-
     /// Load API level cache from disk if it exists and is valid.
     pub fn load_from_disk() -> Option<Self> {
-        let cache_path = Self::cache_file_path().ok()?;
+        let cache_path = cache_file_path(API_LEVEL_CACHE_FILE).ok()?;
         if !cache_path.exists() {
             return None;
         }
@@ -42,7 +51,7 @@ impl ApiLevelCache {
 
     /// Save API level cache to disk.
     pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
-        let cache_path = Self::cache_file_path()?;
+        let cache_path = cache_file_path(API_LEVEL_CACHE_FILE)?;
         let cache_json = serde_json::to_string_pretty(self)?;
         fs::write(cache_path, cache_json)?;
         Ok(())
@@ -50,10 +59,148 @@ impl ApiLevelCache {
 
     /// Clear the API level cache from disk if it exists.
     pub fn clear_from_disk() -> Result<(), anyhow::Error> {
-        let cache_path = Self::cache_file_path()?;
+        let cache_path = cache_file_path(API_LEVEL_CACHE_FILE)?;
         if cache_path.exists() {
             fs::remove_file(cache_path)?;
         }
         Ok(())
     }
 }
+
+/// Last-known device inventory, persisted so a fresh launch can render the
+/// device lists before the SDK tools have answered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceInventoryCache {
+    pub android_devices: Vec<AndroidDevice>,
+    pub ios_devices: Vec<IosDevice>,
+    pub android_device_types: Vec<(String, String)>,
+    pub android_api_levels: Vec<(String, String)>,
+    pub ios_device_types: Vec<(String, String)>,
+    pub ios_runtimes: Vec<(String, String)>,
+    pub timestamp: Option<SystemTime>,
+}
+
+impl DeviceInventoryCache {
+    /// Returns true when the snapshot holds no devices or creation options.
+    pub fn is_empty(&self) -> bool {
+        self.android_devices.is_empty()
+            && self.ios_devices.is_empty()
+            && self.android_device_types.is_empty()
+            && self.android_api_levels.is_empty()
+            && self.ios_device_types.is_empty()
+            && self.ios_runtimes.is_empty()
+    }
+
+    /// Returns true while the snapshot is younger than the inventory TTL.
+    pub fn is_fresh(&self) -> bool {
+        self.timestamp
+            .and_then(|timestamp| timestamp.elapsed().ok())
+            .is_some_and(|age| age < DEVICE_INVENTORY_CACHE_TTL)
+    }
+
+    /// Load the device inventory from disk if it exists and is still fresh.
+    pub fn load_from_disk() -> Option<Self> {
+        let cache_path = cache_file_path(DEVICE_INVENTORY_CACHE_FILE).ok()?;
+        if !cache_path.exists() {
+            return None;
+        }
+
+        let cache_content = fs::read_to_string(cache_path).ok()?;
+        let cache: Self = serde_json::from_str(&cache_content).ok()?;
+        cache.is_fresh().then_some(cache)
+    }
+
+    /// Save the device inventory to disk, stamping it with the current time.
+    pub fn save_to_disk(&mut self) -> Result<(), anyhow::Error> {
+        self.timestamp = Some(SystemTime::now());
+        let cache_path = cache_file_path(DEVICE_INVENTORY_CACHE_FILE)?;
+        let cache_json = serde_json::to_string(self)?;
+        fs::write(cache_path, cache_json)?;
+        Ok(())
+    }
+
+    /// Clear the device inventory from disk if it exists.
+    pub fn clear_from_disk() -> Result<(), anyhow::Error> {
+        let cache_path = cache_file_path(DEVICE_INVENTORY_CACHE_FILE)?;
+        if cache_path.exists() {
+            fs::remove_file(cache_path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Android version names by API level ("14" for 34), as sdkmanager lists
+/// them. Kept on disk so details never wait for sdkmanager to name a level.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AndroidVersionNameCache {
+    pub version_names: BTreeMap<u32, CachedVersionName>,
+    /// File the names are saved to; `None` keeps them in memory only.
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// A version name and when sdkmanager last reported it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedVersionName {
+    pub version_name: String,
+    pub fetched_at: SystemTime,
+}
+
+impl AndroidVersionNameCache {
+    /// The version name of `api_level`, however old it is.
+    pub fn version_name(&self, api_level: u32) -> Option<&str> {
+        self.version_names
+            .get(&api_level)
+            .map(|cached| cached.version_name.as_str())
+    }
+
+    /// The version name of `api_level` if it is younger than the cache TTL.
+    pub fn fresh_version_name(&self, api_level: u32) -> Option<&str> {
+        self.version_names
+            .get(&api_level)
+            .filter(|cached| {
+                cached
+                    .fetched_at
+                    .elapsed()
+                    .is_ok_and(|age| age < ANDROID_VERSION_NAME_CACHE_TTL)
+            })
+            .map(|cached| cached.version_name.as_str())
+    }
+
+    /// Records a version name sdkmanager just reported.
+    pub fn insert(&mut self, api_level: u32, version_name: String) {
+        self.version_names.insert(
+            api_level,
+            CachedVersionName {
+                version_name,
+                fetched_at: SystemTime::now(),
+            },
+        );
+    }
+
+    /// Load the version names from the config directory.
+    pub fn load_from_disk() -> Self {
+        Self::load_from(cache_file_path(ANDROID_VERSION_NAME_CACHE_FILE).ok())
+    }
+
+    /// Load the version names from `path`, falling back to an empty cache.
+    /// Later saves go to the same file.
+    pub fn load_from(path: Option<PathBuf>) -> Self {
+        let mut cache: Self = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        cache.path = path;
+        cache
+    }
+
+    /// Save the version names to the file they were loaded from.
+    pub fn save_to_disk(&self) -> Result<(), anyhow::Error> {
+        let Some(cache_path) = &self.path else {
+            return Ok(());
+        };
+        fs::write(cache_path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
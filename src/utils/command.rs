@@ -2,11 +2,27 @@
 //!
 //! This module provides a unified interface for executing external commands
 //! asynchronously. It handles command execution, output capture, error handling,
-//! and debug logging in a consistent manner across the application.
+//! debug logging, and auditing in a consistent manner across the application.
 
-use crate::constants::env_vars::RUST_LOG;
+use crate::constants::{
+    commands::clipboard,
+    env_vars::RUST_LOG,
+    limits::{MAX_COMMAND_OUTPUT_BYTES, MAX_OUTPUT_LINE_BYTES},
+    patterns::PORT_IN_USE,
+};
+use crate::models::DeviceError;
+use crate::utils::audit::{self, CommandAuditEntry};
+use crate::utils::command_executor::{OutputHandler, ProcessOutput};
+use crate::utils::metrics::{self, Metric};
+use crate::utils::{processes, tasks, tool_paths, xcode};
 use anyhow::{Context, Result};
 use std::ffi::OsStr;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader};
 use tokio::process::Command;
 
 use crate::constants::timeouts::{INITIAL_RETRY_DELAY, MAX_RETRY_DELAY};
@@ -110,14 +126,45 @@ impl CommandRunner {
             eprintln!("[DEBUG] Executing command: {command_str}");
         }
 
-        let output = Command::new(program_ref)
+        let started_at = chrono::Local::now();
+        let timer = Instant::now();
+        let mut command = Command::new(tool_paths::resolve_program(program_ref));
+        xcode::apply_developer_dir(&mut command, program_ref);
+        command
             .args(&args_vec)
-            .output()
-            .await
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // Kill the child if the caller stops waiting (timeout or cancellation)
+        let child = processes::spawn(&mut command, &format_command_line(program_ref, &args_vec));
+        let output = match child {
+            Ok(mut child) => {
+                // Both pipes are drained together so neither can fill up and
+                // stall the command
+                let (stdout, stderr) = tokio::join!(
+                    read_capped(child.stdout.take(), |_| {}),
+                    read_capped(child.stderr.take(), |_| {}),
+                );
+                child.wait().await.map(|status| (status, stdout, stderr))
+            }
+            Err(e) => Err(e),
+        };
+        let duration = timer.elapsed();
+        metrics::record(Metric::Command, duration);
+        audit::record(CommandAuditEntry {
+            started_at,
+            command: format_command_line(program_ref, &args_vec),
+            duration,
+            exit_code: output.as_ref().ok().and_then(|(status, ..)| status.code()),
+            success: output.as_ref().is_ok_and(|(status, ..)| status.success()),
+        })
+        .await;
+        let (status, stdout, stderr) = output
+            .map_err(|e| missing_tool_error(program_ref, e))
             .context("Failed to execute command")?;
 
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = stderr.into_string(program_ref);
+        let stdout = stdout.into_string(program_ref);
 
         // Debug logging only when RUST_LOG=debug is set
         if std::env::var(RUST_LOG)
@@ -126,22 +173,119 @@ impl CommandRunner {
         {
             eprintln!(
                 "[DEBUG] Command exit code: {exit_code:?}",
-                exit_code = output.status.code()
+                exit_code = status.code()
             );
             eprintln!("[DEBUG] Command stdout: {stdout}");
             eprintln!("[DEBUG] Command stderr: {stderr}");
         }
 
-        if !output.status.success() {
+        if !status.success() {
+            if let Some(port) = conflicting_port(&stderr) {
+                return Err(DeviceError::PortConflict { port }.into());
+            }
             return Err(anyhow::anyhow!(
                 "Command failed with exit code {}: stderr: {} stdout: {}",
-                output.status.code().unwrap_or(-1),
+                status.code().unwrap_or(-1),
                 stderr.trim(),
                 stdout.trim()
             ));
         }
 
-        Ok(stdout.into_owned())
+        Ok(stdout)
+    }
+
+    /// Executes a command like [`run`](Self::run), passing each line of stdout
+    /// to `on_line` as it is printed.
+    ///
+    /// Lines are split on `\r` as well as `\n`, since SDK tools redraw their
+    /// progress bars in place. The collected stdout is returned on success.
+    pub async fn run_streaming<S, I, A>(
+        &self,
+        program: S,
+        args: I,
+        on_line: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String>
+    where
+        S: AsRef<OsStr>,
+        I: IntoIterator<Item = A>,
+        A: AsRef<OsStr>,
+    {
+        self.run_streaming_with_env(program, args, &[], on_line)
+            .await
+    }
+
+    /// Executes a command like [`run_streaming`](Self::run_streaming) with
+    /// `envs` added to its environment.
+    pub async fn run_streaming_with_env<S, I, A>(
+        &self,
+        program: S,
+        args: I,
+        envs: &[(String, String)],
+        on_line: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String>
+    where
+        S: AsRef<OsStr>,
+        I: IntoIterator<Item = A>,
+        A: AsRef<OsStr>,
+    {
+        let program_ref = program.as_ref();
+        let args_vec: Vec<_> = args
+            .into_iter()
+            .map(|a| a.as_ref().to_os_string())
+            .collect();
+
+        let started_at = chrono::Local::now();
+        let timer = Instant::now();
+        let mut command = Command::new(tool_paths::resolve_program(program_ref));
+        xcode::apply_developer_dir(&mut command, program_ref);
+        command
+            .args(&args_vec)
+            .envs(envs.iter().map(|(key, value)| (key, value)))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child =
+            processes::spawn(&mut command, &format_command_line(program_ref, &args_vec))
+                .context("Failed to execute command")?;
+
+        let mut line = Vec::new();
+        let read_stdout = read_capped(child.stdout.take(), |chunk| {
+            for &byte in chunk {
+                if byte == b'\n' || byte == b'\r' {
+                    emit_line(&line, on_line);
+                    line.clear();
+                } else if line.len() < MAX_OUTPUT_LINE_BYTES {
+                    line.push(byte);
+                }
+            }
+        });
+        let (stdout, stderr) = tokio::join!(read_stdout, read_capped(child.stderr.take(), |_| {}));
+        emit_line(&line, on_line);
+        let status = child.wait().await;
+
+        let duration = timer.elapsed();
+        metrics::record(Metric::Command, duration);
+        audit::record(CommandAuditEntry {
+            started_at,
+            command: format_command_line(program_ref, &args_vec),
+            duration,
+            exit_code: status.as_ref().ok().and_then(|status| status.code()),
+            success: status.as_ref().is_ok_and(|status| status.success()),
+        })
+        .await;
+        let status = status.context("Failed to execute command")?;
+
+        let stdout = stdout.into_string(program_ref);
+        if !status.success() {
+            return Err(anyhow::anyhow!(
+                "Command failed with exit code {}: stderr: {} stdout: {}",
+                status.code().unwrap_or(-1),
+                stderr.into_string(program_ref).trim(),
+                stdout.trim()
+            ));
+        }
+
+        Ok(stdout)
     }
 
     /// Spawns a command in the background and returns immediately.
@@ -183,17 +327,109 @@ impl CommandRunner {
         I: IntoIterator<Item = A>,
         A: AsRef<OsStr>,
     {
-        let child = Command::new(program)
-            .args(args)
+        let program = program.as_ref();
+        let args: Vec<_> = args
+            .into_iter()
+            .map(|a| a.as_ref().to_os_string())
+            .collect();
+
+        let started_at = chrono::Local::now();
+        let timer = Instant::now();
+        let mut command = Command::new(tool_paths::resolve_program(program));
+        xcode::apply_developer_dir(&mut command, program);
+        let child = command
+            .args(&args)
             .stdout(std::process::Stdio::null()) // Suppress stdout output
             .stderr(std::process::Stdio::null()) // Suppress stderr output
             .stdin(std::process::Stdio::null()) // No stdin needed
-            .spawn()
+            .spawn();
+        audit::record(CommandAuditEntry {
+            started_at,
+            command: format_command_line(program, &args),
+            duration: timer.elapsed(),
+            exit_code: None,
+            success: child.is_ok(),
+        })
+        .await;
+        let child = child
+            .map_err(|e| missing_tool_error(program, e))
             .context("Failed to spawn command")?;
 
         Ok(child.id().unwrap_or(0))
     }
 
+    /// Spawns a process like [`spawn`](Self::spawn), but pipes its stdout
+    /// and stderr to `on_output` line by line from a background task, which
+    /// also reports the exit once both pipes are closed.
+    pub async fn spawn_with_output<S, I, A>(
+        &self,
+        program: S,
+        args: I,
+        on_output: OutputHandler,
+    ) -> Result<u32>
+    where
+        S: AsRef<OsStr>,
+        I: IntoIterator<Item = A>,
+        A: AsRef<OsStr>,
+    {
+        let program = program.as_ref();
+        let args: Vec<_> = args
+            .into_iter()
+            .map(|a| a.as_ref().to_os_string())
+            .collect();
+
+        let started_at = chrono::Local::now();
+        let timer = Instant::now();
+        let mut command = Command::new(tool_paths::resolve_program(program));
+        xcode::apply_developer_dir(&mut command, program);
+        let child = command
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null())
+            .spawn();
+        audit::record(CommandAuditEntry {
+            started_at,
+            command: format_command_line(program, &args),
+            duration: timer.elapsed(),
+            exit_code: None,
+            success: child.is_ok(),
+        })
+        .await;
+        let mut child = child.context("Failed to spawn command")?;
+        let pid = child.id().unwrap_or(0);
+
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        tasks::spawn(async move {
+            let forward = |pipe: Option<Box<dyn AsyncRead + Send + Unpin>>| {
+                let on_output = Arc::clone(&on_output);
+                async move {
+                    let Some(pipe) = pipe else {
+                        return;
+                    };
+                    let mut lines = LossyLines::new(pipe);
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        if !line.trim().is_empty() {
+                            on_output(ProcessOutput::Line(line));
+                        }
+                    }
+                }
+            };
+            tokio::join!(
+                forward(stdout.map(|pipe| Box::new(pipe) as _)),
+                forward(stderr.map(|pipe| Box::new(pipe) as _)),
+            );
+            let status = child.wait().await.ok();
+            on_output(ProcessOutput::Exited {
+                success: status.is_some_and(|status| status.success()),
+                code: status.and_then(|status| status.code()),
+            });
+        });
+
+        Ok(pid)
+    }
+
     /// Executes a command ignoring specific error patterns (useful for "already in state" errors).
     ///
     /// This method runs a command and only returns an error if it doesn't match
@@ -326,6 +562,262 @@ impl CommandRunner {
     }
 }
 
+/// An external command as emu would run it.
+///
+/// Managers build their invocations through this type so the exact command
+/// can be shown to the user or copied to the clipboard before (or instead of)
+/// running it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandLine {
+    /// Program to execute, usually an absolute path inside an SDK
+    pub program: PathBuf,
+    /// Arguments passed to the program
+    pub args: Vec<String>,
+}
+
+impl CommandLine {
+    /// Creates a command line from a program and its arguments.
+    pub fn new<P, I, A>(program: P, args: I) -> Self
+    where
+        P: Into<PathBuf>,
+        I: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        Self {
+            program: program.into(),
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Arguments as string slices, as expected by `CommandExecutor`.
+    pub fn arg_refs(&self) -> Vec<&str> {
+        self.args.iter().map(String::as_str).collect()
+    }
+}
+
+impl fmt::Display for CommandLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_command_line(&self.program, &self.args))
+    }
+}
+
+/// Reads lines from a pipe, replacing invalid UTF-8 and cutting lines longer
+/// than [`MAX_OUTPUT_LINE_BYTES`], so one bad line never ends the stream.
+pub struct LossyLines<R> {
+    reader: BufReader<R>,
+    line: Vec<u8>,
+    split_on_carriage_return: bool,
+}
+
+impl<R: AsyncRead + Unpin> LossyLines<R> {
+    pub fn new(pipe: R) -> Self {
+        Self {
+            reader: BufReader::new(pipe),
+            line: Vec::new(),
+            split_on_carriage_return: false,
+        }
+    }
+
+    /// Also ends a line at `\r`, so each redraw of a progress bar printed
+    /// in place is read as a line of its own.
+    pub fn split_on_carriage_return(mut self) -> Self {
+        self.split_on_carriage_return = true;
+        self
+    }
+
+    /// The next line without its line ending, or `None` once the pipe is
+    /// closed. Cancel-safe: a partly read line is kept for the next call.
+    pub async fn next_line(&mut self) -> std::io::Result<Option<String>> {
+        loop {
+            let available = self.reader.fill_buf().await?;
+            if available.is_empty() {
+                if self.line.is_empty() {
+                    return Ok(None);
+                }
+                return Ok(Some(self.take_line()));
+            }
+
+            let split_on_carriage_return = self.split_on_carriage_return;
+            let newline = available
+                .iter()
+                .position(|&byte| byte == b'\n' || (split_on_carriage_return && byte == b'\r'));
+            let chunk = &available[..newline.unwrap_or(available.len())];
+            let room = MAX_OUTPUT_LINE_BYTES.saturating_sub(self.line.len());
+            self.line.extend_from_slice(&chunk[..chunk.len().min(room)]);
+            let consumed = newline.map_or(available.len(), |position| position + 1);
+            self.reader.consume(consumed);
+
+            if newline.is_some() {
+                return Ok(Some(self.take_line()));
+            }
+        }
+    }
+
+    fn take_line(&mut self) -> String {
+        if self.line.last() == Some(&b'\r') {
+            self.line.pop();
+        }
+        let line = String::from_utf8_lossy(&self.line).into_owned();
+        self.line.clear();
+        line
+    }
+}
+
+/// Output of a pipe, kept up to a size cap so a tool printing without end
+/// cannot exhaust memory. Bytes past the cap are counted, not stored.
+struct CappedOutput {
+    bytes: Vec<u8>,
+    limit: usize,
+    dropped: usize,
+}
+
+impl CappedOutput {
+    fn new(limit: usize) -> Self {
+        Self {
+            bytes: Vec::new(),
+            limit,
+            dropped: 0,
+        }
+    }
+
+    fn push(&mut self, chunk: &[u8]) {
+        let kept = chunk.len().min(self.limit.saturating_sub(self.bytes.len()));
+        self.bytes.extend_from_slice(&chunk[..kept]);
+        self.dropped += chunk.len() - kept;
+    }
+
+    /// The kept output as text, with invalid UTF-8 replaced.
+    fn into_string(self, program: &OsStr) -> String {
+        if self.dropped > 0 {
+            log::warn!(
+                "Dropped {} bytes of output from {} past the {} byte limit",
+                self.dropped,
+                program.to_string_lossy(),
+                self.limit
+            );
+        }
+        String::from_utf8_lossy(&self.bytes).into_owned()
+    }
+}
+
+/// Reads a pipe to the end in chunks, passing each to `on_chunk` as it
+/// arrives and keeping up to [`MAX_COMMAND_OUTPUT_BYTES`] of it.
+async fn read_capped(
+    pipe: Option<impl AsyncRead + Unpin>,
+    mut on_chunk: impl FnMut(&[u8]),
+) -> CappedOutput {
+    let mut output = CappedOutput::new(MAX_COMMAND_OUTPUT_BYTES);
+    let Some(mut pipe) = pipe else {
+        return output;
+    };
+    let mut buffer = [0u8; 8192];
+    while let Ok(read) = pipe.read(&mut buffer).await {
+        if read == 0 {
+            break;
+        }
+        on_chunk(&buffer[..read]);
+        output.push(&buffer[..read]);
+    }
+    output
+}
+
+fn emit_line(line: &[u8], on_line: &(dyn Fn(&str) + Send + Sync)) {
+    let line = String::from_utf8_lossy(line);
+    if !line.trim().is_empty() {
+        on_line(line.trim_end());
+    }
+}
+
+/// Copies text to the system clipboard using the platform's clipboard tool.
+///
+/// Uses `pbcopy` on macOS and tries `wl-copy`, `xclip`, then `xsel` elsewhere.
+pub async fn copy_to_clipboard(text: &str) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    for (tool, args) in clipboard::TOOLS {
+        let Ok(mut child) = Command::new(tool)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            if stdin.write_all(text.as_bytes()).await.is_err() {
+                continue;
+            }
+        }
+
+        if child.wait().await.is_ok_and(|status| status.success()) {
+            return Ok(());
+        }
+    }
+
+    anyhow::bail!("no clipboard tool available")
+}
+
+/// Formats a program and its arguments as a single shell command line.
+///
+/// Arguments containing whitespace, quotes, or shell metacharacters are
+/// single-quoted so the result can be pasted into a terminal as-is.
+pub fn format_command_line<S, I, A>(program: S, args: I) -> String
+where
+    S: AsRef<OsStr>,
+    I: IntoIterator<Item = A>,
+    A: AsRef<OsStr>,
+{
+    std::iter::once(quote_shell_arg(&program.as_ref().to_string_lossy()))
+        .chain(
+            args.into_iter()
+                .map(|arg| quote_shell_arg(&arg.as_ref().to_string_lossy())),
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `arg` as one shell word, single-quoted unless it is plain.
+pub fn quote_shell_arg(arg: &str) -> String {
+    let is_safe = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if is_safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Returns the executable name of `program` without its directory.
+pub(crate) fn program_name(program: impl AsRef<OsStr>) -> String {
+    let program = std::path::Path::new(program.as_ref());
+    program
+        .file_name()
+        .unwrap_or(program.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Maps a "no such file" launch failure to [`DeviceError::ToolMissing`].
+fn missing_tool_error(program: &OsStr, error: std::io::Error) -> anyhow::Error {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        DeviceError::tool_missing(program_name(program)).into()
+    } else {
+        error.into()
+    }
+}
+
+/// Extracts the port from a bind failure reported on stderr.
+fn conflicting_port(stderr: &str) -> Option<u16> {
+    stderr
+        .lines()
+        .find_map(|line| PORT_IN_USE.captures(line))
+        .and_then(|caps| caps[1].parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,6 +851,122 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_missing_program_is_tool_missing() {
+        let runner = CommandRunner::new();
+        let error = runner
+            .run("/nonexistent/emu-missing-tool", &[] as &[&str])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DeviceError>(),
+            Some(DeviceError::ToolMissing { tool }) if tool == "emu-missing-tool"
+        ));
+    }
+
+    #[test]
+    fn test_conflicting_port_is_parsed_from_bind_failure() {
+        assert_eq!(
+            conflicting_port(
+                "* daemon not running\nerror: cannot bind to 127.0.0.1:5037: Address already in use (98)"
+            ),
+            Some(5037)
+        );
+        assert_eq!(conflicting_port("error: device offline"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_streaming_splits_carriage_returns() {
+        let runner = CommandRunner::new();
+        let lines = std::sync::Mutex::new(Vec::new());
+        let output = runner
+            .run_streaming(
+                "sh",
+                &[
+                    "-c",
+                    "printf '[==  ] 50%% Loading\\r[====] 100%% Loading\\ndone'",
+                ],
+                &|line| lines.lock().unwrap().push(line.to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            vec!["[==  ] 50% Loading", "[====] 100% Loading", "done"]
+        );
+        assert!(output.ends_with("done"));
+
+        let result = runner
+            .run_streaming("sh", &["-c", "echo oops >&2; exit 3"], &|_| {})
+            .await;
+        assert!(result.unwrap_err().to_string().contains("exit code 3"));
+
+        let output = runner
+            .run_streaming_with_env(
+                "sh",
+                &["-c", "echo \"$EMU_TEST_GREETING\""],
+                &[("EMU_TEST_GREETING".to_string(), "hello".to_string())],
+                &|_| {},
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.trim(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_run_replaces_invalid_utf8() {
+        let runner = CommandRunner::new();
+        let output = runner
+            .run("sh", &["-c", "printf 'ok \\377 done\\n'"])
+            .await
+            .unwrap();
+        assert_eq!(output, "ok \u{FFFD} done\n");
+    }
+
+    #[test]
+    fn test_capped_output_counts_dropped_bytes() {
+        let mut output = CappedOutput::new(4);
+        output.push(b"abc");
+        output.push(b"def");
+        assert_eq!(output.bytes, b"abcd");
+        assert_eq!(output.dropped, 2);
+        assert_eq!(output.into_string(OsStr::new("sh")), "abcd");
+    }
+
+    #[tokio::test]
+    async fn test_lossy_lines_survive_bad_bytes_and_long_lines() {
+        let mut input = b"first\r\nbad \xff byte\n".to_vec();
+        input.extend(std::iter::repeat_n(b'x', MAX_OUTPUT_LINE_BYTES + 10));
+        input.extend_from_slice(b"\nlast");
+
+        let mut lines = LossyLines::new(input.as_slice());
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "first");
+        assert_eq!(
+            lines.next_line().await.unwrap().unwrap(),
+            "bad \u{FFFD} byte"
+        );
+        assert_eq!(
+            lines.next_line().await.unwrap().unwrap().len(),
+            MAX_OUTPUT_LINE_BYTES
+        );
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "last");
+        assert_eq!(lines.next_line().await.unwrap(), None);
+
+        let input = b"[=  ] 10% Downloading\r[== ] 20% Downloading\r\ndone\n";
+        let mut lines = LossyLines::new(input.as_slice()).split_on_carriage_return();
+        assert_eq!(
+            lines.next_line().await.unwrap().unwrap(),
+            "[=  ] 10% Downloading"
+        );
+        assert_eq!(
+            lines.next_line().await.unwrap().unwrap(),
+            "[== ] 20% Downloading"
+        );
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "");
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "done");
+    }
+
     #[tokio::test]
     async fn test_spawn_command() {
         let runner = CommandRunner::new();
@@ -409,6 +1017,34 @@ mod tests {
         assert!(duration.as_millis() >= 100);
     }
 
+    #[test]
+    fn test_format_command_line_quotes_special_arguments() {
+        let line = format_command_line(
+            "avdmanager",
+            [
+                "create",
+                "avd",
+                "-k",
+                "system-images;android-34;google_apis;x86_64",
+            ],
+        );
+        assert_eq!(
+            line,
+            "avdmanager create avd -k 'system-images;android-34;google_apis;x86_64'"
+        );
+        assert_eq!(
+            format_command_line("echo", ["it's", ""]),
+            "echo 'it'\\''s' ''"
+        );
+    }
+
+    #[test]
+    fn test_command_line_display_matches_format() {
+        let command = CommandLine::new("/sdk/emulator/emulator", ["-avd", "Pixel 7"]);
+        assert_eq!(command.arg_refs(), vec!["-avd", "Pixel 7"]);
+        assert_eq!(command.to_string(), "/sdk/emulator/emulator -avd 'Pixel 7'");
+    }
+
     #[test]
     fn test_command_runner_send_sync() {
         // Ensure CommandRunner is Send + Sync
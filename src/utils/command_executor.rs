@@ -4,8 +4,146 @@
 //! allowing for easy mocking in tests while maintaining the same behavior
 //! in production code.
 
+use crate::constants::{
+    messages::errors::COMMAND_CANCELLED,
+    timeouts::{
+        ADB_COMMAND_MAX_RETRIES, ADB_COMMAND_TIMEOUT, HEALTH_CHECK_TIMEOUT, INITIAL_RETRY_DELAY,
+        MAX_RETRY_DELAY, SDKMANAGER_NETWORK_MAX_RETRIES, SDKMANAGER_NETWORK_TIMEOUT,
+    },
+};
+use crate::models::DeviceError;
+use crate::utils::command::program_name;
 use anyhow::Result;
 use async_trait::async_trait;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// Timeout and retry settings for commands that are known to hang.
+///
+/// Each attempt is bounded by `timeout`; failed or timed-out attempts are
+/// retried up to `max_retries` times with exponential backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandPolicy {
+    /// Maximum duration of a single attempt (`None` waits indefinitely)
+    pub timeout: Option<Duration>,
+    /// Additional attempts after the first one fails
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled after each attempt
+    pub initial_backoff: Duration,
+    /// Upper bound for the retry delay
+    pub max_backoff: Duration,
+}
+
+impl CommandPolicy {
+    /// adb commands against an emulator that may still be booting.
+    pub const ADB_DEVICE: Self = Self::new()
+        .with_timeout(ADB_COMMAND_TIMEOUT)
+        .with_retries(ADB_COMMAND_MAX_RETRIES);
+
+    /// adb commands that shut a device down. A retry could hit an emulator
+    /// that is already going away, so they only time out.
+    pub const ADB_SHUTDOWN: Self = Self::new().with_timeout(ADB_COMMAND_TIMEOUT);
+
+    /// Health checks of running devices, which fail fast instead of retrying.
+    pub const HEALTH_CHECK: Self = Self::new().with_timeout(HEALTH_CHECK_TIMEOUT);
+
+    /// sdkmanager commands that fetch package lists over the network.
+    pub const SDKMANAGER_NETWORK: Self = Self::new()
+        .with_timeout(SDKMANAGER_NETWORK_TIMEOUT)
+        .with_retries(SDKMANAGER_NETWORK_MAX_RETRIES);
+
+    /// A policy without timeout or retries.
+    pub const fn new() -> Self {
+        Self {
+            timeout: None,
+            max_retries: 0,
+            initial_backoff: INITIAL_RETRY_DELAY,
+            max_backoff: MAX_RETRY_DELAY,
+        }
+    }
+
+    /// Sets the per-attempt timeout.
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of retries after the first attempt.
+    pub const fn with_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+impl Default for CommandPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cooperative cancellation shared between a running operation and the UI.
+///
+/// Futures wrapped with [`CancellationToken::run_until_cancelled`] are dropped
+/// when the token is cancelled; commands started through `CommandRunner` are
+/// killed when their future is dropped.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and wakes every waiting operation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// Returns true once `cancel` has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Completes when the token is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Runs `future` to completion unless the token is cancelled first.
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Result<F::Output> {
+        tokio::select! {
+            output = future => Ok(output),
+            _ = self.cancelled() => Err(anyhow::anyhow!(COMMAND_CANCELLED)),
+        }
+    }
+}
+
+/// What a process started with [`CommandExecutor::spawn_with_output`] reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessOutput {
+    /// A line printed on stdout or stderr
+    Line(String),
+    /// The process exited; `code` is `None` when it was killed by a signal
+    Exited { success: bool, code: Option<i32> },
+}
+
+/// Receives the output of a process started with
+/// [`CommandExecutor::spawn_with_output`], from a background task.
+pub type OutputHandler = Arc<dyn Fn(ProcessOutput) + Send + Sync>;
 
 /// Trait for executing external commands
 ///
@@ -19,6 +157,19 @@ pub trait CommandExecutor: Send + Sync {
     /// Spawn a command and return its process ID
     async fn spawn(&self, command: &std::path::Path, args: &[&str]) -> Result<u32>;
 
+    /// Spawn a command like [`spawn`](Self::spawn), passing what it prints
+    /// and its exit to `on_output` instead of discarding them. The default
+    /// discards the output.
+    async fn spawn_with_output(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        on_output: OutputHandler,
+    ) -> Result<u32> {
+        let _ = on_output;
+        self.spawn(command, args).await
+    }
+
     /// Execute a command with retry logic
     async fn run_with_retry(
         &self,
@@ -34,6 +185,71 @@ pub trait CommandExecutor: Send + Sync {
         args: &[&str],
         ignore_patterns: &[&str],
     ) -> Result<String>;
+
+    /// Execute a command, passing each line of stdout to `on_line` as it is
+    /// printed. The default feeds the lines once the command has finished.
+    async fn run_streaming(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        on_line: &(dyn for<'l> Fn(&'l str) + Send + Sync),
+    ) -> Result<String> {
+        let output = self.run(command, args).await?;
+        output
+            .split(['\n', '\r'])
+            .filter(|line| !line.trim().is_empty())
+            .for_each(|line| on_line(line.trim_end()));
+        Ok(output)
+    }
+
+    /// Execute a command like [`run_streaming`](Self::run_streaming) with
+    /// `envs` added to its environment. The default ignores `envs`.
+    async fn run_streaming_with_env(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        envs: &[(String, String)],
+        on_line: &(dyn for<'l> Fn(&'l str) + Send + Sync),
+    ) -> Result<String> {
+        let _ = envs;
+        self.run_streaming(command, args, on_line).await
+    }
+
+    /// Execute a command under a timeout and retry policy
+    async fn run_with_policy(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        policy: &CommandPolicy,
+    ) -> Result<String> {
+        let mut delay = policy.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            let result = match policy.timeout {
+                Some(timeout) => tokio::time::timeout(timeout, self.run(command, args))
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(DeviceError::timeout(program_name(command), timeout.as_secs()).into())
+                    }),
+                None => self.run(command, args).await,
+            };
+
+            match result {
+                Ok(output) => return Ok(output),
+                Err(e) if attempt >= policy.max_retries => return Err(e),
+                Err(e) => {
+                    attempt += 1;
+                    log::warn!(
+                        "Command failed (attempt {attempt}/{}), retrying after {delay:?}: {e}",
+                        policy.max_retries + 1
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = std::cmp::min(delay * 2, policy.max_backoff);
+                }
+            }
+        }
+    }
 }
 
 /// Implementation of CommandExecutor for the actual CommandRunner
@@ -47,6 +263,15 @@ impl CommandExecutor for crate::utils::command::CommandRunner {
         self.spawn(command, args).await
     }
 
+    async fn spawn_with_output(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        on_output: OutputHandler,
+    ) -> Result<u32> {
+        self.spawn_with_output(command, args, on_output).await
+    }
+
     async fn run_with_retry(
         &self,
         command: &std::path::Path,
@@ -65,6 +290,26 @@ impl CommandExecutor for crate::utils::command::CommandRunner {
         self.run_ignoring_errors(command, args, ignore_patterns)
             .await
     }
+
+    async fn run_streaming(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        on_line: &(dyn for<'l> Fn(&'l str) + Send + Sync),
+    ) -> Result<String> {
+        self.run_streaming(command, args, on_line).await
+    }
+
+    async fn run_streaming_with_env(
+        &self,
+        command: &std::path::Path,
+        args: &[&str],
+        envs: &[(String, String)],
+        on_line: &(dyn for<'l> Fn(&'l str) + Send + Sync),
+    ) -> Result<String> {
+        self.run_streaming_with_env(command, args, envs, on_line)
+            .await
+    }
 }
 
 pub mod mock {
@@ -74,11 +319,13 @@ pub mod mock {
 
     /// Mock implementation of CommandExecutor for testing
     type CallHistory = Arc<Mutex<Vec<(String, Vec<String>)>>>;
+    type SpawnOutputs = Arc<Mutex<HashMap<String, (String, bool)>>>;
 
     #[derive(Clone)]
     pub struct MockCommandExecutor {
         responses: Arc<Mutex<HashMap<String, Result<String, String>>>>,
         spawn_responses: Arc<Mutex<HashMap<String, u32>>>,
+        spawn_outputs: SpawnOutputs,
         call_history: CallHistory,
     }
 
@@ -88,6 +335,7 @@ pub mod mock {
             Self {
                 responses: Arc::new(Mutex::new(HashMap::new())),
                 spawn_responses: Arc::new(Mutex::new(HashMap::new())),
+                spawn_outputs: Arc::new(Mutex::new(HashMap::new())),
                 call_history: Arc::new(Mutex::new(Vec::new())),
             }
         }
@@ -117,6 +365,23 @@ pub mod mock {
             self
         }
 
+        /// Output a spawned command prints before exiting, replayed by
+        /// `spawn_with_output`; needs a spawn response for the same command
+        pub fn with_spawn_output(
+            self,
+            command: &str,
+            args: &[&str],
+            output: &str,
+            success: bool,
+        ) -> Self {
+            let key = format!("{} {}", command, args.join(" "));
+            self.spawn_outputs
+                .lock()
+                .unwrap()
+                .insert(key, (output.to_string(), success));
+            self
+        }
+
         /// Get the call history
         pub fn call_history(&self) -> Vec<(String, Vec<String>)> {
             self.call_history.lock().unwrap().clone()
@@ -199,6 +464,31 @@ pub mod mock {
                 .ok_or_else(|| anyhow::anyhow!("No mock spawn response for: {key}"))
         }
 
+        async fn spawn_with_output(
+            &self,
+            command: &std::path::Path,
+            args: &[&str],
+            on_output: OutputHandler,
+        ) -> Result<u32> {
+            let pid = self.spawn(command, args).await?;
+            let command_basename = command
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            let key = format!("{} {}", command_basename, args.join(" "));
+            let output = self.spawn_outputs.lock().unwrap().get(&key).cloned();
+            if let Some((output, success)) = output {
+                for line in output.lines() {
+                    on_output(ProcessOutput::Line(line.to_string()));
+                }
+                on_output(ProcessOutput::Exited {
+                    success,
+                    code: Some(if success { 0 } else { 1 }),
+                });
+            }
+            Ok(pid)
+        }
+
         async fn run_with_retry(
             &self,
             command: &std::path::Path,
@@ -280,4 +570,53 @@ mod tests {
             assert_eq!(history.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn test_run_with_policy_retries_until_exhausted() {
+        let executor = MockCommandExecutor::new().with_error("adb", &["devices"], "offline");
+        let policy = CommandPolicy::new()
+            .with_retries(2)
+            .with_timeout(Duration::from_secs(1));
+        let policy = CommandPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..policy
+        };
+
+        let result = executor
+            .run_with_policy(std::path::Path::new("adb"), &["devices"], &policy)
+            .await;
+        assert!(result.is_err());
+        assert_eq!(executor.call_history().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_policy_success_does_not_retry() {
+        let executor = MockCommandExecutor::new().with_success("adb", &["devices"], "ok");
+
+        let result = executor
+            .run_with_policy(
+                std::path::Path::new("adb"),
+                &["devices"],
+                &CommandPolicy::ADB_DEVICE,
+            )
+            .await;
+        assert_eq!(result.unwrap(), "ok");
+        assert_eq!(executor.call_history().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_token_stops_pending_future() {
+        let token = CancellationToken::new();
+        let waiter = token.clone();
+        let handle = tokio::spawn(async move {
+            waiter
+                .run_until_cancelled(std::future::pending::<()>())
+                .await
+        });
+
+        token.cancel();
+        let result = handle.await.unwrap();
+        assert!(token.is_cancelled());
+        assert_eq!(result.unwrap_err().to_string(), COMMAND_CANCELLED);
+    }
 }
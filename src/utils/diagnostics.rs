@@ -0,0 +1,206 @@
+//! In-app diagnostics log
+//!
+//! emu's own `log::` records are kept in a bounded in-memory ring buffer so
+//! they can be viewed from inside the TUI (F12) and attached to bug reports
+//! without restarting with `--debug`. With `--debug` the records are also
+//! written to the console through env_logger as before.
+
+use crate::constants::limits::MAX_DIAGNOSTIC_ENTRIES;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+/// Most verbose level kept in the ring buffer regardless of `--log-level`.
+const CAPTURE_LEVEL: LevelFilter = LevelFilter::Debug;
+
+static BUFFER: OnceLock<Mutex<DiagnosticBuffer>> = OnceLock::new();
+
+/// A single captured log record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticEntry {
+    /// When the record was logged
+    pub timestamp: chrono::DateTime<chrono::Local>,
+    pub level: Level,
+    /// Module that logged the record (e.g. `emu::managers::android`)
+    pub target: String,
+    pub message: String,
+}
+
+impl DiagnosticEntry {
+    /// One line as shown in the viewer and pasted into bug reports.
+    pub fn to_log_line(&self) -> String {
+        format!(
+            "{} {:<5} {}: {}",
+            self.timestamp.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Fixed-capacity buffer that drops the oldest entry when full.
+#[derive(Debug)]
+struct DiagnosticBuffer {
+    entries: VecDeque<DiagnosticEntry>,
+    capacity: usize,
+}
+
+impl DiagnosticBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn push(&mut self, entry: DiagnosticEntry) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+fn buffer() -> &'static Mutex<DiagnosticBuffer> {
+    BUFFER.get_or_init(|| Mutex::new(DiagnosticBuffer::new(MAX_DIAGNOSTIC_ENTRIES)))
+}
+
+/// Only emu's own records are captured; dependencies are too noisy at debug.
+fn is_emu_target(target: &str) -> bool {
+    target == "emu" || target.starts_with("emu::")
+}
+
+/// Logger that feeds the ring buffer and optionally forwards to env_logger.
+struct DiagnosticLogger {
+    console: Option<env_logger::Logger>,
+}
+
+impl Log for DiagnosticLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        (metadata.level() <= CAPTURE_LEVEL && is_emu_target(metadata.target()))
+            || self
+                .console
+                .as_ref()
+                .is_some_and(|console| console.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= CAPTURE_LEVEL && is_emu_target(record.target()) {
+            record_entry(DiagnosticEntry {
+                timestamp: chrono::Local::now(),
+                level: record.level(),
+                target: record.target().to_string(),
+                message: record.args().to_string(),
+            });
+        }
+        if let Some(console) = &self.console {
+            if console.matches(record) {
+                console.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(console) = &self.console {
+            console.flush();
+        }
+    }
+}
+
+/// Installs the global logger. With `console_level` set (the `--debug` flag),
+/// records at or above that level are also printed through env_logger,
+/// which still honors `RUST_LOG`.
+pub fn init(console_level: Option<&str>) -> Result<(), log::SetLoggerError> {
+    let console = console_level.map(|level| {
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(level))
+            .format_timestamp_secs()
+            .build()
+    });
+    let max_level = console
+        .as_ref()
+        .map_or(CAPTURE_LEVEL, |console| console.filter().max(CAPTURE_LEVEL));
+
+    log::set_boxed_logger(Box::new(DiagnosticLogger { console }))?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+fn record_entry(entry: DiagnosticEntry) {
+    if let Ok(mut buffer) = buffer().lock() {
+        buffer.push(entry);
+    }
+}
+
+/// Captured records, newest first.
+pub fn recent_entries() -> Vec<DiagnosticEntry> {
+    buffer()
+        .lock()
+        .map(|buffer| buffer.entries.iter().rev().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str) -> DiagnosticEntry {
+        DiagnosticEntry {
+            timestamp: chrono::Local::now(),
+            level: Level::Info,
+            target: "emu::app".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest_entries_when_full() {
+        let mut buffer = DiagnosticBuffer::new(2);
+        buffer.push(entry("first"));
+        buffer.push(entry("second"));
+        buffer.push(entry("third"));
+
+        let messages: Vec<_> = buffer.entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["second", "third"]);
+    }
+
+    #[test]
+    fn test_only_emu_targets_are_captured() {
+        assert!(is_emu_target("emu"));
+        assert!(is_emu_target("emu::managers::android"));
+        assert!(!is_emu_target("emulator"));
+        assert!(!is_emu_target("tokio::runtime"));
+    }
+
+    #[test]
+    fn test_logger_captures_emu_records_without_console() {
+        let logger = DiagnosticLogger { console: None };
+        logger.log(
+            &Record::builder()
+                .level(Level::Debug)
+                .target("emu::tests")
+                .args(format_args!("captured diagnostic"))
+                .build(),
+        );
+        logger.log(
+            &Record::builder()
+                .level(Level::Trace)
+                .target("emu::tests")
+                .args(format_args!("too verbose diagnostic"))
+                .build(),
+        );
+
+        let entries = recent_entries();
+        assert!(entries.iter().any(|e| e.message == "captured diagnostic"));
+        assert!(!entries
+            .iter()
+            .any(|e| e.message == "too verbose diagnostic"));
+    }
+
+    #[test]
+    fn test_log_line_includes_level_target_and_message() {
+        let line = entry("device list loaded").to_log_line();
+        assert!(line.contains("INFO"));
+        assert!(line.contains("emu::app: device list loaded"));
+    }
+}
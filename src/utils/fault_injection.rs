@@ -0,0 +1,355 @@
+//! Fault injection for command execution.
+//!
+//! [`FaultyCommandExecutor`] wraps another [`CommandExecutor`] and corrupts
+//! selected calls the way real SDK tools misbehave: commands that hang,
+//! output that stops mid-line, and bytes that are not valid UTF-8. Parsers
+//! and error paths can then be tested against those failures without a
+//! broken SDK.
+//!
+//! ```no_run
+//! use emu::utils::command_executor::mock::MockCommandExecutor;
+//! use emu::utils::fault_injection::{Fault, FaultyCommandExecutor};
+//!
+//! let executor = FaultyCommandExecutor::new(
+//!     MockCommandExecutor::new().with_success("adb", &["devices"], "List of devices attached\n"),
+//! )
+//! .with_fault("adb", &["devices"], Fault::NonUtf8);
+//! ```
+
+use crate::constants::messages::errors::COMMAND_TIMED_OUT;
+use crate::utils::command_executor::{CommandExecutor, OutputHandler};
+use anyhow::Result;
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::future::Future;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Bytes that never form valid UTF-8: a stray continuation byte and a
+/// truncated two-byte sequence.
+const INVALID_UTF8: &[u8] = &[0x80, 0xC3];
+
+/// A way a command can misbehave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The command does not answer for the given time and then fails with
+    /// a timeout error. Callers with a shorter timeout give up first.
+    Hang(Duration),
+    /// Only the first half of the output arrives, cut off mid-line.
+    PartialOutput,
+    /// Every line carries bytes that are not valid UTF-8, as the real
+    /// runner decodes them (U+FFFD replacement characters).
+    NonUtf8,
+}
+
+impl Fault {
+    /// Every fault `with_chaos` picks from.
+    const CHAOS: [Fault; 3] = [
+        Fault::Hang(Duration::from_millis(50)),
+        Fault::PartialOutput,
+        Fault::NonUtf8,
+    ];
+
+    /// Applies an output fault to a successful result.
+    fn corrupt(self, output: String) -> String {
+        match self {
+            Fault::Hang(_) => output,
+            Fault::PartialOutput => truncate_mid_line(&output),
+            Fault::NonUtf8 => inject_invalid_utf8(&output),
+        }
+    }
+}
+
+/// A fault applied to calls of one command.
+#[derive(Debug, Clone)]
+struct FaultRule {
+    /// Basename of the program, e.g. `adb`
+    command: String,
+    /// Leading arguments the call must start with
+    args: Vec<String>,
+    fault: Fault,
+}
+
+impl FaultRule {
+    fn matches(&self, command: &Path, args: &[&str]) -> bool {
+        let basename = command
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_else(|| command.to_string_lossy());
+        basename == self.command.as_str()
+            && args.len() >= self.args.len()
+            && self.args.iter().zip(args).all(|(rule, arg)| rule == arg)
+    }
+}
+
+/// Random faults on any command, reproducible through the seed.
+struct Chaos {
+    rate: f64,
+    rng: StdRng,
+}
+
+/// Decorates a [`CommandExecutor`] with injected faults.
+///
+/// Faults registered with [`with_fault`](Self::with_fault) apply to every
+/// matching call; [`with_chaos`](Self::with_chaos) additionally hits random
+/// calls. Calls without a fault are passed through unchanged.
+#[derive(Clone)]
+pub struct FaultyCommandExecutor<E> {
+    inner: E,
+    rules: Vec<FaultRule>,
+    chaos: Option<Arc<Mutex<Chaos>>>,
+    injected: Arc<Mutex<Vec<(String, Fault)>>>,
+}
+
+impl<E: CommandExecutor> FaultyCommandExecutor<E> {
+    /// Wraps `inner` without any faults.
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            rules: Vec::new(),
+            chaos: None,
+            injected: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Injects `fault` into calls of `command` (a program basename) whose
+    /// arguments start with `args`. Pass no arguments to hit every call.
+    /// The first matching rule wins.
+    pub fn with_fault(mut self, command: &str, args: &[&str], fault: Fault) -> Self {
+        self.rules.push(FaultRule {
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            fault,
+        });
+        self
+    }
+
+    /// Injects a random fault into calls without a rule, each with
+    /// probability `rate` (0.0 - 1.0). The same seed picks the same faults.
+    pub fn with_chaos(mut self, rate: f64, seed: u64) -> Self {
+        self.chaos = Some(Arc::new(Mutex::new(Chaos {
+            rate: rate.clamp(0.0, 1.0),
+            rng: StdRng::seed_from_u64(seed),
+        })));
+        self
+    }
+
+    /// The calls a fault was injected into, as `command args` and fault.
+    pub fn injected_faults(&self) -> Vec<(String, Fault)> {
+        self.injected.lock().unwrap().clone()
+    }
+
+    /// The executor being decorated.
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Picks the fault for a call, if any, and records it.
+    fn fault_for(&self, command: &Path, args: &[&str]) -> Option<Fault> {
+        let fault = self
+            .rules
+            .iter()
+            .find(|rule| rule.matches(command, args))
+            .map(|rule| rule.fault)
+            .or_else(|| {
+                let mut chaos = self.chaos.as_ref()?.lock().unwrap();
+                let rate = chaos.rate;
+                if chaos.rng.gen_bool(rate) {
+                    let index = chaos.rng.gen_range(0..Fault::CHAOS.len());
+                    Some(Fault::CHAOS[index])
+                } else {
+                    None
+                }
+            })?;
+
+        let call = format!("{} {}", command.display(), args.join(" "));
+        self.injected.lock().unwrap().push((call, fault));
+        Some(fault)
+    }
+
+    /// Runs `call` with the fault for this command applied.
+    async fn inject<F>(&self, command: &Path, args: &[&str], call: F) -> Result<String>
+    where
+        F: Future<Output = Result<String>>,
+    {
+        match self.fault_for(command, args) {
+            Some(Fault::Hang(duration)) => {
+                tokio::time::sleep(duration).await;
+                Err(anyhow::anyhow!(
+                    COMMAND_TIMED_OUT.replace("{}", &duration.as_secs().to_string())
+                ))
+            }
+            Some(fault) => call.await.map(|output| fault.corrupt(output)),
+            None => call.await,
+        }
+    }
+}
+
+#[async_trait]
+impl<E: CommandExecutor> CommandExecutor for FaultyCommandExecutor<E> {
+    async fn run(&self, command: &Path, args: &[&str]) -> Result<String> {
+        self.inject(command, args, self.inner.run(command, args))
+            .await
+    }
+
+    async fn spawn(&self, command: &Path, args: &[&str]) -> Result<u32> {
+        if let Some(Fault::Hang(duration)) = self.fault_for(command, args) {
+            tokio::time::sleep(duration).await;
+            anyhow::bail!(COMMAND_TIMED_OUT.replace("{}", &duration.as_secs().to_string()));
+        }
+        self.inner.spawn(command, args).await
+    }
+
+    async fn spawn_with_output(
+        &self,
+        command: &Path,
+        args: &[&str],
+        on_output: OutputHandler,
+    ) -> Result<u32> {
+        if let Some(Fault::Hang(duration)) = self.fault_for(command, args) {
+            tokio::time::sleep(duration).await;
+            anyhow::bail!(COMMAND_TIMED_OUT.replace("{}", &duration.as_secs().to_string()));
+        }
+        self.inner.spawn_with_output(command, args, on_output).await
+    }
+
+    async fn run_with_retry(&self, command: &Path, args: &[&str], retries: u32) -> Result<String> {
+        self.inject(
+            command,
+            args,
+            self.inner.run_with_retry(command, args, retries),
+        )
+        .await
+    }
+
+    async fn run_ignoring_errors(
+        &self,
+        command: &Path,
+        args: &[&str],
+        ignore_patterns: &[&str],
+    ) -> Result<String> {
+        self.inject(
+            command,
+            args,
+            self.inner
+                .run_ignoring_errors(command, args, ignore_patterns),
+        )
+        .await
+    }
+}
+
+/// Keeps the first half of `output`, ending inside a line when possible.
+fn truncate_mid_line(output: &str) -> String {
+    let mut end = output.len() / 2;
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    let mut truncated = &output[..end];
+    if truncated.ends_with('\n') {
+        truncated = &truncated[..truncated.len() - 1];
+    }
+    truncated.to_string()
+}
+
+/// Puts invalid UTF-8 into the middle of every non-empty line and decodes
+/// the result lossily, like `CommandRunner` does.
+fn inject_invalid_utf8(output: &str) -> String {
+    let mut bytes = Vec::with_capacity(output.len() + INVALID_UTF8.len());
+    for line in output.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n');
+        let mut middle = content.len() / 2;
+        while !content.is_char_boundary(middle) {
+            middle -= 1;
+        }
+        bytes.extend_from_slice(&line.as_bytes()[..middle]);
+        if !content.is_empty() {
+            bytes.extend_from_slice(INVALID_UTF8);
+        }
+        bytes.extend_from_slice(&line.as_bytes()[middle..]);
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::command_executor::{mock::MockCommandExecutor, CommandPolicy};
+
+    const ADB_DEVICES: &str = "List of devices attached\nemulator-5554\tdevice\n";
+
+    fn adb() -> MockCommandExecutor {
+        MockCommandExecutor::new().with_success("adb", &["devices"], ADB_DEVICES)
+    }
+
+    #[tokio::test]
+    async fn test_calls_without_faults_pass_through() {
+        let executor =
+            FaultyCommandExecutor::new(adb()).with_fault("adb", &["shell"], Fault::PartialOutput);
+
+        let output = executor.run(Path::new("adb"), &["devices"]).await.unwrap();
+        assert_eq!(output, ADB_DEVICES);
+        assert!(executor.injected_faults().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_partial_output_stops_mid_line() {
+        let executor =
+            FaultyCommandExecutor::new(adb()).with_fault("adb", &[], Fault::PartialOutput);
+
+        let output = executor
+            .run(Path::new("/sdk/platform-tools/adb"), &["devices"])
+            .await
+            .unwrap();
+        assert!(ADB_DEVICES.starts_with(&output));
+        assert!(output.len() < ADB_DEVICES.len());
+        assert!(!output.ends_with('\n'));
+    }
+
+    #[tokio::test]
+    async fn test_non_utf8_output_is_decoded_lossily() {
+        let executor = FaultyCommandExecutor::new(adb()).with_fault("adb", &[], Fault::NonUtf8);
+
+        let output = executor.run(Path::new("adb"), &["devices"]).await.unwrap();
+        assert_eq!(output.lines().count(), 2);
+        assert!(output
+            .lines()
+            .all(|line| line.contains(char::REPLACEMENT_CHARACTER)));
+    }
+
+    #[tokio::test]
+    async fn test_hang_is_cut_short_by_policy_timeout() {
+        let executor = FaultyCommandExecutor::new(adb()).with_fault(
+            "adb",
+            &["devices"],
+            Fault::Hang(Duration::from_secs(60)),
+        );
+        let policy = CommandPolicy::new().with_timeout(Duration::from_millis(20));
+
+        let error = executor
+            .run_with_policy(Path::new("adb"), &["devices"], &policy)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_chaos_is_reproducible_with_the_same_seed() {
+        async fn faults(seed: u64) -> Vec<Fault> {
+            let executor = FaultyCommandExecutor::new(adb()).with_chaos(0.5, seed);
+            for _ in 0..20 {
+                let _ = executor.run(Path::new("adb"), &["devices"]).await;
+            }
+            executor
+                .injected_faults()
+                .into_iter()
+                .map(|(_, fault)| fault)
+                .collect()
+        }
+
+        let first = faults(7).await;
+        assert!(!first.is_empty() && first.len() < 20);
+        assert_eq!(first, faults(7).await);
+    }
+}
@@ -0,0 +1,78 @@
+//! Host machine information.
+//!
+//! Used to warn when a device configuration asks for more resources than the
+//! machine running the emulator actually has.
+
+use crate::constants::numeric::BYTES_PER_KB;
+use std::sync::OnceLock;
+
+static TOTAL_MEMORY_MB: OnceLock<Option<u32>> = OnceLock::new();
+
+/// Returns the total physical memory of the host in MB.
+///
+/// The value is detected once and cached for the lifetime of the process.
+/// Returns `None` when the platform is unsupported or detection fails.
+/// Detection may run `sysctl`, so the TUI calls this once at startup off
+/// the render path and reads [`cached_total_memory_mb`] afterwards.
+pub fn total_memory_mb() -> Option<u32> {
+    *TOTAL_MEMORY_MB.get_or_init(detect_total_memory_mb)
+}
+
+/// The total memory detected by [`total_memory_mb`], or `None` when it has
+/// not been detected yet. Never blocks.
+pub fn cached_total_memory_mb() -> Option<u32> {
+    TOTAL_MEMORY_MB.get().copied().flatten()
+}
+
+#[cfg(target_os = "linux")]
+fn detect_total_memory_mb() -> Option<u32> {
+    let meminfo = std::fs::read_to_string(crate::constants::files::PROC_MEMINFO).ok()?;
+    parse_meminfo_total_mb(&meminfo)
+}
+
+#[cfg(target_os = "macos")]
+fn detect_total_memory_mb() -> Option<u32> {
+    use crate::constants::{commands, numeric::BYTES_PER_MB};
+
+    let output = std::process::Command::new(commands::SYSCTL)
+        .args([commands::sysctl::VALUE_ONLY, commands::sysctl::MEMSIZE])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let bytes: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    u32::try_from(bytes / BYTES_PER_MB).ok()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_total_memory_mb() -> Option<u32> {
+    None
+}
+
+/// Parses the `MemTotal` line of `/proc/meminfo` (reported in kB) into MB.
+pub fn parse_meminfo_total_mb(meminfo: &str) -> Option<u32> {
+    let line = meminfo.lines().find(|line| line.starts_with("MemTotal:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    u32::try_from(kilobytes / BYTES_PER_KB).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo_total_mb() {
+        let meminfo = "MemTotal:       16318480 kB\nMemFree:         1234567 kB\n";
+        assert_eq!(parse_meminfo_total_mb(meminfo), Some(15936));
+    }
+
+    #[test]
+    fn test_parse_meminfo_total_mb_missing_line() {
+        assert_eq!(parse_meminfo_total_mb("MemFree: 1024 kB\n"), None);
+        assert_eq!(parse_meminfo_total_mb("MemTotal: abc kB\n"), None);
+    }
+}
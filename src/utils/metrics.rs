@@ -0,0 +1,184 @@
+//! Timing metrics
+//!
+//! Frame rendering, state lock waits, device refreshes, and external commands
+//! are timed into fixed-bucket histograms. The perf overlay (Shift+P) shows
+//! them so slow frames can be told apart from slow SDK tools when users
+//! report the UI feeling sluggish.
+
+use crate::constants::performance::METRIC_BUCKET_BOUNDS_MS;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// What a timing measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Drawing one frame
+    Frame,
+    /// Waiting for the app state lock before drawing
+    LockWait,
+    /// A device list refresh, manual or automatic
+    Refresh,
+    /// An external command run to completion
+    Command,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [
+        Metric::Frame,
+        Metric::LockWait,
+        Metric::Refresh,
+        Metric::Command,
+    ];
+
+    /// Short label used in the perf overlay.
+    pub fn label(self) -> &'static str {
+        match self {
+            Metric::Frame => "frame",
+            Metric::LockWait => "lock",
+            Metric::Refresh => "refresh",
+            Metric::Command => "command",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Counts of durations per bucket, plus the last and largest values.
+/// The final bucket holds everything above the largest bound.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    buckets: [u64; METRIC_BUCKET_BOUNDS_MS.len() + 1],
+    count: u64,
+    last: Option<Duration>,
+    max: Duration,
+}
+
+impl Histogram {
+    pub fn record(&mut self, duration: Duration) {
+        let millis = duration.as_millis();
+        let bucket = METRIC_BUCKET_BOUNDS_MS
+            .iter()
+            .position(|&bound| millis < u128::from(bound))
+            .unwrap_or(METRIC_BUCKET_BOUNDS_MS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.last = Some(duration);
+        self.max = self.max.max(duration);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn last(&self) -> Option<Duration> {
+        self.last
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Upper bound of the bucket containing the given percentile (0.0-1.0),
+    /// capped at the largest value seen. `None` until something is recorded.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = ((self.count as f64 * percentile).ceil() as u64).clamp(1, self.count);
+        let mut seen = 0;
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(match METRIC_BUCKET_BOUNDS_MS.get(index) {
+                    Some(&bound) => Duration::from_millis(bound).min(self.max),
+                    None => self.max,
+                });
+            }
+        }
+        Some(self.max)
+    }
+}
+
+static HISTOGRAMS: OnceLock<Mutex<[Histogram; 4]>> = OnceLock::new();
+
+fn histograms() -> &'static Mutex<[Histogram; 4]> {
+    HISTOGRAMS.get_or_init(Mutex::default)
+}
+
+/// Records one timing.
+pub fn record(metric: Metric, duration: Duration) {
+    if let Ok(mut histograms) = histograms().lock() {
+        histograms[metric.index()].record(duration);
+    }
+}
+
+/// Current histogram of a metric.
+pub fn snapshot(metric: Metric) -> Histogram {
+    histograms()
+        .lock()
+        .map(|histograms| histograms[metric.index()].clone())
+        .unwrap_or_default()
+}
+
+/// Formats a duration compactly for the overlay ("0.4ms", "12ms", "1.5s").
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_secs_f64() * 1000.0;
+    if millis < 10.0 {
+        format!("{millis:.1}ms")
+    } else if millis < 1000.0 {
+        format!("{millis:.0}ms")
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_tracks_last_max_and_count() {
+        let mut histogram = Histogram::default();
+        assert_eq!(histogram.percentile(0.5), None);
+
+        histogram.record(Duration::from_millis(3));
+        histogram.record(Duration::from_millis(40));
+        histogram.record(Duration::from_millis(5));
+
+        assert_eq!(histogram.count(), 3);
+        assert_eq!(histogram.last(), Some(Duration::from_millis(5)));
+        assert_eq!(histogram.max(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_percentile_reports_bucket_upper_bound() {
+        let mut histogram = Histogram::default();
+        for _ in 0..95 {
+            histogram.record(Duration::from_millis(3));
+        }
+        for _ in 0..5 {
+            histogram.record(Duration::from_millis(300));
+        }
+
+        assert_eq!(histogram.percentile(0.5), Some(Duration::from_millis(4)));
+        assert_eq!(histogram.percentile(0.95), Some(Duration::from_millis(4)));
+        assert_eq!(histogram.percentile(0.99), Some(Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_percentile_above_largest_bound_uses_max() {
+        let mut histogram = Histogram::default();
+        histogram.record(Duration::from_secs(30));
+
+        assert_eq!(histogram.percentile(0.95), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(400)), "0.4ms");
+        assert_eq!(format_duration(Duration::from_millis(12)), "12ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1.5s");
+    }
+}
@@ -5,20 +5,54 @@
 //!
 //! # Module Organization
 //!
+//! - `audit` - Record of executed commands for the history view and audit log
+//! - `boot_wait` - Booting a device and waiting for boot completion from scripts
 //! - `command` - Command execution wrapper with consistent error handling
 //! - `command_executor` - Trait-based abstraction for command execution (testability)
+//! - `diagnostics` - In-memory ring buffer of emu's own log records
+//! - `fault_injection` - Command executor decorator that simulates misbehaving SDK tools (tests only)
+//! - `host` - Host machine information such as total memory
 //! - `logger` - Application logging setup and configuration
+//! - `metrics` - Timing histograms for the perf overlay
+//! - `network` - Reachability of the SDK repository for offline mode
+//! - `processes` - Supervision of helper processes that must not outlive emu
+//! - `refresh_coordinator` - Coalescing of concurrent device list refreshes
+//! - `report` - Sanitized bug report bundles for GitHub issues
+//! - `startup_profile` - Per-phase startup timings for `--profile-startup`
+//! - `summary` - Plain-text and JSON device summaries for non-TTY output
+//! - `tasks` - Registry of background tasks cancelled when emu quits
+//! - `text` - Display-width-aware truncation and padding
+//! - `tool_paths` - SDK tool locations configured in `.emu.toml`
 //! - `validation` - Form field validation framework
+//! - `xcode` - Active Xcode detection and per-session `DEVELOPER_DIR` switching
 
+pub mod audit;
+pub mod boot_wait;
 pub mod cache;
 pub mod command;
 pub mod command_executor;
+pub mod diagnostics;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod fault_injection;
+pub mod host;
 pub mod logger;
+pub mod metrics;
+pub mod network;
+pub mod processes;
+pub mod refresh_coordinator;
+pub mod report;
+pub mod startup_profile;
+pub mod summary;
+pub mod tasks;
+pub mod text;
+pub mod tool_paths;
 pub mod validation;
+pub mod xcode;
 
 // Re-export commonly used utilities
-pub use cache::ApiLevelCache;
-pub use command::CommandRunner;
+pub use cache::{AndroidVersionNameCache, ApiLevelCache, DeviceInventoryCache};
+pub use command::{CommandLine, CommandRunner, LossyLines};
 pub use command_executor::CommandExecutor;
 pub use logger::setup_logger;
+pub use summary::{DeviceSummary, SummaryFormat};
 pub use validation::{DeviceNameValidator, FieldValidator, NumericRangeValidator};
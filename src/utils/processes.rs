@@ -0,0 +1,160 @@
+//! Child process supervision
+//!
+//! Helper processes that only make sense while emu runs, such as logcat, the
+//! iOS log stream, `adb track-devices` and the SDK commands emu waits on, are
+//! spawned through [`spawn`]. They are registered under their PID until the
+//! returned [`SupervisedChild`] is dropped, which kills a process that is
+//! still running and reaps one that has exited. Whatever is still
+//! registered when emu exits or panics is killed by [`kill_all`], so no
+//! `adb logcat` is left behind after switching devices or a crash.
+//!
+//! Emulators, simulators and scrcpy windows are not supervised: they are
+//! meant to keep running after emu quits, or are stopped on their own.
+
+use crate::constants::commands::KILL_PROCESS;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::process::{Child, Command};
+
+static SUPERVISOR: OnceLock<ProcessSupervisor> = OnceLock::new();
+
+type Registry = Arc<Mutex<HashMap<u32, String>>>;
+
+/// PIDs of the running child processes, with a label for each.
+#[derive(Clone, Default)]
+pub struct ProcessSupervisor {
+    children: Registry,
+}
+
+impl ProcessSupervisor {
+    /// Spawns `command` and registers the process until the returned child
+    /// is dropped.
+    pub fn spawn(&self, command: &mut Command, label: &str) -> std::io::Result<SupervisedChild> {
+        let child = command.kill_on_drop(true).spawn()?;
+        let pid = child.id();
+        if let Some(pid) = pid {
+            self.children.lock().unwrap().insert(pid, label.to_string());
+        }
+        Ok(SupervisedChild {
+            child,
+            pid,
+            registry: Arc::clone(&self.children),
+        })
+    }
+
+    /// Labels of the registered processes by PID.
+    pub fn running(&self) -> HashMap<u32, String> {
+        self.children.lock().unwrap().clone()
+    }
+
+    /// Kills every registered process. Blocking, so it also works from a
+    /// panic hook. Returns how many processes were signalled.
+    pub fn kill_all(&self) -> usize {
+        let children = std::mem::take(&mut *self.children.lock().unwrap());
+        if children.is_empty() {
+            return 0;
+        }
+        for (pid, label) in &children {
+            log::debug!("Killing leftover {label} (process {pid})");
+        }
+        let _ = std::process::Command::new(KILL_PROCESS)
+            .args(children.keys().map(|pid| pid.to_string()))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        children.len()
+    }
+}
+
+/// A child process that is killed, if still running, and reaped when
+/// dropped.
+pub struct SupervisedChild {
+    child: Child,
+    pid: Option<u32>,
+    registry: Registry,
+}
+
+impl Deref for SupervisedChild {
+    type Target = Child;
+
+    fn deref(&self) -> &Child {
+        &self.child
+    }
+}
+
+impl DerefMut for SupervisedChild {
+    fn deref_mut(&mut self) -> &mut Child {
+        &mut self.child
+    }
+}
+
+impl Drop for SupervisedChild {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            self.registry.lock().unwrap().remove(&pid);
+        }
+        // Reaps an exited process now; a running one is killed on drop and
+        // reaped by the runtime
+        let _ = self.child.try_wait();
+    }
+}
+
+fn supervisor() -> &'static ProcessSupervisor {
+    SUPERVISOR.get_or_init(ProcessSupervisor::default)
+}
+
+/// Spawns a helper process that must not outlive emu.
+pub fn spawn(command: &mut Command, label: &str) -> std::io::Result<SupervisedChild> {
+    supervisor().spawn(command, label)
+}
+
+/// Kills the helper processes that are still running, e.g. when emu exits.
+pub fn kill_all() -> usize {
+    supervisor().kill_all()
+}
+
+/// Makes a panic of the main thread kill the helper processes before the
+/// previous panic hook reports it. A panicking background task is caught by
+/// tokio and emu keeps running, so its helpers are left alone.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") {
+            kill_all();
+        }
+        previous(info);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_supervised_children_are_tracked_and_killed() {
+        let supervisor = ProcessSupervisor::default();
+
+        let mut finished = supervisor
+            .spawn(Command::new("true").stdout(Stdio::null()), "true")
+            .unwrap();
+        finished.wait().await.unwrap();
+        drop(finished);
+        assert!(supervisor.running().is_empty());
+
+        let mut sleeper = supervisor
+            .spawn(Command::new("sleep").arg("30"), "sleep")
+            .unwrap();
+        let pid = sleeper.id().unwrap();
+        assert_eq!(
+            supervisor.running().get(&pid).map(String::as_str),
+            Some("sleep")
+        );
+
+        assert_eq!(supervisor.kill_all(), 1);
+        assert!(!sleeper.wait().await.unwrap().success());
+        assert!(supervisor.running().is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+//! Coalescing of concurrent device list refreshes
+//!
+//! Several parts of the app list devices on their own schedule: auto-refresh,
+//! the status check scheduled after a start or stop, the create and delete
+//! flows, and the details loader. After a batch of operations they used to
+//! fire at once and run the same `avdmanager`/`simctl` listing several times
+//! in parallel. A `RefreshCoordinator` lets the first caller run the refresh
+//! while later callers subscribe to that in-flight run and share its result.
+
+use anyhow::Result;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+type SharedRefresh<T> = Shared<BoxFuture<'static, Result<T, Arc<anyhow::Error>>>>;
+
+/// Runs at most one refresh at a time; callers arriving while one is in
+/// flight wait for it instead of starting another.
+pub struct RefreshCoordinator<T> {
+    in_flight: Mutex<Option<SharedRefresh<T>>>,
+}
+
+impl<T> Default for RefreshCoordinator<T> {
+    fn default() -> Self {
+        Self {
+            in_flight: Mutex::new(None),
+        }
+    }
+}
+
+impl<T> RefreshCoordinator<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the result of the in-flight refresh, or starts `refresh` when
+    /// none is running. The last caller to finish receives the original
+    /// error; the others receive a [`SharedRefreshError`] caused by it.
+    pub async fn run<F, Fut>(&self, refresh: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        let shared = {
+            let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            match in_flight.as_ref() {
+                Some(shared) => {
+                    log::debug!("Joining in-flight device refresh");
+                    shared.clone()
+                }
+                None => {
+                    let shared = refresh()
+                        .map(|result| result.map_err(Arc::new))
+                        .boxed()
+                        .shared();
+                    *in_flight = Some(shared.clone());
+                    shared
+                }
+            }
+        };
+
+        let result = shared.clone().await;
+
+        // Whichever subscriber finishes first clears the slot so the next
+        // caller starts a fresh refresh
+        let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        if in_flight
+            .as_ref()
+            .is_some_and(|current| current.ptr_eq(&shared))
+        {
+            *in_flight = None;
+        }
+        drop(in_flight);
+        drop(shared);
+
+        // Once every other subscriber is done, the error is no longer shared
+        result.map_err(|e| Arc::try_unwrap(e).unwrap_or_else(|e| SharedRefreshError(e).into()))
+    }
+
+    /// Detaches the in-flight refresh after a device was created, deleted,
+    /// started, or stopped: it may have read the list before the change, so
+    /// callers arriving from now on start a fresh refresh instead of joining.
+    pub fn invalidate(&self) {
+        *self.in_flight.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// The error of a refresh that other callers still share. Like a context
+/// added with anyhow, its source is that error, so `chain()` still finds
+/// structured errors such as `DeviceError` in it.
+#[derive(Debug)]
+pub struct SharedRefreshError(Arc<anyhow::Error>);
+
+impl fmt::Display for SharedRefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Device refresh failed")
+    }
+}
+
+impl std::error::Error for SharedRefreshError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let error: &(dyn std::error::Error + Send + Sync + 'static) = (*self.0).as_ref();
+        Some(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeviceError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn counting_refresh(
+        calls: &Arc<AtomicUsize>,
+    ) -> impl FnOnce() -> BoxFuture<'static, Result<usize>> {
+        let calls = Arc::clone(calls);
+        move || {
+            async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok(calls.fetch_add(1, Ordering::SeqCst) + 1)
+            }
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_refreshes_share_one_run() {
+        let coordinator = RefreshCoordinator::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let (first, second, third) = tokio::join!(
+            coordinator.run(counting_refresh(&calls)),
+            coordinator.run(counting_refresh(&calls)),
+            coordinator.run(counting_refresh(&calls)),
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.unwrap(), 1);
+        assert_eq!(second.unwrap(), 1);
+        assert_eq!(third.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_after_completion_runs_again() {
+        let coordinator = RefreshCoordinator::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        assert_eq!(coordinator.run(counting_refresh(&calls)).await.unwrap(), 1);
+        assert_eq!(coordinator.run(counting_refresh(&calls)).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_starts_a_fresh_refresh() {
+        let coordinator = RefreshCoordinator::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        // `join!` polls in order, so the first refresh is in flight before
+        // the second branch invalidates it
+        let (stale, fresh) = tokio::join!(coordinator.run(counting_refresh(&calls)), async {
+            coordinator.invalidate();
+            coordinator.run(counting_refresh(&calls)).await
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(stale.unwrap() + fresh.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_errors_reach_every_subscriber() {
+        let coordinator: RefreshCoordinator<usize> = RefreshCoordinator::new();
+        let failing = || async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Err(anyhow::anyhow!("adb not found"))
+        };
+
+        let (first, second) = tokio::join!(coordinator.run(failing), coordinator.run(failing));
+
+        assert!(format!("{:#}", first.unwrap_err()).ends_with("adb not found"));
+        assert!(format!("{:#}", second.unwrap_err()).ends_with("adb not found"));
+    }
+
+    #[tokio::test]
+    async fn test_device_errors_survive_the_shared_run() {
+        let coordinator: RefreshCoordinator<usize> = RefreshCoordinator::new();
+        let missing_adb = || async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Err(DeviceError::tool_missing("adb").into())
+        };
+
+        // A lone caller gets the error back as it was raised
+        let error = coordinator.run(missing_adb).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DeviceError>(),
+            Some(DeviceError::ToolMissing { .. })
+        ));
+
+        // Every subscriber still finds it in the chain
+        let (first, second) =
+            tokio::join!(coordinator.run(missing_adb), coordinator.run(missing_adb));
+        for error in [first.unwrap_err(), second.unwrap_err()] {
+            assert!(error.chain().any(|cause| matches!(
+                cause.downcast_ref::<DeviceError>(),
+                Some(DeviceError::ToolMissing { .. })
+            )));
+        }
+    }
+}
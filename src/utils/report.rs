@@ -0,0 +1,315 @@
+//! Bug report bundles
+//!
+//! `emu report` and the diagnostics viewer collect what an issue usually asks
+//! for (environment, SDK tool versions, emu's own log records, and recently
+//! executed commands) into a zip that can be attached to a GitHub issue as is.
+//! The home directory and user name are masked before anything is written.
+
+use crate::constants::{
+    commands::{self, versions},
+    env_vars,
+    files::{
+        REPORT_COMMANDS_FILE, REPORT_DIAGNOSTICS_FILE, REPORT_ENVIRONMENT_FILE, REPORT_EXTENSION,
+        REPORT_FILE_PREFIX, REPORT_TOOL_VERSIONS_FILE,
+    },
+    limits::MAX_COMMAND_HISTORY_ENTRIES,
+};
+use crate::managers::AndroidManager;
+use crate::utils::{
+    audit, command::format_command_line, diagnostics, tool_paths, xcode, CommandRunner,
+};
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// One text file inside a report bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportFile {
+    pub name: &'static str,
+    pub contents: String,
+}
+
+/// Sanitized files that make up a bug report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportBundle {
+    pub files: Vec<ReportFile>,
+}
+
+impl ReportBundle {
+    /// Collects the report. Commands are read before the tool versions are
+    /// queried so the version checks do not push out earlier history.
+    pub async fn collect() -> Self {
+        let commands = command_log().await;
+        let files = [
+            (REPORT_ENVIRONMENT_FILE, environment_info()),
+            (REPORT_TOOL_VERSIONS_FILE, tool_versions().await),
+            (REPORT_DIAGNOSTICS_FILE, diagnostics_log()),
+            (REPORT_COMMANDS_FILE, commands),
+        ];
+
+        let sanitizer = Sanitizer::from_env();
+        Self {
+            files: files
+                .into_iter()
+                .map(|(name, contents)| ReportFile {
+                    name,
+                    contents: sanitizer.apply(&contents),
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes the files into a new zip archive at `path`.
+    pub fn write_zip(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        for report_file in &self.files {
+            zip.start_file(report_file.name, options)?;
+            zip.write_all(report_file.contents.as_bytes())?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+/// Timestamped report path in the working directory, e.g.
+/// `emu-report-20250101-120000.zip`.
+pub fn default_report_path() -> PathBuf {
+    let file_name = format!(
+        "{REPORT_FILE_PREFIX}{}{REPORT_EXTENSION}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    std::env::current_dir().unwrap_or_default().join(file_name)
+}
+
+/// Collects a report and writes it to `path`.
+pub async fn write_report(path: &Path) -> Result<()> {
+    ReportBundle::collect().await.write_zip(path)
+}
+
+fn environment_info() -> String {
+    let mut lines = vec![
+        format!("emu {}", env!("CARGO_PKG_VERSION")),
+        format!("os: {} ({})", std::env::consts::OS, std::env::consts::ARCH),
+        String::new(),
+    ];
+    lines.extend(
+        env_vars::REPORTED_ENV_VARS
+            .iter()
+            .map(|key| match std::env::var(key) {
+                Ok(value) => format!("{key}={value}"),
+                Err(_) => format!("{key} is not set"),
+            }),
+    );
+    lines.extend(
+        tool_paths::tool_paths()
+            .into_iter()
+            .map(|(tool, path)| format!("{tool} configured at {}", path.display())),
+    );
+    lines.join("\n")
+}
+
+async fn tool_versions() -> String {
+    let runner = CommandRunner::new();
+    let mut sections =
+        vec![version_section(&runner, commands::ADB, &[versions::ADB_VERSION]).await];
+
+    sections.push(match AndroidManager::new() {
+        Ok(manager) => format!(
+            "avdmanager (cmdline-tools)\n{}",
+            manager
+                .cmdline_tools_version()
+                .unwrap_or_else(|| versions::UNKNOWN.to_string())
+        ),
+        Err(e) => format!("avdmanager\n{}{e}", versions::UNAVAILABLE_PREFIX),
+    });
+
+    if cfg!(target_os = "macos") {
+        sections.push(
+            version_section(
+                &runner,
+                commands::XCODE_SELECT,
+                &[commands::xcode_select::PRINT_PATH],
+            )
+            .await,
+        );
+        // The simctl and xcodebuild sections below run against this Xcode
+        if let Some(dir) = xcode::developer_dir_override() {
+            sections.push(format!(
+                "{} selected in emu\n{}",
+                env_vars::DEVELOPER_DIR,
+                dir.display()
+            ));
+        }
+        sections.push(
+            version_section(
+                &runner,
+                commands::XCRUN,
+                &[commands::SIMCTL, versions::SIMCTL_VERSION],
+            )
+            .await,
+        );
+        sections.push(
+            version_section(
+                &runner,
+                versions::XCODEBUILD,
+                &[versions::XCODEBUILD_VERSION],
+            )
+            .await,
+        );
+    }
+
+    sections.join("\n\n")
+}
+
+async fn version_section(runner: &CommandRunner, program: &str, args: &[&str]) -> String {
+    let output = match runner.run(program, args).await {
+        Ok(output) => output.trim().to_string(),
+        Err(e) => format!("{}{e}", versions::UNAVAILABLE_PREFIX),
+    };
+    format!("$ {}\n{output}", format_command_line(program, args))
+}
+
+/// emu's own log records, oldest first.
+fn diagnostics_log() -> String {
+    let mut entries = diagnostics::recent_entries();
+    entries.reverse();
+    entries
+        .iter()
+        .map(|entry| entry.to_log_line())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The tail of the audit log file, which spans earlier sessions, or the
+/// commands recorded in this session when there is no file.
+async fn command_log() -> String {
+    let log_path = match audit::log_path().await {
+        Some(path) => Some(path),
+        None => audit::default_log_path(),
+    };
+    if let Some(contents) = log_path.and_then(|path| std::fs::read_to_string(path).ok()) {
+        let lines: Vec<&str> = contents.lines().collect();
+        let start = lines.len().saturating_sub(MAX_COMMAND_HISTORY_ENTRIES);
+        return lines[start..].join("\n");
+    }
+
+    let mut entries = audit::recent_entries().await;
+    entries.reverse();
+    entries
+        .iter()
+        .map(|entry| entry.to_log_line())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Masks the home directory and the user name in report text.
+struct Sanitizer {
+    home: Option<String>,
+    user: Option<regex::Regex>,
+}
+
+impl Sanitizer {
+    fn from_env() -> Self {
+        let home = dirs::home_dir().map(|path| path.to_string_lossy().into_owned());
+        let user = std::env::var(env_vars::USER)
+            .or_else(|_| std::env::var(env_vars::USERNAME))
+            .ok();
+        Self::new(home, user)
+    }
+
+    fn new(home: Option<String>, user: Option<String>) -> Self {
+        Self {
+            home: home.filter(|home| home.len() > 1),
+            user: user
+                .filter(|user| !user.is_empty())
+                .and_then(|user| regex::Regex::new(&format!(r"\b{}\b", regex::escape(&user))).ok()),
+        }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        let mut sanitized = match &self.home {
+            Some(home) => text.replace(home.as_str(), "~"),
+            None => text.to_string(),
+        };
+        if let Some(user) = &self.user {
+            sanitized = user.replace_all(&sanitized, "<user>").into_owned();
+        }
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_sanitizer_masks_home_and_user_name() {
+        let sanitizer = Sanitizer::new(Some("/Users/alex".to_string()), Some("alex".to_string()));
+
+        assert_eq!(
+            sanitizer.apply("ANDROID_HOME=/Users/alex/Library/Android/sdk"),
+            "ANDROID_HOME=~/Library/Android/sdk"
+        );
+        assert_eq!(
+            sanitizer.apply("/opt/alex/sdk owned by alex"),
+            "/opt/<user>/sdk owned by <user>"
+        );
+        // Only whole words are masked
+        assert_eq!(sanitizer.apply("alexandria"), "alexandria");
+    }
+
+    #[test]
+    fn test_environment_info_lists_reported_variables() {
+        let info = environment_info();
+
+        assert!(info.starts_with(&format!("emu {}", env!("CARGO_PKG_VERSION"))));
+        for key in env_vars::REPORTED_ENV_VARS {
+            assert!(info.contains(key), "{key} missing");
+        }
+    }
+
+    #[test]
+    fn test_write_zip_contains_every_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.zip");
+        let bundle = ReportBundle {
+            files: vec![
+                ReportFile {
+                    name: REPORT_ENVIRONMENT_FILE,
+                    contents: "emu 1.0.0".to_string(),
+                },
+                ReportFile {
+                    name: REPORT_COMMANDS_FILE,
+                    contents: "[exit 0] 5ms adb devices".to_string(),
+                },
+            ],
+        };
+
+        bundle.write_zip(&path).unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut contents = String::new();
+        archive
+            .by_name(REPORT_COMMANDS_FILE)
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "[exit 0] 5ms adb devices");
+    }
+
+    #[test]
+    fn test_default_report_path_is_timestamped_zip() {
+        let path = default_report_path();
+        let file_name = path.file_name().unwrap().to_string_lossy();
+
+        assert!(file_name.starts_with(REPORT_FILE_PREFIX));
+        assert!(file_name.ends_with(REPORT_EXTENSION));
+    }
+}
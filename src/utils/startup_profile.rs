@@ -0,0 +1,119 @@
+//! Startup profiling
+//!
+//! With `--profile-startup`, the time spent in each startup phase is kept
+//! and printed as JSON when emu exits, so users can check the startup
+//! promise (< 150ms to the first frame) on their own machine.
+//!
+//! SDK discovery and manager construction are durations of the phase
+//! itself; first render and first device list are measured from the
+//! moment profiling was enabled, at the top of `main`.
+
+use serde::Serialize;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// A phase of startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupPhase {
+    /// Locating the Android SDK and its command-line tools
+    SdkDiscovery,
+    /// Building the platform managers and the app shell, after SDK discovery
+    ManagerConstruction,
+    /// The first frame drawn by the TUI
+    FirstRender,
+    /// The first Android device list loaded in the background
+    FirstDeviceList,
+}
+
+impl StartupPhase {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Recorded phases, in milliseconds. Phases that did not happen before exit
+/// (for example quitting before devices load) are `null`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StartupProfile {
+    pub sdk_discovery_ms: Option<f64>,
+    pub manager_construction_ms: Option<f64>,
+    pub first_render_ms: Option<f64>,
+    pub first_device_list_ms: Option<f64>,
+}
+
+impl StartupProfile {
+    fn from_phases(phases: &[Option<Duration>; 4]) -> Self {
+        let millis = |phase: StartupPhase| {
+            phases[phase.index()].map(|duration| duration.as_secs_f64() * 1000.0)
+        };
+        Self {
+            sdk_discovery_ms: millis(StartupPhase::SdkDiscovery),
+            manager_construction_ms: millis(StartupPhase::ManagerConstruction),
+            first_render_ms: millis(StartupPhase::FirstRender),
+            first_device_list_ms: millis(StartupPhase::FirstDeviceList),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+static STARTED: OnceLock<Instant> = OnceLock::new();
+static PHASES: Mutex<[Option<Duration>; 4]> = Mutex::new([None; 4]);
+
+/// Turns profiling on. Until this is called, recording does nothing.
+pub fn enable() {
+    STARTED.get_or_init(Instant::now);
+}
+
+pub fn is_enabled() -> bool {
+    STARTED.get().is_some()
+}
+
+/// Records how long a phase took. Only the first recording of a phase is
+/// kept, so later refreshes and frames do not overwrite startup timings.
+pub fn record(phase: StartupPhase, duration: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    if let Ok(mut phases) = PHASES.lock() {
+        phases[phase.index()].get_or_insert(duration);
+    }
+}
+
+/// Records a phase as reached now, measured from when profiling was enabled.
+pub fn mark(phase: StartupPhase) {
+    if let Some(started) = STARTED.get() {
+        record(phase, started.elapsed());
+    }
+}
+
+/// The phases recorded so far.
+pub fn snapshot() -> StartupProfile {
+    PHASES
+        .lock()
+        .map(|phases| StartupProfile::from_phases(&phases))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_serializes_missing_phases_as_null() {
+        let mut phases = [None; 4];
+        phases[StartupPhase::SdkDiscovery.index()] = Some(Duration::from_micros(1500));
+        phases[StartupPhase::FirstRender.index()] = Some(Duration::from_millis(42));
+
+        let profile = StartupProfile::from_phases(&phases);
+        assert_eq!(profile.sdk_discovery_ms, Some(1.5));
+        assert_eq!(profile.first_render_ms, Some(42.0));
+
+        let json: serde_json::Value = serde_json::from_str(&profile.to_json().unwrap()).unwrap();
+        assert_eq!(json["sdk_discovery_ms"], 1.5);
+        assert!(json["manager_construction_ms"].is_null());
+        assert!(json["first_device_list_ms"].is_null());
+    }
+}
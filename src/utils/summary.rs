@@ -0,0 +1,140 @@
+//! Non-interactive device summaries.
+//!
+//! When stdout is not a terminal (piped output, CI), the TUI cannot enter raw
+//! mode, so a one-shot summary of all devices is printed instead, either as
+//! tab-separated text or as JSON.
+
+use crate::models::{device::Device, AndroidDevice, DeviceId, IosDevice, Platform};
+use serde::Serialize;
+
+/// Output format of a device summary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SummaryFormat {
+    /// One device per line: platform, name, status, version, identifier
+    #[default]
+    Text,
+    /// A JSON document with a `devices` array
+    Json,
+}
+
+/// One device in a summary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeviceSummaryEntry {
+    /// `android:<avd name>` or `ios:<udid>`, accepted by `emu wait-for-boot`
+    pub id: DeviceId,
+    pub platform: &'static str,
+    pub name: String,
+    pub identifier: String,
+    pub status: String,
+    pub version: String,
+}
+
+/// All discovered devices in a stable order: Android first, then iOS.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DeviceSummary {
+    pub devices: Vec<DeviceSummaryEntry>,
+}
+
+impl DeviceSummary {
+    pub fn new(android_devices: &[AndroidDevice], ios_devices: &[IosDevice]) -> Self {
+        let android = android_devices.iter().map(|device| {
+            Self::entry(
+                Platform::Android,
+                device,
+                format!("API {}", device.api_level),
+            )
+        });
+        let ios = ios_devices.iter().map(|device| {
+            Self::entry(Platform::Ios, device, format!("iOS {}", device.ios_version))
+        });
+        Self {
+            devices: android.chain(ios).collect(),
+        }
+    }
+
+    /// Formats the summary. Text output has no header so it can be fed to
+    /// `cut`, `grep`, or `awk` directly.
+    pub fn render(&self, format: SummaryFormat) -> Result<String, anyhow::Error> {
+        match format {
+            SummaryFormat::Text => Ok(self
+                .devices
+                .iter()
+                .map(|entry| {
+                    [
+                        entry.platform,
+                        &entry.name,
+                        &entry.status,
+                        &entry.version,
+                        &entry.identifier,
+                    ]
+                    .join("\t")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")),
+            SummaryFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+        }
+    }
+
+    fn entry(platform: Platform, device: &dyn Device, version: String) -> DeviceSummaryEntry {
+        DeviceSummaryEntry {
+            id: device.device_id(),
+            platform: platform.short_name(),
+            name: device.name().to_string(),
+            identifier: device.id().to_string(),
+            status: format!("{:?}", device.status()).to_lowercase(),
+            version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeviceStatus;
+
+    fn summary() -> DeviceSummary {
+        let android = AndroidDevice {
+            name: "Pixel_7_API_34".to_string(),
+            api_level: 34,
+            status: DeviceStatus::Running,
+            is_running: true,
+            ..Default::default()
+        };
+        let ios = IosDevice {
+            name: "iPhone 15 (iOS 17.0)".to_string(),
+            udid: "ABC-123".to_string(),
+            device_type: "iPhone 15".to_string(),
+            ios_version: "17.0".to_string(),
+            runtime_version: "17.0".to_string(),
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            is_available: true,
+            availability_error: None,
+        };
+        DeviceSummary::new(&[android], &[ios])
+    }
+
+    #[test]
+    fn test_text_summary_is_tab_separated() {
+        let text = summary().render(SummaryFormat::Text).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "android\tPixel_7_API_34\trunning\tAPI 34\tPixel_7_API_34",
+                "ios\tiPhone 15 (iOS 17.0)\tstopped\tiOS 17.0\tABC-123",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_summary_lists_devices() {
+        let json = summary().render(SummaryFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let devices = value["devices"].as_array().unwrap();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[1]["platform"], "ios");
+        assert_eq!(devices[1]["identifier"], "ABC-123");
+        assert_eq!(devices[1]["id"], "ios:ABC-123");
+    }
+}
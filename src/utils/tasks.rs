@@ -0,0 +1,114 @@
+//! Background task registry
+//!
+//! Background work such as cache loading, log streams and status checks is
+//! spawned through [`spawn`], so quitting can cancel all of it with
+//! [`shutdown`] and wait, for a bounded time, until the tasks have dropped
+//! what they own. Child processes started with `kill_on_drop`, such as
+//! logcat, end together with the task that owns them.
+
+use crate::constants::timeouts::TASK_SHUTDOWN_POLL_INTERVAL;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::task::{AbortHandle, JoinHandle};
+
+static REGISTRY: OnceLock<TaskRegistry> = OnceLock::new();
+
+/// Tasks that are cancelled together on shutdown.
+#[derive(Default)]
+pub struct TaskRegistry {
+    tasks: Mutex<Vec<AbortHandle>>,
+}
+
+impl TaskRegistry {
+    /// Spawns `future` on the runtime and keeps track of it.
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let handle = tokio::spawn(future);
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle.abort_handle());
+        handle
+    }
+
+    /// Number of tracked tasks that have not finished yet.
+    pub fn running(&self) -> usize {
+        self.tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|task| !task.is_finished())
+            .count()
+    }
+
+    /// Cancels every tracked task and waits up to `grace` for them to end.
+    /// Returns how many were still running when the grace period was over.
+    pub async fn shutdown(&self, grace: Duration) -> usize {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        for task in &tasks {
+            task.abort();
+        }
+
+        let deadline = Instant::now() + grace;
+        loop {
+            let running = tasks.iter().filter(|task| !task.is_finished()).count();
+            if running == 0 || Instant::now() >= deadline {
+                return running;
+            }
+            tokio::time::sleep(TASK_SHUTDOWN_POLL_INTERVAL).await;
+        }
+    }
+}
+
+fn registry() -> &'static TaskRegistry {
+    REGISTRY.get_or_init(TaskRegistry::default)
+}
+
+/// Spawns a background task that is cancelled when emu quits.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    registry().spawn(future)
+}
+
+/// Cancels all background tasks, waiting up to `grace` for them to end.
+pub async fn shutdown(grace: Duration) -> usize {
+    registry().shutdown(grace).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_shutdown_cancels_and_awaits_tasks() {
+        let registry = TaskRegistry::default();
+        let dropped = Arc::new(AtomicBool::new(false));
+
+        struct SetOnDrop(Arc<AtomicBool>);
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let guard = SetOnDrop(Arc::clone(&dropped));
+        registry.spawn(async move {
+            let _guard = guard;
+            std::future::pending::<()>().await;
+        });
+        registry.spawn(async {}).await.unwrap();
+        assert_eq!(registry.running(), 1);
+
+        assert_eq!(registry.shutdown(Duration::from_secs(1)).await, 0);
+        assert!(dropped.load(Ordering::SeqCst));
+        assert_eq!(registry.running(), 0);
+    }
+}
@@ -0,0 +1,122 @@
+//! Display-width-aware text helpers.
+//!
+//! Terminal cells are not characters: CJK characters and most emoji take two
+//! cells, combining marks and zero-width joiners take none, and slicing a
+//! `String` by byte index can panic in the middle of a character. These
+//! helpers measure text in terminal cells and only cut between grapheme
+//! clusters, so device names in any script keep columns aligned.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Suffix marking text that was cut short.
+pub const ELLIPSIS: &str = "...";
+
+/// Number of terminal cells the text occupies.
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Shortens text to at most `max_width` cells, ending it with [`ELLIPSIS`]
+/// when something was cut. Widths too small for the ellipsis cut without it.
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if display_width(text) <= max_width {
+        return text.to_string();
+    }
+    let ellipsis_width = display_width(ELLIPSIS);
+    if max_width < ellipsis_width {
+        return take_width(text, max_width);
+    }
+    let mut truncated = take_width(text, max_width - ellipsis_width);
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Pads text with spaces to exactly `width` cells, truncating it first when
+/// it is wider.
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let mut padded = truncate_to_width(text, width);
+    let padding = width.saturating_sub(display_width(&padded));
+    padded.push_str(&" ".repeat(padding));
+    padded
+}
+
+/// Longest prefix of whole grapheme clusters that fits in `max_width` cells.
+fn take_width(text: &str, max_width: usize) -> String {
+    let mut taken = String::new();
+    let mut width = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = display_width(grapheme);
+        if width + grapheme_width > max_width {
+            break;
+        }
+        width += grapheme_width;
+        taken.push_str(grapheme);
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Strings that break naive byte or char based truncation.
+    const TRICKY: &[&str] = &[
+        "",
+        "Pixel_7_API_34",
+        "日本語のデバイス名",
+        "iPhone 15 🍎 Pro",
+        "👩‍👩‍👧‍👦 family",
+        "🇯🇵🇺🇸 flags",
+        "e\u{301}le\u{301}ve cafe\u{301}",
+        "mixed 中文 and ASCII ✨",
+        "zero\u{200b}width\u{200d}joiners",
+        "한국어 기기",
+        "\u{feff}bom",
+        "tab\tand\nnewline",
+    ];
+
+    #[test]
+    fn test_truncate_never_exceeds_width_and_keeps_graphemes() {
+        for text in TRICKY {
+            let graphemes: Vec<&str> = text.graphemes(true).collect();
+            for max_width in 0..=display_width(text) + 2 {
+                let truncated = truncate_to_width(text, max_width);
+                assert!(
+                    display_width(&truncated) <= max_width,
+                    "{text:?} at {max_width}: {truncated:?}"
+                );
+
+                let kept = truncated.strip_suffix(ELLIPSIS).unwrap_or(&truncated);
+                let kept_graphemes: Vec<&str> = kept.graphemes(true).collect();
+                assert!(
+                    graphemes.starts_with(&kept_graphemes),
+                    "{text:?} at {max_width}: {truncated:?} splits a grapheme"
+                );
+
+                if display_width(text) <= max_width {
+                    assert_eq!(&truncated, text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pad_to_width_is_exact() {
+        for text in TRICKY {
+            for width in 0..=display_width(text) + 2 {
+                let padded = pad_to_width(text, width);
+                // A wide grapheme that does not fit leaves a gap that is padded
+                assert_eq!(display_width(&padded), width, "{text:?} at {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_characters_count_two_cells() {
+        assert_eq!(display_width("日本"), 4);
+        assert_eq!(truncate_to_width("日本語のデバイス", 7), "日本...");
+        assert_eq!(truncate_to_width("日本語", 1), "");
+        assert_eq!(truncate_to_width("abc", 2), "ab");
+    }
+}
@@ -0,0 +1,90 @@
+//! Tool location overrides.
+//!
+//! SDK tools are normally found under `ANDROID_HOME`, and `adb` and `xcrun`
+//! on `PATH`. The `[tools]` table of `.emu.toml` can point any of them at
+//! another executable, e.g. a portable SDK or a custom Xcode toolchain. The
+//! overrides are kept for the rest of the process and applied wherever emu
+//! spawns a command by its bare name.
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+static TOOL_PATHS: OnceLock<RwLock<BTreeMap<String, PathBuf>>> = OnceLock::new();
+
+fn overrides_lock() -> &'static RwLock<BTreeMap<String, PathBuf>> {
+    TOOL_PATHS.get_or_init(|| RwLock::new(BTreeMap::new()))
+}
+
+/// Runs `tool` (a bare name such as `adb`) from `path`, or goes back to
+/// the default lookup with `None`.
+pub fn set_tool_path(tool: &str, path: Option<PathBuf>) {
+    if let Ok(mut overrides) = overrides_lock().write() {
+        match path {
+            Some(path) => overrides.insert(tool.to_string(), path),
+            None => overrides.remove(tool),
+        };
+    }
+}
+
+/// The configured location of `tool`, if any.
+pub fn tool_path(tool: &str) -> Option<PathBuf> {
+    overrides_lock()
+        .read()
+        .ok()
+        .and_then(|overrides| overrides.get(tool).cloned())
+}
+
+/// Every configured tool and its location, sorted by tool name.
+pub fn tool_paths() -> Vec<(String, PathBuf)> {
+    overrides_lock()
+        .read()
+        .map(|overrides| {
+            overrides
+                .iter()
+                .map(|(tool, path)| (tool.clone(), path.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The program to spawn for `program`: its configured location when it is
+/// a bare tool name with an override, otherwise `program` unchanged.
+pub fn resolve_program(program: &OsStr) -> PathBuf {
+    let path = Path::new(program);
+    let is_bare_name = path
+        .parent()
+        .is_some_and(|parent| parent.as_os_str().is_empty());
+    program
+        .to_str()
+        .filter(|_| is_bare_name)
+        .and_then(tool_path)
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_program_replaces_bare_tool_names() {
+        let tool = "emu-test-tool";
+        assert_eq!(resolve_program(OsStr::new(tool)), PathBuf::from(tool));
+
+        set_tool_path(tool, Some(PathBuf::from("/opt/portable/emu-test-tool")));
+        assert_eq!(
+            resolve_program(OsStr::new(tool)),
+            PathBuf::from("/opt/portable/emu-test-tool")
+        );
+        // Explicit paths are left alone
+        assert_eq!(
+            resolve_program(OsStr::new("/usr/bin/emu-test-tool")),
+            PathBuf::from("/usr/bin/emu-test-tool")
+        );
+        assert!(tool_paths().iter().any(|(name, _)| name == tool));
+
+        set_tool_path(tool, None);
+        assert_eq!(tool_path(tool), None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Xcode selection.
+//!
+//! Which simulator runtimes and device types simctl sees depends on the
+//! active Xcode. The system-wide choice comes from `xcode-select -p`; emu can
+//! point its own `xcrun` and `xcodebuild` commands at another installed Xcode
+//! through `DEVELOPER_DIR` without touching that setting.
+
+use crate::constants::{
+    commands::{xcode_select, XCODEBUILD, XCODE_SELECT, XCRUN},
+    env_vars::DEVELOPER_DIR,
+    files::xcode::{APPLICATIONS_DIR, APP_EXTENSION, APP_PREFIX, DEVELOPER_SUBDIR},
+};
+use crate::utils::CommandRunner;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+static DEVELOPER_DIR_OVERRIDE: OnceLock<RwLock<Option<PathBuf>>> = OnceLock::new();
+
+/// Where the active developer directory was chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeveloperDirSource {
+    /// Picked inside emu for this session
+    Emu,
+    /// `DEVELOPER_DIR` was set when emu started
+    Environment,
+    /// The system-wide `xcode-select` choice
+    XcodeSelect,
+}
+
+/// The developer directory simctl commands run against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeveloperDir {
+    pub path: PathBuf,
+    pub source: DeveloperDirSource,
+}
+
+fn override_lock() -> &'static RwLock<Option<PathBuf>> {
+    DEVELOPER_DIR_OVERRIDE.get_or_init(|| RwLock::new(None))
+}
+
+/// The developer directory picked inside emu, if any.
+pub fn developer_dir_override() -> Option<PathBuf> {
+    override_lock()
+        .read()
+        .map(|dir| dir.clone())
+        .unwrap_or_default()
+}
+
+/// Points later `xcrun` and `xcodebuild` commands at `dir`, or back at the
+/// default Xcode with `None`.
+pub fn set_developer_dir_override(dir: Option<PathBuf>) {
+    if let Ok(mut current) = override_lock().write() {
+        *current = dir;
+    }
+}
+
+/// Sets `DEVELOPER_DIR` on `command` when it runs an Xcode tool and another
+/// Xcode was picked inside emu.
+pub fn apply_developer_dir(command: &mut tokio::process::Command, program: &OsStr) {
+    let is_xcode_tool = Path::new(program)
+        .file_name()
+        .is_some_and(|name| name == XCRUN || name == XCODEBUILD);
+    if !is_xcode_tool {
+        return;
+    }
+    if let Some(dir) = developer_dir_override() {
+        command.env(DEVELOPER_DIR, dir);
+    }
+}
+
+/// Resolves the developer directory simctl commands currently use.
+pub async fn active_developer_dir() -> Option<DeveloperDir> {
+    if let Some(path) = developer_dir_override() {
+        return Some(DeveloperDir {
+            path,
+            source: DeveloperDirSource::Emu,
+        });
+    }
+    if let Some(path) = std::env::var_os(DEVELOPER_DIR).filter(|dir| !dir.is_empty()) {
+        return Some(DeveloperDir {
+            path: PathBuf::from(path),
+            source: DeveloperDirSource::Environment,
+        });
+    }
+
+    let output = CommandRunner::new()
+        .run(XCODE_SELECT, [xcode_select::PRINT_PATH])
+        .await
+        .ok()?;
+    let path = output.trim();
+    (!path.is_empty()).then(|| DeveloperDir {
+        path: PathBuf::from(path),
+        source: DeveloperDirSource::XcodeSelect,
+    })
+}
+
+/// Developer directories of the Xcode versions installed in `/Applications`.
+pub fn installed_developer_dirs() -> Vec<PathBuf> {
+    installed_developer_dirs_in(Path::new(APPLICATIONS_DIR))
+}
+
+/// Developer directories of `Xcode*.app` bundles in `applications`, sorted
+/// by bundle name.
+pub fn installed_developer_dirs_in(applications: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(applications) else {
+        return Vec::new();
+    };
+
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == APP_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(APP_PREFIX))
+        })
+        .map(|path| path.join(DEVELOPER_SUBDIR))
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// The developer directory after `current` in `installed`, wrapping back to
+/// `None` (the default Xcode) after the last one.
+pub fn next_developer_dir(installed: &[PathBuf], current: Option<&Path>) -> Option<PathBuf> {
+    match current.and_then(|current| installed.iter().position(|dir| dir == current)) {
+        Some(index) => installed.get(index + 1).cloned(),
+        None => installed.first().cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_installed_developer_dirs_in_finds_xcode_bundles() {
+        let applications = tempfile::tempdir().unwrap();
+        for bundle in ["Xcode-15.4.app", "Xcode.app", "Xcodes.txt", "Safari.app"] {
+            std::fs::create_dir_all(applications.path().join(bundle).join(DEVELOPER_SUBDIR))
+                .unwrap();
+        }
+        // A bundle without a developer directory is not a usable Xcode
+        std::fs::create_dir_all(applications.path().join("Xcode-beta.app")).unwrap();
+
+        let dirs = installed_developer_dirs_in(applications.path());
+        assert_eq!(
+            dirs,
+            vec![
+                applications
+                    .path()
+                    .join("Xcode-15.4.app")
+                    .join(DEVELOPER_SUBDIR),
+                applications.path().join("Xcode.app").join(DEVELOPER_SUBDIR),
+            ]
+        );
+        assert!(installed_developer_dirs_in(&applications.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_next_developer_dir_cycles_back_to_default() {
+        let installed = vec![PathBuf::from("/a"), PathBuf::from("/b")];
+        assert_eq!(
+            next_developer_dir(&installed, None),
+            Some(PathBuf::from("/a"))
+        );
+        assert_eq!(
+            next_developer_dir(&installed, Some(Path::new("/a"))),
+            Some(PathBuf::from("/b"))
+        );
+        assert_eq!(next_developer_dir(&installed, Some(Path::new("/b"))), None);
+        assert_eq!(
+            next_developer_dir(&installed, Some(Path::new("/unknown"))),
+            Some(PathBuf::from("/a"))
+        );
+        assert_eq!(next_developer_dir(&[], None), None);
+    }
+}
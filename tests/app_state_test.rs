@@ -31,6 +31,7 @@ fn create_state_with_devices() -> AppState {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -41,6 +42,7 @@ fn create_state_with_devices() -> AppState {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16G".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -55,6 +57,7 @@ fn create_state_with_devices() -> AppState {
             status: DeviceStatus::Running,
             is_running: true,
             is_available: true,
+            availability_error: None,
         },
         IosDevice {
             name: "iPad Air".to_string(),
@@ -65,6 +68,7 @@ fn create_state_with_devices() -> AppState {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
     ];
 
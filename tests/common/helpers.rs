@@ -21,6 +21,7 @@ pub fn create_test_android_device(name: &str) -> AndroidDevice {
         is_running: false,
         ram_size: TEST_RAM_SIZE_DEFAULT.to_string(),
         storage_size: TEST_STORAGE_SIZE_DEFAULT.to_string(),
+        abi: String::new(),
     }
 }
 
@@ -36,6 +37,7 @@ pub fn create_android_device_with_status(name: &str, status: DeviceStatus) -> An
         is_running: status == DeviceStatus::Running,
         ram_size: TEST_RAM_SIZE_DEFAULT.to_string(),
         storage_size: TEST_STORAGE_SIZE_DEFAULT.to_string(),
+        abi: String::new(),
     }
 }
 
@@ -54,6 +56,7 @@ pub fn create_test_ios_device(name: &str) -> IosDevice {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }
 }
 
@@ -72,6 +75,7 @@ pub fn create_ios_device_with_status(name: &str, status: DeviceStatus) -> IosDev
         status,
         is_running: status == DeviceStatus::Running,
         is_available: true,
+        availability_error: None,
     }
 }
 
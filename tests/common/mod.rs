@@ -3,6 +3,7 @@
 //! This module provides shared test helpers for creating mock applications,
 //! terminals, and test scenarios without requiring any real emulators or simulators.
 
+#[cfg(feature = "tui")]
 pub mod assertions;
 pub mod helpers;
 
@@ -59,6 +59,7 @@ impl FixtureAndroidManager {
                     is_running: false,
                     ram_size: "2048".to_string(),
                     storage_size: "8192M".to_string(),
+                    abi: String::new(),
                 });
             } else if trimmed.starts_with("Device: ") && current_device.is_some() {
                 if let Some(ref mut device) = current_device {
@@ -0,0 +1,122 @@
+//! Android SDK output parser tests using fixture data
+//!
+//! These tests run the typed parsers in `managers::android::parsers` against
+//! captured `avdmanager` and `sdkmanager` output, so a format change in a new
+//! SDK release shows up as a failing fixture rather than an empty device list.
+
+use emu::managers::android::parsers::{
+    parse_avd_list, parse_device_definitions, parse_platform_version_name, parse_system_images,
+};
+
+use super::fixture_loader::FixtureLoader;
+
+fn android_output(path: &[&str]) -> String {
+    let mut loader = FixtureLoader::new();
+    loader
+        .get_string("android_outputs.json", path)
+        .unwrap()
+        .unwrap_or_else(|| panic!("missing fixture {path:?}"))
+}
+
+#[test]
+fn test_parse_avd_list_empty() {
+    let output = android_output(&["avdmanager_list_avd", "empty"]);
+    assert!(parse_avd_list(&output).is_empty());
+}
+
+#[test]
+fn test_parse_avd_list_single_device() {
+    let output = android_output(&["avdmanager_list_avd", "single_device"]);
+    let avds = parse_avd_list(&output);
+
+    assert_eq!(avds.len(), 1);
+    assert_eq!(avds[0].name, "Pixel_7_API_34");
+    assert_eq!(avds[0].device, "pixel_7 (Pixel 7)");
+    assert_eq!(avds[0].path, "/Users/user/.android/avd/Pixel_7_API_34.avd");
+    assert_eq!(avds[0].abi, "google_apis_playstore/arm64-v8a");
+    assert!(avds[0].target.starts_with("Google APIs (Google Inc.)"));
+}
+
+#[test]
+fn test_parse_avd_list_multiple_devices() {
+    let output = android_output(&["avdmanager_list_avd", "multiple_devices"]);
+    let names: Vec<String> = parse_avd_list(&output)
+        .into_iter()
+        .map(|avd| avd.name)
+        .collect();
+
+    assert_eq!(
+        names,
+        vec![
+            "Pixel_7_API_34",
+            "Pixel_Tablet_API_33",
+            "Wear_OS_Round_API_30"
+        ]
+    );
+}
+
+#[test]
+fn test_parse_avd_list_with_spaces() {
+    let output = android_output(&["avdmanager_list_avd", "with_spaces"]);
+    let avds = parse_avd_list(&output);
+
+    assert_eq!(avds.len(), 1);
+    assert_eq!(avds[0].name, "Pixel 7 API 34");
+    assert_eq!(avds[0].path, "/Users/user/.android/avd/Pixel 7 API 34.avd");
+}
+
+#[test]
+fn test_parse_device_definitions() {
+    let output = android_output(&["avdmanager_list_device", "comprehensive"]);
+    let definitions = parse_device_definitions(&output);
+
+    assert_eq!(definitions.len(), 10);
+    assert_eq!(definitions[0].id, "tv_1080p");
+    assert_eq!(definitions[0].display_name(), "Android TV (1080p) (Google)");
+
+    let pixel = definitions
+        .iter()
+        .find(|definition| definition.id == "pixel_7")
+        .expect("pixel_7 definition");
+    assert_eq!(pixel.name, "Pixel 7");
+    assert_eq!(pixel.oem.as_deref(), Some("Google"));
+}
+
+#[test]
+fn test_parse_system_images() {
+    let output = android_output(&["sdkmanager_list", "system_images"]);
+    let images = parse_system_images(&output);
+
+    let package_ids: Vec<&str> = images
+        .iter()
+        .map(|image| image.package_id.as_str())
+        .collect();
+    assert_eq!(
+        package_ids,
+        vec![
+            "system-images;android-34;google_apis;arm64-v8a",
+            "system-images;android-34;google_apis_playstore;arm64-v8a",
+            "system-images;android-33;google_apis;x86_64",
+            "system-images;android-30;google_apis;x86",
+        ]
+    );
+    assert!(images.iter().all(|image| image.installed));
+    assert_eq!(images[1].api_level(), Some(34));
+    assert_eq!(images[1].tag, "google_apis_playstore");
+    assert_eq!(images[3].abi, "x86");
+}
+
+#[test]
+fn test_parse_platform_version_names() {
+    let output = android_output(&["sdkmanager_list", "platforms"]);
+
+    assert_eq!(
+        parse_platform_version_name(&output, 34).as_deref(),
+        Some("14")
+    );
+    assert_eq!(
+        parse_platform_version_name(&output, 30).as_deref(),
+        Some("11")
+    );
+    assert_eq!(parse_platform_version_name(&output, 29), None);
+}
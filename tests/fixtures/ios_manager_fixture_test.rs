@@ -84,6 +84,7 @@ impl FixtureiOSManager {
             status,
             is_running: status == DeviceStatus::Running,
             is_available,
+            availability_error: None,
         })
     }
 
@@ -3,6 +3,7 @@
 //! This module provides access to test fixture data and loading utilities.
 
 pub mod android_manager_fixture_test;
+pub mod android_parser_fixture_test;
 pub mod command_utility_fixture_test;
 pub mod fixture_loader;
 pub mod ios_manager_fixture_test;
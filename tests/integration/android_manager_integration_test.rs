@@ -13,7 +13,6 @@
 
 use anyhow::Result;
 use emu::managers::android::AndroidManager;
-use emu::managers::common::DeviceManager;
 use emu::models::DeviceStatus;
 use emu::utils::command_executor::mock::MockCommandExecutor;
 use std::sync::Arc;
@@ -4,7 +4,6 @@
 //! aimed at improving coverage and ensuring feature reliability.
 
 use emu::managers::android::AndroidManager;
-use emu::managers::common::DeviceManager;
 use emu::models::DeviceStatus;
 use emu::utils::command_executor::mock::MockCommandExecutor;
 use std::sync::Arc;
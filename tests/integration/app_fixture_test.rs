@@ -38,6 +38,7 @@ async fn test_app_state_device_management() -> Result<()> {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -48,6 +49,7 @@ async fn test_app_state_device_management() -> Result<()> {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -243,6 +245,7 @@ async fn test_app_state_device_selection() -> Result<()> {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -253,6 +256,7 @@ async fn test_app_state_device_selection() -> Result<()> {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -288,6 +292,7 @@ async fn test_app_state_device_selection() -> Result<()> {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     {
@@ -531,6 +536,7 @@ async fn test_app_state_comprehensive_workflow() -> Result<()> {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
 
     {
@@ -49,6 +49,7 @@ async fn test_device_selection() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -59,6 +60,7 @@ async fn test_device_selection() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -100,6 +102,7 @@ async fn test_ios_device_selection() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
         IosDevice {
             name: "iPad Pro".to_string(),
@@ -110,6 +113,7 @@ async fn test_ios_device_selection() {
             status: DeviceStatus::Running,
             is_running: true,
             is_available: true,
+            availability_error: None,
         },
     ];
 
@@ -191,6 +195,7 @@ async fn test_device_list_updates() {
         is_running: false,
         ram_size: "3072".to_string(),
         storage_size: "12288M".to_string(),
+        abi: String::new(),
     };
 
     state.android_devices.push(android_device);
@@ -207,6 +212,7 @@ async fn test_device_list_updates() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     state.ios_devices.push(ios_device);
@@ -230,6 +236,7 @@ async fn test_panel_switch_selection_persistence() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -240,6 +247,7 @@ async fn test_panel_switch_selection_persistence() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -253,6 +261,7 @@ async fn test_panel_switch_selection_persistence() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     // Change selection in Android panel
@@ -283,6 +292,7 @@ async fn test_device_status_updates() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     // Initial state
@@ -316,6 +326,7 @@ async fn test_device_details() {
         is_running: true,
         ram_size: "8192".to_string(),
         storage_size: "32768M".to_string(),
+        abi: String::new(),
     };
 
     // Verify device details
@@ -334,6 +345,7 @@ async fn test_device_details() {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: true,
+        availability_error: None,
     };
 
     // Verify iOS device details
@@ -42,6 +42,7 @@ async fn test_device_list_management() -> Result<()> {
         is_running: false,
         ram_size: "4096".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     app_state.android_devices.push(android_device);
@@ -56,6 +57,7 @@ async fn test_device_list_management() -> Result<()> {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     app_state.ios_devices.push(ios_device);
@@ -104,6 +106,7 @@ async fn test_device_selection() -> Result<()> {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         };
         app_state.android_devices.push(android_device);
 
@@ -116,6 +119,7 @@ async fn test_device_selection() -> Result<()> {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
         app_state.ios_devices.push(ios_device);
     }
@@ -152,6 +156,7 @@ async fn test_device_status_changes() -> Result<()> {
         is_running: false,
         ram_size: "4096".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     app_state.android_devices.push(android_device);
@@ -264,6 +269,7 @@ async fn test_concurrent_operations_simulation() -> Result<()> {
                 is_running: false,
                 ram_size: "4096".to_string(),
                 storage_size: "8192M".to_string(),
+                abi: String::new(),
             };
             state.android_devices.push(device);
         }
@@ -341,6 +347,7 @@ async fn test_error_states() -> Result<()> {
         is_running: false,
         ram_size: "4096".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     app_state.android_devices.push(device);
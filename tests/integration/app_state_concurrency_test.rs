@@ -101,6 +101,7 @@ async fn test_concurrent_device_list_updates() {
                         is_running: false,
                         ram_size: "4096".to_string(),
                         storage_size: "8192".to_string(),
+                        abi: String::new(),
                     };
                     state.android_devices.push(device);
                 }
@@ -222,6 +223,7 @@ async fn test_state_consistency_under_load() {
                         is_running: false,
                         ram_size: "4096".to_string(),
                         storage_size: "8192".to_string(),
+                        abi: String::new(),
                     };
                     state.android_devices.push(device);
                 }
@@ -293,6 +295,7 @@ async fn test_concurrent_mixed_operations() {
                         is_running: false,
                         ram_size: "4096".to_string(),
                         storage_size: "8192".to_string(),
+                        abi: String::new(),
                     };
                     state.android_devices.push(android_device);
 
@@ -305,6 +308,7 @@ async fn test_concurrent_mixed_operations() {
                         status: DeviceStatus::Stopped,
                         is_running: false,
                         is_available: true,
+                        availability_error: None,
                     };
                     state.ios_devices.push(ios_device);
                 }
@@ -357,6 +361,7 @@ async fn test_high_contention_scenario() {
                             is_running: false,
                             ram_size: "4096".to_string(),
                             storage_size: "8192".to_string(),
+                            abi: String::new(),
                         };
                         state.android_devices.push(device);
                     }
@@ -18,6 +18,7 @@ fn test_complete_device_management_workflow() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     state.ios_devices = vec![IosDevice {
@@ -29,6 +30,7 @@ fn test_complete_device_management_workflow() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     // Step 1: Navigate between panels
@@ -53,6 +55,7 @@ fn test_complete_device_management_workflow() {
         is_running: false,
         ram_size: "4096".to_string(),
         storage_size: "16384".to_string(),
+        abi: String::new(),
     });
 
     state.move_down(); // 0 -> 1
@@ -211,6 +214,7 @@ fn test_performance_critical_operations() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         })
         .collect();
 
@@ -264,6 +268,7 @@ fn test_state_consistency_during_complex_operations() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -274,6 +279,7 @@ fn test_state_consistency_during_complex_operations() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -286,6 +292,7 @@ fn test_state_consistency_during_complex_operations() {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: true,
+        availability_error: None,
     }];
 
     // Test multiple simultaneous state changes
@@ -435,3 +435,26 @@ fn test_placeholder_name_generation() {
     );
     println!("✅ Placeholder name generation works correctly");
 }
+
+#[test]
+fn test_sdk_field_only_with_several_sdks() {
+    let mut form = CreateDeviceForm::for_android();
+    form.active_field = CreateDeviceField::Name;
+    form.next_field();
+    assert_eq!(form.active_field, CreateDeviceField::ApiLevel);
+    assert!(!form.cycle_sdk(true));
+    assert_eq!(form.selected_sdk(), None);
+
+    form.android_sdks = vec!["default".to_string(), "canary".to_string()];
+    form.prev_field();
+    assert_eq!(form.active_field, CreateDeviceField::Sdk);
+    form.prev_field();
+    assert_eq!(form.active_field, CreateDeviceField::Name);
+    form.next_field();
+    assert_eq!(form.active_field, CreateDeviceField::Sdk);
+
+    assert!(form.cycle_sdk(false));
+    assert_eq!(form.selected_sdk(), Some("canary"));
+    assert!(form.cycle_sdk(true));
+    assert_eq!(form.selected_sdk(), Some("default"));
+}
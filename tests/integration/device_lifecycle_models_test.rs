@@ -59,6 +59,7 @@ fn test_duplicate_device_name_detection() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     };
 
     app_state.android_devices = vec![existing_device];
@@ -92,6 +93,7 @@ fn test_device_state_transitions() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     };
 
     // Test valid transitions
@@ -147,6 +149,7 @@ async fn test_concurrent_device_operations() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192".to_string(),
+                abi: String::new(),
             };
 
             // Lock and modify state
@@ -200,6 +203,7 @@ async fn test_device_operation_error_recovery() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -210,6 +214,7 @@ async fn test_device_operation_error_recovery() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -275,6 +280,7 @@ fn test_device_cleanup_completeness() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -365,6 +371,7 @@ fn test_api_level_compatibility() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192".to_string(),
+                abi: String::new(),
             };
 
             // Verify device can be created with this API level
@@ -400,6 +407,7 @@ fn test_device_list_filtering() {
             is_running: matches!(status, DeviceStatus::Running),
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -482,6 +490,7 @@ async fn test_device_operation_timeouts() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -492,6 +501,7 @@ async fn test_device_operation_timeouts() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -582,6 +592,7 @@ fn test_insufficient_system_resources() {
                 is_running: false,
                 ram_size: ram.to_string(),
                 storage_size: storage.to_string(),
+                abi: String::new(),
             };
 
             app_state.android_devices.push(device);
@@ -614,6 +625,7 @@ async fn test_parallel_device_operations_safety() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192".to_string(),
+                abi: String::new(),
             });
         }
     }
@@ -716,6 +728,7 @@ fn test_device_operation_failure_recovery() {
             is_running,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -766,6 +779,7 @@ fn test_system_limit_handling() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
 
         // Only add if under limit
@@ -810,7 +824,7 @@ fn test_system_limit_handling() {
 /// Test device priority and sorting
 #[test]
 fn test_device_priority_sorting() {
-    let mut devices = vec![
+    let mut devices = [
         AndroidDevice {
             android_version_name: "API 30".to_string(),
             name: "device_3".to_string(),
@@ -820,6 +834,7 @@ fn test_device_priority_sorting() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -830,6 +845,7 @@ fn test_device_priority_sorting() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -840,6 +856,7 @@ fn test_device_priority_sorting() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
     ];
 
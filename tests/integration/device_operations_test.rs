@@ -125,6 +125,7 @@ fn test_device_selection_and_details_sync() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -135,6 +136,7 @@ fn test_device_selection_and_details_sync() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -148,6 +150,7 @@ fn test_device_selection_and_details_sync() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     // Test Android panel selection
@@ -290,6 +293,7 @@ fn test_panel_switching_state_consistency() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     state.ios_devices = vec![IosDevice {
@@ -301,6 +305,7 @@ fn test_panel_switching_state_consistency() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     // Test initial state
@@ -216,6 +216,7 @@ async fn test_app_state_inconsistency_recovery() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     // Set out-of-range index
@@ -227,8 +228,8 @@ async fn test_app_state_inconsistency_recovery() {
 
     // Implementation may return None or fix the index
     // Important thing is not to panic
-    if selected.is_some() {
-        assert_eq!(selected.unwrap().name, "Recovery_Device_1");
+    if let Some(selected) = selected {
+        assert_eq!(selected.name, "Recovery_Device_1");
     }
 
     // Index normalization
@@ -462,6 +463,7 @@ async fn test_ui_state_recovery_from_errors() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     // Confirm focus index is within valid range
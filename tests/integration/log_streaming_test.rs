@@ -22,6 +22,7 @@ async fn test_log_streaming_device_selection_change() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -32,6 +33,7 @@ async fn test_log_streaming_device_selection_change() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -178,6 +180,7 @@ async fn test_log_streaming_state_consistency() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -188,6 +191,7 @@ async fn test_log_streaming_state_consistency() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -262,6 +266,7 @@ async fn test_mock_log_streaming_task_lifecycle() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         }];
     }
 
@@ -360,6 +365,7 @@ async fn test_log_streaming_edge_cases() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -370,6 +376,7 @@ async fn test_log_streaming_edge_cases() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
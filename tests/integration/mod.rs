@@ -3,27 +3,45 @@
 mod android_error_handling_test;
 mod android_manager_integration_test;
 mod android_parsing_test;
+#[cfg(feature = "tui")]
 mod app_fixture_test;
+#[cfg(feature = "tui")]
 mod app_main_logic_test;
+#[cfg(feature = "tui")]
 mod app_mod_integration_test;
+#[cfg(feature = "tui")]
 mod app_realistic_test;
+#[cfg(feature = "tui")]
 mod app_state_concurrency_test;
+#[cfg(feature = "tui")]
 mod cache_background_test;
+#[cfg(feature = "tui")]
 mod comprehensive_test;
+#[cfg(feature = "tui")]
 mod device_creation_navigation_test;
 mod device_creation_test;
+#[cfg(feature = "tui")]
 mod device_lifecycle_models_test;
+#[cfg(feature = "tui")]
 mod device_lifecycle_test;
+#[cfg(feature = "tui")]
 mod device_operations_test;
 mod device_sync_test;
+#[cfg(feature = "tui")]
 mod error_recovery_test;
 mod ios_manager_integration_test;
+#[cfg(feature = "tui")]
 mod log_streaming_test;
 mod models_test;
+#[cfg(feature = "tui")]
 mod navigation_circular_test;
+#[cfg(feature = "tui")]
 mod notification_test;
+#[cfg(feature = "tui")]
 mod panel_switching_test;
+#[cfg(feature = "tui")]
 mod platform_switching_test;
+#[cfg(feature = "tui")]
 mod ui_focus_theme_test;
 mod ui_render_test;
 mod utils_command_test;
@@ -20,6 +20,7 @@ async fn test_android_device_model() -> Result<()> {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     // Test Device trait implementation
@@ -48,6 +49,7 @@ async fn test_ios_device_model() -> Result<()> {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: true,
+        availability_error: None,
     };
 
     // Test Device trait implementation
@@ -157,6 +159,7 @@ async fn test_device_validation() -> Result<()> {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     assert!(!valid_android.name().is_empty());
@@ -173,6 +176,7 @@ async fn test_device_validation() -> Result<()> {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     assert!(!valid_ios.name().is_empty());
@@ -193,6 +197,7 @@ async fn test_device_serialization() -> Result<()> {
         is_running: true,
         ram_size: "4096".to_string(),
         storage_size: "16384M".to_string(),
+        abi: String::new(),
     };
 
     // Test that device can be converted to/from JSON (if Serialize/Deserialize is implemented)
@@ -218,6 +223,7 @@ async fn test_platform_specific_features() -> Result<()> {
         is_running: false,
         ram_size: "1024".to_string(),
         storage_size: "4096M".to_string(),
+        abi: String::new(),
     };
 
     // Android devices have API levels
@@ -237,6 +243,7 @@ async fn test_platform_specific_features() -> Result<()> {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     // iOS devices have UDIDs
@@ -283,6 +290,7 @@ async fn test_device_edge_cases() -> Result<()> {
         is_running: false,
         ram_size: "0".to_string(),
         storage_size: "0M".to_string(),
+        abi: String::new(),
     };
 
     assert_eq!(minimal_android.name(), "");
@@ -298,6 +306,7 @@ async fn test_device_edge_cases() -> Result<()> {
         is_running: true,
         ram_size: "16384".to_string(),      // 16GB
         storage_size: "65536M".to_string(), // 64GB
+        abi: String::new(),
     };
 
     assert_eq!(max_android.name().len(), 100);
@@ -313,6 +322,7 @@ async fn test_device_edge_cases() -> Result<()> {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: false, // Unavailable device
+        availability_error: None,
     };
 
     assert!(special_ios.name().contains("🚀"));
@@ -333,6 +343,7 @@ async fn test_model_consistency() -> Result<()> {
         is_running: true, // Should match status
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     assert_eq!(consistent_device.status(), &DeviceStatus::Running);
@@ -347,6 +358,7 @@ async fn test_model_consistency() -> Result<()> {
         is_running: false, // Should match status
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     assert_eq!(stopped_device.status(), &DeviceStatus::Stopped);
@@ -19,6 +19,7 @@ fn test_android_circular_navigation() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -29,6 +30,7 @@ fn test_android_circular_navigation() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -39,6 +41,7 @@ fn test_android_circular_navigation() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -95,6 +98,7 @@ fn test_ios_circular_navigation() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
         IosDevice {
             name: "iPad Pro".to_string(),
@@ -105,6 +109,7 @@ fn test_ios_circular_navigation() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
     ];
 
@@ -183,6 +188,7 @@ fn test_single_device_navigation() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     // Should stay at position 0 for single device
@@ -204,6 +210,7 @@ fn test_single_device_navigation() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     // Should stay at position 0 for single device
@@ -235,6 +242,7 @@ fn test_rapid_circular_navigation() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         })
         .collect();
 
@@ -29,6 +29,7 @@ async fn test_basic_panel_switching() {
         is_running: true,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     state.ios_devices = vec![IosDevice {
@@ -38,6 +39,7 @@ async fn test_basic_panel_switching() {
         ios_version: "16.0".to_string(),
         runtime_version: "iOS 16.0".to_string(),
         is_available: true,
+        availability_error: None,
         is_running: true,
         status: DeviceStatus::Running,
     }];
@@ -80,6 +82,7 @@ async fn test_panel_switching_log_coordination() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -90,6 +93,7 @@ async fn test_panel_switching_log_coordination() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -100,6 +104,7 @@ async fn test_panel_switching_log_coordination() {
         ios_version: "16.0".to_string(),
         runtime_version: "iOS 16.0".to_string(),
         is_available: true,
+        availability_error: None,
         is_running: true,
         status: DeviceStatus::Running,
     }];
@@ -168,6 +173,7 @@ async fn test_panel_switching_device_details() {
         is_running: true,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     }];
 
     state.ios_devices = vec![IosDevice {
@@ -177,6 +183,7 @@ async fn test_panel_switching_device_details() {
         ios_version: "16.0".to_string(),
         runtime_version: "iOS 16.0".to_string(),
         is_available: true,
+        availability_error: None,
         is_running: true,
         status: DeviceStatus::Running,
     }];
@@ -259,6 +266,7 @@ async fn test_panel_switching_performance() {
                 is_running: false,
                 ram_size: "2048".to_string(),
                 storage_size: "8192".to_string(),
+                abi: String::new(),
             })
             .collect();
 
@@ -270,6 +278,7 @@ async fn test_panel_switching_performance() {
                 ios_version: "16.0".to_string(),
                 runtime_version: "iOS 16.0".to_string(),
                 is_available: true,
+                availability_error: None,
                 is_running: false,
                 status: DeviceStatus::Stopped,
             })
@@ -367,6 +376,7 @@ async fn test_panel_switching_mixed_device_states() {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -377,6 +387,7 @@ async fn test_panel_switching_mixed_device_states() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -388,6 +399,7 @@ async fn test_panel_switching_mixed_device_states() {
             ios_version: "16.0".to_string(),
             runtime_version: "iOS 16.0".to_string(),
             is_available: true,
+            availability_error: None,
             is_running: true,
             status: DeviceStatus::Running,
         },
@@ -398,6 +410,7 @@ async fn test_panel_switching_mixed_device_states() {
             ios_version: "15.0".to_string(),
             runtime_version: "iOS 15.0".to_string(),
             is_available: false,
+            availability_error: None,
             is_running: false,
             status: DeviceStatus::Stopped,
         },
@@ -5,7 +5,6 @@
 
 use emu::app::state::{AppState, Panel};
 use emu::managers::android::AndroidManager;
-use emu::managers::common::DeviceManager;
 use emu::models::{AndroidDevice, DeviceStatus};
 use emu::utils::command_executor::mock::MockCommandExecutor;
 use std::sync::Arc;
@@ -153,6 +152,7 @@ async fn test_platform_switch_ui_state_persistence() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -163,6 +163,7 @@ async fn test_platform_switch_ui_state_persistence() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -177,6 +178,7 @@ async fn test_platform_switch_ui_state_persistence() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         }];
     }
 
@@ -373,6 +375,7 @@ async fn test_focus_management_integration() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -383,6 +386,7 @@ async fn test_focus_management_integration() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -393,6 +397,7 @@ async fn test_focus_management_integration() {
             is_running: false,
             ram_size: "6144".to_string(),
             storage_size: "32768".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -428,6 +433,7 @@ async fn test_focus_management_integration() {
                 status: DeviceStatus::Stopped,
                 is_running: false,
                 is_available: true,
+                availability_error: None,
             },
             IosDevice {
                 name: "iOS_Focus_2".to_string(),
@@ -438,6 +444,7 @@ async fn test_focus_management_integration() {
                 status: DeviceStatus::Running,
                 is_running: true,
                 is_available: true,
+                availability_error: None,
             },
         ];
 
@@ -477,6 +484,7 @@ async fn test_platform_state_synchronization() {
         is_running: true,
         ram_size: "2048".to_string(),
         storage_size: "8192".to_string(),
+        abi: String::new(),
     };
 
     let stopped_android = AndroidDevice {
@@ -488,6 +496,7 @@ async fn test_platform_state_synchronization() {
         is_running: false,
         ram_size: "4096".to_string(),
         storage_size: "16384".to_string(),
+        abi: String::new(),
     };
 
     app_state.android_devices = vec![running_android, stopped_android];
@@ -525,6 +534,7 @@ async fn test_platform_state_synchronization() {
             status: DeviceStatus::Running,
             is_running: true,
             is_available: true,
+            availability_error: None,
         };
 
         app_state.ios_devices = vec![running_ios];
@@ -48,6 +48,7 @@ impl UiTestFixture {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "8192".to_string(),
+            abi: String::new(),
         };
         state.android_devices.push(android_device);
 
@@ -61,6 +62,7 @@ impl UiTestFixture {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         };
         state.ios_devices.push(ios_device);
 
@@ -123,6 +125,7 @@ fn test_main_layout_with_android_devices() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -133,6 +136,7 @@ fn test_main_layout_with_android_devices() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16G".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -175,6 +179,7 @@ fn test_main_layout_with_ios_devices() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
         IosDevice {
             name: "iPad_Pro_12_9".to_string(),
@@ -185,6 +190,7 @@ fn test_main_layout_with_ios_devices() {
             status: DeviceStatus::Running,
             is_running: true,
             is_available: true,
+            availability_error: None,
         },
     ];
 
@@ -221,6 +227,7 @@ fn test_device_shortcuts_follow_active_platform_panel() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
     app_state.ios_devices = vec![IosDevice {
         name: "iPhone_15_Pro".to_string(),
@@ -231,6 +238,7 @@ fn test_device_shortcuts_follow_active_platform_panel() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     let theme = Theme::dark();
@@ -302,6 +310,7 @@ fn test_panel_focus_and_selection() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8G".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -312,6 +321,7 @@ fn test_panel_focus_and_selection() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16G".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -372,6 +382,7 @@ fn test_responsive_layout_different_sizes() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8G".to_string(),
+            abi: String::new(),
         }];
 
         // Should not panic with any reasonable terminal size
@@ -408,6 +419,7 @@ fn test_device_details_panel_rendering() {
         is_running: true,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
     app_state.selected_android = 0;
 
@@ -520,6 +532,7 @@ fn test_long_device_names_rendering() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8G".to_string(),
+        abi: String::new(),
     }];
 
     let theme = Theme::dark();
@@ -562,6 +575,7 @@ fn test_many_devices_rendering() {
             is_running: i % 2 == 0,
             ram_size: "2048".to_string(),
             storage_size: "8G".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -641,6 +655,7 @@ fn test_text_truncation_and_layout() {
         is_running: true,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
 
     let theme = Theme::dark();
@@ -700,6 +715,7 @@ fn test_buffer_text_extraction() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8G".to_string(),
+        abi: String::new(),
     }];
 
     let theme = Theme::dark();
@@ -334,6 +334,7 @@ async fn test_ios_manager_get_device_details() {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: true,
+        availability_error: None,
     };
 
     let details = manager.get_device_details(&device.udid).await.unwrap();
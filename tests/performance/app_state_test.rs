@@ -61,6 +61,7 @@ async fn test_app_state_device_lists() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     state.android_devices.push(android_device);
@@ -77,6 +78,7 @@ async fn test_app_state_device_lists() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     state.ios_devices.push(ios_device);
@@ -293,6 +295,7 @@ async fn test_app_state_device_status_transitions() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     // Test different status transitions
@@ -1,9 +1,15 @@
 // Performance test module declarations
 
+#[cfg(feature = "tui")]
 mod app_mod_test;
+#[cfg(feature = "tui")]
 mod app_state_test;
+#[cfg(feature = "tui")]
 mod memory_usage_test;
 mod models_device_info_test;
+#[cfg(feature = "tui")]
 mod responsiveness_validation_test;
+#[cfg(feature = "tui")]
 mod startup_benchmark_test;
+#[cfg(feature = "tui")]
 mod ui_responsiveness_test;
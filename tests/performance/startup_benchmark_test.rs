@@ -7,7 +7,6 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use emu::app::state::AppState;
 use emu::managers::android::AndroidManager;
-use emu::managers::common::DeviceManager;
 #[cfg(feature = "test-utils")]
 use emu::models::{AndroidDevice, DeviceStatus};
 use emu::utils::command_executor::{mock::MockCommandExecutor, CommandExecutor};
@@ -862,6 +861,7 @@ fn create_test_android_devices(count: usize) -> Vec<AndroidDevice> {
             is_running: i % 3 == 0,
             ram_size: format!("{}", 2048 + (i % 4) * 1024),
             storage_size: format!("{}", 8192 + (i % 3) * 4096),
+            abi: String::new(),
         })
         .collect()
 }
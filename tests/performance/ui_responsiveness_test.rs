@@ -36,6 +36,7 @@ async fn test_panel_switching_responsiveness() {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         }];
     }
 
@@ -165,17 +166,13 @@ async fn test_120fps_keyboard_polling() {
     for i in 0..polling_cycles {
         // Simulate key input event processing
         match i % 4 {
-            0 => {
+            0 if app_state.selected_android < app_state.android_devices.len() - 1 => {
                 // Down key
-                if app_state.selected_android < app_state.android_devices.len() - 1 {
-                    app_state.selected_android += 1;
-                }
+                app_state.selected_android += 1;
             }
-            1 => {
+            1 if app_state.selected_android > 0 => {
                 // Up key
-                if app_state.selected_android > 0 {
-                    app_state.selected_android -= 1;
-                }
+                app_state.selected_android -= 1;
             }
             2 => {
                 // Platform switch
@@ -436,6 +433,7 @@ fn create_test_devices(count: usize) -> Vec<AndroidDevice> {
             is_running: i % 4 == 1,
             ram_size: format!("{}", 2048 + (i % 4) * 1024),
             storage_size: format!("{}", 8192 + (i % 3) * 4096),
+            abi: String::new(),
         })
         .collect()
 }
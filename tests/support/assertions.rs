@@ -19,6 +19,30 @@ pub fn assert_mode(state: &AppState, expected: Mode) {
         Mode::ConfirmDelete => state.is_confirm_delete_mode(),
         Mode::ConfirmWipe => state.is_confirm_wipe_mode(),
         Mode::ManageApiLevels => state.is_api_level_mode(),
+        Mode::CommandHistory => state.is_command_history_mode(),
+        Mode::CreationQueue => state.is_creation_queue_mode(),
+        Mode::RepairDevice => state.is_repair_device_mode(),
+        Mode::PruneUnavailable => state.is_prune_unavailable_mode(),
+        Mode::Diagnostics => state.is_diagnostics_mode(),
+        Mode::EditAnnotation => state.is_edit_annotation_mode(),
+        Mode::Search => state.is_search_mode(),
+        Mode::MoveDeviceData => state.is_move_device_data_mode(),
+        Mode::SendIntent => state.is_send_intent_mode(),
+        Mode::ProcessInspector => state.is_process_inspector_mode(),
+        Mode::SystemProperties => state.is_system_properties_mode(),
+        Mode::RunMonkey => state.is_run_monkey_mode(),
+        Mode::LaunchDiagnosis => state.is_launch_diagnosis_mode(),
+        Mode::QuickBoot => state.is_quick_boot_mode(),
+        Mode::DeviceClock => state.is_device_clock_mode(),
+        Mode::TypeIntoDevice => state.is_type_into_device_mode(),
+        Mode::Radios => state.is_radios_mode(),
+        Mode::DeviceCatalog => state.is_device_catalog_mode(),
+        Mode::Snippets => state.is_snippets_mode(),
+        Mode::LaunchApp => state.is_launch_app_mode(),
+        Mode::CaptureTrace => state.is_capture_trace_mode(),
+        Mode::DeveloperToggles => state.is_developer_toggles_mode(),
+        Mode::AppData => state.is_app_data_mode(),
+        Mode::TestMatrix => state.is_test_matrix_mode(),
     };
     assert!(
         actual_matches,
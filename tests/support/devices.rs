@@ -16,6 +16,7 @@ pub fn android_device(name: &str) -> AndroidDevice {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }
 }
 
@@ -56,6 +57,7 @@ pub fn ios_device(name: &str) -> IosDevice {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }
 }
 
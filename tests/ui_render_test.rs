@@ -3,11 +3,23 @@
 
 use emu::{
     app::{
-        state::{CreateDeviceField, Notification},
-        AppState, Mode, Panel,
+        state::{
+            CommandHistoryDialog, CreateDeviceField, DiagnosticsDialog, Notification,
+            PruneUnavailableDialog, QueuedCreationStatus, RepairDeviceDialog,
+        },
+        ApiLevelManagementState, AppState, Mode, Panel,
+    },
+    managers::common::DeviceConfig,
+    models::{
+        device::{AndroidDevice, DeviceStatus, IosDevice},
+        ApiLevel, DeviceCreationPreview, SimulatorUnavailableReason, SystemImageVariant,
     },
-    models::device::{AndroidDevice, DeviceStatus, IosDevice},
     ui::{render::draw_app, Theme},
+    utils::{
+        audit::CommandAuditEntry,
+        diagnostics::DiagnosticEntry,
+        xcode::{DeveloperDir, DeveloperDirSource},
+    },
 };
 use ratatui::{backend::TestBackend, Terminal};
 use std::time::Instant;
@@ -38,6 +50,7 @@ fn create_app_state_with_devices() -> AppState {
             is_running: true,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -48,6 +61,7 @@ fn create_app_state_with_devices() -> AppState {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16G".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -62,6 +76,7 @@ fn create_app_state_with_devices() -> AppState {
             status: DeviceStatus::Running,
             is_running: true,
             is_available: true,
+            availability_error: None,
         },
         IosDevice {
             name: "iPad Air".to_string(),
@@ -72,6 +87,7 @@ fn create_app_state_with_devices() -> AppState {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         },
     ];
 
@@ -148,6 +164,111 @@ fn test_draw_app_fullscreen_logs() {
     assert!(state.fullscreen_logs);
 }
 
+#[test]
+fn test_draw_app_fullscreen_devices_hides_details_and_logs() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.toggle_fullscreen_devices();
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("DEVICES ONLY"));
+    assert!(content.contains("Pixel 7 API 34"));
+    assert!(!content.contains("Logs - "));
+    assert!(!content.contains("Device Details"));
+}
+
+#[test]
+fn test_draw_app_truncates_wide_device_names_by_display_width() {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.ios_devices[0].name = "日本語のとても長いシミュレーター名 👩‍👩‍👧‍👦 テスト端末".to_string();
+    state.active_panel = Panel::Ios;
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    // The first iOS row; wide characters are followed by a blank cell
+    let buffer = terminal.backend().buffer();
+    let ios_panel = buffer.area.width / 10 * 3;
+    let row = (ios_panel..ios_panel * 2)
+        .filter_map(|x| buffer.cell((x, 4)))
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(row.contains("日 本 語"), "{row}");
+    assert!(row.contains("..."), "{row}");
+    assert!(row.ends_with('│'), "{row}");
+}
+
+#[test]
+fn test_draw_app_perf_overlay() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.show_perf_overlay = true;
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("Perf"));
+    for label in ["frame", "lock", "refresh", "command"] {
+        assert!(content.contains(label), "{label} missing");
+    }
+}
+
+#[test]
+fn test_draw_app_zen_logs_has_no_chrome() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.add_log("INFO".to_string(), "zen message".to_string());
+    state.toggle_zen_logs();
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let first_row = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .take(120)
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(first_row.contains("[INFO] zen message"));
+    assert!(!first_row.contains("Emu v"));
+    assert!(!first_row.contains('│'));
+}
+
 #[test]
 fn test_draw_app_create_device_dialog() {
     let mut terminal = create_test_terminal();
@@ -166,6 +287,296 @@ fn test_draw_app_create_device_dialog() {
     assert_eq!(state.mode, Mode::CreateDevice);
 }
 
+#[test]
+fn test_draw_app_create_device_dialog_with_preview() {
+    let backend = TestBackend::new(140, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::CreateDevice;
+    state.active_panel = Panel::Android;
+    state.create_device_form.name = "Pixel 9 API 34".to_string();
+    state.create_device_form.preview = Some(DeviceCreationPreview {
+        avdmanager_path: "avdmanager".to_string(),
+        package_path: "system-images;android-34;google_apis;x86_64".to_string(),
+        tag: "google_apis".to_string(),
+        abi: "x86_64".to_string(),
+        image_installed: true,
+        device_id: Some("pixel_9".to_string()),
+        skin: None,
+        resolution: Some("1080x2424".to_string()),
+        dpi: Some(420),
+    });
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("Preview"));
+    assert!(content.contains("1080x2424 @ 420 dpi"));
+    assert!(content.contains("avdmanager create avd -n Pixel_9_API_34"));
+}
+
+#[test]
+fn test_draw_app_create_device_dialog_shows_name_error_under_field() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::CreateDevice;
+    state.active_panel = Panel::Android;
+    state.create_device_form.name = "Pixel@7".to_string();
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let rows: Vec<String> = buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect();
+    let name_row = rows
+        .iter()
+        .position(|row| row.contains("Name:"))
+        .expect("name field is rendered");
+    // The input's underline sits between the field and its error
+    assert!(rows[name_row + 2].contains("Device name can only contain"));
+}
+
+#[test]
+fn test_draw_app_command_history_dialog() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::CommandHistory;
+    state.command_history_dialog = Some(CommandHistoryDialog {
+        entries: vec![CommandAuditEntry {
+            started_at: chrono::Local::now(),
+            command: "adb devices".to_string(),
+            duration: std::time::Duration::from_millis(42),
+            exit_code: Some(1),
+            success: false,
+        }],
+        scroll_offset: 0,
+        log_path: Some("/tmp/emu/commands.log".into()),
+    });
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("Command History (1)"));
+    assert!(content.contains("exit 1"));
+    assert!(content.contains("adb devices"));
+    assert!(content.contains("/tmp/emu/commands.log"));
+}
+
+#[test]
+fn test_draw_app_diagnostics_dialog() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::Diagnostics;
+    state.diagnostics_dialog = Some(DiagnosticsDialog {
+        entries: vec![DiagnosticEntry {
+            timestamp: chrono::Local::now(),
+            level: log::Level::Warn,
+            target: "emu::app".to_string(),
+            message: "Failed to load device details".to_string(),
+        }],
+        scroll_offset: 0,
+        developer_dir: Some(DeveloperDir {
+            path: "/Applications/Xcode-15.4.app/Contents/Developer".into(),
+            source: DeveloperDirSource::Emu,
+        }),
+        installed_xcodes: vec![
+            "/Applications/Xcode-15.4.app/Contents/Developer".into(),
+            "/Applications/Xcode.app/Contents/Developer".into(),
+        ],
+    });
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("Diagnostics (1)"));
+    assert!(content.contains("WARN"));
+    assert!(content.contains("Failed to load device details"));
+    assert!(content
+        .contains("Xcode: /Applications/Xcode-15.4.app/Contents/Developer (selected in emu)"));
+    assert!(content.contains("[x] Switch Xcode"));
+}
+
+#[test]
+fn test_draw_app_creation_queue_dialog() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::CreationQueue;
+    let pending = state.creation_queue.enqueue(
+        Panel::Android,
+        DeviceConfig::new(
+            "Pixel_Queued".to_string(),
+            "pixel_7".to_string(),
+            "34".to_string(),
+        ),
+    );
+    let active = state.creation_queue.enqueue(
+        Panel::Android,
+        DeviceConfig::new(
+            "Pixel_Active".to_string(),
+            "pixel_7".to_string(),
+            "35".to_string(),
+        ),
+    );
+    state.creation_queue.get_mut(active).unwrap().status = QueuedCreationStatus::Active {
+        stage: Some("Writing configuration".to_string()),
+        progress: Some(80),
+    };
+    assert_eq!(state.creation_queue.items[0].id, pending);
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("1 pending, 1 active of 2, 0 done"));
+    assert!(content.contains("Pixel_Queued"));
+    assert!(content.contains("Pending"));
+    assert!(content.contains("Writing configuration - 80%"));
+}
+
+#[test]
+fn test_draw_app_repair_device_dialog() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::RepairDevice;
+    state.repair_device_dialog = Some(RepairDeviceDialog {
+        device_name: "iPad Air".to_string(),
+        udid: "09876-54321-FEDCBA".to_string(),
+        ios_version: "16.4".to_string(),
+        reason: SimulatorUnavailableReason::RuntimeMissing,
+    });
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("iPad Air is unavailable"));
+    assert!(content.contains("Reason: runtime missing"));
+    assert!(content.contains("[i] Install runtime"));
+}
+
+#[test]
+fn test_draw_app_prune_unavailable_dialog() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    let mut device = state.ios_devices[1].clone();
+    device.is_available = false;
+    device.availability_error = Some("runtime profile not found".to_string());
+    state.mode = Mode::PruneUnavailable;
+    state.prune_unavailable_dialog = Some(PruneUnavailableDialog::new(vec![device], false));
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("Delete 1 unavailable simulator(s)?"));
+    assert!(content.contains("runtime missing"));
+    assert!(content.contains("[y] Delete all"));
+}
+
+#[test]
+fn test_draw_app_details_panel_shows_command() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    state.mode = Mode::Normal;
+    state.active_panel = Panel::Ios;
+    state.shown_command = Some("xcrun simctl boot 12345-67890-ABCDEF".to_string());
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("xcrun simctl boot"));
+}
+
 #[test]
 fn test_draw_app_confirm_delete_dialog() {
     let mut terminal = create_test_terminal();
@@ -229,6 +640,52 @@ fn test_draw_app_manage_api_levels() {
     assert_eq!(state.mode, Mode::ManageApiLevels);
 }
 
+#[test]
+fn test_draw_app_api_levels_shows_image_sizes() {
+    let mut terminal = create_test_terminal();
+    let mut state = create_app_state_with_devices();
+    let theme = Theme::dark();
+
+    let api_level = |api: u32, installed: bool| {
+        let package_id = format!("system-images;android-{api};google_apis;x86_64");
+        let mut level = ApiLevel::new(api, format!("Android {api}"), package_id.clone());
+        let mut variant =
+            SystemImageVariant::new("google_apis".to_string(), "x86_64".to_string(), package_id);
+        variant.is_installed = installed;
+        if installed {
+            variant.installed_size = Some(3 * 1024 * 1024 * 1024);
+        } else {
+            variant.download_size = Some(1536 * 1024 * 1024);
+        }
+        level.variants.push(variant);
+        level.is_installed = installed;
+        level
+    };
+    state.mode = Mode::ManageApiLevels;
+    state.api_level_management = Some(ApiLevelManagementState {
+        api_levels: vec![api_level(35, false), api_level(34, true)],
+        is_loading: false,
+        ..Default::default()
+    });
+
+    terminal
+        .draw(|frame| {
+            draw_app(frame, &mut state, &theme);
+        })
+        .unwrap();
+
+    let content = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(content.contains("~1.5 GB download"));
+    assert!(content.contains("(3.0 GB)"));
+    assert!(content.contains("Disk used: 3.0 GB"));
+}
+
 #[test]
 fn test_draw_app_with_notifications() {
     let mut terminal = create_test_terminal();
@@ -359,6 +816,7 @@ fn test_draw_app_device_scrolling() {
             is_running: i % 2 == 0,
             ram_size: "2048".to_string(),
             storage_size: "8G".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -518,6 +976,7 @@ fn test_render_performance() {
             is_running: i % 3 == 0,
             ram_size: "2048".to_string(),
             storage_size: "8G".to_string(),
+            abi: String::new(),
         });
     }
 
@@ -0,0 +1,100 @@
+//! Golden-file UI tests
+//! Renders fixed app states and compares them with `tests/snapshots/`
+
+#![cfg(feature = "test-utils")]
+
+use emu::{
+    app::{AppState, Panel},
+    models::{
+        device::{AndroidDevice, DeviceStatus, IosDevice},
+        DeviceDetails, Platform,
+    },
+    ui::{assert_snapshot, render_snapshot},
+};
+
+/// The iOS panel title depends on the host, so golden files are kept per OS.
+fn snapshot_path(name: &str) -> String {
+    format!(
+        "{}/tests/snapshots/{name}.{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        std::env::consts::OS
+    )
+}
+
+fn state_with_devices() -> AppState {
+    let mut state = AppState::new();
+    state.is_loading = false;
+    state.android_devices = vec![
+        AndroidDevice {
+            android_version_name: "Android 14".to_string(),
+            name: "Pixel_7_API_34".to_string(),
+            device_type: "pixel_7".to_string(),
+            api_level: 34,
+            status: DeviceStatus::Running,
+            is_running: true,
+            ram_size: "2048".to_string(),
+            storage_size: "8192M".to_string(),
+            abi: String::new(),
+        },
+        AndroidDevice {
+            android_version_name: "Android 13".to_string(),
+            name: "Tablet_API_33".to_string(),
+            device_type: "pixel_tablet".to_string(),
+            api_level: 33,
+            status: DeviceStatus::Stopped,
+            is_running: false,
+            ram_size: "4096".to_string(),
+            storage_size: "16384M".to_string(),
+            abi: String::new(),
+        },
+    ];
+    state.ios_devices = vec![IosDevice {
+        name: "iPhone 15".to_string(),
+        udid: "5E0F3C1A-1234-4B5C-9D8E-0123456789AB".to_string(),
+        device_type: "iPhone 15".to_string(),
+        ios_version: "17.0".to_string(),
+        runtime_version: "iOS 17.0".to_string(),
+        status: DeviceStatus::Stopped,
+        is_running: false,
+        is_available: true,
+        availability_error: None,
+    }];
+    state
+}
+
+#[test]
+fn test_android_panel_snapshot() {
+    let mut state = state_with_devices();
+    // Cached details keep the AVD path independent of $HOME
+    state.cached_device_details = Some(DeviceDetails {
+        name: "Pixel_7_API_34".to_string(),
+        status: "Running".to_string(),
+        platform: Platform::Android,
+        device_type: "pixel_7".to_string(),
+        api_level_or_version: "API 34 (Android 14)".to_string(),
+        ram_size: Some("2048 MB".to_string()),
+        storage_size: Some("8192M".to_string()),
+        resolution: Some("1080x2400".to_string()),
+        dpi: Some("420".to_string()),
+        device_path: Some("~/.android/avd/Pixel_7_API_34.avd".to_string()),
+        system_image: Some("system-images;android-34;google_apis;arm64-v8a".to_string()),
+        identifier: "Pixel_7_API_34".to_string(),
+    });
+    let screen = render_snapshot(&mut state, 120, 40);
+    assert_snapshot(&screen, snapshot_path("android_panel_120x40"));
+}
+
+#[test]
+fn test_ios_panel_snapshot() {
+    let mut state = state_with_devices();
+    state.active_panel = Panel::Ios;
+    let screen = render_snapshot(&mut state, 120, 40);
+    assert_snapshot(&screen, snapshot_path("ios_panel_120x40"));
+}
+
+#[test]
+fn test_small_terminal_snapshot() {
+    let mut state = state_with_devices();
+    let screen = render_snapshot(&mut state, 40, 10);
+    assert_snapshot(&screen, snapshot_path("small_40x10"));
+}
@@ -16,6 +16,7 @@ pub mod helpers {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         }
     }
 
@@ -30,6 +31,7 @@ pub mod helpers {
             status: DeviceStatus::Stopped,
             is_running: false,
             is_available: true,
+            availability_error: None,
         }
     }
 
@@ -1,4 +1,5 @@
 // Unit test module declarations
 pub mod common;
 pub mod managers;
+#[cfg(feature = "tui")]
 pub mod ui;
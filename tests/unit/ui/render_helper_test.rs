@@ -54,6 +54,7 @@ fn test_render_state_preparation() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     };
 
     let ios_device = IosDevice {
@@ -65,6 +66,7 @@ fn test_render_state_preparation() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     };
 
     state.android_devices.push(android_device);
@@ -117,6 +119,7 @@ fn test_render_data_formatting() {
         is_running: true,
         ram_size: "4096".to_string(),
         storage_size: "16384M".to_string(),
+        abi: String::new(),
     };
 
     state.android_devices.push(android_device);
@@ -143,6 +146,7 @@ fn test_render_data_formatting() {
         status: DeviceStatus::Running,
         is_running: true,
         is_available: true,
+        availability_error: None,
     };
 
     state.ios_devices.push(ios_device);
@@ -341,6 +345,7 @@ fn test_render_device_multiple_states() {
             is_running: i % 2 == 0,
             ram_size: (2048 + i * 1024).to_string(),
             storage_size: format!("{size}M", size = 8192 + i * 2048),
+            abi: String::new(),
         };
         state.android_devices.push(device);
     }
@@ -360,6 +365,7 @@ fn test_render_device_multiple_states() {
             },
             is_running: i % 2 == 0,
             is_available: true,
+            availability_error: None,
         };
         state.ios_devices.push(device);
     }
@@ -47,6 +47,7 @@ fn test_android_panel_rendering() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -57,6 +58,7 @@ fn test_android_panel_rendering() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -97,6 +99,7 @@ fn test_ios_panel_rendering() {
         status: DeviceStatus::Stopped,
         is_running: false,
         is_available: true,
+        availability_error: None,
     }];
 
     state.active_panel = Panel::Ios;
@@ -245,6 +248,7 @@ fn test_different_device_states_rendering() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -255,6 +259,7 @@ fn test_different_device_states_rendering() {
             is_running: true,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -294,6 +299,7 @@ fn test_long_device_names_rendering() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
 
     let result = terminal.draw(|frame| {
@@ -350,6 +356,7 @@ fn test_different_selection_indices() {
             is_running: false,
             ram_size: "2048".to_string(),
             storage_size: "8192M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -360,6 +367,7 @@ fn test_different_selection_indices() {
             is_running: false,
             ram_size: "4096".to_string(),
             storage_size: "16384M".to_string(),
+            abi: String::new(),
         },
         AndroidDevice {
             android_version_name: "API 30".to_string(),
@@ -370,6 +378,7 @@ fn test_different_selection_indices() {
             is_running: false,
             ram_size: "8192".to_string(),
             storage_size: "32768M".to_string(),
+            abi: String::new(),
         },
     ];
 
@@ -405,6 +414,7 @@ fn test_out_of_bounds_selection_index() {
         is_running: false,
         ram_size: "2048".to_string(),
         storage_size: "8192M".to_string(),
+        abi: String::new(),
     }];
 
     // Out-of-bounds selection index